@@ -13,7 +13,7 @@ use crate::pages::{
 #[component]
 pub fn App() -> impl IntoView {
     view! {
-        <MingotProvider>
+        <MingotProvider persist_key="mingot-demo-color-scheme">
             <ThemeVars />
             <Router>
                 <DocsLayout>
@@ -52,6 +52,7 @@ pub fn App() -> impl IntoView {
 
                         // Form components
                         <Route path=path!("/form/checkbox") view=move || view! { <ComponentPage slug="checkbox" /> } />
+                        <Route path=path!("/form/date-picker") view=move || view! { <ComponentPage slug="date-picker" /> } />
                         <Route path=path!("/form/file-input") view=move || view! { <ComponentPage slug="file-input" /> } />
                         <Route path=path!("/form/input") view=move || view! { <ComponentPage slug="input" /> } />
                         <Route path=path!("/form/number-input") view=move || view! { <ComponentPage slug="number-input" /> } />
@@ -78,6 +79,7 @@ pub fn App() -> impl IntoView {
                         <Route path=path!("/form/slider") view=move || view! { <ComponentPage slug="slider" /> } />
                         <Route path=path!("/form/switch") view=move || view! { <ComponentPage slug="switch" /> } />
                         <Route path=path!("/form/textarea") view=move || view! { <ComponentPage slug="textarea" /> } />
+                        <Route path=path!("/form/time-picker") view=move || view! { <ComponentPage slug="time-picker" /> } />
                         <Route path=path!("/form/parameter-slider") view=move || view! { <ComponentPage slug="parameter-slider" /> } />
                         <Route path=path!("/form/parameter-grid") view=move || view! { <ComponentPage slug="parameter-grid" /> } />
                         <Route path=path!("/form/parameter-tree") view=move || view! { <ComponentPage slug="parameter-tree" /> } />
@@ -100,6 +102,7 @@ pub fn App() -> impl IntoView {
                         <Route path=path!("/data-display/accordion") view=move || view! { <ComponentPage slug="accordion" /> } />
                         <Route path=path!("/data-display/avatar") view=move || view! { <ComponentPage slug="avatar" /> } />
                         <Route path=path!("/data-display/badge") view=move || view! { <ComponentPage slug="badge" /> } />
+                        <Route path=path!("/data-display/indicator") view=move || view! { <ComponentPage slug="indicator" /> } />
                         <Route path=path!("/data-display/ring-progress") view=move || view! { <ComponentPage slug="ring-progress" /> } />
                         <Route path=path!("/data-display/stats") view=move || view! { <ComponentPage slug="stats" /> } />
                         <Route path=path!("/data-display/table") view=move || view! { <ComponentPage slug="table" /> } />
@@ -68,6 +68,8 @@ pub fn get_component_doc(slug: &str) -> Option<ComponentDoc> {
         "pin-input" => Some(pin_input_doc()),
         "radio" => Some(radio_doc()),
         "select" => Some(select_doc()),
+        "date-picker" => Some(date_picker_doc()),
+        "time-picker" => Some(time_picker_doc()),
         "slider" => Some(slider_doc()),
         "range-slider" => Some(range_slider_doc()),
         "segmented-control" => Some(segmented_control_doc()),
@@ -92,6 +94,7 @@ pub fn get_component_doc(slug: &str) -> Option<ComponentDoc> {
         "accordion" => Some(accordion_doc()),
         "avatar" => Some(avatar_doc()),
         "badge" => Some(badge_doc()),
+        "indicator" => Some(indicator_doc()),
         "ring-progress" => Some(ring_progress_doc()),
         "stats" => Some(stats_doc()),
         "table" => Some(table_doc()),
@@ -206,7 +209,7 @@ fn button_doc() -> ComponentDoc {
 fn number_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "NumberInput",
-        import_name: "NumberInput, NumberInputPrecision, NumberInputFormat, NumberInputLocale, ParseError",
+        import_name: "NumberInput, NumberInputPrecision, NumberInputFormat, NumberInputLocale, NumberRadix, ParseError",
         description: "High-precision numeric input supporting u64, u128, i64, i128, fixed decimals, and arbitrary precision via rust_decimal. Features increment controls, locale formatting, and precision indicators.",
         props: vec![
             PropDoc {
@@ -216,6 +219,34 @@ fn number_input_doc() -> ComponentDoc {
                 description: "Precision type: U64, U128, I64, I128, Decimal(u32), or Arbitrary",
                 required: false,
             },
+            PropDoc {
+                name: "prefix",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Decoration inside the field before the number, e.g. \"$\"; not part of the value",
+                required: false,
+            },
+            PropDoc {
+                name: "suffix",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Decoration inside the field after the number, e.g. \"USD\"; sits left of the +/- controls",
+                required: false,
+            },
+            PropDoc {
+                name: "radix",
+                prop_type: "NumberRadix",
+                default: Some("Decimal"),
+                description: "Entry base for U64/U128/I64/I128: Decimal, Binary, Octal or Hex. Accepts 0x/0o/0b prefixes, any letter case and _ separators",
+                required: false,
+            },
+            PropDoc {
+                name: "emit_radix",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Emit on_valid_change values in the chosen radix instead of canonical decimal",
+                required: false,
+            },
             PropDoc {
                 name: "show_controls",
                 prop_type: "bool",
@@ -225,21 +256,21 @@ fn number_input_doc() -> ComponentDoc {
             },
             PropDoc {
                 name: "step",
-                prop_type: "Option<String>",
+                prop_type: "Option<Signal<String>>",
                 default: Some("\"1\""),
                 description: "Step size for increment/decrement",
                 required: false,
             },
             PropDoc {
                 name: "shift_step",
-                prop_type: "Option<String>",
+                prop_type: "Option<Signal<String>>",
                 default: Some("10x step"),
                 description: "Step size when Shift key is held (default: 10x step)",
                 required: false,
             },
             PropDoc {
                 name: "ctrl_step",
-                prop_type: "Option<String>",
+                prop_type: "Option<Signal<String>>",
                 default: Some("100x step"),
                 description: "Step size when Ctrl key is held (default: 100x step)",
                 required: false,
@@ -295,9 +326,107 @@ fn number_input_doc() -> ComponentDoc {
             },
             PropDoc {
                 name: "on_valid_change",
+                prop_type: "Option<Callback<String>>",
+                default: None,
+                description: "Called with the value whenever a change leaves it valid",
+                required: false,
+            },
+            PropDoc {
+                name: "on_invalid_change",
+                prop_type: "Option<Callback<ParseError>>",
+                default: None,
+                description: "Called with the error on every change that fails to parse; fires after on_change and before any later change is reported",
+                required: false,
+            },
+            PropDoc {
+                name: "on_validation_result",
                 prop_type: "Option<Callback<Result<String, ParseError>>>",
                 default: None,
-                description: "Callback fired with validation result on each change",
+                description: "Deprecated: the old combined callback, fired after on_valid_change/on_invalid_change. Will be removed in the next release",
+                required: false,
+            },
+            PropDoc {
+                name: "negative_style",
+                prop_type: "Option<NegativeStyle>",
+                default: Some("Minus"),
+                description: "How negatives are shown when formatted: Minus or Parentheses (accounting)",
+                required: false,
+            },
+            PropDoc {
+                name: "sig_figs",
+                prop_type: "Option<u32>",
+                default: None,
+                description: "Significant digits shown in the mantissa for Scientific/Engineering formats (display only; the value stays exact)",
+                required: false,
+            },
+            PropDoc {
+                name: "min",
+                prop_type: "Option<Signal<String>>",
+                default: None,
+                description: "Lower bound for stepping; accepts a string or a signal for dynamic ranges",
+                required: false,
+            },
+            PropDoc {
+                name: "max",
+                prop_type: "Option<Signal<String>>",
+                default: None,
+                description: "Upper bound for stepping; accepts a string or a signal for dynamic ranges",
+                required: false,
+            },
+            PropDoc {
+                name: "clamp_on_range_change",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Re-clamp the current value when min/max change",
+                required: false,
+            },
+            PropDoc {
+                name: "clamp",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Snap out-of-range values to the nearest bound on blur; when false they are reported as ParseError::OutOfRange",
+                required: false,
+            },
+            PropDoc {
+                name: "on_range_clamp",
+                prop_type: "Option<Callback<String>>",
+                default: None,
+                description: "Called with the clamped value when a range change or blur moves it",
+                required: false,
+            },
+            PropDoc {
+                name: "default_value",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Value restored by reset; an empty field starts at it",
+                required: false,
+            },
+            PropDoc {
+                name: "on_register",
+                prop_type: "Option<Callback<FieldState<String>>>",
+                default: None,
+                description: "Receives a handle with is_dirty(), reset() and mark_clean()",
+                required: false,
+            },
+            PropDoc {
+                name: "show_reset",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a \"Reset to default\" button while the value is changed",
+                required: false,
+            },
+            PropDoc {
+                name: "quick_convert",
+                prop_type: "Option<Vec<Unit>>",
+                default: None,
+                description: "Units offered in an 'interpret as X, show as Y' conversion row; the value stays a plain number",
+                required: false,
+            },
+            PropDoc {
+                name: "on_quick_convert",
+                prop_type: "Option<Callback<f64>>",
+                default: None,
+                description: "Called with the converted value after a quick conversion",
                 required: false,
             },
         ],
@@ -305,6 +434,7 @@ fn number_input_doc() -> ComponentDoc {
             let u64_value = RwSignal::new(String::new());
             let controls_value = RwSignal::new("100".to_string());
             let format_value = RwSignal::new("1234567.89".to_string());
+            let dirty_field = RwSignal::new(None::<FieldState<String>>);
 
             view! {
                 <div>
@@ -315,11 +445,7 @@ fn number_input_doc() -> ComponentDoc {
                                 label="U64 (up to 18.4 quintillion)"
                                 placeholder="Enter a large integer"
                                 show_precision_indicator=true
-                                on_valid_change=Callback::new(move |result: Result<String, ParseError>| {
-                                    if let Ok(val) = result {
-                                        u64_value.set(val);
-                                    }
-                                })
+                                on_valid_change=Callback::new(move |val: String| u64_value.set(val))
                             />
                             <NumberInput
                                 precision=NumberInputPrecision::Decimal(6)
@@ -411,6 +537,105 @@ fn number_input_doc() -> ComponentDoc {
                         </div>
                     </DemoBlock>
 
+                    <h2 class="section-title">"Currency"</h2>
+                    <DemoBlock title="Prefix and suffix">
+                        <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
+                            <NumberInput
+                                precision=NumberInputPrecision::Decimal(2)
+                                label="Price"
+                                prefix="$"
+                                suffix="USD"
+                                format_on_blur=true
+                                format=NumberInputFormat::Thousand
+                                show_controls=true
+                                show_precision_indicator=true
+                            />
+                            <NumberInput
+                                precision=NumberInputPrecision::Decimal(2)
+                                label="Budget"
+                                prefix="€"
+                                format_on_blur=true
+                                format=NumberInputFormat::Thousand
+                            />
+                        </div>
+                    </DemoBlock>
+
+                    <h2 class="section-title">"Bounded Range"</h2>
+                    <DemoBlock title="Clamp or report out-of-range values">
+                        <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
+                            <NumberInput
+                                precision=NumberInputPrecision::Decimal(2)
+                                label="Discount % (snaps to 0–100 on blur)"
+                                min="0"
+                                max="100"
+                                clamp=true
+                                show_controls=true
+                                step="5"
+                            />
+                            <NumberInput
+                                precision=NumberInputPrecision::U64
+                                label="Port (1–65535, reported as an error)"
+                                min="1"
+                                max="65535"
+                            />
+                        </div>
+                    </DemoBlock>
+
+                    <h2 class="section-title">"Dirty Tracking"</h2>
+                    <DemoBlock title="Reset to default">
+                        <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
+                            <NumberInput
+                                precision=NumberInputPrecision::U64
+                                label="Batch size"
+                                default_value="32"
+                                show_reset=true
+                                on_register=Callback::new(move |state: FieldState<String>| {
+                                    dirty_field.set(Some(state))
+                                })
+                            />
+                            <Text size=TextSize::Sm>
+                                {move || {
+                                    let dirty = dirty_field.get().is_some_and(|f| f.is_dirty().get());
+                                    if dirty { "Unsaved changes" } else { "No changes" }
+                                }}
+                            </Text>
+                        </div>
+                    </DemoBlock>
+
+                    <h2 class="section-title">"Hex, Octal and Binary"</h2>
+                    <DemoBlock title="Non-decimal entry">
+                        <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
+                            <NumberInput
+                                precision=NumberInputPrecision::U64
+                                radix=NumberRadix::Hex
+                                label="Address (hex)"
+                                placeholder="e.g., 0xFF_FF"
+                                show_controls=true
+                                format_on_blur=true
+                                show_overflow_warning=true
+                            />
+                            <NumberInput
+                                precision=NumberInputPrecision::U64
+                                radix=NumberRadix::Binary
+                                label="Flags (binary)"
+                                placeholder="e.g., 1010"
+                                format_on_blur=true
+                            />
+                        </div>
+                    </DemoBlock>
+
+                    <h2 class="section-title">"Quick Convert"</h2>
+                    <DemoBlock title="Interpret as one unit, show as another">
+                        <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
+                            <NumberInput
+                                precision=NumberInputPrecision::Decimal(4)
+                                label="Length"
+                                placeholder="e.g., 12"
+                                quick_convert=length::all()
+                            />
+                        </div>
+                    </DemoBlock>
+
                     <h2 class="section-title">"Real-World Use Cases"</h2>
                     <DemoBlock title="Domain-specific examples">
                         <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 400px;">
@@ -451,6 +676,13 @@ fn input_doc() -> ComponentDoc {
         import_name: "Input, InputVariant, InputSize",
         description: "A text input component with variants, sizes, and HTML5 attribute support.",
         props: vec![
+            PropDoc {
+                name: "id",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Id of the control, e.g. for an external <label for>; also prefixes the description and error ids. Generated in render order under MingotProvider when not set",
+                required: false,
+            },
             PropDoc {
                 name: "variant",
                 prop_type: "Option<InputVariant>",
@@ -493,6 +725,48 @@ fn input_doc() -> ComponentDoc {
                 description: "Whether the input is disabled",
                 required: false,
             },
+            PropDoc {
+                name: "error",
+                prop_type: "Option<Signal<String>>",
+                default: None,
+                description: "Error message; when non-empty the border turns red, the message renders below and the control gets aria-invalid/aria-describedby",
+                required: false,
+            },
+            PropDoc {
+                name: "max_length",
+                prop_type: "Option<usize>",
+                default: None,
+                description: "Maximum length in grapheme clusters; typed or pasted text that doesn't fit is cut without touching the rest of the value, and a current/max counter is shown",
+                required: false,
+            },
+            PropDoc {
+                name: "maxlength",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Deprecated: read as max_length when that is not set. Will be removed in the next release",
+                required: false,
+            },
+            PropDoc {
+                name: "left_section",
+                prop_type: "Option<Children>",
+                default: None,
+                description: "Content inside the border at the start (left, or right in RTL), such as an icon; clicks pass through to the input",
+                required: false,
+            },
+            PropDoc {
+                name: "right_section",
+                prop_type: "Option<Children>",
+                default: None,
+                description: "Content inside the border at the end (right, or left in RTL)",
+                required: false,
+            },
+            PropDoc {
+                name: "clearable",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a × button while there is text; clicking it clears the field and fires on_input(\"\")",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -500,6 +774,24 @@ fn input_doc() -> ComponentDoc {
                     <div style="display: flex; flex-direction: column; gap: 1rem; width: 100%; max-width: 300px;">
                         <Input label="Default" placeholder="Enter text..." />
                         <Input variant=InputVariant::Filled label="Filled" placeholder="Filled variant" />
+                        <Input
+                            label="Email"
+                            description="We'll never share it"
+                            value="not-an-email"
+                            error="Enter a valid email address"
+                        />
+                        <Input
+                            label="Search"
+                            placeholder="Search components..."
+                            clearable=true
+                            left_section=Box::new(|| view! { <span aria-hidden="true">"⌕"</span> }.into_any())
+                        />
+                        <Input
+                            label="Amount"
+                            placeholder="0.00"
+                            left_section=Box::new(|| view! { <span>"$"</span> }.into_any())
+                            right_section=Box::new(|| view! { <span>"USD"</span> }.into_any())
+                        />
                     </div>
                 </DemoBlock>
             }.into_any()
@@ -763,7 +1055,16 @@ fn divider_doc() -> ComponentDoc {
                 name: "label_position",
                 prop_type: "Option<DividerLabelPosition>",
                 default: Some("Center"),
-                description: "Label position: Left, Center, or Right",
+                description:
+                    "Label position: Left, Center, Right, or Start/End to follow the text direction",
+                required: false,
+            },
+            PropDoc {
+                name: "flip_below",
+                prop_type: "Option<Breakpoint>",
+                default: None,
+                description:
+                    "Flip the orientation when the viewport is narrower than this theme breakpoint",
                 required: false,
             },
         ],
@@ -837,6 +1138,13 @@ fn group_doc() -> ComponentDoc {
                 description: "Group content",
                 required: true,
             },
+            PropDoc {
+                name: "stack_below",
+                prop_type: "Option<Breakpoint>",
+                default: None,
+                description: "Switch to a full-width column when the viewport is narrower than this theme breakpoint",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -855,6 +1163,15 @@ fn group_doc() -> ComponentDoc {
                         </Group>
                     </div>
                 </DemoBlock>
+                <DemoBlock title="Responsive (stacks below sm)">
+                    <Group stack_below=Breakpoint::Sm>
+                        <Button>"Save"</Button>
+                        <Divider orientation=DividerOrientation::Vertical flip_below=Breakpoint::Sm />
+                        <Button variant=ButtonVariant::Outline>"Preview"</Button>
+                        <Divider orientation=DividerOrientation::Vertical flip_below=Breakpoint::Sm />
+                        <Button variant=ButtonVariant::Subtle>"Cancel"</Button>
+                    </Group>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -937,6 +1254,20 @@ fn card_doc() -> ComponentDoc {
                 description: "Border radius",
                 required: false,
             },
+            PropDoc {
+                name: "with_border",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Draw a 1px theme border, useful in dark mode where shadows are faint",
+                required: false,
+            },
+            PropDoc {
+                name: "hover_shadow",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Shadow to transition to on hover (respects reduced motion)",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -953,6 +1284,12 @@ fn card_doc() -> ComponentDoc {
                         <Text size=TextSize::Sm color="dimmed">"Card content goes here."</Text>
                     </Card>
                 </DemoBlock>
+                <DemoBlock title="Bordered Card with Hover Elevation">
+                    <Card with_border=true hover_shadow="lg">
+                        <Text weight=TextWeight::Bold>"Hover me"</Text>
+                        <Text size=TextSize::Sm color="dimmed">"Lifts to a larger shadow on hover."</Text>
+                    </Card>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -1092,6 +1429,20 @@ fn paper_doc() -> ComponentDoc {
                 description: "Border radius",
                 required: false,
             },
+            PropDoc {
+                name: "with_border",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Draw a 1px theme border, useful in dark mode where shadows are faint",
+                required: false,
+            },
+            PropDoc {
+                name: "hover_shadow",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Shadow to transition to on hover (respects reduced motion)",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -1139,6 +1490,27 @@ fn breadcrumbs_doc() -> ComponentDoc {
                 description: "Character or string used as separator",
                 required: false,
             },
+            PropDoc {
+                name: "max_items",
+                prop_type: "Option<usize>",
+                default: None,
+                description: "Collapse the middle items into an ellipsis past this many items; clicking the ellipsis shows them all",
+                required: false,
+            },
+            PropDoc {
+                name: "items_before_collapse",
+                prop_type: "Option<usize>",
+                default: Some("1"),
+                description: "Items kept before the ellipsis",
+                required: false,
+            },
+            PropDoc {
+                name: "items_after_collapse",
+                prop_type: "Option<usize>",
+                default: Some("1"),
+                description: "Items kept after the ellipsis",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -1147,11 +1519,28 @@ fn breadcrumbs_doc() -> ComponentDoc {
     BreadcrumbItem::new("Docs").href("/docs"),
     BreadcrumbItem::new("Current Page"),
 ] />"#>
-                    <Breadcrumbs items=vec![
-                        BreadcrumbItem::new("Home").href("/"),
-                        BreadcrumbItem::new("Docs").href("/docs"),
-                        BreadcrumbItem::new("Current Page"),
-                    ] />
+                    <Stack spacing="lg">
+                        <Breadcrumbs items=vec![
+                            BreadcrumbItem::new("Home").href("/"),
+                            BreadcrumbItem::new("Docs").href("/docs"),
+                            BreadcrumbItem::new("Current Page"),
+                        ] />
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Collapsed past 4 items (click the ellipsis)"</Text>
+                            <Breadcrumbs
+                                max_items=4
+                                items_after_collapse=2
+                                items=vec![
+                                    BreadcrumbItem::new("Home").href("/"),
+                                    BreadcrumbItem::new("Projects").href("/projects"),
+                                    BreadcrumbItem::new("Mingot").href("/projects/mingot"),
+                                    BreadcrumbItem::new("src").href("/projects/mingot/src"),
+                                    BreadcrumbItem::new("components").href("/projects/mingot/src/components"),
+                                    BreadcrumbItem::new("breadcrumbs.rs"),
+                                ]
+                            />
+                        </div>
+                    </Stack>
                 </DemoBlock>
             }
             .into_any()
@@ -1258,7 +1647,7 @@ fn navbar_doc() -> ComponentDoc {
 fn tabs_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Tabs",
-        import_name: "Tabs, TabsList, TabsTab, TabsPanel",
+        import_name: "Tabs, TabsList, TabsTab, TabsPanel, DataTabs, TabItem",
         description: "Tabbed interface for organizing content into sections.",
         props: vec![
             PropDoc {
@@ -1276,22 +1665,65 @@ fn tabs_doc() -> ComponentDoc {
                 required: false,
             },
             PropDoc {
-                name: "children",
-                prop_type: "Children",
-                default: None,
-                description: "TabsList and TabsPanel components",
-                required: true,
+                name: "lazy",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Mount a panel's content the first time its tab is activated",
+                required: false,
             },
-        ],
-        demo: || {
-            let active = RwSignal::new("first".to_string());
-            view! {
-                <DemoBlock title="Tabs" code=r#"let active = RwSignal::new("first".to_string());
-<Tabs active=active>
-    <TabsList>
-        <TabsTab value="first">"First"</TabsTab>
-        <TabsTab value="second">"Second"</TabsTab>
-    </TabsList>
+            PropDoc {
+                name: "keep_mounted",
+                prop_type: "Option<bool>",
+                default: Some("true"),
+                description:
+                    "Keep activated panels hidden in the DOM; false unmounts them when inactive",
+                required: false,
+            },
+            PropDoc {
+                name: "closeable",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "TabsTab: show a × button; Delete also closes the focused tab",
+                required: false,
+            },
+            PropDoc {
+                name: "on_close",
+                prop_type: "Option<Callback<String>>",
+                default: None,
+                description: "TabsTab: called with the tab value after a neighbour is activated",
+                required: false,
+            },
+            PropDoc {
+                name: "children",
+                prop_type: "Children",
+                default: None,
+                description: "TabsList and TabsPanel components",
+                required: true,
+            },
+            PropDoc {
+                name: "items",
+                prop_type: "Vec<TabItem>",
+                default: None,
+                description: "DataTabs: tabs built from data instead of children",
+                required: true,
+            },
+        ],
+        demo: || {
+            let active = RwSignal::new("first".to_string());
+            let items = (1..=10)
+                .map(|i| {
+                    TabItem::new(format!("tab-{}", i), format!("Tab {}", i), move || {
+                        view! { <Text>{format!("Generated content for tab {}", i)}</Text> }
+                    })
+                })
+                .collect::<Vec<_>>();
+            view! {
+                <DemoBlock title="Tabs" code=r#"let active = RwSignal::new("first".to_string());
+<Tabs active=active>
+    <TabsList>
+        <TabsTab value="first">"First"</TabsTab>
+        <TabsTab value="second">"Second"</TabsTab>
+    </TabsList>
     <TabsPanel value="first">"First content"</TabsPanel>
     <TabsPanel value="second">"Second content"</TabsPanel>
 </Tabs>"#>
@@ -1312,6 +1744,19 @@ fn tabs_doc() -> ComponentDoc {
                         </TabsPanel>
                     </Tabs>
                 </DemoBlock>
+                <DemoBlock title="Data-driven" code=r#"let items = (1..=10)
+    .map(|i| {
+        TabItem::new(format!("tab-{}", i), format!("Tab {}", i), move || {
+            view! { <Text>{format!("Generated content for tab {}", i)}</Text> }
+        })
+    })
+    .collect::<Vec<_>>();
+
+<DataTabs items=items variant=TabsVariant::Pills />"#>
+                    <div style="overflow-x: auto; width: 100%;">
+                        <DataTabs items=items variant=TabsVariant::Pills />
+                    </div>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -1342,6 +1787,21 @@ fn checkbox_doc() -> ComponentDoc {
                 description: "Label text",
                 required: false,
             },
+            PropDoc {
+                name: "name",
+                prop_type: "Option<String>",
+                default: None,
+                description:
+                    "Form field name; the checkbox is then included in native form submission",
+                required: false,
+            },
+            PropDoc {
+                name: "value",
+                prop_type: "Option<String>",
+                default: Some("\"on\""),
+                description: "Value submitted when checked",
+                required: false,
+            },
             PropDoc {
                 name: "on_change",
                 prop_type: "Option<Callback<bool>>",
@@ -1356,6 +1816,20 @@ fn checkbox_doc() -> ComponentDoc {
                 description: "Whether the checkbox is disabled",
                 required: false,
             },
+            PropDoc {
+                name: "indeterminate",
+                prop_type: "Signal<bool>",
+                default: Some("false"),
+                description: "Show a dash for a partially selected group (aria-checked=\"mixed\")",
+                required: false,
+            },
+            PropDoc {
+                name: "tristate",
+                prop_type: "Option<TriStateGroup>",
+                default: None,
+                description: "Make this the select-all parent of a use_tristate(children) group",
+                required: false,
+            },
         ],
         demo: || {
             let checked = RwSignal::new(false);
@@ -1488,14 +1962,14 @@ fn radio_doc() -> ComponentDoc {
 fn select_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Select",
-        import_name: "Select, SelectOption",
+        import_name: "Select, SelectOption, SelectOptionGroup, default_option_filter",
         description: "A dropdown select input component.",
         props: vec![
             PropDoc {
                 name: "options",
-                prop_type: "Vec<SelectOption>",
+                prop_type: "SelectData",
                 default: None,
-                description: "Vector of select options",
+                description: "Flat Vec<SelectOption> or grouped Vec<SelectOptionGroup>; group headers are not selectable",
                 required: true,
             },
             PropDoc {
@@ -1526,11 +2000,77 @@ fn select_doc() -> ComponentDoc {
                 description: "Change handler",
                 required: false,
             },
+            PropDoc {
+                name: "virtualized",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Render a custom dropdown that only mounts the visible options (for thousands of options); supports keyboard navigation",
+                required: false,
+            },
+            PropDoc {
+                name: "option_height",
+                prop_type: "Option<f64>",
+                default: Some("34.0"),
+                description: "Option row height in pixels when virtualized",
+                required: false,
+            },
+            PropDoc {
+                name: "max_dropdown_height",
+                prop_type: "Option<f64>",
+                default: Some("250.0"),
+                description: "Maximum dropdown height in pixels when virtualized",
+                required: false,
+            },
+            PropDoc {
+                name: "searchable",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a filter input that narrows options by case- and accent-insensitive label match",
+                required: false,
+            },
+            PropDoc {
+                name: "filter_fn",
+                prop_type: "Option<Callback<(SelectOption, String), bool>>",
+                default: None,
+                description: "Custom matcher for searchable mode (defaults to default_option_filter)",
+                required: false,
+            },
+            PropDoc {
+                name: "nothing_found",
+                prop_type: "Option<String>",
+                default: Some("\"No results\""),
+                description: "Row shown when the filter matches nothing",
+                required: false,
+            },
         ],
         demo: || {
             let value = RwSignal::new(String::new());
+            let country = RwSignal::new(String::new());
+            let countries = [
+                ("at", "Austria"),
+                ("be", "Belgium"),
+                ("br", "Brazil"),
+                ("ca", "Canada"),
+                ("ci", "Côte d'Ivoire"),
+                ("de", "Germany"),
+                ("ec", "Ecuador"),
+                ("es", "España"),
+                ("fr", "France"),
+                ("is", "Ísland"),
+                ("jp", "Japan"),
+                ("mx", "México"),
+                ("no", "Norway"),
+                ("pe", "Perú"),
+                ("se", "Sweden"),
+                ("tr", "Türkiye"),
+                ("us", "United States"),
+            ]
+            .into_iter()
+            .map(|(value, label)| SelectOption::new(value, label))
+            .collect::<Vec<_>>();
             view! {
-                <DemoBlock title="Select" code=r#"<Select
+                <Stack spacing="xl">
+                    <DemoBlock title="Select" code=r#"<Select
     label="Choose a framework"
     placeholder="Select one"
     options=vec![
@@ -1539,20 +2079,71 @@ fn select_doc() -> ComponentDoc {
         SelectOption::new("dioxus", "Dioxus"),
     ]
 />"#>
-                    <div style="max-width: 300px;">
-                        <Select
-                            label="Choose a framework"
-                            placeholder="Select one"
-                            value=value
-                            options=vec![
-                                SelectOption::new("leptos", "Leptos"),
-                                SelectOption::new("yew", "Yew"),
-                                SelectOption::new("dioxus", "Dioxus"),
-                            ]
-                            on_change=Callback::new(move |v| value.set(v))
-                        />
-                    </div>
-                </DemoBlock>
+                        <div style="max-width: 300px;">
+                            <Select
+                                label="Choose a framework"
+                                placeholder="Select one"
+                                value=value
+                                options=vec![
+                                    SelectOption::new("leptos", "Leptos"),
+                                    SelectOption::new("yew", "Yew"),
+                                    SelectOption::new("dioxus", "Dioxus"),
+                                ]
+                                on_change=Callback::new(move |v| value.set(v))
+                            />
+                        </div>
+                    </DemoBlock>
+
+                    <DemoBlock title="Searchable" code=r#"// Typing "peru" or "COTE" matches "Perú" and "Côte d'Ivoire"
+<Select
+    label="Country"
+    placeholder="Pick a country"
+    searchable=true
+    options=countries
+/>"#>
+                        <div style="max-width: 300px;">
+                            <Select
+                                label="Country"
+                                placeholder="Pick a country"
+                                searchable=true
+                                value=country
+                                options=countries
+                            />
+                        </div>
+                    </DemoBlock>
+
+                    <DemoBlock title="Grouped" code=r#"<Select
+    placeholder="Pick produce"
+    searchable=true
+    options=vec![
+        SelectOptionGroup::new("Fruits", vec![
+            SelectOption::new("apple", "Apple"),
+            SelectOption::new("banana", "Banana"),
+        ]),
+        SelectOptionGroup::new("Vegetables", vec![
+            SelectOption::new("carrot", "Carrot"),
+            SelectOption::new("leek", "Leek"),
+        ]),
+    ]
+/>"#>
+                        <div style="max-width: 300px;">
+                            <Select
+                                placeholder="Pick produce"
+                                searchable=true
+                                options=vec![
+                                    SelectOptionGroup::new("Fruits", vec![
+                                        SelectOption::new("apple", "Apple"),
+                                        SelectOption::new("banana", "Banana"),
+                                    ]),
+                                    SelectOptionGroup::new("Vegetables", vec![
+                                        SelectOption::new("carrot", "Carrot"),
+                                        SelectOption::new("leek", "Leek"),
+                                    ]),
+                                ]
+                            />
+                        </div>
+                    </DemoBlock>
+                </Stack>
             }
             .into_any()
         },
@@ -1579,6 +2170,21 @@ fn switch_doc() -> ComponentDoc {
                 description: "Label text",
                 required: false,
             },
+            PropDoc {
+                name: "name",
+                prop_type: "Option<String>",
+                default: None,
+                description:
+                    "Form field name; the switch is then included in native form submission",
+                required: false,
+            },
+            PropDoc {
+                name: "value",
+                prop_type: "Option<String>",
+                default: Some("\"on\""),
+                description: "Value submitted when checked",
+                required: false,
+            },
             PropDoc {
                 name: "on_change",
                 prop_type: "Option<Callback<bool>>",
@@ -1613,9 +2219,16 @@ fn switch_doc() -> ComponentDoc {
 fn textarea_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Textarea",
-        import_name: "Textarea",
+        import_name: "Textarea, TextareaResize",
         description: "A multi-line text input component.",
         props: vec![
+            PropDoc {
+                name: "id",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Id of the control, e.g. for an external <label for>; also prefixes the description and error ids. Generated in render order under MingotProvider when not set",
+                required: false,
+            },
             PropDoc {
                 name: "label",
                 prop_type: "Option<String>",
@@ -1644,13 +2257,82 @@ fn textarea_doc() -> ComponentDoc {
                 description: "Input handler",
                 required: false,
             },
+            PropDoc {
+                name: "error",
+                prop_type: "Option<Signal<String>>",
+                default: None,
+                description: "Error message; when non-empty the border turns red, the message renders below and the control gets aria-invalid/aria-describedby",
+                required: false,
+            },
+            PropDoc {
+                name: "max_length",
+                prop_type: "Option<usize>",
+                default: None,
+                description: "Maximum length in grapheme clusters; typed or pasted text that doesn't fit is cut without touching the rest of the value, and a current/max counter is shown",
+                required: false,
+            },
+            PropDoc {
+                name: "maxlength",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Deprecated: read as max_length when that is not set. Will be removed in the next release",
+                required: false,
+            },
+            PropDoc {
+                name: "show_count",
+                prop_type: "Option<bool>",
+                default: Some("true"),
+                description: "Show the current/max counter when max_length is set",
+                required: false,
+            },
+            PropDoc {
+                name: "autosize",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Grow the height to fit the content as it changes",
+                required: false,
+            },
+            PropDoc {
+                name: "min_rows",
+                prop_type: "Option<u32>",
+                default: Some("rows"),
+                description: "Fewest rows shown with autosize",
+                required: false,
+            },
+            PropDoc {
+                name: "max_rows",
+                prop_type: "Option<u32>",
+                default: None,
+                description: "Most rows shown with autosize; longer content scrolls",
+                required: false,
+            },
+            PropDoc {
+                name: "resize",
+                prop_type: "Option<TextareaResize>",
+                default: Some("Vertical"),
+                description: "Resize handle: None, Vertical, Horizontal, Both (None with autosize). Resizing by hand stops autosize",
+                required: false,
+            },
         ],
         demo: || {
             view! {
                 <DemoBlock title="Textarea">
-                    <div style="max-width: 400px;">
-                        <Textarea label="Message" placeholder="Enter your message..." rows=4 />
-                    </div>
+                    <Stack spacing="lg">
+                        <div style="max-width: 400px;">
+                            <Textarea label="Message" placeholder="Enter your message..." rows=4 />
+                        </div>
+                        <div style="max-width: 400px;">
+                            <Textarea
+                                label="Autosize"
+                                description="Grows from 2 to 6 rows, then scrolls"
+                                placeholder="Type several lines..."
+                                autosize=true
+                                min_rows=2
+                                max_rows=6
+                                max_length=280
+                            />
+                        </div>
+                    </Stack>
                 </DemoBlock>
             }
             .into_any()
@@ -1806,6 +2488,13 @@ fn parameter_grid_doc() -> ComponentDoc {
                 description: "Callback when any parameter changes",
                 required: false,
             },
+            PropDoc {
+                name: "on_formula_error",
+                prop_type: "Option<Callback<ParameterFormulaError>>",
+                default: None,
+                description: "Callback when a computed parameter's formula fails or forms a cycle",
+                required: false,
+            },
         ],
         demo: || {
             let params = RwSignal::new(vec![
@@ -1825,6 +2514,10 @@ fn parameter_grid_doc() -> ComponentDoc {
                     .step("0.01")
                     .default("0")
                     .display_precision(2),
+                ParameterDef::new("period", "Period")
+                    .range("0.01", "10")
+                    .formula("1 / frequency")
+                    .display_precision(3),
             ]);
 
             view! {
@@ -1976,8 +2669,9 @@ fn drawer_doc() -> ComponentDoc {
             PropDoc {
                 name: "position",
                 prop_type: "Option<DrawerPosition>",
-                default: Some("Left"),
-                description: "Position: Left, Right, Top, Bottom",
+                default: Some("End"),
+                description:
+                    "Position: Left, Right, Top, Bottom, or Start/End to follow the text direction",
                 required: false,
             },
             PropDoc {
@@ -2088,7 +2782,7 @@ fn modal_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Modal",
         import_name: "Modal",
-        description: "A dialog overlay that appears above the page content.",
+        description: "A dialog overlay that appears above the page content. Traps focus, locks page scroll and returns focus to the trigger on close.",
         props: vec![
             PropDoc {
                 name: "opened",
@@ -2118,6 +2812,20 @@ fn modal_doc() -> ComponentDoc {
                 description: "Called when modal should close",
                 required: false,
             },
+            PropDoc {
+                name: "close_on_escape",
+                prop_type: "Option<bool>",
+                default: Some("true"),
+                description: "Close on Escape; only the topmost of stacked modals reacts",
+                required: false,
+            },
+            PropDoc {
+                name: "close_on_click_outside",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Close when the overlay is clicked",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -2171,6 +2879,20 @@ fn popover_doc() -> ComponentDoc {
                 description: "Position relative to target",
                 required: false,
             },
+            PropDoc {
+                name: "flip",
+                prop_type: "Option<bool>",
+                default: Some("true"),
+                description: "Move to another side when the position would overflow the viewport",
+                required: false,
+            },
+            PropDoc {
+                name: "fallback_positions",
+                prop_type: "Option<Vec<PopoverPosition>>",
+                default: None,
+                description: "Sides to try in order when flipping; defaults to the opposite side",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -2203,7 +2925,7 @@ fn tooltip_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Tooltip",
         import_name: "Tooltip",
-        description: "A small popup that appears on hover to provide additional information.",
+        description: "A small popup that appears on hover or keyboard focus to provide additional information.",
         props: vec![
             PropDoc {
                 name: "label",
@@ -2219,6 +2941,27 @@ fn tooltip_doc() -> ComponentDoc {
                 description: "Position: Top, Bottom, Left, Right",
                 required: false,
             },
+            PropDoc {
+                name: "with_arrow",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Draw a triangle pointing at the target",
+                required: false,
+            },
+            PropDoc {
+                name: "open_delay",
+                prop_type: "u64",
+                default: Some("0"),
+                description: "Milliseconds before showing; leaving earlier cancels it",
+                required: false,
+            },
+            PropDoc {
+                name: "close_delay",
+                prop_type: "u64",
+                default: Some("0"),
+                description: "Milliseconds before hiding",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -2234,6 +2977,9 @@ fn tooltip_doc() -> ComponentDoc {
                         <Tooltip label="This is a tooltip">
                             <Button>"Hover me"</Button>
                         </Tooltip>
+                        <Tooltip label="Delayed, with arrow" with_arrow=true open_delay=400 close_delay=150>
+                            <Button>"Hover or focus me"</Button>
+                        </Tooltip>
                     </Group>
                 </DemoBlock>
             }
@@ -2373,7 +3119,7 @@ fn loader_doc() -> ComponentDoc {
 fn notification_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Notification",
-        import_name: "NotificationProvider, NotificationData, NotificationColor, use_notifications, show_notification",
+        import_name: "NotificationProvider, NotificationData, NotificationColor, NotificationPriority, NotificationAutoClose, Notifications, use_notifications, show_notification",
         description: "Toast notifications system with provider pattern.",
         props: vec![
             PropDoc {
@@ -2390,6 +3136,41 @@ fn notification_doc() -> ComponentDoc {
                 description: "Maximum notifications shown at once",
                 required: false,
             },
+            PropDoc {
+                name: "sound",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Beep (WebAudio) when a High priority notification is shown",
+                required: false,
+            },
+            PropDoc {
+                name: "vibrate",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Vibrate on High priority, unless the user prefers reduced motion",
+                required: false,
+            },
+            PropDoc {
+                name: "muted",
+                prop_type: "Signal<bool>",
+                default: Some("false"),
+                description: "Global mute for sound and vibration",
+                required: false,
+            },
+            PropDoc {
+                name: "auto_close",
+                prop_type: "Option<NotificationAutoClose>",
+                default: Some("5000"),
+                description: "Default auto-close in ms, or false to keep notifications until closed; hovering pauses the countdown",
+                required: false,
+            },
+            PropDoc {
+                name: "show_countdown",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Shrinking bar along the bottom edge showing the time left",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -2399,8 +3180,11 @@ fn notification_doc() -> ComponentDoc {
 </NotificationProvider>
 
 // In components, use the hook
-let show = use_notifications();
-show(show_notification("Message", NotificationColor::Success, Some("Title".into())));"#>
+let notifications = use_notifications();
+let id = notifications.show(show_notification("Uploading…", NotificationColor::Info, None));
+// Change it in place, or remove it
+notifications.update_notification(id, show_notification("Done", NotificationColor::Success, None));
+notifications.hide_notification(id);"#>
                     <Stack spacing="md">
                         <Text size=TextSize::Sm color="dimmed">
                             "Notifications use a provider pattern. Wrap your app with NotificationProvider, then use the use_notifications() hook."
@@ -2425,15 +3209,23 @@ show(show_notification("Message", NotificationColor::Success, Some("Title".into(
 fn progress_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Progress",
-        import_name: "Progress, ProgressSize",
+        import_name: "Progress, ProgressSize, ProgressLabelPosition",
         description: "A progress bar component.",
         props: vec![
             PropDoc {
                 name: "value",
                 prop_type: "Signal<f32>",
-                default: None,
-                description: "Current progress value (0-100)",
-                required: true,
+                default: Some("0.0"),
+                description: "Current progress value (0-100); ignored while indeterminate",
+                required: false,
+            },
+            PropDoc {
+                name: "indeterminate",
+                prop_type: "Signal<bool>",
+                default: Some("false"),
+                description:
+                    "Sweeping bar for tasks of unknown length; no aria-valuenow, aria-busy set",
+                required: false,
             },
             PropDoc {
                 name: "color",
@@ -2463,8 +3255,32 @@ fn progress_doc() -> ComponentDoc {
                 description: "Whether to animate stripes",
                 required: false,
             },
+            PropDoc {
+                name: "label",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Text shown with the bar",
+                required: false,
+            },
+            PropDoc {
+                name: "show_value",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show the rounded percentage",
+                required: false,
+            },
+            PropDoc {
+                name: "label_position",
+                prop_type: "ProgressLabelPosition",
+                default: Some("Inside"),
+                description:
+                    "Inside: centered over the bar with contrast-aware color; Above: in a row above",
+                required: false,
+            },
         ],
         demo: || {
+            let uploaded = RwSignal::new(35.0_f32);
+            let waiting = RwSignal::new(true);
             view! {
                 <DemoBlock title="Progress Bar" code=r#"<Progress value=Signal::derive(|| 50.0) />
 <Progress value=Signal::derive(|| 75.0) striped=true animate=true />"#>
@@ -2475,7 +3291,60 @@ fn progress_doc() -> ComponentDoc {
                         <Progress value=Signal::derive(|| 100.0) color="red" striped=true animate=true />
                     </Stack>
                 </DemoBlock>
-            }
+                <DemoBlock title="Upload with percentage" code=r#"let uploaded = RwSignal::new(35.0_f32);
+
+<Progress value=uploaded label="report.pdf" show_value=true
+    label_position=ProgressLabelPosition::Above />
+<Progress value=uploaded show_value=true size=ProgressSize::Xl striped=true animate=true />"#>
+                    <Stack spacing="md" style="width: 100%;">
+                        <Progress
+                            value=uploaded
+                            label="report.pdf"
+                            show_value=true
+                            label_position=ProgressLabelPosition::Above
+                        />
+                        <Progress
+                            value=uploaded
+                            show_value=true
+                            size=ProgressSize::Xl
+                            striped=true
+                            animate=true
+                        />
+                        <Group>
+                            <Button
+                                size=ButtonSize::Xs
+                                on_click=Callback::new(move |_| uploaded.update(|v| *v = (*v + 10.0).min(100.0)))
+                            >
+                                "+10%"
+                            </Button>
+                            <Button
+                                size=ButtonSize::Xs
+                                variant=ButtonVariant::Default
+                                on_click=Callback::new(move |_| uploaded.set(0.0))
+                            >
+                                "Restart"
+                            </Button>
+                        </Group>
+                    </Stack>
+                </DemoBlock>
+                <DemoBlock title="Indeterminate" code=r#"let waiting = RwSignal::new(true);
+
+<Progress indeterminate=waiting value=Signal::derive(|| 100.0) color="teal" />"#>
+                    <Stack spacing="md" style="width: 100%;">
+                        <Progress indeterminate=waiting value=Signal::derive(|| 100.0) color="teal" />
+                        <Progress indeterminate=true size=ProgressSize::Lg striped=true animate=true />
+                        <Group>
+                            <Button
+                                size=ButtonSize::Xs
+                                variant=ButtonVariant::Default
+                                on_click=Callback::new(move |_| waiting.update(|w| *w = !*w))
+                            >
+                                "Toggle"
+                            </Button>
+                        </Group>
+                    </Stack>
+                </DemoBlock>
+            }
             .into_any()
         },
     }
@@ -2543,7 +3412,7 @@ fn skeleton_doc() -> ComponentDoc {
 fn accordion_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Accordion",
-        import_name: "Accordion, AccordionItem, AccordionVariant",
+        import_name: "Accordion, AccordionItem, AccordionVariant, DataAccordion, AccordionItemData",
         description: "A collapsible content panel component.",
         props: vec![
             PropDoc {
@@ -2560,6 +3429,20 @@ fn accordion_doc() -> ComponentDoc {
                 description: "Allow multiple panels to be open",
                 required: false,
             },
+            PropDoc {
+                name: "value",
+                prop_type: "Option<RwSignal<Vec<String>>>",
+                default: None,
+                description: "Values of the open items; internal state when omitted",
+                required: false,
+            },
+            PropDoc {
+                name: "on_change",
+                prop_type: "Option<Callback<Vec<String>>>",
+                default: None,
+                description: "Called with the open values after a toggle",
+                required: false,
+            },
             PropDoc {
                 name: "children",
                 prop_type: "Children",
@@ -2567,26 +3450,122 @@ fn accordion_doc() -> ComponentDoc {
                 description: "AccordionItem components",
                 required: true,
             },
+            PropDoc {
+                name: "header",
+                prop_type: "Option<Children>",
+                default: None,
+                description:
+                    "AccordionItem: custom header content shown instead of the string label",
+                required: false,
+            },
+            PropDoc {
+                name: "controls",
+                prop_type: "Option<Children>",
+                default: None,
+                description:
+                    "AccordionItem: controls right of the chevron; clicking them does not toggle",
+                required: false,
+            },
+            PropDoc {
+                name: "items",
+                prop_type: "Vec<AccordionItemData>",
+                default: None,
+                description:
+                    "DataAccordion: items built from data; one open at a time unless multiple",
+                required: true,
+            },
         ],
         demo: || {
+            let faq = [
+                (
+                    "install",
+                    "How do I install Mingot?",
+                    "Add mingot to your Cargo.toml.",
+                ),
+                (
+                    "theme",
+                    "Can I change the theme?",
+                    "Pass a Theme to MingotProvider.",
+                ),
+                ("ssr", "Does it support SSR?", "Yes, through Leptos."),
+            ]
+            .into_iter()
+            .map(|(value, question, answer)| {
+                AccordionItemData::new(value, question, move || view! { <Text>{answer}</Text> })
+            })
+            .collect::<Vec<_>>();
+            let open = RwSignal::new(vec!["first".to_string()]);
             view! {
                 <DemoBlock title="Accordion" code=r#"<Accordion>
-    <AccordionItem _value="first" label="First Section">
+    <AccordionItem value="first" label="First Section">
         <Text>"Content here"</Text>
     </AccordionItem>
 </Accordion>"#>
                     <Accordion>
-                        <AccordionItem _value="first" label="First Section">
+                        <AccordionItem value="first" label="First Section">
                             <Text>"Content of the first section."</Text>
                         </AccordionItem>
-                        <AccordionItem _value="second" label="Second Section">
+                        <AccordionItem value="second" label="Second Section">
                             <Text>"Content of the second section."</Text>
                         </AccordionItem>
-                        <AccordionItem _value="third" label="Third Section">
+                        <AccordionItem value="third" label="Third Section">
                             <Text>"Content of the third section."</Text>
                         </AccordionItem>
                     </Accordion>
                 </DemoBlock>
+                <DemoBlock title="Custom Header" code=r#"<AccordionItem
+    value="inbox"
+    header=Box::new(|| view! { "Inbox " <Badge>"12"</Badge> }.into_any())
+    controls=Box::new(|| view! {
+        <Button size=ButtonSize::Xs variant=ButtonVariant::Subtle>"Mark read"</Button>
+    }.into_any())
+>
+    <Text>"Messages"</Text>
+</AccordionItem>"#>
+                    <Accordion variant=AccordionVariant::Contained>
+                        <AccordionItem
+                            value="inbox"
+                            header=Box::new(|| view! { "Inbox " <Badge>"12"</Badge> }.into_any())
+                            controls=Box::new(|| view! {
+                                <Button size=ButtonSize::Xs variant=ButtonVariant::Subtle>"Mark read"</Button>
+                            }.into_any())
+                        >
+                            <Text>"Clicking the button does not toggle the panel."</Text>
+                        </AccordionItem>
+                    </Accordion>
+                </DemoBlock>
+                <DemoBlock title="Controlled" code=r#"let open = RwSignal::new(vec!["first".to_string()]);
+
+<Button on_click=Callback::new(move |_| open.set(vec!["second".to_string()]))>
+    "Open second"
+</Button>
+<Accordion value=open>
+    <AccordionItem value="first" label="First">...</AccordionItem>
+    <AccordionItem value="second" label="Second">...</AccordionItem>
+</Accordion>"#>
+                    <Button on_click=Callback::new(move |_| open.set(vec!["second".to_string()]))>
+                        "Open second"
+                    </Button>
+                    <Text size=TextSize::Sm>{move || format!("Open: {:?}", open.get())}</Text>
+                    <Accordion value=open>
+                        <AccordionItem value="first" label="First">
+                            <Text>"First panel"</Text>
+                        </AccordionItem>
+                        <AccordionItem value="second" label="Second">
+                            <Text>"Second panel"</Text>
+                        </AccordionItem>
+                    </Accordion>
+                </DemoBlock>
+                <DemoBlock title="Data-driven" code=r#"let faq = questions
+    .into_iter()
+    .map(|(value, question, answer)| {
+        AccordionItemData::new(value, question, move || view! { <Text>{answer}</Text> })
+    })
+    .collect::<Vec<_>>();
+
+<DataAccordion items=faq variant=AccordionVariant::Separated />"#>
+                    <DataAccordion items=faq variant=AccordionVariant::Separated />
+                </DemoBlock>
             }
             .into_any()
         },
@@ -2601,9 +3580,10 @@ fn avatar_doc() -> ComponentDoc {
         props: vec![
             PropDoc {
                 name: "src",
-                prop_type: "Option<String>",
+                prop_type: "Signal<Option<String>>",
                 default: None,
-                description: "Image URL",
+                description:
+                    "Image URL; falls back to initials (or a user glyph) if it fails to load",
                 required: false,
             },
             PropDoc {
@@ -2653,6 +3633,13 @@ fn avatar_doc() -> ComponentDoc {
                         <Avatar size=AvatarSize::Lg initials="EF" color="orange" />
                     </Group>
                 </DemoBlock>
+                <DemoBlock title="Broken image fallback" code=r#"<Avatar src="/missing.png" initials="GH" color="grape" />
+<Avatar src="/missing.png" />"#>
+                    <Group spacing="md">
+                        <Avatar src="/missing.png" initials="GH" color="grape" />
+                        <Avatar src="/missing.png" />
+                    </Group>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -2686,15 +3673,44 @@ fn badge_doc() -> ComponentDoc {
                 description: "Size: xs, sm, md, lg, xl",
                 required: false,
             },
+            PropDoc {
+                name: "count",
+                prop_type: "Option<Signal<u64>>",
+                default: None,
+                description: "Numeric mode: shows the count instead of children",
+                required: false,
+            },
+            PropDoc {
+                name: "max_count",
+                prop_type: "Option<u64>",
+                default: Some("99"),
+                description: "Counts above this are shown as e.g. 99+",
+                required: false,
+            },
+            PropDoc {
+                name: "show_zero",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Keep a count badge visible at zero",
+                required: false,
+            },
+            PropDoc {
+                name: "dot",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Render only a small dot",
+                required: false,
+            },
             PropDoc {
                 name: "children",
-                prop_type: "Children",
+                prop_type: "Option<Children>",
                 default: None,
                 description: "Badge content",
-                required: true,
+                required: false,
             },
         ],
         demo: || {
+            let unread = RwSignal::new(98_u64);
             view! {
                 <DemoBlock title="Badge Variants">
                     <Group spacing="md">
@@ -2712,6 +3728,98 @@ fn badge_doc() -> ComponentDoc {
                         <Badge color="orange">"Orange"</Badge>
                     </Group>
                 </DemoBlock>
+                <DemoBlock title="Counts" code=r#"let unread = RwSignal::new(98_u64);
+
+<Badge count=unread color="red" />
+<Badge count=Signal::derive(|| 0) show_zero=true color="gray" />
+<Badge dot=true color="green" />"#>
+                    <Group spacing="md">
+                        <Badge count=unread color="red" />
+                        <Badge count=Signal::derive(|| 0) show_zero=true color="gray" />
+                        <Badge count=Signal::derive(|| 0) />
+                        <Badge dot=true color="green" />
+                        <Button
+                            size=ButtonSize::Xs
+                            variant=ButtonVariant::Default
+                            on_click=Callback::new(move |_| unread.update(|n| *n += 1))
+                        >
+                            "+1"
+                        </Button>
+                    </Group>
+                </DemoBlock>
+            }
+            .into_any()
+        },
+    }
+}
+
+fn indicator_doc() -> ComponentDoc {
+    ComponentDoc {
+        name: "Indicator",
+        import_name: "Indicator, IndicatorPosition",
+        description: "Overlays a dot or notification count on a corner of its child.",
+        props: vec![
+            PropDoc {
+                name: "count",
+                prop_type: "Option<Signal<u64>>",
+                default: None,
+                description: "Count to display; renders a plain dot when omitted",
+                required: false,
+            },
+            PropDoc {
+                name: "max_count",
+                prop_type: "Option<u64>",
+                default: Some("99"),
+                description: "Counts above this are shown as e.g. 99+",
+                required: false,
+            },
+            PropDoc {
+                name: "position",
+                prop_type: "Option<IndicatorPosition>",
+                default: Some("TopEnd"),
+                description: "Corner: TopStart, TopEnd, BottomStart, BottomEnd",
+                required: false,
+            },
+            PropDoc {
+                name: "color",
+                prop_type: "Option<String>",
+                default: Some("red"),
+                description: "Theme color name",
+                required: false,
+            },
+            PropDoc {
+                name: "processing",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a pulsing animation",
+                required: false,
+            },
+            PropDoc {
+                name: "disabled",
+                prop_type: "Signal<bool>",
+                default: Some("false"),
+                description: "Hide the indicator",
+                required: false,
+            },
+        ],
+        demo: || {
+            view! {
+                <DemoBlock title="Indicator">
+                    <Group spacing="xl">
+                        <Indicator>
+                            <Avatar initials="AL" />
+                        </Indicator>
+                        <Indicator count=Signal::stored(7u64)>
+                            <Avatar initials="AT" />
+                        </Indicator>
+                        <Indicator count=Signal::stored(250u64) color="blue">
+                            <Avatar initials="EN" />
+                        </Indicator>
+                        <Indicator processing=true position=IndicatorPosition::BottomEnd color="green">
+                            <Avatar initials="KG" />
+                        </Indicator>
+                    </Group>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -2721,16 +3829,30 @@ fn badge_doc() -> ComponentDoc {
 fn ring_progress_doc() -> ComponentDoc {
     ComponentDoc {
         name: "RingProgress",
-        import_name: "RingProgress, RingProgressSection",
+        import_name: "RingProgress, RingProgressSection, RingProgressTransition",
         description: "A circular progress indicator with support for multiple sections.",
         props: vec![
             PropDoc {
                 name: "sections",
-                prop_type: "Vec<RingProgressSection>",
+                prop_type: "Signal<Vec<RingProgressSection>>",
                 default: None,
                 description: "Progress sections with value and color",
                 required: true,
             },
+            PropDoc {
+                name: "transition",
+                prop_type: "Option<RingProgressTransition>",
+                default: None,
+                description: "Animate arcs when section values change (duration and easing)",
+                required: false,
+            },
+            PropDoc {
+                name: "reduce_motion",
+                prop_type: "Option<bool>",
+                default: None,
+                description: "Skip the transition; follows prefers-reduced-motion when unset",
+                required: false,
+            },
             PropDoc {
                 name: "size",
                 prop_type: "Option<RingProgressSize>",
@@ -2752,8 +3874,31 @@ fn ring_progress_doc() -> ComponentDoc {
                 description: "Content displayed in the center",
                 required: false,
             },
+            PropDoc {
+                name: "show_percentage",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show the summed section value as a percentage in the center",
+                required: false,
+            },
+            PropDoc {
+                name: "precision",
+                prop_type: "Option<usize>",
+                default: Some("0"),
+                description: "Decimal places for the percentage label",
+                required: false,
+            },
+            PropDoc {
+                name: "value_label_format",
+                prop_type: "Option<Callback<f64, String>>",
+                default: None,
+                description:
+                    "Custom formatter for the summed value (custom label takes precedence)",
+                required: false,
+            },
         ],
         demo: || {
+            let used = RwSignal::new(30.0_f32);
             view! {
                 <DemoBlock title="Ring Progress">
                     <Group spacing="xl">
@@ -2768,9 +3913,41 @@ fn ring_progress_doc() -> ComponentDoc {
                                 RingProgressSection::new(25.0, "green"),
                                 RingProgressSection::new(20.0, "orange"),
                             ]
+                            show_percentage=true
                         />
                     </Group>
                 </DemoBlock>
+                <DemoBlock title="Animated" code=r#"let used = RwSignal::new(30.0_f32);
+
+<RingProgress
+    sections=Signal::derive(move || vec![
+        RingProgressSection::new(used.get(), "blue"),
+        RingProgressSection::new(20.0, "teal"),
+    ])
+    transition=RingProgressTransition::new(400).easing("ease-out")
+    show_percentage=true
+/>"#>
+                    <Group spacing="xl">
+                        <RingProgress
+                            sections=Signal::derive(move || vec![
+                                RingProgressSection::new(used.get(), "blue"),
+                                RingProgressSection::new(20.0, "teal"),
+                            ])
+                            transition=RingProgressTransition::new(400).easing("ease-out")
+                            size=RingProgressSize::Lg
+                            show_percentage=true
+                        />
+                        <Button
+                            size=ButtonSize::Xs
+                            variant=ButtonVariant::Default
+                            on_click=Callback::new(move |_| {
+                                used.update(|v| *v = if *v >= 70.0 { 10.0 } else { *v + 20.0 })
+                            })
+                        >
+                            "Change"
+                        </Button>
+                    </Group>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -2818,6 +3995,29 @@ fn stats_doc() -> ComponentDoc {
                 description: "Percentage difference (positive=green, negative=red)",
                 required: false,
             },
+            PropDoc {
+                name: "format",
+                prop_type: "Option<NumberInputFormat>",
+                default: None,
+                description:
+                    "Format a numeric value like NumberInput (Thousand when only locale is set)",
+                required: false,
+            },
+            PropDoc {
+                name: "locale",
+                prop_type: "Option<NumberInputLocale>",
+                default: None,
+                description:
+                    "Locale for a numeric value; defaults to the MingotConfig locale, then US",
+                required: false,
+            },
+            PropDoc {
+                name: "precision",
+                prop_type: "Option<u32>",
+                default: None,
+                description: "Decimal places for a numeric value",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -2840,8 +4040,8 @@ fn stats_doc() -> ComponentDoc {
 fn table_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Table",
-        import_name: "Table, TableColumn",
-        description: "A data table component with sorting and styling options.",
+        import_name: "Table, TableColumn, SortDirection",
+        description: "A data table component with sorting and styling options. Sortable columns sort locally with a per-column comparator (`sort_by`, `sort_by_key`, or numerically for `TableColumn::numeric`).",
         props: vec![
             PropDoc {
                 name: "columns",
@@ -2871,31 +4071,101 @@ fn table_doc() -> ComponentDoc {
                 description: "Highlight rows on hover",
                 required: false,
             },
+            PropDoc {
+                name: "virtualized",
+                prop_type: "bool",
+                default: Some("false"),
+                description:
+                    "Render only rows near the viewport inside a fixed-height scroll container",
+                required: false,
+            },
+            PropDoc {
+                name: "row_height",
+                prop_type: "Option<f64>",
+                default: Some("40"),
+                description: "Fixed row height in pixels used by virtualization",
+                required: false,
+            },
+            PropDoc {
+                name: "viewport_height",
+                prop_type: "Option<f64>",
+                default: Some("400"),
+                description: "Scroll container height in pixels when virtualized",
+                required: false,
+            },
+            PropDoc {
+                name: "overscan",
+                prop_type: "Option<usize>",
+                default: Some("5"),
+                description: "Extra rows rendered above and below the viewport",
+                required: false,
+            },
+            PropDoc {
+                name: "sort_column",
+                prop_type: "Option<RwSignal<Option<String>>>",
+                default: None,
+                description: "Key of the sorted column",
+                required: false,
+            },
+            PropDoc {
+                name: "sort_direction",
+                prop_type: "Option<RwSignal<SortDirection>>",
+                default: Some("None"),
+                description: "Sort direction; header clicks cycle ascending, descending, unsorted",
+                required: false,
+            },
+            PropDoc {
+                name: "on_sort",
+                prop_type: "Option<Callback<(String, SortDirection)>>",
+                default: None,
+                description: "Called on header clicks; when set, rows are not sorted locally (server-side sorting)",
+                required: false,
+            },
         ],
         demo: || {
+            #[derive(Clone)]
+            struct Member {
+                name: &'static str,
+                email: &'static str,
+                role: &'static str,
+                score: f64,
+            }
+
+            let members = vec![
+                Member { name: "John Doe", email: "john@example.com", role: "Admin", score: 9.5 },
+                Member { name: "Jane Smith", email: "jane@example.com", role: "User", score: 12.0 },
+                Member { name: "Ada Lovelace", email: "ada@example.com", role: "Editor", score: 100.25 },
+            ];
+            let columns = vec![
+                TableColumn::new("name", "Name", |m: &Member| m.name)
+                    .sortable(true)
+                    .sort_by_key(|m: &Member| m.name),
+                TableColumn::new("email", "Email", |m: &Member| m.email),
+                TableColumn::new("role", "Role", |m: &Member| m.role)
+                    .sortable(true)
+                    .sort_by_key(|m: &Member| m.role),
+                TableColumn::numeric(
+                    "score",
+                    "Score",
+                    |m: &Member| m.score,
+                    NumberInputFormat::Standard,
+                    NumberInputLocale::US,
+                    Some(2),
+                )
+                .sortable(true),
+            ];
+
             view! {
-                <DemoBlock title="Table">
-                    <table style="width: 100%; border-collapse: collapse;">
-                        <thead>
-                            <tr style="border-bottom: 1px solid #dee2e6;">
-                                <th style="padding: 0.75rem; text-align: left;">"Name"</th>
-                                <th style="padding: 0.75rem; text-align: left;">"Email"</th>
-                                <th style="padding: 0.75rem; text-align: left;">"Role"</th>
-                            </tr>
-                        </thead>
-                        <tbody>
-                            <tr style="border-bottom: 1px solid #dee2e6;">
-                                <td style="padding: 0.75rem;">"John Doe"</td>
-                                <td style="padding: 0.75rem;">"john@example.com"</td>
-                                <td style="padding: 0.75rem;">"Admin"</td>
-                            </tr>
-                            <tr style="border-bottom: 1px solid #dee2e6;">
-                                <td style="padding: 0.75rem;">"Jane Smith"</td>
-                                <td style="padding: 0.75rem;">"jane@example.com"</td>
-                                <td style="padding: 0.75rem;">"User"</td>
-                            </tr>
-                        </tbody>
-                    </table>
+                <DemoBlock title="Sortable Table" code=r#"TableColumn::new("name", "Name", |m: &Member| m.name)
+    .sortable(true)
+    .sort_by_key(|m: &Member| m.name)
+
+// Numeric columns sort by value, not by the formatted text
+TableColumn::numeric("score", "Score", |m: &Member| m.score, format, locale, Some(2))
+    .sortable(true)
+
+<Table columns=columns data=members striped=true />"#>
+                    <Table columns=columns data=Signal::stored(members) striped=true />
                 </DemoBlock>
             }
             .into_any()
@@ -2959,11 +4229,143 @@ fn error_page_doc() -> ComponentDoc {
 // New Form Components (v0.5.0)
 // ============================================================================
 
+fn date_picker_doc() -> ComponentDoc {
+    ComponentDoc {
+        name: "DatePicker",
+        import_name: "DatePicker, CalendarDate",
+        description: "Date entry with a keyboard-navigable calendar popover and ISO text entry. Enable the `chrono` feature to convert values to and from chrono::NaiveDate.",
+        props: vec![
+            PropDoc {
+                name: "value",
+                prop_type: "Option<RwSignal<Option<CalendarDate>>>",
+                default: None,
+                description: "Selected date (controlled)",
+                required: false,
+            },
+            PropDoc {
+                name: "on_change",
+                prop_type: "Option<Callback<CalendarDate>>",
+                default: None,
+                description: "Callback when a date is picked or typed",
+                required: false,
+            },
+            PropDoc {
+                name: "min_date",
+                prop_type: "Option<CalendarDate>",
+                default: None,
+                description: "Earliest selectable date",
+                required: false,
+            },
+            PropDoc {
+                name: "max_date",
+                prop_type: "Option<CalendarDate>",
+                default: None,
+                description: "Latest selectable date",
+                required: false,
+            },
+            PropDoc {
+                name: "first_day_of_week",
+                prop_type: "u32",
+                default: Some("0"),
+                description: "First calendar column, 0 = Sunday through 6 = Saturday",
+                required: false,
+            },
+            PropDoc {
+                name: "allow_manual_entry",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Whether the date can be typed as YYYY-MM-DD",
+                required: false,
+            },
+        ],
+        demo: || {
+            let date = RwSignal::new(CalendarDate::new(2024, 3, 15));
+
+            view! {
+                <DemoBlock title="DatePicker">
+                    <Stack spacing="md">
+                        <DatePicker
+                            value=date
+                            label="Start date"
+                            min_date=CalendarDate::new(2024, 1, 1).unwrap()
+                            max_date=CalendarDate::new(2024, 12, 31).unwrap()
+                            first_day_of_week=1
+                        />
+                        <Text size=TextSize::Sm>
+                            {move || format!(
+                                "Selected: {}",
+                                date.get().map(|d| d.to_string()).unwrap_or_else(|| "none".to_string())
+                            )}
+                        </Text>
+                    </Stack>
+                </DemoBlock>
+            }
+            .into_any()
+        },
+    }
+}
+
+fn time_picker_doc() -> ComponentDoc {
+    ComponentDoc {
+        name: "TimePicker",
+        import_name: "TimePicker, TimeOfDay",
+        description: "Time-of-day entry with hour, minute and optional second fields. Enable the `chrono` feature to convert values to and from chrono::NaiveTime.",
+        props: vec![
+            PropDoc {
+                name: "value",
+                prop_type: "Option<RwSignal<Option<TimeOfDay>>>",
+                default: None,
+                description: "Selected time (controlled)",
+                required: false,
+            },
+            PropDoc {
+                name: "on_change",
+                prop_type: "Option<Callback<TimeOfDay>>",
+                default: None,
+                description: "Callback when the fields form a valid time",
+                required: false,
+            },
+            PropDoc {
+                name: "with_seconds",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a seconds field",
+                required: false,
+            },
+            PropDoc {
+                name: "minute_step",
+                prop_type: "u32",
+                default: Some("1"),
+                description: "Step for the minute field's arrow keys",
+                required: false,
+            },
+        ],
+        demo: || {
+            let time = RwSignal::new(TimeOfDay::new(9, 30, 0));
+
+            view! {
+                <DemoBlock title="TimePicker">
+                    <Stack spacing="md">
+                        <TimePicker value=time label="Start time" minute_step=15 />
+                        <Text size=TextSize::Sm>
+                            {move || format!(
+                                "Selected: {}",
+                                time.get().map(|t| t.to_string()).unwrap_or_else(|| "none".to_string())
+                            )}
+                        </Text>
+                    </Stack>
+                </DemoBlock>
+            }
+            .into_any()
+        },
+    }
+}
+
 fn slider_doc() -> ComponentDoc {
     ComponentDoc {
         name: "Slider",
-        import_name: "Slider, SliderSize, SliderMark",
-        description: "A slider component for selecting numeric values within a range.",
+        import_name: "Slider, SliderSize, SliderMark, SliderOrientation",
+        description: "A slider component for selecting numeric values within a range. The thumb is keyboard accessible: arrow keys step, Page Up/Down take larger steps, Home/End jump to the bounds.",
         props: vec![
             PropDoc {
                 name: "value",
@@ -2993,6 +4395,20 @@ fn slider_doc() -> ComponentDoc {
                 description: "Step increment (0.0 for continuous)",
                 required: false,
             },
+            PropDoc {
+                name: "page_step",
+                prop_type: "Option<f64>",
+                default: Some("10 steps"),
+                description: "Increment for Page Up/Down",
+                required: false,
+            },
+            PropDoc {
+                name: "orientation",
+                prop_type: "SliderOrientation",
+                default: Some("Horizontal"),
+                description: "Horizontal or Vertical (maximum at the top; set the height through style)",
+                required: false,
+            },
             PropDoc {
                 name: "size",
                 prop_type: "Option<SliderSize>",
@@ -3032,6 +4448,7 @@ fn slider_doc() -> ComponentDoc {
         demo: || {
             let slider_value = RwSignal::new(50.0);
             let marks_value = RwSignal::new(25.0);
+            let vertical_value = RwSignal::new(40.0);
 
             view! {
                 <DemoBlock title="Slider">
@@ -3063,6 +4480,24 @@ fn slider_doc() -> ComponentDoc {
                         </div>
                     </Stack>
                 </DemoBlock>
+                <DemoBlock title="Vertical" code=r#"<Slider
+    value=level
+    orientation=SliderOrientation::Vertical
+    step=5.0
+    marks=vec![SliderMark::with_label(0.0, "Low"), SliderMark::with_label(100.0, "High")]
+    on_change=Callback::new(move |v| level.set(v))
+/>"#>
+                    <Slider
+                        value=vertical_value
+                        orientation=SliderOrientation::Vertical
+                        step=5.0
+                        marks=vec![
+                            SliderMark::with_label(0.0, "Low"),
+                            SliderMark::with_label(100.0, "High"),
+                        ]
+                        on_change=Callback::new(move |v| vertical_value.set(v))
+                    />
+                </DemoBlock>
             }
             .into_any()
         },
@@ -3072,7 +4507,7 @@ fn slider_doc() -> ComponentDoc {
 fn range_slider_doc() -> ComponentDoc {
     ComponentDoc {
         name: "RangeSlider",
-        import_name: "RangeSlider, SliderSize",
+        import_name: "RangeSlider, RangeSliderSize, SliderMark",
         description: "A dual-handle slider for selecting a range of values.",
         props: vec![
             PropDoc {
@@ -3100,7 +4535,7 @@ fn range_slider_doc() -> ComponentDoc {
                 name: "min_range",
                 prop_type: "f64",
                 default: Some("0.0"),
-                description: "Minimum gap between handles",
+                description: "Minimum gap between handles; a dragged handle stops short of the other instead of crossing it",
                 required: false,
             },
             PropDoc {
@@ -3117,16 +4552,24 @@ fn range_slider_doc() -> ComponentDoc {
                 description: "Label displayed above the slider",
                 required: false,
             },
+            PropDoc {
+                name: "marks",
+                prop_type: "Option<Vec<SliderMark>>",
+                default: None,
+                description: "Marks to display on the track",
+                required: false,
+            },
             PropDoc {
                 name: "on_change",
                 prop_type: "Option<Callback<(f64, f64)>>",
                 default: None,
-                description: "Callback when range changes",
+                description: "Callback when range changes; always an ordered (low, high)",
                 required: false,
             },
         ],
         demo: || {
             let range_value = RwSignal::new((25.0, 75.0));
+            let hours = RwSignal::new((9.0, 17.0));
 
             view! {
                 <DemoBlock title="Range Slider">
@@ -3146,6 +4589,35 @@ fn range_slider_doc() -> ComponentDoc {
                         </Text>
                     </Stack>
                 </DemoBlock>
+                <DemoBlock title="Marks and minimum range" code=r#"<RangeSlider
+    value=hours
+    min=0.0
+    max=24.0
+    min_range=2.0
+    marks=vec![
+        SliderMark::with_label(0.0, "0:00"),
+        SliderMark::with_label(12.0, "12:00"),
+        SliderMark::with_label(24.0, "24:00"),
+    ]
+    on_change=Callback::new(move |v| hours.set(v))
+/>"#>
+                    <div style="padding-bottom: 1.5rem;">
+                        <RangeSlider
+                            value=hours
+                            min=0.0
+                            max=24.0
+                            min_range=2.0
+                            label="Opening hours"
+                            show_value=true
+                            marks=vec![
+                                SliderMark::with_label(0.0, "0:00"),
+                                SliderMark::with_label(12.0, "12:00"),
+                                SliderMark::with_label(24.0, "24:00"),
+                            ]
+                            on_change=Callback::new(move |v| hours.set(v))
+                        />
+                    </div>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -3224,7 +4696,7 @@ fn segmented_control_doc() -> ComponentDoc {
 fn file_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "FileInput",
-        import_name: "FileInput, FileInfo",
+        import_name: "FileInput, FileInfo, FileRules",
         description: "A file input component for uploading files with a styled interface.",
         props: vec![
             PropDoc {
@@ -3255,6 +4727,28 @@ fn file_input_doc() -> ComponentDoc {
                 description: "Whether multiple files can be selected",
                 required: false,
             },
+            PropDoc {
+                name: "max_size",
+                prop_type: "Option<u64>",
+                default: None,
+                description: "Largest accepted file in bytes; larger files are rejected",
+                required: false,
+            },
+            PropDoc {
+                name: "max_files",
+                prop_type: "Option<usize>",
+                default: None,
+                description: "Most files kept from one selection; extra files are rejected",
+                required: false,
+            },
+            PropDoc {
+                name: "previews",
+                prop_type: "bool",
+                default: Some("true"),
+                description:
+                    "Thumbnails for selected images (object URLs are revoked on clear and unmount)",
+                required: false,
+            },
             PropDoc {
                 name: "clearable",
                 prop_type: "bool",
@@ -3262,6 +4756,13 @@ fn file_input_doc() -> ComponentDoc {
                 description: "Whether to show a clear button",
                 required: false,
             },
+            PropDoc {
+                name: "dropzone",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Large drop area; the compact field accepts dropped files too",
+                required: false,
+            },
             PropDoc {
                 name: "on_change",
                 prop_type: "Option<Callback<Vec<FileInfo>>>",
@@ -3269,6 +4770,13 @@ fn file_input_doc() -> ComponentDoc {
                 description: "Callback when files are selected",
                 required: false,
             },
+            PropDoc {
+                name: "on_error",
+                prop_type: "Option<Callback<Vec<ValidationError>>>",
+                default: None,
+                description: "Why files were rejected: type not accepted, too large, or too many",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -3287,6 +4795,25 @@ fn file_input_doc() -> ComponentDoc {
                         />
                     </Stack>
                 </DemoBlock>
+                <DemoBlock title="Drop zone" code=r#"<FileInput
+    label="Attachments"
+    accept="image/*,.pdf"
+    multiple=true
+    max_files=5
+    max_size=5 * 1024 * 1024
+    dropzone=true
+    on_error=Callback::new(move |errors: Vec<ValidationError>| { /* report */ })
+/>"#>
+                    <FileInput
+                        label="Attachments"
+                        accept="image/*,.pdf"
+                        multiple=true
+                        max_files=5
+                        max_size=5 * 1024 * 1024
+                        dropzone=true
+                        placeholder="Click to browse"
+                    />
+                </DemoBlock>
             }
             .into_any()
         },
@@ -3297,7 +4824,7 @@ fn pin_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "PinInput",
         import_name: "PinInput, PinInputType",
-        description: "A PIN input component for entering verification codes, OTPs, etc.",
+        description: "A PIN input component for entering verification codes, OTPs, etc. Pasting a code fills the cells, typing moves focus forward and Backspace in an empty cell steps back.",
         props: vec![
             PropDoc {
                 name: "length",
@@ -3334,11 +4861,18 @@ fn pin_input_doc() -> ComponentDoc {
                 description: "Placeholder character for empty fields",
                 required: false,
             },
+            PropDoc {
+                name: "on_change",
+                prop_type: "Option<Callback<String>>",
+                default: None,
+                description: "Callback with the concatenated value after each edit or paste",
+                required: false,
+            },
             PropDoc {
                 name: "on_complete",
                 prop_type: "Option<Callback<String>>",
                 default: None,
-                description: "Callback when all fields are filled",
+                description: "Callback when the last empty field is filled",
                 required: false,
             },
         ],
@@ -3349,10 +4883,11 @@ fn pin_input_doc() -> ComponentDoc {
                 <DemoBlock title="PIN Input">
                     <Stack spacing="lg">
                         <div>
-                            <Text size=TextSize::Sm weight=TextWeight::Medium>"Enter verification code"</Text>
+                            <Text size=TextSize::Sm weight=TextWeight::Medium>"Enter or paste a verification code"</Text>
                             <PinInput
                                 length=6
                                 value=pin_value
+                                on_change=Callback::new(move |code: String| pin_value.set(code))
                                 on_complete=Callback::new(move |code: String| {
                                     web_sys::console::log_1(&format!("Code entered: {}", code).into());
                                 })
@@ -3382,10 +4917,24 @@ fn pagination_doc() -> ComponentDoc {
         props: vec![
             PropDoc {
                 name: "total",
-                prop_type: "usize",
+                prop_type: "Option<usize>",
                 default: None,
                 description: "Total number of pages",
-                required: true,
+                required: false,
+            },
+            PropDoc {
+                name: "total_items",
+                prop_type: "Option<usize>",
+                default: None,
+                description: "Item count to page; the page count is computed with page_size when total is unset (zero items disables the controls)",
+                required: false,
+            },
+            PropDoc {
+                name: "page_size",
+                prop_type: "usize",
+                default: Some("10"),
+                description: "Items per page for total_items",
+                required: false,
             },
             PropDoc {
                 name: "value",
@@ -3429,6 +4978,13 @@ fn pagination_doc() -> ComponentDoc {
                 description: "Whether to show previous/next buttons",
                 required: false,
             },
+            PropDoc {
+                name: "with_jump",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Page number input with a Go button; out-of-range pages are clamped",
+                required: false,
+            },
             PropDoc {
                 name: "on_change",
                 prop_type: "Option<Callback<usize>>",
@@ -3440,6 +4996,7 @@ fn pagination_doc() -> ComponentDoc {
         demo: || {
             let current_page = RwSignal::new(1_usize);
             let edge_page = RwSignal::new(10_usize);
+            let items_page = RwSignal::new(1_usize);
 
             view! {
                 <DemoBlock title="Pagination">
@@ -3462,6 +5019,16 @@ fn pagination_doc() -> ComponentDoc {
                                 on_change=Callback::new(move |p| edge_page.set(p))
                             />
                         </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"2,345 items, 25 per page, with jump"</Text>
+                            <Pagination
+                                total_items=2345
+                                page_size=25
+                                value=items_page
+                                with_jump=true
+                                on_change=Callback::new(move |p| items_page.set(p))
+                            />
+                        </div>
                     </Stack>
                 </DemoBlock>
             }
@@ -3473,8 +5040,8 @@ fn pagination_doc() -> ComponentDoc {
 fn angle_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "AngleInput",
-        import_name: "AngleInput, AngleUnit, AngleNormalization, DMS",
-        description: "A specialized input for angle values with support for degrees, radians, gradians, turns, and DMS (degrees-minutes-seconds) formats.",
+        import_name: "AngleInput, AngleUnit, AngleNormalization, AngleConvention, DMS",
+        description: "A specialized input for angle values with support for degrees, radians, gradians, turns, NATO mils, and DMS (degrees-minutes-seconds) formats.",
         props: vec![
             PropDoc {
                 name: "value",
@@ -3501,7 +5068,7 @@ fn angle_input_doc() -> ComponentDoc {
                 name: "unit",
                 prop_type: "AngleUnit",
                 default: Some("Degrees"),
-                description: "Display unit: Degrees, Radians, Gradians, Turns, or DMS",
+                description: "Display unit: Degrees, Radians, Gradians, Turns, DMS, or Mils (6400 per turn)",
                 required: false,
             },
             PropDoc {
@@ -3511,6 +5078,13 @@ fn angle_input_doc() -> ComponentDoc {
                 description: "Angle normalization: None, ZeroTo360, or NegativeTo180",
                 required: false,
             },
+            PropDoc {
+                name: "convention",
+                prop_type: "AngleConvention",
+                default: Some("Math"),
+                description: "Math (0° east, counter-clockwise) or Bearing (0° north, clockwise, with a compass point). The value stays in the math convention",
+                required: false,
+            },
             PropDoc {
                 name: "show_unit_selector",
                 prop_type: "bool",
@@ -3539,6 +5113,34 @@ fn angle_input_doc() -> ComponentDoc {
                 description: "Whether the input is disabled",
                 required: false,
             },
+            PropDoc {
+                name: "snap",
+                prop_type: "Option<f64>",
+                default: None,
+                description: "Round entered values to the nearest multiple of this many degrees (e.g. 15)",
+                required: false,
+            },
+            PropDoc {
+                name: "show_dial",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a rotary dial beside the field; drag with mouse or touch, or focus the handle and use the arrow keys (Shift for 10x). Honors snap and normalization",
+                required: false,
+            },
+            PropDoc {
+                name: "show_presets",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a row of preset angle buttons labelled in the current unit",
+                required: false,
+            },
+            PropDoc {
+                name: "presets",
+                prop_type: "Option<Vec<f64>>",
+                default: Some("[0, 30, 45, 60, 90, 180, 270]"),
+                description: "Preset angles in degrees; setting this also shows the preset row",
+                required: false,
+            },
         ],
         demo: || {
             let angle1 = RwSignal::new(45.0_f64);
@@ -3580,6 +5182,28 @@ fn angle_input_doc() -> ComponentDoc {
                                 label="Compass Heading".to_string()
                             />
                         </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Dial with 15° snapping"</Text>
+                            <AngleInput
+                                value=RwSignal::new(30.0)
+                                normalization=AngleNormalization::ZeroTo360
+                                show_dial=true
+                                snap=15.0
+                                show_unit_selector=true
+                                label="Rotation".to_string()
+                            />
+                        </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Bearing in NATO mils"</Text>
+                            <AngleInput
+                                value=RwSignal::new(180.0)
+                                unit=AngleUnit::Mils
+                                precision=0
+                                convention=AngleConvention::Bearing
+                                show_unit_selector=true
+                                label="Azimuth".to_string()
+                            />
+                        </div>
                     </Stack>
                 </DemoBlock>
             }
@@ -3591,7 +5215,7 @@ fn angle_input_doc() -> ComponentDoc {
 fn fraction_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "FractionInput",
-        import_name: "FractionInput, Fraction, FractionDisplayFormat",
+        import_name: "FractionInput, Fraction, FractionDisplayFormat, DecimalParseMode",
         description: "A specialized input for exact rational numbers with automatic simplification and multiple display formats.",
         props: vec![
             PropDoc {
@@ -3643,6 +5267,27 @@ fn fraction_input_doc() -> ComponentDoc {
                 description: "Decimal precision for decimal format display",
                 required: false,
             },
+            PropDoc {
+                name: "decimal_parse_mode",
+                prop_type: "Option<DecimalParseMode>",
+                default: Some("Approximate(max_denominator)"),
+                description: "How typed decimals become fractions: Exact (0.125 → 1/8), Repeating (also 0.3(3), 0.\\overline{3}, 0.333... → 1/3) or Approximate(max_denominator) for the closest fraction",
+                required: false,
+            },
+            PropDoc {
+                name: "max_denominator",
+                prop_type: "Option<i64>",
+                default: Some("10000 for decimals"),
+                description: "Reject entries whose reduced denominator exceeds this; also the default limit for approximating decimals",
+                required: false,
+            },
+            PropDoc {
+                name: "on_parse_error",
+                prop_type: "Option<Callback<ValidationError>>",
+                default: None,
+                description: "Called when typed text is rejected (unparseable, too large for an exact i64 fraction, or over max_denominator); the input reverts and shows the message",
+                required: false,
+            },
             PropDoc {
                 name: "label",
                 prop_type: "Option<String>",
@@ -3657,11 +5302,26 @@ fn fraction_input_doc() -> ComponentDoc {
                 description: "Whether the input is disabled",
                 required: false,
             },
+            PropDoc {
+                name: "show_actions",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show invert (1/x), negate (±) and reset-to-zero buttons",
+                required: false,
+            },
+            PropDoc {
+                name: "shortcuts",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Enable Alt+I (invert), Alt+N (negate) and Alt+0 (reset) keyboard shortcuts",
+                required: false,
+            },
         ],
         demo: || {
             let frac1 = RwSignal::new(Fraction::new(3, 4));
             let frac2 = RwSignal::new(Fraction::new(7, 4));
             let frac3 = RwSignal::new(Fraction::new(6, 8));
+            let frac4 = RwSignal::new(Fraction::new(1, 7));
             view! {
                 <DemoBlock title="Fraction Input">
                     <Stack spacing="lg">
@@ -3687,6 +5347,14 @@ fn fraction_input_doc() -> ComponentDoc {
                                 label="Enter 6/8 to see simplification".to_string()
                             />
                         </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Repeating decimals"</Text>
+                            <FractionInput
+                                value=frac4
+                                decimal_parse_mode=DecimalParseMode::Repeating
+                                label="Try 0.142857(142857) or 0.1666...".to_string()
+                            />
+                        </div>
                     </Stack>
                 </DemoBlock>
             }
@@ -3698,7 +5366,7 @@ fn fraction_input_doc() -> ComponentDoc {
 fn unit_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "UnitInput",
-        import_name: "UnitInput, Unit, UnitValue, UnitCategory, length, mass, time, temperature, data",
+        import_name: "UnitInput, Unit, UnitValue, UnitCategory, CompoundUnit, SiPrefix, length, mass, time, temperature, data, velocity, force, energy",
         description: "A specialized input for values with physical units, supporting automatic unit conversion.",
         props: vec![
             PropDoc {
@@ -3736,6 +5404,27 @@ fn unit_input_doc() -> ComponentDoc {
                 description: "Number of decimal places to display",
                 required: false,
             },
+            PropDoc {
+                name: "expected_dimension",
+                prop_type: "Option<Dimension>",
+                default: None,
+                description: "Reject values and units outside this dimension (e.g. Dimension::LENGTH)",
+                required: false,
+            },
+            PropDoc {
+                name: "auto_prefix",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Rescale metric values to the SI prefix that keeps them between 1 and 1000 (1500 m → 1.5 km); the base-unit value is kept exactly",
+                required: false,
+            },
+            PropDoc {
+                name: "prefix_range",
+                prop_type: "Option<RangeInclusive<SiPrefix>>",
+                default: Some("SiPrefix::Femto..=SiPrefix::Peta"),
+                description: "Prefixes auto_prefix may pick",
+                required: false,
+            },
             PropDoc {
                 name: "label",
                 prop_type: "Option<String>",
@@ -3776,6 +5465,23 @@ fn unit_input_doc() -> ComponentDoc {
                                 label="Temperature".to_string()
                             />
                         </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Compound units (type \"36 km/h\")"</Text>
+                            <UnitInput
+                                units=velocity::all()
+                                expected_dimension=Dimension::SPEED
+                                label="Velocity".to_string()
+                            />
+                        </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Auto SI prefix (type \"0.0025\" or \"1500\")"</Text>
+                            <UnitInput
+                                units=vec![length::meter()]
+                                auto_prefix=true
+                                prefix_range=SiPrefix::Micro..=SiPrefix::Kilo
+                                label="Wavelength".to_string()
+                            />
+                        </div>
                         <div>
                             <Text size=TextSize::Sm color="dimmed">"Data size"</Text>
                             <UnitInput
@@ -3846,6 +5552,27 @@ fn complex_number_input_doc() -> ComponentDoc {
                 description: "Number of decimal places for display",
                 required: false,
             },
+            PropDoc {
+                name: "freeform",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Single text field that accepts pasted expressions: 3 - 4i, -i, 5∠53.13°, 5 < 0.927, 5e^(i*0.927), 2e^(iπ/3). Angles without °, rad or π use angle_unit",
+                required: false,
+            },
+            PropDoc {
+                name: "on_parse_error",
+                prop_type: "Option<Callback<ValidationError>>",
+                default: None,
+                description: "Called when freeform or pasted text cannot be parsed; the text is kept and the error shown",
+                required: false,
+            },
+            PropDoc {
+                name: "show_operations",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show conjugate (z̄), reciprocal (1/z), negate and normalize buttons. Results appear in the active format; 1/z and z/|z| are disabled at zero",
+                required: false,
+            },
             PropDoc {
                 name: "label",
                 prop_type: "Option<String>",
@@ -3877,9 +5604,18 @@ fn complex_number_input_doc() -> ComponentDoc {
                             <ComplexNumberInput
                                 default_value=ComplexNumber::from_polar(5.0, std::f64::consts::FRAC_PI_4)
                                 format=ComplexFormat::Polar
+                                show_operations=true
                                 label="Phasor".to_string()
                             />
                         </div>
+                        <div>
+                            <Text size=TextSize::Sm color="dimmed">"Freeform entry (try 2e^(iπ/3) or 5∠53.13°)"</Text>
+                            <ComplexNumberInput
+                                default_value=ComplexNumber::new(3.0, -4.0)
+                                freeform=true
+                                label="Impedance".to_string()
+                            />
+                        </div>
                         <div>
                             <Text size=TextSize::Sm color="dimmed">"Pure imaginary"</Text>
                             <ComplexNumberInput
@@ -3898,7 +5634,7 @@ fn complex_number_input_doc() -> ComponentDoc {
 fn uncertainty_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "UncertaintyInput",
-        import_name: "UncertaintyInput, UncertainValue, UncertaintyFormat, UncertaintyType",
+        import_name: "UncertaintyInput, UncertainValue, UncertaintyFormat, UncertaintyType, PropagationMethod, MonteCarloOptions",
         description: "A specialized input for values with measurement uncertainty, supporting symmetric (±) and asymmetric (+/-) uncertainty bounds.",
         props: vec![
             PropDoc {
@@ -3951,24 +5687,66 @@ fn uncertainty_input_doc() -> ComponentDoc {
                 required: false,
             },
             PropDoc {
-                name: "show_info",
-                prop_type: "bool",
-                default: Some("true"),
-                description: "Show additional info (bounds, relative uncertainty)",
+                name: "show_info",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Show additional info (bounds, relative uncertainty)",
+                required: false,
+            },
+            PropDoc {
+                name: "label",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Label text above the input",
+                required: false,
+            },
+            PropDoc {
+                name: "disabled",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Whether the input is disabled",
+                required: false,
+            },
+            PropDoc {
+                name: "formula",
+                prop_type: "Option<String>",
+                default: None,
+                description: "Formula to propagate this value through, e.g. \"x^2 / y\"",
+                required: false,
+            },
+            PropDoc {
+                name: "variable_name",
+                prop_type: "String",
+                default: Some("\"x\""),
+                description: "Name of this input's value inside the formula",
+                required: false,
+            },
+            PropDoc {
+                name: "variables",
+                prop_type: "Option<Signal<Vec<(String, UncertainValue)>>>",
+                default: None,
+                description: "Other uncertain variables referenced by the formula",
+                required: false,
+            },
+            PropDoc {
+                name: "propagation_method",
+                prop_type: "PropagationMethod",
+                default: Some("Analytic"),
+                description: "Analytic (first-order quadrature) or MonteCarlo (sampled, reported alongside analytic)",
                 required: false,
             },
             PropDoc {
-                name: "label",
-                prop_type: "Option<String>",
-                default: None,
-                description: "Label text above the input",
+                name: "monte_carlo_options",
+                prop_type: "MonteCarloOptions",
+                default: Some("MonteCarloOptions::default()"),
+                description: "Sample count, Gaussian/Uniform distribution and RNG seed",
                 required: false,
             },
             PropDoc {
-                name: "disabled",
-                prop_type: "bool",
-                default: Some("false"),
-                description: "Whether the input is disabled",
+                name: "on_propagate",
+                prop_type: "Option<Callback<PropagationResult>>",
+                default: None,
+                description: "Callback with the propagated result",
                 required: false,
             },
         ],
@@ -4035,6 +5813,27 @@ fn interval_input_doc() -> ComponentDoc {
                 description: "Whether to allow infinite bounds",
                 required: false,
             },
+            PropDoc {
+                name: "format",
+                prop_type: "IntervalFormat",
+                default: Some("Mathematical"),
+                description: "Preview format: Mathematical, SetNotation, Latex, or SetBuilderLatex",
+                required: false,
+            },
+            PropDoc {
+                name: "show_copy_latex",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a button that copies the interval as LaTeX",
+                required: false,
+            },
+            PropDoc {
+                name: "show_operations",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show the width, membership of a probe value, and the intersection and union with a second interval",
+                required: false,
+            },
             PropDoc {
                 name: "label",
                 prop_type: "Option<String>",
@@ -4050,6 +5849,16 @@ fn interval_input_doc() -> ComponentDoc {
                 <DemoBlock title="IntervalInput" code=r#"<IntervalInput label="Select Range" />"#>
                     <Stack spacing="md">
                         <IntervalInput label="Range".to_string() />
+                        <IntervalInput
+                            label="LaTeX".to_string()
+                            format=IntervalFormat::SetBuilderLatex
+                            show_copy_latex=true
+                        />
+                        <IntervalInput
+                            label="Set operations".to_string()
+                            bounds=IntervalBounds::HalfOpenLeft
+                            show_operations=true
+                        />
                     </Stack>
                 </DemoBlock>
             }
@@ -4092,6 +5901,34 @@ fn coordinate_input_doc() -> ComponentDoc {
                 description: "Label text above the input",
                 required: false,
             },
+            PropDoc {
+                name: "angle_snap",
+                prop_type: "Option<f64>",
+                default: None,
+                description: "Snap angular components to multiples of this step (in the display angle unit)",
+                required: false,
+            },
+            PropDoc {
+                name: "enable_undo_redo",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Undo/redo edits with Ctrl+Z and Ctrl+Y (or Ctrl+Shift+Z)",
+                required: false,
+            },
+            PropDoc {
+                name: "undo_history_size",
+                prop_type: "usize",
+                default: Some("50"),
+                description: "Maximum number of undo steps",
+                required: false,
+            },
+            PropDoc {
+                name: "show_history_controls",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show undo/redo buttons",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
@@ -4159,6 +5996,20 @@ fn point_locator_doc() -> ComponentDoc {
                 description: "Label text above the canvas",
                 required: false,
             },
+            PropDoc {
+                name: "snap_polar",
+                prop_type: "Option<PolarSnap>",
+                default: None,
+                description: "Snap to rays (angle step in degrees) and optional rings around the origin",
+                required: false,
+            },
+            PropDoc {
+                name: "show_polar_grid",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Draw a polar grid instead of the rectangular grid",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
@@ -4183,7 +6034,7 @@ fn matrix_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "MatrixInput",
         description: "Spreadsheet-style matrix entry with built-in operations like determinant, trace, and transpose.",
-        import_name: "MatrixInput",
+        import_name: "MatrixInput, ComplexMatrix, MatrixElementType",
         props: vec![
             PropDoc {
                 name: "value",
@@ -4224,7 +6075,21 @@ fn matrix_input_doc() -> ComponentDoc {
                 name: "show_operations",
                 prop_type: "bool",
                 default: Some("true"),
-                description: "Show determinant/trace/norm preview",
+                description: "Show determinant/trace/norm preview; square matrices also show eigenvalues and the inverse",
+                required: false,
+            },
+            PropDoc {
+                name: "singular_tolerance",
+                prop_type: "f64",
+                default: Some("1e-10"),
+                description: "Matrices with |det| at or below this are marked singular instead of inverted",
+                required: false,
+            },
+            PropDoc {
+                name: "show_decompositions",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Offer LU (with pivoting) and QR factors shown side by side",
                 required: false,
             },
             PropDoc {
@@ -4248,11 +6113,92 @@ fn matrix_input_doc() -> ComponentDoc {
                 description: "Label text",
                 required: false,
             },
+            PropDoc {
+                name: "enable_undo_redo",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Undo/redo edits with Ctrl+Z and Ctrl+Y (or Ctrl+Shift+Z)",
+                required: false,
+            },
+            PropDoc {
+                name: "undo_history_size",
+                prop_type: "usize",
+                default: Some("50"),
+                description: "Maximum number of undo steps",
+                required: false,
+            },
+            PropDoc {
+                name: "show_history_controls",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show undo/redo buttons",
+                required: false,
+            },
+            PropDoc {
+                name: "on_paste_error",
+                prop_type: "Option<Callback<ValidationError>>",
+                default: None,
+                description: "Receives rejected pastes: ragged rows, non-numeric cells (with their row and column), or a block that doesn't fit without allow_resize",
+                required: false,
+            },
+            PropDoc {
+                name: "show_copy_csv",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a button that copies the matrix as CSV for spreadsheets",
+                required: false,
+            },
+            PropDoc {
+                name: "on_copy_csv",
+                prop_type: "Option<Callback<String>>",
+                default: None,
+                description: "Receives the CSV whenever it is copied",
+                required: false,
+            },
+            PropDoc {
+                name: "element_type",
+                prop_type: "MatrixElementType",
+                default: Some("Real"),
+                description: "Complex cells accept 1+2i or 2∠45° and edit complex_value; paste, undo and decompositions are real-only",
+                required: false,
+            },
+            PropDoc {
+                name: "complex_value",
+                prop_type: "Option<RwSignal<ComplexMatrix>>",
+                default: None,
+                description: "Controlled value when element_type is Complex",
+                required: false,
+            },
+            PropDoc {
+                name: "on_complex_change",
+                prop_type: "Option<Callback<ComplexMatrix>>",
+                default: None,
+                description: "Callback when a complex cell or the size changes",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
 
             let matrix = RwSignal::new(Matrix::identity(3));
+            let complex = RwSignal::new(
+                ComplexMatrix::from_vec(vec![
+                    vec![ComplexNumber::new(2.0, 0.0), ComplexNumber::new(1.0, -1.0)],
+                    vec![ComplexNumber::new(1.0, 1.0), ComplexNumber::new(3.0, 0.0)],
+                ])
+                .unwrap_or_default(),
+            );
+            let teaching = RwSignal::new(
+                Matrix::from_vec(vec![
+                    vec![2.0, 1.0, 1.0],
+                    vec![4.0, -6.0, 0.0],
+                    vec![-2.0, 7.0, 2.0],
+                ])
+                .unwrap_or_default(),
+            );
+            let rotation = RwSignal::new(
+                Matrix::from_vec(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap_or_default(),
+            );
 
             view! {
                 <DemoBlock title="Matrix Input" code=r#"let matrix = RwSignal::new(Matrix::identity(3));
@@ -4267,9 +6213,43 @@ fn matrix_input_doc() -> ComponentDoc {
                             value=matrix
                             show_operations=true
                             allow_resize=true
+                            show_copy_csv=true
+                            description="Paste rows copied from a spreadsheet into any cell".to_string()
                         />
                     </Stack>
                 </DemoBlock>
+                <DemoBlock title="Decompositions" code=r#"let a = Matrix::from_vec(vec![
+    vec![2.0, 1.0, 1.0],
+    vec![4.0, -6.0, 0.0],
+    vec![-2.0, 7.0, 2.0],
+]).unwrap();
+
+<MatrixInput value=RwSignal::new(a) show_decompositions=true />"#>
+                    <MatrixInput value=teaching show_decompositions=true precision=3 />
+                </DemoBlock>
+                <DemoBlock title="Eigenvalues and inverse" code=r#"// 90° rotation: eigenvalues ±i
+let rotation = Matrix::from_vec(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+
+<MatrixInput value=RwSignal::new(rotation) />"#>
+                    <MatrixInput value=rotation precision=3 />
+                </DemoBlock>
+                <DemoBlock title="Complex entries" code=r#"let h = ComplexMatrix::from_vec(vec![
+    vec![ComplexNumber::new(2.0, 0.0), ComplexNumber::new(1.0, -1.0)],
+    vec![ComplexNumber::new(1.0, 1.0), ComplexNumber::new(3.0, 0.0)],
+]).unwrap();
+
+<MatrixInput
+    element_type=MatrixElementType::Complex
+    complex_value=RwSignal::new(h)
+    allow_resize=true
+/>"#>
+                    <MatrixInput
+                        element_type=MatrixElementType::Complex
+                        complex_value=complex
+                        allow_resize=true
+                        precision=3
+                    />
+                </DemoBlock>
             }
             .into_any()
         },
@@ -4280,7 +6260,7 @@ fn vector_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "VectorInput",
         description: "Mathematical vector entry with multiple notations, magnitude display, and vector operations.",
-        import_name: "VectorInput",
+        import_name: "VectorInput, VectorOps, Vector, VectorNotation",
         props: vec![
             PropDoc {
                 name: "value",
@@ -4338,11 +6318,48 @@ fn vector_input_doc() -> ComponentDoc {
                 description: "Label text",
                 required: false,
             },
+            PropDoc {
+                name: "component_labels",
+                prop_type: "Option<Vec<String>>",
+                default: None,
+                description: "Custom per-component labels (e.g. Fx/Fy/Fz); must match the dimensions, otherwise x/y/z are used",
+                required: false,
+            },
+            PropDoc {
+                name: "enable_undo_redo",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Undo/redo edits with Ctrl+Z and Ctrl+Y (or Ctrl+Shift+Z)",
+                required: false,
+            },
+            PropDoc {
+                name: "undo_history_size",
+                prop_type: "usize",
+                default: Some("50"),
+                description: "Maximum number of undo steps",
+                required: false,
+            },
+            PropDoc {
+                name: "show_history_controls",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show undo/redo buttons",
+                required: false,
+            },
+            PropDoc {
+                name: "show_operations",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a normalize button and the unit vector; disabled for the zero vector. Use VectorOps with two inputs for dot and cross products",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
 
             let vector = RwSignal::new(Vector::new(vec![1.0, 2.0, 3.0]));
+            let a = RwSignal::new(Vector::new_3d(1.0, 2.0, 3.0));
+            let b = RwSignal::new(Vector::new_3d(4.0, 5.0, 6.0));
 
             view! {
                 <DemoBlock title="Vector Input" code=r#"let vector = RwSignal::new(Vector::new(vec![1.0, 2.0, 3.0]));
@@ -4357,9 +6374,22 @@ fn vector_input_doc() -> ComponentDoc {
                             value=vector
                             notation=VectorNotation::Column
                             show_magnitude=true
+                            show_operations=true
                         />
                     </Stack>
                 </DemoBlock>
+                <DemoBlock title="Dot and cross products" code=r#"let a = RwSignal::new(Vector::new_3d(1.0, 2.0, 3.0));
+let b = RwSignal::new(Vector::new_3d(4.0, 5.0, 6.0));
+
+<VectorInput value=a label="a" allow_resize=true />
+<VectorInput value=b label="b" allow_resize=true />
+<VectorOps a=a b=b notation=VectorNotation::AngleBrackets precision=2 />"#>
+                    <Stack spacing="md">
+                        <VectorInput value=a label="a" allow_resize=true show_magnitude=false />
+                        <VectorInput value=b label="b" allow_resize=true show_magnitude=false />
+                        <VectorOps a=a b=b notation=VectorNotation::AngleBrackets precision=2 />
+                    </Stack>
+                </DemoBlock>
             }
             .into_any()
         },
@@ -4370,7 +6400,7 @@ fn tensor_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "TensorInput",
         description: "Multi-dimensional tensor entry with slice navigation, reshape operations, and statistics display.",
-        import_name: "TensorInput",
+        import_name: "TensorInput, Tensor, NpyError, ReshapeError",
         props: vec![
             PropDoc {
                 name: "value",
@@ -4421,6 +6451,34 @@ fn tensor_input_doc() -> ComponentDoc {
                 description: "Whether the input is disabled",
                 required: false,
             },
+            PropDoc {
+                name: "enable_npy",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Drop zone that loads NumPy .npy files (<f8/<f4, C order) and reshapes the tensor, plus a .npy download button",
+                required: false,
+            },
+            PropDoc {
+                name: "npy_file_name",
+                prop_type: "String",
+                default: Some("\"tensor.npy\""),
+                description: "File name offered when downloading",
+                required: false,
+            },
+            PropDoc {
+                name: "on_npy_error",
+                prop_type: "Option<Callback<NpyError>>",
+                default: None,
+                description: "Called when a dropped file can't be loaded (unsupported dtype, Fortran order, truncated data)",
+                required: false,
+            },
+            PropDoc {
+                name: "allow_reshape",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Shape editor (e.g. 2, 2, 6 or 4, -1) that reshapes in place once the element count matches",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
@@ -4511,6 +6569,41 @@ let tensor = RwSignal::new(Tensor::from_data(data, vec![2, 2, 2, 2, 3]).unwrap()
                         />
                     </DemoBlock>
 
+                    <DemoBlock title="NumPy .npy Import/Export" code=r#"// In Python: np.save("a.npy", np.random.rand(2, 3, 4))
+<TensorInput
+    shape=vec![2, 3]
+    enable_npy=true
+    npy_file_name="weights.npy"
+    label="Drop a .npy file to load it"
+/>
+
+// Outside the component
+let bytes = tensor.to_npy_bytes();
+let restored = Tensor::from_npy_bytes(&bytes)?;"#>
+                        <TensorInput
+                            shape=vec![2, 3]
+                            enable_npy=true
+                            npy_file_name="weights.npy"
+                            label="Drop a .npy file to load it"
+                        />
+                    </DemoBlock>
+
+                    <DemoBlock title="Reshape" code=r#"// Try "2, 2, 6", "4, -1" or "5, 5"
+<TensorInput
+    shape=vec![2, 12]
+    allow_reshape=true
+    label="24 elements"
+/>
+
+// Outside the component
+tensor.try_reshape("2, 2, 6")?;"#>
+                        <TensorInput
+                            shape=vec![2, 12]
+                            allow_reshape=true
+                            label="24 elements"
+                        />
+                    </DemoBlock>
+
                     <DemoBlock title="Without Statistics" code=r#"<TensorInput
     shape=vec![2, 3]
     show_stats=false
@@ -4591,6 +6684,27 @@ fn symbol_palette_doc() -> ComponentDoc {
                 description: "Label for the palette",
                 required: false,
             },
+            PropDoc {
+                name: "recent_limit",
+                prop_type: "usize",
+                default: Some("16"),
+                description: "Number of recently selected symbols shown above the grid (0 hides the row)",
+                required: false,
+            },
+            PropDoc {
+                name: "persist_key",
+                prop_type: "Option<String>",
+                default: None,
+                description: "localStorage key for keeping recent symbols across page loads",
+                required: false,
+            },
+            PropDoc {
+                name: "wrap_navigation",
+                prop_type: "bool",
+                default: Some("true"),
+                description: "Left/Right arrow keys continue onto the previous/next row; when false they stop at row edges",
+                required: false,
+            },
         ],
         demo: || {
             use mingot::prelude::*;
@@ -4625,6 +6739,21 @@ fn symbol_palette_doc() -> ComponentDoc {
                         />
                     </DemoBlock>
 
+                    <DemoBlock title="Recent Symbols" code=r#"// Pick a few symbols, then reload the page
+<SymbolPalette
+    recent_limit=8
+    persist_key="mingot-demo-recent-symbols"
+    show_tabs=false
+    label="Remembers your last 8 picks"
+/>"#>
+                        <SymbolPalette
+                            recent_limit=8
+                            persist_key="mingot-demo-recent-symbols"
+                            show_tabs=false
+                            label="Remembers your last 8 picks"
+                        />
+                    </DemoBlock>
+
                     <DemoBlock title="Greek Letters Only" code=r#"<SymbolPalette
     categories=vec![SymbolCategory::Greek]
     show_tabs=false
@@ -4711,7 +6840,7 @@ fn formula_input_doc() -> ComponentDoc {
     ComponentDoc {
         name: "FormulaInput",
         description: "Mathematical expression input with parsing, variable support, and function recognition. Supports standard math functions (sin, cos, exp, ln, sqrt, etc.) and evaluates expressions in real-time.",
-        import_name: "FormulaInput",
+        import_name: "FormulaInput, FormulaFunction, DigitSuffix",
         props: vec![
             PropDoc {
                 name: "value",
@@ -4731,7 +6860,35 @@ fn formula_input_doc() -> ComponentDoc {
                 name: "variables",
                 prop_type: "Option<Signal<HashMap<String, f64>>>",
                 default: None,
-                description: "Variable values for evaluation",
+                description: "Variable values and named constants for evaluation (take precedence over pi, e, tau)",
+                required: false,
+            },
+            PropDoc {
+                name: "functions",
+                prop_type: "Option<Signal<HashMap<String, FormulaFunction>>>",
+                default: None,
+                description: "User-defined functions with arity checking; override built-ins of the same name with a warning",
+                required: false,
+            },
+            PropDoc {
+                name: "implicit_multiplication",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Read 2x, 3sin(x) and 2(3+4) as products; binds tighter than * and / but looser than ^",
+                required: false,
+            },
+            PropDoc {
+                name: "digit_suffix",
+                prop_type: "DigitSuffix",
+                default: Some("DigitSuffix::Identifier"),
+                description: "Whether e2 is the name e2 (Identifier) or e * 2 (Multiply) under implicit multiplication",
+                required: false,
+            },
+            PropDoc {
+                name: "units",
+                prop_type: "Option<Vec<Unit>>",
+                default: None,
+                description: "Unit symbols allowed in the formula; 5 m / 2 s evaluates to 2.5 m/s",
                 required: false,
             },
             PropDoc {
@@ -4780,6 +6937,60 @@ fn formula_input_doc() -> ComponentDoc {
                         />
                     </DemoBlock>
 
+                    <DemoBlock title="Custom Functions and Constants" code=r#"let mut functions = HashMap::new();
+functions.insert("gauss".to_string(), FormulaFunction::new(3, |args| {
+    let (x, mu, sigma) = (args[0], args[1], args[2]);
+    Ok((-((x - mu) / sigma).powi(2) / 2.0).exp())
+}));
+let mut constants = HashMap::new();
+constants.insert("mu0".to_string(), 1.0);
+
+<FormulaInput
+    label="Custom function"
+    placeholder="e.g., gauss(1.5, mu0, 0.5)"
+    functions=Signal::derive(move || functions.clone())
+    variables=Signal::derive(move || constants.clone())
+/>"#>
+                        {
+                            let mut functions = std::collections::HashMap::new();
+                            functions.insert("gauss".to_string(), FormulaFunction::new(3, |args| {
+                                let (x, mu, sigma) = (args[0], args[1], args[2]);
+                                Ok((-((x - mu) / sigma).powi(2) / 2.0).exp())
+                            }));
+                            let mut constants = std::collections::HashMap::new();
+                            constants.insert("mu0".to_string(), 1.0);
+                            view! {
+                                <FormulaInput
+                                    label="Custom function"
+                                    placeholder="e.g., gauss(1.5, mu0, 0.5)"
+                                    functions=Signal::derive(move || functions.clone())
+                                    variables=Signal::derive(move || constants.clone())
+                                />
+                            }
+                        }
+                    </DemoBlock>
+
+                    <DemoBlock title="Implicit Multiplication and Units" code=r#"<FormulaInput
+    label="Try 2(3+4), 3sin(pi/2) or 2pi^2"
+    implicit_multiplication=true
+/>
+
+<FormulaInput
+    label="Try 5 m / 2 s or 1 km + 500 m"
+    implicit_multiplication=true
+    units=vec![length::meter(), length::kilometer(), time::second(), time::hour()]
+/>"#>
+                        <FormulaInput
+                            label="Try 2(3+4), 3sin(pi/2) or 2pi^2"
+                            implicit_multiplication=true
+                        />
+                        <FormulaInput
+                            label="Try 5 m / 2 s or 1 km + 500 m"
+                            implicit_multiplication=true
+                            units=vec![length::meter(), length::kilometer(), time::second(), time::hour()]
+                        />
+                    </DemoBlock>
+
                     <DemoBlock title="Show Variables" code=r#"<FormulaInput
     label="Formula with variables"
     placeholder="e.g., x^2 + y"
@@ -4831,7 +7042,7 @@ fn equation_editor_doc() -> ComponentDoc {
     ComponentDoc {
         name: "EquationEditor",
         import_name: "EquationEditor",
-        description: "A WYSIWYG mathematical equation editor designed for geometric algebra expressions with support for Amari library operations.",
+        description: "A WYSIWYG mathematical equation editor designed for geometric algebra expressions with support for Amari library operations. Click a sub-expression or use the arrow keys to move the cursor; toolbar operations apply at the cursor and Backspace/Delete remove the selected node.",
         props: vec![
             PropDoc {
                 name: "value",
@@ -4861,6 +7072,13 @@ fn equation_editor_doc() -> ComponentDoc {
                 description: "Show LaTeX output below the equation",
                 required: false,
             },
+            PropDoc {
+                name: "show_mathml",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Render the equation as native MathML instead of the clickable Unicode view",
+                required: false,
+            },
             PropDoc {
                 name: "size",
                 prop_type: "EquationEditorSize",
@@ -4903,6 +7121,34 @@ fn equation_editor_doc() -> ComponentDoc {
                 description: "Placeholder text when empty",
                 required: false,
             },
+            PropDoc {
+                name: "on_validity",
+                prop_type: "Option<Callback<bool>>",
+                default: None,
+                description: "Called with true when no empty placeholder slots remain, false otherwise",
+                required: false,
+            },
+            PropDoc {
+                name: "show_export",
+                prop_type: "bool",
+                default: Some("false"),
+                description: "Show a Copy SVG button that copies the typeset equation",
+                required: false,
+            },
+            PropDoc {
+                name: "export_options",
+                prop_type: "SvgExportOptions",
+                default: Some("None"),
+                description: "Font size, padding and colors used for export",
+                required: false,
+            },
+            PropDoc {
+                name: "on_export",
+                prop_type: "Callback<String>",
+                default: Some("None"),
+                description: "Receives the exported SVG markup",
+                required: false,
+            },
         ],
         demo: || {
             view! {
@@ -159,6 +159,11 @@ pub fn get_navigation() -> Vec<NavSection> {
                     href: "/form/checkbox",
                     badge: None,
                 },
+                NavItem {
+                    label: "DatePicker",
+                    href: "/form/date-picker",
+                    badge: Some("New"),
+                },
                 NavItem {
                     label: "FileInput",
                     href: "/form/file-input",
@@ -289,6 +294,11 @@ pub fn get_navigation() -> Vec<NavSection> {
                     href: "/form/textarea",
                     badge: None,
                 },
+                NavItem {
+                    label: "TimePicker",
+                    href: "/form/time-picker",
+                    badge: Some("New"),
+                },
                 NavItem {
                     label: "ParameterSlider",
                     href: "/form/parameter-slider",
@@ -384,6 +394,11 @@ pub fn get_navigation() -> Vec<NavSection> {
                     href: "/data-display/badge",
                     badge: None,
                 },
+                NavItem {
+                    label: "Indicator",
+                    href: "/data-display/indicator",
+                    badge: Some("New"),
+                },
                 NavItem {
                     label: "RingProgress",
                     href: "/data-display/ring-progress",
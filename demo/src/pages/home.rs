@@ -71,11 +71,7 @@ fn App() -> impl IntoView {
                 <NumberInput
                     precision=NumberInputPrecision::U64
                     label="Large Integer"
-                    on_valid_change=Callback::new(move |result| {
-                        if let Ok(val) = result {
-                            value.set(val);
-                        }
-                    })
+                    on_valid_change=Callback::new(move |val: String| value.set(val))
                 />
                 <Text>"Value: " {move || value.get()}</Text>
             </Container>
@@ -46,6 +46,26 @@ let theme = ThemeBuilder::from(presets::industrial())
             />
             <ThemeBuilderDemo />
 
+            // Generated palettes
+            <h2 class="section-title">"Generated Palettes"</h2>
+            <p>
+                <code>"ColorScheme::generate_palette()"</code>
+                " turns one brand color into ten shades, lightest first. The input becomes shade 6, "
+                "the one filled components use. " <code>"generated_color()"</code>
+                " registers the result under a name, so " <code>"color=\"brand\""</code>
+                " works on Button, Badge and the rest."
+            </p>
+            <CodeBlock
+                code=r##"let theme = ThemeBuilder::new()
+    .generated_color("brand", "#5f3dc4")?
+    .primary_color("brand")
+    .build();
+
+// Or just the shades; invalid hex is an error, not a panic
+let shades: [String; 10] = ColorScheme::generate_palette("#5f3dc4")?;"##
+                language="rust"
+            />
+
             // Theme Validation
             <h2 class="section-title">"Theme Validation"</h2>
             <p>
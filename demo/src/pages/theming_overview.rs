@@ -85,6 +85,19 @@ fn ThemeControls() -> impl IntoView {
                 language="rust"
             />
 
+            <p>
+                "To remember the user's choice across page loads, give "
+                <code>"MingotProvider"</code> " a " <code>"persist_key"</code>
+                ". The mode is saved to " <code>"localStorage"</code>
+                " under that key whenever it changes and restored on the next visit:"
+            </p>
+            <CodeBlock
+                code=r#"<MingotProvider persist_key="my-app-color-scheme">
+    // Light/Dark/Auto survives reloads
+</MingotProvider>"#
+                language="rust"
+            />
+
             // ThemeOverride section
             <h2 class="section-title">"ThemeOverride"</h2>
             <p>
@@ -113,6 +126,166 @@ view! {
                 language="rust"
             />
 
+            // Component defaults section
+            <h2 class="section-title">"Component Defaults"</h2>
+            <p>
+                "Pass a " <code>"MingotConfig"</code> " to " <code>"MingotProvider"</code>
+                " to set default sizes, variants and the " <code>"NumberInput"</code>
+                " locale once for the whole app. Precedence is: explicit prop > config default > "
+                "built-in default. Read the config in your own components with "
+                <code>"use_mingot_config()"</code> "."
+            </p>
+            <CodeBlock
+                code=r#"use mingot::prelude::*;
+
+let config = MingotConfig::default()
+    .input_size(InputSize::Sm)
+    .button_variant(ButtonVariant::Light)
+    .number_input_locale(NumberInputLocale::EU);
+
+view! {
+    <MingotProvider config=config>
+        <Input placeholder="Small by default" />
+        <Input size=InputSize::Lg placeholder="Explicit size wins" />
+        <Button>"Light by default"</Button>
+    </MingotProvider>
+}"#
+                language="rust"
+            />
+
+            // Styles API section
+            <h2 class="section-title">"Styles API"</h2>
+            <p>
+                "Give " <code>"MingotConfig"</code> " a " <code>"StyleOverrides"</code>
+                " map to add CSS to one part of a component everywhere in the app. Keys are "
+                <code>"component.part"</code> "; the override is applied after the built-in "
+                "styles, and a component's own " <code>"style"</code> " prop still wins."
+            </p>
+            <ul>
+                <li><code>"Button"</code> ": " <code>"button.root"</code></li>
+                <li>
+                    <code>"Input"</code> ": " <code>"input.wrapper"</code> ", "
+                    <code>"input.label"</code> ", " <code>"input.input"</code> ", "
+                    <code>"input.description"</code> ", " <code>"input.counter"</code> ", "
+                    <code>"input.error"</code>
+                </li>
+                <li><code>"Card"</code> ": " <code>"card.root"</code> ", " <code>"card.section"</code></li>
+            </ul>
+            <CodeBlock
+                code=r#"use mingot::prelude::*;
+
+let config = MingotConfig::default().styles(
+    StyleOverrides::new()
+        .set("button.root", "padding: 0 2rem; letter-spacing: 0.02em")
+        .set("input.label", "text-transform: uppercase")
+        .set("card.root", "border-left: 4px solid #228be6"),
+);
+
+view! {
+    <MingotProvider config=config>
+        <Button>"Roomier everywhere"</Button>
+    </MingotProvider>
+}"#
+                language="rust"
+            />
+
+            // Focus rings section
+            <h2 class="section-title">"Focus Rings"</h2>
+            <p>
+                <code>"MingotProvider"</code>
+                " tracks whether the user last used the keyboard or a pointer and shows focus "
+                "rings only for keyboard navigation. The ring is drawn from the theme's "
+                <code>"focus_ring"</code> " token; pass " <code>"focus_rings=false"</code>
+                " to opt out. Read the modality with " <code>"use_interaction_modality()"</code> "."
+            </p>
+            <CodeBlock
+                code=r##"use mingot::prelude::*;
+
+let theme = ThemeBuilder::new()
+    .focus_ring_color("#f76707")
+    .focus_ring_width("3px")
+    .build();
+
+view! {
+    <MingotProvider theme=theme>
+        // Tab to the button to see the ring; clicking it shows none
+        <Button>"Focus me"</Button>
+    </MingotProvider>
+}"##
+                language="rust"
+            />
+
+            // Responsive hooks
+            <h2 class="section-title">"Responsive Hooks"</h2>
+            <p>
+                <code>"use_breakpoint"</code> " returns a signal that is " <code>"true"</code>
+                " while the viewport is at least as wide as a theme breakpoint, and "
+                <code>"use_media_query"</code> " does the same for any query. Both update "
+                "as the window is resized, and breakpoints follow theme changes, so layouts "
+                "can switch from Rust:"
+            </p>
+            <BreakpointDemo />
+            <CodeBlock
+                code=r#"use mingot::prelude::*;
+
+#[component]
+fn Layout() -> impl IntoView {
+    let desktop = use_breakpoint(Breakpoint::Md);
+    let landscape = use_media_query("(orientation: landscape)");
+
+    view! {
+        <Show when=move || desktop.get() fallback=|| view! { <Text>"Mobile nav"</Text> }>
+            <Text>"Desktop nav"</Text>
+        </Show>
+    }
+}"#
+                language="rust"
+            />
+
+            // Right-to-left
+            <h2 class="section-title">"Right-to-Left Layouts"</h2>
+            <p>
+                "Set the theme's " <code>"direction"</code> " to " <code>"Direction::Rtl"</code>
+                " for Arabic, Hebrew and other right-to-left languages. "
+                <code>"MingotProvider"</code> " sets " <code>"dir=\"rtl\""</code>
+                " on its root, and components with start/end placements mirror: "
+                <code>"Drawer"</code> " with " <code>"Start"</code> "/" <code>"End"</code>
+                ", " <code>"Divider"</code> " labels, and " <code>"Input"</code>
+                " sections. " <code>"Left"</code> " and " <code>"Right"</code>
+                " positions stay where they say. Read the direction in your own components with "
+                <code>"use_direction()"</code> "."
+            </p>
+            <DemoBlock title="RTL Island">
+                <ThemeOverride direction=Direction::Rtl>
+                    <Stack spacing="sm">
+                        <Divider label="البداية" label_position=DividerLabelPosition::Start />
+                        <Input
+                            placeholder="بحث"
+                            left_section=Box::new(|| view! { <span aria-hidden="true">"⌕"</span> }.into_any())
+                            clearable=true
+                        />
+                    </Stack>
+                </ThemeOverride>
+            </DemoBlock>
+            <CodeBlock
+                code=r#"let theme = ThemeBuilder::new().direction(Direction::Rtl).build();
+
+view! {
+    <MingotProvider theme=theme>
+        // Slides in from the left, the end side in RTL
+        <Drawer opened=opened position=DrawerPosition::End>
+            ...
+        </Drawer>
+    </MingotProvider>
+}
+
+// Or mirror just part of a page
+<ThemeOverride direction=Direction::Rtl>
+    ...
+</ThemeOverride>"#
+                language="rust"
+            />
+
             // CSS Variables Reference
             <h2 class="section-title">"CSS Variables Reference"</h2>
             <p>
@@ -120,6 +293,22 @@ view! {
                 " variables are injected by " <code>"MingotProvider"</code> ":"
             </p>
             <CssVarsTable />
+            <p>
+                "The names are stable across releases, and the values follow the theme "
+                "signal, so plain HTML and external stylesheets stay in sync with "
+                "scheme toggles. To ship them in a static stylesheet instead, use "
+                <code>"Theme::to_css_variables()"</code> ":"
+            </p>
+            <CodeBlock
+                code=r#"let css = format!(":root {{\n{}}}", Theme::default().to_css_variables());
+// :root {
+//   --mingot-background: #ffffff;
+//   ...
+//   --mingot-color-blue-6: #228be6;
+//   ...
+// }"#
+                language="rust"
+            />
         </div>
     }
 }
@@ -168,6 +357,32 @@ fn ColorSchemeDemo() -> impl IntoView {
     }
 }
 
+#[component]
+fn BreakpointDemo() -> impl IntoView {
+    let breakpoints = [
+        ("xs", Breakpoint::Xs),
+        ("sm", Breakpoint::Sm),
+        ("md", Breakpoint::Md),
+        ("lg", Breakpoint::Lg),
+        ("xl", Breakpoint::Xl),
+    ];
+
+    view! {
+        <DemoBlock title="Active Breakpoints">
+            <Group>
+                {breakpoints.into_iter().map(|(name, breakpoint)| {
+                    let active = use_breakpoint(breakpoint);
+                    move || {
+                        let color = if active.get() { "green" } else { "gray" };
+                        view! { <Badge color=color>{name}</Badge> }
+                    }
+                }).collect_view()}
+            </Group>
+            <Text size=TextSize::Sm>"Resize the window to see breakpoints switch."</Text>
+        </DemoBlock>
+    }
+}
+
 #[component]
 fn ThemeOverrideDemo() -> impl IntoView {
     let theme = use_theme();
@@ -238,6 +453,10 @@ fn CssVarsTable() -> impl IntoView {
         ("--mingot-surface-2", "Highest surface"),
         ("--mingot-hover-bg", "Hover background"),
         ("--mingot-text-dimmed", "Secondary/dimmed text"),
+        (
+            "--mingot-color-{name}-{0..9}",
+            "Every palette shade, e.g. --mingot-color-blue-6",
+        ),
         ("--mingot-spacing-xs", "Extra small spacing (0.625rem)"),
         ("--mingot-spacing-sm", "Small spacing (0.75rem)"),
         ("--mingot-spacing-md", "Medium spacing (1rem)"),
@@ -248,6 +467,23 @@ fn CssVarsTable() -> impl IntoView {
         ("--mingot-radius-md", "Medium radius"),
         ("--mingot-radius-lg", "Large radius"),
         ("--mingot-radius-xl", "Extra large radius"),
+        ("--mingot-shadow-xs", "Extra small shadow"),
+        ("--mingot-shadow-sm", "Small shadow"),
+        ("--mingot-shadow-md", "Medium shadow"),
+        ("--mingot-shadow-lg", "Large shadow"),
+        ("--mingot-shadow-xl", "Extra large shadow"),
+        ("--mingot-border-width", "Default border width (1px)"),
+        ("--mingot-border-style", "Default border style (solid)"),
+        ("--mingot-container-{xs..xl}", "Container max widths"),
+        ("--mingot-focus-ring-color", "Keyboard focus ring color"),
+        (
+            "--mingot-focus-ring-width",
+            "Focus ring outline width (2px)",
+        ),
+        (
+            "--mingot-focus-ring-offset",
+            "Focus ring outline offset (2px)",
+        ),
         ("--mingot-font-family", "Primary font family"),
         ("--mingot-font-family-mono", "Monospace font family"),
         ("--mingot-font-size-xs", "Extra small font (0.75rem)"),
@@ -256,6 +492,11 @@ fn CssVarsTable() -> impl IntoView {
         ("--mingot-font-size-lg", "Large font (1.125rem)"),
         ("--mingot-font-size-xl", "Extra large font (1.25rem)"),
         ("--mingot-font-size-xxl", "Display font (2rem)"),
+        ("--mingot-line-height-{xs..xl}", "Line heights (1 to 1.75)"),
+        (
+            "--mingot-font-weight-{normal,medium,semibold,bold}",
+            "Font weights (400 to 700)",
+        ),
     ];
 
     view! {
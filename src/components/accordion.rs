@@ -1,6 +1,7 @@
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
+use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum AccordionVariant {
@@ -9,10 +10,84 @@ pub enum AccordionVariant {
     Separated,
 }
 
+/// Open item values after setting `value` to `open`. Without `multiple`
+/// opening an item closes every other one.
+pub fn set_open_values(current: &[String], value: &str, open: bool, multiple: bool) -> Vec<String> {
+    if !open {
+        return current.iter().filter(|v| *v != value).cloned().collect();
+    }
+    if !multiple {
+        return vec![value.to_string()];
+    }
+    let mut next = current.to_vec();
+    if !next.iter().any(|v| v == value) {
+        next.push(value.to_string());
+    }
+    next
+}
+
+/// Open-item state shared by an `Accordion` and its items. Reads and
+/// writes the caller's `value` signal when controlled, an internal one
+/// otherwise.
+#[derive(Clone, Copy)]
+struct AccordionState {
+    open: RwSignal<Vec<String>>,
+    multiple: bool,
+    on_change: Option<Callback<Vec<String>>>,
+}
+
+impl AccordionState {
+    fn new(
+        value: Option<RwSignal<Vec<String>>>,
+        multiple: bool,
+        on_change: Option<Callback<Vec<String>>>,
+    ) -> Self {
+        Self {
+            open: value.unwrap_or_else(|| RwSignal::new(Vec::new())),
+            multiple,
+            on_change,
+        }
+    }
+
+    fn is_open(&self, value: &str) -> bool {
+        self.open.with(|open| open.iter().any(|v| v == value))
+    }
+
+    fn set_open(&self, value: &str, open: bool) {
+        let current = self.open.get_untracked();
+        let next = set_open_values(&current, value, open, self.multiple);
+        if next != current {
+            self.open.set(next.clone());
+            if let Some(callback) = self.on_change {
+                callback.run(next);
+            }
+        }
+    }
+
+    fn toggle(&self, value: &str) {
+        let open = self
+            .open
+            .with_untracked(|open| open.iter().any(|v| v == value));
+        self.set_open(value, !open);
+    }
+}
+
+/// Collapsible sections. Open items are tracked by their `value`; pass
+/// `value` to control them (e.g. to open one programmatically or persist
+/// the state), or leave it out for internal state.
 #[component]
 pub fn Accordion(
     #[prop(optional)] variant: Option<AccordionVariant>,
-    #[prop(optional)] multiple: bool,
+    /// Allow several items open at once; otherwise opening one closes
+    /// the rest
+    #[prop(optional)]
+    multiple: bool,
+    /// Values of the open items
+    #[prop(optional)]
+    value: Option<RwSignal<Vec<String>>>,
+    /// Called with the new open values after a user toggle
+    #[prop(optional)]
+    on_change: Option<Callback<Vec<String>>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
@@ -22,7 +97,7 @@ pub fn Accordion(
 
     // Provide context for accordion items
     provide_context::<Signal<AccordionVariant>>(Signal::derive(move || variant));
-    provide_context::<Signal<bool>>(Signal::derive(move || multiple));
+    provide_context(AccordionState::new(value, multiple, on_change));
 
     let accordion_styles = move || {
         let theme_val = theme.get();
@@ -69,11 +144,37 @@ pub fn Accordion(
     }
 }
 
+/// Elements inside an item header that handle their own clicks
+const INTERACTIVE_SELECTOR: &str = "button, a, input, select, textarea, label";
+
+/// Whether a header click landed on (or inside) an interactive element
+fn is_interactive_target(ev: &leptos::ev::MouseEvent) -> bool {
+    event_target::<web_sys::Element>(ev)
+        .closest(INTERACTIVE_SELECTOR)
+        .ok()
+        .flatten()
+        .is_some()
+}
+
 #[component]
 pub fn AccordionItem(
-    #[prop(into)] _value: String,
-    #[prop(into)] label: String,
-    #[prop(optional)] opened: Option<RwSignal<bool>>,
+    /// Key identifying this item in the accordion's open values
+    #[prop(into)]
+    value: String,
+    /// Plain-text header; ignored when `header` is provided
+    #[prop(optional, into)]
+    label: String,
+    /// Custom header content (icons, badges, ...) shown in place of `label`
+    #[prop(optional)]
+    header: Option<Children>,
+    /// Secondary controls aligned to the right of the chevron.
+    /// Clicks inside never toggle the item.
+    #[prop(optional)]
+    controls: Option<Children>,
+    /// Mirrors whether this item is open; setting it opens or closes
+    /// the item
+    #[prop(optional)]
+    opened: Option<RwSignal<bool>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
@@ -81,8 +182,27 @@ pub fn AccordionItem(
     let theme = use_theme();
     let variant = use_context::<Signal<AccordionVariant>>()
         .unwrap_or(Signal::derive(move || AccordionVariant::Default));
+    // A standalone item keeps its own single-item state
+    let state =
+        use_context::<AccordionState>().unwrap_or_else(|| AccordionState::new(None, true, None));
+    let value = StoredValue::new(value);
 
-    let is_opened = opened.unwrap_or_else(|| RwSignal::new(false));
+    let is_opened = Memo::new(move |_| value.with_value(|v| state.is_open(v)));
+
+    if let Some(opened) = opened {
+        Effect::new(move |_| {
+            let wanted = opened.get();
+            if wanted != is_opened.get_untracked() {
+                value.with_value(|v| state.set_open(v, wanted));
+            }
+        });
+        Effect::new(move |_| {
+            let open = is_opened.get();
+            if opened.get_untracked() != open {
+                opened.set(open);
+            }
+        });
+    }
 
     let item_styles = move || {
         let theme_val = theme.get();
@@ -122,6 +242,8 @@ pub fn AccordionItem(
              align-items: center; \
              justify-content: space-between; \
              width: 100%; \
+             box-sizing: border-box; \
+             gap: {}; \
              padding: {} {}; \
              background: none; \
              border: none; \
@@ -131,7 +253,8 @@ pub fn AccordionItem(
              font-weight: {}; \
              color: {}; \
              transition: background-color 0.15s ease;",
-            &*theme_val.spacing.md,
+            &*theme_val.spacing.sm,
+            theme_val.spacing.md,
             theme_val.spacing.lg,
             theme_val.typography.font_sizes.md,
             theme_val.typography.font_weights.medium,
@@ -147,12 +270,17 @@ pub fn AccordionItem(
         )
     };
 
+    // Animating grid rows from 0fr to 1fr follows the content's real
+    // height; visibility keeps closed content out of the tab order
     let panel_styles = move || {
+        let open = is_opened.get();
         format!(
-            "overflow: hidden; \
-             max-height: {}; \
-             transition: max-height 0.2s ease;",
-            if is_opened.get() { "1000px" } else { "0" }
+            "display: grid; \
+             grid-template-rows: {}; \
+             visibility: {}; \
+             transition: grid-template-rows 0.2s ease, visibility 0.2s;",
+            if open { "1fr" } else { "0fr" },
+            if open { "visible" } else { "hidden" }
         )
     };
 
@@ -164,27 +292,271 @@ pub fn AccordionItem(
         )
     };
 
-    let handle_toggle = move |_| {
-        is_opened.update(|opened| *opened = !*opened);
+    let toggle = move || {
+        value.with_value(|v| state.toggle(v));
+    };
+
+    // Buttons, links and inputs inside a custom header keep their own behavior
+    let handle_click = move |ev: leptos::ev::MouseEvent| {
+        if !is_interactive_target(&ev) {
+            toggle();
+        }
+    };
+
+    let handle_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        // Only when the header itself is focused, not a control inside it
+        if ev.target() != ev.current_target() {
+            return;
+        }
+        if ev.key() == "Enter" || ev.key() == " " {
+            ev.prevent_default();
+            toggle();
+        }
     };
 
     let class_str = format!("mingot-accordion-item {}", class.unwrap_or_default());
 
     view! {
         <div class=class_str style=item_styles>
-            <button
+            // A div rather than a <button>, so the header may contain buttons
+            <div
                 class="mingot-accordion-control"
                 style=control_styles
-                on:click=handle_toggle
+                role="button"
+                tabindex="0"
+                aria-expanded=move || is_opened.get().to_string()
+                on:click=handle_click
+                on:keydown=handle_keydown
             >
-                <span>{label}</span>
+                <span class="mingot-accordion-label" style="flex: 1; min-width: 0;">
+                    {match header {
+                        Some(header) => header().into_any(),
+                        None => label.into_any(),
+                    }}
+                </span>
                 <span style=chevron_styles>"▼"</span>
-            </button>
-            <div class="mingot-accordion-panel" style=panel_styles>
-                <div class="mingot-accordion-content" style=content_styles>
-                    {children()}
+                {controls.map(|controls| view! {
+                    <span
+                        class="mingot-accordion-controls"
+                        style="display: flex; align-items: center; gap: 0.25rem;"
+                        on:click=|ev: leptos::ev::MouseEvent| ev.stop_propagation()
+                    >
+                        {controls()}
+                    </span>
+                })}
+            </div>
+            <div
+                class="mingot-accordion-panel"
+                style=panel_styles
+                aria-hidden=move || (!is_opened.get()).to_string()
+            >
+                <div style="min-height: 0; overflow: hidden;">
+                    <div class="mingot-accordion-content" style=content_styles>
+                        {children()}
+                    </div>
                 </div>
             </div>
         </div>
     }
 }
+
+/// One item for [`DataAccordion`]: its value, header label and panel content
+#[derive(Clone)]
+pub struct AccordionItemData {
+    pub value: String,
+    pub label: String,
+    pub icon: Option<String>,
+    pub opened: bool,
+    pub content: Arc<dyn Fn() -> AnyView + Send + Sync>,
+}
+
+impl AccordionItemData {
+    pub fn new<IV>(
+        value: impl Into<String>,
+        label: impl Into<String>,
+        content: impl Fn() -> IV + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+            opened: false,
+            content: Arc::new(move || content().into_any()),
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Start with the item expanded
+    pub fn opened(mut self, opened: bool) -> Self {
+        self.opened = opened;
+        self
+    }
+}
+
+/// Which items start open; without `multiple` only the first requested one
+fn initial_open_states(requested: &[bool], multiple: bool) -> Vec<bool> {
+    let first = requested.iter().position(|&opened| opened);
+    requested
+        .iter()
+        .enumerate()
+        .map(|(i, &opened)| opened && (multiple || Some(i) == first))
+        .collect()
+}
+
+/// `Accordion` built from a list of [`AccordionItemData`] instead of
+/// children. Unless `multiple` is set, only one item is open at a time.
+/// Use `Accordion` with `AccordionItem` children for custom headers or
+/// header controls.
+#[component]
+pub fn DataAccordion(
+    items: Vec<AccordionItemData>,
+    #[prop(optional)] variant: Option<AccordionVariant>,
+    #[prop(optional)] multiple: bool,
+    /// Values of the open items; starts from the items' `opened` flags
+    /// when not given
+    #[prop(optional)]
+    value: Option<RwSignal<Vec<String>>>,
+    #[prop(optional)] on_change: Option<Callback<Vec<String>>>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+) -> impl IntoView {
+    let value = value.unwrap_or_else(|| {
+        let requested: Vec<bool> = items.iter().map(|item| item.opened).collect();
+        let open = initial_open_states(&requested, multiple)
+            .into_iter()
+            .zip(&items)
+            .filter(|(open, _)| *open)
+            .map(|(_, item)| item.value.clone())
+            .collect();
+        RwSignal::new(open)
+    });
+
+    let rendered = items
+        .into_iter()
+        .map(|item| {
+            let content = (item.content)();
+            let icon = item.icon;
+            let label = item.label;
+            let header: Children = Box::new(move || {
+                view! {
+                    <span style="display: inline-flex; align-items: center; gap: 0.5rem;">
+                        {icon.map(|i| view! { <span>{i}</span> })}
+                        <span>{label}</span>
+                    </span>
+                }
+                .into_any()
+            });
+            view! {
+                <AccordionItem value=item.value header=header>
+                    {content}
+                </AccordionItem>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <Accordion
+            variant=variant.unwrap_or(AccordionVariant::Default)
+            multiple=multiple
+            value=value
+            on_change=on_change.unwrap_or_else(|| Callback::new(|_| {}))
+            class=class.unwrap_or_default()
+            style=style.unwrap_or_default()
+        >
+            {rendered}
+        </Accordion>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initial_open_states() {
+        let requested = [false, true, false, true];
+        assert_eq!(
+            initial_open_states(&requested, true),
+            vec![false, true, false, true]
+        );
+        assert_eq!(
+            initial_open_states(&requested, false),
+            vec![false, true, false, false]
+        );
+        assert_eq!(initial_open_states(&[], false), Vec::<bool>::new());
+    }
+
+    fn values(items: &[&str]) -> Vec<String> {
+        items.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_exclusive_open_closes_others() {
+        let open = set_open_values(&values(&["a"]), "b", true, false);
+        assert_eq!(open, values(&["b"]));
+        // Closing the open item leaves nothing open
+        assert!(set_open_values(&open, "b", false, false).is_empty());
+    }
+
+    #[test]
+    fn test_multiple_open_accumulates() {
+        let open = set_open_values(&values(&["a"]), "b", true, true);
+        assert_eq!(open, values(&["a", "b"]));
+        // Opening an already-open item does not duplicate it
+        assert_eq!(set_open_values(&open, "a", true, true), open);
+        assert_eq!(set_open_values(&open, "a", false, true), values(&["b"]));
+    }
+
+    #[test]
+    fn test_uncontrolled_state_tracks_toggles() {
+        let _owner = Owner::new();
+        let changes = RwSignal::new(Vec::<Vec<String>>::new());
+        let state = AccordionState::new(
+            None,
+            false,
+            Some(Callback::new(move |open| changes.update(|c| c.push(open)))),
+        );
+        state.toggle("a");
+        state.toggle("b");
+        assert!(!state.is_open("a"));
+        assert!(state.is_open("b"));
+        assert_eq!(
+            changes.get_untracked(),
+            vec![values(&["a"]), values(&["b"])]
+        );
+    }
+
+    #[test]
+    fn test_controlled_state_uses_caller_signal() {
+        let _owner = Owner::new();
+        let value = RwSignal::new(values(&["b"]));
+        let state = AccordionState::new(Some(value), true, None);
+        assert!(state.is_open("b"));
+
+        state.toggle("a");
+        assert_eq!(value.get_untracked(), values(&["b", "a"]));
+
+        // Programmatic changes are picked up by the items
+        value.set(values(&["c"]));
+        assert!(state.is_open("c"));
+        assert!(!state.is_open("a"));
+    }
+
+    #[test]
+    fn test_accordion_item_data_builder() {
+        let item = AccordionItemData::new("faq", "FAQ", || "Answer")
+            .icon("?")
+            .opened(true);
+        assert_eq!(item.value, "faq");
+        assert_eq!(item.label, "FAQ");
+        assert_eq!(item.icon.as_deref(), Some("?"));
+        assert!(item.opened);
+    }
+}
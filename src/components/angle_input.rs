@@ -1,7 +1,8 @@
 //! AngleInput - High-precision angle entry with multiple unit support
 //!
-//! Supports degrees, radians, gradians, turns, and DMS (degrees-minutes-seconds) format.
-//! Features automatic conversion between units and optional normalization.
+//! Supports degrees, radians, gradians, turns, NATO mils, and DMS
+//! (degrees-minutes-seconds) format. Features automatic conversion between
+//! units, optional normalization, and a compass bearing display.
 
 use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
@@ -9,6 +10,7 @@ use crate::utils::StyleBuilder;
 use leptos::ev;
 use leptos::prelude::*;
 use std::f64::consts::PI;
+use wasm_bindgen::JsCast;
 
 /// Angle unit types
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
@@ -20,6 +22,8 @@ pub enum AngleUnit {
     Turns,
     /// Degrees-Minutes-Seconds format (e.g., 45°30'15")
     DMS,
+    /// NATO angular mils, 6400 per turn
+    Mils,
 }
 
 impl AngleUnit {
@@ -31,6 +35,7 @@ impl AngleUnit {
             AngleUnit::Gradians => " grad",
             AngleUnit::Turns => " turns",
             AngleUnit::DMS => "",
+            AngleUnit::Mils => " mil",
         }
     }
 
@@ -42,6 +47,7 @@ impl AngleUnit {
             AngleUnit::Gradians => "Gradians",
             AngleUnit::Turns => "Turns",
             AngleUnit::DMS => "DMS",
+            AngleUnit::Mils => "Mils",
         }
     }
 }
@@ -58,6 +64,48 @@ pub enum AngleNormalization {
     NegativeTo180,
 }
 
+/// Which way angles are shown in the field
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum AngleConvention {
+    /// Mathematical: 0° is east (the +x axis), increasing counter-clockwise
+    #[default]
+    Math,
+    /// Compass bearing: 0° is north, increasing clockwise, always shown in
+    /// [0°, 360°). The value itself stays in the math convention.
+    Bearing,
+}
+
+/// Convert between a math-convention angle and a compass bearing, in
+/// degrees. The mapping is its own inverse.
+fn flip_bearing(degrees: f64) -> f64 {
+    let flipped = (90.0 - degrees).rem_euclid(360.0);
+    // rem_euclid can round a tiny negative up to exactly 360
+    if flipped >= 360.0 {
+        0.0
+    } else {
+        flipped
+    }
+}
+
+/// The angle shown in the field for a stored (math-convention) angle, and
+/// vice versa; both directions are the same mapping
+fn shown_degrees(degrees: f64, convention: AngleConvention) -> f64 {
+    match convention {
+        AngleConvention::Math => degrees,
+        AngleConvention::Bearing => flip_bearing(degrees),
+    }
+}
+
+/// The 16-wind compass point nearest to a bearing in degrees
+fn compass_point(bearing: f64) -> &'static str {
+    const POINTS: [&str; 16] = [
+        "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW",
+        "NW", "NNW",
+    ];
+    let index = (bearing.rem_euclid(360.0) / 22.5).round() as usize % 16;
+    POINTS[index]
+}
+
 /// Represents a parsed DMS (Degrees-Minutes-Seconds) value
 #[derive(Clone, Debug, PartialEq)]
 pub struct DMS {
@@ -240,6 +288,7 @@ fn to_degrees(value: f64, from_unit: AngleUnit) -> f64 {
         AngleUnit::Radians => value * 180.0 / PI,
         AngleUnit::Gradians => value * 0.9, // 360/400
         AngleUnit::Turns => value * 360.0,
+        AngleUnit::Mils => value * 360.0 / 6400.0,
     }
 }
 
@@ -250,6 +299,7 @@ fn from_degrees(degrees: f64, to_unit: AngleUnit) -> f64 {
         AngleUnit::Radians => degrees * PI / 180.0,
         AngleUnit::Gradians => degrees / 0.9, // 400/360
         AngleUnit::Turns => degrees / 360.0,
+        AngleUnit::Mils => degrees * 6400.0 / 360.0,
     }
 }
 
@@ -309,6 +359,8 @@ fn parse_angle_to_degrees(input: &str, unit: AngleUnit) -> Option<f64> {
                 .trim_end_matches("grad")
                 .trim_end_matches("turns")
                 .trim_end_matches("turn")
+                .trim_end_matches("mils")
+                .trim_end_matches("mil")
                 .trim();
 
             cleaned.parse::<f64>().ok().map(|v| to_degrees(v, unit))
@@ -316,6 +368,154 @@ fn parse_angle_to_degrees(input: &str, unit: AngleUnit) -> Option<f64> {
     }
 }
 
+/// Default preset angles in degrees
+const DEFAULT_PRESETS: [f64; 7] = [0.0, 30.0, 45.0, 60.0, 90.0, 180.0, 270.0];
+
+/// Round an angle in degrees to the nearest multiple of `step` degrees
+fn snap_degrees(degrees: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return degrees;
+    }
+    (degrees / step).round() * step
+}
+
+/// Parse typed text into the value emitted in degrees: parse, snap, normalize
+fn resolve_angle_input(
+    input: &str,
+    unit: AngleUnit,
+    snap: Option<f64>,
+    normalization: AngleNormalization,
+) -> Option<f64> {
+    let degrees = parse_angle_to_degrees(input, unit)?;
+    let snapped = snap.map_or(degrees, |step| snap_degrees(degrees, step));
+    Some(normalize_degrees(snapped, normalization))
+}
+
+/// Format a number with at most `precision` decimals, dropping trailing zeros
+fn trim_decimals(value: f64, precision: u32) -> String {
+    let formatted = format!("{:.prec$}", value, prec = precision as usize);
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Express an angle in degrees as a multiple of π (e.g. `3π/4`), if it is
+/// one with a small denominator
+fn pi_fraction_label(degrees: f64) -> Option<String> {
+    (1..=12).find_map(|den: i64| {
+        let num = degrees / 180.0 * den as f64;
+        if (num - num.round()).abs() > 1e-9 {
+            return None;
+        }
+        let num = num.round() as i64;
+        let divisor = gcd(num.abs(), den);
+        let (num, den) = (num / divisor, den / divisor);
+        let coefficient = match num {
+            0 => return Some("0".to_string()),
+            1 => String::new(),
+            -1 => "-".to_string(),
+            n => n.to_string(),
+        };
+        Some(if den == 1 {
+            format!("{}π", coefficient)
+        } else {
+            format!("{}π/{}", coefficient, den)
+        })
+    })
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Label for a preset angle (in degrees) in the current display unit
+fn preset_label(degrees: f64, unit: AngleUnit, precision: u32) -> String {
+    match unit {
+        AngleUnit::DMS => DMS::from_degrees(degrees).to_string(),
+        AngleUnit::Radians => pi_fraction_label(degrees).unwrap_or_else(|| {
+            format!(
+                "{}{}",
+                trim_decimals(from_degrees(degrees, unit), precision),
+                unit.suffix()
+            )
+        }),
+        _ => format!(
+            "{}{}",
+            trim_decimals(from_degrees(degrees, unit), precision),
+            unit.suffix()
+        ),
+    }
+}
+
+/// Width and height of the dial in pixels
+const DIAL_SIZE: f64 = 120.0;
+/// Radius of the dial ring, in the dial's own coordinates
+const DIAL_RADIUS: f64 = 48.0;
+
+/// Math-convention angle in [0°, 360°) of a pointer at offset `(dx, dy)`
+/// from the dial centre, in screen coordinates (y grows downward)
+fn pointer_angle(dx: f64, dy: f64) -> f64 {
+    (-dy).atan2(dx).to_degrees().rem_euclid(360.0)
+}
+
+/// Point on a circle of `radius` around the dial centre at a math angle
+fn dial_point(degrees: f64, radius: f64) -> (f64, f64) {
+    let center = DIAL_SIZE / 2.0;
+    let radians = degrees.to_radians();
+    (
+        center + radius * radians.cos(),
+        center - radius * radians.sin(),
+    )
+}
+
+/// Bring `raw` to within half a turn of `previous`, then normalize.
+///
+/// Without normalization this lets a dragged dial keep counting past 360°;
+/// with it, the dial wraps cleanly.
+fn settle_angle(raw: f64, previous: f64, normalization: AngleNormalization) -> f64 {
+    let unwrapped = raw + 360.0 * ((previous - raw) / 360.0).round();
+    normalize_degrees(unwrapped, normalization)
+}
+
+/// New stored angle for a drag to `raw` (math degrees in [0°, 360°)),
+/// snapping in the shown convention so bearings snap to bearings
+fn dial_drag_angle(
+    raw: f64,
+    previous: f64,
+    snap: Option<f64>,
+    convention: AngleConvention,
+    normalization: AngleNormalization,
+) -> f64 {
+    let snapped = match snap {
+        Some(step) => shown_degrees(
+            snap_degrees(shown_degrees(raw, convention), step),
+            convention,
+        ),
+        None => raw,
+    };
+    settle_angle(snapped, previous, normalization)
+}
+
+/// New stored angle after nudging the shown angle by `delta` degrees
+fn nudge_angle(
+    current: f64,
+    delta: f64,
+    convention: AngleConvention,
+    normalization: AngleNormalization,
+) -> f64 {
+    let shown = shown_degrees(current, convention) + delta;
+    settle_angle(shown_degrees(shown, convention), current, normalization)
+}
+
 /// AngleInput component for high-precision angle entry
 #[component]
 pub fn AngleInput(
@@ -339,6 +539,11 @@ pub fn AngleInput(
     #[prop(optional)]
     normalization: Option<AngleNormalization>,
 
+    /// Show angles as mathematical angles or compass bearings. The value
+    /// and `on_change` always use the math convention.
+    #[prop(optional)]
+    convention: AngleConvention,
+
     /// Whether to show unit selector dropdown
     #[prop(default = false)]
     show_unit_selector: bool,
@@ -347,6 +552,24 @@ pub fn AngleInput(
     #[prop(optional)]
     on_unit_change: Option<Callback<AngleUnit>>,
 
+    /// Round entered values to the nearest multiple of this many degrees
+    #[prop(optional)]
+    snap: Option<f64>,
+
+    /// Show a rotary dial beside the field; drag its handle (mouse or
+    /// touch) or use the arrow keys to set the angle. Honors `snap`.
+    #[prop(default = false)]
+    show_dial: bool,
+
+    /// Whether to show a row of preset angle buttons
+    #[prop(default = false)]
+    show_presets: bool,
+
+    /// Preset angles in degrees (default: 0°, 30°, 45°, 60°, 90°, 180°, 270°).
+    /// Setting this also shows the preset row.
+    #[prop(optional)]
+    presets: Option<Vec<f64>>,
+
     /// Input variant styling
     #[prop(optional)]
     variant: Option<InputVariant>,
@@ -398,6 +621,13 @@ pub fn AngleInput(
     // Current display unit (can be changed via selector)
     let current_unit = RwSignal::new(unit);
 
+    // Between the stored (math) angle and the angle shown in the field
+    let to_shown = move |degrees: f64| shown_degrees(degrees, convention);
+    let from_shown = to_shown;
+    let format_shown = move |degrees: f64, unit: AngleUnit| {
+        format_angle_value(from_degrees(to_shown(degrees), unit), unit, precision)
+    };
+
     // Text representation for editing
     let display_text = RwSignal::new(String::new());
 
@@ -408,9 +638,7 @@ pub fn AngleInput(
     Effect::new(move || {
         if !is_editing.get() {
             let degrees = angle_value.get();
-            let unit = current_unit.get();
-            let converted = from_degrees(degrees, unit);
-            display_text.set(format_angle_value(converted, unit, precision));
+            display_text.set(format_shown(degrees, current_unit.get()));
         }
     });
 
@@ -432,8 +660,11 @@ pub fn AngleInput(
         let text = display_text.get();
         let unit = current_unit.get();
 
-        if let Some(degrees) = parse_angle_to_degrees(&text, unit) {
-            let normalized = normalize_degrees(degrees, normalization);
+        // Snap in the shown convention, then normalize the stored angle
+        let resolved = resolve_angle_input(&text, unit, snap, AngleNormalization::None)
+            .map(|shown| normalize_degrees(from_shown(shown), normalization));
+
+        if let Some(normalized) = resolved {
             angle_value.set(normalized);
 
             if let Some(callback) = on_change {
@@ -441,13 +672,10 @@ pub fn AngleInput(
             }
 
             // Update display with formatted value
-            let converted = from_degrees(normalized, unit);
-            display_text.set(format_angle_value(converted, unit, precision));
+            display_text.set(format_shown(normalized, unit));
         } else if !text.is_empty() {
             // Invalid input - revert to previous value
-            let degrees = angle_value.get();
-            let converted = from_degrees(degrees, unit);
-            display_text.set(format_angle_value(converted, unit, precision));
+            display_text.set(format_shown(angle_value.get(), unit));
         }
     };
 
@@ -457,10 +685,9 @@ pub fn AngleInput(
         if old_unit != new_unit {
             current_unit.set(new_unit);
 
-            // Convert display value to new unit
-            let degrees = angle_value.get();
-            let converted = from_degrees(degrees, new_unit);
-            display_text.set(format_angle_value(converted, new_unit, precision));
+            // Reformat from the stored angle, so switching units never
+            // accumulates rounding from the displayed text
+            display_text.set(format_shown(angle_value.get(), new_unit));
 
             if let Some(callback) = on_unit_change {
                 callback.run(new_unit);
@@ -468,6 +695,88 @@ pub fn AngleInput(
         }
     };
 
+    let show_presets = show_presets || presets.is_some();
+    let presets = presets.unwrap_or_else(|| DEFAULT_PRESETS.to_vec());
+
+    // Presets are given in the shown convention
+    let preset_value = move |shown: f64| normalize_degrees(from_shown(shown), normalization);
+    let handle_preset = move |degrees: f64| {
+        if disabled.get() {
+            return;
+        }
+        let normalized = preset_value(degrees);
+        angle_value.set(normalized);
+        if let Some(callback) = on_change {
+            callback.run(normalized);
+        }
+    };
+
+    // Dial: update live while dragging, like typing and presets
+    let is_dragging = RwSignal::new(false);
+    let set_from_dial = move |degrees: f64| {
+        angle_value.set(degrees);
+        if let Some(callback) = on_change {
+            callback.run(degrees);
+        }
+    };
+    let drag_to = move |ev: &ev::PointerEvent| {
+        let Some(dial) = ev
+            .current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        else {
+            return;
+        };
+        let rect = dial.get_bounding_client_rect();
+        let dx = ev.client_x() as f64 - (rect.left() + rect.width() / 2.0);
+        let dy = ev.client_y() as f64 - (rect.top() + rect.height() / 2.0);
+        let degrees = dial_drag_angle(
+            pointer_angle(dx, dy),
+            angle_value.get_untracked(),
+            snap,
+            convention,
+            normalization,
+        );
+        set_from_dial(degrees);
+    };
+    let handle_dial_down = move |ev: ev::PointerEvent| {
+        if disabled.get() {
+            return;
+        }
+        if let Some(dial) = ev
+            .current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+        {
+            // Keep receiving moves when the pointer leaves the dial
+            let _ = dial.set_pointer_capture(ev.pointer_id());
+        }
+        is_dragging.set(true);
+        drag_to(&ev);
+    };
+    let handle_dial_move = move |ev: ev::PointerEvent| {
+        if is_dragging.get_untracked() && !disabled.get() {
+            drag_to(&ev);
+        }
+    };
+    let handle_dial_up = move |_ev: ev::PointerEvent| is_dragging.set(false);
+    let handle_dial_keydown = move |ev: ev::KeyboardEvent| {
+        if disabled.get() {
+            return;
+        }
+        let step = snap.unwrap_or(1.0) * if ev.shift_key() { 10.0 } else { 1.0 };
+        let delta = match ev.key().as_str() {
+            "ArrowUp" | "ArrowRight" => step,
+            "ArrowDown" | "ArrowLeft" => -step,
+            _ => return,
+        };
+        ev.prevent_default();
+        set_from_dial(nudge_angle(
+            angle_value.get_untracked(),
+            delta,
+            convention,
+            normalization,
+        ));
+    };
+
     // Clone error for use in multiple closures
     let error_for_style = error.clone();
     let error_for_display = error.clone();
@@ -551,6 +860,69 @@ pub fn AngleInput(
             .build()
     };
 
+    let presets_row_styles = move || {
+        StyleBuilder::new()
+            .add("display", "flex")
+            .add("flex-wrap", "wrap")
+            .add("gap", "0.25rem")
+            .add("margin-top", "0.375rem")
+            .build()
+    };
+
+    let preset_styles = move |is_active: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let primary_color = scheme_colors
+            .get_color(&theme_val.colors.primary_color, 6)
+            .unwrap_or_else(|| "#228be6".to_string());
+        let border_color = scheme_colors
+            .get_color("gray", 4)
+            .unwrap_or_else(|| "#ced4da".to_string());
+
+        StyleBuilder::new()
+            .add("padding", "0.125rem 0.5rem")
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add("font-family", "inherit")
+            .add("border-radius", &*theme_val.radius.sm)
+            .add(
+                "border",
+                format!(
+                    "1px solid {}",
+                    if is_active {
+                        primary_color.clone()
+                    } else {
+                        border_color
+                    }
+                ),
+            )
+            .add(
+                "background-color",
+                if is_active {
+                    primary_color
+                } else {
+                    "transparent".to_string()
+                },
+            )
+            .add(
+                "color",
+                if is_active {
+                    scheme_colors.white.clone()
+                } else {
+                    scheme_colors.text.clone()
+                },
+            )
+            .add(
+                "cursor",
+                if disabled.get() {
+                    "not-allowed"
+                } else {
+                    "pointer"
+                },
+            )
+            .add_if(disabled.get(), "opacity", "0.6")
+            .build()
+    };
+
     let label_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -596,6 +968,7 @@ pub fn AngleInput(
         AngleUnit::Gradians,
         AngleUnit::Turns,
         AngleUnit::DMS,
+        AngleUnit::Mils,
     ];
 
     view! {
@@ -607,72 +980,204 @@ pub fn AngleInput(
                 </label>
             })}
 
-            <div style=input_wrapper_styles>
-                <input
-                    type="text"
-                    style=input_styles
-                    placeholder=placeholder.clone().unwrap_or_else(|| {
-                        match current_unit.get() {
-                            AngleUnit::DMS => "45°30'15\"".to_string(),
-                            AngleUnit::Degrees => "0.00".to_string(),
-                            AngleUnit::Radians => "0.00".to_string(),
-                            AngleUnit::Gradians => "0.00".to_string(),
-                            AngleUnit::Turns => "0.00".to_string(),
+            <div style=move || {
+                if show_dial {
+                    "display: flex; align-items: center; gap: 0.75rem;"
+                } else {
+                    ""
+                }
+            }>
+                <div style=move || {
+                    if show_dial {
+                        format!("flex: 1; {}", input_wrapper_styles())
+                    } else {
+                        input_wrapper_styles()
+                    }
+                }>
+                    <input
+                        type="text"
+                        style=input_styles
+                        placeholder=placeholder.clone().unwrap_or_else(|| {
+                            match current_unit.get() {
+                                AngleUnit::DMS => "45°30'15\"".to_string(),
+                                AngleUnit::Degrees => "0.00".to_string(),
+                                AngleUnit::Radians => "0.00".to_string(),
+                                AngleUnit::Gradians => "0.00".to_string(),
+                                AngleUnit::Turns => "0.00".to_string(),
+                                AngleUnit::Mils => "0".to_string(),
+                            }
+                        })
+                        prop:value=move || display_text.get()
+                        prop:disabled=move || disabled.get()
+                        on:input=handle_input
+                        on:focus=handle_focus
+                        on:blur=handle_blur
+                    />
+
+                    {move || {
+                        if show_unit_selector {
+                            let current = current_unit.get();
+                            view! {
+                                <select
+                                    style="border: none; background: transparent; cursor: pointer; font-size: inherit; color: inherit; padding: 0 0.25rem;"
+                                    on:change=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        let new_unit = match value.as_str() {
+                                            "degrees" => AngleUnit::Degrees,
+                                            "radians" => AngleUnit::Radians,
+                                            "gradians" => AngleUnit::Gradians,
+                                            "turns" => AngleUnit::Turns,
+                                            "dms" => AngleUnit::DMS,
+                                            "mils" => AngleUnit::Mils,
+                                            _ => AngleUnit::Degrees,
+                                        };
+                                        handle_unit_change(new_unit);
+                                    }
+                                >
+                                    {unit_options.iter().map(|u| {
+                                        let value = match u {
+                                            AngleUnit::Degrees => "degrees",
+                                            AngleUnit::Radians => "radians",
+                                            AngleUnit::Gradians => "gradians",
+                                            AngleUnit::Turns => "turns",
+                                            AngleUnit::DMS => "dms",
+                                            AngleUnit::Mils => "mils",
+                                        };
+                                        let is_selected = *u == current;
+                                        view! {
+                                            <option value=value selected=is_selected>
+                                                {u.suffix()}
+                                            </option>
+                                        }
+                                    }).collect_view()}
+                                </select>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <span style=suffix_styles>
+                                    {move || current_unit.get().suffix()}
+                                </span>
+                            }.into_any()
                         }
-                    })
-                    prop:value=move || display_text.get()
-                    prop:disabled=move || disabled.get()
-                    on:input=handle_input
-                    on:focus=handle_focus
-                    on:blur=handle_blur
-                />
-
-                {move || {
-                    if show_unit_selector {
-                        let current = current_unit.get();
-                        view! {
-                            <select
-                                style="border: none; background: transparent; cursor: pointer; font-size: inherit; color: inherit; padding: 0 0.25rem;"
-                                on:change=move |ev| {
-                                    let value = event_target_value(&ev);
-                                    let new_unit = match value.as_str() {
-                                        "degrees" => AngleUnit::Degrees,
-                                        "radians" => AngleUnit::Radians,
-                                        "gradians" => AngleUnit::Gradians,
-                                        "turns" => AngleUnit::Turns,
-                                        "dms" => AngleUnit::DMS,
-                                        _ => AngleUnit::Degrees,
-                                    };
-                                    handle_unit_change(new_unit);
+                    }}
+
+                    {(convention == AngleConvention::Bearing).then(|| view! {
+                        <span class="mingot-angle-compass" style=suffix_styles>
+                            {move || compass_point(to_shown(angle_value.get()))}
+                        </span>
+                    })}
+                </div>
+
+                {show_dial.then(|| {
+                    let center = DIAL_SIZE / 2.0;
+                    // Minor ticks every 30°, labels at the four quarter turns,
+                    // both placed in the shown convention
+                    let ticks = (0..12)
+                        .map(|i| {
+                            let at = from_shown(i as f64 * 30.0);
+                            let (x1, y1) = dial_point(at, DIAL_RADIUS - 4.0);
+                            let (x2, y2) = dial_point(at, DIAL_RADIUS);
+                            view! {
+                                <line
+                                    x1=x1 y1=y1 x2=x2 y2=y2
+                                    stroke="currentColor" stroke-opacity="0.4" stroke-width="1"
+                                />
+                            }
+                        })
+                        .collect_view();
+                    let labels = move || {
+                        let unit = current_unit.get();
+                        [0.0, 90.0, 180.0, 270.0]
+                            .into_iter()
+                            .map(|shown| {
+                                let (x, y) = dial_point(from_shown(shown), DIAL_RADIUS - 14.0);
+                                view! {
+                                    <text
+                                        x=x y=y
+                                        font-size="8"
+                                        text-anchor="middle"
+                                        dominant-baseline="middle"
+                                        fill="currentColor"
+                                        fill-opacity="0.7"
+                                    >
+                                        {preset_label(shown, unit, precision)}
+                                    </text>
                                 }
-                            >
-                                {unit_options.iter().map(|u| {
-                                    let value = match u {
-                                        AngleUnit::Degrees => "degrees",
-                                        AngleUnit::Radians => "radians",
-                                        AngleUnit::Gradians => "gradians",
-                                        AngleUnit::Turns => "turns",
-                                        AngleUnit::DMS => "dms",
-                                    };
-                                    let is_selected = *u == current;
-                                    view! {
-                                        <option value=value selected=is_selected>
-                                            {u.suffix()}
-                                        </option>
-                                    }
-                                }).collect_view()}
-                            </select>
-                        }.into_any()
-                    } else {
-                        view! {
-                            <span style=suffix_styles>
-                                {move || current_unit.get().suffix()}
-                            </span>
-                        }.into_any()
+                            })
+                            .collect_view()
+                    };
+                    let handle_pos = move || dial_point(angle_value.get(), DIAL_RADIUS);
+                    let accent = move || {
+                        let theme_val = theme.get();
+                        crate::theme::get_scheme_colors(&theme_val)
+                            .get_color(&theme_val.colors.primary_color, 6)
+                            .unwrap_or_else(|| "#228be6".to_string())
+                    };
+                    view! {
+                        <svg
+                            class="mingot-angle-dial"
+                            width=DIAL_SIZE
+                            height=DIAL_SIZE
+                            viewBox=format!("0 0 {} {}", DIAL_SIZE, DIAL_SIZE)
+                            style=move || format!(
+                                "flex-shrink: 0; touch-action: none; user-select: none; cursor: {}; opacity: {};",
+                                if disabled.get() { "not-allowed" } else { "pointer" },
+                                if disabled.get() { "0.6" } else { "1" },
+                            )
+                            on:pointerdown=handle_dial_down
+                            on:pointermove=handle_dial_move
+                            on:pointerup=handle_dial_up
+                            on:pointercancel=handle_dial_up
+                        >
+                            <circle
+                                cx=center cy=center r=DIAL_RADIUS
+                                fill="none" stroke="currentColor" stroke-opacity="0.3" stroke-width="2"
+                            />
+                            {ticks}
+                            {labels}
+                            <line
+                                x1=center y1=center
+                                x2=move || handle_pos().0 y2=move || handle_pos().1
+                                stroke=accent stroke-width="2"
+                            />
+                            <circle cx=center cy=center r="2.5" fill=accent />
+                            <circle
+                                class="mingot-angle-dial-handle"
+                                cx=move || handle_pos().0
+                                cy=move || handle_pos().1
+                                r="7"
+                                fill=accent
+                                stroke="white"
+                                stroke-width="2"
+                                tabindex=move || if disabled.get() { "-1" } else { "0" }
+                                role="slider"
+                                aria-label="Angle"
+                                aria-valuenow=move || to_shown(angle_value.get())
+                                aria-valuetext=move || display_text.get()
+                                on:keydown=handle_dial_keydown
+                            />
+                        </svg>
                     }
-                }}
+                })}
             </div>
 
+            {show_presets.then(|| view! {
+                <div class="mingot-angle-presets" style=presets_row_styles>
+                    {presets.iter().map(|&degrees| view! {
+                        <button
+                            type="button"
+                            style=move || preset_styles(
+                                (preset_value(degrees) - angle_value.get()).abs() < 1e-9
+                            )
+                            prop:disabled=move || disabled.get()
+                            on:click=move |_| handle_preset(degrees)
+                        >
+                            {move || preset_label(degrees, current_unit.get(), precision)}
+                        </button>
+                    }).collect_view()}
+                </div>
+            })}
+
             {description.clone().map(|d| view! {
                 <div style=description_styles>{d}</div>
             })}
@@ -756,6 +1261,111 @@ mod tests {
         assert!((gradians - 100.0).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_mils_conversions() {
+        assert_eq!(from_degrees(90.0, AngleUnit::Mils), 1600.0);
+        assert_eq!(to_degrees(1600.0, AngleUnit::Mils), 90.0);
+        assert_eq!(from_degrees(360.0, AngleUnit::Mils), 6400.0);
+        assert!(
+            (parse_angle_to_degrees("3200 mils", AngleUnit::Mils).unwrap() - 180.0).abs() < 1e-12
+        );
+        assert_eq!(format_angle_value(1600.0, AngleUnit::Mils, 0), "1600");
+    }
+
+    #[test]
+    fn test_switching_mils_and_dms_preserves_degrees() {
+        // Units are reformatted from the stored degrees; the conversions
+        // themselves must round-trip within float precision
+        for degrees in [0.0, 12.345678, 45.5, 90.0, 271.0 + 1.0 / 3.0, -33.25] {
+            let mils = from_degrees(degrees, AngleUnit::Mils);
+            assert!((to_degrees(mils, AngleUnit::Mils) - degrees).abs() < 1e-12);
+            let dms = DMS::from_degrees(from_degrees(degrees, AngleUnit::DMS));
+            assert!((dms.to_degrees() - degrees).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_bearing_convention() {
+        // Math 90° (north) is bearing 0°; math 0° (east) is bearing 90°
+        assert_eq!(flip_bearing(90.0), 0.0);
+        assert_eq!(flip_bearing(0.0), 90.0);
+        // Bearing 270° is due west, i.e. math 180°
+        assert_eq!(flip_bearing(270.0), 180.0);
+        assert_eq!(flip_bearing(180.0), 270.0);
+        assert_eq!(
+            format_angle_value(flip_bearing(180.0), AngleUnit::Degrees, 2),
+            "270.00"
+        );
+        assert_eq!(compass_point(270.0), "W");
+        // Shown in mils as well
+        assert_eq!(from_degrees(flip_bearing(180.0), AngleUnit::Mils), 4800.0);
+        // Always within [0, 360)
+        assert_eq!(flip_bearing(90.0 + 1e-16), 0.0);
+        assert_eq!(flip_bearing(-270.0), 0.0);
+    }
+
+    #[test]
+    fn test_compass_point() {
+        assert_eq!(compass_point(0.0), "N");
+        assert_eq!(compass_point(359.0), "N");
+        assert_eq!(compass_point(45.0), "NE");
+        assert_eq!(compass_point(22.5), "NNE");
+        assert_eq!(compass_point(180.0), "S");
+    }
+
+    #[test]
+    fn test_pointer_angle() {
+        // Screen y grows downward, so "up" is math 90°
+        assert!((pointer_angle(10.0, 0.0) - 0.0).abs() < 1e-9);
+        assert!((pointer_angle(0.0, -10.0) - 90.0).abs() < 1e-9);
+        assert!((pointer_angle(-10.0, 0.0) - 180.0).abs() < 1e-9);
+        assert!((pointer_angle(0.0, 10.0) - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dial_drag_wraps_with_normalization() {
+        use AngleNormalization as N;
+        let math = AngleConvention::Math;
+        // ZeroTo360 wraps cleanly past 360°
+        assert!((dial_drag_angle(5.0, 355.0, None, math, N::ZeroTo360) - 5.0).abs() < 1e-9);
+        // Without normalization the dial keeps counting turns
+        assert!((dial_drag_angle(5.0, 355.0, None, math, N::None) - 365.0).abs() < 1e-9);
+        assert!((dial_drag_angle(350.0, 5.0, None, math, N::None) - -10.0).abs() < 1e-9);
+        assert!(
+            (dial_drag_angle(190.0, 170.0, None, math, N::NegativeTo180) - -170.0).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_dial_drag_snaps() {
+        use AngleNormalization as N;
+        let snapped = dial_drag_angle(47.0, 40.0, Some(15.0), AngleConvention::Math, N::ZeroTo360);
+        assert!((snapped - 45.0).abs() < 1e-9);
+        // Bearings snap in bearing degrees: math 47° is bearing 43°, which
+        // snaps to bearing 40° (math 50°) with a 10° step
+        let snapped = dial_drag_angle(
+            47.0,
+            40.0,
+            Some(10.0),
+            AngleConvention::Bearing,
+            N::ZeroTo360,
+        );
+        assert!((snapped - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_nudge_angle() {
+        use AngleNormalization as N;
+        // Math convention: up is counter-clockwise
+        assert!((nudge_angle(359.0, 2.0, AngleConvention::Math, N::ZeroTo360) - 1.0).abs() < 1e-9);
+        assert!((nudge_angle(359.0, 2.0, AngleConvention::Math, N::None) - 361.0).abs() < 1e-9);
+        // Bearing: up is clockwise, so the math angle decreases
+        assert!(
+            (nudge_angle(90.0, 15.0, AngleConvention::Bearing, N::ZeroTo360) - 75.0).abs() < 1e-9
+        );
+        assert!((nudge_angle(90.0, -1.0, AngleConvention::Bearing, N::None) - 91.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_normalization() {
         // Zero to 360
@@ -795,4 +1405,54 @@ mod tests {
         let dms_degrees = parse_angle_to_degrees("45°30'", AngleUnit::DMS).unwrap();
         assert!((dms_degrees - 45.5).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_snap_degrees() {
+        assert_eq!(snap_degrees(47.0, 15.0), 45.0);
+        assert_eq!(snap_degrees(53.0, 15.0), 60.0);
+        assert_eq!(snap_degrees(-8.0, 15.0), -15.0);
+        assert_eq!(snap_degrees(47.0, 0.0), 47.0);
+    }
+
+    #[test]
+    fn test_resolve_angle_input_with_snap() {
+        let none = AngleNormalization::None;
+        assert_eq!(
+            resolve_angle_input("47", AngleUnit::Degrees, Some(15.0), none),
+            Some(45.0)
+        );
+        assert_eq!(
+            resolve_angle_input("47", AngleUnit::Degrees, None, none),
+            Some(47.0)
+        );
+        // Snapping happens in degrees regardless of the display unit
+        let snapped = resolve_angle_input("0.8", AngleUnit::Radians, Some(15.0), none).unwrap();
+        assert!((snapped - 45.0).abs() < 1e-9);
+        // Snap before normalizing
+        assert_eq!(
+            resolve_angle_input(
+                "358",
+                AngleUnit::Degrees,
+                Some(15.0),
+                AngleNormalization::ZeroTo360
+            ),
+            Some(0.0)
+        );
+        assert_eq!(
+            resolve_angle_input("abc", AngleUnit::Degrees, Some(15.0), none),
+            None
+        );
+    }
+
+    #[test]
+    fn test_preset_labels_follow_unit() {
+        assert_eq!(preset_label(45.0, AngleUnit::Degrees, 2), "45°");
+        assert_eq!(preset_label(45.0, AngleUnit::Radians, 2), "π/4");
+        assert_eq!(preset_label(270.0, AngleUnit::Radians, 2), "3π/2");
+        assert_eq!(preset_label(180.0, AngleUnit::Radians, 2), "π");
+        assert_eq!(preset_label(0.0, AngleUnit::Radians, 2), "0");
+        assert_eq!(preset_label(90.0, AngleUnit::Gradians, 2), "100 grad");
+        assert_eq!(preset_label(45.0, AngleUnit::Turns, 3), "0.125 turns");
+        assert_eq!(preset_label(30.0, AngleUnit::Gradians, 2), "33.33 grad");
+    }
 }
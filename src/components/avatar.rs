@@ -56,9 +56,19 @@ impl AvatarRadius {
     }
 }
 
+/// Image to show: `src`, unless that exact URL already failed to load.
+///
+/// Keeping the failed URL (rather than a flag) means the broken image is
+/// not retried, while a new `src` is attempted straight away.
+fn avatar_image(src: Option<String>, failed: Option<&str>) -> Option<String> {
+    src.filter(|s| Some(s.as_str()) != failed)
+}
+
 #[component]
 pub fn Avatar(
-    #[prop(optional, into)] src: Option<String>,
+    /// Image URL; falls back to `initials` (or a user glyph) if it fails to load
+    #[prop(optional, into)]
+    src: Signal<Option<String>>,
     #[prop(optional, into)] alt: Option<String>,
     #[prop(optional)] size: Option<AvatarSize>,
     #[prop(optional)] radius: Option<AvatarRadius>,
@@ -71,7 +81,8 @@ pub fn Avatar(
     let size = size.unwrap_or(AvatarSize::Md);
     let radius = radius.unwrap_or(AvatarRadius::Full);
     let color = color.unwrap_or_else(|| "blue".to_string());
-    let src_clone = src.clone();
+    let failed_src = RwSignal::new(None::<String>);
+    let image = Memo::new(move |_| failed_src.with(|f| avatar_image(src.get(), f.as_deref())));
 
     let avatar_styles = move || {
         let theme_val = theme.get();
@@ -91,7 +102,7 @@ pub fn Avatar(
             .add("user-select", "none")
             .add("position", "relative");
 
-        if src_clone.is_none() {
+        if image.with(Option::is_none) {
             // Show background color for initials
             let bg_color = scheme_colors
                 .get_color(&color, 6)
@@ -120,23 +131,37 @@ pub fn Avatar(
 
     let class_str = format!("mingot-avatar {}", class.unwrap_or_default());
 
+    let alt = StoredValue::new(alt);
+    let initials = StoredValue::new(initials);
+
     view! {
         <div class=class_str style=avatar_styles>
-            {if let Some(image_src) = src {
+            {move || if let Some(image_src) = image.get() {
+                let failed = image_src.clone();
                 view! {
                     <img
                         src=image_src
-                        alt=alt.unwrap_or_default()
+                        alt=alt.get_value().unwrap_or_default()
                         style=image_styles
+                        on:error=move |_| failed_src.set(Some(failed.clone()))
                     />
                 }.into_any()
-            } else if let Some(text) = initials {
+            } else if let Some(text) = initials.get_value() {
                 view! {
                     <span>{text}</span>
                 }.into_any()
             } else {
                 view! {
-                    <span>"?"</span>
+                    <svg
+                        width="60%"
+                        height="60%"
+                        viewBox="0 0 24 24"
+                        fill="currentColor"
+                        aria-label=alt.get_value()
+                    >
+                        <circle cx="12" cy="8" r="4" />
+                        <path d="M4 20c0-4 3.6-6 8-6s8 2 8 6v1H4z" />
+                    </svg>
                 }.into_any()
             }}
         </div>
@@ -182,3 +207,25 @@ pub fn AvatarGroup(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_falls_back_after_error() {
+        let src = Some("/a.png".to_string());
+        assert_eq!(avatar_image(src.clone(), None), src);
+
+        // The error handler records the failed URL: show the fallback and
+        // don't retry it
+        assert_eq!(avatar_image(src.clone(), Some("/a.png")), None);
+
+        // A new src is attempted despite the earlier failure
+        assert_eq!(
+            avatar_image(Some("/b.png".to_string()), Some("/a.png")),
+            Some("/b.png".to_string())
+        );
+        assert_eq!(avatar_image(None, None), None);
+    }
+}
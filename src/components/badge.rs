@@ -1,5 +1,5 @@
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{format_count, StyleBuilder};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -55,6 +55,23 @@ impl BadgeSize {
             BadgeSize::Xl => "8px",
         }
     }
+
+    /// Diameter of a dot-only badge
+    fn indicator_size(&self) -> &'static str {
+        match self {
+            BadgeSize::Xs => "6px",
+            BadgeSize::Sm => "8px",
+            BadgeSize::Md => "10px",
+            BadgeSize::Lg => "12px",
+            BadgeSize::Xl => "14px",
+        }
+    }
+}
+
+/// Text of a count badge, capped like [`format_count`]; `None` when a
+/// zero count should not render
+pub fn badge_count_label(count: u64, max_count: u64, show_zero: bool) -> Option<String> {
+    (count > 0 || show_zero).then(|| format_count(count, max_count))
 }
 
 #[component]
@@ -66,15 +83,31 @@ pub fn Badge(
     #[prop(optional)] full_width: bool,
     #[prop(optional)] left_section: Option<Children>,
     #[prop(optional)] right_section: Option<Children>,
+    /// Numeric mode: shows the count instead of children
+    #[prop(optional, into)]
+    count: Option<Signal<u64>>,
+    /// Counts above this are shown as e.g. `99+`
+    #[prop(optional)]
+    max_count: Option<u64>,
+    /// Keep a count badge visible at zero
+    #[prop(optional)]
+    show_zero: bool,
+    /// Render only a small dot, e.g. an unread marker
+    #[prop(optional)]
+    dot: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
-    children: Children,
+    #[prop(optional)] children: Option<Children>,
 ) -> impl IntoView {
     let theme = use_theme();
     let variant = variant.unwrap_or(BadgeVariant::Filled);
     let size = size.unwrap_or(BadgeSize::Md);
     let color = color.unwrap_or_else(|| "blue".to_string());
     let color_clone = color.clone();
+    let max_count = max_count.unwrap_or(99);
+    let count_label = move || count.map(|c| badge_count_label(c.get(), max_count, show_zero));
+    // A count badge disappears at zero unless `show_zero`
+    let hidden = move || matches!(count_label(), Some(None));
 
     let badge_styles = move || {
         let theme_val = theme.get();
@@ -135,6 +168,17 @@ pub fn Badge(
             builder.add("border-radius", "9999px");
         }
 
+        if dot {
+            builder
+                .add("padding", "0")
+                .add("width", size.indicator_size())
+                .add("height", size.indicator_size());
+        }
+
+        if hidden() {
+            builder.add("display", "none");
+        }
+
         if full_width {
             builder.add("width", "100%");
         }
@@ -166,6 +210,18 @@ pub fn Badge(
 
     let class_str = format!("mingot-badge {}", class.unwrap_or_default());
 
+    if dot {
+        return view! { <span class=class_str style=badge_styles></span> }.into_any();
+    }
+
+    let content = if count.is_some() {
+        view! { <span>{move || count_label().flatten()}</span> }.into_any()
+    } else if let Some(children) = children {
+        view! { <span>{children()}</span> }.into_any()
+    } else {
+        ().into_any()
+    };
+
     view! {
         <span class=class_str style=badge_styles>
             {if variant == BadgeVariant::Dot {
@@ -178,7 +234,7 @@ pub fn Badge(
             } else {
                 ().into_any()
             }}
-            <span>{children()}</span>
+            {content}
             {if let Some(right) = right_section {
                 view! { <span>{right()}</span> }.into_any()
             } else {
@@ -186,4 +242,23 @@ pub fn Badge(
             }}
         </span>
     }
+    .into_any()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_formatting_at_cap() {
+        assert_eq!(badge_count_label(99, 99, false), Some("99".to_string()));
+        assert_eq!(badge_count_label(100, 99, false), Some("99+".to_string()));
+        assert_eq!(badge_count_label(1000, 9, false), Some("9+".to_string()));
+    }
+
+    #[test]
+    fn test_zero_count() {
+        assert_eq!(badge_count_label(0, 99, false), None);
+        assert_eq!(badge_count_label(0, 99, true), Some("0".to_string()));
+    }
 }
@@ -21,15 +21,57 @@ impl BreadcrumbItem {
     }
 }
 
+/// One entry of a breadcrumb trail after collapsing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BreadcrumbSegment {
+    /// The item at this index of `items`
+    Item(usize),
+    /// Stand-in for the hidden middle items
+    Ellipsis,
+}
+
+/// Segments to render for `len` items. Past `max_items`, everything but
+/// the first `before` and last `after` items becomes one ellipsis; when
+/// that would hide nothing the full trail is kept.
+pub fn collapse_breadcrumbs(
+    len: usize,
+    max_items: Option<usize>,
+    before: usize,
+    after: usize,
+) -> Vec<BreadcrumbSegment> {
+    let all = || (0..len).map(BreadcrumbSegment::Item).collect();
+    match max_items {
+        Some(max) if len > max && before + after < len => {
+            let mut segments: Vec<_> = (0..before).map(BreadcrumbSegment::Item).collect();
+            segments.push(BreadcrumbSegment::Ellipsis);
+            segments.extend((len - after..len).map(BreadcrumbSegment::Item));
+            segments
+        }
+        _ => all(),
+    }
+}
+
 #[component]
 pub fn Breadcrumbs(
     #[prop(into)] items: Vec<BreadcrumbItem>,
     #[prop(optional, into)] separator: Option<String>,
+    /// Collapse the middle items into an ellipsis past this many items
+    #[prop(optional)]
+    max_items: Option<usize>,
+    /// Items kept before the ellipsis
+    #[prop(optional)]
+    items_before_collapse: Option<usize>,
+    /// Items kept after the ellipsis
+    #[prop(optional)]
+    items_after_collapse: Option<usize>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
     let separator = separator.unwrap_or_else(|| "/".to_string());
+    let items_before_collapse = items_before_collapse.unwrap_or(1);
+    let items_after_collapse = items_after_collapse.unwrap_or(1);
+    let expanded = RwSignal::new(false);
 
     let breadcrumbs_styles =
         "display: flex; align-items: center; gap: 0.5rem; flex-wrap: wrap;".to_string();
@@ -76,8 +118,78 @@ pub fn Breadcrumbs(
         )
     };
 
+    let ellipsis_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "font-size: {}; \
+             color: {}; \
+             background: none; \
+             border: none; \
+             padding: 0 0.25rem; \
+             border-radius: {}; \
+             cursor: pointer; \
+             font-family: inherit;",
+            &*theme_val.typography.font_sizes.sm, scheme_colors.text, theme_val.radius.xs
+        )
+    };
+
     let class_str = format!("mingot-breadcrumbs {}", class.unwrap_or_default());
-    let items_len = items.len();
+
+    let segments = move || {
+        let max_items = if expanded.get() { None } else { max_items };
+        collapse_breadcrumbs(
+            items.len(),
+            max_items,
+            items_before_collapse,
+            items_after_collapse,
+        )
+        .into_iter()
+        .enumerate()
+        .map(|(position, segment)| {
+            let separator_view = (position > 0).then(|| {
+                view! {
+                    <span class="mingot-breadcrumb-separator" style=separator_styles>
+                        {separator.clone()}
+                    </span>
+                }
+            });
+            let segment_view = match segment {
+                BreadcrumbSegment::Item(index) => {
+                    let item = items[index].clone();
+                    if let Some(href) = item.href {
+                        view! {
+                            <a href=href style=link_styles>
+                                {item.label}
+                            </a>
+                        }
+                        .into_any()
+                    } else {
+                        view! { <span style=item_styles>{item.label}</span> }.into_any()
+                    }
+                }
+                BreadcrumbSegment::Ellipsis => view! {
+                    <button
+                        type="button"
+                        class="mingot-breadcrumb-ellipsis"
+                        style=ellipsis_styles
+                        aria-label="Show all breadcrumbs"
+                        on:click=move |_| expanded.set(true)
+                    >
+                        "…"
+                    </button>
+                }
+                .into_any(),
+            };
+            view! {
+                <>
+                    {separator_view}
+                    {segment_view}
+                </>
+            }
+        })
+        .collect::<Vec<_>>()
+    };
 
     view! {
         <nav
@@ -92,41 +204,69 @@ pub fn Breadcrumbs(
 
             aria-label="breadcrumb"
         >
-            {items
-                .into_iter()
-                .enumerate()
-                .map(|(index, item)| {
-                    let is_last = index == items_len - 1;
-                    let separator_clone = separator.clone();
-                    view! {
-                        <>
-                            {if let Some(href) = item.href {
-                                view! {
-                                    <a href=href style=link_styles>
-                                        {item.label}
-                                    </a>
-                                }
-                                    .into_any()
-                            } else {
-                                view! { <span style=item_styles>{item.label}</span> }.into_any()
-                            }}
-
-                            {if !is_last {
-                                view! {
-                                    <span class="mingot-breadcrumb-separator" style=separator_styles>
-                                        {separator_clone}
-                                    </span>
-                                }
-                                    .into_any()
-                            } else {
-                                ().into_any()
-                            }}
-
-                        </>
-                    }
-                })
-                .collect::<Vec<_>>()}
+            {segments}
 
         </nav>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use BreadcrumbSegment::{Ellipsis, Item};
+
+    #[test]
+    fn test_no_collapse_without_max() {
+        assert_eq!(
+            collapse_breadcrumbs(3, None, 1, 1),
+            vec![Item(0), Item(1), Item(2)]
+        );
+        assert!(collapse_breadcrumbs(0, Some(2), 1, 1).is_empty());
+    }
+
+    #[test]
+    fn test_no_collapse_within_max() {
+        assert_eq!(
+            collapse_breadcrumbs(4, Some(4), 1, 1),
+            vec![Item(0), Item(1), Item(2), Item(3)]
+        );
+    }
+
+    #[test]
+    fn test_collapses_middle_past_max() {
+        assert_eq!(
+            collapse_breadcrumbs(6, Some(4), 1, 1),
+            vec![Item(0), Ellipsis, Item(5)]
+        );
+        assert_eq!(
+            collapse_breadcrumbs(8, Some(5), 2, 2),
+            vec![Item(0), Item(1), Ellipsis, Item(6), Item(7)]
+        );
+    }
+
+    #[test]
+    fn test_collapse_with_zero_before_or_after() {
+        assert_eq!(
+            collapse_breadcrumbs(5, Some(3), 0, 2),
+            vec![Ellipsis, Item(3), Item(4)]
+        );
+        assert_eq!(
+            collapse_breadcrumbs(5, Some(3), 1, 0),
+            vec![Item(0), Ellipsis]
+        );
+    }
+
+    #[test]
+    fn test_keeps_everything_when_nothing_would_hide() {
+        // Two before and two after leave nothing to collapse in four items
+        assert_eq!(
+            collapse_breadcrumbs(4, Some(2), 2, 2),
+            vec![Item(0), Item(1), Item(2), Item(3)]
+        );
+        // Exactly one hidden item still collapses
+        assert_eq!(
+            collapse_breadcrumbs(5, Some(2), 2, 2),
+            vec![Item(0), Item(1), Ellipsis, Item(3), Item(4)]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::theme::use_theme;
+use crate::theme::{use_mingot_config, use_theme};
 use crate::utils::StyleBuilder;
 use leptos::ev;
 use leptos::prelude::*;
@@ -39,11 +39,15 @@ pub fn Button(
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(ButtonVariant::Filled);
-    let size = size.unwrap_or(ButtonSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.button_variant)
+        .unwrap_or(ButtonVariant::Filled);
+    let size = size.or(config.button_size).unwrap_or(ButtonSize::Md);
     let color = color.unwrap_or_else(|| "blue".to_string());
     let button_type = button_type.unwrap_or_else(|| "button".to_string());
     let is_link = as_.as_ref().map(|s| s == "a").unwrap_or(false);
+    let overrides = config.styles;
 
     let button_styles = move || {
         let theme_val = theme.get();
@@ -166,12 +170,14 @@ pub fn Button(
         // Full width
         builder.add_if(is_full_width, "width", "100%");
 
+        let styles = overrides.apply("button.root", builder.build());
+
         // Custom styles
         if let Some(s) = style.as_ref() {
-            return format!("{}; {}", builder.build(), s);
+            return format!("{}; {}", styles, s);
         }
 
-        builder.build()
+        styles
     };
 
     let handle_click = move |ev: ev::MouseEvent| {
@@ -1,4 +1,4 @@
-use crate::theme::use_theme;
+use crate::theme::{use_mingot_config, use_reduced_motion, use_theme};
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
 
@@ -41,6 +41,9 @@ pub fn Card(
     #[prop(optional, into)] radius: Option<String>,
     #[prop(optional)] with_border: bool,
     #[prop(optional, into)] shadow: Option<String>,
+    /// Shadow to transition to on hover, e.g. `"md"` for clickable cards
+    #[prop(optional, into)]
+    hover_shadow: Option<String>,
     #[prop(optional, into)] as_: Option<String>,
     #[prop(optional, into)] href: Option<String>,
     #[prop(optional, into)] class: Option<String>,
@@ -50,6 +53,9 @@ pub fn Card(
     let theme = use_theme();
     let padding = padding.unwrap_or(CardPadding::Md);
     let is_link = as_.as_ref().map(|s| s == "a").unwrap_or(false);
+    let hovered = RwSignal::new(false);
+    let reduced_motion = hover_shadow.is_some().then(use_reduced_motion);
+    let overrides = use_mingot_config().styles;
 
     let card_styles = move || {
         let theme_val = theme.get();
@@ -60,8 +66,7 @@ pub fn Card(
             .add("background-color", scheme_colors.background.clone())
             .add("padding", padding.value(&theme_val))
             .add("display", "flex")
-            .add("flex-direction", "column")
-            .add("transition", "box-shadow 0.15s ease");
+            .add("flex-direction", "column");
 
         // Add link-specific styles
         if is_link {
@@ -84,31 +89,48 @@ pub fn Card(
             );
         }
 
-        if let Some(s) = shadow.as_ref() {
-            builder.add("box-shadow", s);
-        } else {
-            builder.add("box-shadow", &*theme_val.shadows.sm);
+        let shadow = match hover_shadow.as_ref() {
+            Some(h) if hovered.get() => h.as_str(),
+            _ => shadow.as_deref().unwrap_or("sm"),
+        };
+        builder.add("box-shadow", theme_val.shadows.resolve(shadow));
+
+        if reduced_motion.is_some_and(|r| !r.get()) {
+            builder.add("transition", "box-shadow 0.15s ease");
         }
 
+        let styles = overrides.apply("card.root", builder.build());
+
         if let Some(s) = style.as_ref() {
-            return format!("{}; {}", builder.build(), s);
+            return format!("{}; {}", styles, s);
         }
 
-        builder.build()
+        styles
     };
 
     let class_str = format!("mingot-card {}", class.unwrap_or_default());
 
     if is_link {
         view! {
-            <a href=href.unwrap_or_else(|| "#".to_string()) class=class_str style=card_styles>
+            <a
+                href=href.unwrap_or_else(|| "#".to_string())
+                class=class_str
+                style=card_styles
+                on:mouseenter=move |_| hovered.set(true)
+                on:mouseleave=move |_| hovered.set(false)
+            >
                 {children()}
             </a>
         }
         .into_any()
     } else {
         view! {
-            <div class=class_str style=card_styles>
+            <div
+                class=class_str
+                style=card_styles
+                on:mouseenter=move |_| hovered.set(true)
+                on:mouseleave=move |_| hovered.set(false)
+            >
                 {children()}
             </div>
         }
@@ -126,6 +148,7 @@ pub fn CardSection(
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
+    let overrides = use_mingot_config().styles;
 
     let section_styles = move || {
         let theme_val = theme.get();
@@ -156,11 +179,13 @@ pub fn CardSection(
             );
         }
 
+        let styles = overrides.apply("card.section", builder.build());
+
         if let Some(s) = style.as_ref() {
-            return format!("{}; {}", builder.build(), s);
+            return format!("{}; {}", styles, s);
         }
 
-        builder.build()
+        styles
     };
 
     let class_str = format!("mingot-card-section {}", class.unwrap_or_default());
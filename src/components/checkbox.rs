@@ -12,15 +12,116 @@ pub enum CheckboxSize {
     Xl,
 }
 
+/// The `(name, value)` pair a checkable control adds to its form's
+/// `FormData`, following native checkbox rules: only when named, checked
+/// and enabled. A missing `value` submits `"on"`, as browsers do.
+pub(crate) fn form_entry(
+    name: Option<&str>,
+    value: Option<&str>,
+    checked: bool,
+    disabled: bool,
+) -> Option<(String, String)> {
+    match name {
+        Some(name) if checked && !disabled && !name.is_empty() => {
+            Some((name.to_string(), value.unwrap_or("on").to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Aggregate state of a "select all" parent over its child checkboxes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriState {
+    Checked,
+    Unchecked,
+    /// Some, but not all, children are checked
+    Indeterminate,
+}
+
+impl TriState {
+    /// Parent state for the given children; an empty group is unchecked
+    pub fn from_children(children: &[bool]) -> Self {
+        let checked = children.iter().filter(|&&c| c).count();
+        if checked == 0 {
+            TriState::Unchecked
+        } else if checked == children.len() {
+            TriState::Checked
+        } else {
+            TriState::Indeterminate
+        }
+    }
+
+    /// Value every child takes when the parent is clicked: a fully checked
+    /// group clears, anything else selects all
+    pub fn toggle_target(self) -> bool {
+        self != TriState::Checked
+    }
+}
+
+/// Parent/child selection state returned by [`use_tristate`]
+#[derive(Clone, Copy)]
+pub struct TriStateGroup {
+    children: RwSignal<Vec<bool>>,
+    /// Derived parent state
+    pub state: Memo<TriState>,
+    /// Whether the parent should render as indeterminate
+    pub indeterminate: Signal<bool>,
+}
+
+impl TriStateGroup {
+    /// Check all children, or clear them if all are already checked
+    pub fn toggle(&self) {
+        let target = self.state.get_untracked().toggle_target();
+        self.children
+            .update(|children| children.iter_mut().for_each(|c| *c = target));
+    }
+}
+
+/// "Select all / some / none" logic for a parent checkbox.
+///
+/// Pass the result to a parent `Checkbox` via `tristate`; it then shows
+/// checked/indeterminate from the children and sets them all when clicked.
+///
+/// # Example
+/// ```rust,ignore
+/// let children = RwSignal::new(vec![false, true, false]);
+/// let group = use_tristate(children);
+///
+/// view! {
+///     <Checkbox label="Select all" tristate=group />
+/// }
+/// ```
+pub fn use_tristate(children: RwSignal<Vec<bool>>) -> TriStateGroup {
+    let state = Memo::new(move |_| children.with(|c| TriState::from_children(c)));
+
+    TriStateGroup {
+        children,
+        state,
+        indeterminate: Signal::derive(move || state.get() == TriState::Indeterminate),
+    }
+}
+
 #[component]
 pub fn Checkbox(
     #[prop(optional)] checked: Option<RwSignal<bool>>,
+    /// Show a dash for a partially selected group
+    #[prop(optional, into)]
+    indeterminate: Signal<bool>,
+    /// Act as the "select all" parent of a [`use_tristate`] group
+    #[prop(optional)]
+    tristate: Option<TriStateGroup>,
     #[prop(optional)] size: Option<CheckboxSize>,
     #[prop(optional, into)] color: Option<String>,
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional, into)] description: Option<String>,
     #[prop(optional)] disabled: bool,
     #[prop(optional, into)] error: Option<String>,
+    /// Form field name, so the checkbox is included in native form submission
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// Submitted value when checked; defaults to `"on"`
+    #[prop(optional, into)]
+    value: Option<String>,
     #[prop(optional)] on_change: Option<Callback<bool>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
@@ -29,7 +130,18 @@ pub fn Checkbox(
     let size = size.unwrap_or(CheckboxSize::Md);
     let color = color.unwrap_or_else(|| "blue".to_string());
 
-    let is_checked = checked.unwrap_or_else(|| RwSignal::new(false));
+    let own_checked = checked.unwrap_or_else(|| RwSignal::new(false));
+    // A tristate parent derives its state from the group's children
+    let is_checked = Signal::derive(move || match tristate {
+        Some(group) => group.state.get() == TriState::Checked,
+        None => own_checked.get(),
+    });
+    let indeterminate = Signal::derive(move || match tristate {
+        Some(group) => group.indeterminate.get(),
+        None => indeterminate.get(),
+    });
+    // Indeterminate takes the checked look, with a dash for the mark
+    let is_filled = move || is_checked.get() || indeterminate.get();
 
     let error_clone = error.clone();
 
@@ -67,7 +179,7 @@ pub fn Checkbox(
                 "border",
                 format!(
                     "1px solid {}",
-                    if is_checked.get() {
+                    if is_filled() {
                         &check_color
                     } else {
                         &border_color
@@ -76,7 +188,7 @@ pub fn Checkbox(
             )
             .add(
                 "background-color",
-                if is_checked.get() {
+                if is_filled() {
                     check_color.clone()
                 } else {
                     scheme_colors.background.clone()
@@ -101,6 +213,13 @@ pub fn Checkbox(
     };
 
     let checkmark_styles = move || {
+        if indeterminate.get() {
+            return "display: block; \
+                    width: 0.6em; \
+                    height: 0; \
+                    border-top: 0.15em solid white;"
+                .to_string();
+        }
         let display = if is_checked.get() { "block" } else { "none" };
         format!(
             "display: {}; \
@@ -163,8 +282,17 @@ pub fn Checkbox(
 
     let handle_change = move |_ev: ev::Event| {
         if !disabled {
-            let new_value = !is_checked.get();
-            is_checked.set(new_value);
+            let new_value = match tristate {
+                Some(group) => {
+                    group.toggle();
+                    group.state.get_untracked() == TriState::Checked
+                }
+                None => {
+                    let new_value = !own_checked.get();
+                    own_checked.set(new_value);
+                    new_value
+                }
+            };
             if let Some(callback) = on_change {
                 callback.run(new_value);
             }
@@ -180,7 +308,20 @@ pub fn Checkbox(
                     type="checkbox"
                     class=class_str
                     style=checkbox_styles
-                    checked=move || is_checked.get()
+                    name=name
+                    value=value
+                    // Property, not attribute, so FormData follows the signal
+                    prop:checked=move || is_checked.get()
+                    prop:indeterminate=move || indeterminate.get()
+                    aria-checked=move || {
+                        if indeterminate.get() {
+                            "mixed"
+                        } else if is_checked.get() {
+                            "true"
+                        } else {
+                            "false"
+                        }
+                    }
                     disabled=disabled
                     on:change=handle_change
                 />
@@ -208,3 +349,68 @@ pub fn Checkbox(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_entry() {
+        assert_eq!(
+            form_entry(Some("agree"), Some("yes"), true, false),
+            Some(("agree".to_string(), "yes".to_string()))
+        );
+        assert_eq!(
+            form_entry(Some("agree"), None, true, false),
+            Some(("agree".to_string(), "on".to_string()))
+        );
+        // Unchecked, disabled and unnamed controls are not submitted
+        assert_eq!(form_entry(Some("agree"), None, false, false), None);
+        assert_eq!(form_entry(Some("agree"), None, true, true), None);
+        assert_eq!(form_entry(None, Some("yes"), true, false), None);
+    }
+
+    #[test]
+    fn test_tristate_from_children() {
+        assert_eq!(TriState::from_children(&[]), TriState::Unchecked);
+        assert_eq!(
+            TriState::from_children(&[false, false]),
+            TriState::Unchecked
+        );
+        assert_eq!(
+            TriState::from_children(&[true, false]),
+            TriState::Indeterminate
+        );
+        assert_eq!(TriState::from_children(&[true, true]), TriState::Checked);
+    }
+
+    #[test]
+    fn test_use_tristate_transitions() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let children = RwSignal::new(vec![false, false, false]);
+            let group = use_tristate(children);
+            assert_eq!(group.state.get_untracked(), TriState::Unchecked);
+
+            // Flipping one child makes the parent indeterminate
+            children.update(|c| c[1] = true);
+            assert_eq!(group.state.get_untracked(), TriState::Indeterminate);
+            assert!(group.indeterminate.get_untracked());
+
+            // Toggling from indeterminate selects all
+            group.toggle();
+            assert_eq!(children.get_untracked(), vec![true, true, true]);
+            assert_eq!(group.state.get_untracked(), TriState::Checked);
+
+            // Unchecking one child drops back to indeterminate
+            children.update(|c| c[0] = false);
+            assert_eq!(group.state.get_untracked(), TriState::Indeterminate);
+
+            // Toggling from all checked clears everything
+            children.update(|c| c[0] = true);
+            group.toggle();
+            assert_eq!(children.get_untracked(), vec![false, false, false]);
+            assert_eq!(group.state.get_untracked(), TriState::Unchecked);
+        });
+    }
+}
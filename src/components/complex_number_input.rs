@@ -8,6 +8,7 @@ use leptos::tachys::html::event as ev;
 
 use crate::theme::use_theme;
 use crate::utils::style_builder::StyleBuilder;
+use crate::validation::ValidationError;
 
 /// Display format for complex numbers
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -132,6 +133,28 @@ impl ComplexNumber {
         }
     }
 
+    /// Additive inverse (-z)
+    pub fn negate(&self) -> Self {
+        Self {
+            real: -self.real,
+            imaginary: -self.imaginary,
+        }
+    }
+
+    /// Reciprocal (1/z), or `None` for zero
+    pub fn reciprocal(&self) -> Option<Self> {
+        Self::new(1.0, 0.0).div(self)
+    }
+
+    /// Same angle with magnitude 1 (z/|z|), or `None` for zero
+    pub fn normalize(&self) -> Option<Self> {
+        let magnitude = self.magnitude();
+        (magnitude > 0.0).then(|| Self {
+            real: self.real / magnitude,
+            imaginary: self.imaginary / magnitude,
+        })
+    }
+
     /// Check if this is a real number (imaginary part is zero)
     pub fn is_real(&self) -> bool {
         self.imaginary.abs() < f64::EPSILON
@@ -166,6 +189,50 @@ impl ComplexNumber {
         let angle = self.angle();
         format!("{}·e^({}i)", magnitude, angle)
     }
+
+    /// Parse freeform text in rectangular (`3 - 4i`, `-i`, `5`), polar
+    /// (`5∠53.13°`, `5 < 0.927`) or exponential (`5e^(i*0.927)`, `2e^(iπ/3)`)
+    /// form. Angles without a `°`, `rad` or π use `angle_unit`.
+    pub fn parse(input: &str, angle_unit: PolarAngleUnit) -> Result<Self, ValidationError> {
+        parse_complex(input, angle_unit)
+    }
+
+    /// Format in `format`, rounded to `decimal_places`. The result parses
+    /// back with [`ComplexNumber::parse`] using the same `angle_unit`.
+    pub fn format_as(
+        &self,
+        format: ComplexFormat,
+        angle_unit: PolarAngleUnit,
+        decimal_places: u32,
+    ) -> String {
+        let angle = || match angle_unit {
+            PolarAngleUnit::Degrees => {
+                format!("{}°", format_value(self.angle_degrees(), decimal_places))
+            }
+            PolarAngleUnit::Radians => {
+                format!("{} rad", format_value(self.angle(), decimal_places))
+            }
+        };
+        let magnitude = format_value(self.magnitude(), decimal_places);
+
+        match format {
+            ComplexFormat::Rectangular => {
+                let sign = if self.imaginary.is_sign_negative() {
+                    "-"
+                } else {
+                    "+"
+                };
+                format!(
+                    "{} {} {}i",
+                    format_value(self.real, decimal_places),
+                    sign,
+                    format_value(self.imaginary.abs(), decimal_places)
+                )
+            }
+            ComplexFormat::Polar => format!("{}∠{}", magnitude, angle()),
+            ComplexFormat::Exponential => format!("{}·e^(i{})", magnitude, angle()),
+        }
+    }
 }
 
 impl std::fmt::Display for ComplexNumber {
@@ -174,116 +241,206 @@ impl std::fmt::Display for ComplexNumber {
     }
 }
 
-/// Parse a complex number from string (rectangular form)
-fn parse_rectangular(input: &str) -> Option<ComplexNumber> {
-    let input = input.trim().to_lowercase();
-
-    // Handle pure imaginary: "5i" or "-3i" or "i"
-    if input.ends_with('i') && !input.contains('+') && !input.contains('-') {
-        let imag_str = input.trim_end_matches('i');
-        if imag_str.is_empty() {
-            return Some(ComplexNumber::new(0.0, 1.0));
-        }
-        if let Ok(imag) = imag_str.parse::<f64>() {
-            return Some(ComplexNumber::new(0.0, imag));
-        }
-    }
+/// Rejection for text that is not a complex number in any supported form
+fn invalid_complex() -> ValidationError {
+    ValidationError::custom("Enter a complex number like 3 - 4i, 5∠53.13° or 5e^(i0.927)")
+}
 
-    // Handle "a + bi" or "a - bi" format
-    // Split on + or - but keep the sign with the imaginary part
-    let mut parts = Vec::new();
-    let mut current = String::new();
-    let mut chars = input.chars().peekable();
+/// Lowercase with all whitespace removed, so "3 - 4 i" and "3-4i" match
+fn compact(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
 
-    // Handle leading sign
-    if chars.peek() == Some(&'-') || chars.peek() == Some(&'+') {
-        current.push(chars.next().unwrap());
-    }
+/// Parse a finite real number
+fn parse_real(input: &str) -> Result<f64, ValidationError> {
+    input
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|v| v.is_finite())
+        .ok_or_else(invalid_complex)
+}
 
-    for ch in chars {
-        if (ch == '+' || ch == '-') && !current.is_empty() {
-            parts.push(current.trim().to_string());
-            current = String::from(ch);
-        } else {
-            current.push(ch);
-        }
-    }
-    if !current.is_empty() {
-        parts.push(current.trim().to_string());
+/// Parse a coefficient that may be implicit: "" and "+" mean 1, "-" means -1
+fn parse_coefficient(input: &str) -> Result<f64, ValidationError> {
+    match input.trim_end_matches(['*', '·']) {
+        "" | "+" => Ok(1.0),
+        "-" => Ok(-1.0),
+        coefficient => parse_real(coefficient),
     }
+}
 
-    if parts.is_empty() {
-        return None;
+/// Parse radians written as a number or a multiple of π: `0.927`, `π/3`, `-2pi/3`
+fn parse_radians(input: &str) -> Result<f64, ValidationError> {
+    let (numerator, denominator) = match input.split_once('/') {
+        Some((numerator, denominator)) => (numerator, parse_real(denominator)?),
+        None => (input, 1.0),
+    };
+    if denominator == 0.0 {
+        return Err(invalid_complex());
     }
 
-    let mut real = 0.0;
-    let mut imaginary = 0.0;
+    let numerator = match numerator
+        .strip_suffix('π')
+        .or_else(|| numerator.strip_suffix("pi"))
+    {
+        Some(coefficient) => parse_coefficient(coefficient)? * std::f64::consts::PI,
+        None => parse_real(numerator)?,
+    };
+    Ok(numerator / denominator)
+}
 
-    for part in parts {
-        let part = part.trim();
-        if part.ends_with('i') {
-            // Remove 'i' and all spaces for parsing
-            let imag_str = part.trim_end_matches('i').replace(' ', "");
-            let imag_str = imag_str.trim();
-            if imag_str.is_empty() || imag_str == "+" {
-                imaginary = 1.0;
-            } else if imag_str == "-" {
-                imaginary = -1.0;
-            } else {
-                imaginary = imag_str.parse().ok()?;
-            }
-        } else if !part.is_empty() {
-            // Remove spaces for parsing
-            let real_str = part.replace(' ', "");
-            real = real_str.parse().ok()?;
-        }
+/// Parse an angle (already compacted) into radians. A `°`/`deg` or `rad`
+/// suffix, or a multiple of π, overrides `angle_unit`.
+fn parse_angle(input: &str, angle_unit: PolarAngleUnit) -> Result<f64, ValidationError> {
+    if let Some(degrees) = input
+        .strip_suffix('°')
+        .or_else(|| input.strip_suffix("deg"))
+    {
+        return parse_real(degrees).map(f64::to_radians);
+    }
+    if let Some(radians) = input.strip_suffix("rad") {
+        return parse_radians(radians);
+    }
+    if input.contains('π') || input.contains("pi") {
+        return parse_radians(input);
     }
 
-    Some(ComplexNumber::new(real, imaginary))
+    let value = parse_real(input)?;
+    Ok(match angle_unit {
+        PolarAngleUnit::Degrees => value.to_radians(),
+        PolarAngleUnit::Radians => value,
+    })
 }
 
-/// Parse a complex number from polar form (r∠θ or r<θ)
-fn parse_polar(input: &str, angle_in_degrees: bool) -> Option<ComplexNumber> {
-    let input = input.trim();
-
-    // Find the angle symbol (∠ or <)
-    let parts: Vec<&str> = if input.contains('∠') {
-        input.split('∠').collect()
-    } else if input.contains('<') {
-        input.split('<').collect()
-    } else {
-        return None;
-    };
+/// Parse rectangular form: `3+4i`, `3 - 4i`, `-2-i`, `i`, `5`
+fn parse_rectangular(input: &str) -> Result<ComplexNumber, ValidationError> {
+    let input = compact(input);
+    if input.is_empty() {
+        return Err(invalid_complex());
+    }
 
-    if parts.len() != 2 {
-        return None;
+    // Split into signed terms, keeping exponent signs ("1e-3") in place
+    let mut terms = Vec::new();
+    let mut start = 0;
+    for (idx, ch) in input.char_indices() {
+        if (ch == '+' || ch == '-') && idx > start && !input[..idx].ends_with('e') {
+            terms.push(&input[start..idx]);
+            start = idx;
+        }
+    }
+    terms.push(&input[start..]);
+
+    let mut value = ComplexNumber::default();
+    for term in terms {
+        match term.strip_suffix(['i', 'j']) {
+            // Implicit coefficients: "i" is 0+1i, "-i" is 0-1i
+            Some(coefficient) => value.imaginary += parse_coefficient(coefficient)?,
+            None => value.real += parse_real(term)?,
+        }
     }
 
-    let magnitude: f64 = parts[0].trim().parse().ok()?;
-    let angle_str = parts[1].trim().trim_end_matches(['°', 'd', 'r', 'a']);
-    let angle_val: f64 = angle_str.trim().parse().ok()?;
+    Ok(value)
+}
 
-    let angle_radians = if angle_in_degrees || parts[1].contains('°') || parts[1].contains('d') {
-        angle_val.to_radians()
-    } else {
-        angle_val
-    };
+/// Parse polar form (`5∠53.13°` or `5 < 0.927`)
+fn parse_polar(input: &str, angle_unit: PolarAngleUnit) -> Result<ComplexNumber, ValidationError> {
+    let input = compact(input);
+    let (magnitude, angle) = input
+        .split_once('∠')
+        .or_else(|| input.split_once('<'))
+        .ok_or_else(invalid_complex)?;
+
+    Ok(ComplexNumber::from_polar(
+        parse_real(magnitude)?,
+        parse_angle(angle, angle_unit)?,
+    ))
+}
 
-    Some(ComplexNumber::from_polar(magnitude, angle_radians))
+/// Parse exponential form: `5e^(i*0.927)`, `2e^(iπ/3)`, `5·e^(0.927i)`, `e^(iπ)`
+fn parse_exponential(
+    input: &str,
+    angle_unit: PolarAngleUnit,
+) -> Result<ComplexNumber, ValidationError> {
+    let input = compact(input);
+    let (magnitude, exponent) = input.split_once("e^").ok_or_else(invalid_complex)?;
+    let exponent = exponent
+        .strip_prefix('(')
+        .and_then(|e| e.strip_suffix(')'))
+        .unwrap_or(exponent);
+
+    // The exponent is i times the angle: iθ, i*θ, θi or θ*i
+    let angle = exponent
+        .strip_prefix(['i', 'j'])
+        .map(|a| a.trim_start_matches(['*', '·']))
+        .or_else(|| {
+            exponent
+                .strip_suffix(['i', 'j'])
+                .map(|a| a.trim_end_matches(['*', '·']))
+        })
+        .filter(|a| !a.is_empty())
+        .ok_or_else(invalid_complex)?;
+
+    Ok(ComplexNumber::from_polar(
+        parse_coefficient(magnitude)?,
+        parse_angle(angle, angle_unit)?,
+    ))
 }
 
 /// Parse a complex number from any supported format
-#[allow(dead_code)]
-fn parse_complex(input: &str, angle_in_degrees: bool) -> Option<ComplexNumber> {
-    let input = input.trim();
-
-    // Try polar form first
+fn parse_complex(
+    input: &str,
+    angle_unit: PolarAngleUnit,
+) -> Result<ComplexNumber, ValidationError> {
     if input.contains('∠') || input.contains('<') {
-        return parse_polar(input, angle_in_degrees);
+        parse_polar(input, angle_unit)
+    } else if input.to_lowercase().contains("e^") {
+        parse_exponential(input, angle_unit)
+    } else {
+        parse_rectangular(input)
     }
+}
 
-    // Try rectangular form
-    parse_rectangular(input)
+/// Operations offered by ComplexNumberInput's `show_operations` buttons
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ComplexOperation {
+    Conjugate,
+    Reciprocal,
+    Negate,
+    Normalize,
+}
+
+impl ComplexOperation {
+    const ALL: [ComplexOperation; 4] = [
+        ComplexOperation::Conjugate,
+        ComplexOperation::Reciprocal,
+        ComplexOperation::Negate,
+        ComplexOperation::Normalize,
+    ];
+
+    /// Result of the operation, or `None` if it is undefined for `z`
+    fn apply(self, z: &ComplexNumber) -> Option<ComplexNumber> {
+        match self {
+            ComplexOperation::Conjugate => Some(z.conjugate()),
+            ComplexOperation::Reciprocal => z.reciprocal(),
+            ComplexOperation::Negate => Some(z.negate()),
+            ComplexOperation::Normalize => z.normalize(),
+        }
+    }
+
+    /// Button text and tooltip
+    fn label(self) -> (&'static str, &'static str) {
+        match self {
+            ComplexOperation::Conjugate => ("z̄", "Conjugate"),
+            ComplexOperation::Reciprocal => ("1/z", "Reciprocal"),
+            ComplexOperation::Negate => ("−z", "Negate"),
+            ComplexOperation::Normalize => ("z/|z|", "Normalize to unit magnitude"),
+        }
+    }
 }
 
 /// Format a number with specified decimal places
@@ -339,6 +496,16 @@ pub fn ComplexNumberInput(
     /// Placeholder for imaginary part
     #[prop(default = "Imaginary".into())]
     imaginary_placeholder: String,
+    /// Enter the number as a single line of text (`3 - 4i`, `5∠53.13°`,
+    /// `2e^(iπ/3)`) instead of separate fields
+    #[prop(default = false)]
+    freeform: bool,
+    /// Called with the reason when freeform or pasted text cannot be parsed
+    #[prop(optional, into)]
+    on_parse_error: Option<Callback<ValidationError>>,
+    /// Show conjugate (z̄), reciprocal (1/z), negate and normalize buttons
+    #[prop(default = false)]
+    show_operations: bool,
     /// Additional CSS class
     #[prop(optional, into)]
     class: Option<String>,
@@ -348,7 +515,7 @@ pub fn ComplexNumberInput(
     // Internal state
     let complex_value = RwSignal::new(value.map_or(default_value, |v| v.get()));
     let current_format = RwSignal::new(format);
-    let has_error = RwSignal::new(false);
+    let input_error = RwSignal::new(None::<String>);
 
     // Input state for rectangular form
     let real_input = RwSignal::new(format_value(complex_value.get().real, decimal_places));
@@ -368,6 +535,17 @@ pub fn ComplexNumberInput(
         decimal_places,
     ));
 
+    // Freeform text, reformatted whenever the value or format changes
+    let freeform_input = RwSignal::new(String::new());
+    let freeform_editing = RwSignal::new(false);
+    Effect::new(move || {
+        let v = complex_value.get();
+        let fmt = current_format.get();
+        if !freeform_editing.get_untracked() {
+            freeform_input.set(v.format_as(fmt, angle_unit, decimal_places));
+        }
+    });
+
     // Sync with external value
     if let Some(ext_value) = value {
         Effect::new(move || {
@@ -400,12 +578,12 @@ pub fn ComplexNumberInput(
                 new_value.angle()
             };
             angle_input.set(format_value(angle_val, decimal_places));
-            has_error.set(false);
+            input_error.set(None);
             if let Some(cb) = on_change {
                 cb.run(new_value);
             }
         } else {
-            has_error.set(true);
+            input_error.set(Some("Invalid input".to_string()));
         }
     };
 
@@ -424,15 +602,85 @@ pub fn ComplexNumberInput(
             complex_value.set(new_value);
             real_input.set(format_value(new_value.real, decimal_places));
             imag_input.set(format_value(new_value.imaginary, decimal_places));
-            has_error.set(false);
+            input_error.set(None);
             if let Some(cb) = on_change {
                 cb.run(new_value);
             }
         } else {
-            has_error.set(true);
+            input_error.set(Some("Invalid input".to_string()));
+        }
+    };
+
+    // Commit a parsed value to every representation
+    let set_value = move |new_value: ComplexNumber| {
+        complex_value.set(new_value);
+        real_input.set(format_value(new_value.real, decimal_places));
+        imag_input.set(format_value(new_value.imaginary, decimal_places));
+        magnitude_input.set(format_value(new_value.magnitude(), decimal_places));
+        let angle_val = if angle_unit == PolarAngleUnit::Degrees {
+            new_value.angle_degrees()
+        } else {
+            new_value.angle()
+        };
+        angle_input.set(format_value(angle_val, decimal_places));
+        input_error.set(None);
+        if let Some(cb) = on_change {
+            cb.run(new_value);
+        }
+    };
+
+    let report_parse_error = move |err: ValidationError| {
+        input_error.set(Some(err.message.clone()));
+        if let Some(cb) = on_parse_error {
+            cb.run(err);
+        }
+    };
+
+    // Update value from the freeform text; invalid text stays for correction
+    let update_from_text = move || {
+        freeform_editing.set(false);
+        match ComplexNumber::parse(&freeform_input.get_untracked(), angle_unit) {
+            Ok(new_value) => {
+                set_value(new_value);
+                freeform_input.set(new_value.format_as(
+                    current_format.get_untracked(),
+                    angle_unit,
+                    decimal_places,
+                ));
+            }
+            Err(err) => report_parse_error(err),
+        }
+    };
+
+    // Pasting a whole expression into one of the separate fields fills them all
+    let handle_paste = move |ev: ev::ClipboardEvent| {
+        let Some(text) = ev
+            .clipboard_data()
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return;
+        };
+        // Plain numbers paste into the field as usual
+        if parse_real(&text).is_ok() {
+            return;
+        }
+        ev.prevent_default();
+        match ComplexNumber::parse(&text, angle_unit) {
+            Ok(new_value) => set_value(new_value),
+            Err(err) => report_parse_error(err),
         }
     };
 
+    // Apply an operation button; reciprocal and normalize are disabled at zero
+    let apply_operation =
+        move |operation: ComplexOperation| match operation.apply(&complex_value.get_untracked()) {
+            Some(new_value) => set_value(new_value),
+            None => input_error.set(Some(format!(
+                "{} of zero is undefined",
+                operation.label().1
+            ))),
+        };
+
     // Handle format switch
     let handle_format_switch = move |new_format: ComplexFormat| {
         current_format.set(new_format);
@@ -489,7 +737,7 @@ pub fn ComplexNumberInput(
         };
 
         let has_validation_error =
-            has_error.get() || error_for_display.is_some_and(|e| e.get().is_some());
+            input_error.get().is_some() || error_for_display.is_some_and(|e| e.get().is_some());
         let border_color = if has_validation_error {
             scheme_colors
                 .get_color("red", 6)
@@ -655,8 +903,24 @@ pub fn ComplexNumberInput(
                 </label>
             })}
 
-            {move || match current_format.get() {
-                ComplexFormat::Rectangular => {
+            {move || match (freeform, current_format.get()) {
+                (true, _) => {
+                    view! {
+                        <div style=input_row_styles()>
+                            <input
+                                type="text"
+                                style=input_styles()
+                                prop:value=move || freeform_input.get()
+                                on:input=move |ev: ev::Event| freeform_input.set(event_target_value(&ev))
+                                on:focus=move |_| freeform_editing.set(true)
+                                on:blur=move |_| update_from_text()
+                                placeholder="3 - 4i, 5∠53.13° or 5e^(i0.927)"
+                                disabled=disabled
+                            />
+                        </div>
+                    }.into_any()
+                }
+                (false, ComplexFormat::Rectangular) => {
                     let real_handler = move |ev: ev::Event| {
                         real_input.set(event_target_value(&ev));
                     };
@@ -676,6 +940,7 @@ pub fn ComplexNumberInput(
                                 value=move || real_input.get()
                                 on:input=real_handler
                                 on:blur=blur_handler
+                                on:paste=handle_paste
                                 placeholder=real_placeholder_clone
                                 disabled=disabled
                             />
@@ -686,6 +951,7 @@ pub fn ComplexNumberInput(
                                 value=move || imag_input.get()
                                 on:input=imag_handler
                                 on:blur=blur_handler
+                                on:paste=handle_paste
                                 placeholder=imaginary_placeholder_clone
                                 disabled=disabled
                             />
@@ -693,7 +959,7 @@ pub fn ComplexNumberInput(
                         </div>
                     }.into_any()
                 }
-                ComplexFormat::Polar | ComplexFormat::Exponential => {
+                (false, ComplexFormat::Polar | ComplexFormat::Exponential) => {
                     let mag_handler = move |ev: ev::Event| {
                         magnitude_input.set(event_target_value(&ev));
                     };
@@ -712,6 +978,7 @@ pub fn ComplexNumberInput(
                                 value=move || magnitude_input.get()
                                 on:input=mag_handler
                                 on:blur=blur_handler
+                                on:paste=handle_paste
                                 placeholder="Magnitude"
                                 disabled=disabled
                             />
@@ -722,6 +989,7 @@ pub fn ComplexNumberInput(
                                 value=move || angle_input.get()
                                 on:input=ang_handler
                                 on:blur=blur_handler
+                                on:paste=handle_paste
                                 placeholder="Angle"
                                 disabled=disabled
                             />
@@ -731,6 +999,30 @@ pub fn ComplexNumberInput(
                 }
             }}
 
+            {show_operations.then(|| {
+                view! {
+                    <div class="mingot-complex-operations" style=format_switch_styles>
+                        {ComplexOperation::ALL.into_iter().map(|operation| {
+                            let (text, title) = operation.label();
+                            view! {
+                                <button
+                                    type="button"
+                                    title=title
+                                    aria-label=title
+                                    style=move || format_btn_styles(false)
+                                    on:click=move |_| apply_operation(operation)
+                                    prop:disabled=move || {
+                                        disabled || operation.apply(&complex_value.get()).is_none()
+                                    }
+                                >
+                                    {text}
+                                </button>
+                            }
+                        }).collect_view()}
+                    </div>
+                }
+            })}
+
             {allow_format_switch.then(|| {
                 view! {
                     <div style=format_switch_styles>
@@ -767,11 +1059,9 @@ pub fn ComplexNumberInput(
             })}
 
             {move || {
-                let error_msg = if has_error.get() {
-                    Some("Invalid input".to_string())
-                } else {
-                    error_for_display.and_then(|e| e.get())
-                };
+                let error_msg = input_error
+                    .get()
+                    .or_else(|| error_for_display.and_then(|e| e.get()));
                 error_msg.map(|e| view! {
                     <div style=error_styles()>{e}</div>
                 })
@@ -925,18 +1215,161 @@ mod tests {
 
     #[test]
     fn test_parse_polar() {
-        let c = parse_polar("5∠90°", true).unwrap();
+        let c = parse_polar("5∠90°", PolarAngleUnit::Degrees).unwrap();
         assert!(c.real.abs() < 1e-10);
         assert!((c.imaginary - 5.0).abs() < 1e-10);
     }
 
     #[test]
     fn test_parse_polar_radians() {
-        let c = parse_polar(&format!("1∠{}", PI / 2.0), false).unwrap();
+        let c = parse_polar(&format!("1∠{}", PI / 2.0), PolarAngleUnit::Radians).unwrap();
         assert!(c.real.abs() < 1e-10);
         assert!((c.imaginary - 1.0).abs() < 1e-10);
     }
 
+    // Loose enough for three-digit angles like 0.927 rad
+    fn assert_close(c: ComplexNumber, real: f64, imaginary: f64) {
+        assert!(
+            (c.real - real).abs() < 5e-3 && (c.imaginary - imaginary).abs() < 5e-3,
+            "{c:?} != {real} + {imaginary}i"
+        );
+    }
+
+    #[test]
+    fn test_parse_freeform_rectangular() {
+        let parse = |s| ComplexNumber::parse(s, PolarAngleUnit::Degrees).unwrap();
+        assert_eq!(parse("3+4i"), ComplexNumber::new(3.0, 4.0));
+        assert_eq!(parse("  3 -   4 i "), ComplexNumber::new(3.0, -4.0));
+        assert_eq!(parse("-2-i"), ComplexNumber::new(-2.0, -1.0));
+        assert_eq!(parse("i"), ComplexNumber::new(0.0, 1.0));
+        assert_eq!(parse("-i"), ComplexNumber::new(0.0, -1.0));
+        assert_eq!(parse("5"), ComplexNumber::new(5.0, 0.0));
+        assert_eq!(parse("4i + 3"), ComplexNumber::new(3.0, 4.0));
+        assert_eq!(parse("1e-3 + 2.5e2j"), ComplexNumber::new(0.001, 250.0));
+    }
+
+    #[test]
+    fn test_parse_freeform_polar() {
+        let degrees = |s| ComplexNumber::parse(s, PolarAngleUnit::Degrees).unwrap();
+        let radians = |s| ComplexNumber::parse(s, PolarAngleUnit::Radians).unwrap();
+        assert_close(degrees("5∠53.13°"), 3.0, 4.0);
+        assert_close(degrees("5∠53.13"), 3.0, 4.0);
+        assert_close(radians("5 < 0.927"), 3.0, 4.0);
+        // Explicit units win over angle_unit
+        assert_close(degrees("5 < 0.927 rad"), 3.0, 4.0);
+        assert_close(radians("5∠53.13°"), 3.0, 4.0);
+        assert_close(degrees("2∠π/2"), 0.0, 2.0);
+    }
+
+    #[test]
+    fn test_parse_freeform_exponential() {
+        let degrees = |s| ComplexNumber::parse(s, PolarAngleUnit::Degrees).unwrap();
+        let radians = |s| ComplexNumber::parse(s, PolarAngleUnit::Radians).unwrap();
+        assert_close(radians("5e^(i*0.927)"), 3.0, 4.0);
+        assert_close(radians("5·e^(0.927i)"), 3.0, 4.0);
+        assert_close(degrees("5e^(i53.13°)"), 3.0, 4.0);
+        // Bare angles follow angle_unit
+        assert_close(degrees("5e^(i*90)"), 0.0, 5.0);
+        assert_close(degrees("2e^(iπ/3)"), 1.0, 3f64.sqrt());
+        assert_close(degrees("e^(i pi)"), -1.0, 0.0);
+        assert_close(degrees("-e^(i*pi/2)"), 0.0, -1.0);
+    }
+
+    #[test]
+    fn test_parse_freeform_invalid() {
+        for input in [
+            "", "abc", "3+", "3+4k", "5∠", "5∠x°", "5e^(0.5)", "nan", "1/0",
+        ] {
+            let result = ComplexNumber::parse(input, PolarAngleUnit::Degrees);
+            assert_eq!(
+                result,
+                Err(invalid_complex()),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        let values = [
+            ComplexNumber::new(3.0, 4.0),
+            ComplexNumber::new(-2.0, -1.0),
+            ComplexNumber::new(0.0, 1.0),
+            ComplexNumber::new(1.23456, -0.5),
+            ComplexNumber::from_polar(2.0, PI / 3.0),
+        ];
+        let formats = [
+            ComplexFormat::Rectangular,
+            ComplexFormat::Polar,
+            ComplexFormat::Exponential,
+        ];
+        for unit in [PolarAngleUnit::Degrees, PolarAngleUnit::Radians] {
+            for format in formats {
+                for value in values {
+                    let text = value.format_as(format, unit, 4);
+                    let parsed = ComplexNumber::parse(&text, unit).unwrap();
+                    assert_eq!(
+                        parsed.format_as(format, unit, 4),
+                        text,
+                        "{format:?} {unit:?}"
+                    );
+                }
+            }
+        }
+        assert_eq!(
+            ComplexNumber::new(3.0, -4.0).format_as(
+                ComplexFormat::Rectangular,
+                PolarAngleUnit::Degrees,
+                2
+            ),
+            "3.00 - 4.00i"
+        );
+        assert_eq!(
+            ComplexNumber::new(3.0, 4.0).format_as(
+                ComplexFormat::Polar,
+                PolarAngleUnit::Degrees,
+                2
+            ),
+            "5.00∠53.13°"
+        );
+        assert_eq!(
+            ComplexNumber::new(3.0, 4.0).format_as(
+                ComplexFormat::Exponential,
+                PolarAngleUnit::Radians,
+                3
+            ),
+            "5.000·e^(i0.927 rad)"
+        );
+    }
+
+    #[test]
+    fn test_complex_operations() {
+        let z = ComplexNumber::new(3.0, 4.0);
+        assert_eq!(
+            ComplexOperation::Conjugate.apply(&z),
+            Some(ComplexNumber::new(3.0, -4.0))
+        );
+        assert_eq!(
+            ComplexOperation::Negate.apply(&z),
+            Some(ComplexNumber::new(-3.0, -4.0))
+        );
+        let r = ComplexOperation::Reciprocal.apply(&z).unwrap();
+        assert!((r.real - 0.12).abs() < 1e-10);
+        assert!((r.imaginary + 0.16).abs() < 1e-10);
+        let n = ComplexOperation::Normalize.apply(&z).unwrap();
+        assert!((n.magnitude() - 1.0).abs() < 1e-10);
+        assert!((n.angle() - z.angle()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_complex_operations_at_zero() {
+        let zero = ComplexNumber::default();
+        assert_eq!(ComplexOperation::Reciprocal.apply(&zero), None);
+        assert_eq!(ComplexOperation::Normalize.apply(&zero), None);
+        assert_eq!(ComplexOperation::Conjugate.apply(&zero), Some(zero));
+        assert!(ComplexOperation::Negate.apply(&zero).is_some());
+    }
+
     #[test]
     fn test_complex_display() {
         let c = ComplexNumber::new(3.0, 4.0);
@@ -1,4 +1,7 @@
-use crate::utils::StyleBuilder;
+use crate::theme::use_theme;
+use crate::utils::{
+    add_debug_outline, layout_debug_enabled, LayoutDebugKind, StyleBuilder, LAYOUT_DEBUG_CSS,
+};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -26,11 +29,16 @@ impl ContainerSize {
 pub fn Container(
     #[prop(optional)] size: Option<ContainerSize>,
     #[prop(optional)] fluid: bool,
+    /// Outline this layout and label its spacing, for debugging
+    #[prop(optional)]
+    debug: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let size = size.unwrap_or(ContainerSize::Md);
+    let debug = layout_debug_enabled(debug);
+    let theme = debug.then(use_theme);
 
     let container_styles = move || {
         let mut builder = StyleBuilder::new();
@@ -46,6 +54,10 @@ pub fn Container(
             builder.add("max-width", size.max_width());
         }
 
+        if let Some(theme) = theme {
+            add_debug_outline(&mut builder, LayoutDebugKind::Container, &theme.get());
+        }
+
         if let Some(s) = style.as_ref() {
             return format!("{}; {}", builder.build(), s);
         }
@@ -53,10 +65,16 @@ pub fn Container(
         builder.build()
     };
 
+    let debug_label = debug.then(|| {
+        let width = if fluid { "fluid" } else { size.max_width() };
+        LayoutDebugKind::Container.label(&format!("max {}, padding 1rem", width))
+    });
+
     let class_str = format!("mingot-container {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=container_styles>
+        <div class=class_str style=container_styles data-mingot-debug=debug_label>
+            {debug.then(|| view! { <style>{LAYOUT_DEBUG_CSS}</style> })}
             {children()}
         </div>
     }
@@ -5,7 +5,8 @@
 
 use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{EditHistory, HistoryShortcut, StyleBuilder};
+use leptos::ev;
 use leptos::prelude::*;
 use std::f64::consts::PI;
 
@@ -263,6 +264,15 @@ impl Coordinates {
     }
 }
 
+/// Snap a value to the nearest multiple of `step` (no-op for non-positive steps)
+fn snap_to_step(value: f64, step: f64) -> f64 {
+    if step > 0.0 {
+        (value / step).round() * step
+    } else {
+        value
+    }
+}
+
 /// Format a number for display
 fn format_coord_number(value: f64, precision: usize) -> String {
     format!("{:.prec$}", value, prec = precision)
@@ -295,6 +305,10 @@ pub fn CoordinateInput(
     #[prop(optional, default = 4)]
     precision: usize,
 
+    /// Snap angular components to multiples of this step (in `angle_unit`)
+    #[prop(optional, into)]
+    angle_snap: Option<f64>,
+
     /// Input variant
     #[prop(optional)]
     _variant: Option<InputVariant>,
@@ -318,6 +332,18 @@ pub fn CoordinateInput(
     /// Whether the input is disabled
     #[prop(optional)]
     disabled: Signal<bool>,
+
+    /// Enable undo/redo with Ctrl+Z/Ctrl+Y
+    #[prop(optional, default = true)]
+    enable_undo_redo: bool,
+
+    /// Maximum undo history size
+    #[prop(optional, default = 50)]
+    undo_history_size: usize,
+
+    /// Whether to show undo/redo buttons
+    #[prop(optional, default = false)]
+    show_history_controls: bool,
 ) -> impl IntoView {
     let theme = use_theme();
 
@@ -340,15 +366,77 @@ pub fn CoordinateInput(
         })
         .collect();
 
+    // Undo/redo history; each conversion and each field edit is one step
+    let history = RwSignal::new(EditHistory::new(if enable_undo_redo {
+        undo_history_size
+    } else {
+        0
+    }));
+    let field_edit_recorded = RwSignal::new(false);
+
+    let record_history = move || {
+        history.update(|h| h.record(internal_value.get_untracked()));
+    };
+
+    let coord_inputs_for_history = coord_inputs.clone();
+    let step_history = Callback::new(move |shortcut: HistoryShortcut| {
+        let current = internal_value.get_untracked();
+        let restored = history
+            .try_update(|h| match shortcut {
+                HistoryShortcut::Undo => h.undo(current),
+                HistoryShortcut::Redo => h.redo(current),
+            })
+            .flatten();
+        let Some(coords) = restored else {
+            return;
+        };
+
+        for (i, input) in coord_inputs_for_history.iter().enumerate() {
+            let val = coords.values.get(i).copied().unwrap_or(0.0);
+            let display_val = if coords.system.is_angle(i) {
+                angle_unit.from_radians(val)
+            } else {
+                val
+            };
+            input.set(format_coord_number(display_val, precision));
+        }
+
+        field_edit_recorded.set(false);
+        current_system.set(coords.system);
+        internal_value.set(coords.clone());
+        if let Some(cb) = on_change {
+            cb.run(coords);
+        }
+    });
+
+    let handle_history_keydown = move |ev: ev::KeyboardEvent| {
+        if !enable_undo_redo || disabled.get_untracked() {
+            return;
+        }
+        if let Some(shortcut) =
+            HistoryShortcut::from_key(&ev.key(), ev.ctrl_key() || ev.meta_key(), ev.shift_key())
+        {
+            ev.prevent_default();
+            step_history.run(shortcut);
+        }
+    };
+
     // Update coordinates when inputs change
     let update_coords = move |index: usize, new_value: String| {
         if let Ok(parsed) = new_value.parse::<f64>() {
+            // Snapshot once per edit session, not per keystroke
+            if !field_edit_recorded.get_untracked() {
+                record_history();
+                field_edit_recorded.set(true);
+            }
+
             let sys = current_system.get();
             let mut coords = internal_value.get();
 
             // Convert from display unit to internal (radians for angles)
             let internal_value_num = if sys.is_angle(index) {
-                angle_unit.to_radians(parsed)
+                let snapped = angle_snap.map_or(parsed, |step| snap_to_step(parsed, step));
+                angle_unit.to_radians(snapped)
             } else {
                 parsed
             };
@@ -371,6 +459,8 @@ pub fn CoordinateInput(
 
     // Handle system change
     let change_system = Callback::new(move |new_system: CoordinateSystem| {
+        record_history();
+        field_edit_recorded.set(false);
         let current = internal_value.get();
         let converted = current.convert_to(new_system);
 
@@ -584,7 +674,7 @@ pub fn CoordinateInput(
     ];
 
     view! {
-        <div class="mingot-coordinate-input" style=container_styles>
+        <div class="mingot-coordinate-input" style=container_styles on:keydown=handle_history_keydown>
             {label.clone().map(|l| view! {
                 <label style=label_styles>{l}</label>
             })}
@@ -608,11 +698,24 @@ pub fn CoordinateInput(
                                     style=input_styles
                                     prop:value=move || input_signal.get()
                                     disabled=disabled
+                                    on:focus=move |_| field_edit_recorded.set(false)
                                     on:input=move |ev| {
                                         let val = event_target_value(&ev);
                                         input_signal.set(val.clone());
                                         update_coords(i, val);
                                     }
+                                    on:change=move |ev| {
+                                        // Show the snapped angle once editing is finished
+                                        let val = event_target_value(&ev);
+                                        if let (true, Some(step), Ok(parsed)) =
+                                            (is_angle, angle_snap, val.parse::<f64>())
+                                        {
+                                            input_signal.set(format_coord_number(
+                                                snap_to_step(parsed, step),
+                                                precision,
+                                            ));
+                                        }
+                                    }
                                 />
                                 {is_angle.then(|| view! {
                                     <span style=unit_label_styles>{angle_unit.symbol()}</span>
@@ -643,6 +746,31 @@ pub fn CoordinateInput(
                 }
             })}
 
+            {(enable_undo_redo && show_history_controls).then(|| {
+                view! {
+                    <div style=system_selector_styles>
+                        <button
+                            type="button"
+                            style=move || system_button_styles(false)
+                            title="Undo (Ctrl+Z)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_undo())
+                            on:click=move |_| step_history.run(HistoryShortcut::Undo)
+                        >
+                            {"↶ Undo"}
+                        </button>
+                        <button
+                            type="button"
+                            style=move || system_button_styles(false)
+                            title="Redo (Ctrl+Y)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_redo())
+                            on:click=move |_| step_history.run(HistoryShortcut::Redo)
+                        >
+                            {"↷ Redo"}
+                        </button>
+                    </div>
+                }
+            })}
+
             <div style=preview_styles>
                 {move || {
                     let coords = internal_value.get();
@@ -718,6 +846,14 @@ mod tests {
         assert!((CoordAngleUnit::Degrees.to_radians(180.0) - PI).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_snap_to_step() {
+        assert_eq!(snap_to_step(44.0, 15.0), 45.0);
+        assert_eq!(snap_to_step(37.0, 15.0), 30.0);
+        assert_eq!(snap_to_step(-44.0, 15.0), -45.0);
+        assert_eq!(snap_to_step(44.0, 0.0), 44.0);
+    }
+
     #[test]
     fn test_coordinate_system_labels() {
         assert_eq!(CoordinateSystem::Cartesian2D.labels(), vec!["x", "y"]);
@@ -743,4 +879,21 @@ mod tests {
         assert!((original.values[1] - back.values[1]).abs() < 1e-10);
         assert!((original.values[2] - back.values[2]).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_coordinate_history_undo_conversion_and_edit() {
+        let mut history = EditHistory::new(50);
+        let original = Coordinates::cartesian_2d(3.0, 4.0);
+
+        // Edit a field, then convert to polar
+        history.record(original.clone());
+        let edited = Coordinates::cartesian_2d(6.0, 8.0);
+        history.record(edited.clone());
+        let converted = edited.convert_to(CoordinateSystem::Polar);
+
+        let undone = history.undo(converted).unwrap();
+        assert_eq!(undone, edited);
+        assert_eq!(undone.system, CoordinateSystem::Cartesian2D);
+        assert_eq!(history.undo(undone), Some(original));
+    }
 }
@@ -0,0 +1,912 @@
+//! DatePicker - Calendar date entry built on `Popover`
+//!
+//! Provides a month grid with keyboard navigation, min/max limits and a
+//! text field for typing ISO dates (`YYYY-MM-DD`) directly.
+
+use crate::components::popover::{Popover, PopoverDropdown, PopoverPosition, PopoverTarget};
+use crate::theme::use_theme;
+use crate::utils::{FieldState, StyleBuilder};
+use leptos::ev;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+
+/// A proleptic Gregorian calendar date
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    /// Month of the year, 1-12
+    pub month: u32,
+    /// Day of the month, 1-31
+    pub day: u32,
+}
+
+/// Error parsing a date from text
+#[derive(Clone, Debug, PartialEq)]
+pub enum DateParseError {
+    /// Input is not in `YYYY-MM-DD` form
+    InvalidFormat(String),
+    /// Month or day is outside the calendar, or the year is outside
+    /// [`CalendarDate::MIN`]..=[`CalendarDate::MAX`]
+    OutOfRange(String),
+}
+
+impl std::fmt::Display for DateParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat(s) => write!(f, "Expected a date like 2024-03-15, got '{}'", s),
+            Self::OutOfRange(s) => write!(f, "'{}' is not a calendar date", s),
+        }
+    }
+}
+
+impl CalendarDate {
+    /// Earliest supported date, the start of chrono's `NaiveDate` range
+    pub const MIN: Self = Self {
+        year: -262_143,
+        month: 1,
+        day: 1,
+    };
+
+    /// Latest supported date, the end of chrono's `NaiveDate` range
+    pub const MAX: Self = Self {
+        year: 262_142,
+        month: 12,
+        day: 31,
+    };
+
+    /// Create a date, returning `None` if the month or day is invalid or the
+    /// year is outside [`MIN`](Self::MIN)..=[`MAX`](Self::MAX)
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (Self::MIN.year..=Self::MAX.year).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Today's date: local time in the browser, UTC elsewhere
+    pub fn today() -> Self {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let now = js_sys::Date::new_0();
+            Self {
+                year: now.get_full_year() as i32,
+                month: now.get_month() + 1,
+                day: now.get_date(),
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0);
+            Self::from_days(secs.div_euclid(86_400))
+        }
+    }
+
+    /// Parse an ISO `YYYY-MM-DD` date
+    pub fn parse(input: &str) -> Result<Self, DateParseError> {
+        let trimmed = input.trim();
+        let invalid = || DateParseError::InvalidFormat(trimmed.to_string());
+
+        // Split from the right so negative years keep their sign
+        let mut parts = trimmed.rsplitn(3, '-');
+        let day = parts.next().ok_or_else(invalid)?;
+        let month = parts.next().ok_or_else(invalid)?;
+        let year = parts.next().ok_or_else(invalid)?;
+
+        let year = year.parse::<i32>().map_err(|_| invalid())?;
+        let month = month.parse::<u32>().map_err(|_| invalid())?;
+        let day = day.parse::<u32>().map_err(|_| invalid())?;
+
+        Self::new(year, month, day).ok_or_else(|| DateParseError::OutOfRange(trimmed.to_string()))
+    }
+
+    /// Days since 1970-01-01
+    pub fn to_days(&self) -> i64 {
+        let y = self.year as i64 - i64::from(self.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = self.month as i64;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Date `days` after 1970-01-01
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Day of the week, 0 = Sunday through 6 = Saturday
+    pub fn weekday(&self) -> u32 {
+        (self.to_days() + 4).rem_euclid(7) as u32
+    }
+
+    /// The date `days` later (or earlier, if negative), saturating at
+    /// [`MIN`](Self::MIN) and [`MAX`](Self::MAX)
+    pub fn add_days(&self, days: i64) -> Self {
+        let days = self
+            .to_days()
+            .saturating_add(days)
+            .clamp(Self::MIN.to_days(), Self::MAX.to_days());
+        Self::from_days(days)
+    }
+
+    /// The same day `months` later, clamped to the end of shorter months and
+    /// saturating at the first and last month of the supported range
+    pub fn add_months(&self, months: i32) -> Self {
+        let month_index = |date: Self| date.year as i64 * 12 + date.month as i64 - 1;
+        let index = (month_index(*self) + months as i64)
+            .clamp(month_index(Self::MIN), month_index(Self::MAX));
+        let year = index.div_euclid(12) as i32;
+        let month = index.rem_euclid(12) as u32 + 1;
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Clamp into `[min, max]`, ignoring missing limits
+    pub fn clamp_to(self, min: Option<Self>, max: Option<Self>) -> Self {
+        let date = min.map_or(self, |min| self.max(min));
+        max.map_or(date, |max| date.min(max))
+    }
+
+    /// Convert to a `chrono::NaiveDate`; `None` only for dates built outside
+    /// [`new`](Self::new) with an out-of-range year
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for CalendarDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        Self {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+impl std::fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Whether `year` is a Gregorian leap year
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1-12) of `year`
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// The six weeks shown for a month, starting on `first_day_of_week`
+/// (0 = Sunday). Includes trailing days of the previous month and
+/// leading days of the next.
+pub fn month_grid(year: i32, month: u32, first_day_of_week: u32) -> Vec<CalendarDate> {
+    let first = CalendarDate {
+        year,
+        month,
+        day: 1,
+    };
+    let offset = (first.weekday() + 7 - first_day_of_week % 7) % 7;
+    let start = first.add_days(-(offset as i64));
+    (0..42).map(|i| start.add_days(i)).collect()
+}
+
+/// Date reached from `date` by a calendar navigation key, or `None` if the
+/// key does not move focus. Shift with PageUp/PageDown moves by a year.
+pub fn navigate_date(
+    date: CalendarDate,
+    key: &str,
+    shift: bool,
+    first_day_of_week: u32,
+) -> Option<CalendarDate> {
+    let week_offset = (date.weekday() + 7 - first_day_of_week % 7) % 7;
+    Some(match key {
+        "ArrowLeft" => date.add_days(-1),
+        "ArrowRight" => date.add_days(1),
+        "ArrowUp" => date.add_days(-7),
+        "ArrowDown" => date.add_days(7),
+        "PageUp" => date.add_months(if shift { -12 } else { -1 }),
+        "PageDown" => date.add_months(if shift { 12 } else { 1 }),
+        "Home" => date.add_days(-(week_offset as i64)),
+        "End" => date.add_days(6 - week_offset as i64),
+        _ => return None,
+    })
+}
+
+/// Date entry with a calendar popover.
+///
+/// # Example
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use mingot::prelude::*;
+///
+/// let date = RwSignal::new(CalendarDate::new(2024, 3, 15));
+///
+/// view! {
+///     <DatePicker
+///         value=date
+///         label="Start date"
+///         min_date=CalendarDate::new(2024, 1, 1).unwrap()
+///         on_change=Callback::new(move |d: CalendarDate| {
+///             // With the `chrono` feature: let naive = d.to_naive_date();
+///         })
+///     />
+/// }
+/// ```
+#[component]
+pub fn DatePicker(
+    /// Selected date (controlled)
+    #[prop(optional)]
+    value: Option<RwSignal<Option<CalendarDate>>>,
+
+    /// Callback when a date is picked or typed
+    #[prop(optional)]
+    on_change: Option<Callback<CalendarDate>>,
+
+    /// Value restored by reset; also the starting value when `value` is not set
+    #[prop(optional)]
+    default_value: Option<CalendarDate>,
+
+    /// Receives the field's dirty-tracking and reset handle on mount
+    #[prop(optional)]
+    on_register: Option<Callback<FieldState<Option<CalendarDate>>>>,
+
+    /// Earliest selectable date
+    #[prop(optional, into)]
+    min_date: Option<CalendarDate>,
+
+    /// Latest selectable date
+    #[prop(optional, into)]
+    max_date: Option<CalendarDate>,
+
+    /// First column of the calendar, 0 = Sunday through 6 = Saturday
+    #[prop(default = 0)]
+    first_day_of_week: u32,
+
+    /// Whether the date can be typed as `YYYY-MM-DD`
+    #[prop(default = true)]
+    allow_manual_entry: bool,
+
+    /// Placeholder text
+    #[prop(optional, into)]
+    placeholder: Option<String>,
+
+    /// Whether input is disabled
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Error message
+    #[prop(optional, into)]
+    error: Option<String>,
+
+    /// Whether field is required
+    #[prop(optional)]
+    required: bool,
+
+    /// Label text
+    #[prop(optional, into)]
+    label: Option<String>,
+
+    /// Description text
+    #[prop(optional, into)]
+    description: Option<String>,
+
+    /// Additional CSS class
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Additional inline styles
+    #[prop(optional, into)]
+    style: Option<String>,
+) -> impl IntoView {
+    let theme = use_theme();
+    let selected = value.unwrap_or_else(|| RwSignal::new(default_value));
+    if let Some(callback) = on_register {
+        let default = default_value.or_else(|| selected.get_untracked());
+        let field_state = FieldState::new(selected, default).with_reset(move |default| {
+            selected.set(default);
+            if let (Some(date), Some(callback)) = (default, on_change) {
+                callback.run(date);
+            }
+        });
+        callback.run(field_state);
+    }
+    let opened = RwSignal::new(false);
+    let in_range = move |d: CalendarDate| {
+        min_date.is_none_or(|min| d >= min) && max_date.is_none_or(|max| d <= max)
+    };
+
+    // Day with keyboard focus; its month is the one displayed
+    let focused = RwSignal::new(
+        selected
+            .get_untracked()
+            .unwrap_or_else(CalendarDate::today)
+            .clamp_to(min_date, max_date),
+    );
+    let view_month = Memo::new(move |_| {
+        let d = focused.get();
+        (d.year, d.month)
+    });
+
+    let text = RwSignal::new(String::new());
+    let entry_error = RwSignal::new(None::<String>);
+
+    // Keep the text field and focus in step with the selected value
+    Effect::new(move || {
+        let current = selected.get();
+        text.set(current.map(|d| d.to_string()).unwrap_or_default());
+        if let Some(d) = current {
+            focused.set(d.clamp_to(min_date, max_date));
+        }
+    });
+
+    let grid_ref = NodeRef::<leptos::html::Div>::new();
+    let focus_cell = move |date: CalendarDate| {
+        request_animation_frame(move || {
+            let cell = grid_ref
+                .get_untracked()
+                .and_then(|grid| {
+                    grid.query_selector(&format!("[data-date=\"{}\"]", date))
+                        .ok()
+                        .flatten()
+                })
+                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+            if let Some(cell) = cell {
+                let _ = cell.focus();
+            }
+        });
+    };
+
+    // Move focus into the calendar when it opens
+    Effect::new(move || {
+        if opened.get() {
+            focus_cell(focused.get_untracked());
+        }
+    });
+
+    let select_date = move |date: CalendarDate| {
+        if !in_range(date) || disabled.get_untracked() {
+            return;
+        }
+        selected.set(Some(date));
+        entry_error.set(None);
+        opened.set(false);
+        if let Some(callback) = on_change {
+            callback.run(date);
+        }
+    };
+
+    let handle_grid_keydown = move |ev: ev::KeyboardEvent| {
+        let key = ev.key();
+        match key.as_str() {
+            "Enter" | " " => {
+                ev.prevent_default();
+                select_date(focused.get_untracked());
+            }
+            "Escape" => {
+                ev.prevent_default();
+                opened.set(false);
+            }
+            _ => {
+                let next = navigate_date(
+                    focused.get_untracked(),
+                    &key,
+                    ev.shift_key(),
+                    first_day_of_week,
+                );
+                if let Some(next) = next {
+                    ev.prevent_default();
+                    let next = next.clamp_to(min_date, max_date);
+                    focused.set(next);
+                    focus_cell(next);
+                }
+            }
+        }
+    };
+
+    let commit_text = move || {
+        let input = text.get_untracked();
+        if input.trim().is_empty() {
+            entry_error.set(None);
+            return;
+        }
+        match CalendarDate::parse(&input) {
+            Ok(date) if in_range(date) => {
+                let changed = selected.get_untracked() != Some(date);
+                selected.set(Some(date));
+                entry_error.set(None);
+                if changed {
+                    if let Some(callback) = on_change {
+                        callback.run(date);
+                    }
+                }
+            }
+            Ok(date) => entry_error.set(Some(format!("{} is outside the allowed range", date))),
+            Err(e) => entry_error.set(Some(e.to_string())),
+        }
+    };
+
+    let shift_month = move |months: i32| {
+        focused.update(|d| *d = d.add_months(months).clamp_to(min_date, max_date));
+    };
+
+    // Styles
+    let error_for_style = error.clone();
+    let input_wrapper_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let border_color = if error_for_style.is_some() || entry_error.with(Option::is_some) {
+            scheme_colors
+                .get_color("red", 6)
+                .unwrap_or_else(|| "#fa5252".to_string())
+        } else {
+            scheme_colors.border.clone()
+        };
+
+        StyleBuilder::new()
+            .add("display", "flex")
+            .add("align-items", "center")
+            .add("height", "2.25rem")
+            .add("padding", "0 0.25rem 0 0.75rem")
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("background-color", scheme_colors.background.clone())
+            .add("border", format!("1px solid {}", border_color))
+            .add("border-radius", &*theme_val.radius.sm)
+            .add_if(disabled.get(), "opacity", "0.6")
+            .build()
+    };
+
+    let input_styles = move || {
+        StyleBuilder::new()
+            .add("flex", "1")
+            .add("border", "none")
+            .add("background", "transparent")
+            .add("outline", "none")
+            .add("font-family", "inherit")
+            .add("font-size", "inherit")
+            .add("color", "inherit")
+            .add("min-width", "0")
+            .build()
+    };
+
+    let icon_button_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("border", "none")
+            .add("background", "transparent")
+            .add("cursor", "pointer")
+            .add("padding", "0.25rem 0.5rem")
+            .add("font-size", "inherit")
+            .add("color", scheme_colors.text.clone())
+            .build()
+    };
+
+    let header_styles = move || {
+        let theme_val = theme.get();
+        StyleBuilder::new()
+            .add("display", "flex")
+            .add("align-items", "center")
+            .add("justify-content", "space-between")
+            .add("margin-bottom", &*theme_val.spacing.xs)
+            .add(
+                "font-weight",
+                theme_val.typography.font_weights.semibold.to_string(),
+            )
+            .build()
+    };
+
+    let weekday_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("text-align", "center")
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("gray", 6)
+                    .unwrap_or_else(|| "#868e96".to_string()),
+            )
+            .build()
+    };
+
+    let day_styles = move |date: CalendarDate| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let (year, month) = view_month.get();
+        let is_selected = selected.get() == Some(date);
+        let is_outside = date.year != year || date.month != month;
+        let is_today = date == CalendarDate::today();
+
+        let (background, color) = if is_selected {
+            (
+                scheme_colors
+                    .get_color(&theme_val.colors.primary_color, 6)
+                    .unwrap_or_else(|| "#228be6".to_string()),
+                "#ffffff".to_string(),
+            )
+        } else if is_outside {
+            (
+                "transparent".to_string(),
+                scheme_colors
+                    .get_color("gray", 5)
+                    .unwrap_or_else(|| "#adb5bd".to_string()),
+            )
+        } else {
+            ("transparent".to_string(), scheme_colors.text.clone())
+        };
+
+        StyleBuilder::new()
+            .add("height", "2rem")
+            .add("border", "none")
+            .add("border-radius", &*theme_val.radius.sm)
+            .add("background-color", background)
+            .add("color", color)
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("cursor", "pointer")
+            .add_if(is_today && !is_selected, "font-weight", "700")
+            .add_if(!in_range(date), "opacity", "0.4")
+            .add_if(!in_range(date), "cursor", "not-allowed")
+            .build()
+    };
+
+    let label_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "display: block; margin-bottom: 0.25rem; font-size: {}; font-weight: {}; color: {};",
+            &*theme_val.typography.font_sizes.sm,
+            theme_val.typography.font_weights.medium,
+            scheme_colors.text
+        )
+    };
+
+    let description_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string())
+        )
+    };
+
+    let error_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("red", 6)
+                .unwrap_or_else(|| "#fa5252".to_string())
+        )
+    };
+
+    let class_str = format!("mingot-date-picker {}", class.unwrap_or_default());
+    let weekday_order: Vec<&'static str> = (0..7)
+        .map(|i| WEEKDAY_NAMES[((first_day_of_week + i) % 7) as usize])
+        .collect();
+
+    view! {
+        <div class=class_str style=style.unwrap_or_default()>
+            {label.map(|l| view! {
+                <label style=label_styles>
+                    {l}
+                    {required.then(|| view! { <span style="color: #fa5252; margin-left: 0.25rem;">"*"</span> })}
+                </label>
+            })}
+
+            <Popover opened=opened position=PopoverPosition::Bottom width="16rem" style="display: block;">
+                <div style=input_wrapper_styles>
+                    <input
+                        type="text"
+                        style=input_styles
+                        placeholder=placeholder.unwrap_or_else(|| "YYYY-MM-DD".to_string())
+                        prop:value=move || text.get()
+                        prop:readOnly=!allow_manual_entry
+                        prop:disabled=move || disabled.get()
+                        on:input=move |ev| text.set(event_target_value(&ev))
+                        on:blur=move |_| commit_text()
+                        on:keydown=move |ev: ev::KeyboardEvent| {
+                            if ev.key() == "Enter" {
+                                commit_text();
+                            }
+                        }
+                    />
+                    <PopoverTarget>
+                        <button
+                            type="button"
+                            class="mingot-date-picker-toggle"
+                            style=icon_button_styles
+                            aria-label="Open calendar"
+                            aria-haspopup="dialog"
+                            aria-expanded=move || opened.get().to_string()
+                            disabled=disabled
+                        >
+                            "📅"
+                        </button>
+                    </PopoverTarget>
+                </div>
+
+                <PopoverDropdown>
+                    <div class="mingot-date-picker-calendar" role="dialog" aria-label="Choose date">
+                        <div style=header_styles>
+                            <button
+                                type="button"
+                                style=icon_button_styles
+                                aria-label="Previous month"
+                                on:click=move |_| shift_month(-1)
+                            >
+                                "‹"
+                            </button>
+                            <span aria-live="polite">
+                                {move || {
+                                    let (year, month) = view_month.get();
+                                    format!("{} {}", MONTH_NAMES[month as usize - 1], year)
+                                }}
+                            </span>
+                            <button
+                                type="button"
+                                style=icon_button_styles
+                                aria-label="Next month"
+                                on:click=move |_| shift_month(1)
+                            >
+                                "›"
+                            </button>
+                        </div>
+
+                        <div
+                            node_ref=grid_ref
+                            role="grid"
+                            style="display: grid; grid-template-columns: repeat(7, 1fr); gap: 0.125rem;"
+                            on:keydown=handle_grid_keydown
+                        >
+                            {weekday_order.into_iter().map(|name| view! {
+                                <div role="columnheader" style=weekday_styles>{name}</div>
+                            }).collect_view()}
+
+                            {move || {
+                                let (year, month) = view_month.get();
+                                month_grid(year, month, first_day_of_week)
+                                    .into_iter()
+                                    .map(|date| {
+                                        view! {
+                                            <button
+                                                type="button"
+                                                role="gridcell"
+                                                data-date=date.to_string()
+                                                aria-label=date.to_string()
+                                                aria-selected=move || (selected.get() == Some(date)).to_string()
+                                                tabindex=move || if focused.get() == date { "0" } else { "-1" }
+                                                disabled=move || !in_range(date)
+                                                style=move || day_styles(date)
+                                                on:click=move |_| {
+                                                    focused.set(date);
+                                                    select_date(date);
+                                                }
+                                            >
+                                                {date.day}
+                                            </button>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </div>
+                    </div>
+                </PopoverDropdown>
+            </Popover>
+
+            {description.map(|d| view! {
+                <div style=description_styles>{d}</div>
+            })}
+
+            {move || {
+                entry_error
+                    .get()
+                    .or_else(|| error.clone())
+                    .map(|e| view! { <div style=error_styles role="alert">{e}</div> })
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_date_validation() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(2024, 13, 1).is_none());
+        assert!(CalendarDate::new(2024, 4, 31).is_none());
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+    }
+
+    #[test]
+    fn test_days_round_trip() {
+        assert_eq!(date(1970, 1, 1).to_days(), 0);
+        assert_eq!(CalendarDate::from_days(0), date(1970, 1, 1));
+        for days in [-800_000, -1, 1, 19_797, 60_000] {
+            assert_eq!(CalendarDate::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(date(1970, 1, 1).weekday(), 4); // Thursday
+        assert_eq!(date(2024, 3, 15).weekday(), 5); // Friday
+        assert_eq!(date(2000, 1, 1).weekday(), 6); // Saturday
+    }
+
+    #[test]
+    fn test_add_months_clamps_day() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 12, 15).add_months(1), date(2025, 1, 15));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+    }
+
+    #[test]
+    fn test_year_range_is_bounded() {
+        assert!(CalendarDate::new(262_142, 12, 31).is_some());
+        assert!(CalendarDate::new(262_143, 1, 1).is_none());
+        assert!(CalendarDate::new(-262_144, 12, 31).is_none());
+        assert!(matches!(
+            CalendarDate::parse("999999999-01-01"),
+            Err(DateParseError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_navigation_saturates_at_range_ends() {
+        let max = CalendarDate::MAX;
+        assert_eq!(max.add_months(1), max);
+        assert_eq!(max.add_months(i32::MAX), max);
+        assert_eq!(max.add_days(1), max);
+        assert_eq!(date(262_142, 12, 15).add_months(12), date(262_142, 12, 15));
+        assert_eq!(CalendarDate::MIN.add_months(-1), CalendarDate::MIN);
+        assert_eq!(CalendarDate::MIN.add_days(i64::MIN), CalendarDate::MIN);
+
+        // Fields are public, so arithmetic must not overflow on any year
+        let huge = CalendarDate {
+            year: i32::MAX,
+            month: 12,
+            day: 31,
+        };
+        assert_eq!(huge.add_months(1), date(262_142, 12, 31));
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(CalendarDate::parse("2024-03-05"), Ok(date(2024, 3, 5)));
+        assert_eq!(date(2024, 3, 5).to_string(), "2024-03-05");
+        assert!(matches!(
+            CalendarDate::parse("03/05/2024"),
+            Err(DateParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            CalendarDate::parse("2023-02-29"),
+            Err(DateParseError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_month_grid() {
+        // March 2024 starts on a Friday
+        let grid = month_grid(2024, 3, 0);
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[0], date(2024, 2, 25));
+        assert_eq!(grid[5], date(2024, 3, 1));
+        assert_eq!(grid[0].weekday(), 0);
+
+        // Monday-first weeks
+        let grid = month_grid(2024, 3, 1);
+        assert_eq!(grid[0], date(2024, 2, 26));
+        assert_eq!(grid[4], date(2024, 3, 1));
+    }
+
+    #[test]
+    fn test_navigate_date() {
+        let d = date(2024, 3, 15); // Friday
+        assert_eq!(
+            navigate_date(d, "ArrowRight", false, 0),
+            Some(date(2024, 3, 16))
+        );
+        assert_eq!(
+            navigate_date(d, "ArrowUp", false, 0),
+            Some(date(2024, 3, 8))
+        );
+        assert_eq!(
+            navigate_date(d, "PageDown", false, 0),
+            Some(date(2024, 4, 15))
+        );
+        assert_eq!(navigate_date(d, "PageUp", true, 0), Some(date(2023, 3, 15)));
+        assert_eq!(navigate_date(d, "Home", false, 0), Some(date(2024, 3, 10)));
+        assert_eq!(navigate_date(d, "End", false, 0), Some(date(2024, 3, 16)));
+        assert_eq!(navigate_date(d, "Home", false, 1), Some(date(2024, 3, 11)));
+        assert_eq!(navigate_date(d, "a", false, 0), None);
+    }
+
+    #[test]
+    fn test_clamp_to() {
+        let min = Some(date(2024, 1, 1));
+        let max = Some(date(2024, 12, 31));
+        assert_eq!(date(2023, 6, 1).clamp_to(min, max), date(2024, 1, 1));
+        assert_eq!(date(2025, 6, 1).clamp_to(min, max), date(2024, 12, 31));
+        assert_eq!(date(2024, 6, 1).clamp_to(None, None), date(2024, 6, 1));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_round_trip() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+        let d = CalendarDate::from(naive);
+        assert_eq!(d, date(2024, 3, 15));
+        assert_eq!(d.to_naive_date(), Some(naive));
+        assert_eq!(
+            CalendarDate::MIN.to_naive_date(),
+            Some(chrono::NaiveDate::MIN)
+        );
+        assert_eq!(
+            CalendarDate::MAX.to_naive_date(),
+            Some(chrono::NaiveDate::MAX)
+        );
+    }
+}
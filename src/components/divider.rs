@@ -1,4 +1,7 @@
-use crate::theme::use_theme;
+use crate::theme::{
+    use_below_breakpoint, use_direction, use_theme, Breakpoint, Direction, LogicalSide,
+    PhysicalSide,
+};
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
 
@@ -8,26 +11,62 @@ pub enum DividerOrientation {
     Vertical,
 }
 
+impl DividerOrientation {
+    /// The other orientation
+    pub fn flipped(self) -> Self {
+        match self {
+            DividerOrientation::Horizontal => DividerOrientation::Vertical,
+            DividerOrientation::Vertical => DividerOrientation::Horizontal,
+        }
+    }
+}
+
+/// Where a label sits along a horizontal divider. `Left` and `Right` are
+/// fixed on screen; `Start` and `End` follow the theme's text direction.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DividerLabelPosition {
     Left,
     Center,
     Right,
+    Start,
+    End,
 }
 
 impl DividerLabelPosition {
+    /// Position in reading order under `direction`: `Start`, `Center` or
+    /// `End`
+    pub fn logical(self, direction: Direction) -> Self {
+        let side = match self {
+            DividerLabelPosition::Left => PhysicalSide::Left,
+            DividerLabelPosition::Right => PhysicalSide::Right,
+            other => return other,
+        };
+        match direction.logical(side) {
+            LogicalSide::Start => DividerLabelPosition::Start,
+            LogicalSide::End => DividerLabelPosition::End,
+        }
+    }
+
+    /// `justify-content` for a logical position; the provider's `dir`
+    /// makes flex-start the start side
     fn as_str(&self) -> &'static str {
         match self {
-            DividerLabelPosition::Left => "flex-start",
+            DividerLabelPosition::Left | DividerLabelPosition::Start => "flex-start",
             DividerLabelPosition::Center => "center",
-            DividerLabelPosition::Right => "flex-end",
+            DividerLabelPosition::Right | DividerLabelPosition::End => "flex-end",
         }
     }
 }
 
+/// Separator line, optionally labelled.
+///
+/// With `flip_below`, the orientation flips when the viewport is narrower
+/// than that theme breakpoint, e.g. a vertical divider in a `Group` using
+/// the same `stack_below` becomes horizontal once the group stacks.
 #[component]
 pub fn Divider(
     #[prop(optional)] orientation: Option<DividerOrientation>,
+    #[prop(optional)] flip_below: Option<Breakpoint>,
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional)] label_position: Option<DividerLabelPosition>,
     #[prop(optional, into)] size: Option<String>,
@@ -37,8 +76,18 @@ pub fn Divider(
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
-    let orientation = orientation.unwrap_or(DividerOrientation::Horizontal);
+    let base_orientation = orientation.unwrap_or(DividerOrientation::Horizontal);
+    let flipped = flip_below.map(use_below_breakpoint);
+    let orientation = move || {
+        if flipped.is_some_and(|f| f.get()) {
+            base_orientation.flipped()
+        } else {
+            base_orientation
+        }
+    };
+    let direction = use_direction();
     let label_position = label_position.unwrap_or(DividerLabelPosition::Center);
+    let label_position = move || label_position.logical(direction.get());
     let variant = variant.unwrap_or(DividerVariant::Solid);
 
     let has_label = label.is_some();
@@ -68,13 +117,13 @@ pub fn Divider(
             DividerVariant::Dotted => "dotted",
         };
 
-        match orientation {
+        match orientation() {
             DividerOrientation::Horizontal => {
                 if has_label {
                     builder
                         .add("display", "flex")
                         .add("align-items", "center")
-                        .add("justify-content", label_position.as_str())
+                        .add("justify-content", label_position().as_str())
                         .add("gap", &*theme_val.spacing.sm);
                 } else {
                     builder
@@ -159,28 +208,34 @@ pub fn Divider(
 
     let class_str = format!("mingot-divider {}", class.unwrap_or_default());
 
-    if orientation == DividerOrientation::Horizontal && has_label {
-        let label_text = label.unwrap();
-        view! {
-            <div class=class_str style=divider_styles>
-                {if label_position == DividerLabelPosition::Center || label_position == DividerLabelPosition::Right {
-                    view! { <div style=line_styles()></div> }.into_any()
-                } else {
-                    ().into_any()
-                }}
-                <span style=label_styles>{label_text}</span>
-                {if label_position == DividerLabelPosition::Center || label_position == DividerLabelPosition::Left {
-                    view! { <div style=line_styles()></div> }.into_any()
-                } else {
-                    ().into_any()
-                }}
-            </div>
-        }.into_any()
-    } else {
-        view! {
-            <hr class=class_str style=divider_styles />
+    move || {
+        let class_str = class_str.clone();
+        let divider_styles = divider_styles.clone();
+        if orientation() == DividerOrientation::Horizontal && has_label {
+            let label_text = label.clone().unwrap_or_default();
+            let label_position = label_position();
+            view! {
+                <div class=class_str style=divider_styles>
+                    {if label_position != DividerLabelPosition::Start {
+                        view! { <div style=line_styles()></div> }.into_any()
+                    } else {
+                        ().into_any()
+                    }}
+                    <span style=label_styles>{label_text}</span>
+                    {if label_position != DividerLabelPosition::End {
+                        view! { <div style=line_styles()></div> }.into_any()
+                    } else {
+                        ().into_any()
+                    }}
+                </div>
+            }
+            .into_any()
+        } else {
+            view! {
+                <hr class=class_str style=divider_styles />
+            }
+            .into_any()
         }
-        .into_any()
     }
 }
 
@@ -190,3 +245,47 @@ pub enum DividerVariant {
     Dashed,
     Dotted,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_position_ltr() {
+        let ltr = Direction::Ltr;
+        assert_eq!(
+            DividerLabelPosition::Left.logical(ltr),
+            DividerLabelPosition::Start
+        );
+        assert_eq!(
+            DividerLabelPosition::Right.logical(ltr),
+            DividerLabelPosition::End
+        );
+        assert_eq!(
+            DividerLabelPosition::Start.logical(ltr),
+            DividerLabelPosition::Start
+        );
+    }
+
+    #[test]
+    fn test_label_position_rtl() {
+        let rtl = Direction::Rtl;
+        // A label pinned to the left is at the end of a right-to-left row
+        assert_eq!(
+            DividerLabelPosition::Left.logical(rtl),
+            DividerLabelPosition::End
+        );
+        assert_eq!(
+            DividerLabelPosition::Right.logical(rtl),
+            DividerLabelPosition::Start
+        );
+        assert_eq!(
+            DividerLabelPosition::End.logical(rtl),
+            DividerLabelPosition::End
+        );
+        assert_eq!(
+            DividerLabelPosition::Center.logical(rtl),
+            DividerLabelPosition::Center
+        );
+    }
+}
@@ -1,12 +1,46 @@
-use crate::theme::use_theme;
+use crate::theme::{use_theme, Direction, LogicalSide, PhysicalSide};
 use leptos::prelude::*;
 
+/// Edge the drawer slides in from. `Left` and `Right` are fixed on screen;
+/// `Start` and `End` follow the theme's text direction.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DrawerPosition {
     Left,
     Right,
     Top,
     Bottom,
+    /// Left in LTR, right in RTL
+    Start,
+    /// Right in LTR, left in RTL (the default)
+    End,
+}
+
+/// Screen edge a [`DrawerPosition`] resolves to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DrawerEdge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DrawerPosition {
+    /// Screen edge under `direction`, mapping `Start`/`End` to
+    /// `Left`/`Right`
+    pub fn resolve(self, direction: Direction) -> DrawerEdge {
+        let side = match self {
+            DrawerPosition::Left => return DrawerEdge::Left,
+            DrawerPosition::Right => return DrawerEdge::Right,
+            DrawerPosition::Top => return DrawerEdge::Top,
+            DrawerPosition::Bottom => return DrawerEdge::Bottom,
+            DrawerPosition::Start => LogicalSide::Start,
+            DrawerPosition::End => LogicalSide::End,
+        };
+        match direction.physical(side) {
+            PhysicalSide::Left => DrawerEdge::Left,
+            PhysicalSide::Right => DrawerEdge::Right,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -47,7 +81,7 @@ pub fn Drawer(
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
-    let position = position.unwrap_or(DrawerPosition::Right);
+    let position = position.unwrap_or(DrawerPosition::End);
     let size = size.unwrap_or(DrawerSize::Md);
 
     let overlay_styles = move || {
@@ -74,12 +108,13 @@ pub fn Drawer(
         let visible = opened.get();
         let size_val = size.to_size();
 
-        let (width, height, transform_closed, top, left, right, bottom) = match position {
-            DrawerPosition::Left => (size_val, "100%", "translateX(-100%)", "0", "0", "auto", "0"),
-            DrawerPosition::Right => (size_val, "100%", "translateX(100%)", "0", "auto", "0", "0"),
-            DrawerPosition::Top => ("100%", size_val, "translateY(-100%)", "0", "0", "0", "auto"),
-            DrawerPosition::Bottom => ("100%", size_val, "translateY(100%)", "auto", "0", "0", "0"),
-        };
+        let (width, height, transform_closed, top, left, right, bottom) =
+            match position.resolve(theme_val.direction) {
+                DrawerEdge::Left => (size_val, "100%", "translateX(-100%)", "0", "0", "auto", "0"),
+                DrawerEdge::Right => (size_val, "100%", "translateX(100%)", "0", "auto", "0", "0"),
+                DrawerEdge::Top => ("100%", size_val, "translateY(-100%)", "0", "0", "0", "auto"),
+                DrawerEdge::Bottom => ("100%", size_val, "translateY(100%)", "auto", "0", "0", "0"),
+            };
 
         let transform = if visible {
             "translateX(0) translateY(0)"
@@ -244,3 +279,42 @@ pub fn Drawer(
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logical_positions_follow_direction() {
+        assert_eq!(
+            DrawerPosition::Start.resolve(Direction::Ltr),
+            DrawerEdge::Left
+        );
+        assert_eq!(
+            DrawerPosition::End.resolve(Direction::Ltr),
+            DrawerEdge::Right
+        );
+        assert_eq!(
+            DrawerPosition::Start.resolve(Direction::Rtl),
+            DrawerEdge::Right
+        );
+        assert_eq!(
+            DrawerPosition::End.resolve(Direction::Rtl),
+            DrawerEdge::Left
+        );
+    }
+
+    #[test]
+    fn test_physical_positions_are_fixed() {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            for (position, edge) in [
+                (DrawerPosition::Left, DrawerEdge::Left),
+                (DrawerPosition::Right, DrawerEdge::Right),
+                (DrawerPosition::Top, DrawerEdge::Top),
+                (DrawerPosition::Bottom, DrawerEdge::Bottom),
+            ] {
+                assert_eq!(position.resolve(direction), edge);
+            }
+        }
+    }
+}
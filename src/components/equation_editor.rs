@@ -8,6 +8,8 @@ use crate::utils::StyleBuilder;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 
+mod glyphs;
+
 /// Geometric algebra operations supported by the editor
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum GeometricOp {
@@ -340,11 +342,84 @@ pub enum EquationNode {
         base: Box<EquationNode>,
         superscript: Box<EquationNode>,
     },
+    /// Summation over `index` from `lower` to `upper`
+    Summation {
+        index: String,
+        lower: Box<EquationNode>,
+        upper: Box<EquationNode>,
+        body: Box<EquationNode>,
+    },
+    /// Integral with respect to `variable`; indefinite when both bounds are `None`
+    Integral {
+        variable: String,
+        lower: Option<Box<EquationNode>>,
+        upper: Option<Box<EquationNode>>,
+        integrand: Box<EquationNode>,
+    },
+    /// Matrix of entries, row by row
+    Matrix { rows: Vec<Vec<EquationNode>> },
     /// Empty placeholder (cursor position)
     Placeholder,
 }
 
 impl EquationNode {
+    /// Direct child nodes, left to right
+    pub fn children(&self) -> Vec<&EquationNode> {
+        match self {
+            Self::Number(_)
+            | Self::Variable(_)
+            | Self::BasisVector { .. }
+            | Self::Multivector(_)
+            | Self::Placeholder => Vec::new(),
+            Self::BinaryOp { left, right, .. } | Self::ArithmeticOp { left, right, .. } => {
+                vec![left, right]
+            }
+            Self::UnaryOp { operand, .. }
+            | Self::CalculusOp { operand, .. }
+            | Self::GradeProjection { operand, .. } => vec![operand],
+            Self::RotorApplication { rotor, operand } => vec![rotor, operand],
+            Self::Parenthesized(inner) => vec![inner],
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => vec![numerator, denominator],
+            Self::Subscript { base, subscript } => vec![base, subscript],
+            Self::Superscript { base, superscript } => vec![base, superscript],
+            Self::Summation {
+                lower, upper, body, ..
+            } => vec![lower, upper, body],
+            Self::Integral {
+                lower,
+                upper,
+                integrand,
+                ..
+            } => lower
+                .as_deref()
+                .into_iter()
+                .chain(upper.as_deref())
+                .chain([integrand.as_ref()])
+                .collect(),
+            Self::Matrix { rows } => rows.iter().flatten().collect(),
+        }
+    }
+
+    /// Number of empty `Placeholder` slots in the tree
+    pub fn placeholder_count(&self) -> usize {
+        match self {
+            Self::Placeholder => 1,
+            _ => self
+                .children()
+                .into_iter()
+                .map(EquationNode::placeholder_count)
+                .sum(),
+        }
+    }
+
+    /// Whether the expression has no empty slots left
+    pub fn is_complete(&self) -> bool {
+        self.placeholder_count() == 0
+    }
+
     /// Convert to LaTeX string
     pub fn to_latex(&self) -> String {
         match self {
@@ -450,22 +525,507 @@ impl EquationNode {
             Self::Superscript { base, superscript } => {
                 format!("{}^{{{}}}", base.to_latex(), superscript.to_latex())
             }
+            Self::Summation {
+                index,
+                lower,
+                upper,
+                body,
+            } => format!(
+                "\\sum_{{{}={}}}^{{{}}} {}",
+                index,
+                lower.to_latex(),
+                upper.to_latex(),
+                body.to_latex()
+            ),
+            Self::Integral {
+                variable,
+                lower,
+                upper,
+                integrand,
+            } => {
+                let mut latex = "\\int".to_string();
+                if let Some(lower) = lower {
+                    latex.push_str(&format!("_{{{}}}", lower.to_latex()));
+                }
+                if let Some(upper) = upper {
+                    latex.push_str(&format!("^{{{}}}", upper.to_latex()));
+                }
+                format!("{} {} \\, d{}", latex, integrand.to_latex(), variable)
+            }
+            Self::Matrix { rows } => {
+                let body: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(EquationNode::to_latex)
+                            .collect::<Vec<_>>()
+                            .join(" & ")
+                    })
+                    .collect();
+                format!(
+                    "\\begin{{pmatrix}} {} \\end{{pmatrix}}",
+                    body.join(" \\\\ ")
+                )
+            }
             Self::Placeholder => "\\square".to_string(),
         }
     }
 
-    /// Convert to Unicode display string
-    pub fn to_unicode(&self) -> String {
+    /// Convert to a Presentation MathML `<math>` element, which browsers
+    /// render natively and screen readers can announce
+    pub fn to_mathml(&self) -> String {
+        format!(
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">{}</math>",
+            self.mathml_element()
+        )
+    }
+
+    /// MathML for this node as a single element, so it can be used directly
+    /// as an argument of `<msup>`, `<msub>` or `<mfrac>`
+    fn mathml_element(&self) -> String {
+        let mo = |symbol: &str| format!("<mo>{}</mo>", escape_xml(symbol));
+        let function = |name: &str, operand: &EquationNode| {
+            format!(
+                "<mrow><mi>{}</mi><mo>&#x2061;</mo><mrow><mo>(</mo>{}<mo>)</mo></mrow></mrow>",
+                name,
+                operand.mathml_element()
+            )
+        };
+
         match self {
             Self::Number(n) => {
                 if n.fract() == 0.0 {
+                    format!("<mn>{:.0}</mn>", n)
+                } else {
+                    format!("<mn>{}</mn>", n)
+                }
+            }
+            Self::Variable(name) => format!("<mi>{}</mi>", escape_xml(name)),
+            Self::BasisVector { basis_type, index } => {
+                let base = match basis_type {
+                    BasisType::Standard | BasisType::Conformal => "e",
+                    BasisType::Spacetime => "γ",
+                };
+                let subscript = if *basis_type == BasisType::Conformal && *index == 4 {
+                    "<mi>∞</mi>".to_string()
+                } else {
+                    format!("<mn>{}</mn>", index)
+                };
+                format!("<msub><mi>{}</mi>{}</msub>", base, subscript)
+            }
+            Self::Multivector(terms) => {
+                let parts: Vec<String> = terms
+                    .iter()
+                    .map(|(blade, coef)| {
+                        let blade_mi = format!("<mi>{}</mi>", escape_xml(blade));
+                        if blade.is_empty() || blade == "1" {
+                            format!("<mn>{}</mn>", coef)
+                        } else if *coef == 1.0 {
+                            blade_mi
+                        } else if *coef == -1.0 {
+                            format!("<mo>−</mo>{}", blade_mi)
+                        } else {
+                            format!("<mn>{}</mn>{}", coef, blade_mi)
+                        }
+                    })
+                    .collect();
+                format!("<mrow>{}</mrow>", parts.join("<mo>+</mo>"))
+            }
+            Self::BinaryOp { op, left, right } => format!(
+                "<mrow>{}{}{}</mrow>",
+                left.mathml_element(),
+                mo(op.symbol()),
+                right.mathml_element()
+            ),
+            Self::ArithmeticOp { op, left, right } => {
+                let symbol = match op {
+                    '-' => "−".to_string(),
+                    '*' => "×".to_string(),
+                    other => other.to_string(),
+                };
+                format!(
+                    "<mrow>{}{}{}</mrow>",
+                    left.mathml_element(),
+                    mo(&symbol),
+                    right.mathml_element()
+                )
+            }
+            Self::UnaryOp { op, operand } => {
+                let inner = operand.mathml_element();
+                match op {
+                    UnaryOp::Reverse => format!("<msup>{}<mo>†</mo></msup>", inner),
+                    UnaryOp::HodgeDual => format!("<mrow><mo>⋆</mo>{}</mrow>", inner),
+                    UnaryOp::GradeInvolution => {
+                        format!("<mover accent=\"true\">{}<mo>^</mo></mover>", inner)
+                    }
+                    UnaryOp::CliffordConjugate => {
+                        format!("<mover accent=\"true\">{}<mo>¯</mo></mover>", inner)
+                    }
+                    UnaryOp::Normalize => function("normalize", operand),
+                    UnaryOp::Inverse => {
+                        format!("<msup>{}<mrow><mo>−</mo><mn>1</mn></mrow></msup>", inner)
+                    }
+                    UnaryOp::Magnitude => format!("<mrow><mo>‖</mo>{}<mo>‖</mo></mrow>", inner),
+                    UnaryOp::Exp => function("exp", operand),
+                }
+            }
+            Self::CalculusOp {
+                op,
+                operand,
+                variable,
+            } => {
+                let inner = operand.mathml_element();
+                match op {
+                    CalculusOp::Gradient => format!("<mrow><mo>∇</mo>{}</mrow>", inner),
+                    CalculusOp::Divergence => format!("<mrow><mo>∇</mo><mo>·</mo>{}</mrow>", inner),
+                    CalculusOp::Curl => format!("<mrow><mo>∇</mo><mo>∧</mo>{}</mrow>", inner),
+                    CalculusOp::Laplacian => {
+                        format!("<mrow><msup><mo>∇</mo><mn>2</mn></msup>{}</mrow>", inner)
+                    }
+                    CalculusOp::Partial => match variable {
+                        Some(var) => format!(
+                            "<mfrac><mrow><mo>∂</mo>{}</mrow><mrow><mo>∂</mo><mi>{}</mi></mrow></mfrac>",
+                            inner,
+                            escape_xml(var)
+                        ),
+                        None => format!("<mrow><mo>∂</mo>{}</mrow>", inner),
+                    },
+                }
+            }
+            Self::GradeProjection { grade, operand } => format!(
+                "<msub><mrow><mo>⟨</mo>{}<mo>⟩</mo></mrow><mn>{}</mn></msub>",
+                operand.mathml_element(),
+                grade
+            ),
+            Self::RotorApplication { rotor, operand } => {
+                let rotor = rotor.mathml_element();
+                format!(
+                    "<mrow>{}{}<msup>{}<mo>†</mo></msup></mrow>",
+                    rotor,
+                    operand.mathml_element(),
+                    rotor
+                )
+            }
+            Self::Parenthesized(inner) => {
+                format!(
+                    "<mrow><mo>(</mo>{}<mo>)</mo></mrow>",
+                    inner.mathml_element()
+                )
+            }
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => format!(
+                "<mfrac>{}{}</mfrac>",
+                numerator.mathml_element(),
+                denominator.mathml_element()
+            ),
+            Self::Subscript { base, subscript } => format!(
+                "<msub>{}{}</msub>",
+                base.mathml_element(),
+                subscript.mathml_element()
+            ),
+            Self::Superscript { base, superscript } => format!(
+                "<msup>{}{}</msup>",
+                base.mathml_element(),
+                superscript.mathml_element()
+            ),
+            Self::Summation {
+                index,
+                lower,
+                upper,
+                body,
+            } => format!(
+                "<mrow><munderover><mo>∑</mo><mrow><mi>{}</mi><mo>=</mo>{}</mrow>{}</munderover>{}</mrow>",
+                escape_xml(index),
+                lower.mathml_element(),
+                upper.mathml_element(),
+                body.mathml_element()
+            ),
+            Self::Integral {
+                variable,
+                lower,
+                upper,
+                integrand,
+            } => {
+                let sign = match (lower, upper) {
+                    (Some(lower), Some(upper)) => format!(
+                        "<msubsup><mo>∫</mo>{}{}</msubsup>",
+                        lower.mathml_element(),
+                        upper.mathml_element()
+                    ),
+                    (Some(lower), None) => {
+                        format!("<msub><mo>∫</mo>{}</msub>", lower.mathml_element())
+                    }
+                    (None, Some(upper)) => {
+                        format!("<msup><mo>∫</mo>{}</msup>", upper.mathml_element())
+                    }
+                    (None, None) => "<mo>∫</mo>".to_string(),
+                };
+                format!(
+                    "<mrow>{}{}<mi mathvariant=\"normal\">d</mi><mi>{}</mi></mrow>",
+                    sign,
+                    integrand.mathml_element(),
+                    escape_xml(variable)
+                )
+            }
+            Self::Matrix { rows } => {
+                let table: String = rows
+                    .iter()
+                    .map(|row| {
+                        let cells: String = row
+                            .iter()
+                            .map(|cell| format!("<mtd>{}</mtd>", cell.mathml_element()))
+                            .collect();
+                        format!("<mtr>{}</mtr>", cells)
+                    })
+                    .collect();
+                format!("<mrow><mo>(</mo><mtable>{}</mtable><mo>)</mo></mrow>", table)
+            }
+            Self::Placeholder => "<mi mathvariant=\"normal\">□</mi>".to_string(),
+        }
+    }
+
+    /// Mutable direct child nodes, in the same order as [`children`](Self::children)
+    pub fn children_mut(&mut self) -> Vec<&mut EquationNode> {
+        match self {
+            Self::Number(_)
+            | Self::Variable(_)
+            | Self::BasisVector { .. }
+            | Self::Multivector(_)
+            | Self::Placeholder => Vec::new(),
+            Self::BinaryOp { left, right, .. } | Self::ArithmeticOp { left, right, .. } => {
+                vec![left, right]
+            }
+            Self::UnaryOp { operand, .. }
+            | Self::CalculusOp { operand, .. }
+            | Self::GradeProjection { operand, .. } => vec![operand],
+            Self::RotorApplication { rotor, operand } => vec![rotor, operand],
+            Self::Parenthesized(inner) => vec![inner],
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => vec![numerator, denominator],
+            Self::Subscript { base, subscript } => vec![base, subscript],
+            Self::Superscript { base, superscript } => vec![base, superscript],
+            Self::Summation {
+                lower, upper, body, ..
+            } => vec![lower, upper, body],
+            Self::Integral {
+                lower,
+                upper,
+                integrand,
+                ..
+            } => lower
+                .as_deref_mut()
+                .into_iter()
+                .chain(upper.as_deref_mut())
+                .chain([integrand.as_mut()])
+                .collect(),
+            Self::Matrix { rows } => rows.iter_mut().flatten().collect(),
+        }
+    }
+
+    /// Node reached by following `path` (child indices from the root)
+    pub fn node_at(&self, path: &[usize]) -> Option<&EquationNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&index, rest)) => self.children().get(index)?.node_at(rest),
+        }
+    }
+
+    /// Mutable node reached by following `path`
+    pub fn node_at_mut(&mut self, path: &[usize]) -> Option<&mut EquationNode> {
+        match path.split_first() {
+            None => Some(self),
+            Some((&index, rest)) => self
+                .children_mut()
+                .into_iter()
+                .nth(index)?
+                .node_at_mut(rest),
+        }
+    }
+
+    /// Longest prefix of `path` that still points into this tree
+    pub fn clamp_path(&self, path: &[usize]) -> Vec<usize> {
+        let mut node = self;
+        let mut valid = Vec::new();
+        for &index in path {
+            match node.children().get(index) {
+                Some(child) => {
+                    node = child;
+                    valid.push(index);
+                }
+                None => break,
+            }
+        }
+        valid
+    }
+
+    /// Whether this node joins exactly two operands, so deleting one
+    /// leaves the other in its place
+    fn is_binary(&self) -> bool {
+        matches!(
+            self,
+            Self::BinaryOp { .. }
+                | Self::ArithmeticOp { .. }
+                | Self::RotorApplication { .. }
+                | Self::Fraction { .. }
+                | Self::Subscript { .. }
+                | Self::Superscript { .. }
+        )
+    }
+
+    /// Rebuild the node at `path` from its current value; returns `false`
+    /// when the path does not exist
+    pub fn replace_at(
+        &mut self,
+        path: &[usize],
+        build: impl FnOnce(EquationNode) -> EquationNode,
+    ) -> bool {
+        match self.node_at_mut(path) {
+            Some(node) => {
+                let current = std::mem::replace(node, Self::Placeholder);
+                *node = build(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Paths to every leaf (operand or placeholder), left to right
+    pub fn leaf_paths(&self) -> Vec<Vec<usize>> {
+        let children = self.children();
+        if children.is_empty() {
+            return vec![Vec::new()];
+        }
+        children
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, child)| {
+                child.leaf_paths().into_iter().map(move |mut path| {
+                    path.insert(0, index);
+                    path
+                })
+            })
+            .collect()
+    }
+
+    /// Paths to every empty slot, left to right
+    pub fn placeholder_paths(&self) -> Vec<Vec<usize>> {
+        self.leaf_paths()
+            .into_iter()
+            .filter(|path| matches!(self.node_at(path), Some(Self::Placeholder)))
+            .collect()
+    }
+
+    /// Move a cursor path in response to a navigation key.
+    ///
+    /// ArrowLeft/ArrowRight step between leaves; on a compound node they
+    /// collapse to its first or last leaf. ArrowUp selects the parent and
+    /// ArrowDown the first child. Home/End jump to the first/last leaf.
+    /// Returns `None` for keys that are not navigation keys.
+    pub fn navigate(&self, path: &[usize], key: &str) -> Option<Vec<usize>> {
+        let path = self.clamp_path(path);
+        let leaves = self.leaf_paths();
+        let is_leaf = self
+            .node_at(&path)
+            .is_some_and(|node| node.children().is_empty());
+        let within = |leaf: &&Vec<usize>| leaf.starts_with(&path);
+
+        let target = match key {
+            "ArrowLeft" if is_leaf => leaves.iter().rev().find(|leaf| **leaf < path).cloned(),
+            "ArrowRight" if is_leaf => leaves.iter().find(|leaf| **leaf > path).cloned(),
+            "ArrowLeft" => leaves.iter().find(within).cloned(),
+            "ArrowRight" => leaves.iter().rev().find(within).cloned(),
+            "ArrowUp" => path.split_last().map(|(_, parent)| parent.to_vec()),
+            "ArrowDown" => (!is_leaf).then(|| {
+                let mut child = path.clone();
+                child.push(0);
+                child
+            }),
+            "Home" => leaves.first().cloned(),
+            "End" => leaves.last().cloned(),
+            _ => return None,
+        };
+        Some(target.unwrap_or(path))
+    }
+
+    /// Remove the node at `path` and return where the cursor should go.
+    ///
+    /// Deleting one operand of a two-operand node collapses the parent to the
+    /// remaining operand. Deleting the only operand of a wrapper leaves an
+    /// empty slot; deleting that slot removes the wrapper itself. The root
+    /// becomes a placeholder.
+    pub fn delete_at(&mut self, path: &[usize]) -> Vec<usize> {
+        let Some((&index, parent_path)) = path.split_last() else {
+            *self = Self::Placeholder;
+            return Vec::new();
+        };
+        let Some(parent) = self.node_at_mut(parent_path) else {
+            return self.clamp_path(path);
+        };
+        let child_count = parent.children().len();
+        if index >= child_count {
+            return self.clamp_path(path);
+        }
+
+        if parent.is_binary() {
+            let sibling = std::mem::replace(
+                parent.children_mut().swap_remove(1 - index),
+                Self::Placeholder,
+            );
+            *parent = sibling;
+            return parent_path.to_vec();
+        }
+
+        let child = parent.children_mut().swap_remove(index);
+        if matches!(child, Self::Placeholder) {
+            *parent = Self::Placeholder;
+            parent_path.to_vec()
+        } else {
+            *child = Self::Placeholder;
+            path.to_vec()
+        }
+    }
+
+    /// Unicode rendering split into pieces tagged with the path of the node
+    /// that produced them, so a display can map clicks back into the tree
+    pub fn segments(&self) -> Vec<EquationSegment> {
+        let mut segments = Vec::new();
+        self.push_segments(&mut Vec::new(), &mut segments);
+        segments
+    }
+
+    fn push_segments(&self, path: &mut Vec<usize>, out: &mut Vec<EquationSegment>) {
+        let text = |out: &mut Vec<EquationSegment>, path: &[usize], text: String| {
+            out.push(EquationSegment {
+                text,
+                path: path.to_vec(),
+            });
+        };
+        let child = |out: &mut Vec<EquationSegment>,
+                     path: &mut Vec<usize>,
+                     index: usize,
+                     node: &EquationNode| {
+            path.push(index);
+            node.push_segments(path, out);
+            path.pop();
+        };
+
+        match self {
+            Self::Number(n) => {
+                let value = if n.fract() == 0.0 {
                     format!("{:.0}", n)
                 } else {
                     format!("{}", n)
-                }
+                };
+                text(out, path, value);
+            }
+            Self::Variable(name) => text(out, path, name.clone()),
+            Self::BasisVector { basis_type, index } => {
+                text(out, path, basis_type.basis_symbol(*index))
             }
-            Self::Variable(name) => name.clone(),
-            Self::BasisVector { basis_type, index } => basis_type.basis_symbol(*index),
             Self::Multivector(terms) => {
                 let parts: Vec<String> = terms
                     .iter()
@@ -481,46 +1041,55 @@ impl EquationNode {
                         }
                     })
                     .collect();
-                parts.join(" + ")
+                text(out, path, parts.join(" + "));
             }
             Self::BinaryOp { op, left, right } => {
-                format!(
-                    "{} {} {}",
-                    left.to_unicode(),
-                    op.symbol(),
-                    right.to_unicode()
-                )
+                child(out, path, 0, left);
+                text(out, path, format!(" {} ", op.symbol()));
+                child(out, path, 1, right);
             }
             Self::ArithmeticOp { op, left, right } => {
-                format!("{} {} {}", left.to_unicode(), op, right.to_unicode())
+                child(out, path, 0, left);
+                text(out, path, format!(" {} ", op));
+                child(out, path, 1, right);
+            }
+            Self::UnaryOp { op, operand } => {
+                let (prefix, suffix) = match op {
+                    UnaryOp::Reverse => ("", "†"),
+                    UnaryOp::HodgeDual => ("⋆", ""),
+                    UnaryOp::GradeInvolution => ("", "ˆ"),
+                    UnaryOp::CliffordConjugate => ("", "̄"),
+                    UnaryOp::Normalize => ("normalize(", ")"),
+                    UnaryOp::Inverse => ("", "⁻¹"),
+                    UnaryOp::Magnitude => ("‖", "‖"),
+                    UnaryOp::Exp => ("exp(", ")"),
+                };
+                if !prefix.is_empty() {
+                    text(out, path, prefix.to_string());
+                }
+                child(out, path, 0, operand);
+                if !suffix.is_empty() {
+                    text(out, path, suffix.to_string());
+                }
             }
-            Self::UnaryOp { op, operand } => match op {
-                UnaryOp::Reverse => format!("{}†", operand.to_unicode()),
-                UnaryOp::HodgeDual => format!("⋆{}", operand.to_unicode()),
-                UnaryOp::GradeInvolution => format!("{}ˆ", operand.to_unicode()),
-                UnaryOp::CliffordConjugate => format!("{}̄", operand.to_unicode()),
-                UnaryOp::Normalize => format!("normalize({})", operand.to_unicode()),
-                UnaryOp::Inverse => format!("{}⁻¹", operand.to_unicode()),
-                UnaryOp::Magnitude => format!("‖{}‖", operand.to_unicode()),
-                UnaryOp::Exp => format!("exp({})", operand.to_unicode()),
-            },
             Self::CalculusOp {
                 op,
                 operand,
                 variable,
-            } => match op {
-                CalculusOp::Gradient => format!("∇{}", operand.to_unicode()),
-                CalculusOp::Divergence => format!("∇·{}", operand.to_unicode()),
-                CalculusOp::Curl => format!("∇∧{}", operand.to_unicode()),
-                CalculusOp::Laplacian => format!("∇²{}", operand.to_unicode()),
-                CalculusOp::Partial => {
-                    if let Some(var) = variable {
-                        format!("∂{}/∂{}", operand.to_unicode(), var)
-                    } else {
-                        format!("∂{}", operand.to_unicode())
-                    }
+            } => {
+                let prefix = match op {
+                    CalculusOp::Gradient => "∇",
+                    CalculusOp::Divergence => "∇·",
+                    CalculusOp::Curl => "∇∧",
+                    CalculusOp::Laplacian => "∇²",
+                    CalculusOp::Partial => "∂",
+                };
+                text(out, path, prefix.to_string());
+                child(out, path, 0, operand);
+                if let (CalculusOp::Partial, Some(var)) = (op, variable) {
+                    text(out, path, format!("/∂{}", var));
                 }
-            },
+            }
             Self::GradeProjection { grade, operand } => {
                 let subscript = match grade {
                     0 => "₀",
@@ -535,34 +1104,616 @@ impl EquationNode {
                     9 => "₉",
                     _ => "ₙ",
                 };
-                format!("⟨{}⟩{}", operand.to_unicode(), subscript)
+                text(out, path, "⟨".to_string());
+                child(out, path, 0, operand);
+                text(out, path, format!("⟩{}", subscript));
             }
             Self::RotorApplication { rotor, operand } => {
-                format!(
-                    "{}{}{}†",
-                    rotor.to_unicode(),
-                    operand.to_unicode(),
-                    rotor.to_unicode()
-                )
+                child(out, path, 0, rotor);
+                child(out, path, 1, operand);
+                child(out, path, 0, rotor);
+                text(out, path, "†".to_string());
+            }
+            Self::Parenthesized(inner) => {
+                text(out, path, "(".to_string());
+                child(out, path, 0, inner);
+                text(out, path, ")".to_string());
             }
-            Self::Parenthesized(inner) => format!("({})", inner.to_unicode()),
             Self::Fraction {
                 numerator,
                 denominator,
             } => {
-                format!("{}/{}", numerator.to_unicode(), denominator.to_unicode())
+                child(out, path, 0, numerator);
+                text(out, path, "/".to_string());
+                child(out, path, 1, denominator);
             }
             Self::Subscript { base, subscript } => {
-                format!("{}_{}", base.to_unicode(), subscript.to_unicode())
+                child(out, path, 0, base);
+                text(out, path, "_".to_string());
+                child(out, path, 1, subscript);
             }
             Self::Superscript { base, superscript } => {
-                format!("{}^{}", base.to_unicode(), superscript.to_unicode())
+                child(out, path, 0, base);
+                text(out, path, "^".to_string());
+                child(out, path, 1, superscript);
+            }
+            Self::Summation {
+                index,
+                lower,
+                upper,
+                body,
+            } => {
+                text(out, path, format!("∑[{}=", index));
+                child(out, path, 0, lower);
+                text(out, path, "→".to_string());
+                child(out, path, 1, upper);
+                text(out, path, "] ".to_string());
+                child(out, path, 2, body);
+            }
+            Self::Integral {
+                variable,
+                lower,
+                upper,
+                integrand,
+            } => {
+                text(out, path, "∫".to_string());
+                let mut index = 0;
+                if lower.is_some() || upper.is_some() {
+                    text(out, path, "[".to_string());
+                    if let Some(lower) = lower {
+                        child(out, path, index, lower);
+                        index += 1;
+                    }
+                    text(out, path, "→".to_string());
+                    if let Some(upper) = upper {
+                        child(out, path, index, upper);
+                        index += 1;
+                    }
+                    text(out, path, "]".to_string());
+                }
+                text(out, path, " ".to_string());
+                child(out, path, index, integrand);
+                text(out, path, format!(" d{}", variable));
+            }
+            Self::Matrix { rows } => {
+                text(out, path, "[".to_string());
+                let mut index = 0;
+                for (r, row) in rows.iter().enumerate() {
+                    if r > 0 {
+                        text(out, path, "; ".to_string());
+                    }
+                    for (c, cell) in row.iter().enumerate() {
+                        if c > 0 {
+                            text(out, path, ", ".to_string());
+                        }
+                        child(out, path, index, cell);
+                        index += 1;
+                    }
+                }
+                text(out, path, "]".to_string());
+            }
+            Self::Placeholder => text(out, path, "□".to_string()),
+        }
+    }
+
+    /// Convert to Unicode display string
+    pub fn to_unicode(&self) -> String {
+        self.segments()
+            .into_iter()
+            .map(|segment| segment.text)
+            .collect()
+    }
+}
+
+/// A piece of the Unicode rendering and the path of the node it belongs to
+#[derive(Clone, Debug, PartialEq)]
+pub struct EquationSegment {
+    /// Rendered text
+    pub text: String,
+    /// Child indices from the root to the owning node
+    pub path: Vec<usize>,
+}
+
+/// Options for [`EquationNode::to_svg`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgExportOptions {
+    /// Base font size in pixels
+    pub font_size: f64,
+    /// Space around the equation in pixels
+    pub padding: f64,
+    /// Text and rule color
+    pub color: String,
+    /// Background fill; transparent when `None`
+    pub background: Option<String>,
+}
+
+impl Default for SvgExportOptions {
+    fn default() -> Self {
+        Self {
+            font_size: 24.0,
+            padding: 8.0,
+            color: "#000000".to_string(),
+            background: None,
+        }
+    }
+}
+
+/// A positioned primitive in an SVG layout, relative to the box origin
+/// (left edge, baseline; y grows downward)
+#[derive(Clone, Debug, PartialEq)]
+enum SvgItem {
+    Text {
+        x: f64,
+        y: f64,
+        size: f64,
+        italic: bool,
+        text: String,
+    },
+    Rule {
+        x: f64,
+        y: f64,
+        width: f64,
+        thickness: f64,
+    },
+}
+
+/// Typeset box: extent around the baseline plus its primitives
+#[derive(Clone, Debug, Default, PartialEq)]
+struct SvgBox {
+    width: f64,
+    ascent: f64,
+    descent: f64,
+    items: Vec<SvgItem>,
+}
+
+/// Advance width of `text` set in the export outlines, in ems
+fn text_width(text: &str, italic: bool) -> f64 {
+    text.chars()
+        .map(|c| f64::from(glyphs::glyph(c, italic).advance))
+        .sum::<f64>()
+        / glyphs::UNITS_PER_EM
+}
+
+impl SvgBox {
+    fn text(text: &str, size: f64, italic: bool) -> Self {
+        Self {
+            width: text_width(text, italic) * size,
+            ascent: 0.75 * size,
+            descent: 0.25 * size,
+            items: vec![SvgItem::Text {
+                x: 0.0,
+                y: 0.0,
+                size,
+                italic,
+                text: text.to_string(),
+            }],
+        }
+    }
+
+    /// Append `other` to the right, shifted by `dy` (positive = down)
+    fn push(&mut self, other: SvgBox, dy: f64) {
+        let dx = self.width;
+        self.items
+            .extend(other.items.into_iter().map(|item| match item {
+                SvgItem::Text {
+                    x,
+                    y,
+                    size,
+                    italic,
+                    text,
+                } => SvgItem::Text {
+                    x: x + dx,
+                    y: y + dy,
+                    size,
+                    italic,
+                    text,
+                },
+                SvgItem::Rule {
+                    x,
+                    y,
+                    width,
+                    thickness,
+                } => SvgItem::Rule {
+                    x: x + dx,
+                    y: y + dy,
+                    width,
+                    thickness,
+                },
+            }));
+        self.width += other.width;
+        self.ascent = self.ascent.max(other.ascent - dy);
+        self.descent = self.descent.max(other.descent + dy);
+    }
+
+    fn row(parts: Vec<SvgBox>) -> Self {
+        let mut row = SvgBox::default();
+        for part in parts {
+            row.push(part, 0.0);
+        }
+        row
+    }
+
+    /// Stack `numerator` over `denominator` with a centered rule
+    fn fraction(numerator: SvgBox, denominator: SvgBox, size: f64) -> Self {
+        let axis = 0.3 * size;
+        let thickness = (0.05 * size).max(1.0);
+        let gap = 0.15 * size;
+        let pad = 0.1 * size;
+        let width = numerator.width.max(denominator.width) + 2.0 * pad;
+
+        let num_dy = -(axis + thickness / 2.0 + gap + numerator.descent);
+        let den_dy = -axis + thickness / 2.0 + gap + denominator.ascent;
+        let num_dx = (width - numerator.width) / 2.0;
+        let den_dx = (width - denominator.width) / 2.0;
+
+        let mut frac = SvgBox {
+            width: 0.0,
+            ascent: -num_dy + numerator.ascent,
+            descent: den_dy + denominator.descent,
+            items: vec![SvgItem::Rule {
+                x: 0.0,
+                y: -axis - thickness / 2.0,
+                width,
+                thickness,
+            }],
+        };
+        frac.width = num_dx;
+        frac.push(numerator, num_dy);
+        frac.width = den_dx;
+        frac.push(denominator, den_dy);
+        frac.width = width;
+        frac
+    }
+
+    /// Attach optional lower and upper scripts to the right of `base`
+    fn scripts(mut base: SvgBox, lower: Option<SvgBox>, upper: Option<SvgBox>, size: f64) -> Self {
+        let start = base.width;
+        let mut end = start;
+        if let Some(lower) = lower {
+            base.push(lower, 0.35 * size);
+            end = end.max(base.width);
+            base.width = start;
+        }
+        if let Some(upper) = upper {
+            base.push(upper, -0.6 * size);
+            end = end.max(base.width);
+        }
+        base.width = end;
+        base
+    }
+
+    /// Grid of centered cells between square brackets, centered on the math axis
+    fn matrix(rows: Vec<Vec<SvgBox>>, size: f64) -> Self {
+        let axis = 0.3 * size;
+        let thickness = (0.05 * size).max(1.0);
+        let pad = 0.2 * size;
+        let gap_x = 0.6 * size;
+        let gap_y = 0.25 * size;
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let column_widths: Vec<f64> = (0..columns)
+            .map(|c| {
+                rows.iter()
+                    .filter_map(|row| row.get(c))
+                    .map(|cell| cell.width)
+                    .fold(0.0, f64::max)
+            })
+            .collect();
+        let row_extents: Vec<(f64, f64)> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .fold((0.0_f64, 0.0_f64), |(ascent, descent), cell| {
+                        (ascent.max(cell.ascent), descent.max(cell.descent))
+                    })
+            })
+            .collect();
+
+        let content_height = row_extents.iter().map(|(a, d)| a + d).sum::<f64>()
+            + gap_y * rows.len().saturating_sub(1) as f64;
+        let height = content_height.max(size) + 2.0 * pad;
+        let content_width =
+            column_widths.iter().sum::<f64>() + gap_x * columns.saturating_sub(1) as f64;
+        let width = content_width + 2.0 * (pad + thickness);
+        let top = -axis - height / 2.0;
+
+        let bracket = |x: f64, tick_x: f64| {
+            vec![
+                SvgItem::Rule {
+                    x,
+                    y: top,
+                    width: thickness,
+                    thickness: height,
+                },
+                SvgItem::Rule {
+                    x: tick_x,
+                    y: top,
+                    width: pad,
+                    thickness,
+                },
+                SvgItem::Rule {
+                    x: tick_x,
+                    y: top + height - thickness,
+                    width: pad,
+                    thickness,
+                },
+            ]
+        };
+        let mut items = bracket(0.0, 0.0);
+        items.extend(bracket(width - thickness, width - pad));
+
+        let mut grid = SvgBox {
+            width: 0.0,
+            ascent: -top,
+            descent: top + height,
+            items,
+        };
+        let mut y = top + pad + (height - 2.0 * pad - content_height) / 2.0;
+        for (row, (ascent, descent)) in rows.into_iter().zip(row_extents) {
+            let mut x = thickness + pad;
+            for (cell, column_width) in row.into_iter().zip(&column_widths) {
+                grid.width = x + (column_width - cell.width) / 2.0;
+                grid.push(cell, y + ascent);
+                x += column_width + gap_x;
             }
-            Self::Placeholder => "□".to_string(),
+            y += ascent + descent + gap_y;
         }
+        grid.width = width;
+        grid
     }
 }
 
+/// Scale applied to super/subscripts
+const SCRIPT_SCALE: f64 = 0.7;
+
+impl EquationNode {
+    /// Typeset this node at `size` px
+    fn layout_svg(&self, size: f64) -> SvgBox {
+        let text = |s: &str| SvgBox::text(s, size, false);
+        let op = |s: &str| SvgBox::text(&format!(" {} ", s), size, false);
+        let script = |node: &EquationNode| node.layout_svg(size * SCRIPT_SCALE);
+
+        match self {
+            Self::Variable(name) => SvgBox::text(name, size, true),
+            Self::Number(_) | Self::BasisVector { .. } | Self::Multivector(_) => {
+                text(&self.to_unicode())
+            }
+            Self::Placeholder => text("□"),
+            Self::BinaryOp {
+                op: geometric,
+                left,
+                right,
+            } => SvgBox::row(vec![
+                left.layout_svg(size),
+                op(geometric.symbol()),
+                right.layout_svg(size),
+            ]),
+            Self::ArithmeticOp {
+                op: arithmetic,
+                left,
+                right,
+            } => SvgBox::row(vec![
+                left.layout_svg(size),
+                op(&arithmetic.to_string()),
+                right.layout_svg(size),
+            ]),
+            Self::UnaryOp { op: unary, operand } => {
+                let inner = operand.layout_svg(size);
+                let suffix = |mark: &str| {
+                    let mut b = inner.clone();
+                    b.push(
+                        script(&EquationNode::Variable(mark.to_string())),
+                        -0.45 * size,
+                    );
+                    b
+                };
+                match unary {
+                    UnaryOp::Reverse => suffix("†"),
+                    UnaryOp::Inverse => suffix("-1"),
+                    UnaryOp::GradeInvolution => suffix("ˆ"),
+                    UnaryOp::CliffordConjugate => suffix("‾"),
+                    UnaryOp::HodgeDual => SvgBox::row(vec![text("⋆"), inner]),
+                    UnaryOp::Magnitude => SvgBox::row(vec![text("‖"), inner, text("‖")]),
+                    UnaryOp::Normalize => SvgBox::row(vec![text("normalize("), inner, text(")")]),
+                    UnaryOp::Exp => SvgBox::row(vec![text("exp("), inner, text(")")]),
+                }
+            }
+            Self::CalculusOp {
+                op: calculus,
+                operand,
+                variable,
+            } => {
+                let inner = operand.layout_svg(size);
+                match (calculus, variable) {
+                    (CalculusOp::Partial, Some(var)) => SvgBox::fraction(
+                        SvgBox::row(vec![text("∂"), inner]),
+                        SvgBox::row(vec![text("∂"), SvgBox::text(var, size, true)]),
+                        size,
+                    ),
+                    (CalculusOp::Partial, None) => SvgBox::row(vec![text("∂"), inner]),
+                    (CalculusOp::Gradient, _) => SvgBox::row(vec![text("∇"), inner]),
+                    (CalculusOp::Divergence, _) => SvgBox::row(vec![text("∇·"), inner]),
+                    (CalculusOp::Curl, _) => SvgBox::row(vec![text("∇∧"), inner]),
+                    (CalculusOp::Laplacian, _) => {
+                        let mut nabla = text("∇");
+                        nabla.push(script(&EquationNode::Number(2.0)), -0.45 * size);
+                        SvgBox::row(vec![nabla, inner])
+                    }
+                }
+            }
+            Self::GradeProjection { grade, operand } => {
+                let mut b = SvgBox::row(vec![text("⟨"), operand.layout_svg(size), text("⟩")]);
+                b.push(script(&EquationNode::Number(*grade as f64)), 0.2 * size);
+                b
+            }
+            Self::RotorApplication { rotor, operand } => {
+                let mut reversed = rotor.layout_svg(size);
+                reversed.push(
+                    script(&EquationNode::Variable("†".to_string())),
+                    -0.45 * size,
+                );
+                SvgBox::row(vec![
+                    rotor.layout_svg(size),
+                    operand.layout_svg(size),
+                    reversed,
+                ])
+            }
+            Self::Parenthesized(inner) => {
+                SvgBox::row(vec![text("("), inner.layout_svg(size), text(")")])
+            }
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => SvgBox::fraction(
+                numerator.layout_svg(size),
+                denominator.layout_svg(size),
+                size,
+            ),
+            Self::Subscript { base, subscript } => {
+                let mut b = base.layout_svg(size);
+                b.push(script(subscript), 0.2 * size);
+                b
+            }
+            Self::Superscript { base, superscript } => {
+                let mut b = base.layout_svg(size);
+                b.push(script(superscript), -0.45 * size);
+                b
+            }
+            Self::Summation {
+                index,
+                lower,
+                upper,
+                body,
+            } => {
+                let lower = SvgBox::row(vec![
+                    SvgBox::text(index, size * SCRIPT_SCALE, true),
+                    SvgBox::text("=", size * SCRIPT_SCALE, false),
+                    script(lower),
+                ]);
+                let sign = SvgBox::scripts(
+                    SvgBox::text("∑", size * 1.4, false),
+                    Some(lower),
+                    Some(script(upper)),
+                    size,
+                );
+                SvgBox::row(vec![sign, text(" "), body.layout_svg(size)])
+            }
+            Self::Integral {
+                variable,
+                lower,
+                upper,
+                integrand,
+            } => {
+                let sign = SvgBox::scripts(
+                    SvgBox::text("∫", size * 1.4, false),
+                    lower.as_deref().map(script),
+                    upper.as_deref().map(script),
+                    size,
+                );
+                SvgBox::row(vec![
+                    sign,
+                    text(" "),
+                    integrand.layout_svg(size),
+                    text(" d"),
+                    SvgBox::text(variable, size, true),
+                ])
+            }
+            Self::Matrix { rows } => SvgBox::matrix(
+                rows.iter()
+                    .map(|row| row.iter().map(|cell| cell.layout_svg(size)).collect())
+                    .collect(),
+                size,
+            ),
+        }
+    }
+
+    /// Render as a standalone SVG document.
+    ///
+    /// The tree is typeset structurally (stacked fractions, raised and
+    /// lowered scripts). Glyphs are emitted as path outlines from DejaVu
+    /// Serif, so the document renders identically without any fonts
+    /// installed; characters outside the outline set draw as a hollow box.
+    pub fn to_svg(&self, options: &SvgExportOptions) -> String {
+        let layout = self.layout_svg(options.font_size);
+        let width = layout.width + 2.0 * options.padding;
+        let height = layout.ascent + layout.descent + 2.0 * options.padding;
+        let origin_x = options.padding;
+        let origin_y = options.padding + layout.ascent;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+            w = svg_number(width),
+            h = svg_number(height)
+        );
+        if let Some(background) = &options.background {
+            svg.push_str(&format!(
+                "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape_xml(background)
+            ));
+        }
+        svg.push_str(&format!("<g fill=\"{}\">", escape_xml(&options.color)));
+        for item in &layout.items {
+            match item {
+                SvgItem::Text {
+                    x,
+                    y,
+                    size,
+                    italic,
+                    text,
+                } => {
+                    let scale = size / glyphs::UNITS_PER_EM;
+                    // Font units are tiny at pixel sizes; keep more precision than coordinates
+                    let scale_attr = format!("{:.5}", scale)
+                        .trim_end_matches('0')
+                        .trim_end_matches('.')
+                        .to_string();
+                    let mut pen = origin_x + x;
+                    for c in text.chars() {
+                        let glyph = glyphs::glyph(c, *italic);
+                        if !glyph.path.is_empty() {
+                            svg.push_str(&format!(
+                                "<path transform=\"translate({} {}) scale({})\" d=\"{}\"/>",
+                                svg_number(pen),
+                                svg_number(origin_y + y),
+                                scale_attr,
+                                glyph.path
+                            ));
+                        }
+                        pen += f64::from(glyph.advance) * scale;
+                    }
+                }
+                SvgItem::Rule {
+                    x,
+                    y,
+                    width,
+                    thickness,
+                } => svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+                    svg_number(origin_x + x),
+                    svg_number(origin_y + y),
+                    svg_number(*width),
+                    svg_number(*thickness)
+                )),
+            }
+        }
+        svg.push_str("</g></svg>");
+        svg
+    }
+}
+
+/// Format a coordinate with at most two decimals
+fn svg_number(value: f64) -> String {
+    let s = format!("{:.2}", value);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 impl std::fmt::Display for EquationNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_unicode())
@@ -647,6 +1798,9 @@ pub fn EquationEditor(
     /// Show LaTeX output
     #[prop(default = false)]
     show_latex: bool,
+    /// Render the equation as native MathML instead of the clickable Unicode view
+    #[prop(default = false)]
+    show_mathml: bool,
     /// Editor size
     #[prop(default = EquationEditorSize::Md)]
     size: EquationEditorSize,
@@ -665,6 +1819,18 @@ pub fn EquationEditor(
     /// Placeholder text when empty
     #[prop(optional, into)]
     placeholder: Option<String>,
+    /// Callback when the expression becomes complete (`true`) or gains empty slots (`false`)
+    #[prop(optional, into)]
+    on_validity: Option<Callback<bool>>,
+    /// Show a "Copy SVG" button that copies the typeset equation
+    #[prop(default = false)]
+    show_export: bool,
+    /// Size, padding and colors for SVG export
+    #[prop(optional)]
+    export_options: Option<SvgExportOptions>,
+    /// Receives the SVG whenever it is exported
+    #[prop(optional, into)]
+    on_export: Option<Callback<String>>,
 ) -> impl IntoView {
     let theme = use_theme();
 
@@ -673,6 +1839,20 @@ pub fn EquationEditor(
     let active_category = RwSignal::new(ToolbarCategory::Geometric);
     let input_text = RwSignal::new(String::new());
     let is_focused = RwSignal::new(false);
+    // Path of child indices from the root to the selected node
+    let cursor = RwSignal::new(Vec::<usize>::new());
+
+    // Completeness tracking: notify only when the state actually changes
+    let placeholder_count = Memo::new(move |_| equation.get().placeholder_count());
+    Effect::new(move |prev: Option<bool>| {
+        let complete = placeholder_count.get() == 0;
+        if prev != Some(complete) {
+            if let Some(cb) = on_validity {
+                cb.run(complete);
+            }
+        }
+        complete
+    });
 
     // Parse simple text input into equation node
     let parse_input = move |text: &str| -> Option<EquationNode> {
@@ -697,139 +1877,235 @@ pub fn EquationEditor(
         input_text.set(input.value());
     };
 
+    // Commit a new tree, move the cursor and notify
+    let update = move |node: EquationNode, next: Vec<usize>| {
+        cursor.set(node.clamp_path(&next));
+        equation.set(node.clone());
+        if let Some(cb) = on_change {
+            cb.run(node);
+        }
+    };
+
+    // Cursor path, clamped in case the value was replaced from outside
+    let cursor_path = move || equation.with(|eq| eq.clamp_path(&cursor.get()));
+
+    let cursor_on_placeholder = move || {
+        let path = cursor_path();
+        equation.with(|eq| matches!(eq.node_at(&path), Some(EquationNode::Placeholder)))
+    };
+
+    // Wrap the node under the cursor; `slot` selects a child of the wrapper
+    let wrap_at_cursor = move |build: &dyn Fn(EquationNode) -> EquationNode,
+                               slot: Option<usize>| {
+        let path = cursor_path();
+        let mut node = equation.get();
+        node.replace_at(&path, build);
+        let mut next = path;
+        next.extend(slot);
+        update(node, next);
+    };
+
+    // Remove the node under the cursor
+    let delete_at_cursor = move || {
+        let mut node = equation.get();
+        let next = node.delete_at(&cursor_path());
+        update(node, next);
+    };
+
     // Handle key press
     let on_keydown = move |ev: web_sys::KeyboardEvent| {
-        if ev.key() == "Enter" {
-            if let Some(node) = parse_input(&input_text.get()) {
-                equation.set(node.clone());
-                if let Some(cb) = on_change {
-                    cb.run(node);
+        match ev.key().as_str() {
+            "Enter" => {
+                if let Some(value) = parse_input(&input_text.get()) {
+                    let mut node = equation.get();
+                    node.replace_at(&cursor_path(), |_| value);
+                    // Continue with the next empty slot, or select the whole expression
+                    let next = node
+                        .placeholder_paths()
+                        .into_iter()
+                        .next()
+                        .unwrap_or_default();
+                    update(node, next);
+                    input_text.set(String::new());
                 }
-                input_text.set(String::new());
             }
+            "Backspace" if input_text.with(String::is_empty) => {
+                ev.prevent_default();
+                delete_at_cursor();
+            }
+            _ => {}
+        }
+    };
+
+    // Arrow keys move the cursor, Backspace/Delete remove the node under it
+    let on_display_keydown = move |ev: web_sys::KeyboardEvent| {
+        if read_only {
+            return;
+        }
+        let key = ev.key();
+        if key == "Backspace" || key == "Delete" {
+            ev.prevent_default();
+            delete_at_cursor();
+        } else if let Some(next) = equation.with(|eq| eq.navigate(&cursor.get(), &key)) {
+            ev.prevent_default();
+            cursor.set(next);
         }
     };
 
     // Insert geometric operation
     let insert_geometric_op = move |op: GeometricOp| {
-        let current = equation.get();
-        if matches!(current, EquationNode::Placeholder) {
+        if cursor_on_placeholder() {
             // If placeholder, wait for operands
             return;
         }
-        let new_node = EquationNode::BinaryOp {
-            op,
-            left: Box::new(current),
-            right: Box::new(EquationNode::Placeholder),
-        };
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        wrap_at_cursor(
+            &|current| EquationNode::BinaryOp {
+                op,
+                left: Box::new(current),
+                right: Box::new(EquationNode::Placeholder),
+            },
+            Some(1),
+        );
     };
 
     // Insert unary operation
     let insert_unary_op = move |op: UnaryOp| {
-        let current = equation.get();
-        if matches!(current, EquationNode::Placeholder) {
+        if cursor_on_placeholder() {
             return;
         }
-        let new_node = EquationNode::UnaryOp {
-            op,
-            operand: Box::new(current),
-        };
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        wrap_at_cursor(
+            &|current| EquationNode::UnaryOp {
+                op,
+                operand: Box::new(current),
+            },
+            None,
+        );
     };
 
     // Insert calculus operation
     let insert_calculus_op = move |op: CalculusOp| {
-        let current = equation.get();
-        if matches!(current, EquationNode::Placeholder) {
+        if cursor_on_placeholder() {
             return;
         }
-        let new_node = EquationNode::CalculusOp {
-            op,
-            operand: Box::new(current),
-            variable: None,
-        };
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        wrap_at_cursor(
+            &|current| EquationNode::CalculusOp {
+                op,
+                operand: Box::new(current),
+                variable: None,
+            },
+            None,
+        );
     };
 
     // Insert basis vector
     let insert_basis = move |index: usize| {
         let new_node = EquationNode::BasisVector { basis_type, index };
-        let current = equation.get();
-        let final_node = if matches!(current, EquationNode::Placeholder) {
-            new_node
+        if cursor_on_placeholder() {
+            let mut node = equation.get();
+            node.replace_at(&cursor_path(), |_| new_node);
+            let next = node
+                .placeholder_paths()
+                .into_iter()
+                .next()
+                .unwrap_or_default();
+            update(node, next);
         } else {
             // Append with geometric product by default
-            EquationNode::BinaryOp {
-                op: GeometricOp::GeometricProduct,
-                left: Box::new(current),
-                right: Box::new(new_node),
-            }
-        };
-        equation.set(final_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(final_node);
+            wrap_at_cursor(
+                &|current| EquationNode::BinaryOp {
+                    op: GeometricOp::GeometricProduct,
+                    left: Box::new(current),
+                    right: Box::new(new_node.clone()),
+                },
+                None,
+            );
         }
     };
 
     // Insert grade projection
     let insert_grade_projection = move |grade: u8| {
-        let current = equation.get();
-        if matches!(current, EquationNode::Placeholder) {
+        if cursor_on_placeholder() {
             return;
         }
-        let new_node = EquationNode::GradeProjection {
-            grade,
-            operand: Box::new(current),
-        };
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        wrap_at_cursor(
+            &|current| EquationNode::GradeProjection {
+                grade,
+                operand: Box::new(current),
+            },
+            None,
+        );
     };
 
     // Insert parentheses
     let insert_parens = move |_| {
-        let current = equation.get();
-        if matches!(current, EquationNode::Placeholder) {
+        if cursor_on_placeholder() {
             return;
         }
-        let new_node = EquationNode::Parenthesized(Box::new(current));
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        wrap_at_cursor(
+            &|current| EquationNode::Parenthesized(Box::new(current)),
+            None,
+        );
     };
 
     // Insert fraction
     let insert_fraction = move |_| {
-        let current = equation.get();
-        let new_node = EquationNode::Fraction {
-            numerator: Box::new(current),
-            denominator: Box::new(EquationNode::Placeholder),
-        };
-        equation.set(new_node.clone());
-        if let Some(cb) = on_change {
-            cb.run(new_node);
-        }
+        let slot = if cursor_on_placeholder() { 0 } else { 1 };
+        wrap_at_cursor(
+            &|current| EquationNode::Fraction {
+                numerator: Box::new(current),
+                denominator: Box::new(EquationNode::Placeholder),
+            },
+            Some(slot),
+        );
+    };
+
+    // Insert a structure around the node under the cursor and move to its first empty slot
+    let insert_structure = move |build: &dyn Fn(EquationNode) -> EquationNode| {
+        let path = cursor_path();
+        let mut node = equation.get();
+        node.replace_at(&path, build);
+        let next = node
+            .placeholder_paths()
+            .into_iter()
+            .find(|slot| slot.starts_with(&path))
+            .unwrap_or(path);
+        update(node, next);
+    };
+
+    // Insert summation with the current node as its body
+    let insert_summation = move |_| {
+        insert_structure(&|current| EquationNode::Summation {
+            index: "i".to_string(),
+            lower: Box::new(EquationNode::Placeholder),
+            upper: Box::new(EquationNode::Placeholder),
+            body: Box::new(current),
+        });
+    };
+
+    // Insert definite integral with the current node as its integrand
+    let insert_integral = move |_| {
+        insert_structure(&|current| EquationNode::Integral {
+            variable: "x".to_string(),
+            lower: Some(Box::new(EquationNode::Placeholder)),
+            upper: Some(Box::new(EquationNode::Placeholder)),
+            integrand: Box::new(current),
+        });
+    };
+
+    // Insert 2×2 matrix with the current node as its first entry
+    let insert_matrix = move |_| {
+        insert_structure(&|current| EquationNode::Matrix {
+            rows: vec![
+                vec![current, EquationNode::Placeholder],
+                vec![EquationNode::Placeholder, EquationNode::Placeholder],
+            ],
+        });
     };
 
     // Clear equation
     let clear = move |_| {
-        equation.set(EquationNode::Placeholder);
         input_text.set(String::new());
-        if let Some(cb) = on_change {
-            cb.run(EquationNode::Placeholder);
-        }
+        update(EquationNode::Placeholder, Vec::new());
     };
 
     // Container styles
@@ -979,6 +2255,24 @@ pub fn EquationEditor(
             .build()
     };
 
+    // Rendered piece styles; the subtree under the cursor is highlighted
+    let segment_styles = move |selected: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+
+        StyleBuilder::new()
+            .add("white-space", "pre")
+            .add_if(!read_only, "cursor", "pointer")
+            .add_if(
+                selected,
+                "background",
+                scheme_colors
+                    .get_color(&theme_val.colors.primary_color, 1)
+                    .unwrap_or_else(|| "#d0ebff".to_string()),
+            )
+            .build()
+    };
+
     // Input styles
     let input_styles = move || {
         let theme_val = theme.get();
@@ -1048,6 +2342,27 @@ pub fn EquationEditor(
             .build()
     };
 
+    // Incomplete-expression status styles
+    let status_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+
+        StyleBuilder::new()
+            .add(
+                "padding",
+                format!("0 {} {}", &*theme_val.spacing.sm, &*theme_val.spacing.xs),
+            )
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add("text-align", "center")
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("orange", 7)
+                    .unwrap_or_else(|| "#f76707".to_string()),
+            )
+            .build()
+    };
+
     let placeholder_text = placeholder.unwrap_or_else(|| "Enter expression...".to_string());
 
     view! {
@@ -1157,6 +2472,33 @@ pub fn EquationEditor(
                                             >
                                                 "a/b"
                                             </button>
+                                            <button
+                                                type="button"
+                                                style=op_button_styles
+                                                on:click=insert_summation
+                                                title="Summation"
+                                                disabled=read_only
+                                            >
+                                                "∑"
+                                            </button>
+                                            <button
+                                                type="button"
+                                                style=op_button_styles
+                                                on:click=insert_integral
+                                                title="Integral"
+                                                disabled=read_only
+                                            >
+                                                "∫"
+                                            </button>
+                                            <button
+                                                type="button"
+                                                style=op_button_styles
+                                                on:click=insert_matrix
+                                                title="2×2 matrix"
+                                                disabled=read_only
+                                            >
+                                                "[ ]"
+                                            </button>
                                             // Grade projections
                                             {(0..=3u8).map(|grade| {
                                                 let proj = GradeProjection::new(grade);
@@ -1191,17 +2533,64 @@ pub fn EquationEditor(
             })}
 
             // Display area
-            <div style=display_styles>
+            <div
+                class="mingot-equation-display"
+                style=display_styles
+                tabindex=if read_only { "-1" } else { "0" }
+                aria-label="Equation"
+                on:keydown=on_display_keydown
+            >
                 {move || {
                     let eq = equation.get();
                     if matches!(eq, EquationNode::Placeholder) {
-                        placeholder_text.clone()
-                    } else {
-                        eq.to_unicode()
+                        return placeholder_text.clone().into_any();
                     }
+                    if show_mathml {
+                        return view! {
+                            <span class="mingot-equation-mathml" inner_html=eq.to_mathml()></span>
+                        }
+                        .into_any();
+                    }
+                    let active = cursor_path();
+                    eq.segments()
+                        .into_iter()
+                        .map(|segment| {
+                            let selected = !read_only && segment.path.starts_with(&active);
+                            let path = segment.path;
+                            view! {
+                                <span
+                                    class="mingot-equation-segment"
+                                    class:mingot-equation-cursor=selected
+                                    style=move || segment_styles(selected)
+                                    on:click=move |_| {
+                                        if !read_only {
+                                            cursor.set(path.clone());
+                                        }
+                                    }
+                                >
+                                    {segment.text}
+                                </span>
+                            }
+                        })
+                        .collect_view()
+                        .into_any()
                 }}
             </div>
 
+            // Incomplete expression status (hidden for an empty editor)
+            {move || {
+                let count = placeholder_count.get();
+                let is_empty = matches!(equation.get(), EquationNode::Placeholder);
+                (count > 0 && !is_empty).then(|| {
+                    let noun = if count == 1 { "slot" } else { "slots" };
+                    view! {
+                        <div class="mingot-equation-status" style=status_styles>
+                            {format!("{} empty {} remaining", count, noun)}
+                        </div>
+                    }
+                })
+            }}
+
             // Text input row (for entering numbers/variables)
             {move || (!read_only).then(|| {
                 view! {
@@ -1221,6 +2610,34 @@ pub fn EquationEditor(
                 }
             })}
 
+            // SVG export
+            {show_export.then(|| {
+                let export_options = export_options.clone().unwrap_or_default();
+                let export_svg = move |_| {
+                    let svg = equation.get_untracked().to_svg(&export_options);
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.navigator().clipboard().write_text(&svg);
+                    }
+                    if let Some(cb) = on_export {
+                        cb.run(svg);
+                    }
+                };
+                view! {
+                    <div style=input_row_styles>
+                        <button
+                            type="button"
+                            class="mingot-equation-export"
+                            style=op_button_styles
+                            title="Copy the typeset equation as SVG"
+                            on:click=export_svg
+                            disabled=disabled
+                        >
+                            "Copy SVG"
+                        </button>
+                    </div>
+                }
+            })}
+
             // LaTeX output
             {move || show_latex.then(|| {
                 view! {
@@ -1375,6 +2792,50 @@ mod tests {
         assert_eq!(node.to_latex(), "\\frac{1}{2}");
     }
 
+    #[test]
+    fn test_placeholder_count_leaf_nodes() {
+        assert_eq!(EquationNode::Placeholder.placeholder_count(), 1);
+        assert!(!EquationNode::Placeholder.is_complete());
+        assert_eq!(EquationNode::Number(1.0).placeholder_count(), 0);
+        assert!(EquationNode::Variable("x".to_string()).is_complete());
+    }
+
+    #[test]
+    fn test_placeholder_count_nested_incomplete() {
+        // (a ∧ □) / □
+        let node = EquationNode::Fraction {
+            numerator: Box::new(EquationNode::Parenthesized(Box::new(
+                EquationNode::BinaryOp {
+                    op: GeometricOp::WedgeProduct,
+                    left: Box::new(EquationNode::Variable("a".to_string())),
+                    right: Box::new(EquationNode::Placeholder),
+                },
+            ))),
+            denominator: Box::new(EquationNode::Placeholder),
+        };
+        assert_eq!(node.placeholder_count(), 2);
+        assert!(!node.is_complete());
+    }
+
+    #[test]
+    fn test_placeholder_count_nested_complete() {
+        // ∇(⟨a b⟩₂)
+        let node = EquationNode::CalculusOp {
+            op: CalculusOp::Gradient,
+            operand: Box::new(EquationNode::GradeProjection {
+                grade: 2,
+                operand: Box::new(EquationNode::BinaryOp {
+                    op: GeometricOp::GeometricProduct,
+                    left: Box::new(EquationNode::Variable("a".to_string())),
+                    right: Box::new(EquationNode::Variable("b".to_string())),
+                }),
+            }),
+            variable: None,
+        };
+        assert_eq!(node.placeholder_count(), 0);
+        assert!(node.is_complete());
+    }
+
     #[test]
     fn test_complex_expression() {
         // Build: (a ∧ b)†
@@ -1390,4 +2851,302 @@ mod tests {
         };
         assert_eq!(reversed.to_unicode(), "(a ∧ b)†");
     }
+
+    #[test]
+    fn test_svg_export_document() {
+        let eq = EquationNode::ArithmeticOp {
+            op: '+',
+            left: Box::new(EquationNode::Variable("a".into())),
+            right: Box::new(EquationNode::Number(1.0)),
+        };
+        let svg = eq.to_svg(&SvgExportOptions::default());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</g></svg>"));
+        // The variable is italic; spaces around the operator draw nothing
+        assert!(svg.contains(glyphs::glyph('a', true).path));
+        assert!(svg.contains(glyphs::glyph('+', false).path));
+        assert!(svg.contains(glyphs::glyph('1', false).path));
+        assert_eq!(svg.matches("<path").count(), 3);
+    }
+
+    #[test]
+    fn test_svg_does_not_depend_on_system_fonts() {
+        let eq = EquationNode::Summation {
+            index: "k".into(),
+            lower: Box::new(EquationNode::Number(1.0)),
+            upper: Box::new(EquationNode::Variable("n".into())),
+            body: Box::new(EquationNode::UnaryOp {
+                op: UnaryOp::Magnitude,
+                operand: Box::new(EquationNode::Variable("θ".into())),
+            }),
+        };
+        let svg = eq.to_svg(&SvgExportOptions::default());
+        assert!(!svg.contains("<text"));
+        assert!(!svg.contains("font-"));
+        assert!(!svg.contains("@font-face"));
+        for (c, italic) in [('∑', false), ('‖', false), ('θ', true)] {
+            assert!(svg.contains(glyphs::glyph(c, italic).path));
+        }
+        assert!(!svg.contains(glyphs::MISSING.path));
+    }
+
+    #[test]
+    fn test_svg_uncovered_character_draws_box() {
+        let svg = EquationNode::Variable("😀".into()).to_svg(&SvgExportOptions::default());
+        assert!(svg.contains(glyphs::MISSING.path));
+    }
+
+    #[test]
+    fn test_svg_fraction_is_stacked() {
+        let frac = EquationNode::Fraction {
+            numerator: Box::new(EquationNode::Variable("x".into())),
+            denominator: Box::new(EquationNode::Number(2.0)),
+        };
+        let layout = frac.layout_svg(20.0);
+        let ys: Vec<f64> = layout
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                SvgItem::Text { y, .. } => Some(*y),
+                _ => None,
+            })
+            .collect();
+        // Numerator above the baseline, denominator below
+        assert_eq!(ys.len(), 2);
+        assert!(ys[0] < 0.0 && ys[1] > 0.0);
+        assert!(layout
+            .items
+            .iter()
+            .any(|item| matches!(item, SvgItem::Rule { .. })));
+        // Taller than a single line of text
+        assert!(layout.ascent + layout.descent > 20.0);
+    }
+
+    #[test]
+    fn test_svg_size_and_padding() {
+        let eq = EquationNode::Variable("x".into());
+        let small = eq.to_svg(&SvgExportOptions {
+            font_size: 10.0,
+            padding: 0.0,
+            ..Default::default()
+        });
+        // Italic x advance wide, 1em tall
+        let width = svg_number(text_width("x", true) * 10.0);
+        assert!(small.contains(&format!("width=\"{}\" height=\"10\"", width)));
+
+        let padded = eq.to_svg(&SvgExportOptions {
+            font_size: 10.0,
+            padding: 4.0,
+            background: Some("#fff".into()),
+            ..Default::default()
+        });
+        let width = svg_number(text_width("x", true) * 10.0 + 8.0);
+        assert!(padded.contains(&format!("width=\"{}\" height=\"18\"", width)));
+        assert!(padded.contains("<rect width=\"100%\" height=\"100%\" fill=\"#fff\"/>"));
+    }
+
+    #[test]
+    fn test_svg_escapes_attributes() {
+        let eq = EquationNode::Variable("a<b".into());
+        let svg = eq.to_svg(&SvgExportOptions {
+            color: "\"><script>".into(),
+            ..Default::default()
+        });
+        assert!(svg.contains("fill=\"&quot;&gt;&lt;script&gt;\""));
+        assert!(!svg.contains("<script"));
+        // Markup characters in the equation become outlines, not text
+        assert!(svg.contains(glyphs::glyph('<', true).path));
+    }
+
+    fn var(name: &str) -> Box<EquationNode> {
+        Box::new(EquationNode::Variable(name.into()))
+    }
+
+    #[test]
+    fn test_delete_wedge_right_operand_collapses() {
+        // (a ∧ b) + c, delete b
+        let mut eq = EquationNode::ArithmeticOp {
+            op: '+',
+            left: Box::new(EquationNode::BinaryOp {
+                op: GeometricOp::WedgeProduct,
+                left: var("a"),
+                right: var("b"),
+            }),
+            right: var("c"),
+        };
+        let cursor = eq.delete_at(&[0, 1]);
+        assert_eq!(cursor, vec![0]);
+        assert_eq!(eq.to_unicode(), "a + c");
+        assert_eq!(
+            eq.node_at(&cursor),
+            Some(&EquationNode::Variable("a".into()))
+        );
+    }
+
+    #[test]
+    fn test_delete_wrapper_operand_then_wrapper() {
+        let mut eq = EquationNode::UnaryOp {
+            op: UnaryOp::Reverse,
+            operand: var("R"),
+        };
+        assert_eq!(eq.delete_at(&[0]), vec![0]);
+        assert_eq!(eq.to_unicode(), "□†");
+        assert_eq!(eq.delete_at(&[0]), Vec::<usize>::new());
+        assert_eq!(eq, EquationNode::Placeholder);
+    }
+
+    #[test]
+    fn test_cursor_moves_across_fraction() {
+        // a/b ∧ c
+        let eq = EquationNode::BinaryOp {
+            op: GeometricOp::WedgeProduct,
+            left: Box::new(EquationNode::Fraction {
+                numerator: var("a"),
+                denominator: var("b"),
+            }),
+            right: var("c"),
+        };
+        assert_eq!(eq.leaf_paths(), vec![vec![0, 0], vec![0, 1], vec![1]]);
+
+        let right = eq.navigate(&[0, 0], "ArrowRight").unwrap();
+        assert_eq!(right, vec![0, 1]);
+        let right = eq.navigate(&right, "ArrowRight").unwrap();
+        assert_eq!(right, vec![1]);
+        // Stays put at the last leaf
+        assert_eq!(eq.navigate(&right, "ArrowRight").unwrap(), vec![1]);
+
+        let left = eq.navigate(&right, "ArrowLeft").unwrap();
+        assert_eq!(left, vec![0, 1]);
+        assert_eq!(eq.navigate(&left, "ArrowUp").unwrap(), vec![0]);
+        assert_eq!(eq.navigate(&[0], "ArrowDown").unwrap(), vec![0, 0]);
+        // A compound selection collapses to its first or last leaf
+        assert_eq!(eq.navigate(&[0], "ArrowRight").unwrap(), vec![0, 1]);
+        assert_eq!(eq.navigate(&[], "ArrowLeft").unwrap(), vec![0, 0]);
+        assert_eq!(eq.navigate(&[], "Enter"), None);
+    }
+
+    #[test]
+    fn test_segments_map_to_paths() {
+        let eq = EquationNode::Fraction {
+            numerator: var("a"),
+            denominator: Box::new(EquationNode::Placeholder),
+        };
+        let segments = eq.segments();
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].path, vec![0]);
+        assert_eq!(segments[1].path, Vec::<usize>::new());
+        assert_eq!(segments[2].text, "□");
+        assert_eq!(eq.placeholder_paths(), vec![vec![1]]);
+        assert_eq!(eq.clamp_path(&[1, 4]), vec![1]);
+    }
+
+    #[test]
+    fn test_mathml_fraction() {
+        let eq = EquationNode::Fraction {
+            numerator: var("a"),
+            denominator: Box::new(EquationNode::BasisVector {
+                basis_type: BasisType::Standard,
+                index: 1,
+            }),
+        };
+        assert_eq!(
+            eq.to_mathml(),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+             <mfrac><mi>a</mi><msub><mi>e</mi><mn>1</mn></msub></mfrac></math>"
+        );
+    }
+
+    #[test]
+    fn test_mathml_grade_projection() {
+        let eq = EquationNode::GradeProjection {
+            grade: 2,
+            operand: Box::new(EquationNode::BinaryOp {
+                op: GeometricOp::WedgeProduct,
+                left: var("a"),
+                right: var("b"),
+            }),
+        };
+        assert_eq!(
+            eq.to_mathml(),
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">\
+             <msub><mrow><mo>⟨</mo><mrow><mi>a</mi><mo>∧</mo><mi>b</mi></mrow><mo>⟩</mo></mrow>\
+             <mn>2</mn></msub></math>"
+        );
+    }
+
+    #[test]
+    fn test_mathml_reverse_and_escaping() {
+        let eq = EquationNode::UnaryOp {
+            op: UnaryOp::Reverse,
+            operand: var("R"),
+        };
+        assert!(eq.to_mathml().contains("<msup><mi>R</mi><mo>†</mo></msup>"));
+
+        let eq = EquationNode::Variable("a<b".into());
+        assert!(eq.to_mathml().contains("<mi>a&lt;b</mi>"));
+        assert!(EquationNode::Placeholder.to_mathml().contains("□"));
+    }
+
+    #[test]
+    fn test_definite_integral_latex() {
+        let eq = EquationNode::Integral {
+            variable: "x".into(),
+            lower: Some(Box::new(EquationNode::Number(0.0))),
+            upper: Some(Box::new(EquationNode::Number(1.0))),
+            integrand: Box::new(EquationNode::Superscript {
+                base: var("x"),
+                superscript: Box::new(EquationNode::Number(2.0)),
+            }),
+        };
+        assert_eq!(eq.to_latex(), "\\int_{0}^{1} x^{2} \\, dx");
+        assert_eq!(eq.to_unicode(), "∫[0→1] x^2 dx");
+        assert_eq!(eq.children().len(), 3);
+
+        let indefinite = EquationNode::Integral {
+            variable: "t".into(),
+            lower: None,
+            upper: None,
+            integrand: var("f"),
+        };
+        assert_eq!(indefinite.to_latex(), "\\int f \\, dt");
+        assert_eq!(indefinite.leaf_paths(), vec![vec![0]]);
+    }
+
+    #[test]
+    fn test_matrix_latex() {
+        let eq = EquationNode::Matrix {
+            rows: vec![
+                vec![
+                    EquationNode::Variable("a".into()),
+                    EquationNode::Number(1.0),
+                ],
+                vec![EquationNode::Number(0.0), EquationNode::Placeholder],
+            ],
+        };
+        assert_eq!(
+            eq.to_latex(),
+            "\\begin{pmatrix} a & 1 \\\\ 0 & \\square \\end{pmatrix}"
+        );
+        assert_eq!(eq.to_unicode(), "[a, 1; 0, □]");
+        assert_eq!(eq.placeholder_paths(), vec![vec![3]]);
+        assert!(eq.to_svg(&SvgExportOptions::default()).contains("<rect"));
+    }
+
+    #[test]
+    fn test_summation_slots_are_navigable() {
+        let mut eq = EquationNode::Summation {
+            index: "k".into(),
+            lower: Box::new(EquationNode::Placeholder),
+            upper: var("n"),
+            body: var("a"),
+        };
+        assert_eq!(eq.to_latex(), "\\sum_{k=\\square}^{n} a");
+        assert_eq!(eq.to_unicode(), "∑[k=□→n] a");
+        assert_eq!(eq.navigate(&[0], "ArrowRight").unwrap(), vec![1]);
+        assert_eq!(eq.navigate(&[1], "ArrowRight").unwrap(), vec![2]);
+
+        // Three slots: deleting a bound empties it rather than collapsing
+        assert_eq!(eq.delete_at(&[1]), vec![1]);
+        assert_eq!(eq.placeholder_count(), 2);
+    }
 }
@@ -0,0 +1,2057 @@
+//! Glyph outlines for SVG export, so exported equations render the same
+//! without any fonts installed.
+//!
+//! Outlines are taken from DejaVu Serif 2.37 (upright) and DejaVu Serif
+//! Italic (letters and digits only), with a few symbols DejaVu Serif lacks
+//! taken from DejaVu Sans. Coordinates are scaled to 1000 units per em with
+//! the origin on the baseline at the left edge and y growing downward, as in
+//! SVG. Tables are sorted by character.
+//!
+//! Copyright (c) 2003 by Bitstream, Inc. All Rights Reserved. Bitstream Vera
+//! is a trademark of Bitstream, Inc. DejaVu changes are in public domain.
+//!
+//! Permission is hereby granted, free of charge, to any person obtaining a
+//! copy of the fonts accompanying this license ("Fonts") and associated
+//! documentation files (the "Font Software"), to reproduce and distribute the
+//! Font Software, including without limitation the rights to use, copy,
+//! merge, publish, distribute, and/or sell copies of the Font Software, and
+//! to permit persons to whom the Font Software is furnished to do so, subject
+//! to the following conditions:
+//!
+//! The above copyright and trademark notices and this permission notice shall
+//! be included in all copies of one or more of the Font Software typefaces.
+//!
+//! The Font Software may be modified, altered, or added to, and in particular
+//! the designs of glyphs or characters in the Fonts may be modified and
+//! additional glyphs or characters may be added to the Fonts, only if the
+//! fonts are renamed to names not containing either the words "Bitstream" or
+//! the word "Vera".
+//!
+//! This License becomes null and void to the extent applicable to Fonts or
+//! Font Software that has been modified and is distributed under the
+//! "Bitstream Vera" names.
+//!
+//! The Font Software may be sold as part of a larger software package but no
+//! copy of one or more of the Font Software typefaces may be sold by itself.
+//!
+//! THE FONT SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+//! EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO ANY WARRANTIES OF
+//! MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT OF
+//! COPYRIGHT, PATENT, TRADEMARK, OR OTHER RIGHT. IN NO EVENT SHALL BITSTREAM
+//! OR THE GNOME FOUNDATION BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+//! LIABILITY, INCLUDING ANY GENERAL, SPECIAL, INDIRECT, INCIDENTAL, OR
+//! CONSEQUENTIAL DAMAGES, WHETHER IN AN ACTION OF CONTRACT, TORT OR
+//! OTHERWISE, ARISING FROM, OUT OF THE USE OR INABILITY TO USE THE FONT
+//! SOFTWARE OR FROM OTHER DEALINGS IN THE FONT SOFTWARE.
+//!
+//! Except as contained in this notice, the names of Gnome, the Gnome
+//! Foundation, and Bitstream Inc., shall not be used in advertising or
+//! otherwise to promote the sale, use or other dealings in this Font Software
+//! without prior written authorization from the Gnome Foundation or Bitstream
+//! Inc., respectively. For further information, contact: fonts at gnome dot
+//! org.
+
+/// Font units per em used by the outline tables
+pub(super) const UNITS_PER_EM: f64 = 1000.0;
+
+/// One character's outline
+#[derive(Debug, PartialEq)]
+pub(super) struct Glyph {
+    pub c: char,
+    /// Horizontal advance in font units
+    pub advance: u16,
+    /// SVG path data in font units; empty for blank glyphs such as space
+    pub path: &'static str,
+}
+
+/// Hollow box drawn for characters without an outline
+pub(super) static MISSING: Glyph = Glyph {
+    c: '\u{fffd}',
+    advance: 600,
+    path: "M50 -700L550 -700L550 0L50 0ZM100 -50L500 -50L500 -650L100 -650Z",
+};
+
+/// Outline for `c`. Italic falls back to upright for symbols, and anything
+/// not covered gets [`MISSING`].
+pub(super) fn glyph(c: char, italic: bool) -> &'static Glyph {
+    let find = |table: &'static [Glyph]| {
+        table
+            .binary_search_by(|g| g.c.cmp(&c))
+            .ok()
+            .map(|i| &table[i])
+    };
+    italic
+        .then(|| find(ITALIC))
+        .flatten()
+        .or_else(|| find(UPRIGHT))
+        .unwrap_or(&MISSING)
+}
+
+#[rustfmt::skip]
+pub(super) static UPRIGHT: &[Glyph] = &[
+    Glyph {
+        c: ' ',
+        advance: 318,
+        path: "",
+    },
+    Glyph {
+        c: '!',
+        advance: 402,
+        path: "M136 -51Q136 -78 155 -97Q173 -116 201 -116Q228 -116 247 -97Q266 -78 266 -51Q266 -24 247 -5Q228 14 201 14Q173 14 155 -5Q136 -23 136 -51ZM138 -729L264 -729L231 -328L231 -205L171 -205L171 -328Z",
+    },
+    Glyph {
+        c: '"',
+        advance: 460,
+        path: "M176 -729L176 -458L98 -458L98 -729ZM362 -729L362 -458L284 -458L284 -729Z",
+    },
+    Glyph {
+        c: '#',
+        advance: 838,
+        path: "M509 -440L371 -440L330 -277L469 -277ZM440 -718L390 -515L528 -515L578 -718L662 -718L611 -515L761 -515L761 -440L592 -440L552 -277L705 -277L705 -202L533 -202L483 0L399 0L450 -202L312 -202L261 0L178 0L228 -202L77 -202L77 -277L247 -277L287 -440L133 -440L133 -515L306 -515L357 -718Z",
+    },
+    Glyph {
+        c: '$',
+        advance: 636,
+        path: "M337 -33Q392 -38 421 -65Q450 -92 450 -138Q450 -180 421 -209Q393 -239 337 -254ZM288 -580Q238 -576 210 -551Q182 -525 182 -482Q182 -443 208 -417Q233 -391 288 -375ZM86 -35L86 -170L139 -170Q141 -103 178 -69Q215 -35 288 -33L288 -270Q176 -301 130 -342Q85 -383 85 -450Q85 -528 139 -576Q192 -623 288 -629L288 -760L337 -760L337 -629Q386 -625 432 -613Q478 -601 521 -581L521 -453L468 -453Q462 -510 428 -542Q395 -575 337 -580L337 -359Q457 -326 505 -284Q553 -242 553 -171Q553 -90 497 -41Q440 8 337 15L337 147L288 147L288 15Q241 14 191 2Q141 -11 86 -35Z",
+    },
+    Glyph {
+        c: '%',
+        advance: 950,
+        path: "M224 -697Q181 -697 157 -656Q133 -616 133 -543Q133 -471 157 -430Q182 -389 224 -389Q266 -389 290 -430Q314 -471 314 -543Q314 -615 290 -656Q266 -697 224 -697ZM727 -339Q685 -339 660 -298Q636 -257 636 -184Q636 -112 661 -72Q685 -31 727 -31Q769 -31 793 -72Q817 -112 817 -184Q817 -256 793 -298Q769 -339 727 -339ZM727 -383Q804 -383 850 -329Q895 -275 895 -184Q895 -93 849 -39Q804 14 727 14Q649 14 604 -39Q558 -93 558 -184Q558 -276 604 -329Q649 -383 727 -383ZM666 -742L740 -742L284 14L210 14ZM223 -742Q300 -742 346 -689Q392 -635 392 -544Q392 -452 346 -398Q301 -345 223 -345Q146 -345 100 -399Q55 -452 55 -544Q55 -635 101 -688Q146 -742 223 -742Z",
+    },
+    Glyph {
+        c: '&',
+        advance: 890,
+        path: "M540 -113L256 -412Q213 -374 192 -330Q171 -286 171 -236Q171 -155 227 -100Q283 -45 367 -45Q417 -45 461 -62Q505 -80 540 -113ZM843 0L647 0L580 -71Q529 -28 471 -7Q413 14 345 14Q219 14 146 -50Q72 -115 72 -225Q72 -290 109 -346Q146 -403 222 -452Q197 -482 186 -511Q174 -541 174 -573Q174 -651 230 -697Q286 -742 382 -742Q415 -742 458 -734Q502 -726 558 -709L558 -583L503 -583Q496 -635 465 -663Q434 -690 380 -690Q330 -690 298 -662Q267 -635 267 -591Q267 -561 284 -531Q300 -502 353 -447L605 -181Q639 -220 658 -265Q677 -311 682 -362L594 -362L594 -414L825 -414L825 -362L741 -362Q733 -298 709 -243Q685 -188 644 -141L728 -52L843 -52Z",
+    },
+    Glyph {
+        c: '\'',
+        advance: 275,
+        path: "M176 -729L176 -458L98 -458L98 -729Z",
+    },
+    Glyph {
+        c: '(',
+        advance: 390,
+        path: "M319 156Q200 103 140 -13Q79 -128 79 -302Q79 -476 140 -591Q200 -707 319 -760L319 -712Q244 -660 211 -566Q178 -472 178 -302Q178 -132 211 -38Q244 56 319 108Z",
+    },
+    Glyph {
+        c: ')',
+        advance: 390,
+        path: "M71 156L71 108Q146 56 179 -38Q212 -132 212 -302Q212 -472 179 -566Q146 -660 71 -712L71 -760Q190 -707 250 -591Q311 -476 311 -302Q311 -128 250 -13Q190 103 71 156Z",
+    },
+    Glyph {
+        c: '*',
+        advance: 500,
+        path: "M484 -604L293 -515L484 -425L447 -367L277 -473L283 -287L217 -287L223 -473L53 -367L16 -425L207 -514L16 -604L53 -662L223 -556L217 -742L283 -742L277 -556L447 -662Z",
+    },
+    Glyph {
+        c: '+',
+        advance: 838,
+        path: "M458 -627L458 -353L732 -353L732 -274L458 -274L458 0L380 0L380 -274L106 -274L106 -353L380 -353L380 -627Z",
+    },
+    Glyph {
+        c: ',',
+        advance: 318,
+        path: "M36 97Q82 63 104 18Q125 -28 125 -93L125 -111L221 -111Q217 -27 182 32Q146 92 75 136Z",
+    },
+    Glyph {
+        c: '-',
+        advance: 338,
+        path: "M44 -306L294 -306L294 -230L44 -230Z",
+    },
+    Glyph {
+        c: '.',
+        advance: 318,
+        path: "M94 -51Q94 -78 113 -97Q131 -116 159 -116Q186 -116 205 -97Q224 -78 224 -51Q224 -24 205 -5Q186 14 159 14Q131 14 113 -5Q94 -23 94 -51Z",
+    },
+    Glyph {
+        c: '/',
+        advance: 337,
+        path: "M259 -729L337 -729L78 93L0 93Z",
+    },
+    Glyph {
+        c: '0',
+        advance: 636,
+        path: "M318 -34Q393 -34 430 -116Q467 -198 467 -364Q467 -530 430 -612Q393 -694 318 -694Q243 -694 206 -612Q169 -530 169 -364Q169 -198 206 -116Q243 -34 318 -34ZM318 14Q199 14 133 -85Q66 -185 66 -364Q66 -543 133 -643Q199 -742 318 -742Q437 -742 503 -643Q570 -543 570 -364Q570 -185 503 -85Q437 14 318 14Z",
+    },
+    Glyph {
+        c: '1',
+        advance: 636,
+        path: "M142 0L142 -52L269 -52L269 -658L122 -563L122 -627L300 -742L367 -742L367 -52L494 -52L494 0Z",
+    },
+    Glyph {
+        c: '2',
+        advance: 636,
+        path: "M128 -555L73 -555L73 -685Q125 -713 178 -728Q231 -742 282 -742Q396 -742 462 -687Q528 -632 528 -537Q528 -430 378 -281Q367 -270 361 -264L177 -80L481 -80L481 -170L538 -170L538 0L68 0L68 -53L289 -274Q362 -347 394 -408Q425 -470 425 -537Q425 -611 386 -652Q348 -694 281 -694Q211 -694 173 -659Q135 -625 128 -555Z",
+    },
+    Glyph {
+        c: '3',
+        advance: 636,
+        path: "M97 -698Q154 -720 207 -731Q259 -742 305 -742Q412 -742 472 -696Q532 -650 532 -568Q532 -502 491 -458Q449 -414 373 -398Q463 -385 512 -333Q562 -280 562 -197Q562 -95 493 -40Q425 14 296 14Q239 14 184 2Q130 -10 76 -35L76 -177L131 -177Q136 -106 178 -70Q221 -34 298 -34Q373 -34 416 -77Q459 -121 459 -196Q459 -282 415 -326Q370 -370 284 -370L238 -370L238 -420L262 -420Q348 -420 390 -455Q433 -491 433 -562Q433 -626 398 -660Q363 -694 297 -694Q231 -694 195 -663Q158 -631 152 -570L97 -570Z",
+    },
+    Glyph {
+        c: '4',
+        advance: 636,
+        path: "M349 -247L349 -635L100 -247ZM564 0L232 0L232 -52L349 -52L349 -195L31 -195L31 -248L350 -742L447 -742L447 -247L586 -247L586 -195L447 -195L447 -52L564 -52Z",
+    },
+    Glyph {
+        c: '5',
+        advance: 636,
+        path: "M503 -729L503 -649L169 -649L169 -440Q194 -458 228 -466Q262 -475 304 -475Q422 -475 491 -410Q559 -344 559 -231Q559 -115 490 -51Q421 14 296 14Q246 14 193 2Q140 -10 85 -35L85 -177L140 -177Q145 -107 184 -71Q224 -34 296 -34Q373 -34 415 -85Q456 -136 456 -231Q456 -326 415 -376Q374 -427 296 -427Q252 -427 219 -411Q185 -396 159 -363L117 -363L117 -729Z",
+    },
+    Glyph {
+        c: '6',
+        advance: 636,
+        path: "M327 -34Q396 -34 433 -85Q470 -135 470 -230Q470 -325 433 -375Q396 -426 327 -426Q257 -426 221 -377Q184 -328 184 -236Q184 -139 221 -86Q258 -34 327 -34ZM168 -401Q201 -438 243 -456Q285 -474 338 -474Q447 -474 510 -409Q573 -343 573 -230Q573 -119 505 -52Q437 14 323 14Q200 14 133 -78Q67 -170 67 -341Q67 -532 146 -637Q224 -742 367 -742Q406 -742 448 -735Q491 -728 535 -713L535 -593L480 -593Q474 -642 442 -668Q411 -694 357 -694Q262 -694 216 -622Q169 -550 168 -401Z",
+    },
+    Glyph {
+        c: '7',
+        advance: 636,
+        path: "M564 -679L279 0L206 0L478 -649L141 -649L141 -559L84 -559L84 -729L564 -729Z",
+    },
+    Glyph {
+        c: '8',
+        advance: 636,
+        path: "M466 -199Q466 -277 427 -321Q388 -364 318 -364Q248 -364 209 -321Q170 -277 170 -199Q170 -121 209 -77Q248 -34 318 -34Q388 -34 427 -77Q466 -121 466 -199ZM446 -553Q446 -620 412 -657Q378 -694 318 -694Q258 -694 224 -657Q190 -620 190 -553Q190 -486 224 -449Q258 -412 318 -412Q378 -412 412 -449Q446 -486 446 -553ZM393 -388Q476 -377 522 -327Q569 -277 569 -199Q569 -97 504 -41Q439 14 318 14Q197 14 132 -41Q67 -97 67 -199Q67 -277 113 -327Q160 -377 243 -388Q169 -401 130 -444Q91 -487 91 -553Q91 -641 151 -692Q212 -742 318 -742Q424 -742 484 -692Q545 -641 545 -553Q545 -487 506 -444Q466 -401 393 -388Z",
+    },
+    Glyph {
+        c: '9',
+        advance: 636,
+        path: "M468 -327Q435 -290 393 -272Q350 -254 297 -254Q188 -254 126 -319Q63 -385 63 -498Q63 -609 131 -676Q199 -742 313 -742Q437 -742 503 -650Q569 -558 569 -387Q569 -196 490 -91Q412 14 269 14Q230 14 188 7Q146 0 101 -15L101 -136L156 -136Q162 -87 194 -61Q226 -34 279 -34Q374 -34 420 -106Q466 -177 468 -327ZM309 -694Q240 -694 203 -643Q166 -593 166 -498Q166 -403 203 -353Q240 -302 309 -302Q378 -302 415 -351Q452 -400 452 -492Q452 -589 415 -642Q378 -694 309 -694Z",
+    },
+    Glyph {
+        c: ':',
+        advance: 337,
+        path: "M104 -51Q104 -78 123 -97Q142 -116 169 -116Q196 -116 215 -97Q234 -78 234 -51Q234 -23 215 -5Q197 14 169 14Q141 14 123 -5Q104 -23 104 -51ZM104 -369Q104 -396 123 -415Q142 -434 169 -434Q197 -434 215 -416Q234 -397 234 -369Q234 -341 215 -323Q197 -304 169 -304Q142 -304 123 -323Q104 -342 104 -369Z",
+    },
+    Glyph {
+        c: ';',
+        advance: 337,
+        path: "M36 97Q82 63 104 18Q125 -28 125 -93L125 -111L221 -111Q217 -27 182 32Q146 92 75 136ZM106 -369Q106 -396 125 -415Q144 -434 171 -434Q198 -434 217 -415Q236 -396 236 -369Q236 -342 217 -323Q198 -304 171 -304Q144 -304 125 -323Q106 -342 106 -369Z",
+    },
+    Glyph {
+        c: '<',
+        advance: 838,
+        path: "M732 -498L225 -313L732 -129L732 -46L106 -273L106 -354L732 -581Z",
+    },
+    Glyph {
+        c: '=',
+        advance: 838,
+        path: "M106 -452L732 -452L732 -374L106 -374ZM106 -253L732 -253L732 -175L106 -175Z",
+    },
+    Glyph {
+        c: '>',
+        advance: 838,
+        path: "M106 -498L106 -581L732 -354L732 -273L106 -46L106 -129L613 -313Z",
+    },
+    Glyph {
+        c: '?',
+        advance: 536,
+        path: "M176 -51Q176 -78 195 -97Q213 -116 241 -116Q268 -116 287 -97Q306 -78 306 -51Q306 -24 287 -5Q268 14 241 14Q213 14 195 -5Q176 -23 176 -51ZM68 -697Q120 -720 166 -731Q213 -742 254 -742Q362 -742 425 -690Q488 -638 488 -549Q488 -458 434 -400Q380 -343 271 -318L271 -194L211 -194L211 -354Q297 -375 341 -424Q385 -474 385 -550Q385 -617 349 -655Q313 -694 252 -694Q196 -694 161 -662Q125 -630 115 -570L68 -570Z",
+    },
+    Glyph {
+        c: '@',
+        advance: 1000,
+        path: "M632 -121Q603 -81 567 -62Q531 -42 486 -42Q400 -42 347 -103Q294 -164 294 -262Q294 -360 347 -421Q400 -482 486 -482Q531 -482 567 -462Q603 -443 632 -403L632 -472L708 -472L708 -95Q791 -113 837 -174Q883 -235 883 -327Q883 -381 866 -430Q850 -478 817 -519Q765 -584 691 -618Q616 -653 529 -653Q485 -653 442 -644Q399 -634 357 -615Q258 -570 204 -478Q149 -386 149 -265Q149 -184 173 -115Q198 -46 244 4Q297 62 370 93Q443 123 528 123Q591 123 646 104Q700 86 747 49L773 83Q720 128 653 151Q586 174 507 174Q417 174 336 141Q255 109 194 49Q131 -13 98 -93Q66 -173 66 -265Q66 -355 99 -435Q131 -514 194 -576Q256 -638 340 -671Q424 -704 519 -704Q624 -704 710 -664Q796 -623 854 -547Q891 -499 910 -443Q929 -387 929 -326Q929 -195 850 -119Q770 -43 632 -42ZM632 -237L632 -288Q632 -349 597 -387Q562 -425 506 -425Q447 -425 414 -382Q381 -339 381 -262Q381 -185 414 -142Q447 -99 507 -99Q562 -99 597 -137Q632 -176 632 -237Z",
+    },
+    Glyph {
+        c: 'A',
+        advance: 722,
+        path: "M200 -264L468 -264L334 -611ZM-6 0L-6 -52L58 -52L318 -729L400 -729L660 -52L732 -52L732 0L467 0L467 -52L548 -52L487 -212L180 -212L119 -52L199 -52L199 0Z",
+    },
+    Glyph {
+        c: 'B',
+        advance: 735,
+        path: "M247 -52L393 -52Q481 -52 521 -90Q562 -128 562 -211Q562 -293 522 -331Q481 -369 393 -369L247 -369ZM247 -421L371 -421Q451 -421 488 -452Q525 -482 525 -549Q525 -616 488 -646Q451 -677 371 -677L247 -677ZM55 0L55 -52L148 -52L148 -677L55 -677L55 -729L415 -729Q525 -729 581 -684Q637 -639 637 -549Q637 -484 598 -445Q560 -407 485 -398Q578 -386 626 -339Q674 -291 674 -211Q674 -103 605 -51Q537 0 392 0Z",
+    },
+    Glyph {
+        c: 'C',
+        advance: 765,
+        path: "M705 -193Q673 -91 597 -38Q521 14 405 14Q333 14 272 -10Q211 -35 164 -82Q109 -137 83 -206Q56 -276 56 -364Q56 -534 154 -638Q252 -742 413 -742Q473 -742 540 -727Q607 -711 685 -679L685 -511L630 -511Q612 -603 557 -646Q503 -690 405 -690Q288 -690 228 -607Q168 -524 168 -364Q168 -204 228 -121Q288 -38 405 -38Q486 -38 539 -77Q592 -116 615 -193Z",
+    },
+    Glyph {
+        c: 'D',
+        advance: 802,
+        path: "M247 -52L338 -52Q480 -52 556 -133Q632 -214 632 -365Q632 -517 556 -597Q480 -677 338 -677L247 -677ZM55 0L55 -52L148 -52L148 -677L55 -677L55 -729L345 -729Q534 -729 639 -633Q744 -537 744 -365Q744 -193 639 -97Q533 0 345 0Z",
+    },
+    Glyph {
+        c: 'E',
+        advance: 730,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L642 -729L642 -567L582 -567L582 -669L247 -669L247 -425L486 -425L486 -516L546 -516L546 -274L486 -274L486 -365L247 -365L247 -60L590 -60L590 -162L650 -162L650 0Z",
+    },
+    Glyph {
+        c: 'F',
+        advance: 694,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L652 -729L652 -567L592 -567L592 -669L247 -669L247 -425L496 -425L496 -516L556 -516L556 -274L496 -274L496 -365L247 -365L247 -52L364 -52L364 0Z",
+    },
+    Glyph {
+        c: 'G',
+        advance: 799,
+        path: "M640 -511Q623 -603 569 -646Q515 -690 418 -690Q292 -690 230 -609Q168 -528 168 -364Q168 -203 232 -121Q296 -38 420 -38Q475 -38 525 -52Q576 -65 621 -93L621 -281L484 -281L484 -333L720 -333L720 -61Q655 -23 580 -5Q505 14 420 14Q255 14 156 -89Q56 -192 56 -364Q56 -537 156 -639Q255 -742 424 -742Q486 -742 553 -728Q620 -713 695 -684L695 -511Z",
+    },
+    Glyph {
+        c: 'H',
+        advance: 872,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677L247 -425L625 -425L625 -677L532 -677L532 -729L817 -729L817 -677L724 -677L724 -52L817 -52L817 0L532 0L532 -52L625 -52L625 -365L247 -365L247 -52L340 -52L340 0Z",
+    },
+    Glyph {
+        c: 'I',
+        advance: 395,
+        path: "M247 -52L340 -52L340 0L55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677Z",
+    },
+    Glyph {
+        c: 'J',
+        advance: 401,
+        path: "M-84 175L-84 60L-28 60Q-27 108 -5 132Q16 156 59 156Q117 156 140 119Q163 83 163 -24L163 -677L50 -677L50 -729L355 -729L355 -677L262 -677L262 -20Q262 101 214 154Q166 208 58 208Q23 208 -12 200Q-48 191 -84 175Z",
+    },
+    Glyph {
+        c: 'K',
+        advance: 747,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677L247 -401L561 -677L482 -677L482 -729L724 -729L724 -677L642 -677L329 -402L679 -52L762 -52L762 0L593 0L247 -346L247 -52L340 -52L340 0Z",
+    },
+    Glyph {
+        c: 'L',
+        advance: 664,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677L247 -60L580 -60L580 -182L640 -182L640 0Z",
+    },
+    Glyph {
+        c: 'M',
+        advance: 1024,
+        path: "M55 0L55 -52L148 -52L148 -677L50 -677L50 -729L262 -729L518 -210L774 -729L973 -729L973 -677L876 -677L876 -52L969 -52L969 0L684 0L684 -52L777 -52L777 -615L527 -107L458 -107L208 -615L208 -52L301 -52L301 0Z",
+    },
+    Glyph {
+        c: 'N',
+        advance: 875,
+        path: "M49 0L49 -52L147 -52L147 -677L49 -677L49 -729L236 -729L673 -154L673 -677L575 -677L575 -729L831 -729L831 -677L733 -677L733 14L674 14L207 -600L207 -52L305 -52L305 0Z",
+    },
+    Glyph {
+        c: 'O',
+        advance: 820,
+        path: "M410 -38Q530 -38 591 -120Q652 -203 652 -364Q652 -525 591 -608Q530 -690 410 -690Q290 -690 229 -608Q168 -525 168 -364Q168 -203 229 -120Q290 -38 410 -38ZM410 14Q336 14 274 -10Q211 -35 164 -82Q109 -137 83 -206Q56 -275 56 -364Q56 -452 83 -522Q109 -591 164 -646Q212 -694 273 -718Q335 -742 410 -742Q569 -742 667 -638Q764 -534 764 -364Q764 -276 737 -206Q710 -136 656 -82Q608 -34 546 -10Q485 14 410 14Z",
+    },
+    Glyph {
+        c: 'P',
+        advance: 673,
+        path: "M247 -371L376 -371Q449 -371 487 -410Q525 -450 525 -524Q525 -599 487 -638Q449 -677 376 -677L247 -677ZM55 0L55 -52L148 -52L148 -677L55 -677L55 -729L400 -729Q509 -729 573 -674Q637 -618 637 -524Q637 -430 573 -375Q509 -319 400 -319L247 -319L247 -52L360 -52L360 0Z",
+    },
+    Glyph {
+        c: 'Q',
+        advance: 820,
+        path: "M422 14Q254 14 155 -89Q56 -191 56 -364Q56 -452 83 -522Q109 -591 164 -646Q212 -694 273 -718Q335 -742 410 -742Q569 -742 667 -638Q764 -534 764 -364Q764 -219 691 -121Q618 -22 489 6Q515 39 553 54Q591 70 644 70L659 70L659 160Q577 156 518 120Q459 83 422 14ZM410 -38Q530 -38 591 -120Q652 -203 652 -364Q652 -525 591 -608Q530 -690 410 -690Q290 -690 229 -608Q168 -525 168 -364Q168 -203 229 -120Q290 -38 410 -38Z",
+    },
+    Glyph {
+        c: 'R',
+        advance: 753,
+        path: "M479 -362Q513 -353 538 -330Q562 -308 582 -268L688 -52L777 -52L777 0L605 0L491 -232Q458 -299 431 -319Q404 -339 356 -339L247 -339L247 -52L350 -52L350 0L55 0L55 -52L148 -52L148 -677L55 -677L55 -729L425 -729Q531 -729 589 -678Q647 -627 647 -534Q647 -459 605 -416Q562 -373 479 -362ZM247 -391L391 -391Q465 -391 500 -426Q535 -461 535 -534Q535 -607 500 -642Q465 -677 391 -677L247 -677Z",
+    },
+    Glyph {
+        c: 'S',
+        advance: 685,
+        path: "M93 -35L93 -201L149 -201Q151 -118 197 -78Q243 -38 336 -38Q423 -38 469 -73Q514 -107 514 -173Q514 -226 487 -254Q459 -282 370 -309L274 -338Q169 -370 127 -417Q84 -464 84 -547Q84 -640 150 -691Q216 -742 335 -742Q386 -742 446 -731Q507 -720 575 -699L575 -544L520 -544Q512 -621 469 -656Q425 -690 337 -690Q260 -690 219 -658Q179 -627 179 -567Q179 -515 209 -485Q240 -455 338 -426L428 -399Q527 -369 570 -323Q612 -277 612 -199Q612 -93 544 -40Q476 14 342 14Q282 14 219 2Q157 -10 93 -35Z",
+    },
+    Glyph {
+        c: 'T',
+        advance: 667,
+        path: "M191 0L191 -52L284 -52L284 -671L70 -671L70 -557L10 -557L10 -729L657 -729L657 -557L597 -557L597 -671L383 -671L383 -52L476 -52L476 0Z",
+    },
+    Glyph {
+        c: 'U',
+        advance: 843,
+        path: "M140 -677L47 -677L47 -729L332 -729L332 -677L239 -677L239 -300Q239 -160 284 -105Q330 -50 442 -50Q554 -50 599 -105Q645 -160 645 -300L645 -677L552 -677L552 -729L798 -729L798 -677L705 -677L705 -290Q705 -124 641 -55Q577 14 424 14Q271 14 205 -55Q140 -125 140 -290Z",
+    },
+    Glyph {
+        c: 'V',
+        advance: 722,
+        path: "M175 -677L390 -117L605 -677L523 -677L523 -729L737 -729L737 -677L666 -677L406 0L322 0L63 -677L-10 -677L-10 -729L256 -729L256 -677Z",
+    },
+    Glyph {
+        c: 'W',
+        advance: 1028,
+        path: "M762 0L682 0L515 -593L348 0L268 0L77 -677L5 -677L5 -729L271 -729L271 -677L180 -677L332 -138L498 -729L577 -729L746 -132L899 -677L815 -677L815 -729L1025 -729L1025 -677L953 -677Z",
+    },
+    Glyph {
+        c: 'X',
+        advance: 712,
+        path: "M331 -312L153 -52L247 -52L247 0L6 0L6 -52L90 -52L300 -358L87 -677L9 -677L9 -729L298 -729L298 -677L212 -677L368 -442L529 -677L435 -677L435 -729L674 -729L674 -677L592 -677L399 -396L629 -52L707 -52L707 0L418 0L418 -52L505 -52Z",
+    },
+    Glyph {
+        c: 'Y',
+        advance: 660,
+        path: "M189 0L189 -52L282 -52L282 -314L57 -677L-11 -677L-11 -729L260 -729L260 -677L175 -677L357 -382L539 -677L457 -677L457 -729L669 -729L669 -677L600 -677L381 -323L381 -52L474 -52L474 0Z",
+    },
+    Glyph {
+        c: 'Z',
+        advance: 695,
+        path: "M45 0L45 -35L499 -669L122 -669L122 -560L62 -560L62 -729L638 -729L638 -694L184 -60L596 -60L596 -162L656 -162L656 0Z",
+    },
+    Glyph {
+        c: '[',
+        advance: 390,
+        path: "M86 -760L313 -760L313 -708L179 -708L179 80L313 80L313 132L86 132Z",
+    },
+    Glyph {
+        c: '\\',
+        advance: 337,
+        path: "M78 -729L337 93L259 93L0 -729Z",
+    },
+    Glyph {
+        c: ']',
+        advance: 390,
+        path: "M304 -760L304 132L77 132L77 80L211 80L211 -708L77 -708L77 -760Z",
+    },
+    Glyph {
+        c: '^',
+        advance: 838,
+        path: "M467 -729L732 -457L657 -457L419 -643L181 -457L106 -457L371 -729Z",
+    },
+    Glyph {
+        c: '_',
+        advance: 500,
+        path: "M500 197L500 236L0 236L0 197Z",
+    },
+    Glyph {
+        c: '`',
+        advance: 500,
+        path: "M179 -799L306 -615L249 -615L83 -799Z",
+    },
+    Glyph {
+        c: 'a',
+        advance: 596,
+        path: "M398 -163L398 -273L282 -273Q215 -273 183 -244Q150 -215 150 -156Q150 -102 183 -70Q216 -38 273 -38Q329 -38 364 -73Q398 -107 398 -163ZM488 -324L488 -52L568 -52L568 0L398 0L398 -56Q368 -20 329 -3Q290 14 238 14Q151 14 101 -32Q50 -78 50 -156Q50 -236 108 -281Q166 -325 272 -325L398 -325L398 -361Q398 -420 362 -452Q326 -485 261 -485Q208 -485 176 -460Q144 -436 136 -388L90 -388L90 -493Q137 -513 181 -523Q225 -533 267 -533Q375 -533 431 -480Q488 -426 488 -324Z",
+    },
+    Glyph {
+        c: 'b',
+        advance: 640,
+        path: "M115 -52L115 -708L29 -708L29 -760L205 -760L205 -438Q231 -487 272 -510Q313 -533 373 -533Q469 -533 529 -458Q590 -382 590 -260Q590 -138 529 -62Q469 14 373 14Q313 14 272 -9Q231 -32 205 -81L205 0L29 0L29 -52ZM205 -234Q205 -140 241 -91Q277 -42 345 -42Q414 -42 449 -97Q485 -152 485 -260Q485 -368 449 -422Q414 -477 345 -477Q277 -477 241 -428Q205 -378 205 -285Z",
+    },
+    Glyph {
+        c: 'c',
+        advance: 560,
+        path: "M514 -156Q495 -73 441 -29Q387 14 301 14Q188 14 119 -61Q50 -136 50 -260Q50 -384 119 -459Q188 -533 301 -533Q350 -533 399 -522Q448 -510 497 -487L497 -354L445 -354Q435 -422 400 -454Q366 -485 302 -485Q229 -485 192 -428Q155 -372 155 -260Q155 -147 192 -91Q229 -34 302 -34Q360 -34 395 -64Q429 -95 442 -156Z",
+    },
+    Glyph {
+        c: 'd',
+        advance: 640,
+        path: "M525 -52L611 -52L611 0L435 0L435 -81Q409 -32 368 -9Q327 14 267 14Q171 14 111 -62Q50 -138 50 -260Q50 -382 110 -458Q171 -533 267 -533Q327 -533 368 -510Q409 -487 435 -438L435 -708L350 -708L350 -760L525 -760ZM435 -234L435 -285Q435 -378 399 -428Q363 -477 295 -477Q226 -477 190 -422Q155 -368 155 -260Q155 -152 190 -97Q226 -42 295 -42Q363 -42 399 -91Q435 -140 435 -234Z",
+    },
+    Glyph {
+        c: 'e',
+        advance: 592,
+        path: "M542 -250L155 -250L155 -246Q155 -141 194 -88Q234 -34 311 -34Q370 -34 408 -65Q446 -96 461 -157L533 -157Q512 -72 454 -29Q396 14 302 14Q188 14 119 -61Q50 -136 50 -260Q50 -383 118 -458Q186 -533 296 -533Q414 -533 477 -461Q540 -388 542 -250ZM436 -302Q433 -393 398 -439Q362 -485 296 -485Q234 -485 198 -438Q163 -392 155 -302Z",
+    },
+    Glyph {
+        c: 'f',
+        advance: 370,
+        path: "M430 -637L383 -637Q382 -674 362 -693Q342 -712 303 -712Q253 -712 232 -684Q212 -657 212 -586L212 -519L357 -519L357 -467L212 -467L212 -52L327 -52L327 0L36 0L36 -52L122 -52L122 -467L36 -467L36 -519L122 -519L122 -584Q122 -671 167 -715Q212 -760 300 -760Q333 -760 365 -754Q398 -748 430 -736Z",
+    },
+    Glyph {
+        c: 'g',
+        advance: 640,
+        path: "M525 -467L525 -11Q525 101 463 161Q402 222 288 222Q237 222 190 213Q143 204 100 185L100 76L147 76Q156 127 188 150Q221 174 282 174Q361 174 398 129Q435 84 435 -11L435 -81Q409 -32 368 -9Q327 14 267 14Q171 14 111 -62Q50 -138 50 -260Q50 -382 110 -458Q171 -533 267 -533Q327 -533 368 -510Q409 -487 435 -438L435 -519L611 -519L611 -467ZM435 -285Q435 -378 399 -428Q363 -477 295 -477Q226 -477 190 -422Q155 -368 155 -260Q155 -152 190 -97Q226 -42 295 -42Q363 -42 399 -91Q435 -140 435 -234Z",
+    },
+    Glyph {
+        c: 'h',
+        advance: 644,
+        path: "M41 0L41 -52L122 -52L122 -708L36 -708L36 -760L212 -760L212 -427Q237 -479 277 -506Q316 -533 369 -533Q455 -533 496 -484Q536 -435 536 -330L536 -52L616 -52L616 0L368 0L368 -52L446 -52L446 -302Q446 -397 423 -432Q399 -467 340 -467Q277 -467 245 -421Q212 -376 212 -289L212 -52L290 -52L290 0Z",
+    },
+    Glyph {
+        c: 'i',
+        advance: 320,
+        path: "M97 -680Q97 -703 114 -719Q130 -736 153 -736Q175 -736 192 -719Q208 -703 208 -680Q208 -657 192 -641Q176 -625 153 -625Q130 -625 114 -641Q97 -657 97 -680ZM212 -52L297 -52L297 0L36 0L36 -52L122 -52L122 -467L36 -467L36 -519L212 -519Z",
+    },
+    Glyph {
+        c: 'j',
+        advance: 310,
+        path: "M100 -680Q100 -703 116 -719Q133 -736 156 -736Q178 -736 195 -719Q211 -703 211 -680Q211 -657 195 -641Q179 -625 156 -625Q133 -625 116 -641Q100 -657 100 -680ZM122 -467L37 -467L37 -519L212 -519L212 51Q212 131 164 177Q117 222 33 222Q-2 222 -34 214Q-66 206 -96 190L-96 83L-50 83Q-46 130 -26 152Q-5 174 35 174Q80 174 101 144Q122 114 122 51Z",
+    },
+    Glyph {
+        c: 'k',
+        advance: 606,
+        path: "M286 0L34 0L34 -52L115 -52L115 -708L29 -708L29 -760L205 -760L205 -265L424 -467L349 -467L349 -519L584 -519L584 -467L495 -467L341 -324L538 -52L613 -52L613 0L357 0L357 -52L431 -52L276 -265L205 -199L205 -52L286 -52Z",
+    },
+    Glyph {
+        c: 'l',
+        advance: 320,
+        path: "M205 -52L290 -52L290 0L29 0L29 -52L115 -52L115 -708L29 -708L29 -760L205 -760Z",
+    },
+    Glyph {
+        c: 'm',
+        advance: 948,
+        path: "M518 -418Q544 -475 584 -504Q625 -533 678 -533Q760 -533 800 -483Q840 -432 840 -330L840 -52L921 -52L921 0L672 0L672 -52L750 -52L750 -320Q750 -399 727 -433Q703 -467 649 -467Q589 -467 557 -421Q526 -376 526 -289L526 -52L604 -52L604 0L358 0L358 -52L436 -52L436 -323Q436 -401 413 -434Q389 -467 335 -467Q275 -467 243 -421Q212 -376 212 -289L212 -52L290 -52L290 0L41 0L41 -52L122 -52L122 -468L36 -468L36 -519L212 -519L212 -427Q237 -479 275 -506Q314 -533 363 -533Q423 -533 464 -503Q504 -473 518 -418Z",
+    },
+    Glyph {
+        c: 'n',
+        advance: 644,
+        path: "M41 0L41 -52L122 -52L122 -467L36 -467L36 -519L212 -519L212 -427Q237 -479 277 -506Q316 -533 369 -533Q455 -533 496 -484Q536 -435 536 -330L536 -52L616 -52L616 0L368 0L368 -52L446 -52L446 -302Q446 -397 422 -432Q399 -468 340 -468Q277 -468 245 -422Q212 -376 212 -289L212 -52L290 -52L290 0Z",
+    },
+    Glyph {
+        c: 'o',
+        advance: 602,
+        path: "M301 -34Q373 -34 410 -91Q447 -148 447 -260Q447 -371 410 -428Q373 -485 301 -485Q229 -485 192 -428Q155 -371 155 -260Q155 -148 192 -91Q229 -34 301 -34ZM301 14Q188 14 119 -61Q50 -136 50 -260Q50 -384 118 -458Q187 -533 301 -533Q415 -533 483 -458Q552 -384 552 -260Q552 -136 483 -61Q415 14 301 14Z",
+    },
+    Glyph {
+        c: 'p',
+        advance: 640,
+        path: "M205 -285L205 -234Q205 -140 241 -91Q277 -42 345 -42Q414 -42 449 -97Q485 -152 485 -260Q485 -368 449 -422Q414 -477 345 -477Q277 -477 241 -428Q205 -378 205 -285ZM115 -467L29 -467L29 -519L205 -519L205 -438Q231 -487 272 -510Q313 -533 373 -533Q469 -533 529 -458Q590 -382 590 -260Q590 -138 529 -62Q469 14 373 14Q313 14 272 -9Q231 -32 205 -81L205 156L290 156L290 208L29 208L29 156L115 156Z",
+    },
+    Glyph {
+        c: 'q',
+        advance: 640,
+        path: "M525 -467L525 156L611 156L611 208L350 208L350 156L435 156L435 -81Q409 -32 368 -9Q327 14 267 14Q171 14 111 -62Q50 -138 50 -260Q50 -382 110 -458Q171 -533 267 -533Q327 -533 368 -510Q409 -487 435 -438L435 -519L611 -519L611 -467ZM435 -285Q435 -378 399 -428Q363 -477 295 -477Q226 -477 190 -422Q155 -368 155 -260Q155 -152 190 -97Q226 -42 295 -42Q363 -42 399 -91Q435 -140 435 -234Z",
+    },
+    Glyph {
+        c: 'r',
+        advance: 478,
+        path: "M478 -520L478 -390L426 -390Q424 -429 405 -448Q386 -467 349 -467Q283 -467 247 -421Q212 -375 212 -289L212 -52L316 -52L316 0L41 0L41 -52L122 -52L122 -468L36 -468L36 -519L212 -519L212 -427Q238 -481 280 -507Q321 -533 381 -533Q403 -533 427 -530Q451 -526 478 -520Z",
+    },
+    Glyph {
+        c: 's',
+        advance: 513,
+        path: "M56 -29L56 -150L108 -150Q110 -92 144 -63Q179 -34 246 -34Q307 -34 338 -57Q370 -80 370 -123Q370 -157 347 -178Q324 -199 249 -223L184 -245Q117 -267 87 -299Q57 -331 57 -381Q57 -452 109 -493Q162 -533 254 -533Q295 -533 340 -522Q386 -512 434 -491L434 -378L382 -378Q380 -428 347 -457Q314 -485 257 -485Q200 -485 171 -465Q142 -445 142 -405Q142 -372 164 -352Q186 -333 252 -312L323 -290Q397 -267 429 -233Q462 -198 462 -144Q462 -70 406 -28Q349 14 250 14Q200 14 152 3Q104 -7 56 -29Z",
+    },
+    Glyph {
+        c: 't',
+        advance: 402,
+        path: "M108 -467L29 -467L29 -519L108 -519L108 -680L198 -680L198 -519L367 -519L367 -467L198 -467L198 -137Q198 -71 211 -53Q224 -34 258 -34Q293 -34 309 -55Q325 -76 326 -122L394 -122Q390 -51 355 -19Q321 14 250 14Q172 14 140 -20Q108 -55 108 -137Z",
+    },
+    Glyph {
+        c: 'u',
+        advance: 644,
+        path: "M354 -519L522 -519L522 -52L607 -52L607 0L432 0L432 -92Q407 -40 368 -13Q328 14 276 14Q189 14 149 -35Q108 -84 108 -189L108 -467L27 -467L27 -519L198 -519L198 -217Q198 -122 221 -87Q245 -52 304 -52Q367 -52 399 -98Q432 -144 432 -231L432 -467L354 -467Z",
+    },
+    Glyph {
+        c: 'v',
+        advance: 565,
+        path: "M247 0L56 -467L-3 -467L-3 -519L236 -519L236 -467L153 -467L299 -110L445 -467L367 -467L367 -519L562 -519L562 -467L504 -467L313 0Z",
+    },
+    Glyph {
+        c: 'w',
+        advance: 856,
+        path: "M480 -519L613 -114L730 -467L655 -467L655 -519L843 -519L843 -467L785 -467L631 0L556 0L428 -388L300 0L228 0L74 -467L16 -467L16 -519L251 -519L251 -467L167 -467L283 -114L417 -519Z",
+    },
+    Glyph {
+        c: 'x',
+        advance: 564,
+        path: "M291 -317L400 -467L330 -467L330 -519L530 -519L530 -467L461 -467L322 -275L484 -52L552 -52L552 0L312 0L312 -52L378 -52L265 -207L152 -52L219 -52L219 0L22 0L22 -52L91 -52L234 -249L76 -467L12 -467L12 -519L244 -519L244 -467L182 -467Z",
+    },
+    Glyph {
+        c: 'y',
+        advance: 565,
+        path: "M216 95L250 9L56 -467L-3 -467L-3 -519L236 -519L236 -467L153 -467L299 -110L445 -467L367 -467L367 -519L562 -519L562 -467L504 -467L266 117Q242 178 212 200Q182 222 128 222Q105 222 81 218Q57 214 32 207L32 108L78 108Q81 141 95 156Q109 170 138 170Q165 170 181 155Q198 140 216 95Z",
+    },
+    Glyph {
+        c: 'z',
+        advance: 527,
+        path: "M40 0L40 -42L365 -467L108 -467L108 -377L56 -377L56 -519L481 -519L481 -477L156 -52L438 -52L438 -146L490 -146L490 0Z",
+    },
+    Glyph {
+        c: '{',
+        advance: 636,
+        path: "M511 112L511 163L481 163Q359 163 318 127Q277 91 277 -17L277 -143Q277 -216 250 -245Q224 -273 155 -273L125 -273L125 -324L155 -324Q224 -324 250 -353Q277 -381 277 -454L277 -580Q277 -688 318 -724Q359 -760 481 -760L511 -760L511 -709L478 -709Q409 -709 388 -688Q367 -666 367 -597L367 -457Q367 -380 343 -347Q318 -313 255 -299Q319 -282 343 -250Q367 -217 367 -140L367 0Q367 69 388 90Q409 112 478 112Z",
+    },
+    Glyph {
+        c: '|',
+        advance: 337,
+        path: "M208 -764L208 236L129 236L129 -764Z",
+    },
+    Glyph {
+        c: '}',
+        advance: 636,
+        path: "M125 112L159 112Q228 112 248 91Q269 69 269 0L269 -140Q269 -217 293 -250Q317 -282 381 -299Q317 -313 293 -347Q269 -380 269 -457L269 -597Q269 -667 248 -688Q228 -709 159 -709L125 -709L125 -760L156 -760Q277 -760 318 -724Q359 -688 359 -580L359 -454Q359 -381 385 -353Q412 -324 481 -324L511 -324L511 -273L481 -273Q412 -273 385 -245Q359 -216 359 -143L359 -17Q359 91 318 127Q277 163 156 163L125 163Z",
+    },
+    Glyph {
+        c: '~',
+        advance: 838,
+        path: "M732 -394L732 -322Q683 -279 639 -258Q595 -238 550 -238Q503 -238 418 -270L413 -272Q408 -274 397 -278Q325 -306 279 -306Q234 -306 192 -288Q149 -271 106 -233L106 -307Q155 -350 198 -369Q242 -389 288 -389Q335 -389 420 -357L425 -355Q430 -353 440 -349Q511 -321 558 -321Q603 -321 646 -339Q689 -357 732 -394Z",
+    },
+    Glyph {
+        c: '¯',
+        advance: 500,
+        path: "M104 -745L396 -745L396 -673L104 -673Z",
+    },
+    Glyph {
+        c: '°',
+        advance: 500,
+        path: "M250 -675Q213 -675 188 -649Q162 -624 162 -586Q162 -549 187 -524Q212 -499 250 -499Q287 -499 312 -524Q338 -549 338 -586Q338 -623 312 -649Q286 -675 250 -675ZM250 -742Q281 -742 310 -730Q339 -718 360 -696Q382 -674 394 -646Q405 -618 405 -586Q405 -521 360 -477Q314 -432 249 -432Q183 -432 139 -476Q95 -520 95 -586Q95 -651 140 -697Q185 -742 250 -742Z",
+    },
+    Glyph {
+        c: '±',
+        advance: 838,
+        path: "M458 -627L458 -436L732 -436L732 -357L458 -357L458 -166L380 -166L380 -357L106 -357L106 -436L380 -436L380 -627ZM106 -78L732 -78L732 0L106 0Z",
+    },
+    Glyph {
+        c: '²',
+        advance: 401,
+        path: "M83 -633L49 -633L49 -710Q81 -726 115 -734Q149 -742 183 -742Q250 -742 292 -710Q334 -678 334 -627Q334 -567 239 -482L230 -474L114 -369L302 -369L302 -422L338 -422L338 -326L44 -326L44 -363L183 -487Q225 -524 243 -554Q261 -584 261 -616Q261 -658 238 -682Q214 -706 173 -706Q135 -706 112 -688Q89 -669 83 -633Z",
+    },
+    Glyph {
+        c: '³',
+        advance: 401,
+        path: "M61 -717Q97 -729 129 -736Q162 -742 191 -742Q258 -742 295 -716Q331 -689 331 -641Q331 -606 305 -582Q279 -557 233 -549Q291 -541 321 -512Q350 -483 350 -434Q350 -378 307 -349Q265 -319 185 -319Q150 -319 116 -326Q82 -333 49 -346L49 -429L83 -429Q85 -393 109 -374Q133 -355 178 -355Q224 -355 249 -376Q274 -398 274 -438Q274 -483 249 -507Q224 -530 175 -530L149 -530L149 -566L162 -566Q209 -566 234 -584Q258 -602 258 -638Q258 -671 238 -688Q218 -706 181 -706Q141 -706 120 -690Q99 -675 95 -643L61 -643Z",
+    },
+    Glyph {
+        c: '·',
+        advance: 318,
+        path: "M93 -347Q93 -375 112 -393Q130 -412 158 -412Q185 -412 204 -393Q223 -374 223 -347Q223 -320 204 -301Q185 -282 158 -282Q130 -282 112 -301Q93 -319 93 -347Z",
+    },
+    Glyph {
+        c: '¹',
+        advance: 401,
+        path: "M86 -326L86 -367L170 -367L170 -677L72 -625L72 -671L189 -734L239 -734L239 -367L323 -367L323 -326Z",
+    },
+    Glyph {
+        c: '×',
+        advance: 838,
+        path: "M700 -539L474 -313L700 -88L644 -33L419 -258L194 -33L138 -88L363 -313L138 -539L194 -594L419 -369L644 -594Z",
+    },
+    Glyph {
+        c: '÷',
+        advance: 838,
+        path: "M354 -494Q354 -521 373 -540Q392 -559 419 -559Q446 -559 465 -540Q484 -521 484 -494Q484 -467 465 -448Q446 -429 419 -429Q391 -429 373 -448Q354 -466 354 -494ZM354 -133Q354 -160 373 -179Q392 -198 419 -198Q446 -198 465 -179Q484 -160 484 -133Q484 -106 465 -87Q446 -68 419 -68Q391 -68 373 -87Q354 -105 354 -133ZM106 -353L732 -353L732 -274L106 -274Z",
+    },
+    Glyph {
+        c: 'ˆ',
+        advance: 500,
+        path: "M209 -800L291 -800L406 -616L349 -616L250 -743L151 -616L94 -616Z",
+    },
+    Glyph {
+        c: '\u{0300}',
+        advance: 0,
+        path: "M-318 -799L-191 -615L-248 -615L-414 -799Z",
+    },
+    Glyph {
+        c: '\u{0301}',
+        advance: 0,
+        path: "M-182 -800L-85 -800L-251 -616L-308 -616Z",
+    },
+    Glyph {
+        c: '\u{0302}',
+        advance: 0,
+        path: "M-291 -800L-209 -800L-94 -616L-151 -616L-250 -743L-349 -616L-406 -616Z",
+    },
+    Glyph {
+        c: '\u{0303}',
+        advance: 0,
+        path: "M-255 -669L-283 -696Q-295 -708 -303 -711Q-312 -715 -321 -715Q-341 -715 -353 -696Q-365 -676 -367 -639L-414 -639Q-413 -705 -388 -741Q-363 -777 -319 -777Q-300 -777 -285 -770Q-269 -763 -251 -747L-223 -720Q-212 -709 -204 -705Q-195 -701 -185 -701Q-164 -701 -152 -721Q-141 -740 -139 -777L-92 -777Q-93 -711 -118 -675Q-143 -639 -187 -639Q-206 -639 -221 -646Q-237 -653 -255 -669Z",
+    },
+    Glyph {
+        c: '\u{0304}',
+        advance: 0,
+        path: "M-396 -745L-104 -745L-104 -673L-396 -673Z",
+    },
+    Glyph {
+        c: '\u{0307}',
+        advance: 0,
+        path: "M-250 -766Q-227 -766 -210 -749Q-193 -732 -193 -709Q-193 -684 -209 -668Q-225 -652 -250 -652Q-275 -652 -291 -668Q-307 -684 -307 -709Q-307 -732 -290 -749Q-273 -766 -250 -766Z",
+    },
+    Glyph {
+        c: '\u{0308}',
+        advance: 0,
+        path: "M-348 -766Q-325 -766 -308 -749Q-291 -732 -291 -709Q-291 -684 -307 -668Q-323 -652 -348 -652Q-373 -652 -389 -668Q-405 -684 -405 -709Q-405 -732 -388 -749Q-371 -766 -348 -766ZM-158 -766Q-135 -766 -118 -749Q-101 -732 -101 -709Q-101 -684 -117 -668Q-133 -652 -158 -652Q-183 -652 -199 -668Q-215 -684 -215 -709Q-215 -732 -198 -749Q-181 -766 -158 -766Z",
+    },
+    Glyph {
+        c: 'Α',
+        advance: 722,
+        path: "M200 -264L468 -264L334 -611ZM-6 0L-6 -52L58 -52L318 -729L400 -729L660 -52L732 -52L732 0L467 0L467 -52L548 -52L487 -212L180 -212L119 -52L199 -52L199 0Z",
+    },
+    Glyph {
+        c: 'Β',
+        advance: 735,
+        path: "M247 -52L393 -52Q481 -52 521 -90Q562 -128 562 -211Q562 -293 522 -331Q481 -369 393 -369L247 -369ZM247 -421L371 -421Q451 -421 488 -452Q525 -482 525 -549Q525 -616 488 -646Q451 -677 371 -677L247 -677ZM55 0L55 -52L148 -52L148 -677L55 -677L55 -729L415 -729Q525 -729 581 -684Q637 -639 637 -549Q637 -484 598 -445Q560 -407 485 -398Q578 -386 626 -339Q674 -291 674 -211Q674 -103 605 -51Q537 0 392 0Z",
+    },
+    Glyph {
+        c: 'Γ',
+        advance: 694,
+        path: "M247 -52L364 -52L364 0L55 0L55 -52L148 -52L148 -677L55 -677L55 -729L652 -729L652 -566L592 -566L592 -669L247 -669Z",
+    },
+    Glyph {
+        c: 'Δ',
+        advance: 722,
+        path: "M680 0L38 0L318 -729L400 -729ZM548 -52L334 -611L119 -52Z",
+    },
+    Glyph {
+        c: 'Ε',
+        advance: 730,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L642 -729L642 -567L582 -567L582 -669L247 -669L247 -425L486 -425L486 -516L546 -516L546 -274L486 -274L486 -365L247 -365L247 -60L590 -60L590 -162L650 -162L650 0Z",
+    },
+    Glyph {
+        c: 'Ζ',
+        advance: 695,
+        path: "M45 0L45 -35L499 -669L122 -669L122 -560L62 -560L62 -729L638 -729L638 -694L184 -60L596 -60L596 -162L656 -162L656 0Z",
+    },
+    Glyph {
+        c: 'Η',
+        advance: 872,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677L247 -425L625 -425L625 -677L532 -677L532 -729L817 -729L817 -677L724 -677L724 -52L817 -52L817 0L532 0L532 -52L625 -52L625 -365L247 -365L247 -52L340 -52L340 0Z",
+    },
+    Glyph {
+        c: 'Θ',
+        advance: 820,
+        path: "M410 -38Q530 -38 591 -120Q652 -203 652 -364Q652 -525 591 -608Q530 -690 410 -690Q290 -690 229 -608Q168 -525 168 -364Q168 -203 229 -120Q290 -38 410 -38ZM410 14Q336 14 274 -10Q211 -35 164 -82Q109 -137 83 -206Q56 -275 56 -364Q56 -452 83 -522Q109 -591 164 -646Q212 -694 273 -718Q335 -742 410 -742Q569 -742 667 -638Q764 -534 764 -364Q764 -276 737 -206Q710 -136 656 -82Q608 -34 546 -10Q485 14 410 14ZM289 -365L289 -274L229 -274L229 -516L289 -516L289 -425L531 -425L531 -516L591 -516L591 -274L531 -274L531 -365Z",
+    },
+    Glyph {
+        c: 'Ι',
+        advance: 395,
+        path: "M247 -52L340 -52L340 0L55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677Z",
+    },
+    Glyph {
+        c: 'Κ',
+        advance: 747,
+        path: "M55 0L55 -52L148 -52L148 -677L55 -677L55 -729L340 -729L340 -677L247 -677L247 -401L561 -677L482 -677L482 -729L724 -729L724 -677L642 -677L329 -402L679 -52L762 -52L762 0L593 0L247 -346L247 -52L340 -52L340 0Z",
+    },
+    Glyph {
+        c: 'Λ',
+        advance: 722,
+        path: "M548 -52L334 -611L119 -52L199 -52L199 0L-6 0L-6 -52L58 -52L318 -729L400 -729L660 -52L732 -52L732 0L467 0L467 -52Z",
+    },
+    Glyph {
+        c: 'Μ',
+        advance: 1024,
+        path: "M55 0L55 -52L148 -52L148 -677L50 -677L50 -729L262 -729L518 -210L774 -729L973 -729L973 -677L876 -677L876 -52L969 -52L969 0L684 0L684 -52L777 -52L777 -615L527 -107L458 -107L208 -615L208 -52L301 -52L301 0Z",
+    },
+    Glyph {
+        c: 'Ν',
+        advance: 875,
+        path: "M49 0L49 -52L147 -52L147 -677L49 -677L49 -729L236 -729L673 -154L673 -677L575 -677L575 -729L831 -729L831 -677L733 -677L733 14L674 14L207 -600L207 -52L305 -52L305 0Z",
+    },
+    Glyph {
+        c: 'Ξ',
+        advance: 704,
+        path: "M582 -92L582 -162L642 -162L642 0L55 0L55 -162L115 -162L115 -92ZM582 -637L115 -637L115 -567L55 -567L55 -729L642 -729L642 -567L582 -567ZM234 -365L234 -274L174 -274L174 -516L234 -516L234 -425L463 -425L463 -516L523 -516L523 -274L463 -274L463 -365Z",
+    },
+    Glyph {
+        c: 'Ο',
+        advance: 820,
+        path: "M410 -38Q530 -38 591 -120Q652 -203 652 -364Q652 -525 591 -608Q530 -690 410 -690Q290 -690 229 -608Q168 -525 168 -364Q168 -203 229 -120Q290 -38 410 -38ZM410 14Q336 14 274 -10Q211 -35 164 -82Q109 -137 83 -206Q56 -275 56 -364Q56 -452 83 -522Q109 -591 164 -646Q212 -694 273 -718Q335 -742 410 -742Q569 -742 667 -638Q764 -534 764 -364Q764 -276 737 -206Q710 -136 656 -82Q608 -34 546 -10Q485 14 410 14Z",
+    },
+    Glyph {
+        c: 'Π',
+        advance: 872,
+        path: "M817 -729L817 -677L724 -677L724 -52L817 -52L817 0L532 0L532 -52L625 -52L625 -669L247 -669L247 -52L340 -52L340 0L55 0L55 -52L148 -52L148 -677L55 -677L55 -729Z",
+    },
+    Glyph {
+        c: 'Ρ',
+        advance: 673,
+        path: "M247 -371L376 -371Q449 -371 487 -410Q525 -450 525 -524Q525 -599 487 -638Q449 -677 376 -677L247 -677ZM55 0L55 -52L148 -52L148 -677L55 -677L55 -729L400 -729Q509 -729 573 -674Q637 -618 637 -524Q637 -430 573 -375Q509 -319 400 -319L247 -319L247 -52L360 -52L360 0Z",
+    },
+    Glyph {
+        c: 'Σ',
+        advance: 707,
+        path: "M469 -397L199 -92L580 -92L580 -162L640 -162L640 0L41 0L365 -365L41 -729L640 -729L640 -567L580 -567L580 -669L240 -669Z",
+    },
+    Glyph {
+        c: 'Τ',
+        advance: 667,
+        path: "M191 0L191 -52L284 -52L284 -671L70 -671L70 -557L10 -557L10 -729L657 -729L657 -557L597 -557L597 -671L383 -671L383 -52L476 -52L476 0Z",
+    },
+    Glyph {
+        c: 'Υ',
+        advance: 660,
+        path: "M189 0L189 -52L282 -52L282 -314L57 -677L-11 -677L-11 -729L260 -729L260 -677L175 -677L357 -382L539 -677L457 -677L457 -729L669 -729L669 -677L600 -677L381 -323L381 -52L474 -52L474 0Z",
+    },
+    Glyph {
+        c: 'Φ',
+        advance: 820,
+        path: "M657 -167Q608 -132 547 -116Q498 -104 460 -99L460 -52L553 -52L553 0L268 0L268 -52L361 -52L361 -99Q323 -104 273 -116Q213 -132 164 -167Q109 -205 83 -253Q56 -302 56 -364Q56 -426 83 -474Q109 -523 164 -562Q214 -597 273 -612Q306 -620 361 -628L361 -677L268 -677L268 -729L553 -729L553 -677L460 -677L460 -628Q515 -620 547 -612Q606 -597 657 -562Q711 -523 738 -474Q765 -426 765 -364Q765 -302 738 -253Q711 -205 657 -167ZM460 -139Q545 -149 592 -193Q653 -251 653 -364Q653 -481 592 -535Q543 -577 460 -590ZM361 -139L361 -590Q277 -577 229 -535Q168 -481 168 -364Q168 -251 229 -193Q276 -149 361 -139Z",
+    },
+    Glyph {
+        c: 'Χ',
+        advance: 712,
+        path: "M331 -312L153 -52L247 -52L247 0L6 0L6 -52L90 -52L300 -358L87 -677L9 -677L9 -729L298 -729L298 -677L212 -677L368 -442L529 -677L435 -677L435 -729L674 -729L674 -677L592 -677L399 -396L629 -52L707 -52L707 0L418 0L418 -52L505 -52Z",
+    },
+    Glyph {
+        c: 'Ψ',
+        advance: 877,
+        path: "M646 -445L646 -587Q646 -729 740 -729L838 -729L838 -677L789 -677Q744 -677 745 -587L745 -437Q745 -298 680 -240Q623 -188 492 -181L492 -52L585 -52L585 0L300 0L300 -52L393 -52L393 -181Q259 -188 205 -240Q140 -301 140 -437L140 -587Q141 -677 97 -677L47 -677L47 -729L145 -729Q239 -729 239 -587L239 -445Q238 -328 284 -282Q316 -250 393 -237L393 -677L300 -677L300 -729L585 -729L585 -677L492 -677L492 -237Q568 -249 601 -282Q646 -328 646 -445Z",
+    },
+    Glyph {
+        c: 'Ω',
+        advance: 829,
+        path: "M547 -80L711 -80L711 -147L768 -147L768 0L479 0L479 -89Q567 -123 612 -199Q657 -275 657 -388Q657 -524 590 -607Q523 -690 415 -690Q307 -690 240 -607Q173 -524 173 -388Q173 -274 218 -199Q263 -123 350 -89L350 0L62 0L62 -147L119 -147L119 -80L282 -80Q180 -123 121 -208Q61 -294 61 -399Q61 -473 89 -537Q117 -601 171 -651Q219 -695 282 -719Q344 -742 415 -742Q486 -742 548 -719Q610 -696 658 -651Q712 -601 741 -536Q769 -472 769 -399Q769 -295 709 -209Q649 -123 547 -80Z",
+    },
+    Glyph {
+        c: 'α',
+        advance: 675,
+        path: "M432 -128L412 -74Q397 -32 351 -5Q320 13 267 12Q170 11 110 -63Q50 -140 50 -262Q50 -381 110 -459Q167 -533 288 -533Q371 -533 413 -500Q450 -471 464 -408L468 -390L487 -444Q505 -496 555 -521L604 -521L604 -492Q558 -478 541 -432L490 -293L524 -144Q542 -67 561 -59Q587 -46 648 -83L648 -24Q549 33 479 -26Q448 -54 439 -93ZM411 -224L380 -367Q355 -481 289 -482Q233 -482 190 -422Q155 -373 155 -260Q155 -152 190 -97Q229 -37 294 -42Q351 -46 391 -166Z",
+    },
+    Glyph {
+        c: 'β',
+        advance: 578,
+        path: "M153 -63Q192 -34 264 -34Q338 -34 382 -77Q425 -121 425 -196Q425 -298 380 -342Q335 -387 250 -387L204 -387L204 -439L228 -439Q290 -439 333 -475Q368 -504 368 -582Q368 -616 345 -654Q308 -718 263 -717Q153 -713 153 -519ZM63 208L63 -519Q63 -723 173 -750Q231 -765 271 -765Q362 -765 421 -693Q467 -637 467 -588Q467 -519 433 -478Q399 -437 339 -417Q428 -405 479 -352Q528 -299 528 -197Q528 -95 459 -40Q391 14 262 14Q183 14 153 3L153 208Z",
+    },
+    Glyph {
+        c: 'γ',
+        advance: 598,
+        path: "M348 -29Q378 113 350 166Q327 208 293 209Q241 210 219 160Q193 104 261 -8L122 -467L36 -467L36 -519L202 -519L329 -97Q439 -246 453 -349Q459 -397 447 -430Q427 -482 404 -490L404 -519L458 -519Q515 -485 529 -456Q548 -415 548 -389Q548 -342 498 -248Q479 -211 348 -29Z",
+    },
+    Glyph {
+        c: 'δ',
+        advance: 602,
+        path: "M179 -506Q146 -519 130 -542Q112 -569 112 -636Q112 -699 160 -732Q208 -765 303 -765Q330 -765 380 -757Q431 -749 486 -734L486 -638L433 -625Q427 -669 392 -691Q357 -713 311 -713Q265 -713 231 -689Q196 -664 197 -632Q199 -585 215 -570Q253 -533 301 -533Q415 -533 483 -458Q552 -384 552 -260Q552 -136 483 -61Q415 14 301 14Q188 14 119 -61Q50 -136 50 -260Q50 -384 118 -458Q149 -491 179 -506ZM301 -34Q373 -34 410 -91Q447 -148 447 -260Q447 -371 410 -428Q373 -485 301 -485Q229 -485 192 -428Q155 -371 155 -260Q155 -148 192 -91Q229 -34 301 -34Z",
+    },
+    Glyph {
+        c: 'ε',
+        advance: 518,
+        path: "M78 -396Q78 -458 125 -492Q173 -524 269 -524Q295 -524 346 -517Q397 -508 452 -493L452 -385L399 -385Q393 -429 358 -451Q323 -473 277 -473Q163 -473 163 -391Q163 -298 289 -298L334 -298L334 -247L289 -247Q212 -247 174 -216Q138 -187 138 -130Q138 -92 164 -67Q207 -27 276 -27Q333 -27 374 -53Q415 -79 419 -129L472 -129L472 -16Q420 1 368 10Q317 19 278 19Q168 19 108 -20Q49 -58 49 -131Q49 -197 101 -235Q135 -262 194 -274Q154 -284 127 -301Q78 -333 78 -396Z",
+    },
+    Glyph {
+        c: 'ζ',
+        advance: 542,
+        path: "M497 -760L497 -700Q302 -606 211 -472Q155 -389 155 -275Q154 -146 192 -91Q229 -35 326 -35Q479 -36 500 72Q511 127 458 171Q415 208 321 208L321 156Q419 156 419 79Q419 14 324 14Q187 14 119 -61Q50 -136 50 -275Q50 -373 132 -510Q199 -622 384 -700L92 -700L92 -629L41 -629L41 -760Z",
+    },
+    Glyph {
+        c: 'η',
+        advance: 599,
+        path: "M122 0L122 -467L36 -467L36 -519L212 -519L212 -427Q237 -479 277 -506Q316 -533 369 -533Q455 -533 496 -484Q536 -435 536 -330L536 208L446 208L446 -302Q446 -397 422 -432Q399 -468 340 -468Q277 -468 245 -422Q212 -376 212 -289L212 0Z",
+    },
+    Glyph {
+        c: 'θ',
+        advance: 602,
+        path: "M446 -406Q442 -563 409 -643Q373 -731 300 -731Q229 -731 191 -643Q159 -563 155 -406ZM446 -354L155 -354Q159 -198 192 -119Q229 -30 300 -30Q372 -30 409 -119Q442 -198 446 -354ZM300 -771Q414 -771 483 -664Q551 -557 551 -380Q551 -203 482 -96Q414 11 300 11Q188 11 119 -96Q50 -203 50 -380Q50 -557 118 -664Q187 -771 300 -771Z",
+    },
+    Glyph {
+        c: 'ι',
+        advance: 392,
+        path: "M122 -142L122 -467L36 -467L36 -519L212 -519L212 -142Q212 -74 248 -57Q275 -45 336 -81L336 -22Q236 35 167 -24Q122 -63 122 -142Z",
+    },
+    Glyph {
+        c: 'κ',
+        advance: 625,
+        path: "M286 -519L286 -467L205 -467L205 -265L424 -467L349 -467L349 -519L584 -519L584 -467L495 -467L341 -324L538 -52L613 -52L613 0L357 0L357 -52L431 -52L276 -265L205 -199L205 -52L286 -52L286 0L34 0L34 -52L115 -52L115 -467L34 -467L34 -519Z",
+    },
+    Glyph {
+        c: 'λ',
+        advance: 634,
+        path: "M260 -638Q242 -683 225 -698Q208 -713 182 -713Q153 -713 139 -699Q125 -684 122 -651L76 -651L76 -750Q101 -757 125 -761Q149 -765 172 -765Q226 -765 256 -743Q286 -721 310 -660L548 -52L606 -52L606 0L411 0L411 -52L489 -52L351 -411L197 -52L280 -52L280 0L41 0L41 -52L100 -52L302 -529Z",
+    },
+    Glyph {
+        c: 'μ',
+        advance: 650,
+        path: "M361 -519L529 -519L529 -51L614 -51L614 0L439 0L439 -92Q414 -40 378 -13Q342 14 298 14Q270 14 247 5Q224 -3 205 -21L205 208L115 208L115 -467L34 -467L34 -519L205 -519L205 -217Q205 -122 229 -87Q252 -52 311 -52Q374 -52 406 -98Q439 -144 439 -231L439 -467L361 -467Z",
+    },
+    Glyph {
+        c: 'ν',
+        advance: 608,
+        path: "M212 -57Q271 -80 336 -137Q375 -171 421 -256Q451 -309 455 -357Q459 -402 438 -437Q411 -484 375 -490L375 -519L430 -519Q485 -495 524 -442Q555 -401 555 -351Q555 -315 541 -285Q531 -264 496 -213Q449 -148 389 -104Q279 -21 212 0L122 0L122 -467L36 -467L36 -519L212 -519Z",
+    },
+    Glyph {
+        c: 'ξ',
+        advance: 551,
+        path: "M413 -406L413 -354Q283 -354 236 -324Q155 -271 155 -197Q155 -125 191 -70Q215 -35 326 -35Q479 -36 500 72Q511 127 458 171Q415 208 321 208L321 156Q419 156 419 79Q419 14 324 14Q166 14 110 -44Q50 -106 50 -197Q50 -275 152 -354Q171 -369 249 -383Q118 -425 118 -530Q118 -650 278 -700L92 -700L92 -629L41 -629L41 -760L497 -760L497 -700Q223 -682 223 -524Q223 -411 413 -406Z",
+    },
+    Glyph {
+        c: 'ο',
+        advance: 602,
+        path: "M301 -34Q373 -34 410 -91Q447 -148 447 -260Q447 -371 410 -428Q373 -485 301 -485Q229 -485 192 -428Q155 -371 155 -260Q155 -148 192 -91Q229 -34 301 -34ZM301 14Q188 14 119 -61Q50 -136 50 -260Q50 -384 118 -458Q187 -533 301 -533Q415 -533 483 -458Q552 -384 552 -260Q552 -136 483 -61Q415 14 301 14Z",
+    },
+    Glyph {
+        c: 'π',
+        advance: 657,
+        path: "M41 0L41 -52L122 -52L122 -467L36 -467L36 -519L621 -519L621 -467L536 -467L536 -52L616 -52L616 0L368 0L368 -52L446 -52L446 -467L212 -467L212 -52L290 -52L290 0Z",
+    },
+    Glyph {
+        c: 'ρ',
+        advance: 588,
+        path: "M153 -285L153 -234Q153 -140 189 -91Q225 -42 293 -42Q362 -42 397 -97Q433 -152 433 -260Q433 -372 397 -422Q355 -482 293 -482Q228 -482 188 -428Q153 -378 153 -285ZM153 208L63 208L63 -256Q63 -378 105 -438Q153 -494 186 -511Q229 -533 292 -533Q417 -533 477 -458Q538 -382 538 -260Q538 -138 477 -62Q417 14 321 14Q261 14 220 -9Q179 -32 153 -81Z",
+    },
+    Glyph {
+        c: 'ς',
+        advance: 560,
+        path: "M326 -35Q479 -37 500 72Q511 127 458 171Q415 208 321 208L321 156Q419 156 419 79Q419 14 324 14Q187 14 119 -61Q50 -136 50 -260Q50 -384 119 -459Q188 -533 301 -533Q350 -533 399 -522Q448 -510 497 -487L497 -354L445 -354Q435 -422 400 -454Q366 -485 302 -485Q229 -485 192 -428Q155 -372 155 -260Q155 -146 191 -91Q229 -35 326 -35Z",
+    },
+    Glyph {
+        c: 'σ',
+        advance: 683,
+        path: "M301 -34Q373 -34 410 -91Q447 -148 447 -260Q447 -361 410 -423Q377 -476 301 -476Q226 -476 191 -423Q155 -366 155 -260Q155 -148 192 -91Q229 -34 301 -34ZM302 -519Q501 -519 653 -519L653 -467L475 -467Q552 -392 552 -260Q552 -136 483 -61Q415 14 301 14Q188 14 119 -61Q50 -136 50 -260Q50 -384 118 -458Q174 -519 302 -519Z",
+    },
+    Glyph {
+        c: 'τ',
+        advance: 553,
+        path: "M323 -467L323 -142Q323 -73 359 -57Q386 -44 447 -81L447 -22Q347 35 278 -24Q232 -63 233 -142L233 -467L92 -467L92 -389L41 -389L41 -519L515 -519L515 -467Z",
+    },
+    Glyph {
+        c: 'υ',
+        advance: 608,
+        path: "M360 -519L415 -519Q497 -480 526 -424Q559 -361 559 -249Q559 -195 538 -148Q517 -100 478 -64Q442 -31 396 -14Q351 3 298 3Q218 0 167 -46Q122 -86 122 -171L122 -467L36 -467L36 -519L212 -519L212 -171Q212 -107 232 -78Q253 -49 298 -48Q359 -47 414 -112Q458 -164 458 -257Q458 -389 440 -417Q408 -467 360 -490Z",
+    },
+    Glyph {
+        c: 'φ',
+        advance: 700,
+        path: "M395 208L305 208L305 1Q208 0 179 -14Q145 -30 110 -64Q78 -95 56 -148Q44 -177 44 -249Q44 -361 76 -424Q105 -480 188 -519L243 -519L243 -490Q195 -467 162 -417Q144 -390 144 -257Q144 -166 171 -112Q196 -61 305 -51L305 -257Q305 -367 327 -424Q364 -520 477 -521Q573 -523 624 -424Q656 -362 656 -249Q656 -177 644 -148Q622 -95 590 -64Q553 -27 521 -14Q492 -1 395 1ZM395 -52Q505 -63 529 -112Q556 -165 556 -257Q556 -365 538 -417Q519 -473 477 -473Q439 -473 413 -417Q396 -381 395 -257Q395 -119 395 -52Z",
+    },
+    Glyph {
+        c: 'χ',
+        advance: 606,
+        path: "M252 156L252 208L12 208L12 156L80 156L261 -183L196 -406Q182 -455 167 -466Q146 -481 138 -481Q109 -481 95 -467Q81 -452 78 -419L32 -419L32 -518Q57 -525 81 -529Q105 -533 128 -533Q157 -533 192 -511Q229 -487 246 -428L298 -251L414 -466L352 -466L352 -519L584 -519L584 -466L520 -466L335 -123L400 95Q415 144 430 155Q450 170 458 170Q487 170 501 156Q515 141 518 108L564 108L564 207Q540 214 516 218Q491 222 468 222Q439 222 404 200Q367 176 350 117L299 -55L186 156Z",
+    },
+    Glyph {
+        c: 'ψ',
+        advance: 784,
+        path: "M438 208L348 208L348 3Q302 3 260 -14Q228 -27 190 -64Q156 -98 137 -148Q125 -177 125 -257Q125 -365 107 -417Q89 -472 63 -490L63 -519L113 -519Q171 -469 193 -424Q225 -361 225 -257Q225 -164 251 -112Q285 -47 348 -48L348 -519L438 -519L438 -48Q500 -47 533 -112Q560 -164 560 -257Q560 -368 591 -424Q619 -473 672 -519L721 -519L721 -490Q697 -473 677 -417Q659 -366 659 -257Q659 -177 648 -148Q629 -98 594 -64Q557 -27 525 -14Q482 3 438 3Z",
+    },
+    Glyph {
+        c: 'ω',
+        advance: 815,
+        path: "M405 -95Q344 0 267 3Q222 3 179 -14Q147 -27 110 -64Q78 -95 56 -148Q44 -177 44 -249Q44 -361 76 -424Q105 -480 188 -519L243 -519L243 -490Q195 -467 162 -417Q144 -390 144 -257Q144 -164 171 -112Q204 -47 267 -48Q300 -49 323 -78Q360 -128 360 -183L360 -359L450 -359L450 -183Q450 -128 488 -78Q510 -49 543 -48Q606 -47 640 -112Q667 -164 667 -257Q667 -390 648 -417Q616 -467 568 -490L568 -519L623 -519Q705 -480 734 -424Q767 -361 767 -249Q767 -177 754 -148Q732 -95 701 -64Q663 -27 631 -14Q589 3 543 3Q467 0 405 -95Z",
+    },
+    Glyph {
+        c: 'ϑ',
+        advance: 715,
+        path: "M678 -406L678 -364L617 -364Q614 -198 548 -96Q479 11 366 11Q255 11 185 -96Q105 -217 115 -467L34 -467L34 -519L205 -519Q211 -218 258 -119Q300 -30 366 -30Q438 -30 475 -119Q508 -198 512 -354Q398 -354 331 -390Q237 -440 237 -586Q237 -775 409 -771Q499 -769 558 -664Q614 -562 617 -406ZM512 -406Q507 -586 484 -643Q447 -731 411 -731Q328 -731 328 -583Q328 -488 372 -451Q425 -406 512 -406Z",
+    },
+    Glyph {
+        c: 'ϕ',
+        advance: 682,
+        path: "M385 -37Q472 -46 500 -91Q538 -148 538 -260Q538 -371 501 -428Q472 -474 385 -483ZM385 208L295 208L295 13Q165 2 106 -61Q38 -136 38 -260Q38 -384 106 -458Q164 -521 295 -532L295 -760L385 -760L385 -532Q516 -521 574 -458Q643 -384 643 -260Q643 -136 574 -61Q516 2 385 13ZM295 -37L295 -483Q208 -474 180 -428Q143 -371 143 -260Q143 -148 180 -91Q208 -46 295 -37Z",
+    },
+    Glyph {
+        c: 'ϵ',
+        advance: 560,
+        path: "M302 -34Q417 -36 442 -156L514 -156Q498 -86 450 -36Q402 14 301 14Q182 14 116 -61Q50 -136 50 -260Q51 -388 120 -461Q189 -533 301 -533Q356 -533 404 -521Q452 -510 497 -487L497 -354L445 -354Q438 -421 402 -453Q366 -485 302 -485Q158 -485 156 -295L387 -295L387 -244L155 -244Q160 -34 302 -34Z",
+    },
+    Glyph {
+        c: '‖',
+        advance: 500,
+        path: "M369 -764L369 236L290 236L290 -764ZM208 -764L208 236L129 236L129 -764Z",
+    },
+    Glyph {
+        c: '†',
+        advance: 500,
+        path: "M206 -729L294 -729L277 -513L472 -527L472 -446L277 -460L294 96L206 96L223 -460L28 -446L28 -527L223 -513Z",
+    },
+    Glyph {
+        c: '…',
+        advance: 1000,
+        path: "M768 -51Q768 -78 787 -97Q806 -116 833 -116Q860 -116 879 -97Q898 -78 898 -51Q898 -24 879 -5Q860 14 833 14Q805 14 787 -5Q768 -23 768 -51ZM435 -51Q435 -78 454 -97Q473 -116 500 -116Q527 -116 546 -97Q565 -78 565 -51Q565 -24 546 -5Q527 14 500 14Q472 14 454 -5Q435 -23 435 -51ZM102 -51Q102 -78 121 -97Q140 -116 167 -116Q194 -116 213 -97Q232 -78 232 -51Q232 -24 213 -5Q194 14 167 14Q139 14 121 -5Q102 -23 102 -51Z",
+    },
+    Glyph {
+        c: '′',
+        advance: 227,
+        path: "M20 -547L104 -729L203 -729L62 -547Z",
+    },
+    Glyph {
+        c: '″',
+        advance: 374,
+        path: "M166 -547L250 -729L350 -729L208 -547ZM20 -547L104 -729L203 -729L62 -547Z",
+    },
+    Glyph {
+        c: '‾',
+        advance: 500,
+        path: "M500 -755L500 -716L0 -716L0 -755Z",
+    },
+    Glyph {
+        c: '⁰',
+        advance: 401,
+        path: "M304 -531Q304 -624 278 -669Q252 -715 200 -715Q148 -715 123 -669Q97 -624 97 -531Q97 -438 123 -392Q148 -346 200 -346Q252 -346 278 -392Q304 -438 304 -531ZM71 -375Q25 -430 25 -531Q25 -631 71 -687Q118 -742 200 -742Q283 -742 329 -687Q375 -631 375 -531Q375 -430 329 -375Q283 -319 200 -319Q118 -319 71 -375Z",
+    },
+    Glyph {
+        c: '⁴',
+        advance: 401,
+        path: "M220 -474L220 -673L70 -474ZM361 -326L149 -326L149 -367L220 -367L220 -434L26 -434L26 -475L221 -734L290 -734L290 -474L370 -474L370 -434L290 -434L290 -367L361 -367Z",
+    },
+    Glyph {
+        c: '⁵',
+        advance: 401,
+        path: "M323 -734L323 -689L91 -689L91 -573Q109 -583 132 -587Q156 -592 185 -592Q267 -592 315 -556Q362 -519 362 -456Q362 -391 314 -355Q266 -319 180 -319Q145 -319 108 -326Q71 -333 33 -346L33 -425L71 -425Q74 -387 102 -366Q130 -346 180 -346Q233 -346 262 -374Q291 -402 291 -456Q291 -509 262 -537Q233 -565 180 -565Q149 -565 126 -557Q103 -548 84 -530L56 -530L56 -734Z",
+    },
+    Glyph {
+        c: '⁶',
+        advance: 401,
+        path: "M274 -374Q299 -402 299 -455Q299 -508 274 -537Q248 -565 200 -565Q152 -565 126 -538Q101 -510 101 -458Q101 -404 126 -375Q152 -346 200 -346Q248 -346 274 -374ZM90 -552Q108 -570 142 -581Q177 -592 208 -592Q283 -592 327 -555Q371 -519 371 -455Q371 -393 324 -356Q276 -319 197 -319Q111 -319 65 -370Q20 -422 20 -517Q20 -625 74 -684Q129 -742 228 -742Q255 -742 284 -738Q314 -734 345 -726L345 -659L306 -659Q302 -686 280 -701Q258 -715 221 -715Q155 -715 123 -675Q91 -635 90 -552Z",
+    },
+    Glyph {
+        c: '⁷',
+        advance: 401,
+        path: "M372 -706L174 -326L124 -326L312 -689L79 -689L79 -639L39 -639L39 -734L372 -734Z",
+    },
+    Glyph {
+        c: '⁸',
+        advance: 401,
+        path: "M276 -506Q249 -530 200 -530Q151 -530 125 -506Q98 -482 98 -438Q98 -394 125 -370Q151 -346 200 -346Q249 -346 276 -370Q303 -394 303 -438Q303 -482 276 -506ZM289 -637Q289 -674 266 -694Q242 -715 200 -715Q158 -715 135 -694Q111 -674 111 -637Q111 -599 135 -579Q158 -558 200 -558Q242 -558 266 -579Q289 -599 289 -637ZM252 -544Q310 -538 342 -509Q375 -481 375 -438Q375 -381 329 -350Q284 -319 200 -319Q116 -319 71 -350Q26 -381 26 -438Q26 -480 58 -509Q91 -538 148 -544Q97 -552 70 -576Q42 -600 42 -637Q42 -686 84 -714Q126 -742 200 -742Q273 -742 316 -714Q358 -686 358 -637Q358 -600 331 -576Q303 -552 252 -544Z",
+    },
+    Glyph {
+        c: '⁹',
+        advance: 401,
+        path: "M306 -510Q283 -489 253 -479Q224 -469 187 -469Q112 -469 68 -506Q25 -542 25 -605Q25 -668 72 -705Q119 -742 198 -742Q285 -742 330 -691Q376 -639 376 -544Q376 -436 321 -377Q267 -319 167 -319Q137 -319 111 -323Q82 -327 51 -335L51 -403L89 -403Q93 -375 116 -360Q138 -346 174 -346Q240 -346 272 -386Q305 -426 306 -510ZM122 -687Q96 -659 96 -605Q96 -553 122 -524Q147 -496 195 -496Q244 -496 269 -523Q295 -551 295 -603Q295 -657 269 -686Q243 -715 195 -715Q147 -715 122 -687Z",
+    },
+    Glyph {
+        c: '⁺',
+        advance: 528,
+        path: "M289 -677L289 -524L461 -524L461 -479L289 -479L289 -326L239 -326L239 -479L67 -479L67 -524L239 -524L239 -677Z",
+    },
+    Glyph {
+        c: '⁻',
+        advance: 528,
+        path: "M67 -524L461 -524L461 -479L67 -479Z",
+    },
+    Glyph {
+        c: '₀',
+        advance: 401,
+        path: "M304 -205Q304 -297 278 -343Q252 -389 200 -389Q148 -389 123 -343Q97 -297 97 -205Q97 -111 123 -65Q148 -20 200 -20Q252 -20 278 -65Q304 -111 304 -205ZM71 -48Q25 -104 25 -205Q25 -305 71 -360Q118 -416 200 -416Q283 -416 329 -360Q375 -305 375 -205Q375 -104 329 -48Q283 7 200 7Q118 7 71 -48Z",
+    },
+    Glyph {
+        c: '₁',
+        advance: 401,
+        path: "M86 0L86 -41L170 -41L170 -351L72 -299L72 -345L189 -408L239 -408L239 -41L323 -41L323 0Z",
+    },
+    Glyph {
+        c: '₂',
+        advance: 401,
+        path: "M83 -307L49 -307L49 -384Q81 -399 115 -408Q149 -416 183 -416Q250 -416 292 -384Q334 -352 334 -301Q334 -241 239 -156L230 -148L114 -43L302 -43L302 -96L338 -96L338 0L44 0L44 -37L183 -161Q225 -198 243 -228Q261 -258 261 -290Q261 -332 238 -356Q214 -380 173 -380Q135 -380 112 -361Q89 -343 83 -307Z",
+    },
+    Glyph {
+        c: '₃',
+        advance: 401,
+        path: "M61 -391Q97 -403 129 -410Q162 -416 191 -416Q258 -416 295 -390Q331 -363 331 -315Q331 -280 305 -255Q279 -231 233 -223Q291 -215 321 -186Q350 -157 350 -108Q350 -52 307 -22Q265 7 185 7Q150 7 116 0Q82 -6 49 -20L49 -103L83 -103Q85 -66 109 -48Q133 -29 178 -29Q224 -29 249 -50Q274 -72 274 -112Q274 -157 249 -180Q224 -204 175 -204L149 -204L149 -240L162 -240Q209 -240 234 -258Q258 -276 258 -312Q258 -345 238 -362Q218 -380 181 -380Q141 -380 120 -364Q99 -349 95 -317L61 -317Z",
+    },
+    Glyph {
+        c: '₄',
+        advance: 401,
+        path: "M220 -148L220 -347L70 -148ZM361 0L149 0L149 -41L220 -41L220 -108L26 -108L26 -149L221 -408L290 -408L290 -148L370 -148L370 -108L290 -108L290 -41L361 -41Z",
+    },
+    Glyph {
+        c: '₅',
+        advance: 401,
+        path: "M323 -408L323 -363L91 -363L91 -247Q109 -256 132 -261Q156 -266 185 -266Q267 -266 315 -229Q362 -193 362 -130Q362 -65 314 -29Q266 7 180 7Q145 7 108 0Q71 -6 33 -20L33 -99L71 -99Q74 -61 102 -40Q130 -20 180 -20Q233 -20 262 -48Q291 -76 291 -130Q291 -183 262 -211Q233 -239 180 -239Q149 -239 126 -230Q103 -222 84 -204L56 -204L56 -408Z",
+    },
+    Glyph {
+        c: '₆',
+        advance: 401,
+        path: "M274 -48Q299 -76 299 -129Q299 -182 274 -210Q248 -239 200 -239Q152 -239 126 -211Q101 -184 101 -132Q101 -78 126 -49Q152 -20 200 -20Q248 -20 274 -48ZM90 -226Q108 -244 142 -255Q177 -266 208 -266Q283 -266 327 -229Q371 -192 371 -129Q371 -67 324 -30Q276 7 197 7Q111 7 65 -44Q20 -96 20 -191Q20 -299 74 -357Q129 -416 228 -416Q255 -416 284 -412Q314 -408 345 -400L345 -333L306 -333Q302 -360 280 -375Q258 -389 221 -389Q155 -389 123 -349Q91 -309 90 -226Z",
+    },
+    Glyph {
+        c: '₇',
+        advance: 401,
+        path: "M372 -380L174 0L124 0L312 -363L79 -363L79 -312L39 -312L39 -408L372 -408Z",
+    },
+    Glyph {
+        c: '₈',
+        advance: 401,
+        path: "M276 -180Q249 -204 200 -204Q151 -204 125 -180Q98 -156 98 -112Q98 -68 125 -44Q151 -20 200 -20Q249 -20 276 -44Q303 -68 303 -112Q303 -156 276 -180ZM289 -311Q289 -348 266 -368Q242 -389 200 -389Q158 -389 135 -368Q111 -348 111 -311Q111 -273 135 -252Q158 -231 200 -231Q242 -231 266 -252Q289 -273 289 -311ZM252 -218Q310 -212 342 -183Q375 -155 375 -112Q375 -55 329 -23Q284 7 200 7Q116 7 71 -23Q26 -55 26 -112Q26 -154 58 -183Q91 -211 148 -218Q97 -226 70 -250Q42 -273 42 -311Q42 -359 84 -388Q126 -416 200 -416Q273 -416 316 -388Q358 -359 358 -311Q358 -273 331 -250Q303 -226 252 -218Z",
+    },
+    Glyph {
+        c: '₉',
+        advance: 401,
+        path: "M306 -184Q283 -163 253 -153Q224 -143 187 -143Q112 -143 68 -180Q25 -216 25 -279Q25 -342 72 -379Q119 -416 198 -416Q285 -416 330 -365Q376 -313 376 -218Q376 -110 321 -51Q267 7 167 7Q137 7 111 3Q82 -1 51 -9L51 -77L89 -77Q93 -49 116 -34Q138 -20 174 -20Q240 -20 272 -60Q305 -100 306 -184ZM122 -361Q96 -333 96 -279Q96 -227 122 -198Q147 -170 195 -170Q244 -170 269 -197Q295 -225 295 -276Q295 -331 269 -360Q243 -389 195 -389Q147 -389 122 -361Z",
+    },
+    Glyph {
+        c: '₊',
+        advance: 528,
+        path: "M289 -351L289 -198L461 -198L461 -153L289 -153L289 0L239 0L239 -153L67 -153L67 -198L239 -198L239 -351Z",
+    },
+    Glyph {
+        c: '₋',
+        advance: 528,
+        path: "M67 -198L461 -198L461 -153L67 -153Z",
+    },
+    Glyph {
+        c: 'ₖ',
+        advance: 365,
+        path: "M180 0L21 0L21 -29L73 -29L73 -396L18 -396L18 -425L129 -425L129 -148L267 -261L220 -261L220 -291L368 -291L368 -261L312 -261L215 -182L339 -29L386 -29L386 0L225 0L225 -29L271 -29L174 -148L129 -111L129 -29L180 -29Z",
+    },
+    Glyph {
+        c: 'ₙ',
+        advance: 433,
+        path: "M25 0L25 -29L76 -29L76 -261L22 -261L22 -291L131 -291L131 -239Q147 -269 171 -284Q196 -299 229 -299Q282 -299 307 -271Q333 -243 333 -185L333 -29L382 -29L382 0L228 0L228 -29L276 -29L276 -169Q276 -222 262 -242Q248 -262 211 -262Q172 -262 152 -236Q131 -211 131 -162L131 -29L180 -29L180 0Z",
+    },
+    Glyph {
+        c: '\u{20d7}',
+        advance: 0,
+        path: "M-470 -635L-470 -684L-108 -684L-173 -760L-111 -760L-26 -660L-111 -560L-173 -560L-108 -635Z",
+    },
+    Glyph {
+        c: 'ℂ',
+        advance: 796,
+        path: "M745 -208Q716 -95 642 -41Q567 14 441 14Q256 14 149 -88Q42 -189 42 -364Q42 -539 149 -640Q256 -742 441 -742Q506 -742 576 -727Q646 -711 725 -679L725 -500L663 -500Q644 -592 594 -637Q543 -683 461 -683Q354 -683 303 -605Q251 -526 251 -364Q251 -201 303 -123Q354 -45 462 -45Q535 -45 581 -85Q627 -126 648 -208ZM259 -646Q221 -626 190 -598Q101 -513 101 -364Q101 -215 190 -130Q220 -102 259 -83L253 -90Q192 -184 192 -364Q192 -544 253 -637Z",
+    },
+    Glyph {
+        c: 'ℏ',
+        advance: 644,
+        path: "M221 -427Q292 -533 399 -533Q485 -533 516 -484Q535 -454 535 -413Q535 -373 526 -330L472 -52L552 -52L542 0L372 0L431 -302Q440 -352 440 -384Q440 -416 433 -432Q417 -467 355 -467Q294 -467 253 -422Q211 -376 194 -289L138 0L48 0L156 -552L59 -511L48 -549L165 -599L186 -708L100 -708L110 -760L286 -760L263 -640L442 -717L453 -679L253 -594Z",
+    },
+    Glyph {
+        c: 'ℕ',
+        advance: 914,
+        path: "M44 0L44 -59L137 -59L137 -670L44 -670L44 -729L272 -729L712 -213L712 -670L619 -670L619 -729L874 -729L874 -670L781 -670L781 0L653 0L206 -527L206 -59L299 -59L299 0ZM196 -670L196 -630L680 -59L722 -59L722 -110L245 -670Z",
+    },
+    Glyph {
+        c: 'ℚ',
+        advance: 871,
+        path: "M432 14Q248 11 145 -88Q42 -188 42 -364Q42 -541 147 -641Q251 -742 435 -742Q619 -742 724 -642Q828 -541 828 -364Q828 -208 748 -112Q667 -15 519 8Q545 36 582 48Q619 60 678 60L703 60L703 180L689 180Q596 180 534 140Q472 100 432 14ZM435 -45Q528 -45 574 -124Q619 -202 619 -364Q619 -526 574 -604Q528 -683 435 -683Q342 -683 297 -604Q251 -524 251 -364Q251 -204 297 -124Q342 -45 435 -45ZM618 -645Q678 -552 678 -364Q678 -177 619 -83Q642 -95 662 -110Q682 -125 702 -150Q723 -174 737 -203Q751 -232 760 -274Q769 -315 769 -364Q769 -381 768 -396Q767 -412 763 -436Q760 -459 754 -480Q748 -500 737 -525Q725 -549 710 -569Q694 -590 671 -610Q647 -630 618 -645ZM252 -84Q192 -177 192 -364Q192 -551 252 -645Q224 -630 200 -610Q176 -590 161 -570Q146 -549 134 -525Q123 -500 116 -480Q110 -459 107 -436Q103 -413 102 -397Q101 -381 101 -364Q101 -346 102 -330Q103 -314 107 -291Q110 -268 116 -247Q123 -227 134 -203Q146 -179 161 -158Q176 -138 200 -118Q223 -99 252 -84Z",
+    },
+    Glyph {
+        c: 'ℝ',
+        advance: 831,
+        path: "M531 -356Q574 -350 604 -327Q634 -305 656 -262L762 -59L837 -59L837 0L597 0L481 -220Q447 -289 424 -307Q400 -326 358 -326L328 -326L328 -59L422 -59L422 0L47 0L47 -59L140 -59L140 -670L47 -670L47 -729L463 -729Q590 -729 659 -678Q728 -627 728 -533Q728 -457 679 -413Q630 -369 531 -356ZM328 -385L392 -385Q460 -385 497 -422Q534 -459 534 -528Q534 -597 497 -633Q460 -670 392 -670L328 -670ZM269 -59L269 -670L199 -670L199 -59ZM695 -59L604 -235Q587 -266 568 -280Q551 -293 522 -297L504 -300Q515 -283 534 -247L633 -59ZM552 -661Q562 -649 572 -628Q595 -583 593 -528Q593 -508 592 -494Q590 -481 585 -461Q580 -441 571 -423Q616 -436 640 -457Q669 -483 669 -533Q669 -566 658 -590Q647 -613 624 -630Q596 -651 552 -661Z",
+    },
+    Glyph {
+        c: 'ℤ',
+        advance: 730,
+        path: "M37 0L37 -69L472 -662L123 -662L123 -553L56 -553L56 -729L689 -729L689 -660L259 -67L627 -67L627 -169L694 -169L694 0ZM551 -670L103 -59L180 -59L623 -670Z",
+    },
+    Glyph {
+        c: '←',
+        advance: 838,
+        path: "M781 -363L781 -284L152 -284L246 -119L241 -119L37 -321L37 -326L241 -527L246 -527L152 -363Z",
+    },
+    Glyph {
+        c: '→',
+        advance: 838,
+        path: "M57 -363L686 -363L592 -527L597 -527L801 -326L801 -321L597 -119L592 -119L686 -284L57 -284Z",
+    },
+    Glyph {
+        c: '↔',
+        advance: 838,
+        path: "M686 -363L592 -527L597 -527L801 -326L801 -321L597 -119L592 -119L686 -284L152 -284L246 -119L241 -119L37 -321L37 -326L241 -527L246 -527L152 -363Z",
+    },
+    Glyph {
+        c: '⇒',
+        advance: 838,
+        path: "M592 -527L597 -527L801 -326L801 -321L597 -119L592 -119L635 -198L57 -198L57 -277L679 -277L704 -323L679 -369L57 -369L57 -448L635 -448Z",
+    },
+    Glyph {
+        c: '⇔',
+        advance: 838,
+        path: "M592 -527L597 -527L801 -326L801 -321L597 -119L592 -119L635 -198L203 -198L246 -119L241 -119L37 -321L37 -326L241 -527L246 -527L203 -448L635 -448ZM159 -277L679 -277L704 -323L679 -369L159 -369L134 -323Z",
+    },
+    Glyph {
+        c: '∀',
+        advance: 604,
+        path: "M396 -399L208 -399L302 -142ZM270 0L4 -729L88 -729L179 -478L424 -478L516 -729L599 -729L334 0Z",
+    },
+    Glyph {
+        c: '∂',
+        advance: 517,
+        path: "M369 -303Q376 -346 380 -385Q384 -425 384 -462Q384 -525 366 -557Q348 -589 313 -589Q286 -589 251 -561Q216 -532 198 -532Q181 -532 169 -543Q157 -554 157 -569Q157 -599 192 -623Q228 -647 277 -647Q365 -647 417 -571Q469 -496 469 -367Q469 -204 399 -96Q328 12 224 12Q150 12 100 -41Q51 -93 51 -173Q51 -270 105 -332Q159 -395 238 -395Q287 -395 319 -373Q351 -351 369 -303ZM345 -241Q345 -298 324 -328Q303 -359 264 -359Q211 -359 177 -296Q142 -233 142 -132Q142 -75 163 -45Q184 -15 223 -15Q276 -15 311 -79Q345 -142 345 -241Z",
+    },
+    Glyph {
+        c: '∃',
+        advance: 542,
+        path: "M464 -729L464 0L61 0L61 -79L385 -79L385 -325L61 -325L61 -404L385 -404L385 -650L61 -650L61 -729Z",
+    },
+    Glyph {
+        c: '∇',
+        advance: 698,
+        path: "M525 -649L116 -649L321 -117ZM25 -729L667 -729L388 0L304 0Z",
+    },
+    Glyph {
+        c: '∈',
+        advance: 740,
+        path: "M419 -78L634 -78L634 0L419 0Q334 0 262 -42Q190 -84 148 -156Q106 -229 106 -313Q106 -398 148 -471Q190 -543 262 -585Q334 -627 419 -627L634 -627L634 -549L419 -549Q356 -549 302 -517Q248 -485 216 -431Q194 -394 188 -353L634 -353L634 -274L188 -274Q194 -233 216 -196Q248 -142 302 -110Q356 -78 419 -78Z",
+    },
+    Glyph {
+        c: '∉',
+        advance: 740,
+        path: "M419 -78L634 -78L634 0L419 0Q371 0 327 -14L273 132L199 105L254 -47Q188 -88 148 -156Q106 -229 106 -313Q106 -398 148 -471Q190 -543 262 -585Q334 -627 419 -627L465 -627L514 -759L588 -732L550 -627L634 -627L634 -549L521 -549L450 -353L634 -353L634 -274L421 -274L354 -87Q385 -78 419 -78ZM282 -123L337 -274L188 -274Q194 -233 216 -196Q242 -152 282 -123ZM366 -353L437 -549L419 -549Q356 -549 302 -517Q248 -485 216 -431Q194 -394 188 -353Z",
+    },
+    Glyph {
+        c: '∏',
+        advance: 796,
+        path: "M26 -719L769 -719L769 -683L676 -683L676 156L769 156L769 192L478 192L478 156L572 156L572 -683L223 -683L223 156L317 156L317 192L26 192L26 156L119 156L119 -683L26 -683Z",
+    },
+    Glyph {
+        c: '∑',
+        advance: 714,
+        path: "M8 -719L575 -719L629 -541L591 -541L555 -652Q550 -668 535 -676Q520 -683 487 -683L150 -683L545 -277L545 -259L142 100L574 100Q605 100 614 94Q623 87 628 69L663 -43L701 -43L629 192L8 192L8 172L457 -226L8 -693Z",
+    },
+    Glyph {
+        c: '−',
+        advance: 838,
+        path: "M106 -353L732 -353L732 -274L106 -274Z",
+    },
+    Glyph {
+        c: '∓',
+        advance: 838,
+        path: "M458 -461L458 -270L732 -270L732 -191L458 -191L458 0L380 0L380 -191L106 -191L106 -270L380 -270L380 -461ZM106 -627L732 -627L732 -549L106 -549Z",
+    },
+    Glyph {
+        c: '∗',
+        advance: 680,
+        path: "M574 -402L383 -313L574 -223L537 -166L367 -271L373 -85L307 -85L313 -271L143 -166L106 -223L297 -312L106 -402L143 -460L313 -354L307 -541L373 -541L367 -354L537 -460Z",
+    },
+    Glyph {
+        c: '∘',
+        advance: 490,
+        path: "M316 -313Q316 -343 295 -365Q274 -386 245 -386Q215 -386 194 -365Q174 -344 174 -314Q174 -284 194 -263Q215 -243 245 -243Q275 -243 296 -264Q316 -284 316 -313ZM344 -413Q384 -373 384 -314Q384 -255 344 -215Q303 -175 244 -175Q185 -175 146 -215Q106 -254 106 -313Q106 -373 146 -413Q187 -454 246 -454Q305 -454 344 -413Z",
+    },
+    Glyph {
+        c: '√',
+        advance: 637,
+        path: "M545 -811L637 -811L637 -764L581 -764L291 20L259 20L103 -411L42 -389L30 -428L168 -476L293 -130Z",
+    },
+    Glyph {
+        c: '∝',
+        advance: 677,
+        path: "M387 -325Q363 -368 331 -390Q298 -411 257 -411Q208 -411 176 -379Q145 -346 145 -295Q145 -246 174 -213Q203 -180 246 -180Q286 -180 318 -212Q350 -245 387 -325ZM569 -110Q533 -110 497 -138Q461 -167 422 -236Q387 -169 349 -139Q312 -108 265 -108Q199 -108 154 -163Q108 -217 108 -298Q108 -383 149 -436Q189 -489 255 -489Q301 -489 338 -459Q375 -429 411 -360Q444 -427 483 -459Q521 -491 569 -491L569 -418Q548 -418 516 -385Q484 -353 447 -273Q471 -229 504 -208Q536 -186 569 -186Z",
+    },
+    Glyph {
+        c: '∞',
+        advance: 833,
+        path: "M447 -273Q471 -229 504 -208Q536 -186 577 -186Q626 -186 658 -219Q689 -252 689 -303Q689 -353 660 -385Q631 -418 588 -418Q548 -418 516 -385Q484 -353 447 -273ZM387 -325Q363 -368 331 -390Q298 -411 257 -411Q208 -411 176 -379Q145 -346 145 -295Q145 -246 174 -213Q203 -180 246 -180Q286 -180 318 -212Q350 -245 387 -325ZM422 -236Q387 -169 349 -139Q312 -108 265 -108Q199 -108 154 -163Q108 -217 108 -298Q108 -383 149 -436Q189 -489 255 -489Q301 -489 338 -459Q375 -429 411 -360Q444 -427 483 -459Q521 -491 569 -491Q634 -491 680 -436Q726 -381 726 -300Q726 -215 685 -162Q645 -110 579 -110Q533 -110 497 -138Q461 -167 422 -236Z",
+    },
+    Glyph {
+        c: '∧',
+        advance: 732,
+        path: "M129 0L305 -584L427 -584L603 0L508 0L366 -464L224 0Z",
+    },
+    Glyph {
+        c: '∨',
+        advance: 732,
+        path: "M129 -584L224 -584L366 -120L508 -584L603 -584L427 0L305 0Z",
+    },
+    Glyph {
+        c: '∩',
+        advance: 838,
+        path: "M184 -313L184 0L105 0L105 -313Q105 -398 147 -471Q189 -543 262 -585Q334 -627 419 -627Q504 -627 576 -585Q648 -543 690 -471Q732 -398 732 -313L732 0L654 0L654 -313Q654 -377 623 -431Q591 -485 537 -517Q482 -549 419 -549Q355 -549 301 -517Q247 -485 215 -431Q184 -377 184 -313Z",
+    },
+    Glyph {
+        c: '∪',
+        advance: 838,
+        path: "M654 -301L654 -615L732 -615L732 -301Q732 -216 690 -144Q648 -72 576 -30Q504 12 419 12Q334 12 262 -30Q189 -72 147 -144Q105 -216 105 -301L105 -615L184 -615L184 -301Q184 -238 215 -184Q247 -129 301 -98Q355 -66 419 -66Q482 -66 537 -98Q591 -129 623 -184Q654 -238 654 -301Z",
+    },
+    Glyph {
+        c: '∫',
+        advance: 521,
+        path: "M152 128Q204 128 210 -208Q211 -261 212 -289Q218 -540 264 -648Q311 -757 409 -757Q448 -757 473 -736Q497 -716 497 -684Q497 -658 481 -642Q466 -627 439 -627Q418 -627 405 -636Q391 -646 385 -663Q382 -670 380 -688Q378 -705 370 -705Q318 -705 311 -403Q309 -309 307 -255Q299 -21 253 80Q207 181 111 181Q72 181 48 161Q23 141 23 109Q23 82 40 66Q56 51 86 51Q102 51 114 58Q125 65 133 79Q138 87 140 108Q143 128 152 128Z",
+    },
+    Glyph {
+        c: '≈',
+        advance: 838,
+        path: "M732 -299L732 -227Q684 -183 639 -163Q595 -142 550 -142Q505 -142 424 -173Q417 -176 413 -177Q408 -179 397 -183Q325 -211 279 -211Q234 -211 192 -193Q149 -175 106 -138L106 -210Q155 -254 199 -274Q243 -294 288 -294Q335 -294 420 -262L425 -260Q430 -258 440 -254Q511 -226 558 -226Q603 -226 646 -244Q689 -262 732 -299ZM732 -489L732 -417Q684 -374 640 -353Q596 -333 550 -333Q503 -333 418 -365L413 -367Q408 -369 397 -373Q325 -401 279 -401Q234 -401 192 -383Q149 -365 106 -328L106 -400Q155 -444 199 -464Q243 -484 288 -484Q335 -484 420 -452L425 -450Q430 -448 440 -444Q511 -416 558 -416Q603 -416 646 -434Q689 -452 732 -489Z",
+    },
+    Glyph {
+        c: '≠',
+        advance: 838,
+        path: "M106 -452L483 -452L608 -608L669 -558L584 -452L732 -452L732 -374L517 -374L422 -253L732 -253L732 -175L354 -175L228 -19L167 -69L252 -175L106 -175L106 -253L320 -253L415 -374L106 -374Z",
+    },
+    Glyph {
+        c: '≡',
+        advance: 838,
+        path: "M106 -353L732 -353L732 -274L106 -274ZM106 -534L732 -534L732 -456L106 -456ZM106 -171L732 -171L732 -93L106 -93Z",
+    },
+    Glyph {
+        c: '≤',
+        advance: 838,
+        path: "M732 -501L217 -361L732 -221L732 -140L106 -320L106 -403L732 -582ZM106 -76L732 -76L732 0L106 0Z",
+    },
+    Glyph {
+        c: '≥',
+        advance: 838,
+        path: "M732 -76L732 0L106 0L106 -76ZM106 -501L106 -582L732 -403L732 -320L106 -140L106 -221L621 -361Z",
+    },
+    Glyph {
+        c: '⊂',
+        advance: 838,
+        path: "M419 -78L732 -78L732 0L419 0Q334 0 262 -42Q190 -84 148 -156Q106 -229 106 -313Q106 -398 148 -471Q190 -543 262 -585Q334 -627 419 -627L732 -627L732 -549L419 -549Q356 -549 302 -517Q248 -485 216 -431Q184 -377 184 -313Q184 -250 216 -196Q248 -142 302 -110Q356 -78 419 -78Z",
+    },
+    Glyph {
+        c: '⊃',
+        advance: 838,
+        path: "M418 -549L106 -549L106 -627L418 -627Q503 -627 576 -585Q648 -543 690 -471Q732 -398 732 -313Q732 -229 690 -156Q648 -84 576 -42Q503 0 418 0L106 0L106 -78L418 -78Q482 -78 536 -110Q590 -142 622 -196Q654 -250 654 -313Q654 -377 622 -431Q590 -485 536 -517Q482 -549 418 -549Z",
+    },
+    Glyph {
+        c: '⊆',
+        advance: 838,
+        path: "M106 -6L732 -6L732 73L106 73ZM419 -151L732 -151L732 -73L419 -73Q334 -73 262 -115Q190 -157 148 -229Q106 -302 106 -387Q106 -472 148 -544Q190 -616 262 -658Q334 -700 419 -700L732 -700L732 -622L419 -622Q356 -622 302 -590Q248 -559 216 -504Q184 -450 184 -387Q184 -323 216 -269Q248 -215 302 -183Q356 -151 419 -151Z",
+    },
+    Glyph {
+        c: '⊇',
+        advance: 838,
+        path: "M106 -6L732 -6L732 73L106 73ZM418 -622L106 -622L106 -700L418 -700Q503 -700 576 -658Q648 -616 690 -544Q732 -472 732 -387Q732 -302 690 -229Q648 -157 576 -115Q503 -73 418 -73L106 -73L106 -151L418 -151Q482 -151 536 -183Q590 -215 622 -269Q654 -323 654 -387Q654 -450 622 -504Q590 -559 536 -590Q482 -622 418 -622Z",
+    },
+    Glyph {
+        c: '⊕',
+        advance: 838,
+        path: "M105 -313Q105 -398 147 -471Q189 -543 262 -585Q334 -627 419 -627Q504 -627 576 -585Q648 -543 690 -471Q732 -398 732 -313Q732 -229 690 -156Q648 -84 576 -42Q504 0 419 0Q334 0 262 -42Q189 -84 147 -156Q105 -229 105 -313ZM187 -353L380 -353L380 -546Q338 -539 301 -517Q247 -485 215 -431Q194 -394 187 -353ZM458 -546L458 -353L651 -353Q644 -394 623 -431Q591 -485 537 -517Q500 -539 458 -546ZM651 -274L458 -274L458 -81Q500 -88 537 -110Q591 -142 623 -196Q644 -233 651 -274ZM380 -81L380 -274L187 -274Q194 -233 215 -196Q247 -142 301 -110Q338 -88 380 -81Z",
+    },
+    Glyph {
+        c: '⊗',
+        advance: 838,
+        path: "M105 -313Q105 -398 147 -471Q189 -543 262 -585Q334 -627 419 -627Q504 -627 576 -585Q648 -543 690 -471Q732 -398 732 -313Q732 -229 690 -156Q648 -84 576 -42Q504 0 419 0Q334 0 262 -42Q189 -84 147 -156Q105 -229 105 -313ZM215 -196Q221 -186 228 -177L363 -313L227 -450Q221 -440 215 -431Q184 -377 184 -313Q184 -250 215 -196ZM283 -505L419 -369L555 -505Q546 -512 537 -517Q482 -549 419 -549Q355 -549 301 -517Q292 -512 283 -505ZM611 -450L474 -313L610 -177Q617 -186 623 -196Q654 -250 654 -313Q654 -377 623 -431Q617 -441 611 -450ZM555 -122L419 -258L283 -122Q292 -116 301 -110Q355 -78 419 -78Q482 -78 537 -110Q546 -116 555 -122Z",
+    },
+    Glyph {
+        c: '⊥',
+        advance: 940,
+        path: "M106 0L106 -79L431 -79L431 -688L510 -688L510 -79L834 -79L834 0Z",
+    },
+    Glyph {
+        c: '⋅',
+        advance: 342,
+        path: "M106 -347Q106 -375 125 -393Q144 -412 171 -412Q198 -412 217 -393Q236 -374 236 -347Q236 -320 217 -301Q198 -282 171 -282Q144 -282 125 -301Q106 -319 106 -347Z",
+    },
+    Glyph {
+        c: '⋆',
+        advance: 626,
+        path: "M122 -374L268 -374L313 -512L359 -374L504 -374L386 -288L432 -149L313 -235L194 -149L240 -288Z",
+    },
+    Glyph {
+        c: '⌈',
+        advance: 390,
+        path: "M86 -760L313 -760L313 -708L179 -708L179 132L86 132Z",
+    },
+    Glyph {
+        c: '⌉',
+        advance: 390,
+        path: "M304 -760L304 132L211 132L211 -708L77 -708L77 -760Z",
+    },
+    Glyph {
+        c: '⌊',
+        advance: 390,
+        path: "M86 132L86 -760L179 -760L179 80L313 80L313 132Z",
+    },
+    Glyph {
+        c: '⌋',
+        advance: 390,
+        path: "M304 132L77 132L77 80L211 80L211 -760L304 -760Z",
+    },
+    Glyph {
+        c: '⌞',
+        advance: 469,
+        path: "M403 0L403 70L86 70L86 -264L156 -264L156 0Z",
+    },
+    Glyph {
+        c: '⌟',
+        advance: 469,
+        path: "M65 0L312 0L312 -264L383 -264L383 70L65 70Z",
+    },
+    Glyph {
+        c: '□',
+        advance: 945,
+        path: "M146 67L798 67L798 -587L146 -587ZM91 123L91 -643L854 -643L854 123Z",
+    },
+    Glyph {
+        c: '⟨',
+        advance: 390,
+        path: "M89 -313L227 -759L310 -759L172 -313L310 132L227 132Z",
+    },
+    Glyph {
+        c: '⟩',
+        advance: 390,
+        path: "M301 -313L163 132L80 132L218 -313L80 -759L163 -759Z",
+    },
+];
+
+#[rustfmt::skip]
+pub(super) static ITALIC: &[Glyph] = &[
+    Glyph {
+        c: '0',
+        advance: 636,
+        path: "M467 -364Q499 -530 478 -612Q457 -694 382 -694Q307 -694 254 -612Q201 -530 169 -364Q137 -198 158 -116Q179 -34 254 -34Q329 -34 382 -116Q435 -198 467 -364ZM244 14Q125 14 78 -85Q31 -185 66 -364Q101 -543 187 -643Q273 -742 392 -742Q511 -742 558 -643Q604 -543 570 -364Q535 -185 449 -85Q363 14 244 14Z",
+    },
+    Glyph {
+        c: '1',
+        advance: 636,
+        path: "M70 0L80 -52L207 -52L325 -658L159 -563L172 -627L372 -742L439 -742L305 -52L432 -52L422 0Z",
+    },
+    Glyph {
+        c: '2',
+        advance: 636,
+        path: "M164 -555L109 -555L134 -685Q192 -713 248 -728Q304 -742 354 -742Q468 -742 523 -687Q579 -632 560 -537Q540 -430 361 -281Q347 -270 340 -264L120 -80L424 -80L442 -170L499 -170L466 0L-4 0L6 -53L270 -274Q357 -347 401 -408Q444 -470 457 -537Q471 -611 441 -652Q411 -694 343 -694Q273 -694 229 -659Q184 -625 164 -555Z",
+    },
+    Glyph {
+        c: '3',
+        advance: 636,
+        path: "M162 -698Q224 -720 278 -731Q333 -742 379 -742Q486 -742 537 -696Q588 -650 572 -568Q559 -502 509 -458Q459 -414 380 -398Q467 -385 506 -333Q546 -280 529 -197Q510 -95 430 -40Q351 14 222 14Q165 14 113 2Q61 -10 12 -35L40 -177L94 -177Q85 -106 121 -70Q157 -34 234 -34Q309 -34 360 -77Q412 -121 426 -196Q443 -282 407 -326Q371 -370 285 -370L239 -370L249 -420L273 -420Q358 -420 408 -455Q458 -491 472 -562Q484 -626 455 -660Q427 -694 361 -694Q295 -694 252 -663Q210 -631 192 -570L137 -570Z",
+    },
+    Glyph {
+        c: '4',
+        advance: 636,
+        path: "M325 -247L400 -635L76 -247ZM492 0L160 0L170 -52L287 -52L315 -195L-3 -195L7 -248L422 -742L519 -742L423 -247L562 -247L552 -195L413 -195L385 -52L502 -52Z",
+    },
+    Glyph {
+        c: '5',
+        advance: 636,
+        path: "M575 -729L560 -649L226 -649L185 -440Q214 -458 250 -466Q285 -475 327 -475Q445 -475 501 -410Q557 -344 535 -231Q512 -115 430 -51Q349 14 224 14Q173 14 123 2Q73 -10 22 -35L50 -177L105 -177Q96 -107 128 -71Q161 -34 233 -34Q310 -34 362 -85Q413 -136 432 -231Q450 -326 418 -376Q387 -427 310 -427Q266 -427 229 -411Q192 -396 160 -363L118 -363L189 -729Z",
+    },
+    Glyph {
+        c: '6',
+        advance: 636,
+        path: "M339 -426Q270 -426 223 -377Q177 -328 159 -236Q140 -139 167 -86Q194 -34 263 -34Q332 -34 379 -85Q426 -135 444 -230Q462 -325 435 -375Q408 -426 339 -426ZM175 -401Q215 -438 261 -456Q307 -474 359 -474Q468 -474 519 -409Q569 -343 547 -230Q526 -119 445 -52Q363 14 250 14Q126 14 78 -78Q29 -170 62 -341Q100 -532 199 -637Q298 -742 441 -742Q479 -742 521 -735Q562 -728 603 -713L580 -593L524 -593Q528 -642 501 -668Q475 -694 421 -694Q326 -694 266 -622Q206 -550 175 -401Z",
+    },
+    Glyph {
+        c: '7',
+        advance: 636,
+        path: "M625 -679L208 0L135 0L533 -649L196 -649L179 -559L122 -559L155 -729L635 -729Z",
+    },
+    Glyph {
+        c: '8',
+        advance: 636,
+        path: "M434 -199Q449 -277 418 -321Q388 -364 318 -364Q248 -364 200 -321Q153 -277 138 -199Q123 -121 153 -77Q184 -34 254 -34Q324 -34 371 -77Q418 -121 434 -199ZM482 -553Q496 -620 469 -657Q442 -694 382 -694Q322 -694 281 -657Q240 -620 227 -553Q214 -486 240 -449Q267 -412 327 -412Q388 -412 429 -449Q470 -486 482 -553ZM398 -388Q479 -377 515 -327Q552 -277 537 -199Q517 -97 441 -41Q365 14 244 14Q124 14 69 -41Q15 -97 35 -199Q50 -277 106 -327Q162 -377 248 -388Q177 -401 146 -444Q115 -487 127 -553Q145 -641 215 -692Q286 -742 392 -742Q498 -742 548 -692Q599 -641 582 -553Q569 -487 521 -444Q474 -401 398 -388Z",
+    },
+    Glyph {
+        c: '9',
+        advance: 636,
+        path: "M460 -327Q421 -290 375 -272Q329 -254 275 -254Q167 -254 117 -319Q67 -385 89 -498Q110 -609 192 -676Q273 -742 387 -742Q510 -742 558 -650Q606 -558 573 -387Q536 -196 437 -91Q338 14 195 14Q157 14 116 7Q75 0 33 -15L57 -136L111 -136Q108 -87 135 -61Q162 -34 215 -34Q310 -34 370 -106Q430 -177 460 -327ZM373 -694Q304 -694 257 -643Q210 -593 192 -498Q174 -403 201 -353Q228 -302 297 -302Q366 -302 413 -351Q459 -400 477 -492Q496 -589 469 -642Q442 -694 373 -694Z",
+    },
+    Glyph {
+        c: 'A',
+        advance: 722,
+        path: "M181 -264L448 -264L382 -611ZM-77 0L-67 -52L-3 -52L389 -729L471 -729L600 -52L671 -52L661 0L396 0L406 -52L487 -52L457 -212L150 -212L58 -52L138 -52L128 0Z",
+    },
+    Glyph {
+        c: 'B',
+        advance: 735,
+        path: "M186 -52L332 -52Q420 -52 468 -90Q516 -128 532 -211Q548 -293 515 -331Q482 -369 394 -369L248 -369ZM258 -421L382 -421Q462 -421 505 -452Q548 -482 561 -549Q574 -616 543 -646Q512 -677 432 -677L308 -677ZM-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L486 -729Q596 -729 643 -684Q690 -639 673 -549Q661 -484 614 -445Q568 -407 491 -398Q582 -386 621 -339Q660 -291 644 -211Q623 -103 545 -51Q466 0 321 0Z",
+    },
+    Glyph {
+        c: 'C',
+        advance: 765,
+        path: "M672 -193Q620 -91 533 -38Q447 14 331 14Q260 14 204 -10Q147 -35 109 -82Q65 -137 52 -206Q39 -276 56 -364Q89 -534 208 -638Q326 -742 487 -742Q546 -742 611 -727Q675 -711 746 -679L714 -511L659 -511Q658 -603 612 -646Q566 -690 468 -690Q352 -690 275 -607Q199 -524 168 -364Q137 -204 181 -121Q225 -38 341 -38Q423 -38 483 -77Q543 -116 582 -193Z",
+    },
+    Glyph {
+        c: 'D',
+        advance: 802,
+        path: "M186 -52L277 -52Q419 -52 511 -133Q603 -214 632 -365Q662 -517 601 -597Q541 -677 398 -677L308 -677ZM-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L416 -729Q604 -729 691 -633Q778 -537 744 -365Q711 -193 587 -97Q462 0 274 0Z",
+    },
+    Glyph {
+        c: 'E',
+        advance: 730,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L713 -729L682 -567L622 -567L641 -669L306 -669L259 -425L498 -425L515 -516L575 -516L528 -274L468 -274L486 -365L247 -365L188 -60L531 -60L550 -162L610 -162L579 0Z",
+    },
+    Glyph {
+        c: 'F',
+        advance: 694,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L723 -729L691 -567L631 -567L651 -669L306 -669L259 -425L508 -425L525 -516L585 -516L539 -274L479 -274L496 -365L247 -365L186 -52L303 -52L293 0Z",
+    },
+    Glyph {
+        c: 'G',
+        advance: 799,
+        path: "M669 -511Q669 -603 624 -646Q578 -690 481 -690Q355 -690 278 -609Q200 -528 168 -364Q137 -203 185 -121Q232 -38 356 -38Q412 -38 465 -52Q518 -65 568 -93L605 -281L468 -281L478 -333L714 -333L661 -61Q589 -23 510 -5Q432 14 346 14Q181 14 102 -89Q23 -192 56 -364Q90 -537 209 -639Q329 -742 498 -742Q560 -742 624 -728Q688 -713 757 -684L724 -511Z",
+    },
+    Glyph {
+        c: 'H',
+        advance: 872,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677L259 -425L637 -425L686 -677L593 -677L603 -729L888 -729L877 -677L785 -677L663 -52L756 -52L746 0L461 0L471 -52L564 -52L625 -365L247 -365L186 -52L279 -52L269 0Z",
+    },
+    Glyph {
+        c: 'I',
+        advance: 395,
+        path: "M186 -52L279 -52L269 0L-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677Z",
+    },
+    Glyph {
+        c: 'J',
+        advance: 401,
+        path: "M-168 175L-146 60L-91 60Q-99 108 -82 132Q-65 156 -22 156Q36 156 66 119Q96 83 117 -24L244 -677L131 -677L141 -729L446 -729L436 -677L343 -677L215 -20Q192 101 133 154Q74 208 -33 208Q-68 208 -102 200Q-136 191 -168 175Z",
+    },
+    Glyph {
+        c: 'K',
+        advance: 747,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677L254 -401L622 -677L542 -677L553 -729L795 -729L785 -677L703 -677L336 -402L618 -52L701 -52L691 0L522 0L244 -346L186 -52L279 -52L269 0Z",
+    },
+    Glyph {
+        c: 'L',
+        advance: 664,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677L188 -60L521 -60L544 -182L604 -182L569 0Z",
+    },
+    Glyph {
+        c: 'M',
+        advance: 1024,
+        path: "M-16 0L-6 -52L87 -52L208 -677L110 -677L121 -729L333 -729L488 -210L845 -729L1044 -729L1034 -677L937 -677L815 -52L908 -52L898 0L613 0L623 -52L716 -52L826 -615L477 -107L408 -107L257 -615L147 -52L240 -52L230 0Z",
+    },
+    Glyph {
+        c: 'N',
+        advance: 875,
+        path: "M-21 0L-11 -52L87 -52L209 -677L111 -677L121 -729L308 -729L633 -154L735 -677L637 -677L647 -729L903 -729L893 -677L795 -677L661 14L602 14L254 -600L147 -52L246 -52L236 0Z",
+    },
+    Glyph {
+        c: 'O',
+        advance: 820,
+        path: "M347 -38Q466 -38 543 -120Q621 -203 652 -364Q683 -525 638 -608Q593 -690 474 -690Q354 -690 276 -608Q199 -525 168 -364Q137 -203 182 -120Q227 -38 347 -38ZM336 14Q262 14 205 -10Q147 -35 109 -82Q65 -137 52 -206Q39 -275 56 -364Q73 -452 113 -522Q153 -591 219 -646Q276 -694 342 -718Q409 -742 484 -742Q643 -742 720 -638Q797 -534 764 -364Q747 -276 707 -206Q666 -136 601 -82Q544 -34 478 -10Q411 14 336 14Z",
+    },
+    Glyph {
+        c: 'P',
+        advance: 673,
+        path: "M249 -371L377 -371Q450 -371 496 -410Q542 -450 556 -524Q570 -599 540 -638Q509 -677 437 -677L308 -677ZM-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L471 -729Q580 -729 633 -674Q687 -618 668 -524Q650 -430 575 -375Q500 -319 391 -319L238 -319L186 -52L299 -52L289 0Z",
+    },
+    Glyph {
+        c: 'Q',
+        advance: 820,
+        path: "M363 14Q195 14 116 -89Q61 -161 61 -268Q61 -312 70 -364Q87 -452 128 -522Q168 -591 233 -646Q290 -694 356 -718Q423 -742 498 -742Q657 -742 734 -638Q788 -565 788 -460Q788 -415 778 -364Q750 -219 658 -121Q565 -22 431 6Q451 39 486 54Q521 70 574 70L696 70L678 160L621 160Q492 160 438 120Q385 80 363 14ZM361 -38Q480 -38 558 -120Q635 -203 666 -364Q679 -430 679 -483Q679 -559 652 -607Q607 -690 488 -690Q368 -690 291 -608Q213 -525 182 -364Q169 -298 169 -245Q169 -169 196 -120Q241 -38 361 -38Z",
+    },
+    Glyph {
+        c: 'R',
+        advance: 753,
+        path: "M479 -362Q511 -353 531 -330Q552 -308 563 -268L627 -52L716 -52L706 0L534 0L465 -232Q446 -299 422 -319Q399 -339 351 -339L242 -339L186 -52L289 -52L279 0L-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L496 -729Q602 -729 650 -678Q698 -627 680 -534Q666 -459 615 -416Q564 -373 479 -362ZM252 -391L396 -391Q470 -391 512 -426Q554 -461 568 -534Q583 -607 554 -642Q525 -677 452 -677L308 -677Z",
+    },
+    Glyph {
+        c: 'S',
+        advance: 685,
+        path: "M29 -35L61 -201L117 -201Q104 -118 141 -78Q179 -38 272 -38Q359 -38 412 -73Q464 -107 477 -173Q487 -226 465 -254Q443 -282 359 -309L269 -338Q170 -370 137 -417Q104 -464 120 -547Q138 -640 214 -691Q290 -742 409 -742Q459 -742 518 -731Q576 -720 640 -699L610 -544L555 -544Q562 -621 525 -656Q489 -690 400 -690Q323 -690 277 -658Q230 -627 219 -567Q208 -515 233 -485Q257 -455 350 -426L435 -399Q528 -369 562 -323Q595 -277 580 -199Q559 -93 481 -40Q402 14 268 14Q208 14 148 2Q88 -10 29 -35Z",
+    },
+    Glyph {
+        c: 'T',
+        advance: 667,
+        path: "M120 0L130 -52L223 -52L344 -671L129 -671L107 -557L47 -557L81 -729L728 -729L695 -557L635 -557L657 -671L442 -671L322 -52L415 -52L405 0Z",
+    },
+    Glyph {
+        c: 'U',
+        advance: 843,
+        path: "M202 -677L109 -677L119 -729L404 -729L394 -677L301 -677L228 -300Q200 -160 235 -105Q271 -50 382 -50Q494 -50 550 -105Q606 -160 634 -300L707 -677L614 -677L624 -729L870 -729L860 -677L767 -677L692 -290Q660 -124 582 -55Q505 14 352 14Q198 14 146 -55Q95 -125 127 -290Z",
+    },
+    Glyph {
+        c: 'V',
+        advance: 722,
+        path: "M235 -677L342 -117L666 -677L583 -677L594 -729L808 -729L797 -677L727 -677L335 0L251 0L124 -677L51 -677L61 -729L327 -729L316 -677Z",
+    },
+    Glyph {
+        c: 'W',
+        advance: 1028,
+        path: "M691 0L611 0L560 -593L277 0L197 0L138 -677L65 -677L76 -729L342 -729L332 -677L241 -677L288 -138L569 -729L648 -729L701 -132L959 -677L875 -677L886 -729L1096 -729L1085 -677L1014 -677Z",
+    },
+    Glyph {
+        c: 'X',
+        advance: 712,
+        path: "M321 -312L92 -52L186 -52L176 0L-65 0L-55 -52L29 -52L298 -358L147 -677L69 -677L80 -729L369 -729L358 -677L272 -677L383 -442L589 -677L496 -677L506 -729L745 -729L734 -677L652 -677L405 -396L568 -52L646 -52L636 0L347 0L357 -52L444 -52Z",
+    },
+    Glyph {
+        c: 'Y',
+        advance: 660,
+        path: "M118 0L128 -52L221 -52L272 -314L118 -677L49 -677L60 -729L331 -729L320 -677L235 -677L360 -382L600 -677L518 -677L528 -729L740 -729L729 -677L661 -677L373 -323L320 -52L413 -52L403 0Z",
+    },
+    Glyph {
+        c: 'Z',
+        advance: 695,
+        path: "M-26 0L-19 -35L558 -669L181 -669L160 -560L100 -560L133 -729L709 -729L702 -694L125 -60L537 -60L557 -162L616 -162L585 0Z",
+    },
+    Glyph {
+        c: 'a',
+        advance: 596,
+        path: "M363 -81Q298 14 193 14Q107 14 64 -44Q34 -85 34 -144Q34 -168 39 -196Q72 -369 192 -458Q294 -533 418 -533Q501 -533 529 -519L438 -52L516 -52L506 0L348 0ZM128 -146Q128 -42 229 -42Q291 -42 333 -91Q375 -140 393 -234L438 -469L438 -469Q438 -483 399 -483Q306 -483 233 -410Q161 -336 135 -203Q128 -171 128 -146Z",
+    },
+    Glyph {
+        c: 'b',
+        advance: 640,
+        path: "M180 -708L94 -708L104 -760L280 -760L218 -438Q253 -487 299 -510Q344 -533 404 -533Q500 -533 546 -458Q576 -408 576 -338Q576 -301 568 -260Q544 -138 469 -62Q394 14 298 14Q238 14 201 -9Q165 -32 148 -81L133 0L43 0ZM178 -234Q170 -195 170 -165Q170 -120 186 -91Q212 -42 281 -42Q350 -42 396 -97Q442 -152 463 -260Q473 -309 473 -348Q473 -393 459 -422Q435 -477 365 -477Q297 -477 251 -428Q206 -378 188 -285Z",
+    },
+    Glyph {
+        c: 'c',
+        advance: 560,
+        path: "M494 -156Q459 -73 396 -29Q333 14 248 14Q134 14 80 -61Q42 -113 42 -188Q42 -222 50 -260Q74 -384 157 -459Q241 -533 354 -533Q403 -533 450 -522Q497 -510 541 -487L516 -354L463 -354Q463 -361 463 -367Q463 -425 438 -454Q410 -485 346 -485Q273 -485 225 -428Q177 -372 155 -260Q145 -208 145 -168Q145 -122 159 -91Q185 -34 258 -34Q316 -34 356 -64Q397 -95 422 -156Z",
+    },
+    Glyph {
+        c: 'd',
+        advance: 640,
+        path: "M600 -760L453 0L363 0L378 -81Q342 -32 297 -9Q252 14 192 14Q96 14 50 -62Q20 -112 20 -182Q20 -218 28 -260Q52 -382 127 -458Q202 -533 298 -533Q358 -533 395 -510Q431 -487 448 -438L500 -708L415 -708L425 -760ZM408 -234L418 -285Q425 -323 425 -354Q425 -398 410 -428Q384 -477 315 -477Q246 -477 200 -422Q154 -368 133 -260Q123 -210 123 -172Q123 -127 137 -97Q161 -42 230 -42Q299 -42 344 -91Q390 -140 408 -234Z",
+    },
+    Glyph {
+        c: 'e',
+        advance: 592,
+        path: "M146 -199Q145 -181 145 -164Q145 -118 161 -88Q190 -34 267 -34Q324 -34 352 -51Q401 -82 413 -122L479 -122Q459 -60 387 -16Q336 14 235 14Q135 14 81 -61Q42 -113 42 -188Q42 -222 50 -259Q74 -383 156 -458Q239 -533 356 -533Q547 -533 547 -398Q547 -299 447 -250Q345 -201 146 -199ZM397 -309Q452 -341 452 -401Q452 -485 341 -485Q275 -485 230 -435Q185 -385 157 -251Q313 -258 397 -309Z",
+    },
+    Glyph {
+        c: 'f',
+        advance: 370,
+        path: "M498 -637L451 -637Q453 -647 453 -657Q453 -679 441 -693Q424 -712 386 -712Q335 -712 310 -684Q284 -657 270 -586L257 -519L402 -519L392 -467L247 -467L150 32Q133 119 79 164Q25 208 -62 208L-56 160Q-5 160 20 133Q46 104 60 32L157 -467L71 -467L81 -519L167 -519L180 -584Q197 -671 250 -715Q304 -760 392 -760Q424 -760 456 -754Q487 -748 518 -736Z",
+    },
+    Glyph {
+        c: 'g',
+        advance: 640,
+        path: "M597 -519L498 -11Q476 105 403 161Q325 222 216 222Q165 222 120 213Q75 204 35 185L57 76L104 76Q102 127 130 150Q159 174 220 174Q299 174 344 129Q390 85 408 -11L422 -81Q386 -32 341 -9Q295 14 238 14Q152 14 109 -44Q82 -81 79 -131Q77 -161 83 -196Q117 -369 237 -458Q339 -533 458 -533Q567 -534 597 -519ZM498 -467Q497 -483 444 -483Q349 -483 277 -410Q206 -336 179 -203Q172 -163 174 -133Q176 -103 188 -83Q212 -42 274 -42Q343 -42 388 -91Q434 -140 452 -234L461 -285Z",
+    },
+    Glyph {
+        c: 'h',
+        advance: 644,
+        path: "M48 0L186 -708L100 -708L110 -760L286 -760L221 -427Q256 -479 301 -506Q346 -533 399 -533Q485 -533 516 -484Q535 -454 535 -404Q535 -372 526 -330L472 -52L552 -52L542 0L372 0L431 -302Q440 -352 440 -386Q440 -416 433 -432Q417 -467 357 -467Q294 -467 253 -421Q211 -376 194 -289L138 0Z",
+    },
+    Glyph {
+        c: 'i',
+        advance: 320,
+        path: "M158 -680Q162 -703 182 -719Q201 -736 224 -736Q247 -736 260 -719Q270 -707 270 -691Q270 -686 269 -680Q264 -657 245 -641Q226 -625 203 -625Q180 -625 167 -641Q157 -653 157 -669Q157 -674 158 -680ZM150 -52L235 -52L226 0L51 0L141 -467L55 -467L65 -519L241 -519Z",
+    },
+    Glyph {
+        c: 'j',
+        advance: 310,
+        path: "M183 -680Q187 -703 206 -719Q226 -736 249 -736Q271 -736 285 -719Q294 -707 294 -691Q294 -686 293 -680Q289 -657 270 -641Q251 -625 228 -625Q205 -625 191 -641Q181 -653 181 -669Q181 -674 183 -680ZM163 -467L78 -467L88 -519L263 -519L152 51Q137 131 80 177Q23 222 -60 222Q-95 222 -125 214Q-156 206 -183 190L-162 83L-116 83Q-117 95 -117 105Q-117 136 -105 152Q-88 174 -48 174Q-4 174 23 144Q50 114 62 51Z",
+    },
+    Glyph {
+        c: 'k',
+        advance: 606,
+        path: "M330 -324L474 -52L549 -52L539 0L395 0L253 -265L170 -199L131 0L42 0L179 -708L93 -708L103 -760L279 -760L183 -265L441 -467L366 -467L376 -519L522 -519L512 -467Z",
+    },
+    Glyph {
+        c: 'l',
+        advance: 320,
+        path: "M141 -52L226 -52L216 0L42 0L179 -708L93 -708L103 -760L279 -760Z",
+    },
+    Glyph {
+        c: 'm',
+        advance: 948,
+        path: "M547 -418Q584 -475 630 -504Q676 -533 730 -533Q812 -533 842 -482Q860 -452 860 -403Q860 -371 852 -330L798 -52L879 -52L869 0L698 0L760 -320Q768 -358 768 -385Q768 -416 759 -433Q742 -467 688 -467Q628 -467 588 -421Q547 -376 530 -289L474 0L384 0L447 -323Q454 -360 454 -386Q454 -417 445 -434Q428 -467 374 -467Q314 -467 274 -421Q233 -376 216 -289L160 0L70 0L161 -468L75 -468L85 -519L261 -519L243 -427Q278 -479 322 -506Q366 -533 415 -533Q475 -533 510 -503Q544 -473 547 -418Z",
+    },
+    Glyph {
+        c: 'n',
+        advance: 644,
+        path: "M70 0L161 -467L75 -467L85 -519L261 -519L243 -427Q278 -479 323 -506Q368 -533 421 -533Q507 -533 538 -484Q557 -454 557 -404Q557 -372 548 -330L494 -52L574 -52L564 0L394 0L453 -302Q462 -352 462 -386Q462 -416 455 -433Q438 -468 379 -468Q316 -468 275 -422Q233 -376 216 -289L160 0Z",
+    },
+    Glyph {
+        c: 'o',
+        advance: 602,
+        path: "M257 -34Q329 -34 377 -91Q425 -148 447 -260Q457 -311 457 -350Q457 -397 443 -428Q417 -485 345 -485Q272 -485 224 -428Q176 -371 155 -260Q145 -209 145 -170Q145 -123 159 -91Q185 -34 257 -34ZM248 14Q134 14 80 -61Q42 -113 42 -188Q42 -222 50 -260Q74 -384 157 -458Q240 -533 354 -533Q468 -533 522 -458Q559 -407 559 -332Q559 -298 552 -260Q528 -136 445 -61Q361 14 248 14Z",
+    },
+    Glyph {
+        c: 'p',
+        advance: 640,
+        path: "M229 -285L219 -234Q211 -195 211 -165Q211 -120 227 -91Q253 -42 322 -42Q391 -42 437 -97Q483 -152 504 -260Q513 -309 513 -348Q513 -393 500 -422Q475 -477 406 -477Q338 -477 292 -428Q247 -378 229 -285ZM174 -467L88 -467L98 -519L274 -519L259 -438Q294 -487 340 -510Q385 -533 445 -533Q541 -533 586 -458Q617 -408 617 -338Q617 -301 609 -260Q585 -138 510 -62Q435 14 339 14Q279 14 242 -9Q206 -32 189 -81L133 208L43 208Z",
+    },
+    Glyph {
+        c: 'q',
+        advance: 640,
+        path: "M419 -81Q386 -32 345 -9Q304 14 250 14Q164 14 120 -44Q93 -81 90 -131Q88 -161 95 -196Q128 -369 248 -458Q350 -533 470 -533Q564 -534 594 -519L463 156L549 156L539 208L363 208ZM456 -483Q362 -483 289 -410Q217 -336 190 -203Q183 -163 185 -133Q187 -103 199 -83Q223 -42 286 -42Q347 -42 389 -91Q431 -140 449 -234L495 -469Q495 -483 456 -483Z",
+    },
+    Glyph {
+        c: 'r',
+        advance: 478,
+        path: "M527 -520L502 -390L450 -390Q452 -400 452 -408Q452 -434 440 -448Q425 -467 388 -467Q322 -467 277 -421Q233 -375 216 -289L160 0L70 0L161 -468L75 -468L85 -519L261 -519L243 -427Q280 -481 326 -507Q373 -533 433 -533Q455 -533 478 -530Q501 -526 527 -520Z",
+    },
+    Glyph {
+        c: 's',
+        advance: 513,
+        path: "M35 -150L86 -150Q84 -136 84 -123Q84 -34 205 -34Q326 -34 344 -123Q345 -132 345 -147Q345 -163 329 -181Q312 -199 242 -223L181 -245Q119 -267 98 -294Q78 -322 78 -354Q78 -366 81 -381Q95 -452 155 -493Q215 -533 302 -533Q388 -533 479 -491L457 -378L405 -378Q407 -389 407 -399Q407 -435 383 -460Q358 -485 301 -485Q186 -485 170 -405Q168 -396 168 -382Q168 -367 184 -350Q200 -333 262 -312L329 -290Q398 -267 424 -232Q442 -208 442 -184Q442 -82 360 -28Q296 14 197 14Q99 14 11 -29Z",
+    },
+    Glyph {
+        c: 't',
+        advance: 402,
+        path: "M134 -467L55 -467L65 -519L144 -519L175 -680L266 -680L234 -519L403 -519L393 -467L224 -467L160 -137Q153 -99 153 -76Q153 -61 156 -53Q166 -34 200 -34Q235 -34 255 -55Q275 -76 285 -122L353 -122Q335 -51 294 -19Q253 14 183 14Q105 14 79 -21Q63 -42 63 -81Q63 -105 70 -137Z",
+    },
+    Glyph {
+        c: 'u',
+        advance: 644,
+        path: "M484 -519L574 -519L483 -52L568 -52L558 0L383 0L401 -92Q366 -40 321 -13Q276 14 224 14Q138 14 106 -35Q87 -64 87 -115Q87 -147 96 -189L149 -467L68 -467L79 -519L250 -519L191 -217Q182 -167 182 -133Q182 -104 189 -87Q206 -52 265 -52Q328 -52 369 -98Q411 -144 428 -231Z",
+    },
+    Glyph {
+        c: 'v',
+        advance: 565,
+        path: "M263 0L197 0L97 -467L38 -467L47 -519L182 -519L270 -110Q326 -159 363 -221Q441 -350 469 -467L410 -467L420 -519L554 -519Q501 -318 434 -205Q375 -107 263 0Z",
+    },
+    Glyph {
+        c: 'w',
+        advance: 856,
+        path: "M584 -114Q649 -184 664 -210Q718 -308 771 -467L695 -466L705 -519L835 -519L825 -466L825 -466Q775 -285 728 -198Q658 -73 581 0L506 0L453 -388L250 0L178 0L115 -467L56 -466L66 -519L200 -519L255 -114L467 -519L530 -519Z",
+    },
+    Glyph {
+        c: 'x',
+        advance: 564,
+        path: "M64 0L3 0L232 -249L117 -467L53 -467L62 -519L194 -519L302 -317L488 -519L549 -519L325 -275L443 -52L511 -52L501 0L365 0L255 -207Z",
+    },
+    Glyph {
+        c: 'y',
+        advance: 565,
+        path: "M575 -515Q527 -235 442 -88Q317 128 217 193Q173 222 56 222Q33 222 10 218Q-14 214 -37 207L-18 108L28 108Q27 116 27 123Q27 144 36 155Q48 172 80 172Q166 172 241 97L118 -467L59 -467L69 -519L204 -519L319 -4Q397 -122 417 -170Q478 -322 490 -462L431 -462L441 -515Z",
+    },
+    Glyph {
+        c: 'z',
+        advance: 527,
+        path: "M398 -476Q451 -476 502 -519L531 -519L523 -477L168 -107Q173 -105 188 -101Q204 -96 221 -85L245 -70Q291 -42 324 -42Q388 -42 463 -116L451 -57Q368 25 304 25Q266 24 211 -9Q157 -43 123 -43Q70 -43 19 0L-10 0L-2 -42L353 -412Q348 -414 333 -418Q317 -423 300 -434L276 -449Q230 -477 197 -477Q133 -477 58 -403L70 -462Q153 -544 217 -544Q255 -543 310 -510Q364 -476 398 -476Z",
+    },
+    Glyph {
+        c: '²',
+        advance: 401,
+        path: "M102 -633L68 -633L83 -710Q118 -726 154 -734Q190 -742 224 -742Q291 -742 326 -710Q362 -678 352 -627Q340 -567 229 -482L218 -474L82 -369L270 -369L280 -422L316 -422L297 -326L3 -326L11 -363L174 -487Q223 -524 247 -554Q271 -584 277 -616Q285 -658 266 -682Q247 -706 206 -706Q168 -706 142 -688Q115 -669 102 -633Z",
+    },
+    Glyph {
+        c: '³',
+        advance: 401,
+        path: "M97 -717Q135 -729 169 -736Q203 -742 232 -742Q299 -742 331 -716Q362 -689 353 -641Q346 -606 315 -582Q285 -557 236 -549Q293 -541 317 -512Q341 -483 332 -434Q320 -378 272 -349Q224 -319 144 -319Q109 -319 76 -326Q43 -333 13 -346L29 -429L63 -429Q59 -393 79 -374Q99 -355 144 -355Q190 -355 219 -376Q248 -398 256 -438Q265 -483 244 -507Q224 -530 175 -530L149 -530L156 -566L169 -566Q216 -566 244 -584Q271 -602 279 -638Q285 -671 269 -688Q252 -706 215 -706Q175 -706 151 -690Q126 -675 117 -643L83 -643Z",
+    },
+    Glyph {
+        c: '¹',
+        advance: 401,
+        path: "M46 -326L54 -367L138 -367L198 -677L90 -625L99 -671L229 -734L278 -734L207 -367L292 -367L284 -326Z",
+    },
+    Glyph {
+        c: 'ˆ',
+        advance: 500,
+        path: "M321 -800L403 -800L481 -616L425 -616L351 -743L227 -616L170 -616Z",
+    },
+    Glyph {
+        c: 'Α',
+        advance: 722,
+        path: "M181 -264L448 -264L382 -611ZM-77 0L-67 -52L-3 -52L389 -729L471 -729L600 -52L671 -52L661 0L396 0L406 -52L487 -52L457 -212L150 -212L58 -52L138 -52L128 0Z",
+    },
+    Glyph {
+        c: 'Β',
+        advance: 735,
+        path: "M186 -52L332 -52Q420 -52 468 -90Q516 -128 532 -211Q548 -293 515 -331Q482 -369 394 -369L248 -369ZM258 -421L382 -421Q462 -421 505 -452Q548 -482 561 -549Q574 -616 543 -646Q512 -677 432 -677L308 -677ZM-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L486 -729Q596 -729 643 -684Q690 -639 673 -549Q661 -484 614 -445Q568 -407 491 -398Q582 -386 621 -339Q660 -291 644 -211Q623 -103 545 -51Q466 0 321 0Z",
+    },
+    Glyph {
+        c: 'Γ',
+        advance: 694,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L723 -729L691 -566L631 -566L651 -669L306 -669L186 -52L303 -52L293 0Z",
+    },
+    Glyph {
+        c: 'Δ',
+        advance: 722,
+        path: "M609 0L-33 0L389 -729L471 -729ZM487 -52L382 -611L58 -52Z",
+    },
+    Glyph {
+        c: 'Ε',
+        advance: 730,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L713 -729L682 -567L622 -567L641 -669L306 -669L259 -425L498 -425L515 -516L575 -516L528 -274L468 -274L486 -365L247 -365L188 -60L531 -60L550 -162L610 -162L579 0Z",
+    },
+    Glyph {
+        c: 'Ζ',
+        advance: 695,
+        path: "M-26 0L-19 -35L558 -669L181 -669L160 -560L100 -560L133 -729L709 -729L702 -694L125 -60L537 -60L557 -162L616 -162L585 0Z",
+    },
+    Glyph {
+        c: 'Η',
+        advance: 872,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677L259 -425L637 -425L686 -677L593 -677L603 -729L888 -729L877 -677L785 -677L663 -52L756 -52L746 0L461 0L471 -52L564 -52L625 -365L247 -365L186 -52L279 -52L269 0Z",
+    },
+    Glyph {
+        c: 'Θ',
+        advance: 820,
+        path: "M289 -365L271 -274L211 -274L259 -516L319 -516L301 -425L543 -425L561 -516L621 -516L574 -274L514 -274L531 -365ZM347 -38Q466 -38 543 -120Q621 -203 652 -364Q683 -525 638 -608Q593 -690 474 -690Q354 -690 276 -608Q199 -525 168 -364Q137 -203 182 -120Q227 -38 347 -38ZM336 14Q262 14 205 -10Q147 -35 109 -82Q65 -137 52 -206Q39 -275 56 -364Q73 -452 113 -522Q153 -591 219 -646Q276 -694 342 -718Q409 -742 484 -742Q643 -742 720 -638Q797 -534 764 -364Q747 -276 707 -206Q666 -136 601 -82Q544 -34 478 -10Q411 14 336 14Z",
+    },
+    Glyph {
+        c: 'Ι',
+        advance: 395,
+        path: "M186 -52L279 -52L269 0L-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677Z",
+    },
+    Glyph {
+        c: 'Κ',
+        advance: 747,
+        path: "M-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L411 -729L400 -677L308 -677L254 -401L622 -677L542 -677L553 -729L795 -729L785 -677L703 -677L336 -402L618 -52L701 -52L691 0L522 0L244 -346L186 -52L279 -52L269 0Z",
+    },
+    Glyph {
+        c: 'Λ',
+        advance: 722,
+        path: "M487 -52L382 -611L58 -52L138 -52L128 0L-77 0L-67 -52L-3 -52L389 -729L471 -729L600 -52L671 -52L661 0L396 0L406 -52Z",
+    },
+    Glyph {
+        c: 'Μ',
+        advance: 1024,
+        path: "M-16 0L-6 -52L87 -52L208 -677L110 -677L121 -729L333 -729L488 -210L845 -729L1044 -729L1034 -677L937 -677L815 -52L908 -52L898 0L613 0L623 -52L716 -52L826 -615L477 -107L408 -107L257 -615L147 -52L240 -52L230 0Z",
+    },
+    Glyph {
+        c: 'Ν',
+        advance: 875,
+        path: "M-21 0L-11 -52L87 -52L209 -677L111 -677L121 -729L308 -729L633 -154L735 -677L637 -677L647 -729L903 -729L893 -677L795 -677L661 14L602 14L254 -600L147 -52L246 -52L236 0Z",
+    },
+    Glyph {
+        c: 'Ξ',
+        advance: 704,
+        path: "M529 -92L542 -162L603 -162L571 0L-16 0L16 -162L76 -162L62 -92ZM635 -637L168 -637L155 -567L95 -567L126 -729L713 -729L682 -567L622 -567ZM234 -365L216 -274L156 -274L203 -516L263 -516L246 -425L475 -425L493 -516L553 -516L506 -274L446 -274L463 -365Z",
+    },
+    Glyph {
+        c: 'Ο',
+        advance: 820,
+        path: "M347 -38Q466 -38 543 -120Q621 -203 652 -364Q683 -525 638 -608Q593 -690 474 -690Q354 -690 276 -608Q199 -525 168 -364Q137 -203 182 -120Q227 -38 347 -38ZM336 14Q262 14 205 -10Q147 -35 109 -82Q65 -137 52 -206Q39 -275 56 -364Q73 -452 113 -522Q153 -591 219 -646Q276 -694 342 -718Q409 -742 484 -742Q643 -742 720 -638Q797 -534 764 -364Q747 -276 707 -206Q666 -136 601 -82Q544 -34 478 -10Q411 14 336 14Z",
+    },
+    Glyph {
+        c: 'Π',
+        advance: 872,
+        path: "M888 -729L877 -677L785 -677L663 -52L756 -52L746 0L461 0L471 -52L564 -52L684 -669L306 -669L186 -52L279 -52L269 0L-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729Z",
+    },
+    Glyph {
+        c: 'Ρ',
+        advance: 673,
+        path: "M249 -371L377 -371Q450 -371 496 -410Q542 -450 556 -524Q570 -599 540 -638Q509 -677 437 -677L308 -677ZM-16 0L-6 -52L87 -52L208 -677L116 -677L126 -729L471 -729Q580 -729 633 -674Q687 -618 668 -524Q650 -430 575 -375Q500 -319 391 -319L238 -319L186 -52L299 -52L289 0Z",
+    },
+    Glyph {
+        c: 'Σ',
+        advance: 707,
+        path: "M475 -397L146 -92L526 -92L540 -162L600 -162L569 0L-30 0L365 -365L111 -729L710 -729L679 -567L619 -567L639 -669L299 -669Z",
+    },
+    Glyph {
+        c: 'Τ',
+        advance: 667,
+        path: "M120 0L130 -52L223 -52L344 -671L129 -671L107 -557L47 -557L81 -729L728 -729L695 -557L635 -557L657 -671L442 -671L322 -52L415 -52L405 0Z",
+    },
+    Glyph {
+        c: 'Υ',
+        advance: 660,
+        path: "M118 0L128 -52L221 -52L272 -314L118 -677L49 -677L60 -729L331 -729L320 -677L235 -677L360 -382L600 -677L518 -677L528 -729L740 -729L729 -677L661 -677L373 -323L320 -52L413 -52L403 0Z",
+    },
+    Glyph {
+        c: 'Φ',
+        advance: 820,
+        path: "M618 -167Q563 -132 499 -116Q448 -104 408 -99L399 -52L492 -52L482 0L197 0L207 -52L300 -52L309 -99Q272 -104 226 -116Q167 -132 125 -167Q78 -205 61 -253Q44 -302 56 -364Q68 -426 104 -474Q140 -523 202 -562Q259 -597 321 -612Q355 -620 412 -628L421 -677L329 -677L339 -729L624 -729L613 -677L521 -677L511 -628Q564 -620 595 -612Q652 -597 695 -562Q742 -523 759 -474Q776 -426 764 -364Q752 -302 716 -253Q680 -205 618 -167ZM416 -139Q503 -149 559 -193Q631 -251 653 -364Q676 -481 625 -535Q585 -577 504 -590ZM317 -139L405 -590Q318 -577 262 -535Q191 -481 168 -364Q146 -251 196 -193Q234 -149 317 -139Z",
+    },
+    Glyph {
+        c: 'Χ',
+        advance: 712,
+        path: "M321 -312L92 -52L186 -52L176 0L-65 0L-55 -52L29 -52L298 -358L147 -677L69 -677L80 -729L369 -729L358 -677L272 -677L383 -442L589 -677L496 -677L506 -729L745 -729L734 -677L652 -677L405 -396L568 -52L646 -52L636 0L347 0L357 -52L444 -52Z",
+    },
+    Glyph {
+        c: 'Ψ',
+        advance: 877,
+        path: "M662 -445L690 -587Q717 -729 811 -729L909 -729L899 -677L849 -677Q805 -677 789 -587L759 -437Q732 -298 656 -240Q589 -188 457 -181L431 -52L524 -52L514 0L229 0L239 -52L332 -52L357 -181Q225 -188 181 -240Q128 -301 154 -437L184 -587Q202 -677 157 -677L107 -677L118 -729L216 -729Q310 -729 282 -587L254 -445Q231 -328 268 -282Q294 -250 368 -237L454 -677L361 -677L371 -729L656 -729L646 -677L553 -677L467 -237Q546 -249 584 -282Q639 -328 662 -445Z",
+    },
+    Glyph {
+        c: 'Ω',
+        advance: 829,
+        path: "M490 -80L654 -80L667 -147L725 -147L696 0L407 0L424 -89Q519 -123 579 -199Q639 -275 661 -388Q687 -524 636 -607Q585 -690 477 -690Q369 -690 286 -607Q203 -524 176 -388Q154 -274 184 -199Q214 -123 295 -89L278 0L-10 0L19 -147L76 -147L62 -80L226 -80Q132 -123 89 -208Q46 -294 66 -399Q81 -473 121 -537Q162 -601 225 -651Q282 -695 349 -719Q417 -742 487 -742Q558 -742 615 -719Q673 -696 712 -651Q757 -601 773 -536Q789 -472 774 -399Q754 -295 678 -209Q601 -123 490 -80Z",
+    },
+    Glyph {
+        c: 'α',
+        advance: 675,
+        path: "M406 -128L376 -74Q353 -32 301 -5Q269 12 217 12Q215 12 214 12Q118 12 72 -63Q42 -114 42 -184Q42 -220 50 -262Q74 -381 149 -459Q220 -533 339 -533Q424 -533 459 -500Q491 -471 493 -408L494 -390L523 -444Q551 -496 606 -521L655 -521L649 -492Q600 -478 574 -432L497 -293L502 -144Q504 -67 521 -59Q527 -56 537 -56Q563 -56 614 -83L603 -24Q548 4 507 4Q463 4 434 -26Q408 -54 407 -93ZM403 -224L401 -367Q398 -481 331 -482Q275 -482 222 -422Q177 -373 155 -260Q145 -210 145 -172Q145 -127 159 -97Q184 -42 242 -42Q307 -42 373 -166Z",
+    },
+    Glyph {
+        c: 'β',
+        advance: 578,
+        path: "M111 -63Q145 -34 216 -34Q291 -34 343 -77Q394 -121 409 -196Q429 -298 393 -342Q356 -387 271 -387L225 -387L235 -439L259 -439Q321 -439 371 -475Q412 -504 427 -582Q434 -616 418 -654Q393 -718 348 -717Q237 -713 200 -519ZM-32 208L110 -519Q149 -723 264 -750Q326 -765 366 -765Q457 -765 501 -693Q537 -637 527 -588Q514 -519 472 -478Q430 -437 366 -417Q452 -405 492 -352Q532 -299 512 -197Q492 -95 413 -40Q333 14 205 14Q125 14 98 3L58 208Z",
+    },
+    Glyph {
+        c: 'γ',
+        advance: 598,
+        path: "M323 -29Q326 113 287 166Q256 208 222 209Q170 210 158 160Q143 104 232 -8L183 -467L97 -467L107 -519L273 -519L317 -97Q457 -246 490 -349Q506 -397 500 -430Q491 -482 469 -490L475 -519L529 -519Q579 -485 587 -456Q599 -415 594 -389Q584 -342 516 -248Q490 -211 323 -29Z",
+    },
+    Glyph {
+        c: 'δ',
+        advance: 602,
+        path: "M204 -506Q173 -519 163 -542Q149 -569 163 -636Q175 -699 229 -732Q283 -765 379 -765Q405 -765 455 -757Q504 -749 556 -734L537 -638L482 -625Q484 -669 454 -691Q423 -713 377 -713Q331 -713 292 -689Q252 -664 247 -632Q240 -585 253 -570Q284 -533 332 -533Q445 -533 499 -458Q553 -384 529 -260Q505 -136 422 -61Q339 14 225 14Q112 14 58 -61Q3 -136 27 -260Q51 -384 135 -458Q171 -491 204 -506ZM234 -34Q307 -34 355 -91Q403 -148 424 -260Q446 -371 420 -428Q395 -485 322 -485Q250 -485 202 -428Q154 -371 132 -260Q111 -148 137 -91Q163 -34 234 -34Z",
+    },
+    Glyph {
+        c: 'ε',
+        advance: 537,
+        path: "M499 -493L479 -397L425 -385Q427 -428 396 -451Q366 -473 320 -473Q274 -473 235 -449Q196 -424 190 -391Q182 -348 218 -323Q254 -298 319 -298L343 -298L333 -247L288 -247Q223 -247 174 -215Q125 -184 114 -130Q106 -89 142 -59Q178 -27 232 -27Q289 -27 335 -53Q381 -79 396 -129L446 -117L426 -16Q371 1 316 10Q263 19 225 19Q129 19 70 -20Q12 -59 26 -131Q41 -208 95 -246Q124 -265 198 -274L129 -313Q93 -333 105 -396Q118 -458 172 -492Q226 -524 322 -524Q348 -524 397 -517Q447 -508 499 -493Z",
+    },
+    Glyph {
+        c: 'ζ',
+        advance: 542,
+        path: "M591 -760L580 -700Q366 -606 249 -472Q177 -389 155 -275Q129 -146 156 -91Q183 -35 279 -35Q433 -36 433 72Q433 127 372 171Q320 208 227 208L237 156Q335 156 350 79Q363 14 268 14Q131 14 77 -61Q22 -136 50 -275Q69 -373 177 -510Q266 -622 466 -700L174 -700L161 -629L109 -629L135 -760Z",
+    },
+    Glyph {
+        c: 'η',
+        advance: 599,
+        path: "M180 0L90 0L181 -467L95 -467L105 -519L281 -519L263 -427Q298 -479 344 -506Q389 -533 441 -533Q526 -533 558 -484Q589 -436 569 -330L464 208L374 208L473 -302Q491 -397 475 -432Q458 -468 399 -468Q336 -468 295 -422Q253 -376 236 -289Z",
+    },
+    Glyph {
+        c: 'θ',
+        advance: 602,
+        path: "M451 -406Q478 -563 460 -643Q441 -731 369 -731Q297 -731 243 -643Q194 -563 160 -406ZM441 -354L150 -354Q124 -198 141 -119Q161 -30 232 -30Q304 -30 358 -119Q407 -198 441 -354ZM376 -771Q490 -771 538 -664Q586 -557 552 -380Q517 -203 427 -96Q337 11 224 11Q111 11 63 -96Q16 -203 50 -380Q84 -557 174 -664Q263 -771 376 -771Z",
+    },
+    Glyph {
+        c: 'ι',
+        advance: 392,
+        path: "M99 -142L163 -467L77 -467L87 -519L263 -519L189 -142Q176 -74 209 -57Q234 -45 302 -81L291 -22Q180 35 122 -24Q84 -63 99 -142Z",
+    },
+    Glyph {
+        c: 'κ',
+        advance: 656,
+        path: "M339 -519L330 -467L252 -467L213 -265L471 -467L396 -467L406 -519L641 -519L631 -467L542 -467L360 -324L504 -52L579 -52L569 0L313 0L323 -52L397 -52L284 -265L200 -199L171 -52L252 -52L243 0L-9 0L0 -52L82 -52L163 -468L77 -468L86 -519Z",
+    },
+    Glyph {
+        c: 'λ',
+        advance: 634,
+        path: "M310 -638Q300 -683 286 -698Q272 -713 246 -713Q217 -713 201 -699Q184 -684 174 -651L128 -651L147 -750Q173 -757 198 -761Q223 -765 246 -765Q300 -765 326 -743Q352 -721 364 -660L484 -52L542 -52L532 0L337 0L347 -52L425 -52L356 -411L133 -52L216 -52L206 0L-33 0L-23 -52L36 -52L330 -529Z",
+    },
+    Glyph {
+        c: 'μ',
+        advance: 650,
+        path: "M44 208L176 -467L95 -467L105 -519L276 -519L217 -217Q199 -122 215 -87Q232 -52 291 -52Q354 -52 395 -98Q437 -144 454 -231L500 -467L421 -467L432 -519L600 -519L508 -51L593 -51L583 0L409 0L427 -92Q391 -40 350 -13Q309 14 265 14Q237 14 216 5Q194 -3 179 -21L134 208Z",
+    },
+    Glyph {
+        c: 'ν',
+        advance: 608,
+        path: "M172 -57Q237 -80 312 -137Q358 -171 420 -256Q461 -309 474 -357Q487 -402 473 -437Q454 -484 420 -490L426 -519L480 -519Q531 -495 560 -442Q583 -401 573 -351Q566 -315 546 -285Q531 -264 487 -213Q428 -148 359 -104Q233 -21 162 0L71 0L163 -467L77 -467L86 -519L262 -519Z",
+    },
+    Glyph {
+        c: 'ξ',
+        advance: 551,
+        path: "M438 -406L428 -354Q298 -354 246 -324Q154 -271 140 -197Q125 -125 152 -70Q168 -35 279 -35Q433 -36 433 72Q433 127 372 171Q320 208 227 208L237 156Q335 156 350 79Q363 14 268 14Q110 14 65 -44Q17 -106 35 -197Q50 -275 167 -354Q189 -369 270 -383Q146 -425 167 -530Q190 -650 361 -700L174 -700L161 -629L109 -629L135 -760L591 -760L580 -700Q302 -682 271 -524Q249 -411 438 -406Z",
+    },
+    Glyph {
+        c: 'ο',
+        advance: 602,
+        path: "M257 -34Q329 -34 377 -91Q425 -148 447 -260Q457 -311 457 -350Q457 -397 443 -428Q417 -485 345 -485Q272 -485 224 -428Q176 -371 155 -260Q145 -209 145 -170Q145 -123 159 -91Q185 -34 257 -34ZM248 14Q134 14 80 -61Q42 -113 42 -188Q42 -222 50 -260Q74 -384 157 -458Q240 -533 354 -533Q468 -533 522 -458Q559 -407 559 -332Q559 -298 552 -260Q528 -136 445 -61Q361 14 248 14Z",
+    },
+    Glyph {
+        c: 'π',
+        advance: 657,
+        path: "M-9 0L0 -52L82 -52L163 -467L77 -467L86 -519L671 -519L662 -467L577 -467L496 -52L576 -52L566 0L318 0L328 -52L405 -52L486 -467L252 -467L171 -52L250 -52L240 0Z",
+    },
+    Glyph {
+        c: 'ρ',
+        advance: 588,
+        path: "M177 -285L167 -234Q148 -140 175 -91Q201 -42 270 -42Q338 -42 385 -97Q431 -152 452 -260Q473 -372 448 -422Q417 -482 355 -482Q290 -482 240 -428Q195 -378 177 -285ZM81 208L-9 208L81 -256Q105 -378 159 -438Q217 -494 253 -511Q301 -533 364 -533Q489 -533 534 -458Q580 -382 556 -260Q533 -138 458 -62Q382 14 287 14Q227 14 190 -9Q154 -32 137 -81Z",
+    },
+    Glyph {
+        c: 'ς',
+        advance: 560,
+        path: "M301 -35Q455 -37 455 72Q455 127 394 171Q342 208 249 208L259 156Q357 156 372 79Q385 14 290 14Q153 14 99 -61Q44 -136 69 -260Q93 -384 176 -459Q260 -533 373 -533Q422 -533 469 -522Q516 -510 560 -487L534 -354L482 -354Q485 -422 457 -454Q428 -485 364 -485Q292 -485 243 -428Q195 -372 174 -260Q151 -146 178 -91Q205 -35 301 -35Z",
+    },
+    Glyph {
+        c: 'σ',
+        advance: 683,
+        path: "M258 -34Q331 -34 379 -91Q427 -148 448 -260Q468 -361 443 -423Q421 -476 344 -476Q270 -476 225 -423Q177 -366 156 -260Q135 -148 161 -91Q187 -34 258 -34ZM354 -519Q553 -519 705 -519L695 -467L516 -467Q579 -392 553 -260Q529 -136 446 -61Q363 14 249 14Q136 14 82 -61Q27 -136 51 -260Q75 -384 159 -458Q226 -519 354 -519Z",
+    },
+    Glyph {
+        c: 'τ',
+        advance: 553,
+        path: "M364 -467L300 -142Q287 -73 320 -57Q344 -44 413 -81L401 -22Q291 35 233 -24Q195 -63 210 -142L274 -467L133 -467L118 -389L66 -389L92 -519L566 -519L556 -467Z",
+    },
+    Glyph {
+        c: 'υ',
+        advance: 608,
+        path: "M411 -519L465 -519Q541 -480 559 -424Q579 -361 557 -249Q546 -195 517 -148Q486 -100 440 -64Q398 -31 349 -14Q300 3 248 3Q168 0 126 -46Q88 -86 105 -171L163 -467L77 -467L87 -519L263 -519L195 -171Q183 -107 197 -78Q213 -49 258 -48Q318 -47 386 -112Q440 -164 458 -257Q484 -389 472 -417Q448 -467 405 -490Z",
+    },
+    Glyph {
+        c: 'φ',
+        advance: 700,
+        path: "M324 208L234 208L274 1Q178 0 151 -14Q121 -30 92 -64Q66 -95 54 -148Q48 -177 62 -249Q83 -361 128 -424Q168 -480 258 -519L313 -519L308 -490Q255 -467 213 -417Q189 -390 164 -257Q146 -166 162 -112Q178 -61 285 -51L325 -257Q346 -367 379 -424Q435 -520 547 -521Q645 -523 676 -424Q696 -362 674 -249Q660 -177 643 -148Q610 -95 572 -64Q527 -27 493 -14Q462 -1 364 1ZM375 -52Q487 -63 521 -112Q558 -165 576 -257Q597 -365 589 -417Q581 -473 538 -473Q501 -473 464 -417Q439 -381 415 -257Q388 -119 375 -52Z",
+    },
+    Glyph {
+        c: 'χ',
+        advance: 606,
+        path: "M191 156L181 208L-59 208L-48 156L20 156L267 -183L245 -406Q240 -455 227 -466Q209 -481 202 -481Q172 -481 156 -467Q139 -452 129 -419L83 -419L103 -518Q128 -525 153 -529Q178 -533 201 -533Q230 -533 261 -511Q293 -487 299 -428L316 -251L475 -466L413 -466L423 -519L655 -519L645 -466L581 -466L329 -123L352 95Q356 144 369 155Q387 170 395 170Q424 170 440 156Q458 141 467 108L513 108L494 207Q468 214 443 218Q418 222 395 222Q366 222 335 200Q303 176 297 117L280 -55L125 156Z",
+    },
+    Glyph {
+        c: 'ψ',
+        advance: 784,
+        path: "M367 208L277 208L317 3Q271 3 232 -14Q203 -27 173 -64Q145 -98 135 -148Q130 -177 145 -257Q166 -365 158 -417Q150 -472 128 -490L134 -519L183 -519Q232 -469 246 -424Q265 -361 244 -257Q226 -164 243 -112Q264 -47 327 -48L418 -519L509 -519L417 -48Q479 -47 525 -112Q562 -164 580 -257Q602 -368 644 -424Q680 -473 743 -519L792 -519L786 -490Q758 -473 728 -417Q700 -366 679 -257Q664 -177 646 -148Q618 -98 577 -64Q532 -27 498 -14Q452 3 407 3Z",
+    },
+    Glyph {
+        c: 'ω',
+        advance: 815,
+        path: "M374 -95Q294 0 216 3Q171 3 132 -14Q103 -27 72 -64Q46 -95 35 -148Q28 -177 42 -249Q64 -361 108 -424Q148 -480 239 -519L293 -519L288 -490Q235 -467 193 -417Q170 -390 144 -257Q126 -164 143 -112Q163 -47 227 -48Q260 -49 288 -78Q335 -128 346 -183L380 -359L470 -359L436 -183Q425 -128 453 -78Q469 -49 503 -48Q565 -47 611 -112Q648 -164 667 -257Q692 -390 680 -417Q656 -467 613 -490L619 -519L673 -519Q749 -480 767 -424Q787 -361 765 -249Q751 -177 733 -148Q701 -95 663 -64Q618 -27 584 -14Q538 3 493 3Q417 0 374 -95Z",
+    },
+    Glyph {
+        c: 'ϑ',
+        advance: 715,
+        path: "M683 -406L675 -364L614 -364Q579 -198 493 -96Q403 11 290 11Q179 11 129 -96Q73 -217 132 -467L51 -467L61 -519L232 -519Q180 -218 207 -119Q232 -30 298 -30Q370 -30 424 -119Q473 -198 507 -354Q394 -354 333 -390Q249 -440 277 -586Q314 -775 485 -771Q574 -769 613 -664Q649 -562 622 -406ZM517 -406Q547 -586 535 -643Q516 -731 479 -731Q396 -731 367 -583Q349 -488 385 -451Q430 -406 517 -406Z",
+    },
+    Glyph {
+        c: 'ϕ',
+        advance: 682,
+        path: "M339 -37Q427 -46 465 -91Q513 -148 534 -260Q556 -371 530 -428Q510 -474 425 -483ZM291 208L201 208L239 13Q110 2 64 -61Q10 -136 34 -260Q59 -384 142 -458Q212 -521 345 -532L389 -760L479 -760L435 -532Q563 -521 609 -458Q664 -384 639 -260Q615 -136 532 -61Q461 2 329 13ZM249 -37L335 -483Q247 -474 209 -428Q161 -371 139 -260Q118 -148 144 -91Q164 -46 249 -37Z",
+    },
+    Glyph {
+        c: 'ϵ',
+        advance: 560,
+        path: "M42 -186Q42 -221 50 -260Q76 -388 159 -461Q243 -533 351 -533Q460 -533 541 -487L516 -354L463 -354Q464 -365 464 -375Q464 -485 346 -485Q202 -485 163 -295L394 -295L384 -244L152 -244Q145 -202 145 -168Q145 -34 259 -34Q373 -34 422 -156L494 -156Q464 -86 407 -36Q349 14 248 14Q128 14 77 -61Q42 -112 42 -186Z",
+    },
+    Glyph {
+        c: '⁰',
+        advance: 401,
+        path: "M236 -391Q270 -434 293 -527Q316 -620 301 -670Q286 -716 236 -716Q184 -716 150 -670Q115 -624 92 -527Q71 -436 85 -391Q99 -345 149 -345Q200 -345 236 -391ZM143 -319Q63 -319 31 -374Q-2 -430 23 -527Q52 -638 104 -688Q162 -742 242 -742Q322 -742 354 -688Q388 -633 363 -527Q339 -428 281 -374Q224 -319 143 -319Z",
+    },
+    Glyph {
+        c: '⁴',
+        advance: 401,
+        path: "M209 -474L248 -673L59 -474ZM321 -326L109 -326L117 -367L188 -367L202 -434L7 -434L15 -475L261 -734L330 -734L279 -474L359 -474L352 -434L271 -434L258 -367L329 -367Z",
+    },
+    Glyph {
+        c: '⁵',
+        advance: 401,
+        path: "M388 -727L377 -683L152 -683L125 -569Q144 -578 168 -583Q192 -588 221 -588Q299 -588 337 -552Q375 -516 360 -454Q345 -390 290 -354Q235 -319 151 -319Q117 -319 83 -326Q49 -333 15 -346L34 -424L71 -424Q64 -387 87 -366Q109 -345 157 -345Q209 -345 244 -374Q278 -401 291 -454Q303 -506 282 -534Q261 -562 208 -562Q179 -562 154 -553Q130 -544 108 -526L80 -526L127 -727Z",
+    },
+    Glyph {
+        c: '⁶',
+        advance: 401,
+        path: "M274 -453Q287 -505 269 -533Q250 -561 204 -561Q157 -561 125 -534Q94 -507 83 -457Q70 -403 88 -374Q106 -345 152 -345Q199 -345 230 -373Q262 -401 274 -453ZM93 -555Q122 -576 151 -585Q182 -595 217 -595Q289 -595 325 -559Q360 -523 344 -453Q330 -392 275 -355Q220 -319 144 -319Q60 -319 27 -370Q-7 -422 17 -514Q47 -632 109 -685Q176 -742 272 -742Q298 -742 326 -738Q354 -734 381 -726L366 -660L329 -660Q331 -687 313 -701Q295 -716 259 -716Q195 -716 154 -676Q114 -637 93 -555Z",
+    },
+    Glyph {
+        c: '⁷',
+        advance: 401,
+        path: "M381 -707L100 -326L51 -326L319 -690L92 -690L81 -641L42 -641L64 -734L388 -734Z",
+    },
+    Glyph {
+        c: '⁸',
+        advance: 401,
+        path: "M127 -503Q95 -479 84 -436Q74 -393 95 -369Q115 -345 163 -345Q210 -345 242 -369Q274 -393 284 -436Q294 -479 274 -503Q253 -527 206 -527Q159 -527 127 -503ZM308 -695Q290 -716 249 -716Q208 -716 181 -695Q153 -675 145 -638Q136 -602 154 -581Q172 -561 212 -561Q253 -561 281 -581Q308 -602 317 -638Q326 -675 308 -695ZM260 -540Q313 -534 339 -506Q364 -479 354 -436Q340 -380 289 -349Q238 -319 156 -319Q75 -319 39 -349Q2 -380 15 -436Q25 -479 63 -506Q101 -534 159 -540Q118 -547 90 -578Q69 -602 78 -638Q89 -687 137 -714Q184 -742 255 -742Q327 -742 361 -714Q396 -687 384 -638Q374 -596 343 -578Q298 -554 260 -540Z",
+    },
+    Glyph {
+        c: '⁹',
+        advance: 401,
+        path: "M296 -506Q269 -486 238 -476Q207 -466 171 -466Q99 -466 64 -502Q29 -539 45 -608Q60 -669 115 -706Q169 -742 246 -742Q328 -742 362 -691Q396 -640 372 -540Q347 -435 280 -376Q213 -319 117 -319Q91 -319 63 -323Q36 -327 8 -335L23 -401L61 -401Q58 -375 76 -360Q94 -345 130 -345Q194 -345 234 -385Q275 -424 296 -506ZM237 -716Q190 -716 159 -688Q128 -661 115 -608Q101 -552 121 -521Q139 -493 186 -493Q234 -493 264 -520Q293 -547 307 -604Q320 -658 301 -687Q283 -716 237 -716Z",
+    },
+    Glyph {
+        c: '₀',
+        advance: 401,
+        path: "M236 -64Q270 -107 293 -201Q316 -294 301 -344Q286 -390 236 -390Q184 -390 150 -344Q115 -297 92 -201Q71 -109 85 -64Q99 -19 149 -19Q200 -19 236 -64ZM143 7Q63 7 31 -47Q-2 -104 23 -201Q52 -312 104 -361Q162 -416 242 -416Q322 -416 354 -361Q388 -307 363 -201Q339 -102 281 -47Q224 7 143 7Z",
+    },
+    Glyph {
+        c: '₁',
+        advance: 401,
+        path: "M46 0L54 -41L138 -41L198 -351L90 -299L99 -345L229 -408L278 -408L207 -41L292 -41L284 0Z",
+    },
+    Glyph {
+        c: '₂',
+        advance: 401,
+        path: "M102 -307L68 -307L83 -384Q118 -399 154 -408Q190 -416 224 -416Q291 -416 326 -384Q362 -352 352 -301Q340 -241 229 -156L218 -148L82 -43L270 -43L280 -96L316 -96L297 0L3 0L11 -37L174 -161Q223 -198 247 -228Q271 -258 277 -290Q285 -332 266 -356Q247 -380 206 -380Q168 -380 142 -361Q115 -343 102 -307Z",
+    },
+    Glyph {
+        c: '₃',
+        advance: 401,
+        path: "M97 -391Q135 -403 169 -410Q203 -416 232 -416Q299 -416 331 -390Q362 -363 353 -315Q346 -280 315 -255Q285 -231 236 -223Q293 -215 317 -186Q341 -157 332 -108Q320 -52 272 -22Q224 7 144 7Q109 7 76 0Q43 -6 13 -20L29 -103L63 -103Q59 -66 79 -48Q99 -29 144 -29Q190 -29 219 -50Q248 -72 256 -112Q265 -157 244 -180Q224 -204 175 -204L149 -204L156 -240L169 -240Q216 -240 244 -258Q271 -276 279 -312Q285 -345 269 -362Q252 -380 215 -380Q175 -380 151 -364Q126 -349 117 -317L83 -317Z",
+    },
+    Glyph {
+        c: '₄',
+        advance: 401,
+        path: "M209 -148L248 -347L59 -148ZM321 0L109 0L117 -41L188 -41L202 -108L7 -108L15 -149L261 -408L330 -408L279 -148L359 -148L352 -108L271 -108L258 -41L329 -41Z",
+    },
+    Glyph {
+        c: '₅',
+        advance: 401,
+        path: "M388 -401L377 -357L152 -357L125 -243Q144 -252 168 -257Q192 -262 221 -262Q299 -262 337 -226Q375 -190 360 -127Q345 -64 290 -28Q235 7 151 7Q117 7 83 0Q49 -6 15 -20L34 -98L71 -98Q64 -61 87 -40Q109 -19 157 -19Q209 -19 244 -47Q278 -75 291 -127Q303 -180 282 -208Q261 -235 208 -235Q179 -235 154 -227Q130 -218 108 -200L80 -200L127 -401Z",
+    },
+    Glyph {
+        c: '₆',
+        advance: 401,
+        path: "M274 -127Q287 -179 269 -207Q250 -235 204 -235Q157 -235 125 -208Q94 -181 83 -130Q70 -77 88 -48Q106 -19 152 -19Q199 -19 230 -47Q262 -75 274 -127ZM93 -229Q122 -250 151 -259Q182 -269 217 -269Q289 -269 325 -232Q360 -197 344 -127Q330 -66 275 -29Q220 7 144 7Q60 7 27 -43Q-7 -96 17 -188Q47 -306 109 -358Q176 -416 272 -416Q298 -416 326 -412Q354 -408 381 -400L366 -334L329 -334Q331 -361 313 -375Q295 -390 259 -390Q195 -390 154 -350Q114 -311 93 -229Z",
+    },
+    Glyph {
+        c: '₇',
+        advance: 401,
+        path: "M381 -380L100 0L51 0L319 -364L92 -364L81 -314L42 -314L64 -408L388 -408Z",
+    },
+    Glyph {
+        c: '₈',
+        advance: 401,
+        path: "M127 -177Q95 -153 84 -110Q74 -67 95 -43Q115 -19 163 -19Q210 -19 242 -43Q274 -67 284 -110Q294 -153 274 -177Q253 -201 206 -201Q159 -201 127 -177ZM308 -369Q290 -390 249 -390Q208 -390 181 -369Q153 -349 145 -312Q136 -275 154 -255Q172 -234 212 -234Q253 -234 281 -255Q308 -275 317 -312Q326 -349 308 -369ZM260 -214Q313 -208 339 -180Q364 -153 354 -110Q340 -54 289 -23Q238 7 156 7Q75 7 39 -23Q2 -54 15 -110Q25 -153 63 -180Q101 -208 159 -214Q118 -221 90 -252Q69 -275 78 -312Q89 -360 137 -388Q184 -416 255 -416Q327 -416 361 -388Q396 -360 384 -312Q374 -270 343 -252Q298 -228 260 -214Z",
+    },
+    Glyph {
+        c: '₉',
+        advance: 401,
+        path: "M296 -180Q269 -160 238 -150Q207 -140 171 -140Q99 -140 64 -176Q29 -212 45 -282Q60 -343 115 -379Q169 -416 246 -416Q328 -416 362 -365Q396 -313 372 -213Q347 -108 280 -50Q213 7 117 7Q91 7 63 3Q36 -1 8 -9L23 -75L61 -75Q58 -48 76 -34Q94 -19 130 -19Q194 -19 234 -59Q275 -98 296 -180ZM237 -390Q190 -390 159 -362Q128 -334 115 -282Q101 -226 121 -194Q139 -167 186 -167Q234 -167 264 -193Q293 -221 307 -278Q320 -332 301 -361Q283 -390 237 -390Z",
+    },
+    Glyph {
+        c: 'ₖ',
+        advance: 365,
+        path: "M200 -182L291 -29L338 -29L332 0L241 0L152 -148L99 -111L75 0L18 0L105 -396L50 -396L57 -425L167 -425L107 -148L270 -261L223 -261L229 -291L321 -291L314 -261Z",
+    },
+    Glyph {
+        c: 'ₙ',
+        advance: 405,
+        path: "M44 0L102 -261L47 -261L54 -291L165 -291L153 -239Q175 -269 204 -284Q232 -299 265 -299Q319 -299 339 -271Q351 -254 351 -227Q351 -208 346 -185L312 -29L362 -29L355 0L248 0L285 -169Q292 -197 292 -216Q292 -233 287 -242Q276 -262 239 -262Q199 -262 173 -236Q147 -211 136 -162L101 0Z",
+    },
+    Glyph {
+        c: 'ℏ',
+        advance: 644,
+        path: "M221 -427Q292 -533 399 -533Q485 -533 516 -484Q535 -454 535 -413Q535 -373 526 -330L472 -52L552 -52L542 0L372 0L431 -302Q440 -352 440 -384Q440 -416 433 -432Q417 -467 355 -467Q294 -467 253 -422Q211 -376 194 -289L138 0L48 0L156 -552L59 -511L48 -549L165 -599L186 -708L100 -708L110 -760L286 -760L263 -640L442 -717L453 -679L253 -594Z",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_are_sorted() {
+        for table in [UPRIGHT, ITALIC] {
+            assert!(table.windows(2).all(|pair| pair[0].c < pair[1].c));
+        }
+    }
+
+    #[test]
+    fn test_italic_falls_back_to_upright() {
+        assert_ne!(glyph('x', true), glyph('x', false));
+        assert_eq!(glyph('+', true), glyph('+', false));
+        assert_eq!(glyph('∑', true).c, '∑');
+    }
+
+    #[test]
+    fn test_uncovered_character_uses_missing_box() {
+        assert_eq!(glyph('😀', false), &MISSING);
+        assert!(!MISSING.path.is_empty());
+    }
+
+    #[test]
+    fn test_combining_marks_take_no_space() {
+        assert_eq!(glyph('\u{0304}', false).advance, 0);
+        assert!(!glyph('\u{0304}', false).path.is_empty());
+    }
+}
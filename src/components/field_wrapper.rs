@@ -0,0 +1,270 @@
+use crate::theme::{use_mingot_config, use_theme};
+use crate::utils::use_id_sequence;
+use leptos::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_FIELD_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Element ids tying a form control to its description and error
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldIds {
+    pub control: String,
+    pub description: String,
+    pub error: String,
+}
+
+impl FieldIds {
+    /// Ids derived from `base`, e.g. `email` → `email-description`
+    pub fn new(base: &str) -> Self {
+        Self {
+            control: base.to_string(),
+            description: format!("{}-description", base),
+            error: format!("{}-error", base),
+        }
+    }
+
+    /// Fresh ids, unique within the page.
+    ///
+    /// Below a `MingotProvider` they follow render order, so server and
+    /// hydrated client agree; elsewhere a process-wide counter is used.
+    pub fn generate() -> Self {
+        match use_id_sequence() {
+            Some(sequence) => Self::new(&format!("mingot-field-{}", sequence.next())),
+            None => {
+                let id = NEXT_FIELD_ID.fetch_add(1, Ordering::Relaxed);
+                Self::new(&format!("mingot-field-g{}", id))
+            }
+        }
+    }
+}
+
+/// `aria-describedby` and `aria-invalid` for a field's control
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldAria {
+    /// Space-separated ids of the description and error, when shown
+    pub described_by: Option<String>,
+    pub invalid: bool,
+}
+
+impl FieldAria {
+    /// An empty `error` counts as no error
+    pub fn new(ids: &FieldIds, has_description: bool, error: Option<&str>) -> Self {
+        let invalid = error.is_some_and(|e| !e.is_empty());
+        let described_by: Vec<&str> = [
+            has_description.then_some(ids.description.as_str()),
+            invalid.then_some(ids.error.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        Self {
+            described_by: (!described_by.is_empty()).then(|| described_by.join(" ")),
+            invalid,
+        }
+    }
+
+    /// Value for the `aria-invalid` attribute, omitted when valid
+    pub fn invalid_attr(&self) -> Option<&'static str> {
+        self.invalid.then_some("true")
+    }
+}
+
+/// Label, description and error around a form control.
+///
+/// The control itself is the child and should take `ids.control` as its
+/// id and the attributes from [`FieldAria`], so assistive tech reads the
+/// description and error with it. The error renders only while `error`
+/// holds a non-empty message.
+///
+/// # Example
+/// ```rust,ignore
+/// let ids = FieldIds::generate();
+/// let error = Signal::derive(move || if email.get().contains('@') { String::new() } else { "Invalid email".into() });
+/// let aria = {
+///     let ids = ids.clone();
+///     move || FieldAria::new(&ids, false, Some(&error.get()))
+/// };
+///
+/// view! {
+///     <FieldWrapper ids=ids.clone() label="Email" error=error>
+///         <input
+///             id=ids.control
+///             aria-describedby=move || aria().described_by
+///             aria-invalid=move || aria().invalid_attr()
+///         />
+///     </FieldWrapper>
+/// }
+/// ```
+#[component]
+pub fn FieldWrapper(
+    ids: FieldIds,
+    #[prop(optional, into)] label: Signal<Option<String>>,
+    #[prop(optional, into)] description: Signal<Option<String>>,
+    #[prop(optional, into)] error: Option<Signal<String>>,
+    #[prop(optional)] required: bool,
+    /// Rendered between the description and the error, e.g. a counter
+    #[prop(optional)]
+    footer: Option<Children>,
+    /// Component prefix for `StyleOverrides` parts (`{parts}.wrapper`,
+    /// `{parts}.label`, `{parts}.description`, `{parts}.error`)
+    #[prop(optional)]
+    parts: Option<&'static str>,
+    #[prop(optional, into)] class: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let theme = use_theme();
+    let overrides = StoredValue::new(use_mingot_config().styles);
+    let part = move |name: &str, base: String| match parts {
+        Some(prefix) => overrides.with_value(|o| o.apply(&format!("{}.{}", prefix, name), base)),
+        None => base,
+    };
+
+    let label_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        part(
+            "label",
+            format!(
+                "display: block; margin-bottom: 0.25rem; font-size: {}; font-weight: {}; color: {};",
+                &*theme_val.typography.font_sizes.sm,
+                theme_val.typography.font_weights.medium,
+                scheme_colors.text
+            ),
+        )
+    };
+
+    let description_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        part(
+            "description",
+            format!(
+                "margin-top: 0.25rem; font-size: {}; color: {};",
+                &*theme_val.typography.font_sizes.xs,
+                scheme_colors
+                    .get_color("gray", 6)
+                    .unwrap_or_else(|| "#868e96".to_string())
+            ),
+        )
+    };
+
+    let error_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        part(
+            "error",
+            format!(
+                "margin-top: 0.25rem; font-size: {}; color: {};",
+                &*theme_val.typography.font_sizes.xs,
+                scheme_colors
+                    .get_color("red", 6)
+                    .unwrap_or_else(|| "#fa5252".to_string())
+            ),
+        )
+    };
+
+    let class_str = format!("mingot-field {}", class.unwrap_or_default());
+    let FieldIds {
+        control,
+        description: description_id,
+        error: error_id,
+    } = ids;
+
+    view! {
+        <div class=class_str style=move || part("wrapper", "width: 100%;".to_string())>
+            {move || label.get().map(|l| view! {
+                <label for=control.clone() style=label_styles>
+                    {l}
+                    {if required { " *" } else { "" }}
+                </label>
+            })}
+
+            {children()}
+
+            {move || description.get().map(|d| view! {
+                <div id=description_id.clone() style=description_styles>{d}</div>
+            })}
+
+            {footer.map(|footer| footer())}
+
+            {move || {
+                error
+                    .map(|e| e.get())
+                    .filter(|e| !e.is_empty())
+                    .map(|e| view! {
+                        <div id=error_id.clone() class="mingot-field-error" role="alert" style=error_styles>
+                            {e}
+                        </div>
+                    })
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_ids_from_base() {
+        let ids = FieldIds::new("email");
+        assert_eq!(ids.control, "email");
+        assert_eq!(ids.description, "email-description");
+        assert_eq!(ids.error, "email-error");
+    }
+
+    #[test]
+    fn test_generated_ids_are_unique() {
+        assert_ne!(FieldIds::generate(), FieldIds::generate());
+    }
+
+    #[test]
+    fn test_generated_ids_follow_render_order() {
+        // Two renders of the same tree, e.g. on the server and when
+        // hydrating, get the same ids
+        let render = || {
+            Owner::new().with(|| {
+                provide_context(crate::utils::IdSequence::new());
+                (FieldIds::generate(), FieldIds::generate())
+            })
+        };
+        let (first, second) = render();
+        assert_eq!(first.control, "mingot-field-0");
+        assert_eq!(second.control, "mingot-field-1");
+        assert_eq!(render(), (first, second));
+    }
+
+    #[test]
+    fn test_aria_without_description_or_error() {
+        let aria = FieldAria::new(&FieldIds::new("f"), false, None);
+        assert_eq!(aria.described_by, None);
+        assert!(!aria.invalid);
+        assert_eq!(aria.invalid_attr(), None);
+    }
+
+    #[test]
+    fn test_aria_with_error() {
+        let aria = FieldAria::new(&FieldIds::new("f"), false, Some("Required"));
+        assert_eq!(aria.described_by.as_deref(), Some("f-error"));
+        assert!(aria.invalid);
+        assert_eq!(aria.invalid_attr(), Some("true"));
+    }
+
+    #[test]
+    fn test_aria_with_description_and_error() {
+        let ids = FieldIds::new("f");
+        let aria = FieldAria::new(&ids, true, Some("Too short"));
+        assert_eq!(aria.described_by.as_deref(), Some("f-description f-error"));
+
+        let aria = FieldAria::new(&ids, true, None);
+        assert_eq!(aria.described_by.as_deref(), Some("f-description"));
+        assert!(!aria.invalid);
+    }
+
+    #[test]
+    fn test_empty_error_is_valid() {
+        let aria = FieldAria::new(&FieldIds::new("f"), false, Some(""));
+        assert!(!aria.invalid);
+        assert_eq!(aria.described_by, None);
+    }
+}
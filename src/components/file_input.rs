@@ -1,6 +1,7 @@
 use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
+use crate::validation::ValidationError;
 use leptos::ev;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
@@ -24,18 +25,148 @@ impl FileInfo {
 
     /// Format file size in human-readable form
     pub fn formatted_size(&self) -> String {
-        if self.size < 1024 {
-            format!("{} B", self.size)
-        } else if self.size < 1024 * 1024 {
-            format!("{:.1} KB", self.size as f64 / 1024.0)
-        } else if self.size < 1024 * 1024 * 1024 {
-            format!("{:.1} MB", self.size as f64 / (1024.0 * 1024.0))
+        format_file_size(self.size)
+    }
+
+    /// Whether the browser reported an image MIME type
+    pub fn is_image(&self) -> bool {
+        self.file_type.starts_with("image/")
+    }
+}
+
+/// Human-readable byte count, e.g. `"2.0 KB"`
+pub fn format_file_size(size: u64) -> String {
+    if size < 1024 {
+        format!("{} B", size)
+    } else if size < 1024 * 1024 {
+        format!("{:.1} KB", size as f64 / 1024.0)
+    } else if size < 1024 * 1024 * 1024 {
+        format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} GB", size as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+/// Whether a file matches an `accept` list such as `".pdf,image/*"`:
+/// `.ext` entries match the name's extension, `type/*` any subtype and
+/// anything else the exact MIME type, all case-insensitively. An empty
+/// list accepts everything.
+pub fn file_matches_accept(accept: &str, name: &str, mime: &str) -> bool {
+    let name = name.to_lowercase();
+    let mime = mime.to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|p| p.trim().to_lowercase())
+        .filter(|p| !p.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(group) = pattern.strip_suffix("/*") {
+            mime.split_once('/').is_some_and(|(g, _)| g == group)
         } else {
-            format!("{:.1} GB", self.size as f64 / (1024.0 * 1024.0 * 1024.0))
+            mime == pattern
+        }
+    })
+}
+
+/// Limits checked when files are picked or dropped
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileRules {
+    pub accept: Option<String>,
+    pub multiple: bool,
+    /// Largest allowed file, in bytes
+    pub max_size: Option<u64>,
+    /// Most files kept from one selection
+    pub max_files: Option<usize>,
+}
+
+impl FileRules {
+    /// Indices of the `files` to keep, in order, and an error for each one
+    /// left out: a type not matching `accept`, larger than `max_size`, or
+    /// beyond `max_files` (one when not `multiple`)
+    pub fn check(&self, files: &[FileInfo]) -> (Vec<usize>, Vec<ValidationError>) {
+        let limit = if self.multiple {
+            self.max_files.unwrap_or(usize::MAX)
+        } else {
+            1
+        };
+        let mut kept = Vec::new();
+        let mut errors = Vec::new();
+        for (i, file) in files.iter().enumerate() {
+            if !file_matches_accept(
+                self.accept.as_deref().unwrap_or(""),
+                &file.name,
+                &file.file_type,
+            ) {
+                errors.push(ValidationError::custom(format!(
+                    "{}: file type not accepted",
+                    file.name
+                )));
+            } else if let Some(max_size) = self.max_size.filter(|&max| file.size > max) {
+                errors.push(ValidationError::file_too_large(
+                    &file.name,
+                    format_file_size(max_size),
+                ));
+            } else if kept.len() >= limit {
+                errors.push(if self.multiple {
+                    ValidationError::too_many_files(&file.name, limit)
+                } else {
+                    ValidationError::custom(format!("{}: only one file can be selected", file.name))
+                });
+            } else {
+                kept.push(i);
+            }
         }
+        (kept, errors)
     }
 }
 
+/// [`FileRules::check`] returning the kept files themselves
+pub fn filter_files(
+    files: Vec<FileInfo>,
+    rules: &FileRules,
+) -> (Vec<FileInfo>, Vec<ValidationError>) {
+    let (kept, errors) = rules.check(&files);
+    let files = files
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| kept.contains(i))
+        .map(|(_, f)| f)
+        .collect();
+    (files, errors)
+}
+
+/// Object URLs backing the image previews. Every URL handed out is
+/// returned again (for revoking) when replaced or cleared.
+#[derive(Debug, Default)]
+struct PreviewUrls {
+    urls: Vec<String>,
+}
+
+impl PreviewUrls {
+    /// Track `urls` instead of the current ones, returning the old ones
+    fn replace(&mut self, urls: Vec<String>) -> Vec<String> {
+        std::mem::replace(&mut self.urls, urls)
+    }
+
+    fn clear(&mut self) -> Vec<String> {
+        self.replace(Vec::new())
+    }
+}
+
+fn revoke_object_urls(urls: Vec<String>) {
+    #[cfg(target_arch = "wasm32")]
+    for url in urls {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = urls;
+}
+
 /// A file input component for uploading files.
 ///
 /// # Example
@@ -54,6 +185,11 @@ impl FileInfo {
 ///     />
 /// }
 /// ```
+///
+/// Files can also be dragged onto the field; set `dropzone` for a larger
+/// drop area. Files that don't match `accept`, exceed `max_size`, or go
+/// over `max_files` (one when not `multiple`) are left out and reported
+/// through `on_error`. Selected images get thumbnail previews.
 #[component]
 pub fn FileInput(
     /// Label displayed above the input
@@ -71,9 +207,21 @@ pub fn FileInput(
     /// Whether multiple files can be selected
     #[prop(default = false)]
     multiple: bool,
+    /// Largest accepted file size in bytes
+    #[prop(optional)]
+    max_size: Option<u64>,
+    /// Most files kept from one selection (with `multiple`)
+    #[prop(optional)]
+    max_files: Option<usize>,
+    /// Show thumbnails of selected images
+    #[prop(default = true)]
+    previews: bool,
     /// Whether to show a clear button
     #[prop(default = true)]
     clearable: bool,
+    /// Render as a large drop area instead of a compact field
+    #[prop(default = false)]
+    dropzone: bool,
     /// Input variant style
     #[prop(optional)]
     variant: Option<InputVariant>,
@@ -92,6 +240,9 @@ pub fn FileInput(
     /// Callback when files are selected
     #[prop(optional)]
     on_change: Option<Callback<Vec<FileInfo>>>,
+    /// Callback with the reasons files were rejected
+    #[prop(optional)]
+    on_error: Option<Callback<Vec<ValidationError>>>,
     /// Additional CSS class
     #[prop(optional, into)]
     class: Option<String>,
@@ -111,7 +262,23 @@ pub fn FileInput(
     });
 
     let selected_files = RwSignal::new(Vec::<FileInfo>::new());
+    let rejections = RwSignal::new(Vec::<ValidationError>::new());
+    let drag_over = RwSignal::new(false);
     let input_ref = NodeRef::<leptos::html::Input>::new();
+    let rules = StoredValue::new(FileRules {
+        accept: accept.clone(),
+        multiple,
+        max_size,
+        max_files,
+    });
+    // (file index in `selected_files`, object URL) per image thumbnail
+    let thumbnails = RwSignal::new(Vec::<(usize, String)>::new());
+    let preview_urls = StoredValue::new_local(PreviewUrls::default());
+    on_cleanup(move || {
+        if let Some(urls) = preview_urls.try_update_value(|p| p.clear()) {
+            revoke_object_urls(urls);
+        }
+    });
 
     let error_clone = error.clone();
     let input_styles = move || {
@@ -126,6 +293,9 @@ pub fn FileInput(
             .add("display", "flex")
             .add("align-items", "center")
             .add("gap", "0.5rem")
+            .add_if(dropzone, "flex-direction", "column")
+            .add_if(dropzone, "justify-content", "center")
+            .add_if(dropzone, "text-align", "center")
             .add("width", "100%")
             .add("font-family", &*theme_val.typography.font_family)
             .add("border-radius", &*theme_val.radius.sm)
@@ -209,6 +379,26 @@ pub fn FileInput(
             }
         }
 
+        if dropzone {
+            builder
+                .add("min-height", "8rem")
+                .add("padding", &*theme_val.spacing.lg)
+                .add("border-style", "dashed")
+                .add("border-width", "2px");
+        }
+
+        if drag_over.get() && !is_disabled {
+            let highlight = scheme_colors
+                .get_color("blue", 6)
+                .unwrap_or_else(|| "#228be6".to_string());
+            builder.add("border-color", highlight).add(
+                "background-color",
+                scheme_colors
+                    .get_color("blue", 0)
+                    .unwrap_or_else(|| "#e7f5ff".to_string()),
+            );
+        }
+
         // Disabled state
         if is_disabled {
             builder.add("opacity", "0.6");
@@ -221,23 +411,50 @@ pub fn FileInput(
         builder.build()
     };
 
-    let handle_change = move |ev: ev::Event| {
-        let target = ev.target();
-        let input: web_sys::HtmlInputElement = target
-            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
-            .expect("Expected HtmlInputElement");
-
-        let files = input.files();
-        let mut file_list = Vec::new();
+    // Shared by the picker and drops
+    let select_files = move |files: Option<web_sys::FileList>| {
+        let mut raw_files = Vec::new();
 
         if let Some(files) = files {
             for i in 0..files.length() {
                 if let Some(file) = files.get(i) {
-                    file_list.push(FileInfo::from_file(&file));
+                    raw_files.push(file);
                 }
             }
         }
 
+        let infos: Vec<FileInfo> = raw_files.iter().map(FileInfo::from_file).collect();
+        let (kept, errors) = rules.with_value(|r| r.check(&infos));
+        let file_list: Vec<FileInfo> = kept.iter().map(|&i| infos[i].clone()).collect();
+
+        let new_thumbnails: Vec<(usize, String)> = if previews {
+            kept.iter()
+                .enumerate()
+                .filter(|(_, &i)| infos[i].is_image())
+                .filter_map(|(pos, &i)| {
+                    web_sys::Url::create_object_url_with_blob(&raw_files[i])
+                        .ok()
+                        .map(|url| (pos, url))
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let urls = new_thumbnails.iter().map(|(_, url)| url.clone()).collect();
+        revoke_object_urls(
+            preview_urls
+                .try_update_value(|p| p.replace(urls))
+                .unwrap_or_default(),
+        );
+        thumbnails.set(new_thumbnails);
+
+        rejections.set(errors.clone());
+        if !errors.is_empty() {
+            if let Some(callback) = on_error {
+                callback.run(errors);
+            }
+        }
+
         selected_files.set(file_list.clone());
 
         if let Some(callback) = on_change {
@@ -245,6 +462,52 @@ pub fn FileInput(
         }
     };
 
+    let handle_change = move |ev: ev::Event| {
+        let target = ev.target();
+        let input: web_sys::HtmlInputElement = target
+            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+            .expect("Expected HtmlInputElement");
+
+        select_files(input.files());
+    };
+
+    // Without preventDefault on dragover and drop the browser would open
+    // the file instead
+    let handle_dragover = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        if disabled.get_untracked() {
+            return;
+        }
+        if let Some(dt) = ev.data_transfer() {
+            dt.set_drop_effect("copy");
+        }
+        drag_over.set(true);
+    };
+
+    let handle_dragleave = move |ev: ev::DragEvent| {
+        // Moving onto a child fires dragleave on the zone too
+        let still_inside = ev
+            .related_target()
+            .and_then(|t| t.dyn_into::<web_sys::Node>().ok())
+            .zip(
+                ev.current_target()
+                    .and_then(|t| t.dyn_into::<web_sys::Node>().ok()),
+            )
+            .is_some_and(|(to, zone)| zone.contains(Some(&to)));
+        if !still_inside {
+            drag_over.set(false);
+        }
+    };
+
+    let handle_drop = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        drag_over.set(false);
+        if disabled.get_untracked() {
+            return;
+        }
+        select_files(ev.data_transfer().and_then(|dt| dt.files()));
+    };
+
     let handle_click = move |_| {
         if disabled.get() {
             return;
@@ -265,6 +528,13 @@ pub fn FileInput(
         }
 
         selected_files.set(Vec::new());
+        rejections.set(Vec::new());
+        thumbnails.set(Vec::new());
+        revoke_object_urls(
+            preview_urls
+                .try_update_value(|p| p.clear())
+                .unwrap_or_default(),
+        );
 
         if let Some(callback) = on_change {
             callback.run(Vec::new());
@@ -274,7 +544,11 @@ pub fn FileInput(
     let display_text = move || {
         let files = selected_files.get();
         if files.is_empty() {
-            placeholder.clone()
+            if dropzone {
+                format!("Drag files here or {}", placeholder.to_lowercase())
+            } else {
+                placeholder.clone()
+            }
         } else if files.len() == 1 {
             files[0].name.clone()
         } else {
@@ -342,6 +616,37 @@ pub fn FileInput(
         )
     };
 
+    let preview_list_styles = move || {
+        let theme_val = theme.get();
+        format!(
+            "display: flex; flex-wrap: wrap; gap: {}; margin-top: {};",
+            &*theme_val.spacing.xs, &*theme_val.spacing.xs
+        )
+    };
+
+    let preview_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin: 0; width: 4rem; border: 1px solid {}; border-radius: {}; overflow: hidden;",
+            scheme_colors.border, &*theme_val.radius.sm
+        )
+    };
+
+    let preview_image_styles = "display: block; width: 100%; height: 4rem; object-fit: cover;";
+
+    let preview_caption_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "padding: 0.125rem; text-align: center; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string())
+        )
+    };
+
     let class_str = format!("mingot-file-input {}", class.unwrap_or_default());
 
     view! {
@@ -354,18 +659,28 @@ pub fn FileInput(
             })}
 
             <div
+                class="mingot-file-input-dropzone"
                 style=input_styles
+                data-dragging=move || drag_over.get().then_some("true")
                 on:click=handle_click
+                on:dragenter=handle_dragover
+                on:dragover=handle_dragover
+                on:dragleave=handle_dragleave
+                on:drop=handle_drop
             >
                 // Upload icon
-                <svg style=icon_styles width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
+                <svg style=icon_styles width=if dropzone { "32" } else { "16" } height=if dropzone { "32" } else { "16" } viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2">
                     <path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4"></path>
                     <polyline points="17 8 12 3 7 8"></polyline>
                     <line x1="12" y1="3" x2="12" y2="15"></line>
                 </svg>
 
                 // Display text
-                <span style="flex: 1; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;">
+                <span style=if dropzone {
+                    "overflow: hidden; text-overflow: ellipsis; white-space: nowrap; max-width: 100%;"
+                } else {
+                    "flex: 1; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"
+                }>
                     {display_text}
                 </span>
 
@@ -402,6 +717,29 @@ pub fn FileInput(
                 on:change=handle_change
             />
 
+            {move || {
+                let thumbs = thumbnails.get();
+                (!thumbs.is_empty()).then(|| {
+                    let files = selected_files.get();
+                    view! {
+                        <div class="mingot-file-input-previews" style=preview_list_styles>
+                            {thumbs
+                                .into_iter()
+                                .filter_map(|(i, url)| files.get(i).cloned().map(|f| (f, url)))
+                                .map(|(file, url)| view! {
+                                    <figure style=preview_styles title=file.name.clone()>
+                                        <img src=url alt=file.name.clone() style=preview_image_styles />
+                                        <figcaption style=preview_caption_styles>
+                                            {file.formatted_size()}
+                                        </figcaption>
+                                    </figure>
+                                })
+                                .collect::<Vec<_>>()}
+                        </div>
+                    }
+                })
+            }}
+
             {description.map(|d| view! {
                 <div style=description_styles>{d}</div>
             })}
@@ -409,6 +747,14 @@ pub fn FileInput(
             {error.map(|e| view! {
                 <div style=error_styles>{e}</div>
             })}
+
+            {move || {
+                rejections.with(|r| {
+                    r.iter()
+                        .map(|e| view! { <div style=error_styles role="alert">{e.message.clone()}</div> })
+                        .collect::<Vec<_>>()
+                })
+            }}
         </div>
     }
 }
@@ -416,6 +762,149 @@ pub fn FileInput(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::validation::ErrorCode;
+
+    fn file(name: &str, file_type: &str) -> FileInfo {
+        FileInfo {
+            name: name.to_string(),
+            size: 1,
+            file_type: file_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_accept_by_extension() {
+        let accept = ".pdf, .DOCX";
+        assert!(file_matches_accept(accept, "report.pdf", "application/pdf"));
+        assert!(file_matches_accept(accept, "REPORT.PDF", ""));
+        assert!(file_matches_accept(accept, "letter.docx", ""));
+        assert!(!file_matches_accept(accept, "photo.png", "image/png"));
+        assert!(!file_matches_accept(accept, "pdf", ""));
+    }
+
+    #[test]
+    fn test_accept_by_mime() {
+        assert!(file_matches_accept("image/*", "photo.png", "image/png"));
+        assert!(file_matches_accept("image/*", "photo.JPG", "IMAGE/JPEG"));
+        assert!(!file_matches_accept("image/*", "notes.txt", "text/plain"));
+        // No MIME type reported: wildcard groups can't match
+        assert!(!file_matches_accept("image/*", "photo.heic", ""));
+        assert!(file_matches_accept(
+            "application/json",
+            "data.json",
+            "application/json"
+        ));
+        assert!(!file_matches_accept(
+            "application/json",
+            "data.jsonl",
+            "application/jsonl"
+        ));
+        assert!(file_matches_accept("image/*,.csv", "table.csv", "text/csv"));
+    }
+
+    #[test]
+    fn test_empty_accept_allows_everything() {
+        assert!(file_matches_accept("", "anything.bin", ""));
+        assert!(file_matches_accept(" , ", "anything.bin", ""));
+    }
+
+    fn rules(accept: &str, multiple: bool) -> FileRules {
+        FileRules {
+            accept: Some(accept.to_string()),
+            multiple,
+            ..FileRules::default()
+        }
+    }
+
+    #[test]
+    fn test_filter_files() {
+        let files = vec![
+            file("a.png", "image/png"),
+            file("b.txt", "text/plain"),
+            file("c.jpg", "image/jpeg"),
+        ];
+
+        let (kept, errors) = filter_files(files.clone(), &rules("image/*", true));
+        assert_eq!(
+            kept.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+            vec!["a.png", "c.jpg"]
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.starts_with("b.txt"));
+
+        // Single mode keeps the first accepted file only
+        let (kept, errors) = filter_files(files, &rules("image/*", false));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].name, "a.png");
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_max_size_rejects_only_oversized() {
+        let mut big = file("big.png", "image/png");
+        big.size = 2_000_001;
+        let mut exact = file("exact.png", "image/png");
+        exact.size = 2_000_000;
+        let rules = FileRules {
+            multiple: true,
+            max_size: Some(2_000_000),
+            ..FileRules::default()
+        };
+
+        let (kept, errors) = rules.check(&[big, exact, file("small.png", "image/png")]);
+        assert_eq!(kept, vec![1, 2]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, ErrorCode::FileTooLarge);
+        assert!(errors[0].message.starts_with("big.png"));
+    }
+
+    #[test]
+    fn test_max_files_keeps_the_first() {
+        let rules = FileRules {
+            multiple: true,
+            max_files: Some(2),
+            ..FileRules::default()
+        };
+        let files = [file("a", ""), file("b", ""), file("c", ""), file("d", "")];
+        let (kept, errors) = rules.check(&files);
+        assert_eq!(kept, vec![0, 1]);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| e.code == ErrorCode::TooManyFiles));
+
+        // Rejected files don't use up the count
+        let mut big = file("big", "");
+        big.size = 10;
+        let rules = FileRules {
+            max_size: Some(5),
+            ..rules
+        };
+        let (kept, _) = rules.check(&[big, file("a", ""), file("b", "")]);
+        assert_eq!(kept, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_preview_urls_returned_for_revoking() {
+        let mut urls = PreviewUrls::default();
+        assert!(urls
+            .replace(vec!["blob:1".into(), "blob:2".into()])
+            .is_empty());
+
+        // A new selection hands back the previous URLs
+        assert_eq!(
+            urls.replace(vec!["blob:3".into()]),
+            vec!["blob:1".to_string(), "blob:2".to_string()]
+        );
+
+        // Clearing hands back everything still tracked, once
+        assert_eq!(urls.clear(), vec!["blob:3".to_string()]);
+        assert!(urls.clear().is_empty());
+    }
+
+    #[test]
+    fn test_is_image() {
+        assert!(file("a.png", "image/png").is_image());
+        assert!(!file("a.pdf", "application/pdf").is_image());
+    }
 
     #[test]
     fn test_file_info_formatted_size_bytes() {
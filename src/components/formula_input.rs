@@ -3,11 +3,13 @@
 //! Provides a text input that parses and validates mathematical expressions,
 //! supports variables, and recognizes common functions.
 
+use crate::components::unit_input::{CompoundUnit, Dimension, Unit};
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::f64::consts::{E, PI, TAU};
+use std::sync::Arc;
 
 /// Recognized mathematical functions
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -170,18 +172,232 @@ impl MathFunction {
     }
 }
 
+/// Value of a built-in constant such as `pi`, `e` or `tau`
+fn builtin_constant(name: &str) -> Option<f64> {
+    match name {
+        "pi" | "PI" | "π" => Some(PI),
+        "e" | "E" => Some(E),
+        "tau" | "TAU" | "τ" => Some(TAU),
+        _ => None,
+    }
+}
+
+/// Body of a [`FormulaFunction`]
+type FormulaFn = dyn Fn(&[f64]) -> Result<f64, String> + Send + Sync;
+
+/// A user-defined function with a fixed number of arguments
+///
+/// # Example
+/// ```rust,ignore
+/// let gauss = FormulaFunction::new(3, |args| {
+///     let (x, mu, sigma) = (args[0], args[1], args[2]);
+///     Ok((-((x - mu) / sigma).powi(2) / 2.0).exp())
+/// });
+/// ```
+#[derive(Clone)]
+pub struct FormulaFunction {
+    arity: usize,
+    function: Arc<FormulaFn>,
+}
+
+impl FormulaFunction {
+    /// Create a function taking exactly `arity` arguments
+    pub fn new(
+        arity: usize,
+        function: impl Fn(&[f64]) -> Result<f64, String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            arity,
+            function: Arc::new(function),
+        }
+    }
+
+    /// Number of arguments the function takes
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    /// Call the function, checking the argument count
+    pub fn call(&self, args: &[f64]) -> Result<f64, String> {
+        if args.len() != self.arity {
+            return Err(format!("expected {} args, got {}", self.arity, args.len()));
+        }
+        (self.function)(args)
+    }
+}
+
+impl std::fmt::Debug for FormulaFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormulaFunction")
+            .field("arity", &self.arity)
+            .finish_non_exhaustive()
+    }
+}
+
+/// How a name ending in digits, such as `e2` or `x1`, is read when
+/// implicit multiplication is on
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DigitSuffix {
+    /// The digits are part of the name: `e2` is the variable `e2`
+    #[default]
+    Identifier,
+    /// The digits are a separate factor: `e2` is `e * 2`. Function names
+    /// such as `log10` are never split.
+    Multiply,
+}
+
+/// Parsing and evaluation options for formulas
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormulaOptions {
+    /// Read adjacent operands as a product: `2x`, `3sin(x)`, `2(3+4)`,
+    /// `(a)(b)`. An implicit product binds tighter than `*` and `/` but
+    /// looser than `^`, so `2x^2` is `2*(x^2)` and `1/2x` is `1/(2*x)`.
+    pub implicit_multiplication: bool,
+    /// How to read names ending in digits under implicit multiplication
+    pub digit_suffix: DigitSuffix,
+    /// Units whose symbols may appear in the formula, e.g. `5 m / 2 s`
+    pub units: Vec<Unit>,
+}
+
+/// A number with a (possibly compound) unit, produced by unit-aware
+/// evaluation. A plain number has no unit factors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Quantity {
+    pub value: f64,
+    pub unit: CompoundUnit,
+}
+
+impl Quantity {
+    /// A quantity in `unit`
+    pub fn new(value: f64, unit: impl Into<CompoundUnit>) -> Self {
+        Self {
+            value,
+            unit: unit.into(),
+        }
+    }
+
+    /// A plain number
+    pub fn number(value: f64) -> Self {
+        Self {
+            value,
+            unit: CompoundUnit::default(),
+        }
+    }
+
+    /// Whether the quantity has no unit factors
+    pub fn is_number(&self) -> bool {
+        self.unit.factors().is_empty()
+    }
+
+    /// Value as a plain number if the units cancel out (m/km → 0.001)
+    fn dimensionless_value(&self) -> Option<f64> {
+        (self.unit.dimension() == Some(Dimension::DIMENSIONLESS))
+            .then(|| self.value * self.unit.to_base())
+    }
+
+    /// Symbol of the unit for messages, "1" for plain numbers
+    fn unit_label(&self) -> String {
+        if self.is_number() {
+            "1".to_string()
+        } else {
+            self.unit.symbol()
+        }
+    }
+
+    /// This quantity's unit times `other`'s raised to `sign`
+    fn combine(&self, other: &Quantity, sign: i8) -> CompoundUnit {
+        other
+            .unit
+            .factors()
+            .iter()
+            .fold(self.unit.clone(), |unit, (u, exp)| {
+                unit.pow(u.clone(), exp * sign)
+            })
+    }
+
+    /// `other`'s value in this quantity's unit, for sums and remainders
+    fn align(&self, other: &Quantity, op: char) -> Result<f64, String> {
+        if self.unit == other.unit {
+            return Ok(other.value);
+        }
+        other.unit.convert(other.value, &self.unit).map_err(|_| {
+            format!(
+                "Cannot apply '{}' to {} and {}",
+                op,
+                self.unit_label(),
+                other.unit_label()
+            )
+        })
+    }
+}
+
+impl std::fmt::Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_number() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.unit.symbol())
+        }
+    }
+}
+
 /// Token types for the expression parser
 #[derive(Clone, Debug, PartialEq)]
 pub enum Token {
     Number(f64),
     Variable(String),
     Function(MathFunction),
+    CustomFunction(String),
     Operator(char),
     LeftParen,
     RightParen,
     Comma,
 }
 
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Variable(name) | Token::CustomFunction(name) => write!(f, "{}", name),
+            Token::Function(func) => write!(f, "{}", func.name()),
+            Token::Operator(op) => write!(f, "'{}'", op),
+            Token::LeftParen => write!(f, "'('"),
+            Token::RightParen => write!(f, "')'"),
+            Token::Comma => write!(f, "','"),
+        }
+    }
+}
+
+/// Location of a token or parse error in the formula text, in chars.
+/// `end` is exclusive; an empty span marks a position between chars.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FormulaSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl FormulaSpan {
+    /// Create a span covering chars `start..end`
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Span from the start of `self` to the end of `other`
+    pub fn to(self, other: FormulaSpan) -> Self {
+        Self::new(self.start, other.end.max(self.end))
+    }
+
+    /// Number of chars covered
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Whether the span marks a position rather than covering any chars
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 /// Expression AST node
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
@@ -200,6 +416,11 @@ pub enum Expression {
         function: MathFunction,
         args: Vec<Expression>,
     },
+    /// Call to a user-defined [`FormulaFunction`]
+    CustomCall {
+        name: String,
+        args: Vec<Expression>,
+    },
 }
 
 impl Expression {
@@ -222,7 +443,7 @@ impl Expression {
             Expression::UnaryOp { operand, .. } => {
                 operand.collect_variables(vars);
             }
-            Expression::FunctionCall { args, .. } => {
+            Expression::FunctionCall { args, .. } | Expression::CustomCall { args, .. } => {
                 for arg in args {
                     arg.collect_variables(vars);
                 }
@@ -231,25 +452,60 @@ impl Expression {
         }
     }
 
+    /// Get the names of all user-defined functions called in the expression
+    pub fn custom_functions(&self) -> HashSet<String> {
+        let mut names = HashSet::new();
+        self.collect_custom_functions(&mut names);
+        names
+    }
+
+    fn collect_custom_functions(&self, names: &mut HashSet<String>) {
+        match self {
+            Expression::CustomCall { name, args } => {
+                names.insert(name.clone());
+                for arg in args {
+                    arg.collect_custom_functions(names);
+                }
+            }
+            Expression::BinaryOp { left, right, .. } => {
+                left.collect_custom_functions(names);
+                right.collect_custom_functions(names);
+            }
+            Expression::UnaryOp { operand, .. } => {
+                operand.collect_custom_functions(names);
+            }
+            Expression::FunctionCall { args, .. } => {
+                for arg in args {
+                    arg.collect_custom_functions(names);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Evaluate the expression with given variable values
     pub fn evaluate(&self, variables: &HashMap<String, f64>) -> Result<f64, String> {
+        self.evaluate_with(variables, &HashMap::new())
+    }
+
+    /// Evaluate the expression with given variable values and user-defined
+    /// functions. Variables take precedence over built-in constants such as
+    /// `pi` and `e`.
+    pub fn evaluate_with(
+        &self,
+        variables: &HashMap<String, f64>,
+        functions: &HashMap<String, FormulaFunction>,
+    ) -> Result<f64, String> {
         match self {
             Expression::Number(n) => Ok(*n),
-            Expression::Variable(name) => {
-                // Check for constants first
-                match name.as_str() {
-                    "pi" | "PI" | "π" => Ok(PI),
-                    "e" | "E" => Ok(E),
-                    "tau" | "TAU" | "τ" => Ok(TAU),
-                    _ => variables
-                        .get(name)
-                        .copied()
-                        .ok_or_else(|| format!("Undefined variable: {}", name)),
-                }
-            }
+            Expression::Variable(name) => variables
+                .get(name)
+                .copied()
+                .or_else(|| builtin_constant(name))
+                .ok_or_else(|| format!("Undefined variable: {}", name)),
             Expression::BinaryOp { op, left, right } => {
-                let l = left.evaluate(variables)?;
-                let r = right.evaluate(variables)?;
+                let l = left.evaluate_with(variables, functions)?;
+                let r = right.evaluate_with(variables, functions)?;
                 Ok(match op {
                     '+' => l + r,
                     '-' => l - r,
@@ -261,7 +517,7 @@ impl Expression {
                 })
             }
             Expression::UnaryOp { op, operand } => {
-                let val = operand.evaluate(variables)?;
+                let val = operand.evaluate_with(variables, functions)?;
                 Ok(match op {
                     '-' => -val,
                     '+' => val,
@@ -276,9 +532,147 @@ impl Expression {
                         args.len()
                     ));
                 }
-                let arg = args[0].evaluate(variables)?;
+                let arg = args[0].evaluate_with(variables, functions)?;
                 Ok(function.evaluate(arg))
             }
+            Expression::CustomCall { name, args } => {
+                let function = functions
+                    .get(name)
+                    .ok_or_else(|| format!("Unknown function: {}", name))?;
+                let values = args
+                    .iter()
+                    .map(|arg| arg.evaluate_with(variables, functions))
+                    .collect::<Result<Vec<_>, _>>()?;
+                function
+                    .call(&values)
+                    .map_err(|message| format!("{}: {}", name, message))
+            }
+        }
+    }
+}
+
+impl Expression {
+    /// Evaluate with units. Names resolve to `variables` first, then to the
+    /// symbols of `units`, then to built-in constants. Products and
+    /// quotients combine units (`5 m / 2 s` is `2.5 m/s`), sums convert the
+    /// right operand into the left one's unit, and functions need
+    /// dimensionless arguments.
+    pub fn evaluate_quantity(
+        &self,
+        variables: &HashMap<String, Quantity>,
+        units: &[Unit],
+        functions: &HashMap<String, FormulaFunction>,
+    ) -> Result<Quantity, String> {
+        let plain_args = |args: &[Expression]| {
+            args.iter()
+                .map(|arg| {
+                    let q = arg.evaluate_quantity(variables, units, functions)?;
+                    q.dimensionless_value().ok_or_else(|| {
+                        format!(
+                            "Function arguments must be unitless, got {}",
+                            q.unit_label()
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()
+        };
+        match self {
+            Expression::Number(n) => Ok(Quantity::number(*n)),
+            Expression::Variable(name) => variables
+                .get(name)
+                .cloned()
+                .or_else(|| {
+                    units
+                        .iter()
+                        .find(|u| &u.symbol == name)
+                        .map(|u| Quantity::new(1.0, u.clone()))
+                })
+                .or_else(|| builtin_constant(name).map(Quantity::number))
+                .ok_or_else(|| format!("Undefined variable: {}", name)),
+            Expression::BinaryOp { op, left, right } => {
+                let l = left.evaluate_quantity(variables, units, functions)?;
+                let r = right.evaluate_quantity(variables, units, functions)?;
+                match op {
+                    '*' => Ok(Quantity {
+                        value: l.value * r.value,
+                        unit: l.combine(&r, 1),
+                    }),
+                    '/' => Ok(Quantity {
+                        value: l.value / r.value,
+                        unit: l.combine(&r, -1),
+                    }),
+                    '+' | '-' | '%' => {
+                        let rv = l.align(&r, *op)?;
+                        let value = match op {
+                            '+' => l.value + rv,
+                            '-' => l.value - rv,
+                            _ => l.value % rv,
+                        };
+                        Ok(Quantity {
+                            value,
+                            unit: l.unit,
+                        })
+                    }
+                    '^' => {
+                        let exponent = r.dimensionless_value().ok_or_else(|| {
+                            format!("Exponents must be unitless, got {}", r.unit_label())
+                        })?;
+                        if l.is_number() {
+                            return Ok(Quantity::number(l.value.powf(exponent)));
+                        }
+                        let n = Some(exponent)
+                            .filter(|e| e.fract() == 0.0 && e.abs() <= f64::from(i8::MAX))
+                            .map(|e| e as i8)
+                            .ok_or_else(|| {
+                                format!("{} can only be raised to a whole power", l.unit_label())
+                            })?;
+                        let unit = l
+                            .unit
+                            .factors()
+                            .iter()
+                            .try_fold(CompoundUnit::default(), |unit, (u, exp)| {
+                                exp.checked_mul(n).map(|e| unit.pow(u.clone(), e))
+                            })
+                            .ok_or_else(|| "Unit exponent out of range".to_string())?;
+                        Ok(Quantity {
+                            value: l.value.powi(i32::from(n)),
+                            unit,
+                        })
+                    }
+                    _ => Err(format!("Unknown operator: {}", op)),
+                }
+            }
+            Expression::UnaryOp { op, operand } => {
+                let q = operand.evaluate_quantity(variables, units, functions)?;
+                match op {
+                    '-' => Ok(Quantity {
+                        value: -q.value,
+                        unit: q.unit,
+                    }),
+                    '+' => Ok(q),
+                    _ => Err(format!("Unknown unary operator: {}", op)),
+                }
+            }
+            Expression::FunctionCall { function, args } => {
+                let args = plain_args(args)?;
+                if args.len() != 1 {
+                    return Err(format!(
+                        "Function {} expects 1 argument, got {}",
+                        function.name(),
+                        args.len()
+                    ));
+                }
+                Ok(Quantity::number(function.evaluate(args[0])))
+            }
+            Expression::CustomCall { name, args } => {
+                let function = functions
+                    .get(name)
+                    .ok_or_else(|| format!("Unknown function: {}", name))?;
+                function
+                    .call(&plain_args(args)?)
+                    .map(Quantity::number)
+                    .map_err(|message| format!("{}: {}", name, message))
+            }
         }
     }
 }
@@ -304,6 +698,10 @@ impl std::fmt::Display for Expression {
                 let args_str: Vec<String> = args.iter().map(|a| a.to_string()).collect();
                 write!(f, "{}({})", function.name(), args_str.join(", "))
             }
+            Expression::CustomCall { name, args } => {
+                let args_str: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                write!(f, "{}({})", name, args_str.join(", "))
+            }
         }
     }
 }
@@ -313,12 +711,25 @@ impl std::fmt::Display for Expression {
 pub enum FormulaParseError {
     UnexpectedCharacter(char),
     UnexpectedToken(String),
+    /// A `)` without a matching `(`
     UnmatchedParenthesis,
+    /// A `(` that is never closed
+    UnclosedParenthesis,
     EmptyExpression,
     InvalidNumber(String),
     UnknownFunction(String),
     MissingOperand,
+    /// An operator at the end of the input with nothing after it
+    TrailingOperator(char),
+    /// A function name not followed by `(`
+    MissingArguments(String),
     TrailingInput(String),
+    /// A function called with the wrong number of arguments
+    ArityMismatch {
+        name: String,
+        expected: usize,
+        got: usize,
+    },
 }
 
 impl std::fmt::Display for FormulaParseError {
@@ -326,101 +737,175 @@ impl std::fmt::Display for FormulaParseError {
         match self {
             Self::UnexpectedCharacter(c) => write!(f, "Unexpected character: '{}'", c),
             Self::UnexpectedToken(t) => write!(f, "Unexpected token: {}", t),
-            Self::UnmatchedParenthesis => write!(f, "Unmatched parenthesis"),
+            Self::UnmatchedParenthesis => write!(f, "Unmatched ')'"),
+            Self::UnclosedParenthesis => write!(f, "Unclosed '('"),
             Self::EmptyExpression => write!(f, "Empty expression"),
             Self::InvalidNumber(s) => write!(f, "Invalid number: {}", s),
             Self::UnknownFunction(s) => write!(f, "Unknown function: {}", s),
             Self::MissingOperand => write!(f, "Missing operand"),
+            Self::TrailingOperator(op) => write!(f, "Expected a value after '{}'", op),
+            Self::MissingArguments(name) => write!(f, "Expected '(' after {}", name),
             Self::TrailingInput(s) => write!(f, "Trailing input: {}", s),
+            Self::ArityMismatch {
+                name,
+                expected,
+                got,
+            } => write!(f, "{}: expected {} args, got {}", name, expected, got),
         }
     }
 }
 
-/// Tokenizer for mathematical expressions
-fn tokenize(input: &str) -> Result<Vec<Token>, FormulaParseError> {
+/// Tokenizer for mathematical expressions. Names accepted by `is_custom` are
+/// user-defined functions, ahead of built-ins with the same name. Returns the
+/// span of each token, or of the offending input on error.
+/// With `split_digits`, a name ending in digits that isn't a function
+/// (`e2`) becomes the name followed by a number.
+fn tokenize_with(
+    input: &str,
+    is_custom: impl Fn(&str) -> bool,
+    split_digits: bool,
+) -> Result<(Vec<Token>, Vec<FormulaSpan>), (FormulaParseError, FormulaSpan)> {
+    let chars: Vec<char> = input.chars().collect();
     let mut tokens = Vec::new();
-    let mut chars = input.chars().peekable();
+    let mut spans = Vec::new();
+    let mut i = 0;
 
-    while let Some(&ch) = chars.peek() {
-        match ch {
+    while let Some(&ch) = chars.get(i) {
+        let start = i;
+        let token = match ch {
             ' ' | '\t' | '\n' => {
-                chars.next();
+                i += 1;
+                continue;
             }
             '0'..='9' | '.' => {
                 let mut num_str = String::new();
-                while let Some(&c) = chars.peek() {
-                    if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' {
+                while let Some(&c) = chars.get(i) {
+                    if c.is_ascii_digit() || c == '.' {
                         num_str.push(c);
-                        chars.next();
+                        i += 1;
+                    } else if (c == 'e' || c == 'E') && starts_exponent(&chars[i + 1..]) {
+                        // Scientific notation; a bare `e` is left for `2e` = 2·e
+                        num_str.push(c);
+                        i += 1;
                         // Handle negative exponent
-                        if (c == 'e' || c == 'E') && chars.peek() == Some(&'-') {
+                        if chars.get(i) == Some(&'-') {
                             num_str.push('-');
-                            chars.next();
+                            i += 1;
                         }
                     } else {
                         break;
                     }
                 }
-                let num: f64 = num_str
-                    .parse()
-                    .map_err(|_| FormulaParseError::InvalidNumber(num_str))?;
-                tokens.push(Token::Number(num));
+                match num_str.parse() {
+                    Ok(num) => Token::Number(num),
+                    Err(_) => {
+                        return Err((
+                            FormulaParseError::InvalidNumber(num_str),
+                            FormulaSpan::new(start, i),
+                        ))
+                    }
+                }
             }
             'a'..='z' | 'A'..='Z' | '_' | 'α'..='ω' | 'Α'..='Ω' => {
                 let mut name = String::new();
-                while let Some(&c) = chars.peek() {
+                while let Some(&c) = chars.get(i) {
                     if c.is_alphanumeric()
                         || c == '_'
                         || ('α'..='ω').contains(&c)
                         || ('Α'..='Ω').contains(&c)
                     {
                         name.push(c);
-                        chars.next();
+                        i += 1;
                     } else {
                         break;
                     }
                 }
+                let digits = name.chars().rev().take_while(char::is_ascii_digit).count();
+                let is_function = is_custom(&name) || MathFunction::try_from_name(&name).is_some();
+                if split_digits && digits > 0 && digits < name.chars().count() && !is_function {
+                    let split = i - digits;
+                    let (prefix, number) = name.split_at(name.len() - digits);
+                    tokens.push(Token::Variable(prefix.to_string()));
+                    spans.push(FormulaSpan::new(start, split));
+                    tokens.push(Token::Number(number.parse().unwrap_or_default()));
+                    spans.push(FormulaSpan::new(split, i));
+                    continue;
+                }
                 // Check if it's a function
-                if let Some(func) = MathFunction::try_from_name(&name) {
-                    tokens.push(Token::Function(func));
+                if is_custom(&name) {
+                    Token::CustomFunction(name)
+                } else if let Some(func) = MathFunction::try_from_name(&name) {
+                    Token::Function(func)
                 } else {
-                    tokens.push(Token::Variable(name));
+                    Token::Variable(name)
                 }
             }
             '+' | '-' | '*' | '/' | '^' | '%' => {
-                tokens.push(Token::Operator(ch));
-                chars.next();
+                i += 1;
+                Token::Operator(ch)
             }
             '(' => {
-                tokens.push(Token::LeftParen);
-                chars.next();
+                i += 1;
+                Token::LeftParen
             }
             ')' => {
-                tokens.push(Token::RightParen);
-                chars.next();
+                i += 1;
+                Token::RightParen
             }
             ',' => {
-                tokens.push(Token::Comma);
-                chars.next();
+                i += 1;
+                Token::Comma
             }
             _ => {
-                return Err(FormulaParseError::UnexpectedCharacter(ch));
+                return Err((
+                    FormulaParseError::UnexpectedCharacter(ch),
+                    FormulaSpan::new(start, start + 1),
+                ));
             }
-        }
+        };
+        tokens.push(token);
+        spans.push(FormulaSpan::new(start, i));
     }
 
-    Ok(tokens)
+    Ok((tokens, spans))
+}
+
+/// Whether `rest` (after an `e`) continues a number in scientific notation
+fn starts_exponent(rest: &[char]) -> bool {
+    match rest {
+        [d, ..] if d.is_ascii_digit() => true,
+        ['-', d, ..] => d.is_ascii_digit(),
+        _ => false,
+    }
 }
 
 /// Parser for mathematical expressions
 pub struct Parser {
     tokens: Vec<Token>,
     pos: usize,
+    /// Source span of each token (empty when built from bare tokens)
+    spans: Vec<FormulaSpan>,
+    /// Length of the source in chars
+    end: usize,
+    /// Argument counts of user-defined functions
+    arities: HashMap<String, usize>,
+    /// Whether adjacent operands multiply
+    implicit_multiplication: bool,
+    /// Where the last parse error occurred
+    error_span: Option<FormulaSpan>,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, pos: 0 }
+        Self {
+            tokens,
+            pos: 0,
+            spans: Vec::new(),
+            end: 0,
+            arities: HashMap::new(),
+            implicit_multiplication: false,
+            error_span: None,
+        }
     }
 
     fn peek(&self) -> Option<&Token> {
@@ -433,31 +918,60 @@ impl Parser {
         token
     }
 
-    fn expect(&mut self, expected: &Token) -> Result<(), FormulaParseError> {
-        if self.peek() == Some(expected) {
-            self.advance();
-            Ok(())
-        } else {
-            Err(FormulaParseError::UnexpectedToken(format!(
-                "Expected {:?}, got {:?}",
-                expected,
-                self.peek()
-            )))
+    /// Source span of the token at `pos`, or the end of input past the last token
+    fn span_at(&self, pos: usize) -> FormulaSpan {
+        self.spans
+            .get(pos)
+            .copied()
+            .unwrap_or(FormulaSpan::new(self.end, self.end))
+    }
+
+    /// Record where `error` happened and return it
+    fn fail<T>(
+        &mut self,
+        error: FormulaParseError,
+        span: FormulaSpan,
+    ) -> Result<T, FormulaParseError> {
+        self.error_span = Some(span);
+        Err(error)
+    }
+
+    /// Where the last error from [`Parser::parse`] occurred
+    pub fn error_span(&self) -> Option<FormulaSpan> {
+        self.error_span
+    }
+
+    /// Consume the `)` matching the `(` at `open`
+    fn expect_closing(&mut self, open: usize) -> Result<(), FormulaParseError> {
+        match self.peek() {
+            Some(Token::RightParen) => {
+                self.advance();
+                Ok(())
+            }
+            Some(token) => {
+                let error = FormulaParseError::UnexpectedToken(token.to_string());
+                self.fail(error, self.span_at(self.pos))
+            }
+            None => self.fail(FormulaParseError::UnclosedParenthesis, self.span_at(open)),
         }
     }
 
     pub fn parse(&mut self) -> Result<Expression, FormulaParseError> {
         if self.tokens.is_empty() {
-            return Err(FormulaParseError::EmptyExpression);
+            return self.fail(FormulaParseError::EmptyExpression, FormulaSpan::new(0, 0));
         }
         let expr = self.parse_expression()?;
-        if self.pos < self.tokens.len() {
-            return Err(FormulaParseError::TrailingInput(format!(
-                "{:?}",
-                &self.tokens[self.pos..]
-            )));
+        match self.peek() {
+            None => Ok(expr),
+            Some(Token::RightParen) => self.fail(
+                FormulaParseError::UnmatchedParenthesis,
+                self.span_at(self.pos),
+            ),
+            Some(token) => {
+                let error = FormulaParseError::TrailingInput(token.to_string());
+                self.fail(error, self.span_at(self.pos))
+            }
         }
-        Ok(expr)
     }
 
     fn parse_expression(&mut self) -> Result<Expression, FormulaParseError> {
@@ -486,13 +1000,13 @@ impl Parser {
     }
 
     fn parse_multiplicative(&mut self) -> Result<Expression, FormulaParseError> {
-        let mut left = self.parse_power()?;
+        let mut left = self.parse_implicit()?;
 
         while let Some(Token::Operator(op)) = self.peek() {
             if *op == '*' || *op == '/' || *op == '%' {
                 let op = *op;
                 self.advance();
-                let right = self.parse_power()?;
+                let right = self.parse_implicit()?;
                 left = Expression::BinaryOp {
                     op,
                     left: Box::new(left),
@@ -506,6 +1020,34 @@ impl Parser {
         Ok(left)
     }
 
+    /// Implicit products such as `2x` or `3sin(x)`, binding tighter than
+    /// `*` and `/` so `1/2x` is `1/(2*x)`
+    fn parse_implicit(&mut self) -> Result<Expression, FormulaParseError> {
+        let mut left = self.parse_power()?;
+
+        while self.implicit_multiplication
+            && matches!(
+                self.peek(),
+                Some(
+                    Token::Number(_)
+                        | Token::Variable(_)
+                        | Token::Function(_)
+                        | Token::CustomFunction(_)
+                        | Token::LeftParen
+                )
+            )
+        {
+            let right = self.parse_power()?;
+            left = Expression::BinaryOp {
+                op: '*',
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
     fn parse_power(&mut self) -> Result<Expression, FormulaParseError> {
         let base = self.parse_unary()?;
 
@@ -538,6 +1080,7 @@ impl Parser {
     }
 
     fn parse_primary(&mut self) -> Result<Expression, FormulaParseError> {
+        let start = self.pos;
         match self.peek().cloned() {
             Some(Token::Number(n)) => {
                 self.advance();
@@ -545,35 +1088,133 @@ impl Parser {
             }
             Some(Token::Variable(name)) => {
                 self.advance();
+                if self.peek() == Some(&Token::LeftParen) && !self.implicit_multiplication {
+                    return self.fail(
+                        FormulaParseError::UnknownFunction(name),
+                        self.span_at(start),
+                    );
+                }
                 Ok(Expression::Variable(name))
             }
             Some(Token::Function(func)) => {
                 self.advance();
-                self.expect(&Token::LeftParen)?;
-                let arg = self.parse_expression()?;
-                self.expect(&Token::RightParen)?;
+                let args = self.parse_arguments()?;
+                if args.len() != 1 {
+                    let error = FormulaParseError::ArityMismatch {
+                        name: func.name().to_string(),
+                        expected: 1,
+                        got: args.len(),
+                    };
+                    return self.fail(error, self.span_at(start).to(self.span_at(self.pos - 1)));
+                }
                 Ok(Expression::FunctionCall {
                     function: func,
-                    args: vec![arg],
+                    args,
                 })
             }
+            Some(Token::CustomFunction(name)) => {
+                self.advance();
+                let args = self.parse_arguments()?;
+                if let Some(&expected) = self.arities.get(&name) {
+                    if args.len() != expected {
+                        let error = FormulaParseError::ArityMismatch {
+                            name,
+                            expected,
+                            got: args.len(),
+                        };
+                        return self
+                            .fail(error, self.span_at(start).to(self.span_at(self.pos - 1)));
+                    }
+                }
+                Ok(Expression::CustomCall { name, args })
+            }
             Some(Token::LeftParen) => {
                 self.advance();
                 let expr = self.parse_expression()?;
-                self.expect(&Token::RightParen)?;
+                self.expect_closing(start)?;
                 Ok(expr)
             }
-            Some(token) => Err(FormulaParseError::UnexpectedToken(format!("{:?}", token))),
-            None => Err(FormulaParseError::MissingOperand),
+            Some(token) => {
+                let error = FormulaParseError::UnexpectedToken(token.to_string());
+                self.fail(error, self.span_at(start))
+            }
+            // Point at whatever was left waiting for an operand
+            None => match start.checked_sub(1).and_then(|prev| self.tokens.get(prev)) {
+                Some(&Token::Operator(op)) => self.fail(
+                    FormulaParseError::TrailingOperator(op),
+                    self.span_at(start - 1),
+                ),
+                Some(Token::LeftParen) => self.fail(
+                    FormulaParseError::UnclosedParenthesis,
+                    self.span_at(start - 1),
+                ),
+                _ => self.fail(FormulaParseError::MissingOperand, self.span_at(start)),
+            },
+        }
+    }
+
+    /// Parse a parenthesized, comma-separated argument list
+    fn parse_arguments(&mut self) -> Result<Vec<Expression>, FormulaParseError> {
+        let open = self.pos;
+        match self.peek() {
+            Some(Token::LeftParen) => {
+                self.advance();
+            }
+            _ => {
+                // A function name must be followed by its arguments
+                let error = FormulaParseError::MissingArguments(self.tokens[open - 1].to_string());
+                return self.fail(error, self.span_at(open - 1));
+            }
         }
+        let mut args = vec![self.parse_expression()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.advance();
+            args.push(self.parse_expression()?);
+        }
+        self.expect_closing(open)?;
+        Ok(args)
     }
 }
 
 /// Parse an expression string
 pub fn parse_expression(input: &str) -> Result<Expression, FormulaParseError> {
-    let tokens = tokenize(input)?;
-    let mut parser = Parser::new(tokens);
-    parser.parse()
+    parse_expression_with(input, &HashMap::new())
+}
+
+/// Parse an expression string that may call user-defined functions.
+/// A user function named like a built-in (e.g. `sin`) replaces it.
+pub fn parse_expression_with(
+    input: &str,
+    functions: &HashMap<String, FormulaFunction>,
+) -> Result<Expression, FormulaParseError> {
+    parse_expression_located(input, functions, &FormulaOptions::default())
+        .map_err(|(error, _)| error)
+}
+
+/// Like [`parse_expression_with`], but also reports where in `input` the
+/// error occurred so it can be highlighted
+pub fn parse_expression_located(
+    input: &str,
+    functions: &HashMap<String, FormulaFunction>,
+    options: &FormulaOptions,
+) -> Result<Expression, (FormulaParseError, FormulaSpan)> {
+    let split_digits =
+        options.implicit_multiplication && options.digit_suffix == DigitSuffix::Multiply;
+    let (tokens, spans) = tokenize_with(input, |name| functions.contains_key(name), split_digits)?;
+    let mut parser = Parser {
+        spans,
+        end: input.chars().count(),
+        arities: functions
+            .iter()
+            .map(|(name, function)| (name.clone(), function.arity()))
+            .collect(),
+        implicit_multiplication: options.implicit_multiplication,
+        ..Parser::new(tokens)
+    };
+    parser.parse().map_err(|error| {
+        let span = parser.error_span().unwrap_or_default();
+        (error, span)
+    })
 }
 
 /// Result of parsing a formula
@@ -583,10 +1224,94 @@ pub struct FormulaResult {
     pub expression: Option<Expression>,
     /// Parse error (if any)
     pub error: Option<FormulaParseError>,
+    /// Where in the formula the parse error occurred
+    pub error_span: Option<FormulaSpan>,
     /// Variables found in the expression
     pub variables: HashSet<String>,
-    /// Evaluated result (if no free variables)
+    /// Evaluated result (if no free variables), in `quantity`'s unit when
+    /// evaluating with units
     pub value: Option<f64>,
+    /// Evaluated result with its unit, when [`FormulaOptions::units`] is set
+    pub quantity: Option<Quantity>,
+    /// User definitions that shadow a built-in function or constant
+    pub warnings: Vec<String>,
+}
+
+/// Parse `input` and evaluate it when every variable is defined in
+/// `variables`, is a built-in constant, or is one of `options.units`
+pub fn analyze_formula(
+    input: &str,
+    variables: &HashMap<String, f64>,
+    functions: &HashMap<String, FormulaFunction>,
+    options: &FormulaOptions,
+) -> FormulaResult {
+    let expr = match parse_expression_located(input, functions, options) {
+        Ok(expr) => expr,
+        Err((err, span)) => {
+            return FormulaResult {
+                expression: None,
+                error: Some(err),
+                error_span: Some(span),
+                variables: HashSet::new(),
+                value: None,
+                quantity: None,
+                warnings: Vec::new(),
+            }
+        }
+    };
+
+    // Unit symbols are not free variables
+    let is_unit = |name: &str| {
+        !variables.contains_key(name) && options.units.iter().any(|u| u.symbol == name)
+    };
+    let vars: HashSet<String> = expr
+        .variables()
+        .into_iter()
+        .filter(|name| !is_unit(name))
+        .collect();
+    let mut warnings: Vec<String> = expr
+        .custom_functions()
+        .into_iter()
+        .filter(|name| MathFunction::try_from_name(name).is_some())
+        .map(|name| format!("{} overrides the built-in function", name))
+        .chain(
+            vars.iter()
+                .filter(|name| variables.contains_key(*name) && builtin_constant(name).is_some())
+                .map(|name| format!("{} overrides the built-in constant", name)),
+        )
+        .collect();
+    warnings.sort();
+
+    let defined = vars
+        .iter()
+        .all(|v| variables.contains_key(v) || builtin_constant(v).is_some());
+    let quantity = if defined && !options.units.is_empty() {
+        let quantities = variables
+            .iter()
+            .map(|(name, value)| (name.clone(), Quantity::number(*value)))
+            .collect();
+        expr.evaluate_quantity(&quantities, &options.units, functions)
+            .ok()
+    } else {
+        None
+    };
+    let value = match &quantity {
+        Some(q) => Some(q.value),
+        None if defined && options.units.is_empty() => {
+            expr.evaluate_with(variables, functions).ok()
+        }
+        None => None,
+    };
+
+    FormulaResult {
+        expression: Some(expr),
+        error: None,
+        error_span: None,
+        variables: vars,
+        value,
+        quantity,
+        warnings,
+    }
 }
 
 /// Formula input component
@@ -600,10 +1325,26 @@ pub fn FormulaInput(
     #[prop(optional, into)]
     on_change: Option<Callback<FormulaResult>>,
 
-    /// Variables to use for evaluation
+    /// Variables and named constants to use for evaluation
     #[prop(optional, into)]
     variables: Option<Signal<HashMap<String, f64>>>,
 
+    /// User-defined functions, consulted before the built-ins
+    #[prop(optional, into)]
+    functions: Option<Signal<HashMap<String, FormulaFunction>>>,
+
+    /// Read `2x`, `3sin(x)` and `2(3+4)` as products (see [`FormulaOptions`])
+    #[prop(optional, default = false)]
+    implicit_multiplication: bool,
+
+    /// How names ending in digits (`e2`) are read with implicit multiplication
+    #[prop(optional)]
+    digit_suffix: DigitSuffix,
+
+    /// Units that may appear in the formula; the result keeps its unit
+    #[prop(optional, into)]
+    units: Option<Vec<Unit>>,
+
     /// Allowed variables (empty = any) - reserved for future validation
     #[prop(optional, into)]
     _allowed_variables: Option<Vec<String>>,
@@ -642,6 +1383,12 @@ pub fn FormulaInput(
     let internal_value = value.unwrap_or_else(|| RwSignal::new(String::new()));
     let parse_result: RwSignal<Option<FormulaResult>> = RwSignal::new(None);
 
+    let options = StoredValue::new(FormulaOptions {
+        implicit_multiplication,
+        digit_suffix,
+        units: units.unwrap_or_default(),
+    });
+
     // Parse on input change
     let parse_formula = move |input: &str| {
         if input.is_empty() {
@@ -649,37 +1396,10 @@ pub fn FormulaInput(
             return;
         }
 
-        let result = parse_expression(input);
         let vars_map = variables.map(|v| v.get()).unwrap_or_default();
-
-        let formula_result = match result {
-            Ok(expr) => {
-                let vars = expr.variables();
-                let value = if vars.iter().all(|v| {
-                    vars_map.contains_key(v)
-                        || matches!(
-                            v.as_str(),
-                            "pi" | "PI" | "π" | "e" | "E" | "tau" | "TAU" | "τ"
-                        )
-                }) {
-                    expr.evaluate(&vars_map).ok()
-                } else {
-                    None
-                };
-                FormulaResult {
-                    expression: Some(expr),
-                    error: None,
-                    variables: vars,
-                    value,
-                }
-            }
-            Err(err) => FormulaResult {
-                expression: None,
-                error: Some(err),
-                variables: HashSet::new(),
-                value: None,
-            },
-        };
+        let functions_map = functions.map(|f| f.get()).unwrap_or_default();
+        let formula_result = options
+            .with_value(|options| analyze_formula(input, &vars_map, &functions_map, options));
 
         if let Some(cb) = on_change {
             cb.run(formula_result.clone());
@@ -783,6 +1503,43 @@ pub fn FormulaInput(
             .build()
     };
 
+    // Lines up with the input text: same font and padding, plus the border
+    let error_marker_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("font-family", "monospace")
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("white-space", "pre")
+            .add("overflow", "hidden")
+            .add("line-height", "1")
+            .add(
+                "padding",
+                format!("0 calc({} + 1px)", &*theme_val.spacing.sm),
+            )
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("red", 6)
+                    .unwrap_or_else(|| "#fa5252".to_string()),
+            )
+            .build()
+    };
+
+    let warning_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("yellow", 8)
+                    .unwrap_or_else(|| "#f08c00".to_string()),
+            )
+            .build()
+    };
+
     let description_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -848,6 +1605,7 @@ pub fn FormulaInput(
                 placeholder=placeholder.unwrap_or_else(|| "Enter formula (e.g., sin(x) + 2*y)".to_string())
                 prop:value=move || internal_value.get()
                 disabled=disabled
+                aria-invalid=move || parse_result.with(|r| r.as_ref().is_some_and(|r| r.error.is_some())).to_string()
                 on:input=move |ev| {
                     let val = event_target_value(&ev);
                     internal_value.set(val.clone());
@@ -855,6 +1613,22 @@ pub fn FormulaInput(
                 }
             />
 
+            // Caret under the offending part of the formula
+            {move || {
+                parse_result
+                    .get()
+                    .and_then(|r| r.error_span)
+                    .map(|span| view! {
+                        <div style=error_marker_styles aria-hidden="true">
+                            {format!(
+                                "{}^{}",
+                                " ".repeat(span.start),
+                                "~".repeat(span.len().saturating_sub(1))
+                            )}
+                        </div>
+                    })
+            }}
+
             {move || {
                 let result = parse_result.get();
                 match result {
@@ -869,6 +1643,7 @@ pub fn FormulaInput(
                         view! {
                             <div style=result_styles>
                                 {"= "}{format!("{:.10}", r.value.unwrap()).trim_end_matches('0').trim_end_matches('.').to_string()}
+                                {r.quantity.filter(|q| !q.is_number()).map(|q| format!(" {}", q.unit.symbol()))}
                             </div>
                         }.into_any()
                     }
@@ -883,6 +1658,16 @@ pub fn FormulaInput(
                 }
             }}
 
+            {move || {
+                let warnings = parse_result
+                    .get()
+                    .map(|r| r.warnings)
+                    .unwrap_or_default();
+                warnings.into_iter().map(|w| view! {
+                    <div style=warning_styles>{w}</div>
+                }).collect_view()
+            }}
+
             {move || {
                 let result = parse_result.get();
                 if show_variables {
@@ -914,6 +1699,12 @@ pub fn FormulaInput(
 mod tests {
     use super::*;
 
+    fn tokenize(input: &str) -> Result<Vec<Token>, FormulaParseError> {
+        tokenize_with(input, |_| false, false)
+            .map(|(tokens, _)| tokens)
+            .map_err(|(error, _)| error)
+    }
+
     #[test]
     fn test_tokenize_simple() {
         let tokens = tokenize("1 + 2").unwrap();
@@ -1043,6 +1834,267 @@ mod tests {
         assert!((result - 0.001).abs() < 1e-15);
     }
 
+    fn custom_functions() -> HashMap<String, FormulaFunction> {
+        let mut functions = HashMap::new();
+        functions.insert(
+            "hypot".to_string(),
+            FormulaFunction::new(2, |args| Ok(args[0].hypot(args[1]))),
+        );
+        functions.insert(
+            "gauss".to_string(),
+            FormulaFunction::new(3, |args| {
+                let (x, mu, sigma) = (args[0], args[1], args[2]);
+                Ok((-((x - mu) / sigma).powi(2) / 2.0).exp())
+            }),
+        );
+        functions
+    }
+
+    #[test]
+    fn test_evaluate_custom_function() {
+        let functions = custom_functions();
+        let expr = parse_expression_with("2 * hypot(3, x)", &functions).unwrap();
+        assert_eq!(
+            expr.custom_functions(),
+            HashSet::from(["hypot".to_string()])
+        );
+
+        let mut vars = HashMap::new();
+        vars.insert("x".to_string(), 4.0);
+        assert_eq!(expr.evaluate_with(&vars, &functions), Ok(10.0));
+
+        let result = analyze_formula(
+            "gauss(1, 1, 2) + hypot(x, 0)",
+            &vars,
+            &functions,
+            &FormulaOptions::default(),
+        );
+        assert_eq!(result.value, Some(5.0));
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_custom_function_arity_error() {
+        let functions = custom_functions();
+        let err = parse_expression_with("gauss(x, 0)", &functions).unwrap_err();
+        assert_eq!(
+            err,
+            FormulaParseError::ArityMismatch {
+                name: "gauss".to_string(),
+                expected: 3,
+                got: 2
+            }
+        );
+        assert_eq!(err.to_string(), "gauss: expected 3 args, got 2");
+
+        let result = analyze_formula(
+            "1 + hypot(1)",
+            &HashMap::new(),
+            &functions,
+            &FormulaOptions::default(),
+        );
+        assert!(result.expression.is_none());
+        assert_eq!(
+            result.error.map(|e| e.to_string()),
+            Some("hypot: expected 2 args, got 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_user_definitions_shadow_builtins() {
+        let mut functions = HashMap::new();
+        functions.insert("sin".to_string(), FormulaFunction::new(1, |_| Ok(42.0)));
+        let mut vars = HashMap::new();
+        vars.insert("e".to_string(), 2.0);
+
+        let result = analyze_formula("sin(0) + e", &vars, &functions, &FormulaOptions::default());
+        assert_eq!(result.value, Some(44.0));
+        assert_eq!(
+            result.warnings,
+            vec![
+                "e overrides the built-in constant".to_string(),
+                "sin overrides the built-in function".to_string(),
+            ]
+        );
+
+        // Built-ins are unaffected without user definitions
+        let result = analyze_formula(
+            "sin(0) + e",
+            &HashMap::new(),
+            &HashMap::new(),
+            &FormulaOptions::default(),
+        );
+        assert_eq!(result.value, Some(E));
+        assert!(result.warnings.is_empty());
+    }
+
+    fn error_at(input: &str) -> (String, FormulaSpan) {
+        let (error, span) =
+            parse_expression_located(input, &HashMap::new(), &FormulaOptions::default())
+                .unwrap_err();
+        (error.to_string(), span)
+    }
+
+    #[test]
+    fn test_parse_error_positions() {
+        // Trailing operator points at the operator, not the end of input
+        assert_eq!(
+            error_at("sin(x + "),
+            (
+                "Expected a value after '+'".to_string(),
+                FormulaSpan::new(6, 7)
+            )
+        );
+        assert_eq!(
+            error_at("sin(x"),
+            ("Unclosed '('".to_string(), FormulaSpan::new(3, 4))
+        );
+        assert_eq!(
+            error_at("(1 + 2))"),
+            ("Unmatched ')'".to_string(), FormulaSpan::new(7, 8))
+        );
+        assert_eq!(
+            error_at("2 * foo(x)"),
+            ("Unknown function: foo".to_string(), FormulaSpan::new(4, 7))
+        );
+        assert_eq!(
+            error_at("1 + * 2"),
+            ("Unexpected token: '*'".to_string(), FormulaSpan::new(4, 5))
+        );
+        assert_eq!(
+            error_at("x $ 2"),
+            (
+                "Unexpected character: '$'".to_string(),
+                FormulaSpan::new(2, 3)
+            )
+        );
+        assert_eq!(
+            error_at("2 3"),
+            ("Trailing input: 3".to_string(), FormulaSpan::new(2, 3))
+        );
+        assert_eq!(
+            error_at("sqrt(4, 9)"),
+            (
+                "sqrt: expected 1 args, got 2".to_string(),
+                FormulaSpan::new(0, 10)
+            )
+        );
+        // Offsets count chars, not bytes
+        assert_eq!(
+            error_at("π * (θ"),
+            ("Unclosed '('".to_string(), FormulaSpan::new(4, 5))
+        );
+    }
+
+    #[test]
+    fn test_analyze_formula_error_span() {
+        let result = analyze_formula(
+            "sin(x + ",
+            &HashMap::new(),
+            &HashMap::new(),
+            &FormulaOptions::default(),
+        );
+        assert_eq!(result.error, Some(FormulaParseError::TrailingOperator('+')));
+        assert_eq!(result.error_span, Some(FormulaSpan::new(6, 7)));
+
+        let result = analyze_formula(
+            "sin(x)",
+            &HashMap::new(),
+            &HashMap::new(),
+            &FormulaOptions::default(),
+        );
+        assert_eq!(result.error_span, None);
+    }
+
+    fn eval_implicit(input: &str, digit_suffix: DigitSuffix) -> Result<f64, FormulaParseError> {
+        let options = FormulaOptions {
+            implicit_multiplication: true,
+            digit_suffix,
+            ..Default::default()
+        };
+        let mut vars = HashMap::new();
+        vars.insert("x".to_string(), 3.0);
+        vars.insert("e2".to_string(), 100.0);
+        let expr =
+            parse_expression_located(input, &HashMap::new(), &options).map_err(|(e, _)| e)?;
+        Ok(expr.evaluate(&vars).unwrap())
+    }
+
+    #[test]
+    fn test_implicit_multiplication() {
+        let eval = |input| eval_implicit(input, DigitSuffix::Identifier);
+        assert_eq!(eval("2(3+4)"), Ok(14.0));
+        assert_eq!(eval("3sin(0)"), Ok(0.0));
+        assert_eq!(eval("2x"), Ok(6.0));
+        assert_eq!(eval("(1+1)(2+3)"), Ok(10.0));
+        assert_eq!(eval("x(x+1)"), Ok(12.0));
+        // Binds looser than ^ and tighter than / and *
+        assert_eq!(eval("2x^2"), Ok(18.0));
+        assert_eq!(eval("12/2x"), Ok(2.0));
+        assert_eq!(eval("2e"), Ok(2.0 * E));
+        assert_eq!(eval("2e3"), Ok(2000.0));
+
+        // Off by default
+        assert!(parse_expression("2(3+4)").is_err());
+        assert!(parse_expression("2x").is_err());
+    }
+
+    #[test]
+    fn test_digit_suffix_disambiguation() {
+        assert_eq!(eval_implicit("e2", DigitSuffix::Identifier), Ok(100.0));
+        assert_eq!(eval_implicit("e2", DigitSuffix::Multiply), Ok(2.0 * E));
+        assert_eq!(eval_implicit("x2 + 1", DigitSuffix::Multiply), Ok(7.0));
+        // Function names are never split
+        assert_eq!(eval_implicit("log10(1000)", DigitSuffix::Multiply), Ok(3.0));
+        // Splitting only applies with implicit multiplication
+        let options = FormulaOptions {
+            digit_suffix: DigitSuffix::Multiply,
+            ..Default::default()
+        };
+        let expr = parse_expression_located("e2", &HashMap::new(), &options).unwrap();
+        assert_eq!(expr, Expression::Variable("e2".to_string()));
+    }
+
+    #[test]
+    fn test_unit_aware_evaluation() {
+        use crate::components::unit_input::{length, time};
+
+        let options = FormulaOptions {
+            implicit_multiplication: true,
+            units: vec![length::meter(), length::kilometer(), time::second()],
+            ..Default::default()
+        };
+        let result = analyze_formula("5 m / 2 s", &HashMap::new(), &HashMap::new(), &options);
+        let quantity = result.quantity.unwrap();
+        assert_eq!(quantity.value, 2.5);
+        assert_eq!(quantity.unit.symbol(), "m/s");
+        assert_eq!(quantity.to_string(), "2.5 m/s");
+        assert!(result.variables.is_empty());
+
+        // Sums convert into the left operand's unit
+        let result = analyze_formula("1 km + 500 m", &HashMap::new(), &HashMap::new(), &options);
+        assert_eq!(
+            result.quantity.map(|q| q.to_string()),
+            Some("1.5 km".to_string())
+        );
+
+        let result = analyze_formula("(3 m)^2", &HashMap::new(), &HashMap::new(), &options);
+        assert_eq!(
+            result.quantity.map(|q| q.to_string()),
+            Some("9 m²".to_string())
+        );
+
+        let expr = parse_expression_located("1 m + 1 s", &HashMap::new(), &options).unwrap();
+        assert_eq!(
+            expr.evaluate_quantity(&HashMap::new(), &options.units, &HashMap::new()),
+            Err("Cannot apply '+' to m and s".to_string())
+        );
+        let expr = parse_expression_located("sin(2 m)", &HashMap::new(), &options).unwrap();
+        assert!(expr
+            .evaluate_quantity(&HashMap::new(), &options.units, &HashMap::new())
+            .is_err());
+    }
+
     #[test]
     fn test_math_function_evaluate() {
         assert!((MathFunction::Sin.evaluate(0.0)).abs() < 1e-10);
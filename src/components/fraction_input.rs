@@ -6,8 +6,10 @@
 use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
+use crate::validation::{ErrorCode, ValidationError};
 use leptos::ev;
 use leptos::prelude::*;
+use std::num::{IntErrorKind, ParseIntError};
 
 /// Display format for fractions
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
@@ -21,6 +23,17 @@ pub enum FractionDisplayFormat {
     Decimal,
 }
 
+/// How FractionInput turns typed decimals into fractions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecimalParseMode {
+    /// Terminating decimals convert exactly: 0.125 → 1/8
+    Exact,
+    /// Exact, plus repeating notation: 0.3(3), 0.\overline{3} or 0.333... → 1/3
+    Repeating,
+    /// Closest fraction whose denominator is at most the given limit
+    Approximate(i64),
+}
+
 /// Represents an exact fraction (rational number)
 #[derive(Clone, Debug, PartialEq)]
 pub struct Fraction {
@@ -58,55 +71,90 @@ impl Fraction {
     }
 
     /// Create a fraction from a mixed number (whole + fraction)
+    ///
+    /// Panics if the denominator is zero or the result does not fit in
+    /// i64; use [`Fraction::checked_from_mixed`] for untrusted input.
     pub fn from_mixed(whole: i64, numerator: i64, denominator: i64) -> Self {
         if denominator == 0 {
             panic!("Denominator cannot be zero");
         }
-        let sign = if whole < 0 || numerator < 0 { -1 } else { 1 };
-        let whole_abs = whole.abs();
-        let num_abs = numerator.abs();
+        Self::checked_from_mixed(whole, numerator, denominator).expect("Mixed number overflows i64")
+    }
 
-        Self {
-            numerator: sign * (whole_abs * denominator.abs() + num_abs),
-            denominator: denominator.abs(),
+    /// Create a fraction from a mixed number, or `None` if the denominator
+    /// is zero or the result does not fit in i64
+    pub fn checked_from_mixed(whole: i64, numerator: i64, denominator: i64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
         }
+        let sign = if whole < 0 || numerator < 0 { -1 } else { 1 };
+        let den = i128::from(denominator).abs();
+        let num = sign * (i128::from(whole).abs() * den + i128::from(numerator).abs());
+
+        Self::from_wide(num, den)
     }
 
-    /// Compute the greatest common divisor using Euclidean algorithm
-    fn gcd(a: i64, b: i64) -> i64 {
-        let mut a = a.abs();
-        let mut b = b.abs();
-        while b != 0 {
-            let temp = b;
-            b = a % b;
-            a = temp;
+    /// Narrow an i128 ratio, reducing it first only if it would not fit
+    fn from_wide(num: i128, den: i128) -> Option<Self> {
+        if let (Ok(numerator), Ok(denominator)) = (i64::try_from(num), i64::try_from(den)) {
+            return Some(Self {
+                numerator,
+                denominator,
+            });
         }
-        a
+        Self::reduce_wide(num, den)
     }
 
-    /// Simplify the fraction to lowest terms
-    pub fn simplify(&self) -> Self {
-        if self.numerator == 0 {
-            return Self {
-                numerator: 0,
-                denominator: 1,
-            };
+    /// Reduce an i128 ratio to lowest terms with a positive denominator
+    fn reduce_wide(num: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+        if num == 0 {
+            return Some(Self::default());
         }
 
-        let gcd = Self::gcd(self.numerator, self.denominator);
-        let mut num = self.numerator / gcd;
-        let mut den = self.denominator / gcd;
-
+        let gcd = Self::gcd(num, den);
+        let (mut num, mut den) = (num / gcd, den / gcd);
         // Ensure denominator is positive
         if den < 0 {
             num = -num;
             den = -den;
         }
 
-        Self {
-            numerator: num,
-            denominator: den,
+        Some(Self {
+            numerator: i64::try_from(num).ok()?,
+            denominator: i64::try_from(den).ok()?,
+        })
+    }
+
+    /// Compute the greatest common divisor using Euclidean algorithm.
+    ///
+    /// Works in i128 so that `i64::MIN` has an absolute value.
+    fn gcd(a: i128, b: i128) -> i128 {
+        let mut a = a.abs();
+        let mut b = b.abs();
+        while b != 0 {
+            let temp = b;
+            b = a % b;
+            a = temp;
         }
+        a
+    }
+
+    /// Simplify the fraction to lowest terms, or `None` if the reduced
+    /// fraction does not fit in i64 (e.g. `1/i64::MIN`, whose denominator
+    /// cannot be made positive)
+    pub fn checked_simplify(&self) -> Option<Self> {
+        Self::reduce_wide(self.numerator.into(), self.denominator.into())
+    }
+
+    /// Simplify the fraction to lowest terms.
+    ///
+    /// Returns the fraction unchanged in the rare case where the reduced
+    /// form does not fit in i64.
+    pub fn simplify(&self) -> Self {
+        self.checked_simplify().unwrap_or_else(|| self.clone())
     }
 
     /// Convert to decimal
@@ -114,55 +162,81 @@ impl Fraction {
         self.numerator as f64 / self.denominator as f64
     }
 
-    /// Create from decimal with specified precision (max denominator)
+    /// Create from decimal with specified precision (max denominator).
+    ///
+    /// Returns the best rational approximation whose denominator does not
+    /// exceed `max_denominator`, found from the continued fraction expansion
+    /// of `value` (including semiconvergents).
     pub fn from_decimal(value: f64, max_denominator: i64) -> Self {
-        if value == 0.0 {
+        if value == 0.0 || !value.is_finite() {
             return Self::default();
         }
 
         let negative = value < 0.0;
-        let value = value.abs();
-
-        // Use continued fraction algorithm for best rational approximation
-        let mut best_num = 0i64;
-        let mut best_den = 1i64;
-        let mut best_error = value;
+        let target = value.abs();
+        let max_denominator = max_denominator.max(1);
+        let error = |num: i64, den: i64| (target - num as f64 / den as f64).abs();
+
+        // (num, den) is the latest convergent, (prev_num, prev_den) the one before
+        let (mut prev_num, mut prev_den, mut num, mut den) = (0i64, 1i64, 1i64, 0i64);
+        let mut x = target;
+
+        loop {
+            let a = x.floor() as i64;
+            let next = a
+                .checked_mul(num)
+                .and_then(|n| n.checked_add(prev_num))
+                .zip(a.checked_mul(den).and_then(|d| d.checked_add(prev_den)));
+            let Some((next_num, next_den)) = next else {
+                break;
+            };
 
-        for den in 1..=max_denominator {
-            let num = (value * den as f64).round() as i64;
-            let error = (value - num as f64 / den as f64).abs();
+            if next_den > max_denominator {
+                // The largest semiconvergent that still fits may beat the last convergent
+                let k = (max_denominator - prev_den) / den;
+                let semi = k
+                    .checked_mul(num)
+                    .and_then(|n| n.checked_add(prev_num))
+                    .map(|n| (n, k * den + prev_den));
+                if let Some((semi_num, semi_den)) = semi {
+                    if error(semi_num, semi_den) < error(num, den) {
+                        (num, den) = (semi_num, semi_den);
+                    }
+                }
+                break;
+            }
 
-            if error < best_error {
-                best_error = error;
-                best_num = num;
-                best_den = den;
+            (prev_num, prev_den, num, den) = (num, den, next_num, next_den);
 
-                if error < 1e-10 {
-                    break;
-                }
+            let rest = x - a as f64;
+            if rest < 1e-12 || error(num, den) < 1e-10 {
+                break;
             }
+            x = 1.0 / rest;
         }
 
         Self {
-            numerator: if negative { -best_num } else { best_num },
-            denominator: best_den,
+            numerator: if negative { -num } else { num },
+            denominator: den,
         }
         .simplify()
     }
 
     /// Check if this represents a whole number
     pub fn is_whole(&self) -> bool {
-        self.numerator % self.denominator == 0
+        // wrapping_rem: i64::MIN % -1 is 0 but overflows with `%`
+        self.numerator.wrapping_rem(self.denominator) == 0
     }
 
     /// Get the whole number part
     pub fn whole_part(&self) -> i64 {
-        self.numerator / self.denominator
+        self.numerator.saturating_div(self.denominator)
     }
 
     /// Get the fractional part (numerator of the proper fraction)
     pub fn fractional_numerator(&self) -> i64 {
-        (self.numerator % self.denominator).abs()
+        // |remainder| < |denominator|, so this always fits
+        self.numerator.wrapping_rem(self.denominator).unsigned_abs() as i64
     }
 
     /// Check if the fraction is negative
@@ -200,9 +274,9 @@ impl Fraction {
             format!(
                 "{}{} {}/{}",
                 sign,
-                whole.abs(),
+                whole.unsigned_abs(),
                 frac_num,
-                simplified.denominator.abs()
+                simplified.denominator.unsigned_abs()
             )
         }
     }
@@ -211,98 +285,369 @@ impl Fraction {
     pub fn to_decimal_string(&self, precision: u32) -> String {
         format!("{:.prec$}", self.to_decimal(), prec = precision as usize)
     }
+
+    /// Reciprocal (denominator/numerator), or `None` for zero.
+    ///
+    /// The sign moves to the numerator where it fits; for a numerator of
+    /// `i64::MIN` it stays in the denominator. See [`try_inverse`](Self::try_inverse)
+    /// to treat that case as an error.
+    pub fn inverse(&self) -> Option<Fraction> {
+        if self.numerator == 0 {
+            return None;
+        }
+        Some(self.try_inverse().unwrap_or(Self {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }))
+    }
+
+    /// Additive inverse (-a/b).
+    ///
+    /// For a numerator of `i64::MIN` the sign goes into the denominator
+    /// instead. See [`try_negate`](Self::try_negate) to treat that case as
+    /// an error.
+    pub fn negate(&self) -> Fraction {
+        if let Ok(negated) = self.try_negate() {
+            negated
+        } else if let Some(denominator) = self.denominator.checked_neg() {
+            Self {
+                numerator: self.numerator,
+                denominator,
+            }
+        } else {
+            // i64::MIN / i64::MIN is 1
+            Self::from_whole(-1)
+        }
+    }
+
+    /// Reciprocal (denominator/numerator), with a positive denominator.
+    ///
+    /// Fails for zero, which has no reciprocal, and when moving the sign
+    /// overflows (a numerator of `i64::MIN`).
+    pub fn try_inverse(&self) -> Result<Fraction, FractionOpError> {
+        if self.numerator == 0 {
+            return Err(FractionOpError::DivisionByZero);
+        }
+        if self.numerator < 0 {
+            Ok(Self {
+                numerator: self
+                    .denominator
+                    .checked_neg()
+                    .ok_or(FractionOpError::Overflow)?,
+                denominator: self
+                    .numerator
+                    .checked_neg()
+                    .ok_or(FractionOpError::Overflow)?,
+            })
+        } else {
+            Ok(Self {
+                numerator: self.denominator,
+                denominator: self.numerator,
+            })
+        }
+    }
+
+    /// Additive inverse (-a/b). Fails for a numerator of `i64::MIN`, whose
+    /// negation doesn't fit.
+    pub fn try_negate(&self) -> Result<Fraction, FractionOpError> {
+        Ok(Self {
+            numerator: self
+                .numerator
+                .checked_neg()
+                .ok_or(FractionOpError::Overflow)?,
+            denominator: self.denominator,
+        })
+    }
+}
+
+/// Why [`Fraction::try_inverse`] or [`Fraction::try_negate`] has no result
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FractionOpError {
+    /// Inverting zero
+    DivisionByZero,
+    /// The result doesn't fit in an `i64` fraction
+    Overflow,
+}
+
+impl std::fmt::Display for FractionOpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DivisionByZero => write!(f, "Cannot invert zero"),
+            Self::Overflow => write!(f, "Result is too large to represent exactly"),
+        }
+    }
 }
 
+impl std::error::Error for FractionOpError {}
+
 impl std::fmt::Display for Fraction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_fraction_string())
     }
 }
 
+/// Rejection for text that is not a fraction, mixed number or decimal
+fn invalid_fraction() -> ValidationError {
+    ValidationError::custom("Enter a fraction (3/4), mixed number (1 1/2) or decimal (0.75)")
+}
+
+/// Rejection for values that do not fit in an i64 fraction
+fn fraction_overflow() -> ValidationError {
+    ValidationError::custom("Number is too large to represent exactly")
+}
+
+/// Parse an integer, telling overflow apart from malformed text
+fn parse_int(input: &str) -> Result<i64, ValidationError> {
+    input
+        .trim()
+        .parse()
+        .map_err(|e: ParseIntError| match e.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => fraction_overflow(),
+            _ => invalid_fraction(),
+        })
+}
+
 /// Parse a fraction from various string formats
-fn parse_fraction(input: &str) -> Option<Fraction> {
+fn parse_fraction(input: &str, mode: DecimalParseMode) -> Result<Fraction, ValidationError> {
     let trimmed = input.trim();
 
     if trimmed.is_empty() {
-        return None;
+        return Err(invalid_fraction());
     }
 
-    // Try parsing as mixed number: "1 1/2" or "-2 3/4"
-    if let Some(fraction) = parse_mixed_number(trimmed) {
-        return Some(fraction);
+    if let Some((whole, rest)) = trimmed.split_once(char::is_whitespace) {
+        // Mixed number: "1 1/2" or "-2 3/4"
+        if !whole.contains('/') && rest.contains('/') && !rest.trim().contains(' ') {
+            return parse_mixed_number(whole, rest.trim());
+        }
     }
 
-    // Try parsing as simple fraction: "1/2" or "-3/4"
-    if let Some(fraction) = parse_simple_fraction(trimmed) {
-        return Some(fraction);
+    // Simple fraction: "1/2" or "-3/4"
+    if trimmed.contains('/') {
+        return parse_simple_fraction(trimmed);
     }
 
-    // Try parsing as decimal: "0.5" or "-1.25"
-    if let Some(fraction) = parse_decimal_to_fraction(trimmed) {
-        return Some(fraction);
+    // Whole number: "5" or "-3"
+    let digits = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        return parse_int(trimmed).map(Fraction::from_whole);
     }
 
-    // Try parsing as whole number: "5" or "-3"
-    if let Ok(whole) = trimmed.parse::<i64>() {
-        return Some(Fraction::from_whole(whole));
+    // Decimal: "0.5" or "-1.25"
+    parse_decimal_to_fraction(trimmed, mode)
+}
+
+fn parse_mixed_number(whole: &str, fraction: &str) -> Result<Fraction, ValidationError> {
+    let whole = parse_int(whole)?;
+    let (num, den) = fraction.split_once('/').ok_or_else(invalid_fraction)?;
+    let num = parse_int(num)?;
+    let den = parse_int(den)?;
+
+    if den == 0 {
+        return Err(ValidationError::custom("Denominator cannot be zero"));
     }
 
-    None
+    Fraction::checked_from_mixed(whole, num, den).ok_or_else(fraction_overflow)
 }
 
-fn parse_mixed_number(input: &str) -> Option<Fraction> {
-    // Match patterns like "1 1/2" or "-2 3/4"
-    let parts: Vec<&str> = input.split_whitespace().collect();
+fn parse_simple_fraction(input: &str) -> Result<Fraction, ValidationError> {
+    let (num, den) = input.split_once('/').ok_or_else(invalid_fraction)?;
+    let num = parse_int(num)?;
+    let den = parse_int(den)?;
 
-    if parts.len() != 2 {
-        return None;
+    if den == 0 {
+        return Err(ValidationError::custom("Denominator cannot be zero"));
     }
 
-    let whole: i64 = parts[0].parse().ok()?;
+    Ok(Fraction::new(num, den))
+}
 
-    // Parse the fraction part
-    let frac_parts: Vec<&str> = parts[1].split('/').collect();
-    if frac_parts.len() != 2 {
-        return None;
+fn parse_decimal_to_fraction(
+    input: &str,
+    mode: DecimalParseMode,
+) -> Result<Fraction, ValidationError> {
+    match mode {
+        DecimalParseMode::Exact => parse_exact_decimal(input, false),
+        DecimalParseMode::Repeating => parse_exact_decimal(input, true),
+        DecimalParseMode::Approximate(max_denominator) => {
+            let value: f64 = input.parse().map_err(|_| invalid_fraction())?;
+            if !value.is_finite() {
+                return Err(invalid_fraction());
+            }
+            // Beyond i64 the approximation would saturate rather than convert
+            if value.abs() >= i64::MAX as f64 {
+                return Err(fraction_overflow());
+            }
+            Ok(Fraction::from_decimal(value, max_denominator))
+        }
     }
+}
 
-    let num: i64 = frac_parts[0].parse().ok()?;
-    let den: i64 = frac_parts[1].parse().ok()?;
-
-    if den == 0 {
-        return None;
+/// Reject fractions whose denominator, once reduced, exceeds `max_denominator`
+fn check_max_denominator(
+    fraction: Fraction,
+    max_denominator: Option<i64>,
+) -> Result<Fraction, ValidationError> {
+    let Some(max) = max_denominator else {
+        return Ok(fraction);
+    };
+    let reduced = fraction.checked_simplify().ok_or_else(fraction_overflow)?;
+    if reduced.denominator > max {
+        return Err(ValidationError::new(
+            format!("Denominator must be at most {}", max),
+            ErrorCode::MaxValue,
+        ));
     }
+    Ok(fraction)
+}
 
-    Some(Fraction::from_mixed(whole, num, den))
+/// Repeating block written after the decimal digits
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Repetend<'a> {
+    /// `0.1(6)` or `0.1\overline{6}`
+    Digits(&'a str),
+    /// `0.1666...`: the block is inferred from the trailing digits
+    Trailing,
 }
 
-fn parse_simple_fraction(input: &str) -> Option<Fraction> {
-    let parts: Vec<&str> = input.split('/').collect();
+/// Split a repeating-block marker off the end of a decimal
+fn split_repetend(input: &str) -> (&str, Option<Repetend<'_>>) {
+    if let Some(rest) = input
+        .strip_suffix("...")
+        .or_else(|| input.strip_suffix('…'))
+    {
+        return (rest, Some(Repetend::Trailing));
+    }
+    if let Some(rest) = input.strip_suffix(')') {
+        if let Some((mantissa, digits)) = rest.rsplit_once('(') {
+            return (mantissa, Some(Repetend::Digits(digits)));
+        }
+    }
+    if let Some(rest) = input.strip_suffix('}') {
+        if let Some((mantissa, digits)) = rest.rsplit_once("\\overline{") {
+            return (mantissa, Some(Repetend::Digits(digits)));
+        }
+    }
+    (input, None)
+}
 
-    if parts.len() != 2 {
+/// Split `digits` into a fixed part and the shortest block repeated at its
+/// end ("1666" → "166", "6"); a lone final digit repeats if nothing does
+fn trailing_repetend(digits: &str) -> Option<(&str, &str)> {
+    if digits.is_empty() {
         return None;
     }
+    let len = digits.len();
+    let period = (1..=len / 2)
+        .find(|&p| digits[len - p..] == digits[len - 2 * p..len - p])
+        .unwrap_or(1);
+    Some(digits.split_at(len - period))
+}
 
-    let num: i64 = parts[0].trim().parse().ok()?;
-    let den: i64 = parts[1].trim().parse().ok()?;
+/// Split a trailing `e±N` exponent off a decimal
+fn split_exponent(input: &str) -> Option<(&str, i32)> {
+    match input.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => Some((mantissa, exponent.parse().ok()?)),
+        None => Some((input, 0)),
+    }
+}
 
-    if den == 0 {
-        return None;
+/// Parse a decimal without going through floating point: `0.125` becomes
+/// 125/1000, and with `repeating` also `0.1(6)`, `0.1\\overline{6}` and
+/// `0.1666...` become 15/90
+fn parse_exact_decimal(input: &str, repeating: bool) -> Result<Fraction, ValidationError> {
+    let (negative, body) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let (mantissa, marker) = if repeating {
+        split_repetend(body)
+    } else {
+        (body, None)
+    };
+    // Exponents only make sense on terminating decimals
+    let (mantissa, exponent) = match marker {
+        Some(_) => (mantissa, 0),
+        None => split_exponent(mantissa).ok_or_else(invalid_fraction)?,
+    };
+
+    let (int_digits, fixed) = match mantissa.split_once('.') {
+        Some(parts) => parts,
+        // A repeating block needs a decimal point: "3(3)" is not a number
+        None if marker.is_some() => return Err(invalid_fraction()),
+        None => (mantissa, ""),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(int_digits) || !is_digits(fixed) {
+        return Err(invalid_fraction());
+    }
+
+    let (fixed, repetend) = match marker {
+        Some(Repetend::Digits(digits)) => (fixed, digits),
+        Some(Repetend::Trailing) => trailing_repetend(fixed).ok_or_else(invalid_fraction)?,
+        None => (fixed, ""),
+    };
+    if !is_digits(repetend) || (marker.is_some() && repetend.is_empty()) {
+        return Err(invalid_fraction());
+    }
+    if int_digits.is_empty() && fixed.is_empty() && repetend.is_empty() {
+        return Err(invalid_fraction());
     }
 
-    Some(Fraction::new(num, den))
+    // From here on every failure is a value too large for i128
+    let wide = || -> Option<(i128, i128)> {
+        // int.fixed = shifted / scale
+        let scale = 10i128.checked_pow(fixed.len() as u32)?;
+        let shifted: i128 = match format!("{}{}", int_digits, fixed).as_str() {
+            "" => 0,
+            digits => digits.parse().ok()?,
+        };
+
+        let (mut num, mut den) = if repetend.is_empty() {
+            (shifted, scale)
+        } else {
+            // int.fixed(rep) = shifted/scale + rep/(scale * (10^len(rep) - 1))
+            let period = 10i128.checked_pow(repetend.len() as u32)? - 1;
+            let block: i128 = repetend.parse().ok()?;
+            (
+                shifted.checked_mul(period)?.checked_add(block)?,
+                scale.checked_mul(period)?,
+            )
+        };
+
+        let power = 10i128.checked_pow(exponent.unsigned_abs())?;
+        if exponent >= 0 {
+            num = num.checked_mul(power)?;
+        } else {
+            den = den.checked_mul(power)?;
+        }
+        Some((if negative { -num } else { num }, den))
+    };
+
+    wide()
+        .and_then(|(num, den)| Fraction::from_wide(num, den))
+        .ok_or_else(fraction_overflow)
 }
 
-fn parse_decimal_to_fraction(input: &str) -> Option<Fraction> {
-    let value: f64 = input.parse().ok()?;
+/// Quick operations offered by FractionInput's action buttons and shortcuts
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FractionAction {
+    Invert,
+    Negate,
+    Reset,
+}
 
-    // Don't parse if it looks like a fraction (contains /)
-    if input.contains('/') {
-        return None;
+impl FractionAction {
+    /// Result of applying the action, or why it is undefined (1/0) or
+    /// doesn't fit
+    fn apply(self, fraction: &Fraction) -> Result<Fraction, FractionOpError> {
+        match self {
+            FractionAction::Invert => fraction.try_inverse(),
+            FractionAction::Negate => fraction.try_negate(),
+            FractionAction::Reset => Ok(Fraction::default()),
+        }
     }
-
-    // Use a reasonable max denominator for conversion
-    Some(Fraction::from_decimal(value, 10000))
 }
 
 /// FractionInput component for exact rational number entry
@@ -328,9 +673,21 @@ pub fn FractionInput(
     #[prop(default = 4)]
     decimal_precision: u32,
 
-    /// Maximum denominator when converting from decimal (used for decimal-to-fraction conversion)
-    #[prop(default = 10000)]
-    _max_denominator: i64,
+    /// Largest accepted denominator (after reducing). Entries beyond it are
+    /// rejected with an error; also the limit for the default decimal parsing.
+    #[prop(optional)]
+    max_denominator: Option<i64>,
+
+    /// How typed decimals are converted; defaults to
+    /// `DecimalParseMode::Approximate(max_denominator)`, or
+    /// `Approximate(10000)` without a `max_denominator`
+    #[prop(optional)]
+    decimal_parse_mode: Option<DecimalParseMode>,
+
+    /// Called when typed text is rejected: unparseable, too large for an
+    /// exact i64 fraction, or over `max_denominator`
+    #[prop(optional)]
+    on_parse_error: Option<Callback<ValidationError>>,
 
     /// Whether to show format selector
     #[prop(default = false)]
@@ -340,6 +697,14 @@ pub fn FractionInput(
     #[prop(optional)]
     on_format_change: Option<Callback<FractionDisplayFormat>>,
 
+    /// Whether to show invert (1/x), negate (±) and reset buttons
+    #[prop(default = false)]
+    show_actions: bool,
+
+    /// Enable Alt+I (invert), Alt+N (negate) and Alt+0 (reset) shortcuts
+    #[prop(default = false)]
+    shortcuts: bool,
+
     /// Input variant styling
     #[prop(optional)]
     variant: Option<InputVariant>,
@@ -383,6 +748,13 @@ pub fn FractionInput(
     let theme = use_theme();
     let variant = variant.unwrap_or(InputVariant::Default);
     let size = size.unwrap_or(InputSize::Md);
+    let decimal_parse_mode = decimal_parse_mode.unwrap_or(DecimalParseMode::Approximate(
+        max_denominator.unwrap_or(10000),
+    ));
+    let parse = move |text: &str| {
+        parse_fraction(text, decimal_parse_mode)
+            .and_then(|fraction| check_max_denominator(fraction, max_denominator))
+    };
 
     // Internal fraction value
     let fraction_value = value.unwrap_or_else(|| RwSignal::new(Fraction::default()));
@@ -435,6 +807,9 @@ pub fn FractionInput(
         is_editing.set(true);
     };
 
+    // Why the last committed text was rejected
+    let input_error = RwSignal::new(None::<String>);
+
     // Handle blur - parse and validate
     let handle_blur = move |_ev: ev::FocusEvent| {
         is_editing.set(false);
@@ -442,26 +817,88 @@ pub fn FractionInput(
         let text = display_text.get();
         let fmt = current_format.get();
 
-        if let Some(mut parsed) = parse_fraction(&text) {
-            if auto_simplify {
-                parsed = parsed.simplify();
+        match parse(&text) {
+            Ok(mut parsed) => {
+                if auto_simplify {
+                    parsed = parsed.simplify();
+                }
+
+                fraction_value.set(parsed.clone());
+
+                if let Some(callback) = on_change {
+                    callback.run(parsed.clone());
+                }
+
+                // Update display with formatted value
+                display_text.set(format_fraction(&parsed, fmt));
+                input_error.set(None);
             }
+            Err(err) if !text.is_empty() => {
+                // Invalid input - revert to previous value and say why
+                let frac = fraction_value.get();
+                display_text.set(format_fraction(&frac, fmt));
+                input_error.set(Some(err.message.clone()));
+                if let Some(callback) = on_parse_error {
+                    callback.run(err);
+                }
+            }
+            Err(_) => input_error.set(None),
+        }
+    };
 
-            fraction_value.set(parsed.clone());
+    // Brief error shown when an action cannot be applied
+    let action_error = RwSignal::new(None::<String>);
 
-            if let Some(callback) = on_change {
-                callback.run(parsed.clone());
+    // Apply invert/negate/reset to the current value (including uncommitted text)
+    let apply_action = move |action: FractionAction| {
+        if disabled.get() {
+            return;
+        }
+
+        let current = if is_editing.get_untracked() {
+            parse(&display_text.get_untracked()).unwrap_or_else(|_| fraction_value.get_untracked())
+        } else {
+            fraction_value.get_untracked()
+        };
+
+        let mut result = match action.apply(&current) {
+            Ok(result) => result,
+            Err(err) => {
+                action_error.set(Some(err.to_string()));
+                set_timeout(
+                    move || action_error.set(None),
+                    std::time::Duration::from_millis(2000),
+                );
+                return;
             }
+        };
 
-            // Update display with formatted value
-            display_text.set(format_fraction(&parsed, fmt));
-        } else if !text.is_empty() {
-            // Invalid input - revert to previous value
-            let frac = fraction_value.get();
-            display_text.set(format_fraction(&frac, fmt));
+        if auto_simplify {
+            result = result.simplify();
+        }
+        action_error.set(None);
+        fraction_value.set(result.clone());
+        display_text.set(format_fraction(&result, current_format.get_untracked()));
+
+        if let Some(callback) = on_change {
+            callback.run(result);
         }
     };
 
+    let handle_keydown = move |ev: ev::KeyboardEvent| {
+        if !shortcuts || !ev.alt_key() {
+            return;
+        }
+        let action = match ev.code().as_str() {
+            "KeyI" => FractionAction::Invert,
+            "KeyN" => FractionAction::Negate,
+            "Digit0" | "Numpad0" => FractionAction::Reset,
+            _ => return,
+        };
+        ev.prevent_default();
+        apply_action(action);
+    };
+
     // Handle format change
     let handle_format_change = move |new_format: FractionDisplayFormat| {
         if current_format.get() != new_format {
@@ -494,7 +931,7 @@ pub fn FractionInput(
             InputSize::Xl => ("3rem", &*theme_val.typography.font_sizes.lg, "0 1.25rem"),
         };
 
-        let border_color = if error_for_style.is_some() {
+        let border_color = if error_for_style.is_some() || input_error.get().is_some() {
             scheme_colors
                 .get_color("red", 6)
                 .unwrap_or_else(|| "#fa5252".to_string())
@@ -575,6 +1012,34 @@ pub fn FractionInput(
         )
     };
 
+    let action_button_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+
+        StyleBuilder::new()
+            .add("border", "none")
+            .add("background", "transparent")
+            .add("padding", "0 0.25rem")
+            .add("font-family", "inherit")
+            .add("font-size", "0.75rem")
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("gray", 7)
+                    .unwrap_or_else(|| "#495057".to_string()),
+            )
+            .add("border-radius", &*theme_val.radius.xs)
+            .add(
+                "cursor",
+                if disabled.get() {
+                    "not-allowed"
+                } else {
+                    "pointer"
+                },
+            )
+            .build()
+    };
+
     let container_styles = style.clone().unwrap_or_default();
     let container_class = class.clone().unwrap_or_default();
 
@@ -610,8 +1075,35 @@ pub fn FractionInput(
                     on:input=handle_input
                     on:focus=handle_focus
                     on:blur=handle_blur
+                    on:keydown=handle_keydown
                 />
 
+                {show_actions.then(|| {
+                    let actions = [
+                        (FractionAction::Invert, "1/x", "Invert (swap numerator and denominator)"),
+                        (FractionAction::Negate, "±", "Negate"),
+                        (FractionAction::Reset, "0", "Reset to zero"),
+                    ];
+                    view! {
+                        <div class="mingot-fraction-actions" style="display: flex; gap: 0.125rem; margin-left: 0.25rem;">
+                            {actions.into_iter().map(|(action, text, title)| view! {
+                                <button
+                                    type="button"
+                                    title=title
+                                    aria-label=title
+                                    style=action_button_styles
+                                    prop:disabled=move || disabled.get()
+                                    // Keep focus in the text field so uncommitted text is used
+                                    on:mousedown=|ev: ev::MouseEvent| ev.prevent_default()
+                                    on:click=move |_| apply_action(action)
+                                >
+                                    {text}
+                                </button>
+                            }).collect_view()}
+                        </div>
+                    }
+                })}
+
                 {move || {
                     if show_format_selector {
                         let current = current_format.get();
@@ -657,6 +1149,14 @@ pub fn FractionInput(
             {error_for_display.clone().map(|e| view! {
                 <div style=error_styles role="alert">{e}</div>
             })}
+
+            {move || input_error.get().map(|e| view! {
+                <div style=error_styles role="alert">{e}</div>
+            })}
+
+            {move || action_error.get().map(|e| view! {
+                <div style=error_styles role="alert">{e}</div>
+            })}
         </div>
     }
 }
@@ -665,6 +1165,8 @@ pub fn FractionInput(
 mod tests {
     use super::*;
 
+    const DEFAULT_MODE: DecimalParseMode = DecimalParseMode::Approximate(10000);
+
     #[test]
     fn test_fraction_new() {
         let f = Fraction::new(3, 4);
@@ -762,52 +1264,295 @@ mod tests {
 
     #[test]
     fn test_parse_simple_fraction() {
-        let f = parse_fraction("3/4").unwrap();
+        let f = parse_fraction("3/4", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, 3);
         assert_eq!(f.denominator, 4);
 
-        let f = parse_fraction("-1/2").unwrap();
+        let f = parse_fraction("-1/2", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, -1);
         assert_eq!(f.denominator, 2);
     }
 
     #[test]
     fn test_parse_mixed_number() {
-        let f = parse_fraction("1 1/2").unwrap();
+        let f = parse_fraction("1 1/2", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, 3);
         assert_eq!(f.denominator, 2);
 
-        let f = parse_fraction("2 3/4").unwrap();
+        let f = parse_fraction("2 3/4", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, 11);
         assert_eq!(f.denominator, 4);
     }
 
     #[test]
     fn test_parse_decimal() {
-        let f = parse_fraction("0.5").unwrap().simplify();
+        let f = parse_fraction("0.5", DEFAULT_MODE).unwrap().simplify();
         assert_eq!(f.numerator, 1);
         assert_eq!(f.denominator, 2);
 
-        let f = parse_fraction("1.25").unwrap().simplify();
+        let f = parse_fraction("1.25", DEFAULT_MODE).unwrap().simplify();
         assert_eq!(f.numerator, 5);
         assert_eq!(f.denominator, 4);
     }
 
+    #[test]
+    fn test_parse_decimal_exact() {
+        let f = parse_fraction("0.125", DecimalParseMode::Exact).unwrap();
+        assert_eq!(f, Fraction::new(125, 1000));
+        assert_eq!(f.simplify(), Fraction::new(1, 8));
+
+        let f = parse_fraction("-2.5e-3", DecimalParseMode::Exact).unwrap();
+        assert_eq!(f.simplify(), Fraction::new(-1, 400));
+
+        // Not approximated: 0.3333 stays 3333/10000
+        let f = parse_fraction("0.3333", DecimalParseMode::Exact).unwrap();
+        assert_eq!(f, Fraction::new(3333, 10000));
+
+        // Repeating notation is only understood in Repeating mode
+        assert!(parse_fraction("0.(3)", DecimalParseMode::Exact).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_repeating() {
+        let mode = DecimalParseMode::Repeating;
+        let one_third = Fraction::new(1, 3);
+        assert_eq!(
+            parse_fraction("0.3(3)", mode).unwrap().simplify(),
+            one_third
+        );
+        assert_eq!(
+            parse_fraction("0.\\overline{3}", mode).unwrap().simplify(),
+            one_third
+        );
+        assert_eq!(
+            parse_fraction("0.333...", mode).unwrap().simplify(),
+            one_third
+        );
+
+        let f = parse_fraction("0.142857(142857)", mode).unwrap().simplify();
+        assert_eq!(f, Fraction::new(1, 7));
+
+        let f = parse_fraction("-1.1(6)", mode).unwrap().simplify();
+        assert_eq!(f, Fraction::new(-7, 6));
+
+        let f = parse_fraction("0.142857142857...", mode)
+            .unwrap()
+            .simplify();
+        assert_eq!(f, Fraction::new(1, 7));
+
+        // Terminating decimals still parse exactly
+        let f = parse_fraction("0.125", mode).unwrap().simplify();
+        assert_eq!(f, Fraction::new(1, 8));
+
+        assert!(parse_fraction("3(3)", mode).is_err());
+        assert!(parse_fraction("0.()", mode).is_err());
+        assert!(parse_fraction("0.(3a)", mode).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_approximate_pi() {
+        let pi = "3.14159265358979";
+        let approx = |limit| parse_fraction(pi, DecimalParseMode::Approximate(limit)).unwrap();
+        assert_eq!(approx(10), Fraction::new(22, 7));
+        // A semiconvergent beats 22/7 below 106
+        assert_eq!(approx(100), Fraction::new(311, 99));
+        assert_eq!(approx(1000), Fraction::new(355, 113));
+        assert!(approx(1000).denominator <= 1000);
+    }
+
     #[test]
     fn test_parse_whole_number() {
-        let f = parse_fraction("5").unwrap();
+        let f = parse_fraction("5", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, 5);
         assert_eq!(f.denominator, 1);
 
-        let f = parse_fraction("-3").unwrap();
+        let f = parse_fraction("-3", DEFAULT_MODE).unwrap();
         assert_eq!(f.numerator, -3);
         assert_eq!(f.denominator, 1);
     }
 
+    #[test]
+    fn test_parse_overflow_is_an_error() {
+        let overflow = fraction_overflow();
+        assert_eq!(
+            parse_fraction("99999999999999999999/2", DEFAULT_MODE),
+            Err(overflow.clone())
+        );
+        // 2^62 * 2 + 1 used to overflow while building the mixed number
+        assert_eq!(
+            parse_fraction("4611686018427387904 1/2", DEFAULT_MODE),
+            Err(overflow.clone())
+        );
+        assert_eq!(parse_fraction("1e30", DEFAULT_MODE), Err(overflow.clone()));
+        assert_eq!(
+            parse_fraction(
+                "0.1(234567890123456789012345678901234567)",
+                DecimalParseMode::Repeating
+            ),
+            Err(overflow)
+        );
+        assert_eq!(
+            parse_fraction("1/0", DEFAULT_MODE).unwrap_err().message,
+            "Denominator cannot be zero"
+        );
+        assert_eq!(parse_fraction("abc", DEFAULT_MODE), Err(invalid_fraction()));
+    }
+
+    #[test]
+    fn test_large_fraction_switches_formats() {
+        let f = parse_fraction("1000000000/999999999", DEFAULT_MODE).unwrap();
+        assert_eq!(f.to_fraction_string(), "1000000000/999999999");
+        assert_eq!(f.to_mixed_string(), "1 1/999999999");
+
+        let f = Fraction::new(i64::MIN, 3);
+        assert_eq!(f.to_mixed_string(), "-3074457345618258602 2/3");
+        assert!(!f.is_whole());
+    }
+
+    #[test]
+    fn test_simplify_extremes() {
+        // gcd(i64::MIN, i64::MIN) is 2^63, which only fits in i128
+        assert_eq!(
+            Fraction::new(i64::MIN, i64::MIN).simplify(),
+            Fraction::new(1, 1)
+        );
+        assert_eq!(
+            Fraction::new(i64::MIN, 2).simplify(),
+            Fraction::new(i64::MIN / 2, 1)
+        );
+        // -i64::MIN does not fit, so the sign cannot move to the numerator
+        assert_eq!(Fraction::new(1, i64::MIN).checked_simplify(), None);
+        assert_eq!(
+            Fraction::new(1, i64::MIN).simplify(),
+            Fraction::new(1, i64::MIN)
+        );
+        assert!(Fraction::new(i64::MIN, -1).is_whole());
+        assert_eq!(Fraction::new(i64::MIN, -1).checked_simplify(), None);
+    }
+
+    #[test]
+    fn test_max_denominator() {
+        let limit = Some(10000);
+        assert!(check_max_denominator(Fraction::new(1, 10000), limit).is_ok());
+        // Reduced before comparing: 2/20000 is 1/10000
+        assert_eq!(
+            check_max_denominator(Fraction::new(2, 20000), limit),
+            Ok(Fraction::new(2, 20000))
+        );
+        let err = check_max_denominator(Fraction::new(1, 20000), limit).unwrap_err();
+        assert_eq!(err.code, ErrorCode::MaxValue);
+        assert_eq!(err.message, "Denominator must be at most 10000");
+        assert!(check_max_denominator(Fraction::new(1, 20000), None).is_ok());
+    }
+
     #[test]
     fn test_gcd() {
         assert_eq!(Fraction::gcd(12, 8), 4);
         assert_eq!(Fraction::gcd(17, 5), 1);
         assert_eq!(Fraction::gcd(100, 25), 25);
     }
+
+    #[test]
+    fn test_fraction_try_inverse() {
+        assert_eq!(Fraction::new(3, 4).try_inverse(), Ok(Fraction::new(4, 3)));
+        // Sign moves to the numerator
+        assert_eq!(Fraction::new(-2, 5).try_inverse(), Ok(Fraction::new(-5, 2)));
+        assert_eq!(
+            Fraction::from_whole(7).try_inverse(),
+            Ok(Fraction::new(1, 7))
+        );
+    }
+
+    #[test]
+    fn test_fraction_try_inverse_of_zero() {
+        assert_eq!(
+            Fraction::default().try_inverse(),
+            Err(FractionOpError::DivisionByZero)
+        );
+        assert_eq!(
+            Fraction::new(0, 5).try_inverse(),
+            Err(FractionOpError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_fraction_try_inverse_extremes() {
+        let min = Fraction::new(i64::MIN, 3);
+        assert_eq!(min.try_inverse(), Err(FractionOpError::Overflow));
+
+        let max = Fraction::new(i64::MAX, 2);
+        assert_eq!(max.try_inverse(), Ok(Fraction::new(2, i64::MAX)));
+        let negative_max = Fraction::new(-i64::MAX, 2);
+        assert_eq!(negative_max.try_inverse(), Ok(Fraction::new(-2, i64::MAX)));
+    }
+
+    #[test]
+    fn test_fraction_try_negate() {
+        assert_eq!(Fraction::new(3, 4).try_negate(), Ok(Fraction::new(-3, 4)));
+        assert_eq!(Fraction::new(-3, 4).try_negate(), Ok(Fraction::new(3, 4)));
+        assert_eq!(Fraction::default().try_negate(), Ok(Fraction::new(0, 1)));
+    }
+
+    #[test]
+    fn test_fraction_try_negate_extremes() {
+        let min = Fraction::new(i64::MIN, 3);
+        assert_eq!(min.try_negate(), Err(FractionOpError::Overflow));
+        assert_eq!(
+            Fraction::new(i64::MAX, 1).try_negate(),
+            Ok(Fraction::new(-i64::MAX, 1))
+        );
+    }
+
+    #[test]
+    fn test_fraction_inverse() {
+        assert_eq!(Fraction::new(3, 4).inverse(), Some(Fraction::new(4, 3)));
+        assert_eq!(Fraction::new(-2, 5).inverse(), Some(Fraction::new(-5, 2)));
+        assert_eq!(Fraction::default().inverse(), None);
+        // The sign can't move to the numerator, so it stays below
+        let inverted = Fraction::new(i64::MIN, 3).inverse().unwrap();
+        assert_eq!(inverted, Fraction::new(3, i64::MIN));
+        assert!(inverted.is_negative());
+    }
+
+    #[test]
+    fn test_fraction_negate() {
+        assert_eq!(Fraction::new(3, 4).negate(), Fraction::new(-3, 4));
+        assert_eq!(Fraction::new(-3, 4).negate(), Fraction::new(3, 4));
+        assert_eq!(Fraction::default().negate(), Fraction::new(0, 1));
+        // The sign goes into the denominator when the numerator can't take it
+        let negated = Fraction::new(i64::MIN, 3).negate();
+        assert_eq!(negated, Fraction::new(i64::MIN, -3));
+        assert!(!negated.is_negative());
+        assert_eq!(
+            Fraction::new(i64::MIN, i64::MIN).negate(),
+            Fraction::from_whole(-1)
+        );
+    }
+
+    #[test]
+    fn test_fraction_actions() {
+        let half = Fraction::new(1, 2);
+        assert_eq!(FractionAction::Invert.apply(&half), Ok(Fraction::new(2, 1)));
+        assert_eq!(
+            FractionAction::Negate.apply(&half),
+            Ok(Fraction::new(-1, 2))
+        );
+        assert_eq!(FractionAction::Reset.apply(&half), Ok(Fraction::default()));
+    }
+
+    #[test]
+    fn test_fraction_action_errors_are_distinct() {
+        let zero = FractionAction::Invert
+            .apply(&Fraction::default())
+            .unwrap_err();
+        let min = Fraction::new(i64::MIN, 1);
+        let overflow = FractionAction::Invert.apply(&min).unwrap_err();
+        assert_eq!(zero.to_string(), "Cannot invert zero");
+        assert_eq!(overflow, FractionOpError::Overflow);
+        assert_ne!(zero.to_string(), overflow.to_string());
+        assert_eq!(
+            FractionAction::Negate.apply(&min),
+            Err(FractionOpError::Overflow)
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{
+    add_debug_outline, layout_debug_enabled, LayoutDebugKind, StyleBuilder, LAYOUT_DEBUG_CSS,
+};
 use leptos::prelude::*;
 
 /// Responsive column span configuration
@@ -113,12 +115,17 @@ pub fn Grid(
     #[prop(optional)] align: Option<GridAlign>,
     #[prop(optional)] justify: Option<GridJustify>,
     #[prop(optional)] _grow: bool,
+    /// Outline this layout and label its spacing, for debugging
+    #[prop(optional)]
+    debug: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
     let columns = columns.unwrap_or(12);
+    let debug = layout_debug_enabled(debug);
+    let debug_gap = gutter.clone();
 
     let grid_styles = move || {
         let theme_val = theme.get();
@@ -143,6 +150,10 @@ pub fn Grid(
             builder.add("justify-content", j.as_str());
         }
 
+        if debug {
+            add_debug_outline(&mut builder, LayoutDebugKind::Grid, &theme_val);
+        }
+
         if let Some(s) = style.as_ref() {
             return format!("{}; {}", builder.build(), s);
         }
@@ -153,10 +164,20 @@ pub fn Grid(
     // Provide grid columns context so GridCol can access it
     provide_context::<Signal<u32>>(Signal::derive(move || columns));
 
+    let debug_label = move || {
+        debug.then(|| {
+            let gap = debug_gap
+                .clone()
+                .unwrap_or_else(|| theme.get().spacing.md.to_string());
+            LayoutDebugKind::Grid.label(&format!("{} cols, gap {}", columns, gap))
+        })
+    };
+
     let class_str = format!("mingot-grid {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=grid_styles>
+        <div class=class_str style=grid_styles data-mingot-debug=debug_label>
+            {debug.then(|| view! { <style>{LAYOUT_DEBUG_CSS}</style> })}
             {children()}
         </div>
     }
@@ -265,11 +286,16 @@ pub fn SimpleGrid(
     #[prop(optional)] cols: Option<u32>,
     #[prop(optional, into)] spacing: Option<String>,
     #[prop(optional, into)] min_child_width: Option<String>,
+    /// Outline this layout and label its spacing, for debugging
+    #[prop(optional)]
+    debug: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
+    let debug = layout_debug_enabled(debug);
+    let debug_gap = spacing.clone();
 
     let grid_styles = move || {
         let theme_val = theme.get();
@@ -298,6 +324,10 @@ pub fn SimpleGrid(
             builder.add("gap", &*theme_val.spacing.md);
         }
 
+        if debug {
+            add_debug_outline(&mut builder, LayoutDebugKind::Grid, &theme_val);
+        }
+
         if let Some(s) = style.as_ref() {
             return format!("{}; {}", builder.build(), s);
         }
@@ -305,10 +335,20 @@ pub fn SimpleGrid(
         builder.build()
     };
 
+    let debug_label = move || {
+        debug.then(|| {
+            let gap = debug_gap
+                .clone()
+                .unwrap_or_else(|| theme.get().spacing.md.to_string());
+            LayoutDebugKind::Grid.label(&format!("gap {}", gap))
+        })
+    };
+
     let class_str = format!("mingot-simple-grid {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=grid_styles>
+        <div class=class_str style=grid_styles data-mingot-debug=debug_label>
+            {debug.then(|| view! { <style>{LAYOUT_DEBUG_CSS}</style> })}
             {children()}
         </div>
     }
@@ -1,5 +1,7 @@
-use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::theme::{use_below_breakpoint, use_theme, Breakpoint};
+use crate::utils::{
+    add_debug_outline, layout_debug_enabled, LayoutDebugKind, StyleBuilder, LAYOUT_DEBUG_CSS,
+};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -42,12 +44,20 @@ impl GroupJustify {
     }
 }
 
+/// Horizontal flex row of children.
+///
+/// With `stack_below`, the row switches to a full-width column when the
+/// viewport is narrower than that theme breakpoint.
 #[component]
 pub fn Group(
     #[prop(optional, into)] spacing: Option<String>,
     #[prop(optional)] align: Option<GroupAlign>,
     #[prop(optional)] justify: Option<GroupJustify>,
     #[prop(optional)] wrap: bool,
+    #[prop(optional)] stack_below: Option<Breakpoint>,
+    /// Outline this layout and label its spacing, for debugging
+    #[prop(optional)]
+    debug: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
@@ -55,15 +65,26 @@ pub fn Group(
     let theme = use_theme();
     let align = align.unwrap_or(GroupAlign::Center);
     let justify = justify.unwrap_or(GroupJustify::Start);
+    let stacked = stack_below.map(use_below_breakpoint);
+    let debug = layout_debug_enabled(debug);
+    let debug_gap = spacing.clone();
 
     let group_styles = move || {
         let theme_val = theme.get();
         let mut builder = StyleBuilder::new();
 
+        let is_stacked = stacked.is_some_and(|s| s.get());
         builder
             .add("display", "flex")
-            .add("flex-direction", "row")
-            .add("align-items", align.as_str())
+            .add("flex-direction", if is_stacked { "column" } else { "row" })
+            .add(
+                "align-items",
+                if is_stacked {
+                    "stretch"
+                } else {
+                    align.as_str()
+                },
+            )
             .add("justify-content", justify.as_str());
 
         // Spacing
@@ -75,6 +96,10 @@ pub fn Group(
             builder.add("flex-wrap", "wrap");
         }
 
+        if debug {
+            add_debug_outline(&mut builder, LayoutDebugKind::Group, &theme_val);
+        }
+
         if let Some(s) = style.as_ref() {
             return format!("{}; {}", builder.build(), s);
         }
@@ -82,10 +107,20 @@ pub fn Group(
         builder.build()
     };
 
+    let debug_label = move || {
+        debug.then(|| {
+            let gap = debug_gap
+                .clone()
+                .unwrap_or_else(|| theme.get().spacing.md.to_string());
+            LayoutDebugKind::Group.label(&format!("gap {}", gap))
+        })
+    };
+
     let class_str = format!("mingot-group {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=group_styles>
+        <div class=class_str style=group_styles data-mingot-debug=debug_label>
+            {debug.then(|| view! { <style>{LAYOUT_DEBUG_CSS}</style> })}
             {children()}
         </div>
     }
@@ -0,0 +1,188 @@
+use crate::theme::use_theme;
+use crate::utils::{format_count, StyleBuilder};
+use leptos::prelude::*;
+
+/// Corner of the wrapped element where the indicator is placed
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IndicatorPosition {
+    TopStart,
+    #[default]
+    TopEnd,
+    BottomStart,
+    BottomEnd,
+}
+
+impl IndicatorPosition {
+    /// CSS offsets and translation placing the indicator's center on the corner
+    fn placement(self, offset: i32) -> (&'static str, &'static str, String, String) {
+        let inset = format!("{}px", offset);
+        match self {
+            IndicatorPosition::TopStart => ("top", "left", inset, "translate(-50%, -50%)".into()),
+            IndicatorPosition::TopEnd => ("top", "right", inset, "translate(50%, -50%)".into()),
+            IndicatorPosition::BottomStart => {
+                ("bottom", "left", inset, "translate(-50%, 50%)".into())
+            }
+            IndicatorPosition::BottomEnd => {
+                ("bottom", "right", inset, "translate(50%, 50%)".into())
+            }
+        }
+    }
+}
+
+/// Pulse for `processing`, drawn in the badge's `--mingot-indicator-color`
+const PROCESSING_KEYFRAMES: &str = "@keyframes mingot-indicator-processing {
+    0% { box-shadow: 0 0 0 0 color-mix(in srgb, var(--mingot-indicator-color) 60%, transparent); }
+    70% { box-shadow: 0 0 0 6px color-mix(in srgb, var(--mingot-indicator-color) 0%, transparent); }
+    100% { box-shadow: 0 0 0 0 color-mix(in srgb, var(--mingot-indicator-color) 0%, transparent); }
+}";
+
+/// Overlays a dot or count on a corner of its child (icon, avatar, button).
+///
+/// Without a `count` the indicator renders as a plain dot. Counts above
+/// `max_count` are shown capped, e.g. `99+`.
+#[component]
+pub fn Indicator(
+    #[prop(optional, into)] count: Option<Signal<u64>>,
+    #[prop(optional)] max_count: Option<u64>,
+    #[prop(optional)] show_zero: bool,
+    #[prop(optional)] position: Option<IndicatorPosition>,
+    #[prop(optional)] offset: Option<i32>,
+    #[prop(optional, into)] color: Option<String>,
+    #[prop(optional)] size: Option<u32>,
+    #[prop(optional)] processing: bool,
+    #[prop(optional, into)] disabled: Signal<bool>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let theme = use_theme();
+    let max_count = max_count.unwrap_or(99);
+    let position = position.unwrap_or_default();
+    let offset = offset.unwrap_or(0);
+    let color = color.unwrap_or_else(|| "red".to_string());
+    let size = size.unwrap_or(10);
+
+    let label = move || {
+        count.map(|c| {
+            let value = c.get();
+            (value > 0 || show_zero).then(|| format_count(value, max_count))
+        })
+    };
+
+    // Hidden when disabled, or when a count is given but is zero (unless show_zero)
+    let is_visible = move || !disabled.get() && !matches!(label(), Some(None));
+
+    let wrapper_styles = move || {
+        let mut builder = StyleBuilder::new();
+        builder
+            .add("position", "relative")
+            .add("display", "inline-flex");
+
+        if let Some(s) = style.as_ref() {
+            return format!("{}; {}", builder.build(), s);
+        }
+
+        builder.build()
+    };
+
+    let indicator_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let bg_color = scheme_colors
+            .get_color(&color, 6)
+            .unwrap_or_else(|| "#fa5252".to_string());
+        let (vertical, horizontal, inset, transform) = position.placement(offset);
+        let has_label = matches!(label(), Some(Some(_)));
+
+        let mut builder = StyleBuilder::new();
+        builder
+            .add("position", "absolute")
+            .add(vertical, inset.clone())
+            .add(horizontal, inset)
+            .add("transform", transform)
+            .add("z-index", "1")
+            .add("display", "inline-flex")
+            .add("align-items", "center")
+            .add("justify-content", "center")
+            .add("box-sizing", "border-box")
+            .add("min-width", format!("{}px", size))
+            .add("height", format!("{}px", size))
+            .add("border-radius", "9999px")
+            .add("background-color", bg_color.clone())
+            .add("color", scheme_colors.white.clone())
+            .add(
+                "border",
+                format!("2px solid {}", scheme_colors.background.clone()),
+            )
+            .add("white-space", "nowrap")
+            .add("line-height", "1");
+
+        if has_label {
+            builder
+                .add("height", format!("{}px", size + 8))
+                .add("min-width", format!("{}px", size + 8))
+                .add("padding", "0 0.3125rem")
+                .add("font-size", &*theme_val.typography.font_sizes.xs)
+                .add(
+                    "font-weight",
+                    theme_val.typography.font_weights.bold.to_string(),
+                );
+        }
+
+        if processing {
+            builder.add("--mingot-indicator-color", bg_color).add(
+                "animation",
+                "mingot-indicator-processing 1.2s ease-out infinite",
+            );
+        }
+
+        builder.build()
+    };
+
+    let class_str = format!("mingot-indicator {}", class.unwrap_or_default());
+
+    view! {
+        <div class=class_str style=wrapper_styles>
+            {processing.then(|| view! { <style>{PROCESSING_KEYFRAMES}</style> })}
+            {children()}
+            {move || {
+                let indicator_styles = indicator_styles.clone();
+                is_visible().then(|| view! {
+                    <span class="mingot-indicator-badge" style=indicator_styles>
+                        {label().flatten()}
+                    </span>
+                })
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processing_pulse_follows_indicator_color() {
+        assert!(!PROCESSING_KEYFRAMES.contains("rgba"));
+        assert_eq!(
+            PROCESSING_KEYFRAMES
+                .matches("var(--mingot-indicator-color)")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_indicator_position_default() {
+        assert_eq!(IndicatorPosition::default(), IndicatorPosition::TopEnd);
+    }
+
+    #[test]
+    fn test_indicator_position_placement() {
+        let (vertical, horizontal, inset, transform) = IndicatorPosition::BottomStart.placement(4);
+        assert_eq!(vertical, "bottom");
+        assert_eq!(horizontal, "left");
+        assert_eq!(inset, "4px");
+        assert_eq!(transform, "translate(-50%, 50%)");
+    }
+}
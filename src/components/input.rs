@@ -1,5 +1,6 @@
-use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::components::field_wrapper::{FieldAria, FieldIds, FieldWrapper};
+use crate::theme::{use_mingot_config, use_theme, LogicalSide};
+use crate::utils::{grapheme_count, limit_insertion, CharacterCountState, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
 
@@ -19,14 +20,57 @@ pub enum InputSize {
     Xl,
 }
 
+impl InputSize {
+    /// Field height, also the width of a left/right section
+    pub fn height(self) -> &'static str {
+        match self {
+            InputSize::Xs => "1.875rem",
+            InputSize::Sm => "2.25rem",
+            InputSize::Md => "2.625rem",
+            InputSize::Lg => "3.125rem",
+            InputSize::Xl => "3.75rem",
+        }
+    }
+
+    /// Horizontal padding on a side without a section
+    pub fn padding_x(self) -> &'static str {
+        match self {
+            InputSize::Xs => "0.625rem",
+            InputSize::Sm => "0.75rem",
+            InputSize::Md => "0.875rem",
+            InputSize::Lg => "1rem",
+            InputSize::Xl => "1.125rem",
+        }
+    }
+}
+
+/// CSS `padding` for an input of `size`, making room for the sections
+/// present so the text never runs under them
+pub fn input_padding(size: InputSize, left_section: bool, right_section: bool) -> String {
+    let side = |section: bool| {
+        if section {
+            size.height()
+        } else {
+            size.padding_x()
+        }
+    };
+    format!("0 {} 0 {}", side(right_section), side(left_section))
+}
+
 #[component]
 pub fn Input(
+    /// Id of the control, e.g. for an external `<label for>`; also the
+    /// prefix of the description and error ids. Generated when not set.
+    #[prop(optional, into)]
+    id: Option<String>,
     #[prop(optional)] variant: Option<InputVariant>,
     #[prop(optional)] size: Option<InputSize>,
     #[prop(optional, into)] placeholder: Option<String>,
     #[prop(optional, into)] value: Signal<String>,
     #[prop(optional, into)] disabled: Signal<bool>,
-    #[prop(optional, into)] error: Option<String>,
+    /// Error message; a non-empty one turns the border red and renders below
+    #[prop(optional, into)]
+    error: Option<Signal<String>>,
     #[prop(optional)] required: bool,
     #[prop(optional, into)] input_type: Option<String>,
     #[prop(optional)] on_input: Option<Callback<String>>,
@@ -40,15 +84,79 @@ pub fn Input(
     #[prop(optional, into)] max: Option<String>,
     #[prop(optional, into)] pattern: Option<String>,
     #[prop(optional, into)] autocomplete: Option<String>,
-    #[prop(optional, into)] maxlength: Option<String>,
     #[prop(optional, into)] minlength: Option<String>,
+    /// Deprecated: use `max_length`. Read as a `max_length` when that is
+    /// not set; will be removed in the next release.
+    #[prop(optional, into)]
+    maxlength: Option<String>,
+    /// Maximum length in grapheme clusters. Typed or pasted text that
+    /// doesn't fit is cut without touching the rest of the value; longer
+    /// values set through `value` are flagged.
+    #[prop(optional)]
+    max_length: Option<usize>,
+    /// Content drawn inside the border on the left, e.g. an icon. Sits
+    /// at the start of the field, so on the right in RTL themes.
+    #[prop(optional)]
+    left_section: Option<Children>,
+    /// Content drawn inside the border on the right (the left in RTL)
+    #[prop(optional)]
+    right_section: Option<Children>,
+    /// Show a clear button on the right (the left in RTL) while there is text
+    #[prop(optional)]
+    clearable: bool,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(InputVariant::Default);
-    let size = size.unwrap_or(InputSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.input_variant)
+        .unwrap_or(InputVariant::Default);
+    let size = size.or(config.input_size).unwrap_or(InputSize::Md);
     let input_type = input_type.unwrap_or_else(|| "text".to_string());
+    let overrides = StoredValue::new(config.styles);
+    let part = move |name: &str, base: String| overrides.with_value(|o| o.apply(name, base));
+
+    let max_length = max_length.or_else(|| maxlength.and_then(|m| m.trim().parse().ok()));
+
+    // Character counter state, counted in grapheme clusters
+    let char_count = RwSignal::new(grapheme_count(&value.get_untracked()));
+    // What the field held before the current edit, to tell what was inserted
+    let last_value = StoredValue::new(value.get_untracked());
+    Effect::new(move |_| {
+        let current = value.get();
+        char_count.set(grapheme_count(&current));
+        last_value.set_value(current);
+    });
+    let over_limit = move || {
+        max_length.is_some_and(|max| {
+            CharacterCountState::from_count(char_count.get(), max) == CharacterCountState::Exceeded
+        })
+    };
+
+    // The caller's error, else the length limit's
+    let error_message = Signal::derive(move || {
+        let message = error.map(|e| e.get()).unwrap_or_default();
+        if !message.is_empty() {
+            return message;
+        }
+        max_length
+            .filter(|_| over_limit())
+            .map(|max| format!("Must be at most {} characters", max))
+            .unwrap_or_default()
+    });
+    let ids = id.as_deref().map_or_else(FieldIds::generate, FieldIds::new);
+    let has_description = description.is_some();
+    let aria = {
+        let ids = ids.clone();
+        move || error_message.with(|e| FieldAria::new(&ids, has_description, Some(e)))
+    };
+    let aria_invalid = aria.clone();
+
+    // The sections sit at the start and end of the field, which swap
+    // sides in RTL
+    let has_start = left_section.is_some();
+    let has_end = right_section.is_some() || clearable;
+    let input_ref = NodeRef::<leptos::html::Input>::new();
 
-    let error_clone = error.clone();
     let input_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -65,43 +173,22 @@ pub fn Input(
             .add("box-sizing", "border-box");
 
         // Size-based styles
-        match size {
-            InputSize::Xs => {
-                builder
-                    .add("height", "1.875rem")
-                    .add("padding", "0 0.625rem")
-                    .add("font-size", &*theme_val.typography.font_sizes.xs);
-            }
-            InputSize::Sm => {
-                builder
-                    .add("height", "2.25rem")
-                    .add("padding", "0 0.75rem")
-                    .add("font-size", &*theme_val.typography.font_sizes.sm);
-            }
-            InputSize::Md => {
-                builder
-                    .add("height", "2.625rem")
-                    .add("padding", "0 0.875rem")
-                    .add("font-size", &*theme_val.typography.font_sizes.sm);
-            }
-            InputSize::Lg => {
-                builder
-                    .add("height", "3.125rem")
-                    .add("padding", "0 1rem")
-                    .add("font-size", &*theme_val.typography.font_sizes.md);
-            }
-            InputSize::Xl => {
-                builder
-                    .add("height", "3.75rem")
-                    .add("padding", "0 1.125rem")
-                    .add("font-size", &*theme_val.typography.font_sizes.lg);
-            }
-        }
+        let font_size = match size {
+            InputSize::Xs => &theme_val.typography.font_sizes.xs,
+            InputSize::Sm | InputSize::Md => &theme_val.typography.font_sizes.sm,
+            InputSize::Lg => &theme_val.typography.font_sizes.md,
+            InputSize::Xl => &theme_val.typography.font_sizes.lg,
+        };
+        let (has_left, has_right) = theme_val.direction.to_physical(has_start, has_end);
+        builder
+            .add("height", size.height())
+            .add("padding", input_padding(size, has_left, has_right))
+            .add("font-size", &**font_size);
 
         // Variant-based styles
         match variant {
             InputVariant::Default => {
-                let border_color = if error_clone.is_some() {
+                let border_color = if error_message.with(|e| !e.is_empty()) {
                     scheme_colors
                         .get_color("red", 6)
                         .unwrap_or_else(|| "#fa5252".to_string())
@@ -128,8 +215,13 @@ pub fn Input(
                 builder
                     .add("background-color", "transparent")
                     .add("color", scheme_colors.text.clone())
-                    .add("border", "none")
-                    .add("padding", "0");
+                    .add("border", "none");
+                // No padding of its own, only room for the sections
+                let side = |section: bool| if section { size.height() } else { "0" };
+                builder.add(
+                    "padding",
+                    format!("0 {} 0 {}", side(has_right), side(has_left)),
+                );
             }
         }
 
@@ -140,74 +232,144 @@ pub fn Input(
             builder.add("cursor", "text");
         }
 
+        let styles = part("input.input", builder.build());
+
         if let Some(s) = style.as_ref() {
-            return format!("{}; {}", builder.build(), s);
+            return format!("{}; {}", styles, s);
         }
 
-        builder.build()
+        styles
     };
 
     let handle_input = move |ev: ev::Event| {
-        let input_value = event_target_value(&ev);
+        let mut input_value = event_target_value(&ev);
+        // Enforced here rather than with the DOM maxlength, which counts
+        // UTF-16 code units and would split emoji
+        let limited = max_length
+            .and_then(|max| last_value.with_value(|last| limit_insertion(last, &input_value, max)));
+        if let Some((allowed, caret)) = limited {
+            input_value = allowed;
+            if let Some(input) = input_ref.get() {
+                input.set_value(&input_value);
+                let _ = input.set_selection_range(caret, caret);
+            }
+        }
+        last_value.set_value(input_value.clone());
+        char_count.set(grapheme_count(&input_value));
         if let Some(callback) = on_input {
             callback.run(input_value);
         }
     };
 
-    let handle_change = move |ev: ev::Event| {
-        let change_value = event_target_value(&ev);
-        if let Some(callback) = on_change {
-            callback.run(change_value);
+    let handle_clear = move |_: ev::MouseEvent| {
+        if let Some(input) = input_ref.get() {
+            input.set_value("");
+            let _ = input.focus();
+        }
+        last_value.set_value(String::new());
+        char_count.set(0);
+        if let Some(callback) = on_input {
+            callback.run(String::new());
         }
     };
 
-    let label_styles = move || {
+    let section_styles = move |side: LogicalSide| {
         let theme_val = theme.get();
+        let side = theme_val.direction.physical(side).as_str();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        format!(
-            "display: block; margin-bottom: 0.25rem; font-size: {}; font-weight: {}; color: {};",
-            &*theme_val.typography.font_sizes.sm,
-            theme_val.typography.font_weights.medium,
-            scheme_colors.text
+        part(
+            "input.section",
+            format!(
+                "position: absolute; top: 0; bottom: 0; {}: 0; width: {}; \
+                 display: flex; align-items: center; justify-content: center; \
+                 color: {};",
+                side,
+                size.height(),
+                scheme_colors
+                    .get_color("gray", 6)
+                    .unwrap_or_else(|| "#868e96".to_string())
+            ),
         )
     };
 
-    let description_styles = move || {
+    let clear_styles = move || {
         let theme_val = theme.get();
-        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
         format!(
-            "margin-top: 0.25rem; font-size: {}; color: {};",
-            &*theme_val.typography.font_sizes.xs,
-            scheme_colors
-                .get_color("gray", 6)
-                .unwrap_or_else(|| "#868e96".to_string())
+            "background: none; border: none; padding: 0; cursor: pointer; \
+             color: inherit; font-size: {}; line-height: 1; font-family: inherit;",
+            &*theme_val.typography.font_sizes.md
         )
     };
+    let show_clear = move || clearable && char_count.get() > 0 && !disabled.get();
 
-    let error_styles = move || {
+    let handle_change = move |ev: ev::Event| {
+        let change_value = event_target_value(&ev);
+        if let Some(callback) = on_change {
+            callback.run(change_value);
+        }
+    };
+
+    let counter_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        format!(
-            "margin-top: 0.25rem; font-size: {}; color: {};",
-            &*theme_val.typography.font_sizes.xs,
-            scheme_colors
+        let state = max_length
+            .map(|max| CharacterCountState::from_count(char_count.get(), max))
+            .unwrap_or(CharacterCountState::Normal);
+        let color = match state {
+            CharacterCountState::Normal => scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string()),
+            CharacterCountState::Warning | CharacterCountState::Exceeded => scheme_colors
                 .get_color("red", 6)
-                .unwrap_or_else(|| "#fa5252".to_string())
+                .unwrap_or_else(|| "#fa5252".to_string()),
+        };
+        part(
+            "input.counter",
+            format!(
+                "margin-top: 0.25rem; font-size: {}; color: {}; text-align: end;",
+                &*theme_val.typography.font_sizes.xs, color
+            ),
         )
     };
 
     let class_str = format!("mingot-input {}", class.unwrap_or_default());
 
     view! {
-        <div class="mingot-input-wrapper" style="width: 100%;">
-            {label.map(|l| view! {
-                <label style=label_styles>
-                    {l}
-                    {if required { " *" } else { "" }}
-                </label>
+        <FieldWrapper
+            ids=ids.clone()
+            class="mingot-input-wrapper"
+            parts="input"
+            label=label
+            description=description
+            error=error_message
+            required=required
+            footer=Box::new(move || {
+                max_length
+                    .map(|max| view! {
+                        <div class="mingot-input-counter" style=counter_styles>
+                            {move || format!("{}/{}", char_count.get(), max)}
+                        </div>
+                    })
+                    .into_any()
+            })
+        >
+
+            <div class="mingot-input-field" style="position: relative;">
+            {left_section.map(|left| view! {
+                // Decorative: clicks fall through to the input
+                <div
+                    class="mingot-input-section mingot-input-left-section"
+                    style=move || format!("{} pointer-events: none;", section_styles(LogicalSide::Start))
+                >
+                    {left()}
+                </div>
             })}
 
             <input
+                node_ref=input_ref
+                id=ids.control
+                aria-describedby=move || aria().described_by
+                aria-invalid=move || aria_invalid().invalid_attr()
                 type=input_type
                 class=class_str
                 style=input_styles
@@ -222,17 +384,84 @@ pub fn Input(
                 max=max
                 pattern=pattern
                 autocomplete=autocomplete
-                maxlength=maxlength
                 minlength=minlength
             />
 
-            {description.map(|d| view! {
-                <div style=description_styles>{d}</div>
+            {has_end.then(|| view! {
+                <div
+                    class="mingot-input-section mingot-input-right-section"
+                    style=move || section_styles(LogicalSide::End)
+                >
+                    {clearable.then(|| view! {
+                        <button
+                            type="button"
+                            class="mingot-input-clear"
+                            aria-label="Clear"
+                            style=move || {
+                                let display = if show_clear() { "" } else { " display: none;" };
+                                format!("{}{}", clear_styles(), display)
+                            }
+                            on:click=handle_clear
+                        >
+                            "×"
+                        </button>
+                    })}
+                    {right_section.map(|right| view! {
+                        // The clear button takes the spot while it shows
+                        <span style=move || if show_clear() { "display: none;" } else { "display: contents;" }>
+                            {right()}
+                        </span>
+                    })}
+                </div>
             })}
+            </div>
 
-            {error.map(|e| view! {
-                <div style=error_styles>{e}</div>
-            })}
-        </div>
+        </FieldWrapper>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_without_sections() {
+        assert_eq!(
+            input_padding(InputSize::Md, false, false),
+            "0 0.875rem 0 0.875rem"
+        );
+        assert_eq!(
+            input_padding(InputSize::Xs, false, false),
+            "0 0.625rem 0 0.625rem"
+        );
+    }
+
+    #[test]
+    fn test_padding_makes_room_for_sections() {
+        // A section side is padded by the section width, the input height
+        assert_eq!(
+            input_padding(InputSize::Md, true, false),
+            "0 0.875rem 0 2.625rem"
+        );
+        assert_eq!(
+            input_padding(InputSize::Md, false, true),
+            "0 2.625rem 0 0.875rem"
+        );
+        assert_eq!(
+            input_padding(InputSize::Md, true, true),
+            "0 2.625rem 0 2.625rem"
+        );
+    }
+
+    #[test]
+    fn test_padding_follows_size() {
+        assert_eq!(
+            input_padding(InputSize::Xs, true, false),
+            "0 0.625rem 0 1.875rem"
+        );
+        assert_eq!(
+            input_padding(InputSize::Xl, false, true),
+            "0 3.75rem 0 1.125rem"
+        );
     }
 }
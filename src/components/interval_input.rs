@@ -6,6 +6,7 @@ use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
+use std::cmp::Ordering;
 
 /// Interval bounds type
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -165,31 +166,116 @@ impl Interval {
         }
     }
 
-    /// Check if the interval is empty (min > max for closed, min >= max for open)
+    /// Check if the interval is empty (min > max, or min == max unless
+    /// both endpoints are included). `[2, 2]` holds one point; `[2, 2)` none.
     pub fn is_empty(&self) -> bool {
-        match (self.min, self.max) {
-            (Some(min), Some(max)) => {
-                if self.bounds == IntervalBounds::Open {
-                    min >= max
-                } else if self.bounds == IntervalBounds::Closed {
-                    min > max
-                } else {
-                    min >= max
+        let (min, include_min) = self.lower();
+        let (max, include_max) = self.upper();
+        min > max || (min == max && !(include_min && include_max))
+    }
+
+    /// Width of the interval: 0 when empty, infinite when unbounded
+    pub fn width(&self) -> f64 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.upper().0 - self.lower().0
+        }
+    }
+
+    /// Check if interval intersects with another (shares at least one point)
+    pub fn intersects(&self, other: &Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Points in both intervals, or `None` if they share none.
+    /// `[1, 3) ∩ (2, 5]` is `(2, 3)`; `[1, 2] ∩ [2, 3]` is the single point `[2, 2]`.
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let (a, b) = (self.lower(), other.lower());
+        let lower = match a.0.total_cmp(&b.0) {
+            Ordering::Greater => a,
+            Ordering::Less => b,
+            Ordering::Equal => (a.0, a.1 && b.1),
+        };
+        let (a, b) = (self.upper(), other.upper());
+        let upper = match a.0.total_cmp(&b.0) {
+            Ordering::Less => a,
+            Ordering::Greater => b,
+            Ordering::Equal => (a.0, a.1 && b.1),
+        };
+
+        let result = Self::from_endpoints(lower, upper);
+        (!result.is_empty()).then_some(result)
+    }
+
+    /// Points in either interval, in ascending order: one interval when
+    /// they overlap or touch at an included endpoint, two when there is a
+    /// gap, none when both are empty. `(-∞, 5] ∪ [5, ∞)` is `(-∞, ∞)`;
+    /// `[1, 2) ∪ (2, 3]` stays two intervals because 2 is in neither.
+    pub fn union(&self, other: &Interval) -> Vec<Interval> {
+        let mut parts: Vec<&Interval> = [self, other]
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .collect();
+        parts.sort_by(|a, b| {
+            let (a, b) = (a.lower(), b.lower());
+            a.0.total_cmp(&b.0).then(b.1.cmp(&a.1))
+        });
+
+        match parts.as_slice() {
+            [first, second] => {
+                let (first_max, include_first_max) = first.upper();
+                let (second_min, include_second_min) = second.lower();
+                let connected = first_max > second_min
+                    || (first_max == second_min && (include_first_max || include_second_min));
+                if !connected {
+                    return vec![first.normalized(), second.normalized()];
                 }
+
+                let (a, b) = (first.upper(), second.upper());
+                let upper = match a.0.total_cmp(&b.0) {
+                    Ordering::Greater => a,
+                    Ordering::Less => b,
+                    Ordering::Equal => (a.0, a.1 || b.1),
+                };
+                vec![Self::from_endpoints(first.lower(), upper)]
             }
-            _ => false, // Unbounded intervals are never empty
+            _ => parts.into_iter().map(Interval::normalized).collect(),
         }
     }
 
-    /// Check if interval intersects with another
-    pub fn intersects(&self, other: &Interval) -> bool {
-        // Complex logic for all bound combinations
-        let self_min = self.min.unwrap_or(f64::NEG_INFINITY);
-        let self_max = self.max.unwrap_or(f64::INFINITY);
-        let other_min = other.min.unwrap_or(f64::NEG_INFINITY);
-        let other_max = other.max.unwrap_or(f64::INFINITY);
+    /// Lower endpoint and whether it is included; -∞ never is
+    fn lower(&self) -> (f64, bool) {
+        match self.min {
+            Some(min) => (min, self.bounds.includes_left()),
+            None => (f64::NEG_INFINITY, false),
+        }
+    }
 
-        self_min < other_max && other_min < self_max
+    /// Upper endpoint and whether it is included; ∞ never is
+    fn upper(&self) -> (f64, bool) {
+        match self.max {
+            Some(max) => (max, self.bounds.includes_right()),
+            None => (f64::INFINITY, false),
+        }
+    }
+
+    /// Interval from endpoints; infinite ones become unbounded and open
+    fn from_endpoints(lower: (f64, bool), upper: (f64, bool)) -> Interval {
+        let min = (lower.0 != f64::NEG_INFINITY).then_some(lower.0);
+        let max = (upper.0 != f64::INFINITY).then_some(upper.0);
+        let bounds = match (lower.1 && min.is_some(), upper.1 && max.is_some()) {
+            (true, true) => IntervalBounds::Closed,
+            (false, false) => IntervalBounds::Open,
+            (true, false) => IntervalBounds::HalfOpenLeft,
+            (false, true) => IntervalBounds::HalfOpenRight,
+        };
+        Interval::new(min, max, bounds)
+    }
+
+    /// Same set of points, with open brackets on infinite sides
+    fn normalized(&self) -> Interval {
+        Self::from_endpoints(self.lower(), self.upper())
     }
 
     /// Format as mathematical notation
@@ -222,6 +308,54 @@ impl Interval {
             min_str, left_cmp, right_cmp, max_str
         )
     }
+
+    /// Format as LaTeX interval notation, e.g. `[0, 1)` or `(-\infty, 5]`.
+    /// Infinite bounds always take an open bracket.
+    pub fn to_latex(&self) -> String {
+        let left = if self.min.is_some() {
+            self.bounds.left_bracket()
+        } else {
+            "("
+        };
+        let right = if self.max.is_some() {
+            self.bounds.right_bracket()
+        } else {
+            ")"
+        };
+        let min_str = self.min.map_or(r"-\infty".to_string(), format_number);
+        let max_str = self.max.map_or(r"\infty".to_string(), format_number);
+        format!("{}{}, {}{}", left, min_str, max_str, right)
+    }
+
+    /// Format as LaTeX set-builder notation, e.g. `\{x : 0 < x \le 1\}`.
+    /// Infinite bounds drop their side of the inequality.
+    pub fn to_set_builder_latex(&self) -> String {
+        let left_cmp = if self.bounds.includes_left() {
+            r"\le"
+        } else {
+            "<"
+        };
+        let right_cmp = if self.bounds.includes_right() {
+            r"\le"
+        } else {
+            "<"
+        };
+
+        let condition = match (self.min, self.max) {
+            (Some(min), Some(max)) => format!(
+                "{} {} x {} {}",
+                format_number(min),
+                left_cmp,
+                right_cmp,
+                format_number(max)
+            ),
+            (Some(min), None) => format!("{} {} x", format_number(min), left_cmp),
+            (None, Some(max)) => format!("x {} {}", right_cmp, format_number(max)),
+            (None, None) => r"x \in \mathbb{R}".to_string(),
+        };
+
+        format!(r"\{{x : {}\}}", condition)
+    }
 }
 
 /// Format a number, removing trailing zeros
@@ -234,6 +368,39 @@ fn format_number(value: f64) -> String {
     }
 }
 
+/// Width for display, "∞" when unbounded
+fn format_width(width: f64) -> String {
+    if width.is_infinite() {
+        "∞".to_string()
+    } else {
+        format_number(width)
+    }
+}
+
+/// Result of a set operation: its intervals joined by ∪, or ∅ when empty
+fn format_interval_set(parts: &[Interval]) -> String {
+    if parts.is_empty() {
+        return "∅".to_string();
+    }
+    parts
+        .iter()
+        .map(Interval::to_math_string)
+        .collect::<Vec<_>>()
+        .join(" ∪ ")
+}
+
+/// "0.5 ∈ [0, 1]" or "2 ∉ [0, 1]" for a probe value; `None` until it parses
+fn membership_text(interval: &Interval, probe: &str) -> Option<String> {
+    let x = probe.trim().parse::<f64>().ok()?;
+    let symbol = if interval.contains(x) { "∈" } else { "∉" };
+    Some(format!(
+        "{} {} {}",
+        format_number(x),
+        symbol,
+        interval.to_math_string()
+    ))
+}
+
 /// Parse an interval from string notation
 pub fn parse_interval(input: &str) -> Result<Interval, String> {
     let trimmed = input.trim();
@@ -309,6 +476,10 @@ pub enum IntervalFormat {
     Mathematical,
     /// Set notation: {x | a ≤ x ≤ b}
     SetNotation,
+    /// LaTeX interval notation: [a, b)
+    Latex,
+    /// LaTeX set-builder notation: \{x : a \le x < b\}
+    SetBuilderLatex,
 }
 
 /// Interval input component
@@ -361,6 +532,19 @@ pub fn IntervalInput(
     /// Number of decimal places to display
     #[prop(optional, default = 4)]
     _decimal_places: u32,
+
+    /// Show a "Copy LaTeX" button; set-builder formats copy set-builder LaTeX
+    #[prop(optional)]
+    show_copy_latex: bool,
+
+    /// Receives the LaTeX whenever it is copied
+    #[prop(optional, into)]
+    on_copy_latex: Option<Callback<String>>,
+
+    /// Show the width, membership of a probe value, and the intersection
+    /// and union with a second interval typed as text
+    #[prop(optional)]
+    show_operations: bool,
 ) -> impl IntoView {
     let theme = use_theme();
 
@@ -648,10 +832,101 @@ pub fn IntervalInput(
                     match format {
                         IntervalFormat::Mathematical => interval.to_math_string(),
                         IntervalFormat::SetNotation => interval.to_set_notation(),
+                        IntervalFormat::Latex => interval.to_latex(),
+                        IntervalFormat::SetBuilderLatex => interval.to_set_builder_latex(),
                     }
                 }}
             </div>
 
+            {show_copy_latex.then(|| {
+                let copy_latex = move |_| {
+                    let interval = internal_value.get_untracked();
+                    let latex = match format {
+                        IntervalFormat::SetNotation | IntervalFormat::SetBuilderLatex => {
+                            interval.to_set_builder_latex()
+                        }
+                        IntervalFormat::Mathematical | IntervalFormat::Latex => interval.to_latex(),
+                    };
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.navigator().clipboard().write_text(&latex);
+                    }
+                    if let Some(cb) = on_copy_latex {
+                        cb.run(latex);
+                    }
+                };
+                view! {
+                    <div>
+                        <button
+                            type="button"
+                            class="mingot-interval-copy-latex"
+                            style=move || bounds_button_styles(false)
+                            title="Copy the interval as LaTeX"
+                            on:click=copy_latex
+                            disabled=disabled
+                        >
+                            "Copy LaTeX"
+                        </button>
+                    </div>
+                }
+            })}
+
+            {show_operations.then(|| {
+                let probe = RwSignal::new(String::new());
+                let other = RwSignal::new(String::new());
+                view! {
+                    <div class="mingot-interval-operations" style=container_styles>
+                        <div style=preview_styles>
+                            {move || format!("width = {}", format_width(internal_value.get().width()))}
+                        </div>
+                        <div style=input_row_styles>
+                            <span style=preview_styles>"x ="</span>
+                            <input
+                                type="text"
+                                style=input_styles
+                                placeholder="probe"
+                                prop:value=move || probe.get()
+                                disabled=disabled
+                                on:input=move |ev| probe.set(event_target_value(&ev))
+                            />
+                            <span class="mingot-interval-membership" style=preview_styles>
+                                {move || membership_text(&internal_value.get(), &probe.get())}
+                            </span>
+                        </div>
+                        <div style=input_row_styles>
+                            <span style=preview_styles>"J ="</span>
+                            <input
+                                type="text"
+                                style=move || format!("{}; width: 8rem;", input_styles())
+                                placeholder="[a, b)"
+                                prop:value=move || other.get()
+                                disabled=disabled
+                                on:input=move |ev| other.set(event_target_value(&ev))
+                            />
+                        </div>
+                        {move || {
+                            let text = other.get();
+                            if text.trim().is_empty() {
+                                return None;
+                            }
+                            Some(match parse_interval(&text) {
+                                Ok(j) => {
+                                    let i = internal_value.get();
+                                    let intersection: Vec<Interval> = i.intersection(&j).into_iter().collect();
+                                    view! {
+                                        <div style=preview_styles>
+                                            <div>{format!("I ∩ J = {}", format_interval_set(&intersection))}</div>
+                                            <div>{format!("I ∪ J = {}", format_interval_set(&i.union(&j)))}</div>
+                                        </div>
+                                    }
+                                    .into_any()
+                                }
+                                Err(e) => view! { <div style=error_styles>{e}</div> }.into_any(),
+                            })
+                        }}
+                    </div>
+                }
+            })}
+
             {description.map(|d| view! {
                 <div style=description_styles>{d}</div>
             })}
@@ -787,4 +1062,331 @@ mod tests {
         assert!(from_neg_inf.contains(10.0));
         assert!(!from_neg_inf.contains(11.0));
     }
+
+    #[test]
+    fn test_to_latex_finite_bounds() {
+        assert_eq!(Interval::closed(0.0, 1.0).to_latex(), "[0, 1]");
+        assert_eq!(Interval::open(0.0, 1.0).to_latex(), "(0, 1)");
+        assert_eq!(Interval::half_open_left(0.0, 1.5).to_latex(), "[0, 1.5)");
+        assert_eq!(Interval::half_open_right(-2.0, 1.0).to_latex(), "(-2, 1]");
+    }
+
+    #[test]
+    fn test_to_latex_infinite_bounds() {
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, true).to_latex(),
+            r"(-\infty, 5]"
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, false).to_latex(),
+            r"(-\infty, 5)"
+        );
+        assert_eq!(
+            Interval::to_pos_infinity(0.0, true).to_latex(),
+            r"[0, \infty)"
+        );
+        assert_eq!(
+            Interval::to_pos_infinity(0.0, false).to_latex(),
+            r"(0, \infty)"
+        );
+        assert_eq!(
+            Interval::new(None, None, IntervalBounds::Open).to_latex(),
+            r"(-\infty, \infty)"
+        );
+        // Closed bounds never put a square bracket on an infinite side
+        assert_eq!(
+            Interval::new(None, None, IntervalBounds::Closed).to_latex(),
+            r"(-\infty, \infty)"
+        );
+        assert_eq!(
+            Interval::new(Some(1.0), None, IntervalBounds::Closed).to_latex(),
+            r"[1, \infty)"
+        );
+    }
+
+    #[test]
+    fn test_to_set_builder_latex() {
+        assert_eq!(
+            Interval::closed(0.0, 1.0).to_set_builder_latex(),
+            r"\{x : 0 \le x \le 1\}"
+        );
+        assert_eq!(
+            Interval::half_open_right(0.0, 1.0).to_set_builder_latex(),
+            r"\{x : 0 < x \le 1\}"
+        );
+        assert_eq!(
+            Interval::half_open_left(0.0, 1.0).to_set_builder_latex(),
+            r"\{x : 0 \le x < 1\}"
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, true).to_set_builder_latex(),
+            r"\{x : x \le 5\}"
+        );
+        assert_eq!(
+            Interval::to_pos_infinity(0.0, false).to_set_builder_latex(),
+            r"\{x : 0 < x\}"
+        );
+        assert_eq!(
+            Interval::new(None, None, IntervalBounds::Open).to_set_builder_latex(),
+            r"\{x : x \in \mathbb{R}\}"
+        );
+    }
+
+    const ALL_BOUNDS: [IntervalBounds; 4] = [
+        IntervalBounds::Closed,
+        IntervalBounds::Open,
+        IntervalBounds::HalfOpenLeft,
+        IntervalBounds::HalfOpenRight,
+    ];
+
+    /// Bounds with the given left and right inclusion
+    fn bounds_of(include_left: bool, include_right: bool) -> IntervalBounds {
+        match (include_left, include_right) {
+            (true, true) => IntervalBounds::Closed,
+            (false, false) => IntervalBounds::Open,
+            (true, false) => IntervalBounds::HalfOpenLeft,
+            (false, true) => IntervalBounds::HalfOpenRight,
+        }
+    }
+
+    #[test]
+    fn test_degenerate_intervals() {
+        assert!(!Interval::closed(2.0, 2.0).is_empty());
+        assert!(Interval::half_open_left(2.0, 2.0).is_empty());
+        assert!(Interval::half_open_right(2.0, 2.0).is_empty());
+        assert!(Interval::open(2.0, 2.0).is_empty());
+        assert!(!Interval::from_neg_infinity(2.0, false).is_empty());
+    }
+
+    #[test]
+    fn test_width() {
+        assert_eq!(Interval::half_open_left(1.0, 3.0).width(), 2.0);
+        assert_eq!(Interval::closed(2.0, 2.0).width(), 0.0);
+        assert_eq!(Interval::closed(3.0, 1.0).width(), 0.0);
+        assert_eq!(Interval::to_pos_infinity(0.0, true).width(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_contains_at_boundaries() {
+        for bounds in ALL_BOUNDS {
+            let interval = Interval::new(Some(1.0), Some(3.0), bounds);
+            assert_eq!(interval.contains(1.0), bounds.includes_left(), "{bounds:?}");
+            assert_eq!(
+                interval.contains(3.0),
+                bounds.includes_right(),
+                "{bounds:?}"
+            );
+            assert!(interval.contains(2.0));
+            assert!(!interval.contains(0.999) && !interval.contains(3.001));
+        }
+    }
+
+    #[test]
+    fn test_intersection_of_overlapping_intervals() {
+        // [1, 3) ∩ (2, 5] = (2, 3)
+        assert_eq!(
+            Interval::half_open_left(1.0, 3.0).intersection(&Interval::half_open_right(2.0, 5.0)),
+            Some(Interval::open(2.0, 3.0))
+        );
+
+        // The overlap takes its left end from b and its right end from a
+        for a_bounds in ALL_BOUNDS {
+            for b_bounds in ALL_BOUNDS {
+                let a = Interval::new(Some(1.0), Some(3.0), a_bounds);
+                let b = Interval::new(Some(2.0), Some(5.0), b_bounds);
+                let expected = Interval::new(
+                    Some(2.0),
+                    Some(3.0),
+                    bounds_of(b_bounds.includes_left(), a_bounds.includes_right()),
+                );
+                assert_eq!(
+                    a.intersection(&b),
+                    Some(expected.clone()),
+                    "{a_bounds:?} {b_bounds:?}"
+                );
+                assert_eq!(
+                    b.intersection(&a),
+                    Some(expected),
+                    "{a_bounds:?} {b_bounds:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_intersection_of_touching_intervals() {
+        // [1, 2] ∩ [2, 3] is the single point 2, not empty
+        assert_eq!(
+            Interval::closed(1.0, 2.0).intersection(&Interval::closed(2.0, 3.0)),
+            Some(Interval::closed(2.0, 2.0))
+        );
+
+        for a_bounds in ALL_BOUNDS {
+            for b_bounds in ALL_BOUNDS {
+                let a = Interval::new(Some(1.0), Some(2.0), a_bounds);
+                let b = Interval::new(Some(2.0), Some(3.0), b_bounds);
+                let shares_two = a_bounds.includes_right() && b_bounds.includes_left();
+                assert_eq!(a.intersects(&b), shares_two, "{a_bounds:?} {b_bounds:?}");
+                assert_eq!(
+                    a.intersection(&b),
+                    shares_two.then(|| Interval::closed(2.0, 2.0)),
+                    "{a_bounds:?} {b_bounds:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_intersection_with_shared_endpoint_and_nesting() {
+        // Same left end: included only if both include it
+        assert_eq!(
+            Interval::half_open_left(1.0, 4.0).intersection(&Interval::open(1.0, 3.0)),
+            Some(Interval::open(1.0, 3.0))
+        );
+        assert_eq!(
+            Interval::closed(0.0, 10.0).intersection(&Interval::half_open_left(2.0, 3.0)),
+            Some(Interval::half_open_left(2.0, 3.0))
+        );
+        assert_eq!(
+            Interval::closed(0.0, 1.0).intersection(&Interval::closed(5.0, 6.0)),
+            None
+        );
+        assert_eq!(
+            Interval::closed(3.0, 1.0).intersection(&Interval::closed(0.0, 5.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_intersection_with_infinite_bounds() {
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, true)
+                .intersection(&Interval::to_pos_infinity(5.0, true)),
+            Some(Interval::closed(5.0, 5.0))
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, true)
+                .intersection(&Interval::to_pos_infinity(5.0, false)),
+            None
+        );
+        assert_eq!(
+            Interval::new(None, None, IntervalBounds::Open)
+                .intersection(&Interval::to_pos_infinity(0.0, true)),
+            Some(Interval::to_pos_infinity(0.0, true))
+        );
+        // An infinite side is always open, whatever the stored bounds say
+        assert_eq!(
+            Interval::new(None, Some(2.0), IntervalBounds::Closed).intersection(&Interval::new(
+                None,
+                None,
+                IntervalBounds::Closed
+            )),
+            Some(Interval::from_neg_infinity(2.0, true))
+        );
+    }
+
+    #[test]
+    fn test_union_of_touching_intervals() {
+        for a_bounds in ALL_BOUNDS {
+            for b_bounds in ALL_BOUNDS {
+                let a = Interval::new(Some(1.0), Some(2.0), a_bounds);
+                let b = Interval::new(Some(2.0), Some(3.0), b_bounds);
+                let union = a.union(&b);
+                if a_bounds.includes_right() || b_bounds.includes_left() {
+                    let merged = Interval::new(
+                        Some(1.0),
+                        Some(3.0),
+                        bounds_of(a_bounds.includes_left(), b_bounds.includes_right()),
+                    );
+                    assert_eq!(union, vec![merged], "{a_bounds:?} {b_bounds:?}");
+                } else {
+                    // 2 is in neither, so [1, 2) ∪ (2, 3] keeps its gap
+                    assert_eq!(
+                        union,
+                        vec![a.clone(), b.clone()],
+                        "{a_bounds:?} {b_bounds:?}"
+                    );
+                }
+                assert_eq!(b.union(&a), union, "{a_bounds:?} {b_bounds:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_union_of_overlapping_and_disjoint_intervals() {
+        assert_eq!(
+            Interval::half_open_left(1.0, 3.0).union(&Interval::half_open_right(2.0, 5.0)),
+            vec![Interval::closed(1.0, 5.0)]
+        );
+        // Same left end: included if either includes it
+        assert_eq!(
+            Interval::open(1.0, 2.0).union(&Interval::half_open_left(1.0, 3.0)),
+            vec![Interval::half_open_left(1.0, 3.0)]
+        );
+        assert_eq!(
+            Interval::closed(0.0, 10.0).union(&Interval::open(2.0, 3.0)),
+            vec![Interval::closed(0.0, 10.0)]
+        );
+        // Disjoint pieces come back in ascending order
+        assert_eq!(
+            Interval::closed(5.0, 6.0).union(&Interval::open(1.0, 2.0)),
+            vec![Interval::open(1.0, 2.0), Interval::closed(5.0, 6.0)]
+        );
+    }
+
+    #[test]
+    fn test_union_with_infinite_bounds() {
+        let everything = Interval::new(None, None, IntervalBounds::Open);
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, true).union(&Interval::to_pos_infinity(5.0, true)),
+            vec![everything.clone()]
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, false).union(&Interval::to_pos_infinity(5.0, true)),
+            vec![everything]
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(5.0, false).union(&Interval::to_pos_infinity(5.0, false)),
+            vec![
+                Interval::from_neg_infinity(5.0, false),
+                Interval::to_pos_infinity(5.0, false)
+            ]
+        );
+        assert_eq!(
+            Interval::from_neg_infinity(0.0, true).union(&Interval::closed(-3.0, 4.0)),
+            vec![Interval::from_neg_infinity(4.0, true)]
+        );
+    }
+
+    #[test]
+    fn test_union_with_empty_intervals() {
+        let empty = Interval::open(2.0, 2.0);
+        assert_eq!(
+            empty.union(&Interval::closed(0.0, 1.0)),
+            vec![Interval::closed(0.0, 1.0)]
+        );
+        assert_eq!(
+            empty.union(&Interval::closed(3.0, 1.0)),
+            Vec::<Interval>::new()
+        );
+    }
+
+    #[test]
+    fn test_operation_display() {
+        assert_eq!(format_interval_set(&[]), "∅");
+        let parts = Interval::closed(0.0, 1.0).union(&Interval::open(2.0, 3.0));
+        assert_eq!(format_interval_set(&parts), "[0, 1] ∪ (2, 3)");
+        assert_eq!(format_width(f64::INFINITY), "∞");
+
+        let interval = Interval::half_open_left(0.0, 1.0);
+        assert_eq!(
+            membership_text(&interval, "0"),
+            Some("0 ∈ [0, 1)".to_string())
+        );
+        assert_eq!(
+            membership_text(&interval, " 1 "),
+            Some("1 ∉ [0, 1)".to_string())
+        );
+        assert_eq!(membership_text(&interval, "x"), None);
+    }
 }
@@ -3,9 +3,11 @@
 //! Supports arbitrary-precision matrix operations with keyboard navigation,
 //! row/column manipulation, and operation previews.
 
+use crate::components::complex_number_input::{ComplexNumber, PolarAngleUnit};
 use crate::components::input::InputSize;
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{EditHistory, HistoryShortcut, StyleBuilder};
+use crate::validation::ValidationError;
 use leptos::ev;
 use leptos::prelude::*;
 
@@ -332,6 +334,887 @@ impl Matrix {
             .collect();
         format!("{{{}}}", rows.join(", "))
     }
+
+    /// Format as CSV, one row per line, for pasting into a spreadsheet.
+    /// Values keep full precision.
+    pub fn to_csv(&self) -> String {
+        self.data
+            .iter()
+            .map(|row| row.iter().map(f64::to_string).collect::<Vec<_>>().join(","))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Parse tab- or comma-separated rows, as copied from a spreadsheet.
+    /// Tabs win when both appear. Ragged rows and non-numeric cells are
+    /// rejected with their position.
+    pub fn from_delimited(text: &str) -> Result<Matrix, ValidationError> {
+        let lines: Vec<&str> = text
+            .trim_end_matches(['\r', '\n'])
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .collect();
+        if lines.iter().all(|line| line.trim().is_empty()) {
+            return Err(ValidationError::custom("Nothing to paste"));
+        }
+        let delimiter = if text.contains('\t') { '\t' } else { ',' };
+
+        let mut data = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let row = line
+                .split(delimiter)
+                .enumerate()
+                .map(|(j, cell)| {
+                    let cell = cell.trim().trim_matches('"').trim();
+                    cell.parse::<f64>().map_err(|_| {
+                        ValidationError::custom(format!(
+                            "Row {}, column {}: \"{}\" is not a number",
+                            i + 1,
+                            j + 1,
+                            cell
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<f64>, _>>()?;
+
+            if let Some(first) = data.first().map(Vec::len) {
+                if row.len() != first {
+                    return Err(ValidationError::custom(format!(
+                        "Row {} has {} values, but row 1 has {}",
+                        i + 1,
+                        row.len(),
+                        first
+                    )));
+                }
+            }
+            data.push(row);
+        }
+
+        let rows = data.len();
+        let cols = data[0].len();
+        Ok(Matrix { data, rows, cols })
+    }
+}
+
+/// `matrix` with `block` written over it, its top-left corner at
+/// (`row`, `col`); an error if the block runs past the edge
+fn paste_into(
+    matrix: &Matrix,
+    block: &Matrix,
+    row: usize,
+    col: usize,
+) -> Result<Matrix, ValidationError> {
+    if row + block.rows() > matrix.rows() || col + block.cols() > matrix.cols() {
+        return Err(ValidationError::custom(format!(
+            "A {}×{} block doesn't fit at row {}, column {} of a {}×{} matrix",
+            block.rows(),
+            block.cols(),
+            row + 1,
+            col + 1,
+            matrix.rows(),
+            matrix.cols()
+        )));
+    }
+    let mut result = matrix.clone();
+    for i in 0..block.rows() {
+        for j in 0..block.cols() {
+            result.set(row + i, col + j, block.data[i][j]);
+        }
+    }
+    Ok(result)
+}
+
+/// Pivots smaller than this fraction of the matrix's largest entry are
+/// treated as zero, so a matrix in nanometers and the same matrix in
+/// meters are equally singular
+const PIVOT_EPSILON: f64 = 1e-10;
+
+/// Default for `MatrixInput`'s `singular_tolerance` on `|det|`
+const DEFAULT_SINGULAR_TOLERANCE: f64 = 1e-10;
+
+/// Absolute pivot threshold for a matrix whose largest entry has magnitude
+/// `scale`. It is zero for a zero matrix, so compare with `<=`.
+fn pivot_tolerance(scale: f64) -> f64 {
+    PIVOT_EPSILON * scale
+}
+
+/// LU factorization with partial pivoting: `P·A = L·U`
+#[derive(Clone, Debug, PartialEq)]
+pub struct LuDecomposition {
+    /// Unit lower-triangular factor
+    pub l: Matrix,
+    /// Upper-triangular factor
+    pub u: Matrix,
+    /// Row permutation: row `i` of `P·A` is row `permutation[i]` of `A`
+    pub permutation: Vec<usize>,
+    /// Number of row swaps made while pivoting
+    pub swaps: usize,
+}
+
+impl LuDecomposition {
+    /// The permutation as a matrix `P`
+    pub fn p(&self) -> Matrix {
+        let n = self.permutation.len();
+        let mut p = Matrix::zeros(n, n);
+        for (i, &source) in self.permutation.iter().enumerate() {
+            p.set(i, source, 1.0);
+        }
+        p
+    }
+}
+
+/// QR factorization `A = Q·R` by Householder reflections
+#[derive(Clone, Debug, PartialEq)]
+pub struct QrDecomposition {
+    /// Orthogonal factor (rows × rows)
+    pub q: Matrix,
+    /// Upper-triangular factor (rows × cols)
+    pub r: Matrix,
+}
+
+/// Why a matrix could not be decomposed
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecompositionError {
+    /// LU needs a square matrix
+    NotSquare { rows: usize, cols: usize },
+    /// No usable pivot in this column: the matrix is singular
+    Singular { column: usize },
+    /// The matrix has no entries
+    Empty,
+}
+
+impl std::fmt::Display for DecompositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotSquare { rows, cols } => write!(
+                f,
+                "LU decomposition needs a square matrix, but this one is {}×{}",
+                rows, cols
+            ),
+            Self::Singular { column } => write!(
+                f,
+                "The matrix is singular: column {} has no nonzero pivot",
+                column + 1
+            ),
+            Self::Empty => write!(f, "The matrix is empty"),
+        }
+    }
+}
+
+impl std::error::Error for DecompositionError {}
+
+/// Why eigenvalues could not be computed
+#[derive(Clone, Debug, PartialEq)]
+pub enum EigenvalueError {
+    /// Eigenvalues need a square matrix
+    NotSquare { rows: usize, cols: usize },
+    /// The matrix has no entries
+    Empty,
+    /// Non-symmetric matrices are only solved up to 3×3
+    Unsupported { size: usize },
+}
+
+impl std::fmt::Display for EigenvalueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotSquare { rows, cols } => write!(
+                f,
+                "Eigenvalues need a square matrix, but this one is {}×{}",
+                rows, cols
+            ),
+            Self::Empty => write!(f, "The matrix is empty"),
+            Self::Unsupported { size } => write!(
+                f,
+                "Eigenvalues of a non-symmetric {}×{} matrix are not supported",
+                size, size
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EigenvalueError {}
+
+/// Jacobi sweeps before giving up on convergence
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+/// Decomposition the operations panel can display
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatrixDecomposition {
+    Lu,
+    Qr,
+}
+
+impl MatrixDecomposition {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MatrixDecomposition::Lu => "LU",
+            MatrixDecomposition::Qr => "QR",
+        }
+    }
+}
+
+impl Matrix {
+    /// Matrix product `self · other`, or `None` if the shapes don't match
+    pub fn multiply(&self, other: &Matrix) -> Option<Matrix> {
+        if self.cols != other.rows {
+            return None;
+        }
+        let mut result = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                let sum = (0..self.cols)
+                    .map(|k| self.data[i][k] * other.data[k][j])
+                    .sum();
+                result.set(i, j, sum);
+            }
+        }
+        Some(result)
+    }
+
+    /// LU decomposition with partial pivoting (Doolittle form)
+    #[allow(clippy::needless_range_loop)]
+    pub fn lu(&self) -> Result<LuDecomposition, DecompositionError> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(DecompositionError::Empty);
+        }
+        if !self.is_square() {
+            return Err(DecompositionError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let n = self.rows;
+        let tolerance = pivot_tolerance(self.max_abs());
+        let mut u = self.data.clone();
+        let mut l = vec![vec![0.0; n]; n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            // Largest remaining entry in this column becomes the pivot
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| u[a][k].abs().total_cmp(&u[b][k].abs()))
+                .unwrap_or(k);
+            if u[pivot_row][k].abs() <= tolerance {
+                return Err(DecompositionError::Singular { column: k });
+            }
+
+            if pivot_row != k {
+                u.swap(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                // Multipliers already found belong to the swapped rows
+                for j in 0..k {
+                    let tmp = l[k][j];
+                    l[k][j] = l[pivot_row][j];
+                    l[pivot_row][j] = tmp;
+                }
+                swaps += 1;
+            }
+
+            l[k][k] = 1.0;
+            for i in (k + 1)..n {
+                let factor = u[i][k] / u[k][k];
+                l[i][k] = factor;
+                for j in k..n {
+                    u[i][j] -= factor * u[k][j];
+                }
+                // Exact zero below the pivot, not rounding noise
+                u[i][k] = 0.0;
+            }
+        }
+
+        Ok(LuDecomposition {
+            l: Matrix {
+                data: l,
+                rows: n,
+                cols: n,
+            },
+            u: Matrix {
+                data: u,
+                rows: n,
+                cols: n,
+            },
+            permutation,
+            swaps,
+        })
+    }
+
+    /// QR decomposition by Householder reflections; works for any shape
+    #[allow(clippy::needless_range_loop)]
+    pub fn qr(&self) -> Result<QrDecomposition, DecompositionError> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(DecompositionError::Empty);
+        }
+
+        let m = self.rows;
+        let n = self.cols;
+        let tolerance = pivot_tolerance(self.max_abs());
+        let mut r = self.data.clone();
+        let mut q = Matrix::identity(m).data;
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            let norm = (k..m).map(|i| r[i][k] * r[i][k]).sum::<f64>().sqrt();
+            if norm <= tolerance {
+                continue;
+            }
+
+            // Reflect column k onto -sign(r[k][k])·‖x‖·e_k to avoid cancellation
+            let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..m).map(|i| r[i][k]).collect();
+            v[0] -= alpha;
+            let v_norm_sq: f64 = v.iter().map(|x| x * x).sum();
+            if v_norm_sq <= tolerance * tolerance {
+                continue;
+            }
+
+            // R ← H·R
+            for j in 0..n {
+                let dot: f64 = (k..m).map(|i| v[i - k] * r[i][j]).sum();
+                let scale = 2.0 * dot / v_norm_sq;
+                for i in k..m {
+                    r[i][j] -= scale * v[i - k];
+                }
+            }
+            // Q ← Q·H
+            for i in 0..m {
+                let dot: f64 = (k..m).map(|j| q[i][j] * v[j - k]).sum();
+                let scale = 2.0 * dot / v_norm_sq;
+                for j in k..m {
+                    q[i][j] -= scale * v[j - k];
+                }
+            }
+            for i in (k + 1)..m {
+                r[i][k] = 0.0;
+            }
+        }
+
+        Ok(QrDecomposition {
+            q: Matrix {
+                data: q,
+                rows: m,
+                cols: m,
+            },
+            r: Matrix {
+                data: r,
+                rows: m,
+                cols: n,
+            },
+        })
+    }
+
+    /// Largest absolute entry
+    fn max_abs(&self) -> f64 {
+        self.data
+            .iter()
+            .flatten()
+            .fold(0.0, |max, x| f64::max(max, x.abs()))
+    }
+
+    /// Whether the matrix equals its transpose, entry by entry within `tolerance`
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        self.is_square()
+            && (0..self.rows)
+                .all(|i| (0..i).all(|j| (self.data[i][j] - self.data[j][i]).abs() <= tolerance))
+    }
+
+    /// Eigenvalues, largest real part first, with complex conjugate pairs
+    /// as `a + bi` then `a - bi`.
+    ///
+    /// Symmetric matrices of any size use the Jacobi method and always have
+    /// real eigenvalues. Other matrices are solved in closed form up to 3×3
+    /// and may have complex eigenvalues, e.g. a rotation by θ has `e^{±iθ}`.
+    pub fn eigenvalues(&self) -> Result<Vec<ComplexNumber>, EigenvalueError> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(EigenvalueError::Empty);
+        }
+        if !self.is_square() {
+            return Err(EigenvalueError::NotSquare {
+                rows: self.rows,
+                cols: self.cols,
+            });
+        }
+
+        let mut values = if self.is_symmetric(pivot_tolerance(self.max_abs())) {
+            self.jacobi_eigenvalues()
+                .into_iter()
+                .map(|re| ComplexNumber::new(re, 0.0))
+                .collect()
+        } else {
+            match self.rows {
+                1 => vec![ComplexNumber::new(self.data[0][0], 0.0)],
+                2 => quadratic_roots(
+                    -self.data[0][0] - self.data[1][1],
+                    self.determinant().unwrap_or(0.0),
+                ),
+                3 => {
+                    let d = &self.data;
+                    let principal_minors = d[0][0] * d[1][1] - d[0][1] * d[1][0]
+                        + d[0][0] * d[2][2]
+                        - d[0][2] * d[2][0]
+                        + d[1][1] * d[2][2]
+                        - d[1][2] * d[2][1];
+                    cubic_roots(
+                        -self.trace().unwrap_or(0.0),
+                        principal_minors,
+                        -self.determinant().unwrap_or(0.0),
+                    )
+                }
+                size => return Err(EigenvalueError::Unsupported { size }),
+            }
+        };
+
+        values.sort_by(|a, b| {
+            b.real
+                .total_cmp(&a.real)
+                .then(b.imaginary.total_cmp(&a.imaginary))
+        });
+        Ok(values)
+    }
+
+    /// Diagonal of the cyclic Jacobi iteration on a symmetric matrix
+    #[allow(clippy::needless_range_loop)]
+    fn jacobi_eigenvalues(&self) -> Vec<f64> {
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let scale = self.frobenius_norm().max(f64::MIN_POSITIVE);
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let off_diagonal: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum::<f64>()
+                .sqrt();
+            if off_diagonal <= 1e-14 * scale {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    // Rotation angle that zeroes a[p][q]
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[k][p], a[k][q]);
+                        a[k][p] = c * akp - s * akq;
+                        a[k][q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[p][k], a[q][k]);
+                        a[p][k] = c * apk - s * aqk;
+                        a[q][k] = s * apk + c * aqk;
+                    }
+                }
+            }
+        }
+
+        (0..n).map(|i| a[i][i]).collect()
+    }
+
+    /// Inverse by Gauss-Jordan elimination, or `None` if the matrix is not
+    /// square or is singular (`|det| ≤ tolerance`)
+    #[allow(clippy::needless_range_loop)]
+    pub fn inverse(&self, tolerance: f64) -> Option<Matrix> {
+        let det = self.determinant()?;
+        if self.rows == 0 || det.abs() <= tolerance {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut inv = Matrix::identity(n).data;
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&x, &y| a[x][k].abs().total_cmp(&a[y][k].abs()))
+                .unwrap_or(k);
+            if a[pivot_row][k] == 0.0 {
+                return None;
+            }
+            a.swap(k, pivot_row);
+            inv.swap(k, pivot_row);
+
+            let pivot = a[k][k];
+            for j in 0..n {
+                a[k][j] /= pivot;
+                inv[k][j] /= pivot;
+            }
+            for i in (0..n).filter(|&i| i != k) {
+                let factor = a[i][k];
+                if factor == 0.0 {
+                    continue;
+                }
+                for j in 0..n {
+                    a[i][j] -= factor * a[k][j];
+                    inv[i][j] -= factor * inv[k][j];
+                }
+            }
+        }
+
+        Some(Matrix {
+            data: inv,
+            rows: n,
+            cols: n,
+        })
+    }
+}
+
+/// Number type of the cells in a [`MatrixInput`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MatrixElementType {
+    /// `f64` cells backed by [`Matrix`]
+    #[default]
+    Real,
+    /// Complex cells backed by [`ComplexMatrix`], typed like in ComplexNumberInput
+    Complex,
+}
+
+/// Which transpose the complex operations panel shows
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransposeKind {
+    /// Aᵀ: rows become columns
+    Plain,
+    /// Aᴴ: transpose with every entry conjugated
+    Conjugate,
+}
+
+impl TransposeKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransposeKind::Plain => "Aᵀ",
+            TransposeKind::Conjugate => "Aᴴ",
+        }
+    }
+}
+
+/// A matrix with complex entries, e.g. a quantum gate or a DFT matrix
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComplexMatrix {
+    /// Matrix data stored in row-major order
+    data: Vec<Vec<ComplexNumber>>,
+    /// Number of rows
+    rows: usize,
+    /// Number of columns
+    cols: usize,
+}
+
+impl Default for ComplexMatrix {
+    fn default() -> Self {
+        Self::zeros(3, 3)
+    }
+}
+
+impl From<&Matrix> for ComplexMatrix {
+    fn from(matrix: &Matrix) -> Self {
+        Self {
+            data: matrix
+                .data
+                .iter()
+                .map(|row| row.iter().map(|&v| ComplexNumber::new(v, 0.0)).collect())
+                .collect(),
+            rows: matrix.rows,
+            cols: matrix.cols,
+        }
+    }
+}
+
+impl ComplexMatrix {
+    /// Create a new matrix with given dimensions filled with zeros
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        let data = vec![vec![ComplexNumber::new(0.0, 0.0); cols]; rows];
+        Self { data, rows, cols }
+    }
+
+    /// Create an identity matrix
+    pub fn identity(size: usize) -> Self {
+        Self::from(&Matrix::identity(size))
+    }
+
+    /// Create a matrix from a 2D vector, or `None` if the rows are ragged
+    pub fn from_vec(data: Vec<Vec<ComplexNumber>>) -> Option<Self> {
+        let rows = data.len();
+        let cols = data.first().map_or(0, Vec::len);
+        if !data.iter().all(|row| row.len() == cols) {
+            return None;
+        }
+        Some(Self { data, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Get a value at (row, col)
+    pub fn get(&self, row: usize, col: usize) -> Option<ComplexNumber> {
+        self.data.get(row).and_then(|r| r.get(col).copied())
+    }
+
+    /// Set a value at (row, col)
+    pub fn set(&mut self, row: usize, col: usize, value: ComplexNumber) {
+        if row < self.rows && col < self.cols {
+            self.data[row][col] = value;
+        }
+    }
+
+    /// Add a row of zeros at the specified index
+    pub fn add_row(&mut self, index: usize) {
+        if index <= self.rows {
+            self.data
+                .insert(index, vec![ComplexNumber::new(0.0, 0.0); self.cols]);
+            self.rows += 1;
+        }
+    }
+
+    /// Add a column of zeros at the specified index
+    pub fn add_col(&mut self, index: usize) {
+        if index <= self.cols {
+            for row in &mut self.data {
+                row.insert(index, ComplexNumber::new(0.0, 0.0));
+            }
+            self.cols += 1;
+        }
+    }
+
+    /// Remove a row at the specified index
+    pub fn remove_row(&mut self, index: usize) {
+        if index < self.rows && self.rows > 1 {
+            self.data.remove(index);
+            self.rows -= 1;
+        }
+    }
+
+    /// Remove a column at the specified index
+    pub fn remove_col(&mut self, index: usize) {
+        if index < self.cols && self.cols > 1 {
+            for row in &mut self.data {
+                row.remove(index);
+            }
+            self.cols -= 1;
+        }
+    }
+
+    /// Sum of the diagonal, for square matrices
+    pub fn trace(&self) -> Option<ComplexNumber> {
+        if !self.is_square() {
+            return None;
+        }
+        Some((0..self.rows).fold(ComplexNumber::new(0.0, 0.0), |sum, i| {
+            sum.add(&self.data[i][i])
+        }))
+    }
+
+    /// Determinant by Gaussian elimination with partial pivoting
+    #[allow(clippy::needless_range_loop)]
+    pub fn determinant(&self) -> Option<ComplexNumber> {
+        if !self.is_square() {
+            return None;
+        }
+        let n = self.rows;
+        let tolerance = pivot_tolerance(self.max_magnitude());
+        let mut a = self.data.clone();
+        let mut det = ComplexNumber::new(1.0, 0.0);
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&x, &y| a[x][k].magnitude().total_cmp(&a[y][k].magnitude()))
+                .unwrap_or(k);
+            if a[pivot_row][k].magnitude() <= tolerance {
+                return Some(ComplexNumber::new(0.0, 0.0));
+            }
+            if pivot_row != k {
+                a.swap(k, pivot_row);
+                det = det.negate();
+            }
+
+            let pivot = a[k][k];
+            det = det.mul(&pivot);
+            for i in (k + 1)..n {
+                let factor = a[i][k].div(&pivot)?;
+                for j in k..n {
+                    a[i][j] = a[i][j].sub(&factor.mul(&a[k][j]));
+                }
+            }
+        }
+
+        Some(det)
+    }
+
+    /// Plain transpose Aᵀ
+    pub fn transpose(&self) -> ComplexMatrix {
+        let mut result = ComplexMatrix::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result.set(j, i, self.data[i][j]);
+            }
+        }
+        result
+    }
+
+    /// Conjugate (Hermitian) transpose Aᴴ
+    pub fn conjugate_transpose(&self) -> ComplexMatrix {
+        let mut result = self.transpose();
+        for row in &mut result.data {
+            for value in row.iter_mut() {
+                *value = value.conjugate();
+            }
+        }
+        result
+    }
+
+    /// The transpose of the given kind
+    pub fn transposed(&self, kind: TransposeKind) -> ComplexMatrix {
+        match kind {
+            TransposeKind::Plain => self.transpose(),
+            TransposeKind::Conjugate => self.conjugate_transpose(),
+        }
+    }
+
+    /// Largest entry magnitude
+    fn max_magnitude(&self) -> f64 {
+        self.data
+            .iter()
+            .flatten()
+            .fold(0.0, |max, z| f64::max(max, z.magnitude()))
+    }
+
+    /// Whether the matrix equals its conjugate transpose (A = Aᴴ), entry
+    /// by entry within `tolerance`
+    pub fn is_hermitian(&self, tolerance: f64) -> bool {
+        self.is_square()
+            && (0..self.rows).all(|i| {
+                (0..=i).all(|j| {
+                    self.data[i][j]
+                        .sub(&self.data[j][i].conjugate())
+                        .magnitude()
+                        <= tolerance
+                })
+            })
+    }
+
+    /// Calculate the Frobenius norm
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data
+            .iter()
+            .flat_map(|row| row.iter())
+            .map(|z| z.real * z.real + z.imaginary * z.imaginary)
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+/// Roots of `x² + bx + c`
+fn quadratic_roots(b: f64, c: f64) -> Vec<ComplexNumber> {
+    let half_b = b / 2.0;
+    let discriminant = half_b * half_b - c;
+    if discriminant >= 0.0 {
+        let root = discriminant.sqrt();
+        vec![
+            ComplexNumber::new(-half_b + root, 0.0),
+            ComplexNumber::new(-half_b - root, 0.0),
+        ]
+    } else {
+        let im = (-discriminant).sqrt();
+        vec![
+            ComplexNumber::new(-half_b, im),
+            ComplexNumber::new(-half_b, -im),
+        ]
+    }
+}
+
+/// Roots of `x³ + ax² + bx + c`, via the depressed cubic `t³ + pt + q`
+/// with `x = t - a/3`
+fn cubic_roots(a: f64, b: f64, c: f64) -> Vec<ComplexNumber> {
+    let shift = -a / 3.0;
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+    let discriminant = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+    // Repeated roots land on either side of zero through rounding
+    let scale = (q / 2.0).powi(2).max((p / 3.0).abs().powi(3)).max(1.0);
+
+    if discriminant > 1e-12 * scale {
+        // One real root and a complex conjugate pair
+        let root = discriminant.sqrt();
+        let u = (-q / 2.0 + root).cbrt();
+        let v = (-q / 2.0 - root).cbrt();
+        let im = 3f64.sqrt() / 2.0 * (u - v);
+        vec![
+            ComplexNumber::new(u + v + shift, 0.0),
+            ComplexNumber::new(-(u + v) / 2.0 + shift, im),
+            ComplexNumber::new(-(u + v) / 2.0 + shift, -im),
+        ]
+    } else if p.abs() < 1e-12 * scale {
+        // Triple root
+        vec![ComplexNumber::new(shift, 0.0); 3]
+    } else {
+        // Three real roots (trigonometric form)
+        let r = 2.0 * (-p / 3.0).sqrt();
+        let cos_arg = (3.0 * q / (p * r)).clamp(-1.0, 1.0);
+        let phi = cos_arg.acos() / 3.0;
+        (0..3)
+            .map(|k| {
+                let t = r * (phi - 2.0 * std::f64::consts::PI * k as f64 / 3.0).cos();
+                ComplexNumber::new(t + shift, 0.0)
+            })
+            .collect()
+    }
+}
+
+/// A complex value as `a`, or `a + bi` / `a - bi` when it has an
+/// imaginary part, at a fixed precision
+fn format_complex(value: &ComplexNumber, precision: usize) -> String {
+    // Avoid printing "-0.0000" for rounding noise
+    let clean = |x: f64| {
+        if x.abs() < 0.5 * 10f64.powi(-(precision as i32)) {
+            0.0
+        } else {
+            x
+        }
+    };
+    let (re, im) = (clean(value.real), clean(value.imaginary));
+    if im == 0.0 {
+        format!("{:.prec$}", re, prec = precision)
+    } else {
+        let sign = if im < 0.0 { '-' } else { '+' };
+        format!(
+            "{:.prec$} {} {:.prec$}i",
+            re,
+            sign,
+            im.abs(),
+            prec = precision
+        )
+    }
+}
+
+/// Compact cell text that parses back with `ComplexNumber::parse`:
+/// `3`, `2i`, `1+2i`, `0.5-i`
+fn format_complex_cell(value: &ComplexNumber) -> String {
+    let imaginary = match value.imaginary.abs() {
+        1.0 => String::new(),
+        im => format_number(im),
+    };
+    match (value.real == 0.0, value.imaginary == 0.0) {
+        (_, true) => format_number(value.real),
+        (true, false) if value.imaginary < 0.0 => format!("-{}i", imaginary),
+        (true, false) => format!("{}i", imaginary),
+        (false, false) => {
+            let sign = if value.imaginary < 0.0 { '-' } else { '+' };
+            format!("{}{}{}i", format_number(value.real), sign, imaginary)
+        }
+    }
 }
 
 /// Format a number, removing unnecessary trailing zeros
@@ -353,15 +1236,79 @@ pub enum MatrixOperation {
     FrobeniusNorm,
 }
 
-impl MatrixOperation {
-    /// Get the display label
-    pub fn label(&self) -> &'static str {
-        match self {
-            MatrixOperation::Determinant => "det",
-            MatrixOperation::Trace => "tr",
-            MatrixOperation::Transpose => "T",
-            MatrixOperation::FrobeniusNorm => "‖·‖F",
-        }
+impl MatrixOperation {
+    /// Get the display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            MatrixOperation::Determinant => "det",
+            MatrixOperation::Trace => "tr",
+            MatrixOperation::Transpose => "T",
+            MatrixOperation::FrobeniusNorm => "‖·‖F",
+        }
+    }
+}
+
+/// Named factors to display for a decomposition, e.g. `P`, `L`, `U`
+fn decomposition_factors(
+    matrix: &Matrix,
+    kind: MatrixDecomposition,
+) -> Result<Vec<(&'static str, Matrix)>, DecompositionError> {
+    match kind {
+        MatrixDecomposition::Lu => {
+            let lu = matrix.lu()?;
+            let mut factors = Vec::new();
+            // P only matters once pivoting has swapped rows
+            if lu.swaps > 0 {
+                factors.push(("P", lu.p()));
+            }
+            factors.push(("L", lu.l));
+            factors.push(("U", lu.u));
+            Ok(factors)
+        }
+        MatrixDecomposition::Qr => {
+            let qr = matrix.qr()?;
+            Ok(vec![("Q", qr.q), ("R", qr.r)])
+        }
+    }
+}
+
+/// A read-only factor matrix with its name, e.g. `L = [ ... ]`
+fn factor_view(
+    name: &'static str,
+    matrix: &Matrix,
+    notation: MatrixNotation,
+    precision: usize,
+) -> impl IntoView {
+    complex_factor_view(name, &ComplexMatrix::from(matrix), notation, precision)
+}
+
+/// A read-only complex matrix with its name, e.g. `Aᴴ = [ ... ]`
+fn complex_factor_view(
+    name: &'static str,
+    matrix: &ComplexMatrix,
+    notation: MatrixNotation,
+    precision: usize,
+) -> impl IntoView {
+    let cols = matrix.cols();
+    let cells = (0..matrix.rows())
+        .flat_map(|i| (0..cols).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let value = matrix.get(i, j).unwrap_or_default();
+            view! { <span style="text-align: right;">{format_complex(&value, precision)}</span> }
+        })
+        .collect_view();
+    view! {
+        <div style="display: flex; align-items: center; gap: 0.25rem; font-family: monospace; font-size: 0.8125rem;">
+            <span style="font-weight: 600;">{format!("{} =", name)}</span>
+            <span style="font-size: 1.5em;">{notation.left()}</span>
+            <div style=format!(
+                "display: grid; grid-template-columns: repeat({}, auto); column-gap: 0.75rem;",
+                cols,
+            )>
+                {cells}
+            </div>
+            <span style="font-size: 1.5em;">{notation.right()}</span>
+        </div>
     }
 }
 
@@ -392,6 +1339,15 @@ pub fn MatrixInput(
     #[prop(optional, default = true)]
     show_operations: bool,
 
+    /// Offer LU and QR decompositions in the operations panel
+    #[prop(optional)]
+    show_decompositions: bool,
+
+    /// Square matrices with `|det|` at or below this are shown as singular
+    /// instead of inverted
+    #[prop(optional, default = DEFAULT_SINGULAR_TOLERANCE)]
+    singular_tolerance: f64,
+
     /// Matrix notation style
     #[prop(optional)]
     notation: MatrixNotation,
@@ -419,18 +1375,118 @@ pub fn MatrixInput(
     /// Whether the input is disabled
     #[prop(optional)]
     disabled: Signal<bool>,
+
+    /// Enable undo/redo with Ctrl+Z/Ctrl+Y
+    #[prop(optional, default = true)]
+    enable_undo_redo: bool,
+
+    /// Maximum undo history size
+    #[prop(optional, default = 50)]
+    undo_history_size: usize,
+
+    /// Whether to show undo/redo buttons
+    #[prop(optional, default = false)]
+    show_history_controls: bool,
+
+    /// Receives pasted text that can't be used: ragged rows, non-numeric
+    /// cells, or (without `allow_resize`) a block that doesn't fit
+    #[prop(optional, into)]
+    on_paste_error: Option<Callback<ValidationError>>,
+
+    /// Show a "Copy CSV" button for pasting the matrix into a spreadsheet
+    #[prop(optional)]
+    show_copy_csv: bool,
+
+    /// Receives the CSV whenever it is copied
+    #[prop(optional, into)]
+    on_copy_csv: Option<Callback<String>>,
+
+    /// Cell number type. `Complex` cells accept the same text as
+    /// ComplexNumberInput (`1+2i`, `2∠45°`) and edit `complex_value`;
+    /// paste, undo and decompositions are only available for `Real`.
+    #[prop(optional)]
+    element_type: MatrixElementType,
+
+    /// Current complex matrix value, for `element_type=MatrixElementType::Complex`
+    #[prop(optional, into)]
+    complex_value: Option<RwSignal<ComplexMatrix>>,
+
+    /// Callback when the complex matrix changes
+    #[prop(optional, into)]
+    on_complex_change: Option<Callback<ComplexMatrix>>,
 ) -> impl IntoView {
     let theme = use_theme();
 
     // Internal state
     let internal_matrix = value.unwrap_or_else(|| RwSignal::new(Matrix::zeros(rows, cols)));
 
+    let is_complex = element_type == MatrixElementType::Complex;
+    // Without `complex_value`, start from the real matrix
+    let complex_matrix = complex_value
+        .unwrap_or_else(|| RwSignal::new(ComplexMatrix::from(&internal_matrix.get_untracked())));
+
+    // Decomposition shown in the operations panel, if any
+    let decomposition = RwSignal::new(None::<MatrixDecomposition>);
+
+    // Transpose shown in the complex operations panel, if any
+    let transpose_shown = RwSignal::new(None::<TransposeKind>);
+
     // Currently focused cell
     let focused_cell: RwSignal<Option<(usize, usize)>> = RwSignal::new(None);
 
+    // Undo/redo history; each resize and each cell edit is one step
+    let history = RwSignal::new(EditHistory::new(if enable_undo_redo {
+        undo_history_size
+    } else {
+        0
+    }));
+    let cell_edit_recorded = RwSignal::new(false);
+
+    // Last rejected paste, shown under the grid
+    let paste_error = RwSignal::new(None::<ValidationError>);
+
+    let record_history = move || {
+        history.update(|h| h.record(internal_matrix.get_untracked()));
+    };
+
+    let step_history = move |shortcut: HistoryShortcut| {
+        let current = internal_matrix.get_untracked();
+        let restored = history
+            .try_update(|h| match shortcut {
+                HistoryShortcut::Undo => h.undo(current),
+                HistoryShortcut::Redo => h.redo(current),
+            })
+            .flatten();
+        if let Some(matrix) = restored {
+            cell_edit_recorded.set(false);
+            internal_matrix.set(matrix.clone());
+            if let Some(cb) = on_change {
+                cb.run(matrix);
+            }
+        }
+    };
+
+    let handle_history_keydown = move |ev: ev::KeyboardEvent| {
+        if !enable_undo_redo || is_complex || disabled.get_untracked() {
+            return;
+        }
+        if let Some(shortcut) =
+            HistoryShortcut::from_key(&ev.key(), ev.ctrl_key() || ev.meta_key(), ev.shift_key())
+        {
+            ev.prevent_default();
+            step_history(shortcut);
+        }
+    };
+
     // Update matrix when cell changes
     let update_cell = move |row: usize, col: usize, value: String| {
         if let Ok(num) = value.parse::<f64>() {
+            // Snapshot once per edit session, not per keystroke
+            if !cell_edit_recorded.get_untracked() {
+                record_history();
+                cell_edit_recorded.set(true);
+            }
+            paste_error.set(None);
             let mut matrix = internal_matrix.get();
             matrix.set(row, col, num);
             internal_matrix.set(matrix.clone());
@@ -440,6 +1496,68 @@ pub fn MatrixInput(
         }
     };
 
+    // Complex cells keep their last valid value while the text doesn't parse
+    let update_complex_cell = move |row: usize, col: usize, value: String| {
+        if let Ok(num) = ComplexNumber::parse(&value, PolarAngleUnit::Degrees) {
+            let mut matrix = complex_matrix.get_untracked();
+            matrix.set(row, col, num);
+            complex_matrix.set(matrix.clone());
+            if let Some(cb) = on_complex_change {
+                cb.run(matrix);
+            }
+        }
+    };
+
+    // Resize the complex matrix and notify
+    let resize_complex = move |resize: fn(&mut ComplexMatrix)| {
+        let mut matrix = complex_matrix.get_untracked();
+        resize(&mut matrix);
+        complex_matrix.set(matrix.clone());
+        if let Some(cb) = on_complex_change {
+            cb.run(matrix);
+        }
+    };
+
+    // Pasting rows copied from a spreadsheet fills the grid: the whole
+    // matrix when it can resize, otherwise a block at the pasted cell
+    let handle_paste = move |row: usize, col: usize, ev: ev::ClipboardEvent| {
+        let Some(text) = ev
+            .clipboard_data()
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return;
+        };
+        // A single value pastes into the cell as usual
+        if !text.trim().contains(['\t', ',', '\n']) {
+            return;
+        }
+        ev.prevent_default();
+
+        let pasted = Matrix::from_delimited(&text).and_then(|block| {
+            if allow_resize {
+                Ok(block)
+            } else {
+                paste_into(&internal_matrix.get_untracked(), &block, row, col)
+            }
+        });
+        match pasted {
+            Ok(matrix) => {
+                record_history();
+                paste_error.set(None);
+                internal_matrix.set(matrix.clone());
+                if let Some(cb) = on_change {
+                    cb.run(matrix);
+                }
+            }
+            Err(err) => {
+                if let Some(cb) = on_paste_error {
+                    cb.run(err.clone());
+                }
+                paste_error.set(Some(err));
+            }
+        }
+    };
+
     // Handle keyboard navigation (arrow keys only - Tab handled by tabindex)
     let handle_keydown = move |_row: usize, _col: usize, _ev: ev::KeyboardEvent| {
         // Arrow key navigation could be added here if needed
@@ -448,6 +1566,10 @@ pub fn MatrixInput(
 
     // Add row
     let add_row = move |_| {
+        if is_complex {
+            return resize_complex(|m| m.add_row(m.rows()));
+        }
+        record_history();
         let mut matrix = internal_matrix.get();
         matrix.add_row(matrix.rows());
         internal_matrix.set(matrix);
@@ -455,6 +1577,10 @@ pub fn MatrixInput(
 
     // Add column
     let add_col = move |_| {
+        if is_complex {
+            return resize_complex(|m| m.add_col(m.cols()));
+        }
+        record_history();
         let mut matrix = internal_matrix.get();
         matrix.add_col(matrix.cols());
         internal_matrix.set(matrix);
@@ -462,8 +1588,12 @@ pub fn MatrixInput(
 
     // Remove row
     let remove_row = move |_| {
+        if is_complex {
+            return resize_complex(|m| m.remove_row(m.rows() - 1));
+        }
         let mut matrix = internal_matrix.get();
         if matrix.rows() > 1 {
+            record_history();
             matrix.remove_row(matrix.rows() - 1);
             internal_matrix.set(matrix);
         }
@@ -471,8 +1601,12 @@ pub fn MatrixInput(
 
     // Remove column
     let remove_col = move |_| {
+        if is_complex {
+            return resize_complex(|m| m.remove_col(m.cols() - 1));
+        }
         let mut matrix = internal_matrix.get();
         if matrix.cols() > 1 {
+            record_history();
             matrix.remove_col(matrix.cols() - 1);
             internal_matrix.set(matrix);
         }
@@ -558,13 +1692,14 @@ pub fn MatrixInput(
     };
 
     let grid_styles = move || {
-        let matrix = internal_matrix.get();
+        let cols = if is_complex {
+            complex_matrix.with(ComplexMatrix::cols)
+        } else {
+            internal_matrix.with(Matrix::cols)
+        };
         StyleBuilder::new()
             .add("display", "grid")
-            .add(
-                "grid-template-columns",
-                format!("repeat({}, 1fr)", matrix.cols()),
-            )
+            .add("grid-template-columns", format!("repeat({}, 1fr)", cols))
             .add("gap", "2px")
             .build()
     };
@@ -631,6 +1766,29 @@ pub fn MatrixInput(
             .build()
     };
 
+    let toggle_button_styles = move |active: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("padding", "0.125rem 0.5rem")
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add("border", format!("1px solid {}", scheme_colors.border))
+            .add("border-radius", &*theme_val.radius.sm)
+            .add("cursor", "pointer")
+            .add(
+                "background-color",
+                if active {
+                    scheme_colors
+                        .get_color("blue", 1)
+                        .unwrap_or_else(|| "#d0ebff".to_string())
+                } else {
+                    scheme_colors.background.clone()
+                },
+            )
+            .add("color", scheme_colors.text.clone())
+            .build()
+    };
+
     let description_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -660,7 +1818,7 @@ pub fn MatrixInput(
     };
 
     view! {
-        <div class="mingot-matrix-input" style=container_styles>
+        <div class="mingot-matrix-input" style=container_styles on:keydown=handle_history_keydown>
             {label.clone().map(|l| view! {
                 <label style=label_styles>{l}</label>
             })}
@@ -670,6 +1828,35 @@ pub fn MatrixInput(
 
                 <div style=grid_styles>
                     {move || {
+                        if is_complex {
+                            let matrix = complex_matrix.get();
+                            let cols = matrix.cols();
+                            let mut cells = Vec::new();
+                            for r in 0..matrix.rows() {
+                                for c in 0..cols {
+                                    let val = matrix.get(r, c).unwrap_or_default();
+                                    let val_str = RwSignal::new(format_complex_cell(&val));
+                                    let tab_index = (r * cols + c + 1) as i32;
+
+                                    cells.push(view! {
+                                        <input
+                                            type="text"
+                                            style=move || format!("{}; width: auto; min-width: 6rem;", cell_styles())
+                                            tabindex=tab_index
+                                            prop:value=move || val_str.get()
+                                            disabled=disabled
+                                            on:input=move |ev| {
+                                                let new_val = event_target_value(&ev);
+                                                val_str.set(new_val.clone());
+                                                update_complex_cell(r, c, new_val);
+                                            }
+                                        />
+                                    });
+                                }
+                            }
+                            return cells.collect_view().into_any();
+                        }
+
                         let matrix = internal_matrix.get();
                         let cols = matrix.cols();
                         let mut cells = Vec::new();
@@ -693,21 +1880,27 @@ pub fn MatrixInput(
                                         }
                                         on:focus=move |_| {
                                             focused_cell.set(Some((r, c)));
+                                            cell_edit_recorded.set(false);
                                         }
                                         on:keydown=move |ev| {
                                             handle_keydown(r, c, ev);
                                         }
+                                        on:paste=move |ev| handle_paste(r, c, ev)
                                     />
                                 });
                             }
                         }
-                        cells.collect_view()
+                        cells.collect_view().into_any()
                     }}
                 </div>
 
                 <span style=right_bracket_styles></span>
             </div>
 
+            {move || paste_error.get().map(|e| view! {
+                <div class="mingot-matrix-paste-error" style=error_styles role="alert">{e.message}</div>
+            })}
+
             {allow_resize.then(|| {
                 view! {
                     <div style="display: flex; gap: 0.5rem; flex-wrap: wrap;">
@@ -727,7 +1920,111 @@ pub fn MatrixInput(
                 }
             })}
 
-            {show_operations.then(|| {
+            {(enable_undo_redo && show_history_controls && !is_complex).then(|| {
+                view! {
+                    <div style="display: flex; gap: 0.5rem;">
+                        <button
+                            type="button"
+                            style=resize_button_styles
+                            title="Undo (Ctrl+Z)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_undo())
+                            on:click=move |_| step_history(HistoryShortcut::Undo)
+                        >
+                            {"↶ Undo"}
+                        </button>
+                        <button
+                            type="button"
+                            style=resize_button_styles
+                            title="Redo (Ctrl+Y)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_redo())
+                            on:click=move |_| step_history(HistoryShortcut::Redo)
+                        >
+                            {"↷ Redo"}
+                        </button>
+                    </div>
+                }
+            })}
+
+            {(show_copy_csv && !is_complex).then(|| {
+                let copy_csv = move |_| {
+                    let csv = internal_matrix.get_untracked().to_csv();
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.navigator().clipboard().write_text(&csv);
+                    }
+                    if let Some(cb) = on_copy_csv {
+                        cb.run(csv);
+                    }
+                };
+                view! {
+                    <div>
+                        <button
+                            type="button"
+                            class="mingot-matrix-copy-csv"
+                            style=resize_button_styles
+                            title="Copy the matrix as CSV"
+                            on:click=copy_csv
+                        >
+                            "Copy CSV"
+                        </button>
+                    </div>
+                }
+            })}
+
+            {(show_operations && is_complex).then(|| {
+                let toggle = move |kind: TransposeKind| {
+                    transpose_shown.update(|t| {
+                        *t = if *t == Some(kind) { None } else { Some(kind) };
+                    });
+                };
+                view! {
+                    <div style=operations_styles>
+                        {move || {
+                            let matrix = complex_matrix.get();
+                            let mut ops = vec![format!("{}×{}", matrix.rows(), matrix.cols())];
+                            if let Some(det) = matrix.determinant() {
+                                ops.push(format!("det = {}", format_complex(&det, precision)));
+                            }
+                            if let Some(tr) = matrix.trace() {
+                                ops.push(format!("tr = {}", format_complex(&tr, precision)));
+                            }
+                            ops.push(format!(
+                                "‖A‖F = {:.prec$}",
+                                matrix.frobenius_norm(),
+                                prec = precision
+                            ));
+                            if matrix.is_hermitian(pivot_tolerance(matrix.max_magnitude())) {
+                                ops.push("Hermitian (A = Aᴴ)".to_string());
+                            }
+                            ops.into_iter().map(|op| view! { <span>{op}</span> }).collect_view()
+                        }}
+                    </div>
+                    <div class="mingot-matrix-transpose">
+                        <div style="display: flex; gap: 0.25rem; margin-bottom: 0.5rem;">
+                            {[TransposeKind::Plain, TransposeKind::Conjugate]
+                                .into_iter()
+                                .map(|kind| view! {
+                                    <button
+                                        type="button"
+                                        style=move || toggle_button_styles(transpose_shown.get() == Some(kind))
+                                        aria-pressed=move || (transpose_shown.get() == Some(kind)).to_string()
+                                        on:click=move |_| toggle(kind)
+                                    >
+                                        {kind.label()}
+                                    </button>
+                                })
+                                .collect_view()}
+                        </div>
+                        {move || {
+                            transpose_shown.get().map(|kind| {
+                                let transposed = complex_matrix.with(|m| m.transposed(kind));
+                                complex_factor_view(kind.label(), &transposed, notation, precision)
+                            })
+                        }}
+                    </div>
+                }
+            })}
+
+            {(show_operations && !is_complex).then(|| {
                 view! {
                     <div style=operations_styles>
                         {move || {
@@ -756,6 +2053,89 @@ pub fn MatrixInput(
                             }).collect_view()
                         }}
                     </div>
+                    {move || {
+                        let matrix = internal_matrix.get();
+                        matrix.is_square().then(|| {
+                            let eigenvalues = match matrix.eigenvalues() {
+                                Ok(values) => {
+                                    let complex = values.iter().any(|v| v.imaginary != 0.0);
+                                    let list = values
+                                        .iter()
+                                        .map(|v| format_complex(v, precision))
+                                        .collect::<Vec<_>>()
+                                        .join(", ");
+                                    view! {
+                                        <span>{format!("λ = {}", list)}</span>
+                                        {complex.then(|| view! {
+                                            <span>"(complex: no real eigenvectors for these)"</span>
+                                        })}
+                                    }
+                                    .into_any()
+                                }
+                                Err(e) => view! { <span>{e.to_string()}</span> }.into_any(),
+                            };
+                            let inverse = match matrix.inverse(singular_tolerance) {
+                                Some(inv) => factor_view("A⁻¹", &inv, notation, precision).into_any(),
+                                None => view! {
+                                    <span class="mingot-matrix-singular" style=error_styles role="status">
+                                        {format!("Singular: |det| ≤ {:e}, no inverse", singular_tolerance)}
+                                    </span>
+                                }
+                                .into_any(),
+                            };
+                            view! {
+                                <div class="mingot-matrix-eigen" style=operations_styles>{eigenvalues}</div>
+                                <div class="mingot-matrix-inverse">{inverse}</div>
+                            }
+                        })
+                    }}
+                }
+            })}
+
+            {(show_decompositions && !is_complex).then(|| {
+                let toggle = move |kind: MatrixDecomposition| {
+                    decomposition.update(|d| {
+                        *d = if *d == Some(kind) { None } else { Some(kind) };
+                    });
+                };
+                view! {
+                    <div class="mingot-matrix-decompositions">
+                        <div style="display: flex; gap: 0.25rem; margin-bottom: 0.5rem;">
+                            {[MatrixDecomposition::Lu, MatrixDecomposition::Qr]
+                                .into_iter()
+                                .map(|kind| view! {
+                                    <button
+                                        type="button"
+                                        style=move || toggle_button_styles(decomposition.get() == Some(kind))
+                                        aria-pressed=move || (decomposition.get() == Some(kind)).to_string()
+                                        on:click=move |_| toggle(kind)
+                                    >
+                                        {kind.label()}
+                                    </button>
+                                })
+                                .collect_view()}
+                        </div>
+                        {move || {
+                            let matrix = internal_matrix.get();
+                            decomposition.get().map(|kind| {
+                                match decomposition_factors(&matrix, kind) {
+                                    Ok(factors) => view! {
+                                        <div style="display: flex; gap: 1rem; flex-wrap: wrap; align-items: center;">
+                                            {factors
+                                                .into_iter()
+                                                .map(|(name, factor)| factor_view(name, &factor, notation, precision))
+                                                .collect_view()}
+                                        </div>
+                                    }
+                                    .into_any(),
+                                    Err(e) => view! {
+                                        <div style=error_styles role="status">{e.to_string()}</div>
+                                    }
+                                    .into_any(),
+                                }
+                            })
+                        }}
+                    </div>
                 }
             })}
 
@@ -911,4 +2291,479 @@ mod tests {
         assert_eq!(MatrixNotation::Parentheses.left(), "(");
         assert_eq!(MatrixNotation::Bars.left(), "|");
     }
+
+    fn assert_matrix_close(a: &Matrix, b: &Matrix) {
+        assert_eq!((a.rows(), a.cols()), (b.rows(), b.cols()));
+        for i in 0..a.rows() {
+            for j in 0..a.cols() {
+                let (x, y) = (a.get(i, j).unwrap(), b.get(i, j).unwrap());
+                assert!((x - y).abs() < 1e-9, "({}, {}): {} != {}", i, j, x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_reconstructs_original() {
+        let a = Matrix::from_vec(vec![
+            vec![2.0, 1.0, 1.0],
+            vec![4.0, -6.0, 0.0],
+            vec![-2.0, 7.0, 2.0],
+        ])
+        .unwrap();
+        let lu = a.lu().unwrap();
+
+        // L is unit lower-triangular, U is upper-triangular
+        for i in 0..3 {
+            assert_eq!(lu.l.get(i, i), Some(1.0));
+            for j in (i + 1)..3 {
+                assert_eq!(lu.l.get(i, j), Some(0.0));
+                assert_eq!(lu.u.get(j, i), Some(0.0));
+            }
+        }
+
+        let pa = lu.p().multiply(&a).unwrap();
+        let product = lu.l.multiply(&lu.u).unwrap();
+        assert_matrix_close(&product, &pa);
+
+        // det(A) = (-1)^swaps · ∏ diag(U)
+        let diag: f64 = (0..3).map(|i| lu.u.get(i, i).unwrap()).product();
+        let sign = if lu.swaps.is_multiple_of(2) {
+            1.0
+        } else {
+            -1.0
+        };
+        assert!((sign * diag - a.determinant().unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lu_pivots_on_zero_leading_entry() {
+        let a = Matrix::from_vec(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        let lu = a.lu().unwrap();
+        assert_eq!(lu.permutation, vec![1, 0]);
+        assert_eq!(lu.swaps, 1);
+        assert_matrix_close(
+            &lu.l.multiply(&lu.u).unwrap(),
+            &lu.p().multiply(&a).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_lu_errors() {
+        let singular = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(
+            singular.lu(),
+            Err(DecompositionError::Singular { column: 1 })
+        );
+        assert_eq!(
+            Matrix::zeros(2, 3).lu(),
+            Err(DecompositionError::NotSquare { rows: 2, cols: 3 })
+        );
+        assert_eq!(
+            DecompositionError::NotSquare { rows: 2, cols: 3 }.to_string(),
+            "LU decomposition needs a square matrix, but this one is 2×3"
+        );
+    }
+
+    #[test]
+    fn test_pivot_tolerance_scales_with_matrix() {
+        // Invertible at any scale
+        let tiny = Matrix::from_vec(vec![vec![4e-12, 7e-12], vec![2e-12, 6e-12]]).unwrap();
+        assert!(tiny.lu().is_ok());
+        let det = ComplexMatrix::from(&tiny).determinant().unwrap();
+        assert!(det.magnitude() > 0.0);
+
+        // Singular up to rounding noise, however large the entries
+        let huge = Matrix::from_vec(vec![vec![1e12, 2e12], vec![2e12, 4e12 + 1e-3]]).unwrap();
+        assert_eq!(huge.lu(), Err(DecompositionError::Singular { column: 1 }));
+
+        assert_eq!(
+            Matrix::zeros(2, 2).lu(),
+            Err(DecompositionError::Singular { column: 0 })
+        );
+    }
+
+    #[test]
+    fn test_qr_reconstructs_original() {
+        let a = Matrix::from_vec(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+        ])
+        .unwrap();
+        let qr = a.qr().unwrap();
+        assert_matrix_close(&qr.q.multiply(&qr.r).unwrap(), &a);
+        // Q is orthogonal
+        assert_matrix_close(
+            &qr.q.transpose().multiply(&qr.q).unwrap(),
+            &Matrix::identity(3),
+        );
+        assert_eq!(qr.r.get(2, 0), Some(0.0));
+        assert_eq!(qr.r.get(2, 1), Some(0.0));
+    }
+
+    #[test]
+    fn test_qr_non_square() {
+        let a = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]).unwrap();
+        let qr = a.qr().unwrap();
+        assert_eq!((qr.q.rows(), qr.q.cols()), (3, 3));
+        assert_eq!((qr.r.rows(), qr.r.cols()), (3, 2));
+        assert_matrix_close(&qr.q.multiply(&qr.r).unwrap(), &a);
+    }
+
+    #[test]
+    fn test_decomposition_factors() {
+        let names = |m: &Matrix, kind| {
+            decomposition_factors(m, kind)
+                .unwrap()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>()
+        };
+        let diagonal = Matrix::from_vec(vec![vec![2.0, 0.0], vec![0.0, 3.0]]).unwrap();
+        assert_eq!(names(&diagonal, MatrixDecomposition::Lu), vec!["L", "U"]);
+        let swapped = Matrix::from_vec(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eq!(
+            names(&swapped, MatrixDecomposition::Lu),
+            vec!["P", "L", "U"]
+        );
+        assert_eq!(names(&swapped, MatrixDecomposition::Qr), vec!["Q", "R"]);
+    }
+
+    fn assert_eigenvalues(matrix: &Matrix, expected: &[(f64, f64)]) {
+        let values = matrix.eigenvalues().unwrap();
+        assert_eq!(values.len(), expected.len(), "{:?}", values);
+        for (value, &(re, im)) in values.iter().zip(expected) {
+            assert!(
+                (value.real - re).abs() < 1e-9 && (value.imaginary - im).abs() < 1e-9,
+                "got {:?}, expected {} + {}i",
+                values,
+                re,
+                im
+            );
+        }
+    }
+
+    #[test]
+    fn test_identity_eigenvalues_are_all_one() {
+        for n in 1..=5 {
+            assert_eigenvalues(&Matrix::identity(n), &vec![(1.0, 0.0); n]);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigenvalues_by_jacobi() {
+        let m = Matrix::from_vec(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        assert_eigenvalues(&m, &[(3.0, 0.0), (1.0, 0.0)]);
+
+        let m = Matrix::from_vec(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap();
+        let r2 = 2f64.sqrt();
+        assert_eigenvalues(&m, &[(2.0 + r2, 0.0), (2.0, 0.0), (2.0 - r2, 0.0)]);
+
+        // 4×4 is beyond the closed forms, but fine when symmetric
+        let ones = Matrix::fill(4, 4, 1.0);
+        assert_eigenvalues(&ones, &[(4.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_rotation_has_complex_eigenvalues() {
+        // 90° rotation: ±i
+        let m = Matrix::from_vec(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eigenvalues(&m, &[(0.0, 1.0), (0.0, -1.0)]);
+
+        // Rotation by θ about the z axis: 1 and e^{±iθ}
+        let theta = std::f64::consts::PI / 3.0;
+        let (c, s) = (theta.cos(), theta.sin());
+        let m =
+            Matrix::from_vec(vec![vec![c, -s, 0.0], vec![s, c, 0.0], vec![0.0, 0.0, 1.0]]).unwrap();
+        assert_eigenvalues(&m, &[(1.0, 0.0), (c, s), (c, -s)]);
+    }
+
+    #[test]
+    fn test_non_symmetric_real_eigenvalues() {
+        let m = Matrix::from_vec(vec![vec![4.0, 1.0], vec![2.0, 3.0]]).unwrap();
+        assert_eigenvalues(&m, &[(5.0, 0.0), (2.0, 0.0)]);
+
+        // Upper triangular: eigenvalues are the diagonal
+        let m = Matrix::from_vec(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![0.0, 4.0, 5.0],
+            vec![0.0, 0.0, 6.0],
+        ])
+        .unwrap();
+        assert_eigenvalues(&m, &[(6.0, 0.0), (4.0, 0.0), (1.0, 0.0)]);
+
+        // Jordan block: triple root
+        let m = Matrix::from_vec(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![0.0, 2.0, 1.0],
+            vec![0.0, 0.0, 2.0],
+        ])
+        .unwrap();
+        assert_eigenvalues(&m, &[(2.0, 0.0), (2.0, 0.0), (2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_eigenvalue_errors() {
+        assert_eq!(
+            Matrix::zeros(2, 3).eigenvalues(),
+            Err(EigenvalueError::NotSquare { rows: 2, cols: 3 })
+        );
+        assert_eq!(
+            Matrix::zeros(0, 0).eigenvalues(),
+            Err(EigenvalueError::Empty)
+        );
+
+        let mut m = Matrix::identity(4);
+        m.set(0, 1, 1.0);
+        assert_eq!(
+            m.eigenvalues(),
+            Err(EigenvalueError::Unsupported { size: 4 })
+        );
+    }
+
+    #[test]
+    fn test_format_complex() {
+        assert_eq!(format_complex(&ComplexNumber::new(1.0, 0.0), 2), "1.00");
+        assert_eq!(
+            format_complex(&ComplexNumber::new(0.5, -0.866), 2),
+            "0.50 - 0.87i"
+        );
+        assert_eq!(
+            format_complex(&ComplexNumber::new(-1e-9, 2.0), 2),
+            "0.00 + 2.00i"
+        );
+    }
+
+    fn c(re: f64, im: f64) -> ComplexNumber {
+        ComplexNumber::new(re, im)
+    }
+
+    #[test]
+    fn test_complex_determinant_2x2() {
+        let m = ComplexMatrix::from_vec(vec![
+            vec![c(1.0, 1.0), c(2.0, 0.0)],
+            vec![c(3.0, 0.0), c(4.0, -1.0)],
+        ])
+        .unwrap();
+        // (1+i)(4-i) - 6 = 5+3i - 6
+        let det = m.determinant().unwrap();
+        assert!((det.real - -1.0).abs() < 1e-10);
+        assert!((det.imaginary - 3.0).abs() < 1e-10);
+
+        let tr = m.trace().unwrap();
+        assert_eq!((tr.real, tr.imaginary), (5.0, 0.0));
+        assert!(ComplexMatrix::zeros(2, 3).determinant().is_none());
+    }
+
+    #[test]
+    fn test_complex_determinant_needs_pivoting() {
+        let m = ComplexMatrix::from_vec(vec![
+            vec![c(0.0, 0.0), c(0.0, 1.0)],
+            vec![c(2.0, 0.0), c(1.0, 0.0)],
+        ])
+        .unwrap();
+        // 0·1 - i·2 = -2i
+        let det = m.determinant().unwrap();
+        assert!(det.real.abs() < 1e-10);
+        assert!((det.imaginary - -2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_conjugate_transpose() {
+        let m = ComplexMatrix::from_vec(vec![
+            vec![c(1.0, 2.0), c(3.0, -1.0), c(0.0, 1.0)],
+            vec![c(4.0, 0.0), c(5.0, 5.0), c(-2.0, 0.0)],
+        ])
+        .unwrap();
+
+        let t = m.transposed(TransposeKind::Plain);
+        assert_eq!((t.rows(), t.cols()), (3, 2));
+        assert_eq!(t.get(0, 1), Some(c(4.0, 0.0)));
+        assert_eq!(t.get(1, 0), Some(c(3.0, -1.0)));
+
+        let h = m.transposed(TransposeKind::Conjugate);
+        assert_eq!((h.rows(), h.cols()), (3, 2));
+        assert_eq!(h.get(0, 0), Some(c(1.0, -2.0)));
+        assert_eq!(h.get(1, 0), Some(c(3.0, 1.0)));
+        assert_eq!(h.get(1, 1), Some(c(5.0, -5.0)));
+        assert_eq!(h.conjugate_transpose(), m);
+    }
+
+    #[test]
+    fn test_is_hermitian() {
+        let hermitian = ComplexMatrix::from_vec(vec![
+            vec![c(2.0, 0.0), c(1.0, -1.0)],
+            vec![c(1.0, 1.0), c(3.0, 0.0)],
+        ])
+        .unwrap();
+        assert!(hermitian.is_hermitian(1e-12));
+        assert_eq!(hermitian.conjugate_transpose(), hermitian);
+
+        // Symmetric but not Hermitian: the off-diagonal entries aren't conjugates
+        let symmetric = ComplexMatrix::from_vec(vec![
+            vec![c(2.0, 0.0), c(1.0, 1.0)],
+            vec![c(1.0, 1.0), c(3.0, 0.0)],
+        ])
+        .unwrap();
+        assert!(!symmetric.is_hermitian(1e-12));
+        // A real diagonal entry is required
+        let mut m = hermitian.clone();
+        m.set(0, 0, c(2.0, 1.0));
+        assert!(!m.is_hermitian(1e-12));
+    }
+
+    #[test]
+    fn test_complex_matrix_from_real() {
+        let real = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let m = ComplexMatrix::from(&real);
+        assert_eq!(m.get(1, 0), Some(c(3.0, 0.0)));
+        let det = m.determinant().unwrap();
+        assert!((det.real - real.determinant().unwrap()).abs() < 1e-10);
+        assert!(det.imaginary.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_format_complex_cell_round_trips() {
+        for (value, text) in [
+            (c(3.0, 0.0), "3"),
+            (c(0.0, 2.0), "2i"),
+            (c(0.0, -1.0), "-i"),
+            (c(1.0, 2.0), "1+2i"),
+            (c(0.5, -1.0), "0.5-i"),
+            (c(0.0, 0.0), "0"),
+        ] {
+            assert_eq!(format_complex_cell(&value), text);
+            let parsed = ComplexNumber::parse(text, PolarAngleUnit::Degrees).unwrap();
+            assert_eq!(parsed, value);
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix::from_vec(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+        let inv = m.inverse(DEFAULT_SINGULAR_TOLERANCE).unwrap();
+        let expected = Matrix::from_vec(vec![vec![0.6, -0.7], vec![-0.2, 0.4]]).unwrap();
+        assert_matrix_close(&inv, &expected);
+        assert_matrix_close(&m.multiply(&inv).unwrap(), &Matrix::identity(2));
+
+        // Needs pivoting: zero in the top-left corner
+        let m = Matrix::from_vec(vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+            vec![3.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        let inv = m.inverse(DEFAULT_SINGULAR_TOLERANCE).unwrap();
+        assert_matrix_close(&m.multiply(&inv).unwrap(), &Matrix::identity(3));
+    }
+
+    #[test]
+    fn test_inverse_of_singular_matrix() {
+        let singular = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(singular.inverse(DEFAULT_SINGULAR_TOLERANCE), None);
+        assert_eq!(
+            Matrix::zeros(2, 3).inverse(DEFAULT_SINGULAR_TOLERANCE),
+            None
+        );
+
+        // Nearly singular: the tolerance decides
+        let nearly = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.001]]).unwrap();
+        assert!(nearly.inverse(DEFAULT_SINGULAR_TOLERANCE).is_some());
+        assert_eq!(nearly.inverse(0.01), None);
+    }
+
+    #[test]
+    fn test_paste_2x3_from_spreadsheet() {
+        let expected = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.5, -5.0, 6e3]]).unwrap();
+        // Spreadsheets copy tab-separated rows with a trailing newline
+        assert_eq!(
+            Matrix::from_delimited("1\t2\t3\r\n4.5\t-5\t6e3\r\n"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            Matrix::from_delimited("1, 2, 3\n\"4.5\",-5,6000"),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_paste_rejects_ragged_rows() {
+        let err = Matrix::from_delimited("1,2,3\n4,5\n6,7,8").unwrap_err();
+        assert_eq!(err.message, "Row 2 has 2 values, but row 1 has 3");
+    }
+
+    #[test]
+    fn test_paste_reports_non_numeric_cell_position() {
+        let err = Matrix::from_delimited("1\t2\n3\tabc").unwrap_err();
+        assert_eq!(err.message, "Row 2, column 2: \"abc\" is not a number");
+
+        let err = Matrix::from_delimited("1,,3").unwrap_err();
+        assert_eq!(err.message, "Row 1, column 2: \"\" is not a number");
+
+        assert_eq!(
+            Matrix::from_delimited(" \n").unwrap_err().message,
+            "Nothing to paste"
+        );
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let m = Matrix::from_vec(vec![vec![0.1, -2.0], vec![1e-12, 3.25]]).unwrap();
+        assert_eq!(m.to_csv(), "0.1,-2\n0.000000000001,3.25");
+        assert_eq!(Matrix::from_delimited(&m.to_csv()), Ok(m));
+    }
+
+    #[test]
+    fn test_paste_into_fixed_size_matrix() {
+        let block = Matrix::from_delimited("7,8\n9,10").unwrap();
+        let pasted = paste_into(&Matrix::zeros(3, 3), &block, 1, 1).unwrap();
+        assert_eq!(
+            pasted,
+            Matrix::from_vec(vec![
+                vec![0.0, 0.0, 0.0],
+                vec![0.0, 7.0, 8.0],
+                vec![0.0, 9.0, 10.0],
+            ])
+            .unwrap()
+        );
+
+        let err = paste_into(&Matrix::zeros(3, 3), &block, 2, 0).unwrap_err();
+        assert_eq!(
+            err.message,
+            "A 2×2 block doesn't fit at row 3, column 1 of a 3×3 matrix"
+        );
+    }
+
+    #[test]
+    fn test_matrix_history_undo_resize() {
+        let mut history = EditHistory::new(50);
+        let original = Matrix::identity(2);
+
+        let mut resized = original.clone();
+        history.record(resized.clone());
+        resized.remove_row(1);
+        assert_eq!(resized.rows(), 1);
+
+        assert_eq!(history.undo(resized.clone()), Some(original));
+        assert_eq!(history.redo(Matrix::identity(2)), Some(resized));
+    }
+
+    #[test]
+    fn test_matrix_history_undo_cell_edit() {
+        let mut history = EditHistory::new(50);
+        let original = Matrix::zeros(2, 2);
+
+        let mut edited = original.clone();
+        history.record(edited.clone());
+        edited.set(0, 1, 7.5);
+
+        let restored = history.undo(edited).unwrap();
+        assert_eq!(restored.get(0, 1), Some(0.0));
+        assert_eq!(restored, original);
+    }
 }
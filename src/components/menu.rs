@@ -1,5 +1,120 @@
 use crate::theme::use_theme;
+use crate::utils::{choose_placement, Placement, Rect};
 use leptos::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use wasm_bindgen::JsCast;
+
+static NEXT_SUBMENU_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Chain of open [`MenuSubmenu`] ids, outermost first. Opening a submenu
+/// truncates everything at or below its depth, so only one branch is ever
+/// open.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SubmenuPath(Vec<usize>);
+
+impl SubmenuPath {
+    /// Open submenu `id` at `depth`, closing any other branch at that depth
+    pub fn open(&mut self, depth: usize, id: usize) {
+        self.0.truncate(depth);
+        self.0.push(id);
+    }
+
+    /// Close every submenu at `depth` and below
+    pub fn close(&mut self, depth: usize) {
+        self.0.truncate(depth);
+    }
+
+    pub fn is_open(&self, depth: usize, id: usize) -> bool {
+        self.0.get(depth) == Some(&id)
+    }
+
+    /// Number of open submenu levels
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn ids(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+/// Screen-space box used by [`flyout_placement`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlyoutRect {
+    pub left: f64,
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+}
+
+impl FlyoutRect {
+    pub fn new(left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlyoutSide {
+    #[default]
+    Right,
+    Left,
+}
+
+/// Where a submenu flyout should be drawn relative to its item
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FlyoutPlacement {
+    pub side: FlyoutSide,
+    /// Vertical offset in pixels (negative moves the flyout up) that keeps
+    /// it inside the viewport
+    pub shift_y: f64,
+}
+
+/// Decide which side of `anchor` a flyout of `width` x `height` fits on.
+/// Prefers the right; flips left when the right side is too narrow and
+/// the left has more room. Shifts upwards when the flyout would run past
+/// the bottom edge, but never above the top of the viewport.
+pub fn flyout_placement(
+    anchor: FlyoutRect,
+    width: f64,
+    height: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+) -> FlyoutPlacement {
+    let target = Rect::new(
+        anchor.left,
+        anchor.top,
+        anchor.right - anchor.left,
+        anchor.bottom - anchor.top,
+    );
+    let side = match choose_placement(
+        target,
+        width,
+        height,
+        (viewport_width, viewport_height),
+        0.0,
+        &[Placement::Right, Placement::Left],
+    ) {
+        Placement::Left => FlyoutSide::Left,
+        _ => FlyoutSide::Right,
+    };
+
+    let overflow = (anchor.top + height - viewport_height).max(0.0);
+    let shift_y = -overflow.min(anchor.top.max(0.0));
+
+    FlyoutPlacement { side, shift_y }
+}
+
+/// Submenu state shared by a [`Menu`] and its nested [`MenuSubmenu`]s
+#[derive(Clone, Copy)]
+struct SubmenuContext {
+    path: RwSignal<SubmenuPath>,
+    depth: usize,
+}
 
 #[component]
 pub fn Menu(
@@ -10,7 +125,16 @@ pub fn Menu(
     let theme = use_theme();
     let opened = RwSignal::new(false);
 
+    let path = RwSignal::new(SubmenuPath::default());
+
     provide_context::<RwSignal<bool>>(opened);
+    provide_context(SubmenuContext { path, depth: 0 });
+
+    Effect::new(move |_| {
+        if !opened.get() {
+            path.update(|p| p.close(0));
+        }
+    });
 
     let menu_styles = move || {
         let theme_val = theme.get();
@@ -144,6 +268,7 @@ pub fn MenuItem(
 ) -> impl IntoView {
     let theme = use_theme();
     let opened = use_context::<RwSignal<bool>>().unwrap_or_else(|| RwSignal::new(false));
+    let submenu = use_context::<SubmenuContext>();
 
     let item_styles = move || {
         let theme_val = theme.get();
@@ -185,11 +310,28 @@ pub fn MenuItem(
         }
     };
 
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if matches!(ev.key().as_str(), "Enter" | " ") {
+            ev.prevent_default();
+            handle_click(());
+        }
+    };
+
+    // Hovering a plain item closes any sibling submenu
+    let handle_pointerenter = move |_| {
+        if let Some(ctx) = submenu {
+            ctx.path.update(|p| p.close(ctx.depth));
+        }
+    };
+
     let class_str = format!("mingot-menu-item {}", class.unwrap_or_default());
 
     view! {
         <div
             class=class_str
+            role="menuitem"
+            tabindex=if disabled { "-1" } else { "0" }
+            aria-disabled=disabled.to_string()
             style=move || {
                 if let Some(s) = style.as_ref() {
                     format!("{}; {}", item_styles(), s)
@@ -198,7 +340,9 @@ pub fn MenuItem(
                 }
             }
 
-            on:click=handle_click
+            on:click=move |_| handle_click(())
+            on:keydown=handle_keydown
+            on:pointerenter=handle_pointerenter
         >
             {icon.as_ref().map(|i| view! { <span>{i.clone()}</span> })}
             <span>{children()}</span>
@@ -287,3 +431,456 @@ pub fn MenuLabel(
         </div>
     }
 }
+
+/// Menu entry that opens a nested flyout of further items. The flyout
+/// opens on hover for mouse pointers and on click for touch, sits beside
+/// the item and flips to the other side when there is no room. ArrowRight
+/// enters the submenu; ArrowLeft or Escape leaves it.
+///
+/// # Example
+/// ```rust,ignore
+/// view! {
+///     <MenuDropdown>
+///         <MenuItem>"Open"</MenuItem>
+///         <MenuSubmenu label="Export">
+///             <MenuItem>"PNG"</MenuItem>
+///             <MenuItem>"SVG"</MenuItem>
+///         </MenuSubmenu>
+///     </MenuDropdown>
+/// }
+/// ```
+#[component]
+pub fn MenuSubmenu(
+    #[prop(into)] label: String,
+    #[prop(optional, into)] icon: Option<String>,
+    #[prop(optional)] disabled: bool,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let theme = use_theme();
+    let parent = use_context::<SubmenuContext>().unwrap_or_else(|| SubmenuContext {
+        path: RwSignal::new(SubmenuPath::default()),
+        depth: 0,
+    });
+    let id = NEXT_SUBMENU_ID.fetch_add(1, Ordering::Relaxed);
+    let depth = parent.depth;
+    let path = parent.path;
+
+    let is_open = Memo::new(move |_| path.with(|p| p.is_open(depth, id)));
+    let placement = RwSignal::new(FlyoutPlacement::default());
+    let hover_opened = RwSignal::new(false);
+    let anchor_ref = NodeRef::<leptos::html::Div>::new();
+    let flyout_ref = NodeRef::<leptos::html::Div>::new();
+
+    provide_context(SubmenuContext {
+        path,
+        depth: depth + 1,
+    });
+
+    let open = move || {
+        if !disabled {
+            path.update(|p| p.open(depth, id));
+        }
+    };
+    let close = move || path.update(|p| p.close(depth));
+
+    // Measure once the flyout is displayed and flip it if it would leave
+    // the viewport
+    Effect::new(move |_| {
+        if !is_open.get() {
+            placement.set(FlyoutPlacement::default());
+            return;
+        }
+        let (Some(anchor), Some(flyout)) = (anchor_ref.get(), flyout_ref.get()) else {
+            return;
+        };
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let viewport_width = window
+            .inner_width()
+            .ok()
+            .and_then(|v| v.as_f64())
+            .unwrap_or(f64::MAX);
+        let viewport_height = window
+            .inner_height()
+            .ok()
+            .and_then(|v| v.as_f64())
+            .unwrap_or(f64::MAX);
+        let rect = anchor.get_bounding_client_rect();
+        let size = flyout.get_bounding_client_rect();
+        placement.set(flyout_placement(
+            FlyoutRect::new(rect.left(), rect.top(), rect.right(), rect.bottom()),
+            size.width(),
+            size.height(),
+            viewport_width,
+            viewport_height,
+        ));
+    });
+
+    let focus_first_item = move || {
+        if let Some(flyout) = flyout_ref.get() {
+            if let Ok(Some(item)) = flyout.query_selector("[role='menuitem']") {
+                if let Ok(item) = item.dyn_into::<web_sys::HtmlElement>() {
+                    let _ = item.focus();
+                }
+            }
+        }
+    };
+
+    let handle_pointerenter = move |ev: web_sys::PointerEvent| {
+        if ev.pointer_type() == "mouse" && !is_open.get_untracked() {
+            hover_opened.set(true);
+            open();
+        }
+    };
+
+    let handle_click = move |ev: web_sys::MouseEvent| {
+        ev.stop_propagation();
+        if hover_opened.get_untracked() {
+            hover_opened.set(false);
+        } else if is_open.get_untracked() {
+            close();
+        } else {
+            open();
+        }
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if matches!(ev.key().as_str(), "ArrowRight" | "Enter" | " ") {
+            ev.prevent_default();
+            ev.stop_propagation();
+            open();
+            focus_first_item();
+        }
+    };
+
+    let handle_flyout_keydown = move |ev: web_sys::KeyboardEvent| {
+        if matches!(ev.key().as_str(), "ArrowLeft" | "Escape") {
+            ev.prevent_default();
+            ev.stop_propagation();
+            close();
+            if let Some(anchor) = anchor_ref.get() {
+                let _ = anchor.focus();
+            }
+        }
+    };
+
+    let item_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+
+        let cursor = if disabled { "not-allowed" } else { "pointer" };
+        let opacity = if disabled { "0.5" } else { "1" };
+        let background = if is_open.get() {
+            scheme_colors
+                .get_color("gray", 1)
+                .unwrap_or_else(|| "#f1f3f5".to_string())
+        } else {
+            "transparent".to_string()
+        };
+
+        format!(
+            "display: flex; \
+             align-items: center; \
+             gap: {}; \
+             padding: {} {}; \
+             border-radius: {}; \
+             font-size: {}; \
+             color: {}; \
+             background-color: {}; \
+             cursor: {}; \
+             opacity: {}; \
+             user-select: none; \
+             white-space: nowrap;",
+            &*theme_val.spacing.sm,
+            theme_val.spacing.xs,
+            theme_val.spacing.sm,
+            theme_val.radius.sm,
+            theme_val.typography.font_sizes.sm,
+            scheme_colors.text,
+            background,
+            cursor,
+            opacity
+        )
+    };
+
+    let flyout_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let current = placement.get();
+        let display = if is_open.get() { "block" } else { "none" };
+        let side = match current.side {
+            FlyoutSide::Right => "left: 100%; margin-left: 0.25rem;",
+            FlyoutSide::Left => "right: 100%; margin-right: 0.25rem;",
+        };
+
+        format!(
+            "position: absolute; \
+             top: {}px; \
+             {} \
+             min-width: 180px; \
+             background-color: {}; \
+             border: 1px solid {}; \
+             border-radius: {}; \
+             box-shadow: {}; \
+             z-index: 1001; \
+             padding: {}; \
+             display: {};",
+            current.shift_y,
+            side,
+            scheme_colors.background,
+            scheme_colors.border,
+            theme_val.radius.sm,
+            theme_val.shadows.md,
+            theme_val.spacing.xs,
+            display
+        )
+    };
+
+    let class_str = format!("mingot-menu-submenu {}", class.unwrap_or_default());
+
+    view! {
+        <div class=class_str style="position: relative;">
+            <div
+                node_ref=anchor_ref
+                class="mingot-menu-submenu-target"
+                role="menuitem"
+                aria-haspopup="menu"
+                aria-expanded=move || is_open.get().to_string()
+                aria-disabled=disabled.to_string()
+                tabindex=if disabled { "-1" } else { "0" }
+                style=move || {
+                    if let Some(s) = style.as_ref() {
+                        format!("{}; {}", item_styles(), s)
+                    } else {
+                        item_styles()
+                    }
+                }
+
+                on:pointerenter=handle_pointerenter
+                on:click=handle_click
+                on:keydown=handle_keydown
+            >
+                {icon.map(|i| view! { <span>{i}</span> })}
+                <span style="flex: 1;">{label}</span>
+                <span aria-hidden="true">"›"</span>
+            </div>
+            <div
+                node_ref=flyout_ref
+                class="mingot-menu-submenu-dropdown"
+                role="menu"
+                style=flyout_styles
+                on:keydown=handle_flyout_keydown
+            >
+                {children()}
+            </div>
+        </div>
+    }
+}
+
+/// One entry for [`DataMenu`]
+#[derive(Clone)]
+pub struct MenuItemData {
+    pub value: String,
+    pub label: String,
+    pub icon: Option<String>,
+    pub disabled: bool,
+    /// Draw a `MenuDivider` above this item
+    pub divider_before: bool,
+    pub on_click: Option<Callback<()>>,
+}
+
+impl MenuItemData {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+            disabled: false,
+            divider_before: false,
+            on_click: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn divider_before(mut self, divider: bool) -> Self {
+        self.divider_before = divider;
+        self
+    }
+
+    pub fn on_click(mut self, callback: Callback<()>) -> Self {
+        self.on_click = Some(callback);
+        self
+    }
+}
+
+/// `Menu` built from a list of [`MenuItemData`]. The children are the
+/// target that opens the dropdown. Use `Menu` with `MenuItem` children for
+/// custom item markup.
+///
+/// # Example
+/// ```rust,ignore
+/// let items = vec![
+///     MenuItemData::new("edit", "Edit").icon("✏️"),
+///     MenuItemData::new("delete", "Delete").divider_before(true),
+/// ];
+///
+/// view! {
+///     <DataMenu items=items on_select=Callback::new(|value: String| log!("{value}"))>
+///         <Button>"Actions"</Button>
+///     </DataMenu>
+/// }
+/// ```
+#[component]
+pub fn DataMenu(
+    items: Vec<MenuItemData>,
+    /// Called with the value of the clicked item, after its own `on_click`
+    #[prop(optional)]
+    on_select: Option<Callback<String>>,
+    /// Heading shown above the items
+    #[prop(optional, into)]
+    label: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    let entries = items
+        .into_iter()
+        .map(|item| {
+            let value = item.value;
+            let item_click = item.on_click;
+            let handle_click = Callback::new(move |_: ()| {
+                if let Some(callback) = item_click {
+                    callback.run(());
+                }
+                if let Some(callback) = on_select {
+                    callback.run(value.clone());
+                }
+            });
+            let label = item.label;
+            let entry = match item.icon {
+                Some(icon) => view! {
+                    <MenuItem icon=icon disabled=item.disabled on_click=handle_click>
+                        {label}
+                    </MenuItem>
+                }
+                .into_any(),
+                None => view! {
+                    <MenuItem disabled=item.disabled on_click=handle_click>{label}</MenuItem>
+                }
+                .into_any(),
+            };
+            view! {
+                {item.divider_before.then(|| view! { <MenuDivider /> })}
+                {entry}
+            }
+        })
+        .collect_view();
+
+    view! {
+        <Menu class=class.unwrap_or_default() style=style.unwrap_or_default()>
+            <MenuTarget>{children()}</MenuTarget>
+            <MenuDropdown>
+                {label.map(|l| view! { <MenuLabel>{l}</MenuLabel> })}
+                {entries}
+            </MenuDropdown>
+        </Menu>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_item_data_builder() {
+        let item = MenuItemData::new("delete", "Delete")
+            .icon("🗑")
+            .disabled(true)
+            .divider_before(true);
+        assert_eq!(item.value, "delete");
+        assert_eq!(item.label, "Delete");
+        assert_eq!(item.icon.as_deref(), Some("🗑"));
+        assert!(item.disabled);
+        assert!(item.divider_before);
+        assert!(item.on_click.is_none());
+    }
+
+    #[test]
+    fn test_submenu_path_open_replaces_sibling_branch() {
+        let mut path = SubmenuPath::default();
+        path.open(0, 1);
+        path.open(1, 2);
+        assert!(path.is_open(0, 1));
+        assert!(path.is_open(1, 2));
+        assert_eq!(path.depth(), 2);
+
+        // Opening a sibling at depth 0 drops the whole previous branch
+        path.open(0, 3);
+        assert_eq!(path.ids(), &[3]);
+        assert!(!path.is_open(0, 1));
+        assert!(!path.is_open(1, 2));
+    }
+
+    #[test]
+    fn test_submenu_path_close_truncates_deeper_levels() {
+        let mut path = SubmenuPath::default();
+        path.open(0, 1);
+        path.open(1, 2);
+        path.open(2, 5);
+        path.close(1);
+        assert_eq!(path.ids(), &[1]);
+        path.close(0);
+        assert_eq!(path.depth(), 0);
+
+        // Closing an empty path is a no-op
+        path.close(3);
+        assert_eq!(path, SubmenuPath::default());
+    }
+
+    #[test]
+    fn test_flyout_placement_prefers_right() {
+        let anchor = FlyoutRect::new(100.0, 50.0, 300.0, 80.0);
+        let placement = flyout_placement(anchor, 200.0, 150.0, 1024.0, 768.0);
+        assert_eq!(placement.side, FlyoutSide::Right);
+        assert_eq!(placement.shift_y, 0.0);
+    }
+
+    #[test]
+    fn test_flyout_placement_flips_left_near_right_edge() {
+        let anchor = FlyoutRect::new(800.0, 50.0, 1000.0, 80.0);
+        let placement = flyout_placement(anchor, 200.0, 150.0, 1024.0, 768.0);
+        assert_eq!(placement.side, FlyoutSide::Left);
+    }
+
+    #[test]
+    fn test_flyout_placement_stays_right_when_left_is_tighter() {
+        // Neither side fits, but the right has more room
+        let anchor = FlyoutRect::new(50.0, 50.0, 150.0, 80.0);
+        let placement = flyout_placement(anchor, 400.0, 150.0, 400.0, 768.0);
+        assert_eq!(placement.side, FlyoutSide::Right);
+    }
+
+    #[test]
+    fn test_flyout_placement_shifts_up_near_bottom_edge() {
+        let anchor = FlyoutRect::new(100.0, 700.0, 300.0, 730.0);
+        let placement = flyout_placement(anchor, 200.0, 150.0, 1024.0, 768.0);
+        assert_eq!(placement.shift_y, -82.0);
+
+        // Never pushed above the top of the viewport
+        let anchor = FlyoutRect::new(100.0, 40.0, 300.0, 70.0);
+        let placement = flyout_placement(anchor, 200.0, 900.0, 1024.0, 768.0);
+        assert_eq!(placement.shift_y, -40.0);
+    }
+}
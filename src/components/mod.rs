@@ -30,7 +30,9 @@ pub mod angle_input;
 pub mod checkbox;
 pub mod complex_number_input;
 pub mod coordinate_input;
+pub mod date_picker;
 pub mod equation_editor;
+pub mod field_wrapper;
 pub mod file_input;
 pub mod formula_input;
 pub mod fraction_input;
@@ -53,6 +55,7 @@ pub mod switch;
 pub mod symbol_palette;
 pub mod tensor_input;
 pub mod textarea;
+pub mod time_picker;
 pub mod uncertainty_input;
 pub mod unit_input;
 pub mod vector_input;
@@ -75,6 +78,7 @@ pub mod skeleton;
 pub mod accordion;
 pub mod avatar;
 pub mod badge;
+pub mod indicator;
 pub mod ring_progress;
 pub mod stats;
 pub mod table;
@@ -99,10 +103,12 @@ pub use checkbox::*;
 pub use complex_number_input::*;
 pub use container::*;
 pub use coordinate_input::*;
+pub use date_picker::*;
 pub use divider::*;
 pub use drawer::*;
 pub use equation_editor::*;
 pub use error_page::*;
+pub use field_wrapper::*;
 pub use file_input::*;
 pub use footer::*;
 pub use formula_input::*;
@@ -111,6 +117,7 @@ pub use grid::*;
 pub use group::*;
 pub use header::*;
 pub use hero::*;
+pub use indicator::*;
 pub use input::*;
 pub use interval_input::*;
 pub use loader::*;
@@ -147,6 +154,7 @@ pub use tabs::*;
 pub use tensor_input::*;
 pub use text::*;
 pub use textarea::*;
+pub use time_picker::*;
 pub use tooltip::*;
 pub use uncertainty_input::*;
 pub use unit_input::*;
@@ -1,8 +1,23 @@
 use crate::theme::use_theme;
 use leptos::ev;
 use leptos::prelude::*;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use wasm_bindgen::JsCast;
 
+/// Elements that may take focus, before disabled and hidden ones are
+/// filtered out
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button, input, select, textarea, \
+     iframe, [tabindex], [contenteditable='true']";
+
+static NEXT_MODAL_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static OPEN_MODALS: RefCell<ModalStack> = RefCell::new(ModalStack::default());
+    /// Body `overflow` and scroll offset saved when the first modal opened
+    static SCROLL_LOCK: RefCell<Option<(String, f64)>> = const { RefCell::new(None) };
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ModalSize {
     Xs,
@@ -26,6 +41,185 @@ impl ModalSize {
     }
 }
 
+/// What the focus trap needs to know about an element to decide whether
+/// Tab can land on it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FocusCandidate {
+    pub tag: String,
+    pub disabled: bool,
+    /// Not rendered (e.g. inside a `display: none` subtree)
+    pub hidden: bool,
+    pub tabindex: Option<i32>,
+    /// `type` attribute, for inputs
+    pub input_type: Option<String>,
+}
+
+impl FocusCandidate {
+    pub fn is_tabbable(&self) -> bool {
+        if self.hidden || self.tabindex.is_some_and(|t| t < 0) {
+            return false;
+        }
+        let tag = self.tag.to_ascii_lowercase();
+        let form_control = matches!(tag.as_str(), "button" | "input" | "select" | "textarea");
+        if form_control && self.disabled {
+            return false;
+        }
+        !(tag == "input" && self.input_type.as_deref() == Some("hidden"))
+    }
+}
+
+/// Indices of the candidates Tab can reach, in document order
+pub fn tabbable_indices(candidates: &[FocusCandidate]) -> Vec<usize> {
+    candidates
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.is_tabbable())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Index to focus when Tab (Shift+Tab when `backwards`) is pressed with
+/// element `current` focused. Wraps at both ends; focus outside the list
+/// goes to the first (or last) element. `None` when nothing is tabbable.
+pub fn next_focus_index(current: Option<usize>, count: usize, backwards: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    Some(match (current, backwards) {
+        (Some(i), false) => (i + 1) % count,
+        (Some(i), true) => (i + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    })
+}
+
+/// Open modals, innermost last. Only the topmost one handles keys.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ModalStack(Vec<usize>);
+
+impl ModalStack {
+    fn push(&mut self, id: usize) {
+        self.remove(id);
+        self.0.push(id);
+    }
+
+    fn remove(&mut self, id: usize) {
+        self.0.retain(|&open| open != id);
+    }
+
+    fn top(&self) -> Option<usize> {
+        self.0.last().copied()
+    }
+
+    fn contains(&self, id: usize) -> bool {
+        self.0.contains(&id)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn focus_candidate(element: &web_sys::HtmlElement) -> FocusCandidate {
+    FocusCandidate {
+        tag: element.tag_name(),
+        disabled: element.has_attribute("disabled"),
+        hidden: element.offset_parent().is_none(),
+        tabindex: element
+            .get_attribute("tabindex")
+            .and_then(|t| t.trim().parse().ok()),
+        input_type: element.get_attribute("type"),
+    }
+}
+
+/// Tabbable descendants of `root`, in document order
+fn tabbable_elements(root: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = root.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+    let elements: Vec<_> = (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect();
+    let candidates: Vec<_> = elements.iter().map(focus_candidate).collect();
+    tabbable_indices(&candidates)
+        .into_iter()
+        .map(|i| elements[i].clone())
+        .collect()
+}
+
+fn active_element() -> Option<web_sys::HtmlElement> {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok())
+}
+
+fn lock_scroll() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(body) = window.document().and_then(|d| d.body()) else {
+        return;
+    };
+    let overflow = body
+        .style()
+        .get_property_value("overflow")
+        .unwrap_or_default();
+    let scroll_y = window.scroll_y().unwrap_or(0.0);
+    SCROLL_LOCK.with(|lock| *lock.borrow_mut() = Some((overflow, scroll_y)));
+    let _ = body.style().set_property("overflow", "hidden");
+}
+
+fn unlock_scroll() {
+    let Some((overflow, scroll_y)) = SCROLL_LOCK.with(|lock| lock.borrow_mut().take()) else {
+        return;
+    };
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Some(body) = window.document().and_then(|d| d.body()) {
+        if overflow.is_empty() {
+            let _ = body.style().remove_property("overflow");
+        } else {
+            let _ = body.style().set_property("overflow", &overflow);
+        }
+    }
+    window.scroll_to_with_x_and_y(window.scroll_x().unwrap_or(0.0), scroll_y);
+}
+
+/// Register modal `id` as open, locking body scroll for the first one
+fn open_modal(id: usize) {
+    let first = OPEN_MODALS.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let first = stack.is_empty();
+        stack.push(id);
+        first
+    });
+    if first {
+        lock_scroll();
+    }
+}
+
+/// Unregister modal `id`, restoring body scroll once none is left
+fn release_modal(id: usize) {
+    let last = OPEN_MODALS.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        if !stack.contains(id) {
+            return false;
+        }
+        stack.remove(id);
+        stack.is_empty()
+    });
+    if last {
+        unlock_scroll();
+    }
+}
+
+/// Dialog over a dimmed overlay. While open it traps Tab focus, locks
+/// page scroll and closes on Escape or an overlay click; focus returns to
+/// the previously focused element on close. With stacked modals only the
+/// topmost one reacts to the keyboard.
 #[component]
 pub fn Modal(
     #[prop(into)] opened: Signal<bool>,
@@ -33,14 +227,49 @@ pub fn Modal(
     #[prop(optional)] size: Option<ModalSize>,
     #[prop(optional, into)] title: Option<String>,
     #[prop(optional)] centered: bool,
-    #[prop(optional)] close_on_click_outside: bool,
-    #[prop(optional)] _close_on_escape: bool,
+    /// Call `on_close` when the overlay is clicked (opt-in)
+    #[prop(optional)]
+    close_on_click_outside: bool,
+    /// Call `on_close` when Escape is pressed (default `true`)
+    #[prop(optional)]
+    close_on_escape: Option<bool>,
     #[prop(optional)] with_close_button: bool,
     #[prop(optional, into)] padding: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
     let size = size.unwrap_or(ModalSize::Md);
+    let close_on_escape = close_on_escape.unwrap_or(true);
+    let id = NEXT_MODAL_ID.fetch_add(1, Ordering::Relaxed);
+    let modal_ref = NodeRef::<leptos::html::Div>::new();
+    let return_focus = StoredValue::new_local(None::<web_sys::HtmlElement>);
+
+    Effect::new(move |was_open: Option<bool>| {
+        let is_open = opened.get();
+        let was_open = was_open.unwrap_or(false);
+        if is_open && !was_open {
+            return_focus.set_value(active_element());
+            open_modal(id);
+            if let Some(modal) = modal_ref.get() {
+                match tabbable_elements(&modal).first() {
+                    Some(first) => {
+                        let _ = first.focus();
+                    }
+                    None => {
+                        let _ = modal.focus();
+                    }
+                }
+            }
+        } else if !is_open && was_open {
+            release_modal(id);
+            if let Some(previous) = return_focus.get_value() {
+                let _ = previous.focus();
+            }
+        }
+        is_open
+    });
+
+    on_cleanup(move || release_modal(id));
 
     let overlay_styles = move || {
         let _theme_val = theme.get();
@@ -144,17 +373,48 @@ pub fn Modal(
         }
     };
 
-    // TODO: Add escape key handler when close_on_escape is true
-    // This would require using window event listeners
+    let aria_label = title.clone();
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if OPEN_MODALS.with(|stack| stack.borrow().top()) != Some(id) {
+            return;
+        }
+        match ev.key().as_str() {
+            "Escape" if close_on_escape => {
+                ev.prevent_default();
+                ev.stop_propagation();
+                handle_close();
+            }
+            "Tab" => {
+                ev.prevent_default();
+                let Some(modal) = modal_ref.get() else {
+                    return;
+                };
+                let elements = tabbable_elements(&modal);
+                let current = active_element()
+                    .and_then(|focused| elements.iter().position(|element| element == &focused));
+                if let Some(target) = next_focus_index(current, elements.len(), ev.shift_key()) {
+                    let _ = elements[target].focus();
+                }
+            }
+            _ => {}
+        }
+    };
 
     view! {
         <div
             class="mingot-modal-overlay"
             style=overlay_styles
             on:click=handle_overlay_click
+            on:keydown=handle_keydown
         >
             <div
+                node_ref=modal_ref
                 class="mingot-modal"
+                role="dialog"
+                aria-modal="true"
+                aria-label=aria_label
+                tabindex="-1"
                 style=modal_styles
                 on:click=|ev: ev::MouseEvent| {
                     // Prevent clicks on modal from bubbling to overlay
@@ -194,3 +454,83 @@ pub fn Modal(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(tag: &str) -> FocusCandidate {
+        FocusCandidate {
+            tag: tag.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tabbable_indices_skip_disabled_hidden_and_negative_tabindex() {
+        let candidates = vec![
+            candidate("BUTTON"),
+            FocusCandidate {
+                disabled: true,
+                ..candidate("INPUT")
+            },
+            FocusCandidate {
+                tabindex: Some(-1),
+                ..candidate("DIV")
+            },
+            FocusCandidate {
+                hidden: true,
+                ..candidate("A")
+            },
+            FocusCandidate {
+                input_type: Some("hidden".to_string()),
+                ..candidate("INPUT")
+            },
+            FocusCandidate {
+                tabindex: Some(0),
+                ..candidate("DIV")
+            },
+            candidate("TEXTAREA"),
+        ];
+        assert_eq!(tabbable_indices(&candidates), vec![0, 5, 6]);
+    }
+
+    #[test]
+    fn test_disabled_only_blocks_form_controls() {
+        let link = FocusCandidate {
+            disabled: true,
+            ..candidate("a")
+        };
+        assert!(link.is_tabbable());
+    }
+
+    #[test]
+    fn test_next_focus_index_wraps() {
+        assert_eq!(next_focus_index(Some(0), 3, false), Some(1));
+        assert_eq!(next_focus_index(Some(2), 3, false), Some(0));
+        assert_eq!(next_focus_index(Some(0), 3, true), Some(2));
+        assert_eq!(next_focus_index(Some(1), 3, true), Some(0));
+        // Focus outside the modal re-enters at the matching end
+        assert_eq!(next_focus_index(None, 3, false), Some(0));
+        assert_eq!(next_focus_index(None, 3, true), Some(2));
+        assert_eq!(next_focus_index(Some(0), 1, false), Some(0));
+        assert_eq!(next_focus_index(None, 0, false), None);
+    }
+
+    #[test]
+    fn test_modal_stack_topmost() {
+        let mut stack = ModalStack::default();
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.top(), Some(2));
+        stack.remove(2);
+        assert_eq!(stack.top(), Some(1));
+        // Re-opening moves a modal back to the top
+        stack.push(3);
+        stack.push(1);
+        assert_eq!(stack.top(), Some(1));
+        stack.remove(1);
+        stack.remove(3);
+        assert!(stack.is_empty());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::theme::use_theme;
+use crate::theme::{use_reduced_motion, use_theme};
+use crate::utils::TicketGate;
 use leptos::prelude::*;
 use std::collections::HashMap;
 
@@ -12,8 +13,9 @@ pub enum NotificationPosition {
     BottomCenter,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum NotificationColor {
+    #[default]
     Info,
     Success,
     Warning,
@@ -40,33 +42,354 @@ impl NotificationColor {
     }
 }
 
-#[derive(Clone, Debug)]
+/// How urgently a notification should grab attention
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NotificationPriority {
+    Low,
+    #[default]
+    Normal,
+    /// Bypasses `max_notifications` and may beep/vibrate if the provider opts in
+    High,
+}
+
+/// When a notification dismisses itself
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NotificationAutoClose {
+    /// Use the provider's `auto_close`
+    #[default]
+    Default,
+    /// Stay until closed
+    Never,
+    /// Close after this many milliseconds
+    After(u32),
+}
+
+impl NotificationAutoClose {
+    /// Milliseconds until dismissal, `None` for sticky notifications
+    pub fn resolve(self, default: Option<u32>) -> Option<u32> {
+        match self {
+            NotificationAutoClose::Default => default,
+            NotificationAutoClose::Never => None,
+            NotificationAutoClose::After(ms) => Some(ms),
+        }
+    }
+}
+
+impl From<u32> for NotificationAutoClose {
+    fn from(ms: u32) -> Self {
+        NotificationAutoClose::After(ms)
+    }
+}
+
+/// `false` makes a notification sticky; `true` uses the default
+impl From<bool> for NotificationAutoClose {
+    fn from(enabled: bool) -> Self {
+        if enabled {
+            NotificationAutoClose::Default
+        } else {
+            NotificationAutoClose::Never
+        }
+    }
+}
+
+/// A notification to show. Build it with [`show_notification`] or a
+/// struct literal ending in `..Default::default()`, so fields added later
+/// keep their defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct NotificationData {
+    /// Assigned when shown; pass it to `update_notification` or
+    /// `hide_notification`
     pub id: usize,
     pub title: Option<String>,
     pub message: String,
     pub color: NotificationColor,
     pub icon: Option<String>,
-    pub auto_close: Option<u32>, // milliseconds
+    pub auto_close: NotificationAutoClose,
+    pub priority: NotificationPriority,
+}
+
+/// Auto-close countdown that pauses while the pointer is over the
+/// notification. Each `resume` hands out a ticket for the pending
+/// timeout; pausing or cancelling invalidates it, so a stale timeout
+/// never dismisses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DismissTimer {
+    duration: f64,
+    remaining: f64,
+    running_since: Option<f64>,
+    tickets: TicketGate,
+    cancelled: bool,
+}
+
+impl DismissTimer {
+    /// A paused timer with `duration` milliseconds left
+    pub fn new(duration: f64) -> Self {
+        Self {
+            duration,
+            remaining: duration,
+            running_since: None,
+            tickets: TicketGate::default(),
+            cancelled: false,
+        }
+    }
+
+    /// Start or restart counting down at `now`. Returns the ticket and
+    /// the delay to wait, or `None` if already running or cancelled.
+    pub fn resume(&mut self, now: f64) -> Option<(u64, f64)> {
+        if self.cancelled || self.running_since.is_some() {
+            return None;
+        }
+        self.running_since = Some(now);
+        Some((self.tickets.issue(), self.remaining))
+    }
+
+    /// Stop counting down at `now`, keeping the time left
+    pub fn pause(&mut self, now: f64) {
+        if let Some(since) = self.running_since.take() {
+            self.remaining = (self.remaining - (now - since)).max(0.0);
+            self.tickets.invalidate();
+        }
+    }
+
+    /// Stop for good, e.g. when closed by hand
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+        self.running_since = None;
+        self.tickets.invalidate();
+    }
+
+    /// Whether the timeout holding `ticket` should dismiss the notification
+    pub fn should_dismiss(&self, ticket: u64) -> bool {
+        !self.cancelled && self.running_since.is_some() && self.tickets.is_current(ticket)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Milliseconds left at `now`
+    pub fn remaining(&self, now: f64) -> f64 {
+        match self.running_since {
+            Some(since) => (self.remaining - (now - since)).max(0.0),
+            None => self.remaining,
+        }
+    }
+
+    /// Share of the duration left at `now`, from 1.0 down to 0.0
+    pub fn fraction_remaining(&self, now: f64) -> f64 {
+        if self.duration <= 0.0 {
+            return 0.0;
+        }
+        self.remaining(now) / self.duration
+    }
+}
+
+/// Provider-wide defaults for its notifications
+#[derive(Clone, Copy, Debug)]
+struct NotificationDefaults {
+    auto_close: Option<u32>,
+    show_countdown: bool,
+}
+
+impl Default for NotificationDefaults {
+    fn default() -> Self {
+        Self {
+            auto_close: Some(DEFAULT_AUTO_CLOSE_MS),
+            show_countdown: false,
+        }
+    }
 }
 
-type NotificationMap = RwSignal<HashMap<usize, NotificationData>>;
-type NotificationIdCounter = RwSignal<usize>;
+const DEFAULT_AUTO_CLOSE_MS: u32 = 5000;
+
+/// Milliseconds since the epoch, for pausing countdowns
+fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Notifications currently shown by a provider
+#[derive(Clone, Debug, Default)]
+struct NotificationStore {
+    items: HashMap<usize, NotificationData>,
+    /// Tick of the last show/update per id; eviction drops the stalest
+    touched: HashMap<usize, u64>,
+    clock: u64,
+    next_id: usize,
+}
+
+impl NotificationStore {
+    fn touch(&mut self, id: usize) {
+        self.clock += 1;
+        self.touched.insert(id, self.clock);
+    }
+
+    /// Add `data` under a fresh id, evicting to stay within `max`
+    fn show(&mut self, mut data: NotificationData, max: usize) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        data.id = id;
+        if let Some(evicted) = notification_to_evict(&self.items, &self.touched, max, data.priority)
+        {
+            self.hide(evicted);
+        }
+        self.items.insert(id, data);
+        self.touch(id);
+        id
+    }
+
+    /// Replace the content of notification `id` in place. `false` (and no
+    /// change) when it is not shown.
+    fn update(&mut self, id: usize, mut data: NotificationData) -> bool {
+        let Some(existing) = self.items.get_mut(&id) else {
+            return false;
+        };
+        data.id = id;
+        *existing = data;
+        self.touch(id);
+        true
+    }
+
+    fn hide(&mut self, id: usize) -> bool {
+        self.touched.remove(&id);
+        self.items.remove(&id).is_some()
+    }
+}
+
+/// Opt-in attention alerts for high-priority notifications
+#[derive(Clone, Copy)]
+struct NotificationAlerts {
+    sound: bool,
+    vibrate: bool,
+    muted: Signal<bool>,
+    reduced_motion: Signal<bool>,
+}
+
+impl NotificationAlerts {
+    /// Which alerts (sound, vibration) to fire for a notification
+    fn for_priority(self, priority: NotificationPriority) -> (bool, bool) {
+        if priority < NotificationPriority::High || self.muted.get_untracked() {
+            return (false, false);
+        }
+        (
+            self.sound,
+            self.vibrate && !self.reduced_motion.get_untracked(),
+        )
+    }
+}
+
+/// Notification to drop to make room for `incoming`, if any.
+///
+/// High-priority notifications don't count toward `max` and are never
+/// evicted; otherwise the lower-priority one shown or updated longest ago
+/// goes (by `touched` tick, then by id), so a toast that keeps being
+/// updated stays.
+fn notification_to_evict(
+    existing: &HashMap<usize, NotificationData>,
+    touched: &HashMap<usize, u64>,
+    max: usize,
+    incoming: NotificationPriority,
+) -> Option<usize> {
+    if incoming >= NotificationPriority::High {
+        return None;
+    }
+    let queued: Vec<usize> = existing
+        .values()
+        .filter(|n| n.priority < NotificationPriority::High)
+        .map(|n| n.id)
+        .collect();
+    if queued.len() >= max {
+        queued
+            .into_iter()
+            .min_by_key(|id| (touched.get(id).copied().unwrap_or(0), *id))
+    } else {
+        None
+    }
+}
+
+/// Short two-tone beep through WebAudio
+fn play_alert_beep() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Ok(ctx) = web_sys::AudioContext::new() else {
+            return;
+        };
+        let now = ctx.current_time();
+
+        for (i, frequency) in [880.0_f32, 660.0].into_iter().enumerate() {
+            let (Ok(oscillator), Ok(gain)) = (ctx.create_oscillator(), ctx.create_gain()) else {
+                return;
+            };
+            let start = now + i as f64 * 0.18;
+            oscillator.set_type(web_sys::OscillatorType::Sine);
+            oscillator.frequency().set_value(frequency);
+            gain.gain().set_value(0.15);
+            let _ = gain
+                .gain()
+                .exponential_ramp_to_value_at_time(0.001, start + 0.15);
+            let _ = oscillator.connect_with_audio_node(&gain);
+            let _ = gain.connect_with_audio_node(&ctx.destination());
+            let _ = oscillator.start_with_when(start);
+            let _ = oscillator.stop_with_when(start + 0.15);
+        }
+
+        set_timeout(
+            move || {
+                let _ = ctx.close();
+            },
+            std::time::Duration::from_millis(600),
+        );
+    }
+}
+
+/// Vibrate the device, where supported (mostly mobile)
+fn vibrate_device() {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(window) = web_sys::window() {
+        let _ = window.navigator().vibrate_with_duration(200);
+    }
+}
 
 #[component]
 pub fn NotificationProvider(
     #[prop(optional)] position: Option<NotificationPosition>,
     #[prop(optional)] max_notifications: Option<usize>,
+    /// Beep for high-priority notifications (off by default)
+    #[prop(optional)]
+    sound: bool,
+    /// Vibrate for high-priority notifications, unless the user prefers
+    /// reduced motion (off by default)
+    #[prop(optional)]
+    vibrate: bool,
+    /// Global mute for sound and vibration
+    #[prop(optional, into)]
+    muted: Signal<bool>,
+    /// Auto-close for notifications that don't set their own: a duration
+    /// in milliseconds, or `false` to keep them until closed (default 5000)
+    #[prop(optional, into)]
+    auto_close: Option<NotificationAutoClose>,
+    /// Show a shrinking bar with the time left before auto-close
+    #[prop(optional)]
+    show_countdown: bool,
     children: Children,
 ) -> impl IntoView {
     let position = position.unwrap_or(NotificationPosition::TopRight);
-    let notifications = RwSignal::new(HashMap::new());
-    let id_counter = RwSignal::new(0usize);
+    let store = RwSignal::new(NotificationStore::default());
 
-    provide_context::<NotificationMap>(notifications);
-    provide_context::<NotificationIdCounter>(id_counter);
+    provide_context(store);
     provide_context::<Signal<NotificationPosition>>(Signal::derive(move || position));
     provide_context::<Signal<usize>>(Signal::derive(move || max_notifications.unwrap_or(5)));
+    provide_context(NotificationAlerts {
+        sound,
+        vibrate,
+        muted,
+        reduced_motion: use_reduced_motion(),
+    });
+    provide_context(NotificationDefaults {
+        auto_close: auto_close
+            .unwrap_or_default()
+            .resolve(Some(DEFAULT_AUTO_CLOSE_MS)),
+        show_countdown,
+    });
 
     view! {
         <>
@@ -79,8 +402,8 @@ pub fn NotificationProvider(
 #[component]
 fn NotificationContainer() -> impl IntoView {
     let theme = use_theme();
-    let notifications =
-        use_context::<NotificationMap>().unwrap_or_else(|| RwSignal::new(HashMap::new()));
+    let store = use_context::<RwSignal<NotificationStore>>()
+        .unwrap_or_else(|| RwSignal::new(NotificationStore::default()));
     let position = use_context::<Signal<NotificationPosition>>()
         .unwrap_or_else(|| Signal::derive(move || NotificationPosition::TopRight));
 
@@ -122,57 +445,116 @@ fn NotificationContainer() -> impl IntoView {
         )
     };
 
+    // Keyed by id so existing toasts (and their timers) survive when
+    // another one is added, updated or removed
+    let ordered = move || {
+        let mut ids: Vec<usize> = store.with(|s| s.items.keys().copied().collect());
+        ids.sort_unstable();
+        ids
+    };
+
     view! {
         <div class="mingot-notification-container" style=container_styles>
-            {move || {
-                let notifs = notifications.get();
-                notifs
-                    .values()
-                    .cloned()
-                    .collect::<Vec<_>>()
-                    .into_iter()
-                    .map(|notification| {
-                        view! { <NotificationItem notification=notification /> }
-                    })
-                    .collect::<Vec<_>>()
-            }}
-
+            <style>
+                "@keyframes mingot-notification-countdown {
+                    from { transform: scaleX(1); }
+                    to { transform: scaleX(0); }
+                }"
+            </style>
+            <For each=ordered key=|id| *id let:id>
+                <NotificationItem id=id />
+            </For>
         </div>
     }
 }
 
 #[component]
-fn NotificationItem(notification: NotificationData) -> impl IntoView {
+fn NotificationItem(id: usize) -> impl IntoView {
     let theme = use_theme();
-    let notifications =
-        use_context::<NotificationMap>().unwrap_or_else(|| RwSignal::new(HashMap::new()));
+    let store = use_context::<RwSignal<NotificationStore>>()
+        .unwrap_or_else(|| RwSignal::new(NotificationStore::default()));
+
+    let defaults = use_context::<NotificationDefaults>().unwrap_or_default();
+
+    // Follows `update_notification` so the toast changes in place
+    let notification = Memo::new(move |_| {
+        store
+            .with(|s| s.items.get(&id).cloned())
+            .unwrap_or_else(|| show_notification("", NotificationColor::Info, None))
+    });
+    let color = move || notification.with(|n| n.color);
 
-    let id = notification.id;
     let is_visible = RwSignal::new(true);
+    let paused = RwSignal::new(false);
+    let duration =
+        Memo::new(move |_| notification.with(|n| n.auto_close.resolve(defaults.auto_close)));
+    let timer = StoredValue::new(None::<DismissTimer>);
 
-    // Auto-close timer
-    if let Some(duration) = notification.auto_close {
+    let dismiss = move || {
+        is_visible.set(false);
+        // Wait for animation then remove
         set_timeout(
             move || {
-                is_visible.set(false);
-                // Wait for animation then remove
-                set_timeout(
-                    move || {
-                        notifications.update(|n| {
-                            n.remove(&id);
-                        });
-                    },
-                    std::time::Duration::from_millis(300),
-                );
+                store.update(|s| {
+                    s.hide(id);
+                });
             },
-            std::time::Duration::from_millis(duration as u64),
+            std::time::Duration::from_millis(300),
         );
-    }
+    };
+
+    let start_countdown = move || {
+        let Some(Some((ticket, delay))) =
+            timer.try_update_value(|t| t.as_mut().and_then(|t| t.resume(now_ms())))
+        else {
+            return;
+        };
+        set_timeout(
+            move || {
+                let expired = timer
+                    .try_with_value(|t| t.as_ref().is_some_and(|t| t.should_dismiss(ticket)))
+                    .unwrap_or(false);
+                if expired {
+                    dismiss();
+                }
+            },
+            std::time::Duration::from_millis(delay.ceil() as u64),
+        );
+    };
+
+    // (Re)start the countdown whenever the auto-close changes, e.g. when
+    // an update turns a sticky "Uploading…" into a closing "Done"
+    Effect::new(move |_| {
+        let ms = duration.get();
+        timer.update_value(|t| {
+            if let Some(old) = t {
+                old.cancel();
+            }
+            *t = ms.map(|ms| DismissTimer::new(ms as f64));
+        });
+        if !paused.get_untracked() {
+            start_countdown();
+        }
+    });
+
+    let handle_mouseenter = move |_| {
+        timer.update_value(|t| {
+            if let Some(t) = t {
+                t.pause(now_ms());
+            }
+        });
+        paused.set(true);
+    };
+
+    let handle_mouseleave = move |_| {
+        paused.set(false);
+        start_countdown();
+    };
 
     let notification_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        let color_name = notification.color.to_color_name();
+        let color_name = color().to_color_name();
 
         let bg_color = scheme_colors
             .get_color(color_name, 0)
@@ -204,6 +586,8 @@ fn NotificationItem(notification: NotificationData) -> impl IntoView {
              min-width: 300px; \
              max-width: 400px; \
              pointer-events: auto; \
+             position: relative; \
+             overflow: hidden; \
              opacity: {}; \
              transform: {}; \
              transition: all 0.3s ease;",
@@ -267,65 +651,149 @@ fn NotificationItem(notification: NotificationData) -> impl IntoView {
     };
 
     let handle_close = move |_| {
-        is_visible.set(false);
-        set_timeout(
-            move || {
-                notifications.update(|n| {
-                    n.remove(&id);
-                });
-            },
-            std::time::Duration::from_millis(300),
-        );
+        timer.update_value(|t| {
+            if let Some(t) = t {
+                t.cancel();
+            }
+        });
+        dismiss();
+    };
+
+    let countdown_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let color = scheme_colors
+            .get_color(color().to_color_name(), 6)
+            .unwrap_or_else(|| "#228be6".to_string());
+        format!(
+            "position: absolute; \
+             left: 0; \
+             bottom: 0; \
+             width: 100%; \
+             height: 3px; \
+             background-color: {}; \
+             transform-origin: left center; \
+             animation: mingot-notification-countdown {}ms linear forwards; \
+             animation-play-state: {};",
+            color,
+            duration.get().unwrap_or(0),
+            if paused.get() { "paused" } else { "running" }
+        )
+    };
+
+    // High priority interrupts screen readers; the rest wait politely
+    let role = move || {
+        if notification.with(|n| n.priority) >= NotificationPriority::High {
+            "alert"
+        } else {
+            "status"
+        }
     };
 
-    let icon_display = notification
-        .icon
-        .unwrap_or_else(|| notification.color.default_icon().to_string());
+    let icon_display = move || {
+        notification.with(|n| {
+            n.icon
+                .clone()
+                .unwrap_or_else(|| n.color.default_icon().to_string())
+        })
+    };
+    let title = move || notification.with(|n| n.title.clone());
+    let message = move || notification.with(|n| n.message.clone());
 
     view! {
-        <div class="mingot-notification" style=notification_styles>
+        <div
+            class="mingot-notification"
+            style=notification_styles
+            role=role
+            on:mouseenter=handle_mouseenter
+            on:mouseleave=handle_mouseleave
+        >
             <div class="mingot-notification-icon" style=icon_styles>
                 {icon_display}
             </div>
 
             <div class="mingot-notification-content" style=content_styles>
-                {notification.title.as_ref().map(|t| {
-                    view! { <div class="mingot-notification-title" style=title_styles>{t.clone()}</div> }
+                {move || title().map(|t| {
+                    view! { <div class="mingot-notification-title" style=title_styles>{t}</div> }
                 })}
                 <div class="mingot-notification-message" style=message_styles>
-                    {notification.message}
+                    {message}
                 </div>
             </div>
 
             <button class="mingot-notification-close" style=close_button_styles on:click=handle_close>
                 "✕"
             </button>
+
+            {move || (defaults.show_countdown && duration.get().is_some())
+                .then(|| view! { <div class="mingot-notification-countdown" style=countdown_styles></div> })}
         </div>
     }
 }
 
-// Hook for showing notifications
-pub fn use_notifications() -> impl Fn(NotificationData) {
-    let notifications =
-        use_context::<NotificationMap>().unwrap_or_else(|| RwSignal::new(HashMap::new()));
-    let id_counter = use_context::<NotificationIdCounter>().unwrap_or_else(|| RwSignal::new(0));
-    let max_notifications =
-        use_context::<Signal<usize>>().unwrap_or_else(|| Signal::derive(move || 5));
-
-    move |mut data: NotificationData| {
-        let id = id_counter.get();
-        id_counter.update(|c| *c += 1);
-        data.id = id;
+/// Handle returned by [`use_notifications`] for showing, updating and
+/// hiding the provider's notifications
+#[derive(Clone, Copy)]
+pub struct Notifications {
+    store: RwSignal<NotificationStore>,
+    max_notifications: Signal<usize>,
+    alerts: Option<NotificationAlerts>,
+}
 
-        notifications.update(|n| {
-            // Remove oldest if at max
-            if n.len() >= max_notifications.get() {
-                if let Some(oldest_id) = n.keys().min().copied() {
-                    n.remove(&oldest_id);
-                }
+impl Notifications {
+    /// Show a notification and return its id
+    pub fn show(&self, data: NotificationData) -> usize {
+        if let Some(alerts) = self.alerts {
+            let (beep, vibrate) = alerts.for_priority(data.priority);
+            if beep {
+                play_alert_beep();
             }
-            n.insert(id, data);
-        });
+            if vibrate {
+                vibrate_device();
+            }
+        }
+
+        let max = self.max_notifications.get_untracked();
+        let mut id = 0;
+        self.store.update(|s| id = s.show(data, max));
+        id
+    }
+
+    /// Change a shown notification in place (message, color, auto-close,
+    /// ...). Does nothing and returns `false` if `id` is no longer shown.
+    pub fn update_notification(&self, id: usize, data: NotificationData) -> bool {
+        let mut updated = false;
+        self.store.update(|s| updated = s.update(id, data));
+        updated
+    }
+
+    /// Remove a notification; `false` if it was not shown
+    pub fn hide_notification(&self, id: usize) -> bool {
+        let mut hidden = false;
+        self.store.update(|s| hidden = s.hide(id));
+        hidden
+    }
+}
+
+/// Notifications of the nearest `NotificationProvider`
+///
+/// # Example
+/// ```rust,ignore
+/// let notifications = use_notifications();
+/// let id = notifications.show(NotificationData {
+///     auto_close: NotificationAutoClose::Never,
+///     ..show_notification("Uploading…", NotificationColor::Info, None)
+/// });
+/// // later
+/// notifications.update_notification(id, show_notification("Done", NotificationColor::Success, None));
+/// ```
+pub fn use_notifications() -> Notifications {
+    Notifications {
+        store: use_context::<RwSignal<NotificationStore>>()
+            .unwrap_or_else(|| RwSignal::new(NotificationStore::default())),
+        max_notifications: use_context::<Signal<usize>>()
+            .unwrap_or_else(|| Signal::derive(move || 5)),
+        alerts: use_context::<NotificationAlerts>(),
     }
 }
 
@@ -336,11 +804,217 @@ pub fn show_notification(
     title: Option<String>,
 ) -> NotificationData {
     NotificationData {
-        id: 0, // Will be set by use_notifications
         title,
         message: message.into(),
         color,
-        icon: None,
-        auto_close: Some(5000), // 5 seconds default
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(id: usize, priority: NotificationPriority) -> NotificationData {
+        NotificationData {
+            id,
+            priority,
+            ..show_notification("msg", NotificationColor::Info, None)
+        }
+    }
+
+    #[test]
+    fn test_notification_data_default() {
+        let data = NotificationData {
+            message: "Saved".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            data,
+            show_notification("Saved", NotificationColor::Info, None)
+        );
+        assert_eq!(data.priority, NotificationPriority::Normal);
+        assert_eq!(data.auto_close, NotificationAutoClose::Default);
+    }
+
+    fn map(items: &[(usize, NotificationPriority)]) -> HashMap<usize, NotificationData> {
+        items
+            .iter()
+            .map(|&(id, p)| (id, notification(id, p)))
+            .collect()
+    }
+
+    #[test]
+    fn test_evicts_oldest_when_full() {
+        use NotificationPriority::*;
+        let existing = map(&[(0, Normal), (1, Low), (2, Normal)]);
+        assert_eq!(
+            notification_to_evict(&existing, &HashMap::new(), 3, Normal),
+            Some(0)
+        );
+        assert_eq!(
+            notification_to_evict(&existing, &HashMap::new(), 4, Normal),
+            None
+        );
+    }
+
+    #[test]
+    fn test_high_priority_bypasses_limit() {
+        use NotificationPriority::*;
+        let existing = map(&[(0, High), (1, Normal), (2, Normal)]);
+        // Incoming high priority never evicts
+        assert_eq!(
+            notification_to_evict(&existing, &HashMap::new(), 2, High),
+            None
+        );
+        // High priority neither counts toward the limit nor gets evicted
+        assert_eq!(
+            notification_to_evict(&existing, &HashMap::new(), 3, Normal),
+            None
+        );
+        assert_eq!(
+            notification_to_evict(&existing, &HashMap::new(), 2, Normal),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_store_update_by_id_in_place() {
+        let mut store = NotificationStore::default();
+        let id = store.show(
+            show_notification("Uploading…", NotificationColor::Info, None),
+            5,
+        );
+        let other = store.show(show_notification("Other", NotificationColor::Info, None), 5);
+
+        assert!(store.update(
+            id,
+            show_notification("Done", NotificationColor::Success, None)
+        ));
+        assert_eq!(store.items.len(), 2);
+        let updated = &store.items[&id];
+        assert_eq!(updated.id, id);
+        assert_eq!(updated.message, "Done");
+        assert_eq!(updated.color, NotificationColor::Success);
+        assert_eq!(store.items[&other].message, "Other");
+
+        // Unknown ids are a no-op
+        assert!(!store.update(99, show_notification("x", NotificationColor::Info, None)));
+        assert!(!store.items.contains_key(&99));
+    }
+
+    #[test]
+    fn test_store_hide_by_id() {
+        let mut store = NotificationStore::default();
+        let id = store.show(show_notification("a", NotificationColor::Info, None), 5);
+        assert!(store.hide(id));
+        assert!(store.items.is_empty());
+        assert!(!store.hide(id));
+        assert!(!store.update(id, show_notification("b", NotificationColor::Info, None)));
+    }
+
+    #[test]
+    fn test_store_keeps_recently_updated_when_full() {
+        let mut store = NotificationStore::default();
+        let progress = store.show(show_notification("0%", NotificationColor::Info, None), 2);
+        let second = store.show(show_notification("b", NotificationColor::Info, None), 2);
+        store.update(
+            progress,
+            show_notification("50%", NotificationColor::Info, None),
+        );
+
+        // The oldest by id is being updated, so the other one goes
+        let third = store.show(show_notification("c", NotificationColor::Info, None), 2);
+        assert!(store.items.contains_key(&progress));
+        assert!(!store.items.contains_key(&second));
+        assert!(store.items.contains_key(&third));
+    }
+
+    #[test]
+    fn test_auto_close_resolution() {
+        assert_eq!(
+            NotificationAutoClose::Default.resolve(Some(5000)),
+            Some(5000)
+        );
+        assert_eq!(NotificationAutoClose::Default.resolve(None), None);
+        assert_eq!(NotificationAutoClose::from(false).resolve(Some(5000)), None);
+        assert_eq!(
+            NotificationAutoClose::from(true),
+            NotificationAutoClose::Default
+        );
+        assert_eq!(NotificationAutoClose::from(800).resolve(None), Some(800));
+    }
+
+    #[test]
+    fn test_dismiss_timer_runs_out() {
+        let mut timer = DismissTimer::new(1000.0);
+        assert!(!timer.is_running());
+        let (ticket, delay) = timer.resume(0.0).unwrap();
+        assert_eq!(delay, 1000.0);
+        assert!(timer.is_running());
+        assert_eq!(timer.fraction_remaining(250.0), 0.75);
+        assert!(timer.should_dismiss(ticket));
+        // Already running: no second timeout
+        assert_eq!(timer.resume(10.0), None);
+    }
+
+    #[test]
+    fn test_dismiss_timer_hover_pause_and_resume() {
+        let mut timer = DismissTimer::new(1000.0);
+        let (first, _) = timer.resume(0.0).unwrap();
+
+        // Hover after 400ms freezes the remaining time
+        timer.pause(400.0);
+        assert!(!timer.should_dismiss(first));
+        assert_eq!(timer.remaining(5000.0), 600.0);
+
+        // Leaving resumes with only the time that was left
+        let (second, delay) = timer.resume(5000.0).unwrap();
+        assert_eq!(delay, 600.0);
+        assert!(!timer.should_dismiss(first));
+        assert!(timer.should_dismiss(second));
+        assert_eq!(timer.remaining(5300.0), 300.0);
+    }
+
+    #[test]
+    fn test_dismiss_timer_cancel_on_close() {
+        let mut timer = DismissTimer::new(1000.0);
+        let (ticket, _) = timer.resume(0.0).unwrap();
+        timer.cancel();
+        assert!(!timer.should_dismiss(ticket));
+        // A cancelled timer never restarts, e.g. on a late mouseleave
+        assert_eq!(timer.resume(100.0), None);
+    }
+
+    #[test]
+    fn test_alerts_are_opt_in_and_respect_preferences() {
+        use NotificationPriority::*;
+        let owner = Owner::new();
+        owner.with(|| {
+            let muted = RwSignal::new(false);
+            let reduced_motion = RwSignal::new(false);
+            let alerts = NotificationAlerts {
+                sound: true,
+                vibrate: true,
+                muted: muted.into(),
+                reduced_motion: reduced_motion.into(),
+            };
+            assert_eq!(alerts.for_priority(Normal), (false, false));
+            assert_eq!(alerts.for_priority(High), (true, true));
+
+            reduced_motion.set(true);
+            assert_eq!(alerts.for_priority(High), (true, false));
+
+            muted.set(true);
+            assert_eq!(alerts.for_priority(High), (false, false));
+
+            let off = NotificationAlerts {
+                sound: false,
+                vibrate: false,
+                muted: Signal::derive(|| false),
+                reduced_motion: Signal::derive(|| false),
+            };
+            assert_eq!(off.for_priority(High), (false, false));
+        });
     }
 }
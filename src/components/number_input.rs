@@ -1,6 +1,8 @@
+use crate::components::field_wrapper::FieldIds;
 use crate::components::input::{InputSize, InputVariant};
-use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::components::unit_input::{Unit, UnitValue};
+use crate::theme::{use_mingot_config, use_theme};
+use crate::utils::{FieldState, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
@@ -36,6 +38,45 @@ pub enum NumberInputLocale {
     Indian, // 12,34,567.89
 }
 
+/// Base for entering integer values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum NumberRadix {
+    #[default]
+    Decimal,
+    Binary,
+    Octal,
+    Hex,
+}
+
+impl NumberRadix {
+    pub fn base(&self) -> u32 {
+        match self {
+            NumberRadix::Decimal => 10,
+            NumberRadix::Binary => 2,
+            NumberRadix::Octal => 8,
+            NumberRadix::Hex => 16,
+        }
+    }
+
+    /// Literal prefix, e.g. `0x`; empty for decimal
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            NumberRadix::Decimal => "",
+            NumberRadix::Binary => "0b",
+            NumberRadix::Octal => "0o",
+            NumberRadix::Hex => "0x",
+        }
+    }
+}
+
+/// How negative values are displayed when formatted
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum NegativeStyle {
+    #[default]
+    Minus, // -1,234.00
+    Parentheses, // (1,234.00) - accounting style
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ParseError {
     InvalidFormat(String),
@@ -44,6 +85,8 @@ pub enum ParseError {
     TooManyDecimals(u32),
     NegativeNotAllowed,
     DecimalNotAllowed,
+    /// Outside the component's `min`/`max` range
+    OutOfRange(String),
 }
 
 impl std::fmt::Display for ParseError {
@@ -57,6 +100,7 @@ impl std::fmt::Display for ParseError {
             }
             ParseError::NegativeNotAllowed => write!(f, "Negative values not allowed"),
             ParseError::DecimalNotAllowed => write!(f, "Decimal values not allowed"),
+            ParseError::OutOfRange(msg) => write!(f, "Out of range: {}", msg),
         }
     }
 }
@@ -233,6 +277,45 @@ fn convert_to_engineering(input: &str) -> String {
     }
 }
 
+/// Scientific notation with `sig_figs` significant digits in the mantissa
+/// (e.g. 3 -> `6.02e23`)
+fn convert_to_scientific_sig(num: f64, sig_figs: u32) -> String {
+    let decimals = sig_figs.max(1) as usize - 1;
+    format!("{:.*e}", decimals, num)
+}
+
+/// Engineering notation with `sig_figs` significant digits, keeping the
+/// exponent a multiple of 3 (e.g. 3 -> `602e21`)
+fn convert_to_engineering_sig(num: f64, sig_figs: u32) -> String {
+    if num == 0.0 {
+        return "0".to_string();
+    }
+
+    // Round via scientific formatting so carries (9.99 -> 1.00e+1) are handled
+    let scientific = convert_to_scientific_sig(num.abs(), sig_figs);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let digits: String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    let eng_exponent = exponent.div_euclid(3) * 3;
+    let integer_len = (exponent - eng_exponent) as usize + 1;
+    let padded = format!("{:0<width$}", digits, width = integer_len);
+    let (integer, fraction) = padded.split_at(integer_len);
+
+    let sign = if num < 0.0 { "-" } else { "" };
+    let mantissa = if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    };
+
+    if eng_exponent == 0 {
+        format!("{}{}", sign, mantissa)
+    } else {
+        format!("{}{}e{}", sign, mantissa, eng_exponent)
+    }
+}
+
 /// Get locale-specific separators
 fn get_locale_separators(locale: NumberInputLocale) -> (char, char) {
     match locale {
@@ -284,7 +367,11 @@ fn add_indian_separators(input: &str) -> String {
     result
 }
 
-fn format_number(input: &str, format: NumberInputFormat, thousand_separator: char) -> String {
+fn format_with_separator(
+    input: &str,
+    format: NumberInputFormat,
+    thousand_separator: char,
+) -> String {
     match format {
         NumberInputFormat::Standard => input.to_string(),
         NumberInputFormat::Thousand => add_thousand_separators(input, thousand_separator),
@@ -293,6 +380,49 @@ fn format_number(input: &str, format: NumberInputFormat, thousand_separator: cha
     }
 }
 
+/// Format a number for display with the same engine `NumberInput` uses, so
+/// `Stats`, `Table` and other read-only displays match the inputs.
+///
+/// `precision` fixes the number of decimal places; for `Scientific` and
+/// `Engineering` it fixes the mantissa to `precision + 1` significant digits.
+///
+/// # Example
+/// ```
+/// use mingot::{format_number, NumberInputFormat, NumberInputLocale};
+///
+/// let value = 1234567.89;
+/// assert_eq!(
+///     format_number(value, NumberInputFormat::Thousand, NumberInputLocale::EU, Some(2)),
+///     "1.234.567,89"
+/// );
+/// assert_eq!(
+///     format_number(value, NumberInputFormat::Thousand, NumberInputLocale::Indian, Some(2)),
+///     "12,34,567.89"
+/// );
+/// ```
+pub fn format_number(
+    value: f64,
+    format: NumberInputFormat,
+    locale: NumberInputLocale,
+    precision: Option<u32>,
+) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let raw = match precision {
+        Some(places) => format!("{:.1$}", value, places as usize),
+        None => value.to_string(),
+    };
+    format_display_value(
+        &raw,
+        format,
+        Some(locale),
+        ',',
+        NegativeStyle::Minus,
+        precision.map(|places| places + 1),
+    )
+}
+
 /// Format number according to locale settings
 fn format_number_locale(
     input: &str,
@@ -327,6 +457,79 @@ fn format_number_locale(
     }
 }
 
+/// Render a leading minus sign in the requested negative style.
+/// The sign wraps the whole grouped number, e.g. "-1,234.00" -> "(1,234.00)".
+fn apply_negative_style(formatted: &str, style: NegativeStyle) -> String {
+    match style {
+        NegativeStyle::Minus => formatted.to_string(),
+        NegativeStyle::Parentheses => match formatted.strip_prefix('-') {
+            Some(magnitude) => format!("({})", magnitude),
+            None => formatted.to_string(),
+        },
+    }
+}
+
+/// Convert an accounting-style negative "(1,234.00)" back to "-1,234.00".
+/// Values without surrounding parentheses are returned unchanged.
+fn parse_accounting_negative(input: &str) -> String {
+    let trimmed = input.trim();
+    match trimmed
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        Some(magnitude) => format!("-{}", magnitude.trim()),
+        None => trimmed.to_string(),
+    }
+}
+
+/// Undo display formatting: accounting negatives, thousand separators
+/// and an alternate decimal separator, giving a plain `1234.5` string.
+fn unformat_display_value(
+    shown: &str,
+    thousand_separator: char,
+    decimal_separator: char,
+) -> String {
+    let cleaned = parse_accounting_negative(shown)
+        .replace([thousand_separator, '_', '\'', ' '], "")
+        .trim()
+        .to_string();
+    if decimal_separator != '.' {
+        cleaned.replace(decimal_separator, ".")
+    } else {
+        cleaned
+    }
+}
+
+/// Format a value for display when the input is not focused.
+///
+/// `sig_figs` limits the mantissa digits in scientific/engineering formats;
+/// it only affects the displayed string.
+fn format_display_value(
+    value: &str,
+    format: NumberInputFormat,
+    locale: Option<NumberInputLocale>,
+    thousand_separator: char,
+    negative_style: NegativeStyle,
+    sig_figs: Option<u32>,
+) -> String {
+    let rounded = sig_figs.and_then(|sf| {
+        let num = value.replace([',', '_'], "").parse::<f64>().ok()?;
+        match format {
+            NumberInputFormat::Scientific => Some(convert_to_scientific_sig(num, sf)),
+            NumberInputFormat::Engineering => Some(convert_to_engineering_sig(num, sf)),
+            _ => None,
+        }
+    });
+    let formatted = if let Some(rounded) = rounded {
+        rounded
+    } else if let Some(loc) = locale {
+        format_number_locale(value, format, loc)
+    } else {
+        format_with_separator(value, format, thousand_separator)
+    };
+    apply_negative_style(&formatted, negative_style)
+}
+
 /// Calculate the number of decimal places in a value
 fn count_decimal_places(input: &str) -> u32 {
     let cleaned = input.replace([',', '_'], "");
@@ -715,6 +918,9 @@ fn normalize_pasted_number(input: &str, decimal_separator: char) -> String {
         .trim()
         .to_string();
 
+    // Accounting-style negatives: (1,234.00) -> -1,234.00
+    let without_currency = parse_accounting_negative(&without_currency);
+
     // Remove percentage sign
     let without_percent = without_currency.trim_end_matches('%').trim().to_string();
 
@@ -764,7 +970,7 @@ fn increment_value(
         };
     }
 
-    let cleaned = current.replace([',', '_'], "");
+    let cleaned = parse_accounting_negative(current).replace([',', '_'], "");
     let step_cleaned = step.replace([',', '_'], "");
 
     let result = match precision {
@@ -831,8 +1037,8 @@ fn increment_value(
                 current_val - step_val
             };
 
-            let bounded = apply_bounds_f64(new_val, min, max);
-            format!("{:.1$}", bounded, places as usize)
+            let stepped = format!("{:.1$}", new_val, places as usize);
+            decimal_bound_crossed(&stepped, places, min, max).unwrap_or(stepped)
         }
         #[cfg(feature = "high-precision")]
         NumberInputPrecision::Arbitrary => {
@@ -916,23 +1122,23 @@ fn apply_bounds_i128(value: i128, min: Option<&str>, max: Option<&str>) -> i128
     result
 }
 
-fn apply_bounds_f64(value: f64, min: Option<&str>, max: Option<&str>) -> f64 {
-    let mut result = value;
-    if let Some(min_str) = min {
-        if let Ok(min_val) = min_str.replace([',', '_'], "").parse::<f64>() {
-            if result < min_val {
-                result = min_val;
-            }
-        }
-    }
-    if let Some(max_str) = max {
-        if let Ok(max_val) = max_str.replace([',', '_'], "").parse::<f64>() {
-            if result > max_val {
-                result = max_val;
-            }
-        }
-    }
-    result
+/// The bound a `Decimal` value lies beyond, shown with `places` decimals,
+/// or `None` when it is in range. Compared exactly like [`compare_values`],
+/// so a value the component accepts is one `is_within` accepts too.
+fn decimal_bound_crossed(
+    value: &str,
+    places: u32,
+    min: Option<&str>,
+    max: Option<&str>,
+) -> Option<String> {
+    use std::cmp::Ordering;
+
+    let crossed = |bound: &str, side: Ordering| compare_decimal_strings(value, bound) == Ok(side);
+    let bound = max
+        .filter(|max| crossed(max, Ordering::Greater))
+        .or_else(|| min.filter(|min| crossed(min, Ordering::Less)))?;
+    let bound: f64 = bound.replace([',', '_'], "").trim().parse().ok()?;
+    Some(format!("{:.1$}", bound, places as usize))
 }
 
 #[cfg(feature = "high-precision")]
@@ -962,6 +1168,423 @@ fn apply_bounds_decimal(
     result
 }
 
+/// Clamp `current` into `[min, max]` for the given precision.
+///
+/// Returns `Some(clamped)` only if the value was out of range, so callers
+/// can tell whether anything changed. Empty or unparseable values are left
+/// alone.
+fn clamp_to_range(
+    current: &str,
+    precision: NumberInputPrecision,
+    min: Option<&str>,
+    max: Option<&str>,
+) -> Option<String> {
+    let cleaned = parse_accounting_negative(current).replace([',', '_'], "");
+
+    match precision {
+        NumberInputPrecision::U64 => {
+            let value: u64 = cleaned.parse().ok()?;
+            let bounded = apply_bounds_u64(value, min, max);
+            (bounded != value).then(|| bounded.to_string())
+        }
+        NumberInputPrecision::U128 => {
+            let value: u128 = cleaned.parse().ok()?;
+            let bounded = apply_bounds_u128(value, min, max);
+            (bounded != value).then(|| bounded.to_string())
+        }
+        NumberInputPrecision::I64 => {
+            let value: i64 = cleaned.parse().ok()?;
+            let bounded = apply_bounds_i64(value, min, max);
+            (bounded != value).then(|| bounded.to_string())
+        }
+        NumberInputPrecision::I128 => {
+            let value: i128 = cleaned.parse().ok()?;
+            let bounded = apply_bounds_i128(value, min, max);
+            (bounded != value).then(|| bounded.to_string())
+        }
+        NumberInputPrecision::Decimal(places) => decimal_bound_crossed(&cleaned, places, min, max),
+        #[cfg(feature = "high-precision")]
+        NumberInputPrecision::Arbitrary => {
+            use rust_decimal::Decimal;
+            use std::str::FromStr;
+
+            let value = Decimal::from_str(&cleaned).ok()?;
+            let bounded = apply_bounds_decimal(value, min, max);
+            (bounded != value).then(|| bounded.to_string())
+        }
+    }
+}
+
+/// Check `value` against the inclusive `[min, max]` range, reporting which
+/// bound it violates. Values or bounds that don't parse are left for format
+/// validation to report.
+fn check_range(
+    value: &str,
+    precision: NumberInputPrecision,
+    min: Option<&str>,
+    max: Option<&str>,
+) -> Result<(), ParseError> {
+    use std::cmp::Ordering;
+
+    if let Some(min) = min {
+        if compare_values(value, min, precision) == Ok(Ordering::Less) {
+            return Err(ParseError::OutOfRange(format!(
+                "Value is below the minimum ({})",
+                min
+            )));
+        }
+    }
+    if let Some(max) = max {
+        if compare_values(value, max, precision) == Ok(Ordering::Greater) {
+            return Err(ParseError::OutOfRange(format!(
+                "Value is above the maximum ({})",
+                max
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reinterpret `current`, a plain decimal string such as `1234.5`, as a
+/// quantity in `from` and convert it to `to`, returning the converted
+/// number and its text for `precision`.
+///
+/// Integer precisions round to the nearest whole number; unsigned ones
+/// reject negative results. `None` if the value doesn't parse or the units
+/// are incompatible. Display formatting (separators, radix prefixes) must
+/// be removed first; the component does this before converting.
+pub fn quick_convert_value(
+    current: &str,
+    from: &Unit,
+    to: &Unit,
+    precision: NumberInputPrecision,
+) -> Option<(f64, String)> {
+    let value: f64 = current.trim().parse().ok()?;
+    let converted = UnitValue::new(value, from.clone()).convert_to(to)?.value;
+
+    let text = match precision {
+        NumberInputPrecision::U64 | NumberInputPrecision::U128 if converted < 0.0 => {
+            return None;
+        }
+        NumberInputPrecision::U64
+        | NumberInputPrecision::U128
+        | NumberInputPrecision::I64
+        | NumberInputPrecision::I128 => format!("{:.0}", converted.round()),
+        NumberInputPrecision::Decimal(places) => format!("{:.1$}", converted, places as usize),
+        #[cfg(feature = "high-precision")]
+        NumberInputPrecision::Arbitrary => converted.to_string(),
+    };
+
+    Some((converted, text))
+}
+
+/// Compare two numeric strings using the semantics of `precision`.
+///
+/// Integers are compared in their native width, so large `u128`/`i128`
+/// values never lose precision. `Decimal` values are compared exactly on
+/// their digits (`"1.50"` equals `"1.5"`), and `Arbitrary` uses
+/// `rust_decimal`. Separators and accounting negatives are accepted, as in
+/// the component.
+///
+/// # Example
+/// ```
+/// use mingot::{compare_values, NumberInputPrecision};
+/// use std::cmp::Ordering;
+///
+/// let precision = NumberInputPrecision::U128;
+/// assert_eq!(
+///     compare_values(
+///         "340282366920938463463374607431768211455",
+///         "340282366920938463463374607431768211454",
+///         precision,
+///     ),
+///     Ok(Ordering::Greater)
+/// );
+/// ```
+pub fn compare_values(
+    a: &str,
+    b: &str,
+    precision: NumberInputPrecision,
+) -> Result<std::cmp::Ordering, ParseError> {
+    let a = parse_accounting_negative(a);
+    let b = parse_accounting_negative(b);
+
+    match precision {
+        NumberInputPrecision::U64 => Ok(validate_u64(&a)?.cmp(&validate_u64(&b)?)),
+        NumberInputPrecision::U128 => Ok(validate_u128(&a)?.cmp(&validate_u128(&b)?)),
+        NumberInputPrecision::I64 => Ok(validate_i64(&a)?.cmp(&validate_i64(&b)?)),
+        NumberInputPrecision::I128 => Ok(validate_i128(&a)?.cmp(&validate_i128(&b)?)),
+        NumberInputPrecision::Decimal(_) => compare_decimal_strings(&a, &b),
+        #[cfg(feature = "high-precision")]
+        NumberInputPrecision::Arbitrary => {
+            Ok(validate_arbitrary(&a)?.cmp(&validate_arbitrary(&b)?))
+        }
+    }
+}
+
+/// Whether two canonical values are the same number (`"1.50"` and `"1.5"`).
+/// Text that doesn't parse only matches itself.
+fn same_number(a: &str, b: &str, precision: NumberInputPrecision) -> bool {
+    a == b || compare_values(a, b, precision) == Ok(std::cmp::Ordering::Equal)
+}
+
+/// Whether `value` lies within the inclusive `[min, max]` range.
+///
+/// Missing bounds are unbounded. Errors if any present value doesn't parse
+/// for `precision`.
+pub fn is_within(
+    value: &str,
+    min: Option<&str>,
+    max: Option<&str>,
+    precision: NumberInputPrecision,
+) -> Result<bool, ParseError> {
+    use std::cmp::Ordering;
+
+    if let Some(min) = min {
+        if compare_values(value, min, precision)? == Ordering::Less {
+            return Ok(false);
+        }
+    }
+    if let Some(max) = max {
+        if compare_values(value, max, precision)? == Ordering::Greater {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Split a plain decimal into (negative, integer digits, fraction digits)
+/// with leading/trailing zeros removed. `None` for exponent notation.
+fn normalize_decimal_digits(input: &str) -> Option<(bool, String, String)> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part
+        .chars()
+        .chain(frac_part.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let int_digits = int_part.trim_start_matches('0').to_string();
+    let frac_digits = frac_part.trim_end_matches('0').to_string();
+    // -0 and 0 are the same value
+    let negative = negative && !(int_digits.is_empty() && frac_digits.is_empty());
+    Some((negative, int_digits, frac_digits))
+}
+
+fn compare_decimal_strings(a: &str, b: &str) -> Result<std::cmp::Ordering, ParseError> {
+    use std::cmp::Ordering;
+
+    let clean = |s: &str| -> Result<String, ParseError> {
+        let cleaned = s.replace([',', '_'], "").trim().to_string();
+        if cleaned.is_empty() {
+            return Err(ParseError::InvalidFormat("Empty input".to_string()));
+        }
+        cleaned
+            .parse::<f64>()
+            .map_err(|_| ParseError::InvalidFormat("Not a valid number".to_string()))?;
+        Ok(cleaned)
+    };
+    let (a, b) = (clean(a)?, clean(b)?);
+
+    let (Some((a_neg, a_int, a_frac)), Some((b_neg, b_int, b_frac))) =
+        (normalize_decimal_digits(&a), normalize_decimal_digits(&b))
+    else {
+        // Exponent notation: fall back to floating point
+        let (x, y) = (
+            a.parse::<f64>().unwrap_or(0.0),
+            b.parse::<f64>().unwrap_or(0.0),
+        );
+        return Ok(x.partial_cmp(&y).unwrap_or(Ordering::Equal));
+    };
+
+    let magnitude = a_int
+        .len()
+        .cmp(&b_int.len())
+        .then_with(|| a_int.cmp(&b_int))
+        .then_with(|| a_frac.cmp(&b_frac));
+
+    Ok(match (a_neg, b_neg) {
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+        (false, false) => magnitude,
+        (true, true) => magnitude.reverse(),
+    })
+}
+
+/// Parse an integer typed in `radix` (optional sign, optional `0x`/`0o`/`0b`
+/// prefix, `_` separators, any letter case) into a canonical decimal string,
+/// checked against the precision's range. Non-integer precisions are
+/// returned unchanged.
+fn radix_to_decimal(
+    input: &str,
+    radix: NumberRadix,
+    precision: NumberInputPrecision,
+) -> Result<String, ParseError> {
+    let cleaned: String = input
+        .chars()
+        .filter(|ch| *ch != '_' && !ch.is_whitespace())
+        .collect();
+    if cleaned.is_empty() {
+        return Err(ParseError::InvalidFormat("Empty input".to_string()));
+    }
+
+    let (negative, unsigned) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.as_str()),
+    };
+    let prefix = radix.prefix();
+    let digits = if !prefix.is_empty()
+        && unsigned.len() >= 2
+        && unsigned[..2].eq_ignore_ascii_case(prefix)
+    {
+        &unsigned[2..]
+    } else {
+        unsigned
+    };
+
+    if digits.is_empty() || !digits.chars().all(|ch| ch.is_digit(radix.base())) {
+        return Err(ParseError::InvalidFormat(format!(
+            "Not a valid base-{} number",
+            radix.base()
+        )));
+    }
+
+    // Anything that doesn't fit u128 is too large for every integer type
+    let magnitude = u128::from_str_radix(digits, radix.base()).map_err(|_| {
+        ParseError::Overflow(format!(
+            "Value exceeds {} maximum",
+            precision_name(precision)
+        ))
+    })?;
+    let decimal = if negative && magnitude != 0 {
+        format!("-{}", magnitude)
+    } else {
+        magnitude.to_string()
+    };
+
+    match precision {
+        NumberInputPrecision::U64 | NumberInputPrecision::U128 if negative && magnitude != 0 => {
+            Err(ParseError::NegativeNotAllowed)
+        }
+        NumberInputPrecision::U64 => validate_u64(&decimal).map(|v| v.to_string()),
+        NumberInputPrecision::U128 => validate_u128(&decimal).map(|v| v.to_string()),
+        NumberInputPrecision::I64 => validate_i64(&decimal).map(|v| v.to_string()),
+        NumberInputPrecision::I128 => validate_i128(&decimal).map(|v| v.to_string()),
+        _ => Ok(input.to_string()),
+    }
+}
+
+/// Render a decimal integer string in `radix` with its prefix, e.g.
+/// `"255"` → `"0xFF"`. Returns `None` if the input isn't an integer.
+fn decimal_to_radix(decimal: &str, radix: NumberRadix) -> Option<String> {
+    let cleaned = decimal.replace([',', '_'], "");
+    let cleaned = cleaned.trim();
+    let (sign, magnitude) = match cleaned.strip_prefix('-') {
+        Some(rest) => ("-", rest.parse::<u128>().ok()?),
+        None => ("", cleaned.parse::<u128>().ok()?),
+    };
+    let sign = if magnitude == 0 { "" } else { sign };
+    let digits = match radix {
+        NumberRadix::Decimal => magnitude.to_string(),
+        NumberRadix::Binary => format!("{:b}", magnitude),
+        NumberRadix::Octal => format!("{:o}", magnitude),
+        NumberRadix::Hex => format!("{:X}", magnitude),
+    };
+    Some(format!("{}{}{}", sign, radix.prefix(), digits))
+}
+
+fn precision_name(precision: NumberInputPrecision) -> &'static str {
+    match precision {
+        NumberInputPrecision::U64 => "u64",
+        NumberInputPrecision::U128 => "u128",
+        NumberInputPrecision::I64 => "i64",
+        NumberInputPrecision::I128 => "i128",
+        _ => "integer",
+    }
+}
+
+/// Characters accepted while typing in a non-decimal radix
+fn is_valid_radix_char(ch: char, radix: NumberRadix, allow_negative: bool) -> bool {
+    match ch {
+        '-' => allow_negative,
+        '_' => true,
+        // Prefix letters; 'b' is also a hex digit
+        'x' | 'X' => radix == NumberRadix::Hex,
+        'o' | 'O' => radix == NumberRadix::Octal,
+        'b' | 'B' if radix == NumberRadix::Binary => true,
+        _ => ch.is_digit(radix.base()),
+    }
+}
+
+/// Width reserved on the right for the +/- controls
+const CONTROLS_WIDTH: &str = "2rem";
+
+/// Inner padding for one side of the field. `start` is where the side's
+/// content begins (the base padding, or the controls' width); an `affix`
+/// sits there and the text starts after it.
+fn side_padding(start: &str, affix: Option<&str>) -> String {
+    match affix {
+        Some(affix) if !affix.is_empty() => {
+            format!("calc({} + {}ch + 0.375rem)", start, affix.chars().count())
+        }
+        _ => start.to_string(),
+    }
+}
+
+/// `aria-describedby` naming the prefix and suffix spans (`{base}-prefix`,
+/// `{base}-suffix`) that are shown. The spans are `aria-hidden` so they
+/// aren't read again while browsing, but the unit or currency is still
+/// announced with the field.
+fn affix_described_by(base: &str, prefix: Option<&str>, suffix: Option<&str>) -> Option<String> {
+    let ids: Vec<String> = [("prefix", prefix), ("suffix", suffix)]
+        .into_iter()
+        .filter(|(_, affix)| affix.is_some_and(|a| !a.is_empty()))
+        .map(|(side, _)| format!("{}-{}", base, side))
+        .collect();
+    (!ids.is_empty()).then(|| ids.join(" "))
+}
+
+/// Where `NumberInput` sends validation results.
+///
+/// Each change reports exactly once: to `on_valid` or `on_invalid`, then to
+/// the deprecated combined `on_result`, synchronously within the event that
+/// caused it. A value going valid -> invalid -> valid is therefore reported
+/// as valid, invalid, valid, in that order, with nothing in between.
+#[derive(Clone, Copy, Default)]
+struct ValidationCallbacks {
+    on_valid: Option<Callback<String>>,
+    on_invalid: Option<Callback<ParseError>>,
+    on_result: Option<Callback<Result<String, ParseError>>>,
+}
+
+impl ValidationCallbacks {
+    fn report(&self, result: Result<String, ParseError>) {
+        match &result {
+            Ok(value) => {
+                if let Some(callback) = self.on_valid {
+                    callback.run(value.clone());
+                }
+            }
+            Err(err) => {
+                if let Some(callback) = self.on_invalid {
+                    callback.run(err.clone());
+                }
+            }
+        }
+        if let Some(callback) = self.on_result {
+            callback.run(result);
+        }
+    }
+}
+
 // Input filtering
 fn is_valid_char(
     ch: char,
@@ -988,13 +1611,57 @@ fn is_valid_char(
 pub fn NumberInput(
     // Core value handling
     #[prop(optional)] value: Option<RwSignal<String>>,
-    #[prop(optional)] on_change: Option<Callback<String>>,
-    #[prop(optional)] on_valid_change: Option<Callback<Result<String, ParseError>>>,
+    /// Called with the typed text on input; after a step, wheel, clamp or
+    /// reset, with the canonical decimal value rather than the display form
+    #[prop(optional)]
+    on_change: Option<Callback<String>>,
+    /// Called with the value whenever a change leaves it valid
+    #[prop(optional)]
+    on_valid_change: Option<Callback<String>>,
+    /// Called with the error on every change that fails to parse or validate
+    #[prop(optional)]
+    on_invalid_change: Option<Callback<ParseError>>,
+    /// Deprecated: use `on_valid_change` and `on_invalid_change`. Receives
+    /// every validation result; will be removed in the next release.
+    #[prop(optional)]
+    on_validation_result: Option<Callback<Result<String, ParseError>>>,
+    /// Value restored by reset. An empty field starts at this value;
+    /// without it, the initial value is the default.
+    #[prop(optional, into)]
+    default_value: Option<String>,
+    /// Receives the field's dirty-tracking and reset handle on mount
+    #[prop(optional)]
+    on_register: Option<Callback<FieldState<String>>>,
+    /// Show a reset button while the value differs from the default
+    #[prop(optional)]
+    show_reset: bool,
 
     // Precision configuration
     #[prop(optional)] precision: Option<NumberInputPrecision>,
-    #[prop(optional, into)] min: Option<String>,
-    #[prop(optional, into)] max: Option<String>,
+    /// Base for entering U64/U128/I64/I128 values (`0xFF`, `0b1010`, ...).
+    /// Ignored for decimal and arbitrary precision.
+    #[prop(optional)]
+    radix: NumberRadix,
+    /// Emit the value from `on_valid_change` in `radix` (with prefix)
+    /// instead of the canonical decimal string
+    #[prop(optional)]
+    emit_radix: bool,
+    /// Lower bound; may be a signal so the range can change at runtime
+    #[prop(optional, into)]
+    min: Option<Signal<String>>,
+    /// Upper bound; may be a signal so the range can change at runtime
+    #[prop(optional, into)]
+    max: Option<Signal<String>>,
+    /// Re-clamp the current value when `min`/`max` change
+    #[prop(default = false)]
+    clamp_on_range_change: bool,
+    /// Snap out-of-range values to the nearest bound on blur. When false,
+    /// they are reported as `ParseError::OutOfRange` instead.
+    #[prop(default = false)]
+    clamp: bool,
+    /// Called with the new value when it is clamped into range
+    #[prop(optional)]
+    on_range_clamp: Option<Callback<String>>,
 
     // Increment/decrement controls
     /// Whether to show +/- controls
@@ -1002,13 +1669,13 @@ pub fn NumberInput(
     show_controls: bool,
     /// Step size for increment/decrement (default: "1")
     #[prop(optional, into)]
-    step: Option<String>,
+    step: Option<Signal<String>>,
     /// Step size when Shift is held (default: 10x step)
     #[prop(optional, into)]
-    shift_step: Option<String>,
+    shift_step: Option<Signal<String>>,
     /// Step size when Ctrl is held (default: 100x step)
     #[prop(optional, into)]
-    ctrl_step: Option<String>,
+    ctrl_step: Option<Signal<String>>,
     /// Whether to allow mouse wheel to change value
     #[prop(default = false)]
     allow_mouse_wheel: bool,
@@ -1029,6 +1696,13 @@ pub fn NumberInput(
     /// Whether to auto-format value on blur
     #[prop(default = false)]
     format_on_blur: bool,
+    /// How negative values are shown when formatted (e.g. accounting parentheses)
+    #[prop(optional)]
+    negative_style: Option<NegativeStyle>,
+    /// Significant digits shown in the mantissa for Scientific/Engineering
+    /// formats. Display only: the entered value is kept exact.
+    #[prop(optional)]
+    sig_figs: Option<u32>,
     /// Threshold for auto-switching to scientific notation (e.g., 1e12 = 1 trillion)
     /// Set to 0 to disable auto-switch
     #[prop(default = 0.0)]
@@ -1052,6 +1726,14 @@ pub fn NumberInput(
     /// Whether to show context menu on right-click with format conversion options
     #[prop(default = false)]
     show_context_menu: bool,
+    /// Units for the quick-convert dropdown ("interpret as X, show as Y").
+    /// Unlike `UnitInput`, the value carries no unit: converting rewrites
+    /// the number in place and the field stays a plain number.
+    #[prop(optional)]
+    quick_convert: Option<Vec<Unit>>,
+    /// Receives the converted numeric value after a quick conversion
+    #[prop(optional)]
+    on_quick_convert: Option<Callback<f64>>,
 
     // Enhanced input handling
     /// Whether to detect and convert pasted number formats automatically
@@ -1076,6 +1758,14 @@ pub fn NumberInput(
     #[prop(optional)] variant: Option<InputVariant>,
     #[prop(optional)] size: Option<InputSize>,
     #[prop(optional, into)] placeholder: Option<String>,
+    /// Decoration shown inside the field before the number, e.g. `"$"`.
+    /// Not part of the value.
+    #[prop(optional, into)]
+    prefix: Option<String>,
+    /// Decoration shown inside the field after the number, e.g. `"USD"`.
+    /// Not part of the value.
+    #[prop(optional, into)]
+    suffix: Option<String>,
     #[prop(optional, into)] disabled: Signal<bool>,
     #[prop(optional, into)] error: Option<String>,
     #[prop(optional)] required: bool,
@@ -1085,9 +1775,14 @@ pub fn NumberInput(
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(InputVariant::Default);
-    let size = size.unwrap_or(InputSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.input_variant)
+        .unwrap_or(InputVariant::Default);
+    let size = size.or(config.input_size).unwrap_or(InputSize::Md);
     let precision = precision.unwrap_or_default();
+    let negative_style = negative_style.unwrap_or_default();
+    let locale = locale.or(config.number_input_locale);
 
     // Determine allow_negative and allow_decimal based on precision if not explicitly set
     #[cfg(feature = "high-precision")]
@@ -1117,7 +1812,48 @@ pub fn NumberInput(
     #[cfg(not(feature = "high-precision"))]
     let allow_decimal = allow_decimal || matches!(precision, NumberInputPrecision::Decimal(_));
 
-    let number_value = value.unwrap_or_else(|| RwSignal::new(String::new()));
+    // Non-decimal entry only applies to the fixed-width integer types
+    let radix_mode = radix != NumberRadix::Decimal
+        && matches!(
+            precision,
+            NumberInputPrecision::U64
+                | NumberInputPrecision::U128
+                | NumberInputPrecision::I64
+                | NumberInputPrecision::I128
+        );
+    // Stepping, clamping and overflow checks work on decimal strings
+    let to_decimal = move |raw: String| -> String {
+        if radix_mode {
+            radix_to_decimal(&raw, radix, precision).unwrap_or(raw)
+        } else {
+            raw
+        }
+    };
+    let from_decimal = move |decimal: String| -> String {
+        if radix_mode {
+            decimal_to_radix(&decimal, radix).unwrap_or(decimal)
+        } else {
+            decimal
+        }
+    };
+    // Form of a valid value passed to `on_valid_change`
+    let emitted = move |decimal: String| -> String {
+        if emit_radix {
+            from_decimal(decimal)
+        } else {
+            decimal
+        }
+    };
+
+    // Every change runs `on_change` first, then reports its validation result
+    let validation_callbacks = ValidationCallbacks {
+        on_valid: on_valid_change,
+        on_invalid: on_invalid_change,
+        on_result: on_validation_result,
+    };
+    let report = move |result: Result<String, ParseError>| validation_callbacks.report(result);
+
+    let number_value = value.unwrap_or_else(|| RwSignal::new(String::new()));
 
     // Undo/redo state management
     let undo_stack: RwSignal<Vec<String>> = RwSignal::new(Vec::new());
@@ -1166,9 +1902,7 @@ pub fn NumberInput(
                 if let Some(callback) = on_change {
                     callback.run(prev_value.clone());
                 }
-                if let Some(callback) = on_valid_change {
-                    callback.run(Ok(prev_value));
-                }
+                report(Ok(prev_value));
             }
         });
         undone
@@ -1192,9 +1926,7 @@ pub fn NumberInput(
                 if let Some(callback) = on_change {
                     callback.run(next_value.clone());
                 }
-                if let Some(callback) = on_valid_change {
-                    callback.run(Ok(next_value));
-                }
+                report(Ok(next_value));
             }
         });
         redone
@@ -1206,39 +1938,108 @@ pub fn NumberInput(
         _ => None,
     };
 
-    // Store min/max as owned strings for use in closures
-    let min_value = min.clone();
-    let max_value = max.clone();
-    let step_value = step.unwrap_or_else(|| "1".to_string());
-    let shift_step_value = shift_step.unwrap_or_else(|| {
-        // Default shift step is 10x the step
-        let step_num: f64 = step_value.parse().unwrap_or(1.0);
-        format!("{}", step_num * 10.0)
-    });
-    let ctrl_step_value = ctrl_step.unwrap_or_else(|| {
-        // Default ctrl step is 100x the step
-        let step_num: f64 = step_value.parse().unwrap_or(1.0);
-        format!("{}", step_num * 100.0)
-    });
+    // Range and steps are read at interaction time so they can be reactive
+    let current_bounds = move || {
+        (
+            min.map(|m| m.get_untracked()),
+            max.map(|m| m.get_untracked()),
+        )
+    };
+    let current_step = move |use_shift: bool, use_ctrl: bool| -> String {
+        let base = step
+            .map(|s| s.get_untracked())
+            .unwrap_or_else(|| "1".to_string());
+        // Modifier steps default to 10x (Shift) and 100x (Ctrl) the step
+        let scaled = |factor: f64| {
+            let step_num: f64 = base.parse().unwrap_or(1.0);
+            format!("{}", step_num * factor)
+        };
+        if use_ctrl {
+            ctrl_step
+                .map(|s| s.get_untracked())
+                .unwrap_or_else(|| scaled(100.0))
+        } else if use_shift {
+            shift_step
+                .map(|s| s.get_untracked())
+                .unwrap_or_else(|| scaled(10.0))
+        } else {
+            base
+        }
+    };
+
+    // Track whether we're focused (to show raw vs formatted value)
+    let is_focused = RwSignal::new(false);
+
+    // Exact value behind a sig-fig rounded display, paired with the rounded
+    // string it was shown as
+    let exact_value: RwSignal<Option<(String, String)>> = RwSignal::new(None);
+
+    // Current value, preferring the exact value over its rounded display
+    let current_exact = move || {
+        let shown = number_value.get();
+        match exact_value.get_untracked() {
+            Some((exact, rounded)) if rounded == shown => exact,
+            _ => shown,
+        }
+    };
+
+    // Format a raw value for display, remembering it when sig figs round it
+    let format_for_display = move |raw: &str, format_type: NumberInputFormat| -> String {
+        let formatted = format_display_value(
+            raw,
+            format_type,
+            locale,
+            thousand_separator,
+            negative_style,
+            sig_figs,
+        );
+        exact_value.set(sig_figs.map(|_| (raw.to_string(), formatted.clone())));
+        formatted
+    };
+
+    // Value to display after a programmatic change: formatted while blurred
+    // so that e.g. stepping across zero switches between 1.00 and (1.00)
+    let display_value = move |raw: String| -> String {
+        if radix_mode {
+            from_decimal(raw)
+        } else if format_on_blur && !is_focused.get_untracked() && !raw.is_empty() {
+            format_for_display(&raw, format.unwrap_or(NumberInputFormat::Thousand))
+        } else {
+            raw
+        }
+    };
+
+    // Re-clamp the value when a reactive min/max moves past it
+    if clamp_on_range_change {
+        Effect::new(move |prev: Option<()>| {
+            let (min_now, max_now) = (min.map(|m| m.get()), max.map(|m| m.get()));
+            // The initial range is applied by the user's own value, not here
+            if prev.is_none() {
+                return;
+            }
 
-    // Clone values for use in multiple closures
-    let min_for_increment = min_value.clone();
-    let max_for_increment = max_value.clone();
-    let step_for_increment = step_value.clone();
-    let shift_step_for_increment = shift_step_value.clone();
-    let ctrl_step_for_increment = ctrl_step_value.clone();
-
-    let min_for_wheel = min_value.clone();
-    let max_for_wheel = max_value.clone();
-    let step_for_wheel = step_value.clone();
-    let shift_step_for_wheel = shift_step_value.clone();
-    let ctrl_step_for_wheel = ctrl_step_value.clone();
-
-    let min_for_keyboard = min_value.clone();
-    let max_for_keyboard = max_value.clone();
-    let step_for_keyboard = step_value.clone();
-    let shift_step_for_keyboard = shift_step_value.clone();
-    let ctrl_step_for_keyboard = ctrl_step_value.clone();
+            let current = untrack(current_exact);
+            let Some(clamped) = clamp_to_range(
+                &to_decimal(current.clone()),
+                precision,
+                min_now.as_deref(),
+                max_now.as_deref(),
+            ) else {
+                return;
+            };
+
+            push_undo(current);
+            number_value.set(display_value(clamped.clone()));
+
+            if let Some(callback) = on_change {
+                callback.run(clamped.clone());
+            }
+            report(Ok(emitted(clamped.clone())));
+            if let Some(callback) = on_range_clamp {
+                callback.run(clamped);
+            }
+        });
+    }
 
     // Increment/decrement handler
     // use_shift: Shift key held (10x step)
@@ -1248,49 +2049,38 @@ pub fn NumberInput(
             return;
         }
 
-        let current = number_value.get();
+        let current = current_exact();
 
         // Push to undo stack before changing
         push_undo(current.clone());
 
-        let step_to_use = if use_ctrl {
-            &ctrl_step_for_increment
-        } else if use_shift {
-            &shift_step_for_increment
-        } else {
-            &step_for_increment
-        };
+        let step_to_use = current_step(use_shift, use_ctrl);
+        let (min_now, max_now) = current_bounds();
 
         let new_value = increment_value(
-            &current,
-            step_to_use,
+            &to_decimal(current),
+            &step_to_use,
             precision,
             is_increment,
-            min_for_increment.as_deref(),
-            max_for_increment.as_deref(),
+            min_now.as_deref(),
+            max_now.as_deref(),
         );
 
-        number_value.set(new_value.clone());
+        number_value.set(display_value(new_value.clone()));
 
         if let Some(callback) = on_change {
             callback.run(new_value.clone());
         }
 
-        if let Some(callback) = on_valid_change {
-            callback.run(Ok(new_value));
-        }
+        report(Ok(emitted(new_value)));
     };
 
-    // Create clones for button handlers - detect Shift/Ctrl from mouse event
-    let handle_increment = {
-        let handle_step = handle_step.clone();
-        move |ev: ev::MouseEvent| handle_step(true, ev.shift_key(), ev.ctrl_key())
-    };
+    // Button handlers - detect Shift/Ctrl from mouse event
+    let handle_increment =
+        move |ev: ev::MouseEvent| handle_step(true, ev.shift_key(), ev.ctrl_key());
 
-    let handle_decrement = {
-        let handle_step = handle_step.clone();
-        move |ev: ev::MouseEvent| handle_step(false, ev.shift_key(), ev.ctrl_key())
-    };
+    let handle_decrement =
+        move |ev: ev::MouseEvent| handle_step(false, ev.shift_key(), ev.ctrl_key());
 
     // Validation function based on precision
     let validate_input = move |input: String| -> Result<String, ParseError> {
@@ -1298,30 +2088,145 @@ pub fn NumberInput(
             return Ok(String::new());
         }
 
-        match precision {
-            NumberInputPrecision::U64 => {
-                validate_u64(&input)?;
-                Ok(input)
-            }
-            NumberInputPrecision::U128 => {
-                validate_u128(&input)?;
-                Ok(input)
-            }
-            NumberInputPrecision::I64 => {
-                validate_i64(&input)?;
-                Ok(input)
-            }
-            NumberInputPrecision::I128 => {
-                validate_i128(&input)?;
-                Ok(input)
-            }
-            NumberInputPrecision::Decimal(places) => validate_decimal(&input, places),
-            #[cfg(feature = "high-precision")]
-            NumberInputPrecision::Arbitrary => {
-                validate_arbitrary(&input)?;
-                Ok(input)
+        let validated = if radix_mode {
+            radix_to_decimal(&input, radix, precision)?
+        } else {
+            match precision {
+                NumberInputPrecision::U64 => {
+                    validate_u64(&input)?;
+                    input
+                }
+                NumberInputPrecision::U128 => {
+                    validate_u128(&input)?;
+                    input
+                }
+                NumberInputPrecision::I64 => {
+                    validate_i64(&input)?;
+                    input
+                }
+                NumberInputPrecision::I128 => {
+                    validate_i128(&input)?;
+                    input
+                }
+                NumberInputPrecision::Decimal(places) => validate_decimal(&input, places)?,
+                #[cfg(feature = "high-precision")]
+                NumberInputPrecision::Arbitrary => {
+                    validate_arbitrary(&input)?;
+                    input
+                }
             }
+        };
+
+        // With `clamp`, out-of-range values are snapped on blur instead
+        if !clamp {
+            let (min_now, max_now) = current_bounds();
+            check_range(
+                &validated,
+                precision,
+                min_now.as_deref(),
+                max_now.as_deref(),
+            )?;
+        }
+
+        Ok(emitted(validated))
+    };
+
+    // A shown value as a plain decimal string. `formatted` says whether it
+    // is in blurred display form (locale separators, accounting negatives);
+    // otherwise it is raw input, whose decimal point is always '.'
+    let canonical_of = move |shown: String, formatted: bool| -> String {
+        if radix_mode {
+            return to_decimal(shown);
+        }
+        if !formatted {
+            return shown.trim().to_string();
+        }
+        let (thou_sep, dec_sep) = match locale {
+            Some(loc) => get_locale_separators(loc),
+            None => (thousand_separator, decimal_separator),
+        };
+        unformat_display_value(&shown, thou_sep, dec_sep)
+    };
+    // Whether the field currently shows the blurred display form
+    let shows_formatted =
+        move || (format_on_blur || auto_scientific_threshold > 0.0) && !is_focused.get();
+    let canonical_value = move || canonical_of(current_exact(), untrack(shows_formatted));
+
+    // Dirty tracking against the default value, compared as numbers so that
+    // formatting on blur doesn't count as an edit
+    if let Some(default) = default_value.as_ref() {
+        if number_value.get_untracked().is_empty() {
+            number_value.set(display_value(to_decimal(default.clone())));
+        }
+    }
+    // The default is kept in blurred display form, without sig-fig
+    // rounding, so that an external reset shows it formatted
+    let blurred_form = move |raw: String| -> String {
+        if radix_mode {
+            from_decimal(to_decimal(raw))
+        } else if format_on_blur && !raw.is_empty() {
+            format_display_value(
+                &raw,
+                format.unwrap_or(NumberInputFormat::Thousand),
+                locale,
+                thousand_separator,
+                negative_style,
+                None,
+            )
+        } else {
+            raw
+        }
+    };
+    let field_state = FieldState::with_comparator(
+        number_value,
+        blurred_form(default_value.unwrap_or_else(|| number_value.get_untracked())),
+        move |value, default| {
+            // Compare the exact value behind a sig-fig rounded display
+            let value = match exact_value.get_untracked() {
+                Some((exact, rounded)) if &rounded == value => exact,
+                _ => value.clone(),
+            };
+            same_number(
+                &canonical_of(value, shows_formatted()),
+                &canonical_of(default.clone(), format_on_blur),
+                precision,
+            )
+        },
+    );
+    // Reset to the default value, undoably, shown as a programmatic change.
+    // The reset button and `FieldState::reset` both come through here.
+    let restore_default = move |default: String| {
+        push_undo(number_value.get_untracked());
+        let decimal = canonical_of(default, format_on_blur);
+        number_value.set(display_value(decimal.clone()));
+        if let Some(callback) = on_change {
+            callback.run(decimal.clone());
+        }
+        report(Ok(emitted(decimal)));
+    };
+    let field_state = field_state.with_reset(restore_default);
+    let is_dirty = field_state.is_dirty();
+    if let Some(callback) = on_register {
+        callback.run(field_state);
+    }
+
+    // Commit a decimal value computed by the component: snap it into range
+    // with `clamp`, show it in display form and report it through the same
+    // validation as typed input, so overflow and range errors surface
+    let commit_decimal = move |decimal: String| {
+        let decimal = if clamp {
+            let (min_now, max_now) = current_bounds();
+            clamp_to_range(&decimal, precision, min_now.as_deref(), max_now.as_deref())
+                .unwrap_or(decimal)
+        } else {
+            decimal
+        };
+        number_value.set(display_value(decimal.clone()));
+
+        if let Some(callback) = on_change {
+            callback.run(decimal.clone());
         }
+        report(validate_input(from_decimal(decimal)));
     };
 
     let handle_input = move |ev: ev::Event| {
@@ -1331,13 +2236,17 @@ pub fn NumberInput(
         let filtered: String = input_value
             .chars()
             .filter(|ch| {
-                is_valid_char(
-                    *ch,
-                    &number_value.get(),
-                    allow_negative,
-                    allow_decimal,
-                    allow_scientific,
-                )
+                if radix_mode {
+                    is_valid_radix_char(*ch, radix, allow_negative)
+                } else {
+                    is_valid_char(
+                        *ch,
+                        &number_value.get(),
+                        allow_negative,
+                        allow_decimal,
+                        allow_scientific,
+                    )
+                }
             })
             .collect();
 
@@ -1352,10 +2261,8 @@ pub fn NumberInput(
             callback.run(filtered.clone());
         }
 
-        // Call on_valid_change with validation result
-        if let Some(callback) = on_valid_change {
-            callback.run(validation_result);
-        }
+        // Report the validation result
+        report(validation_result);
     };
 
     // Keyboard handler for arrow up/down and undo/redo
@@ -1390,32 +2297,25 @@ pub fn NumberInput(
                 // Push to undo stack before changing
                 push_undo(current.clone());
 
-                let step_to_use = if use_ctrl {
-                    &ctrl_step_for_keyboard
-                } else if use_shift {
-                    &shift_step_for_keyboard
-                } else {
-                    &step_for_keyboard
-                };
+                let step_to_use = current_step(use_shift, use_ctrl);
+                let (min_now, max_now) = current_bounds();
 
                 let new_value = increment_value(
-                    &current,
-                    step_to_use,
+                    &to_decimal(current),
+                    &step_to_use,
                     precision,
                     true,
-                    min_for_keyboard.as_deref(),
-                    max_for_keyboard.as_deref(),
+                    min_now.as_deref(),
+                    max_now.as_deref(),
                 );
 
-                number_value.set(new_value.clone());
+                number_value.set(from_decimal(new_value.clone()));
 
                 if let Some(callback) = on_change {
                     callback.run(new_value.clone());
                 }
 
-                if let Some(callback) = on_valid_change {
-                    callback.run(Ok(new_value));
-                }
+                report(Ok(emitted(new_value)));
             }
             "ArrowDown" => {
                 ev.prevent_default();
@@ -1424,32 +2324,25 @@ pub fn NumberInput(
                 // Push to undo stack before changing
                 push_undo(current.clone());
 
-                let step_to_use = if use_ctrl {
-                    &ctrl_step_for_keyboard
-                } else if use_shift {
-                    &shift_step_for_keyboard
-                } else {
-                    &step_for_keyboard
-                };
+                let step_to_use = current_step(use_shift, use_ctrl);
+                let (min_now, max_now) = current_bounds();
 
                 let new_value = increment_value(
-                    &current,
-                    step_to_use,
+                    &to_decimal(current),
+                    &step_to_use,
                     precision,
                     false,
-                    min_for_keyboard.as_deref(),
-                    max_for_keyboard.as_deref(),
+                    min_now.as_deref(),
+                    max_now.as_deref(),
                 );
 
-                number_value.set(new_value.clone());
+                number_value.set(from_decimal(new_value.clone()));
 
                 if let Some(callback) = on_change {
                     callback.run(new_value.clone());
                 }
 
-                if let Some(callback) = on_valid_change {
-                    callback.run(Ok(new_value));
-                }
+                report(Ok(emitted(new_value)));
             }
             _ => {}
         }
@@ -1493,46 +2386,70 @@ pub fn NumberInput(
         // Push to undo stack before changing
         push_undo(current.clone());
 
-        let step_to_use = if use_ctrl {
-            &ctrl_step_for_wheel
-        } else if use_shift {
-            &shift_step_for_wheel
-        } else {
-            &step_for_wheel
-        };
+        let step_to_use = current_step(use_shift, use_ctrl);
+        let (min_now, max_now) = current_bounds();
 
         let new_value = increment_value(
-            &current,
-            step_to_use,
+            &to_decimal(current),
+            &step_to_use,
             precision,
             is_increment,
-            min_for_wheel.as_deref(),
-            max_for_wheel.as_deref(),
+            min_now.as_deref(),
+            max_now.as_deref(),
         );
 
-        number_value.set(new_value.clone());
+        number_value.set(from_decimal(new_value.clone()));
 
         if let Some(callback) = on_change {
             callback.run(new_value.clone());
         }
 
-        if let Some(callback) = on_valid_change {
-            callback.run(Ok(new_value));
-        }
+        report(Ok(emitted(new_value)));
     };
 
-    // Track whether we're focused (to show raw vs formatted value)
-    let is_focused = RwSignal::new(false);
-
     // Handle blur - apply formatting if enabled
     let handle_blur = move |_ev: ev::FocusEvent| {
         is_focused.set(false);
 
+        // Snap to the nearest bound before any formatting
+        if clamp {
+            let current = current_exact();
+            let (min_now, max_now) = current_bounds();
+            if let Some(clamped) = clamp_to_range(
+                &to_decimal(current.clone()),
+                precision,
+                min_now.as_deref(),
+                max_now.as_deref(),
+            ) {
+                push_undo(current);
+                number_value.set(from_decimal(clamped.clone()));
+
+                if let Some(callback) = on_change {
+                    callback.run(clamped.clone());
+                }
+                report(Ok(emitted(clamped.clone())));
+                if let Some(callback) = on_range_clamp {
+                    callback.run(clamped);
+                }
+            }
+        }
+
+        // Canonical prefixed form, e.g. `ff_ff` -> `0xFFFF`
+        if radix_mode {
+            let current = number_value.get();
+            if format_on_blur {
+                if let Ok(decimal) = radix_to_decimal(&current, radix, precision) {
+                    number_value.set(from_decimal(decimal));
+                }
+            }
+            return;
+        }
+
         if !format_on_blur && auto_scientific_threshold == 0.0 {
             return;
         }
 
-        let current = number_value.get();
+        let current = parse_accounting_negative(&current_exact());
         if current.is_empty() {
             return;
         }
@@ -1547,12 +2464,8 @@ pub fn NumberInput(
             }
         }
 
-        // Apply formatting with locale support if specified
-        let formatted = if let Some(loc) = locale {
-            format_number_locale(&current, format_type, loc)
-        } else {
-            format_number(&current, format_type, thousand_separator)
-        };
+        // Apply formatting with locale, negative-style and sig-fig support
+        let formatted = format_for_display(&current, format_type);
 
         // Update the displayed value
         number_value.set(formatted);
@@ -1562,7 +2475,7 @@ pub fn NumberInput(
     let handle_focus = move |_ev: ev::FocusEvent| {
         is_focused.set(true);
 
-        if !format_on_blur && auto_scientific_threshold == 0.0 {
+        if radix_mode || !format_on_blur && auto_scientific_threshold == 0.0 {
             return;
         }
 
@@ -1574,22 +2487,7 @@ pub fn NumberInput(
         };
 
         // Strip formatting on focus to allow editing
-        let current = number_value.get();
-
-        // Also strip 'e' notation separators and locale-specific separators
-        let cleaned = current
-            .replace([thou_sep, '_', '\'', ' '], "")
-            .trim()
-            .to_string();
-
-        // Handle alternate decimal separators
-        let cleaned = if dec_sep != '.' {
-            cleaned.replace(dec_sep, ".")
-        } else {
-            cleaned
-        };
-
-        number_value.set(cleaned);
+        number_value.set(unformat_display_value(&current_exact(), thou_sep, dec_sep));
     };
 
     // Handle paste - detect and normalize pasted values
@@ -1608,7 +2506,10 @@ pub fn NumberInput(
                 push_undo(number_value.get());
 
                 // Use enhanced normalization if format detection is enabled
-                let cleaned = if allow_paste_format_detection {
+                let cleaned = if radix_mode {
+                    // Decimal normalization would mangle hex digits
+                    pasted_text.trim().to_string()
+                } else if allow_paste_format_detection {
                     normalize_pasted_number_enhanced(
                         &pasted_text,
                         decimal_separator,
@@ -1623,13 +2524,17 @@ pub fn NumberInput(
                 let filtered: String = cleaned
                     .chars()
                     .filter(|ch| {
-                        is_valid_char(
-                            *ch,
-                            &number_value.get(),
-                            allow_negative,
-                            allow_decimal,
-                            allow_scientific,
-                        )
+                        if radix_mode {
+                            is_valid_radix_char(*ch, radix, allow_negative)
+                        } else {
+                            is_valid_char(
+                                *ch,
+                                &number_value.get(),
+                                allow_negative,
+                                allow_decimal,
+                                allow_scientific,
+                            )
+                        }
                     })
                     .collect();
 
@@ -1641,9 +2546,7 @@ pub fn NumberInput(
                     callback.run(filtered.clone());
                 }
 
-                if let Some(callback) = on_valid_change {
-                    callback.run(validation_result);
-                }
+                report(validation_result);
             }
         }
     };
@@ -1697,20 +2600,24 @@ pub fn NumberInput(
 
         match conversion {
             FormatConversion::ToStandard => {
-                let formatted = format_number(&cleaned, NumberInputFormat::Standard, ',');
+                let formatted = format_with_separator(&cleaned, NumberInputFormat::Standard, ',');
                 number_value.set(formatted);
             }
             FormatConversion::ToThousand => {
-                let formatted =
-                    format_number(&cleaned, NumberInputFormat::Thousand, thousand_separator);
+                let formatted = format_with_separator(
+                    &cleaned,
+                    NumberInputFormat::Thousand,
+                    thousand_separator,
+                );
                 number_value.set(formatted);
             }
             FormatConversion::ToScientific => {
-                let formatted = format_number(&cleaned, NumberInputFormat::Scientific, ',');
+                let formatted = format_with_separator(&cleaned, NumberInputFormat::Scientific, ',');
                 number_value.set(formatted);
             }
             FormatConversion::ToEngineering => {
-                let formatted = format_number(&cleaned, NumberInputFormat::Engineering, ',');
+                let formatted =
+                    format_with_separator(&cleaned, NumberInputFormat::Engineering, ',');
                 number_value.set(formatted);
             }
             FormatConversion::CopyValue => {
@@ -1755,6 +2662,43 @@ pub fn NumberInput(
         )
     };
 
+    // Quick-convert: indexes into `quick_convert` for "interpret as" and "show as"
+    let convert_from = RwSignal::new(0usize);
+    let convert_to = RwSignal::new(1usize);
+    let quick_convert_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; display: flex; align-items: center; gap: 0.25rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("gray", 7)
+                .unwrap_or_else(|| "#495057".to_string())
+        )
+    };
+    let quick_convert_control_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "font-size: inherit; padding: 0.125rem 0.25rem; border: 1px solid {}; border-radius: {}; background: {}; color: {}; cursor: pointer;",
+            scheme_colors.border,
+            &*theme_val.radius.sm,
+            scheme_colors.background,
+            scheme_colors.text
+        )
+    };
+    let reset_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; padding: 0; border: none; background: none; font-size: {}; color: {}; cursor: pointer; text-decoration: underline;",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("blue", 6)
+                .unwrap_or_else(|| "#228be6".to_string())
+        )
+    };
+
     let error_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -1902,6 +2846,58 @@ pub fn NumberInput(
     let input_wrapper_styles =
         move || "position: relative; display: flex; align-items: stretch;".to_string();
 
+    // Horizontal padding per size; the right side starts after the controls
+    let base_padding = match size {
+        InputSize::Xs => "0.625rem",
+        InputSize::Sm => "0.75rem",
+        InputSize::Md => "0.875rem",
+        InputSize::Lg => "1rem",
+        InputSize::Xl => "1.125rem",
+    };
+    let right_start = if show_controls {
+        CONTROLS_WIDTH
+    } else {
+        base_padding
+    };
+    let size_metrics = move |theme_val: &crate::theme::Theme| -> (&'static str, String) {
+        let font_sizes = &theme_val.typography.font_sizes;
+        match size {
+            InputSize::Xs => ("1.875rem", font_sizes.xs.to_string()),
+            InputSize::Sm => ("2.25rem", font_sizes.sm.to_string()),
+            InputSize::Md => ("2.625rem", font_sizes.sm.to_string()),
+            InputSize::Lg => ("3.125rem", font_sizes.md.to_string()),
+            InputSize::Xl => ("3.75rem", font_sizes.lg.to_string()),
+        }
+    };
+    let prefix_text = prefix.clone();
+    let suffix_text = suffix.clone();
+    let affix_base = FieldIds::generate().control;
+    let described_by = affix_described_by(&affix_base, prefix.as_deref(), suffix.as_deref());
+
+    // Prefix/suffix sit over the input's padding; clicks pass through to
+    // the input so the caret stays on the number
+    let affix_styles = move |side: &str, offset: &str| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("position", "absolute")
+            .add(side, offset)
+            .add("top", "0")
+            .add("bottom", "0")
+            .add("display", "flex")
+            .add("align-items", "center")
+            .add("pointer-events", "none")
+            .add("white-space", "nowrap")
+            .add("font-size", size_metrics(&theme_val).1)
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("gray", 6)
+                    .unwrap_or_else(|| "#868e96".to_string()),
+            )
+            .build()
+    };
+
     // Adjust input padding when controls are shown
     let error_clone2 = error.clone();
     let input_with_controls_styles = move || {
@@ -1919,74 +2915,19 @@ pub fn NumberInput(
             .add("outline", "none")
             .add("box-sizing", "border-box");
 
-        // Size-based styles with extra padding for controls
-        match size {
-            InputSize::Xs => {
-                builder
-                    .add("height", "1.875rem")
-                    .add(
-                        "padding",
-                        if show_controls {
-                            "0 2rem 0 0.625rem"
-                        } else {
-                            "0 0.625rem"
-                        },
-                    )
-                    .add("font-size", &*theme_val.typography.font_sizes.xs);
-            }
-            InputSize::Sm => {
-                builder
-                    .add("height", "2.25rem")
-                    .add(
-                        "padding",
-                        if show_controls {
-                            "0 2rem 0 0.75rem"
-                        } else {
-                            "0 0.75rem"
-                        },
-                    )
-                    .add("font-size", &*theme_val.typography.font_sizes.sm);
-            }
-            InputSize::Md => {
-                builder
-                    .add("height", "2.625rem")
-                    .add(
-                        "padding",
-                        if show_controls {
-                            "0 2rem 0 0.875rem"
-                        } else {
-                            "0 0.875rem"
-                        },
-                    )
-                    .add("font-size", &*theme_val.typography.font_sizes.sm);
-            }
-            InputSize::Lg => {
-                builder
-                    .add("height", "3.125rem")
-                    .add(
-                        "padding",
-                        if show_controls {
-                            "0 2rem 0 1rem"
-                        } else {
-                            "0 1rem"
-                        },
-                    )
-                    .add("font-size", &*theme_val.typography.font_sizes.md);
-            }
-            InputSize::Xl => {
-                builder
-                    .add("height", "3.75rem")
-                    .add(
-                        "padding",
-                        if show_controls {
-                            "0 2rem 0 1.125rem"
-                        } else {
-                            "0 1.125rem"
-                        },
-                    )
-                    .add("font-size", &*theme_val.typography.font_sizes.lg);
-            }
-        }
+        // Size-based styles, with room for the controls and any prefix/suffix
+        let (height, font_size) = size_metrics(&theme_val);
+        builder
+            .add("height", height)
+            .add(
+                "padding",
+                format!(
+                    "0 {} 0 {}",
+                    side_padding(right_start, suffix_text.as_deref()),
+                    side_padding(base_padding, prefix_text.as_deref())
+                ),
+            )
+            .add("font-size", font_size);
 
         // Variant-based styles
         match variant {
@@ -2057,6 +2998,7 @@ pub fn NumberInput(
                     placeholder=placeholder.unwrap_or_default()
                     disabled=move || disabled.get()
                     required=required
+                    aria-describedby=described_by
                     prop:value=move || number_value.get()
                     on:input=handle_input
                     on:keydown=handle_keydown
@@ -2068,6 +3010,28 @@ pub fn NumberInput(
                     on:contextmenu=handle_contextmenu
                 />
 
+                {prefix.map(|p| view! {
+                    <span
+                        id=format!("{}-prefix", affix_base)
+                        class="mingot-number-input-prefix"
+                        style=move || affix_styles("left", base_padding)
+                        aria-hidden="true"
+                    >
+                        {p}
+                    </span>
+                })}
+                // Placed left of the controls so the two never overlap
+                {suffix.map(|s| view! {
+                    <span
+                        id=format!("{}-suffix", affix_base)
+                        class="mingot-number-input-suffix"
+                        style=move || affix_styles("right", right_start)
+                        aria-hidden="true"
+                    >
+                        {s}
+                    </span>
+                })}
+
                 // Selection info popup
                 {move || selection_info.get().map(|info| {
                     view! {
@@ -2101,7 +3065,7 @@ pub fn NumberInput(
                                 class="mingot-number-input-increment"
                                 style=inc_styles
                                 disabled=move || disabled.get()
-                                on:click=handle_increment.clone()
+                                on:click=handle_increment
                                 aria-label="Increment"
                                 tabindex="-1"
                             >
@@ -2114,7 +3078,7 @@ pub fn NumberInput(
                                 class="mingot-number-input-decrement"
                                 style=dec_styles
                                 disabled=move || disabled.get()
-                                on:click=handle_decrement.clone()
+                                on:click=handle_decrement
                                 aria-label="Decrement"
                                 tabindex="-1"
                             >
@@ -2131,6 +3095,95 @@ pub fn NumberInput(
                 <div style=description_styles>{d}</div>
             })}
 
+            // Reset to default
+            {show_reset.then_some(move || is_dirty.get().then(|| view! {
+                <button
+                    type="button"
+                    class="mingot-number-input-reset"
+                    style=reset_styles
+                    disabled=move || disabled.get()
+                    on:click=move |_| field_state.reset()
+                >
+                    "Reset to default"
+                </button>
+            }))}
+
+            // Quick unit conversion
+            {quick_convert.filter(|units| units.len() > 1).map(|units| {
+                let units = StoredValue::new(units);
+                let apply_conversion = move |_| {
+                    let converted = units.with_value(|units| {
+                        quick_convert_value(
+                            &canonical_value(),
+                            &units[convert_from.get()],
+                            &units[convert_to.get()],
+                            precision,
+                        )
+                    });
+                    if let Some((numeric, text)) = converted {
+                        push_undo(number_value.get());
+                        // The number is now expressed in the target unit
+                        convert_from.set(convert_to.get());
+                        commit_decimal(text);
+                        if let Some(callback) = on_quick_convert {
+                            callback.run(numeric);
+                        }
+                    }
+                };
+                let unit_options = move |selected: RwSignal<usize>| {
+                    units.with_value(|units| {
+                        units
+                            .iter()
+                            .enumerate()
+                            .map(|(i, unit)| {
+                                view! {
+                                    <option value=i.to_string() selected=move || selected.get() == i>
+                                        {unit.symbol.clone()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    })
+                };
+                let on_select = move |selected: RwSignal<usize>| {
+                    move |ev: ev::Event| {
+                        if let Ok(i) = event_target_value(&ev).parse() {
+                            selected.set(i);
+                        }
+                    }
+                };
+                view! {
+                    <div class="mingot-number-input-quick-convert" style=quick_convert_styles>
+                        <span>"as"</span>
+                        <select
+                            style=quick_convert_control_styles
+                            aria-label="Interpret value as"
+                            disabled=move || disabled.get()
+                            on:change=on_select(convert_from)
+                        >
+                            {unit_options(convert_from)}
+                        </select>
+                        <span>"→"</span>
+                        <select
+                            style=quick_convert_control_styles
+                            aria-label="Convert to"
+                            disabled=move || disabled.get()
+                            on:change=on_select(convert_to)
+                        >
+                            {unit_options(convert_to)}
+                        </select>
+                        <button
+                            type="button"
+                            style=quick_convert_control_styles
+                            disabled=move || disabled.get()
+                            on:click=apply_conversion
+                        >
+                            "Convert"
+                        </button>
+                    </div>
+                }
+            })}
+
             // Precision indicator
             {show_precision_indicator.then(|| {
                 let precision_description = get_precision_description(precision);
@@ -2176,7 +3229,11 @@ pub fn NumberInput(
                     )
                 };
                 let warning_message = move || {
-                    check_overflow_warning(&number_value.get(), precision, overflow_warning_threshold)
+                    check_overflow_warning(
+                        &to_decimal(number_value.get()),
+                        precision,
+                        overflow_warning_threshold,
+                    )
                 };
                 view! {
                     {move || warning_message().map(|msg| view! {
@@ -2263,6 +3320,131 @@ pub fn NumberInput(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_quick_convert_value() {
+        use crate::components::unit_input::length;
+
+        let (numeric, text) = quick_convert_value(
+            "2.5",
+            &length::kilometer(),
+            &length::meter(),
+            NumberInputPrecision::Decimal(2),
+        )
+        .unwrap();
+        assert!((numeric - 2500.0).abs() < 1e-9);
+        assert_eq!(text, "2500.00");
+
+        // Integer precisions round
+        let (_, text) = quick_convert_value(
+            "1",
+            &length::inch(),
+            &length::millimeter(),
+            NumberInputPrecision::I64,
+        )
+        .unwrap();
+        assert_eq!(text, "25");
+
+        // Display formatting is removed before converting
+        let (_, text) = quick_convert_value(
+            &unformat_display_value("1,000", ',', '.'),
+            &length::meter(),
+            &length::kilometer(),
+            NumberInputPrecision::Decimal(1),
+        )
+        .unwrap();
+        assert_eq!(text, "1.0");
+    }
+
+    #[test]
+    fn test_quick_convert_with_comma_decimal_separator() {
+        use crate::components::unit_input::length;
+
+        // "1.234,5" in a European locale is 1234.5, not 1.2345
+        let canonical = unformat_display_value("1.234,5", '.', ',');
+        assert_eq!(canonical, "1234.5");
+        let (numeric, text) = quick_convert_value(
+            &canonical,
+            &length::meter(),
+            &length::centimeter(),
+            NumberInputPrecision::Decimal(1),
+        )
+        .unwrap();
+        assert!((numeric - 123450.0).abs() < 1e-9);
+        assert_eq!(text, "123450.0");
+    }
+
+    #[test]
+    fn test_quick_convert_from_radix_value() {
+        use crate::components::unit_input::data;
+
+        // A hex field converts its decimal value, not the "0xFF" text
+        let decimal =
+            radix_to_decimal("0xFF", NumberRadix::Hex, NumberInputPrecision::U64).unwrap();
+        let (_, text) = quick_convert_value(
+            &decimal,
+            &data::kilobyte(),
+            &data::byte(),
+            NumberInputPrecision::U64,
+        )
+        .unwrap();
+        assert_eq!(text, "255000");
+        assert!(quick_convert_value(
+            "0xFF",
+            &data::kilobyte(),
+            &data::byte(),
+            NumberInputPrecision::U64
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_quick_convert_result_overflows_integer_field() {
+        use crate::components::unit_input::length;
+
+        // The converted text is validated like typed input, so a 31-digit
+        // result is an overflow for a U64 field rather than a valid value
+        let (_, text) = quick_convert_value(
+            "1e24",
+            &length::kilometer(),
+            &length::millimeter(),
+            NumberInputPrecision::U64,
+        )
+        .unwrap();
+        assert_eq!(text.len(), 31);
+        assert!(validate_u64(&text).is_err());
+    }
+
+    #[test]
+    fn test_quick_convert_value_rejects() {
+        use crate::components::unit_input::{length, mass, temperature};
+
+        // Incompatible units
+        assert!(quick_convert_value(
+            "1",
+            &length::meter(),
+            &mass::kilogram(),
+            NumberInputPrecision::I64
+        )
+        .is_none());
+
+        // Negative result for an unsigned field
+        assert!(quick_convert_value(
+            "0",
+            &temperature::kelvin(),
+            &temperature::celsius(),
+            NumberInputPrecision::U64
+        )
+        .is_none());
+
+        assert!(quick_convert_value(
+            "abc",
+            &length::meter(),
+            &length::kilometer(),
+            NumberInputPrecision::I64
+        )
+        .is_none());
+    }
+
     #[test]
     fn test_increment_u64() {
         let result = increment_value("10", "1", NumberInputPrecision::U64, true, None, None);
@@ -2300,6 +3482,210 @@ mod tests {
         assert_eq!(result, "0");
     }
 
+    #[test]
+    fn test_compare_values_large_integers() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            compare_values(
+                "340282366920938463463374607431768211455",
+                "340282366920938463463374607431768211454",
+                NumberInputPrecision::U128
+            ),
+            Ok(Ordering::Greater)
+        );
+        // Differ only beyond f64's 53-bit mantissa
+        assert_eq!(
+            compare_values(
+                "170141183460469231731687303715884105727",
+                "170141183460469231731687303715884105726",
+                NumberInputPrecision::I128
+            ),
+            Ok(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_values("1,000", "(1,000)", NumberInputPrecision::I64),
+            Ok(Ordering::Greater)
+        );
+        assert!(compare_values("abc", "1", NumberInputPrecision::U64).is_err());
+    }
+
+    #[test]
+    fn test_compare_values_decimal_trailing_zeros() {
+        use std::cmp::Ordering;
+        let precision = NumberInputPrecision::Decimal(4);
+        assert_eq!(
+            compare_values("1.50", "1.5", precision),
+            Ok(Ordering::Equal)
+        );
+        assert_eq!(
+            compare_values("001.5000", "1.5", precision),
+            Ok(Ordering::Equal)
+        );
+        assert_eq!(compare_values("-0.0", "0", precision), Ok(Ordering::Equal));
+        assert_eq!(
+            compare_values("10.01", "9.999", precision),
+            Ok(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_values("-10.01", "-9.999", precision),
+            Ok(Ordering::Less)
+        );
+        assert_eq!(
+            compare_values(".5", "0.49", precision),
+            Ok(Ordering::Greater)
+        );
+        // Beyond f64 precision
+        assert_eq!(
+            compare_values("0.10000000000000000001", "0.1", precision),
+            Ok(Ordering::Greater)
+        );
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[test]
+    fn test_compare_values_arbitrary() {
+        use std::cmp::Ordering;
+        assert_eq!(
+            compare_values("2.500", "2.5", NumberInputPrecision::Arbitrary),
+            Ok(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_is_within() {
+        let precision = NumberInputPrecision::U128;
+        let max = "340282366920938463463374607431768211454";
+        assert_eq!(
+            is_within(
+                "340282366920938463463374607431768211455",
+                None,
+                Some(max),
+                precision
+            ),
+            Ok(false)
+        );
+        assert_eq!(is_within("5", Some("0"), Some(max), precision), Ok(true));
+        assert_eq!(
+            is_within(
+                "2.50",
+                Some("0"),
+                Some("2.5"),
+                NumberInputPrecision::Decimal(2)
+            ),
+            Ok(true)
+        );
+        assert!(is_within("5", Some("x"), None, NumberInputPrecision::I64).is_err());
+    }
+
+    #[test]
+    fn test_check_range() {
+        let p = NumberInputPrecision::I64;
+        // Exactly at either bound is in range
+        assert_eq!(check_range("0", p, Some("0"), Some("100")), Ok(()));
+        assert_eq!(check_range("100", p, Some("0"), Some("100")), Ok(()));
+        assert!(matches!(
+            check_range("101", p, Some("0"), Some("100")),
+            Err(ParseError::OutOfRange(msg)) if msg.contains("maximum (100)")
+        ));
+        assert!(matches!(
+            check_range("-1", p, Some("0"), None),
+            Err(ParseError::OutOfRange(msg)) if msg.contains("minimum (0)")
+        ));
+        // Compared without going through f64
+        assert!(check_range(
+            "340282366920938463463374607431768211455",
+            NumberInputPrecision::U128,
+            None,
+            Some("340282366920938463463374607431768211454"),
+        )
+        .is_err());
+        assert_eq!(
+            check_range("2.50", NumberInputPrecision::Decimal(2), None, Some("2.5")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_step_at_and_past_bounds() {
+        use NumberInputPrecision::*;
+        // Already at the bound: stepping further is refused
+        for precision in [U64, U128, I64, I128] {
+            assert_eq!(
+                increment_value("100", "1", precision, true, Some("0"), Some("100")),
+                "100"
+            );
+            assert_eq!(
+                increment_value("0", "1", precision, false, Some("0"), Some("100")),
+                "0"
+            );
+            // A step that would overshoot lands on the bound
+            assert_eq!(
+                increment_value("95", "10", precision, true, Some("0"), Some("100")),
+                "100"
+            );
+            assert_eq!(
+                increment_value("5", "10", precision, false, Some("0"), Some("100")),
+                "0"
+            );
+        }
+        assert_eq!(
+            increment_value("9.95", "0.10", Decimal(2), true, None, Some("10.00")),
+            "10.00"
+        );
+        assert_eq!(
+            increment_value("10.00", "0.10", Decimal(2), true, None, Some("10.00")),
+            "10.00"
+        );
+    }
+
+    #[test]
+    fn test_clamp_to_range_lowering_max() {
+        // Lowering max below the current value clamps and reports the change
+        assert_eq!(
+            clamp_to_range("75", NumberInputPrecision::I64, Some("0"), Some("50")),
+            Some("50".to_string())
+        );
+        // In range: nothing to notify
+        assert_eq!(
+            clamp_to_range("25", NumberInputPrecision::I64, Some("0"), Some("50")),
+            None
+        );
+        assert_eq!(
+            clamp_to_range("-1.5", NumberInputPrecision::Decimal(2), Some("0"), None),
+            Some("0.00".to_string())
+        );
+        assert_eq!(
+            clamp_to_range("1,000", NumberInputPrecision::U64, None, Some("999")),
+            Some("999".to_string())
+        );
+        // Empty/invalid values are left for validation to report
+        assert_eq!(
+            clamp_to_range("", NumberInputPrecision::I64, None, Some("50")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_clamp_agrees_with_compare_values() {
+        // Equal to 0.1 as an f64, but above it as a decimal
+        let value = "0.10000000000000000001";
+        let precision = NumberInputPrecision::Decimal(2);
+        assert_eq!(
+            compare_values(value, "0.1", precision),
+            Ok(std::cmp::Ordering::Greater)
+        );
+        assert_eq!(is_within(value, None, Some("0.1"), precision), Ok(false));
+        assert_eq!(
+            clamp_to_range(value, precision, None, Some("0.1")),
+            Some("0.10".to_string())
+        );
+        assert_eq!(clamp_to_range("0.1", precision, None, Some("0.1")), None);
+        assert_eq!(
+            clamp_to_range("-0.10000000000000000001", precision, Some("-0.1"), None),
+            Some("-0.10".to_string())
+        );
+    }
+
     #[test]
     fn test_increment_i64_negative() {
         let result = increment_value("-10", "1", NumberInputPrecision::I64, true, None, None);
@@ -2432,7 +3818,7 @@ mod tests {
     #[test]
     fn test_format_number_standard() {
         assert_eq!(
-            format_number("1234567", NumberInputFormat::Standard, ','),
+            format_with_separator("1234567", NumberInputFormat::Standard, ','),
             "1234567"
         );
     }
@@ -2440,11 +3826,11 @@ mod tests {
     #[test]
     fn test_format_number_thousand() {
         assert_eq!(
-            format_number("1234567", NumberInputFormat::Thousand, ','),
+            format_with_separator("1234567", NumberInputFormat::Thousand, ','),
             "1,234,567"
         );
         assert_eq!(
-            format_number("1234567.89", NumberInputFormat::Thousand, ','),
+            format_with_separator("1234567.89", NumberInputFormat::Thousand, ','),
             "1,234,567.89"
         );
     }
@@ -2452,7 +3838,7 @@ mod tests {
     #[test]
     fn test_format_number_scientific() {
         // Scientific notation
-        let result = format_number("1234567", NumberInputFormat::Scientific, ',');
+        let result = format_with_separator("1234567", NumberInputFormat::Scientific, ',');
         assert!(result.contains("e") || result.contains("E"));
     }
 
@@ -2476,6 +3862,163 @@ mod tests {
         assert_eq!(normalize_pasted_number("£1,000", '.'), "1000");
     }
 
+    #[test]
+    fn test_apply_negative_style() {
+        assert_eq!(
+            apply_negative_style("-1,234.00", NegativeStyle::Parentheses),
+            "(1,234.00)"
+        );
+        assert_eq!(
+            apply_negative_style("1,234.00", NegativeStyle::Parentheses),
+            "1,234.00"
+        );
+        assert_eq!(
+            apply_negative_style("-1,234.00", NegativeStyle::Minus),
+            "-1,234.00"
+        );
+    }
+
+    #[test]
+    fn test_parse_accounting_negative() {
+        assert_eq!(parse_accounting_negative("(1,234.00)"), "-1,234.00");
+        assert_eq!(parse_accounting_negative(" ( 42 ) "), "-42");
+        assert_eq!(parse_accounting_negative("1,234.00"), "1,234.00");
+        assert_eq!(parse_accounting_negative("-5"), "-5");
+        assert_eq!(
+            validate_decimal(&parse_accounting_negative("(1,234.00)"), 2),
+            Ok("-1234.00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_focus_and_blur_without_edit_is_not_dirty() {
+        // format_on_blur: the default is stored and shown formatted,
+        // unformatted on focus and formatted again on blur
+        let blurred = |raw: &str, locale| {
+            format_display_value(
+                raw,
+                NumberInputFormat::Thousand,
+                Some(locale),
+                ',',
+                NegativeStyle::Parentheses,
+                None,
+            )
+        };
+        for (default, locale, thou, dec, precision) in [
+            (
+                "1000",
+                NumberInputLocale::US,
+                ',',
+                '.',
+                NumberInputPrecision::U64,
+            ),
+            (
+                "-1000.50",
+                NumberInputLocale::US,
+                ',',
+                '.',
+                NumberInputPrecision::Decimal(2),
+            ),
+            (
+                "1234.5",
+                NumberInputLocale::EU,
+                '.',
+                ',',
+                NumberInputPrecision::Decimal(1),
+            ),
+        ] {
+            let stored = blurred(default, locale);
+            assert_ne!(stored, default);
+            let focused = unformat_display_value(&stored, thou, dec);
+            assert_eq!(focused, default);
+            let reblurred = blurred(&focused, locale);
+            assert!(same_number(
+                &unformat_display_value(&reblurred, thou, dec),
+                &unformat_display_value(&stored, thou, dec),
+                precision,
+            ));
+            // The raw strings differ, which is what made the field dirty
+            assert_ne!(reblurred, default);
+        }
+    }
+
+    #[test]
+    fn test_same_number() {
+        let decimal = NumberInputPrecision::Decimal(2);
+        assert!(same_number("1.50", "1.5", decimal));
+        assert!(!same_number("1.51", "1.5", decimal));
+        assert!(same_number("", "", decimal));
+        assert!(!same_number("", "0", decimal));
+        assert!(!same_number("abc", "abd", NumberInputPrecision::U64));
+    }
+
+    #[test]
+    fn test_format_display_value_accounting() {
+        let formatted = format_display_value(
+            "-1234.00",
+            NumberInputFormat::Thousand,
+            Some(NumberInputLocale::US),
+            ',',
+            NegativeStyle::Parentheses,
+            None,
+        );
+        assert_eq!(formatted, "(1,234.00)");
+
+        // Round trip back to a raw negative value
+        assert_eq!(
+            parse_accounting_negative(&formatted).replace(',', ""),
+            "-1234.00"
+        );
+    }
+
+    #[test]
+    fn test_increment_across_zero_accounting() {
+        // Stepping up from (1.00) crosses zero and loses the parentheses
+        let up = increment_value(
+            "(1.00)",
+            "2",
+            NumberInputPrecision::Decimal(2),
+            true,
+            None,
+            None,
+        );
+        assert_eq!(up, "1.00");
+        let display = format_display_value(
+            &up,
+            NumberInputFormat::Thousand,
+            None,
+            ',',
+            NegativeStyle::Parentheses,
+            None,
+        );
+        assert_eq!(display, "1.00");
+
+        // Stepping down from 1.00 crosses zero and gains them
+        let down = increment_value(
+            "1.00",
+            "2",
+            NumberInputPrecision::Decimal(2),
+            false,
+            None,
+            None,
+        );
+        let display = format_display_value(
+            &down,
+            NumberInputFormat::Thousand,
+            None,
+            ',',
+            NegativeStyle::Parentheses,
+            None,
+        );
+        assert_eq!(display, "(1.00)");
+    }
+
+    #[test]
+    fn test_normalize_pasted_number_accounting_negative() {
+        assert_eq!(normalize_pasted_number("(1,234.00)", '.'), "-1234.00");
+        assert_eq!(normalize_pasted_number("($1,234.00)", '.'), "-1234.00");
+    }
+
     #[test]
     fn test_normalize_pasted_number_alternate_separators() {
         // European format with comma as decimal (user set decimal_separator to ',')
@@ -2486,6 +4029,186 @@ mod tests {
         assert_eq!(normalize_pasted_number("1'234'567", '.'), "1234567");
     }
 
+    #[test]
+    fn test_radix_to_decimal() {
+        use NumberInputPrecision::*;
+        assert_eq!(
+            radix_to_decimal("0xFF", NumberRadix::Hex, U64),
+            Ok("255".to_string())
+        );
+        assert_eq!(
+            radix_to_decimal("ff_FF", NumberRadix::Hex, U64),
+            Ok("65535".to_string())
+        );
+        assert_eq!(
+            radix_to_decimal("1010", NumberRadix::Binary, U64),
+            Ok("10".to_string())
+        );
+        assert_eq!(
+            radix_to_decimal("0B1010", NumberRadix::Binary, U64),
+            Ok("10".to_string())
+        );
+        assert_eq!(
+            radix_to_decimal("0o777", NumberRadix::Octal, I64),
+            Ok("511".to_string())
+        );
+        assert_eq!(
+            radix_to_decimal("-0x80", NumberRadix::Hex, I64),
+            Ok("-128".to_string())
+        );
+        assert!(matches!(
+            radix_to_decimal("0b102", NumberRadix::Binary, U64),
+            Err(ParseError::InvalidFormat(_))
+        ));
+        assert_eq!(
+            radix_to_decimal("-0x1", NumberRadix::Hex, U64),
+            Err(ParseError::NegativeNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_radix_overflow_uses_type_max() {
+        use NumberInputPrecision::*;
+        assert_eq!(
+            radix_to_decimal("0xFFFF_FFFF_FFFF_FFFF", NumberRadix::Hex, U64),
+            Ok(u64::MAX.to_string())
+        );
+        assert!(matches!(
+            radix_to_decimal("0x1_0000_0000_0000_0000", NumberRadix::Hex, U64),
+            Err(ParseError::Overflow(_))
+        ));
+        assert!(matches!(
+            radix_to_decimal("0x8000_0000_0000_0000", NumberRadix::Hex, I64),
+            Err(ParseError::Overflow(_))
+        ));
+        assert_eq!(
+            radix_to_decimal("-0x8000_0000_0000_0000", NumberRadix::Hex, I64),
+            Ok(i64::MIN.to_string())
+        );
+        assert!(matches!(
+            radix_to_decimal(&"f".repeat(33), NumberRadix::Hex, U128),
+            Err(ParseError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_radix_round_trip_without_precision_loss() {
+        for radix in [
+            NumberRadix::Binary,
+            NumberRadix::Octal,
+            NumberRadix::Hex,
+            NumberRadix::Decimal,
+        ] {
+            let max = u128::MAX.to_string();
+            let encoded = decimal_to_radix(&max, radix).unwrap();
+            assert_eq!(
+                radix_to_decimal(&encoded, radix, NumberInputPrecision::U128),
+                Ok(max)
+            );
+
+            let min = i128::MIN.to_string();
+            let encoded = decimal_to_radix(&min, radix).unwrap();
+            assert_eq!(
+                radix_to_decimal(&encoded, radix, NumberInputPrecision::I128),
+                Ok(min)
+            );
+        }
+        assert_eq!(
+            decimal_to_radix("255", NumberRadix::Hex),
+            Some("0xFF".to_string())
+        );
+        assert_eq!(
+            decimal_to_radix("-5", NumberRadix::Binary),
+            Some("-0b101".to_string())
+        );
+        assert_eq!(decimal_to_radix("1.5", NumberRadix::Hex), None);
+    }
+
+    #[test]
+    fn test_is_valid_radix_char() {
+        assert!(is_valid_radix_char('F', NumberRadix::Hex, false));
+        assert!(is_valid_radix_char('b', NumberRadix::Hex, false));
+        assert!(is_valid_radix_char('x', NumberRadix::Hex, false));
+        assert!(!is_valid_radix_char('g', NumberRadix::Hex, false));
+        assert!(!is_valid_radix_char('2', NumberRadix::Binary, false));
+        assert!(is_valid_radix_char('b', NumberRadix::Binary, false));
+        assert!(!is_valid_radix_char('8', NumberRadix::Octal, false));
+        assert!(!is_valid_radix_char('-', NumberRadix::Hex, false));
+        assert!(is_valid_radix_char('_', NumberRadix::Octal, false));
+    }
+
+    #[test]
+    fn test_validation_callbacks_order() {
+        use std::sync::{Arc, Mutex};
+
+        let owner = Owner::new();
+        owner.with(|| {
+            let log = Arc::new(Mutex::new(Vec::<String>::new()));
+            let record = |prefix: &'static str| {
+                let log = Arc::clone(&log);
+                move |entry: String| log.lock().unwrap().push(format!("{prefix}:{entry}"))
+            };
+            let valid = record("valid");
+            let invalid = record("invalid");
+            let result = record("result");
+            let callbacks = ValidationCallbacks {
+                on_valid: Some(Callback::new(valid)),
+                on_invalid: Some(Callback::new(move |err: ParseError| {
+                    invalid(err.to_string())
+                })),
+                on_result: Some(Callback::new(move |r: Result<String, ParseError>| {
+                    result(if r.is_ok() { "ok" } else { "err" }.to_string())
+                })),
+            };
+
+            // valid -> invalid -> valid
+            callbacks.report(Ok("12".to_string()));
+            callbacks.report(Err(ParseError::NegativeNotAllowed));
+            callbacks.report(Ok("13".to_string()));
+
+            assert_eq!(
+                *log.lock().unwrap(),
+                vec![
+                    "valid:12",
+                    "result:ok",
+                    "invalid:Negative values not allowed",
+                    "result:err",
+                    "valid:13",
+                    "result:ok",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_affix_described_by() {
+        assert_eq!(affix_described_by("f", None, None), None);
+        assert_eq!(affix_described_by("f", Some(""), None), None);
+        assert_eq!(
+            affix_described_by("f", Some("$"), None).as_deref(),
+            Some("f-prefix")
+        );
+        assert_eq!(
+            affix_described_by("f", Some("$"), Some("USD")).as_deref(),
+            Some("f-prefix f-suffix")
+        );
+    }
+
+    #[test]
+    fn test_side_padding() {
+        assert_eq!(side_padding("0.875rem", None), "0.875rem");
+        assert_eq!(side_padding("0.875rem", Some("")), "0.875rem");
+        assert_eq!(
+            side_padding("0.875rem", Some("$")),
+            "calc(0.875rem + 1ch + 0.375rem)"
+        );
+        // A suffix next to the controls starts after them
+        assert_eq!(
+            side_padding(CONTROLS_WIDTH, Some("USD")),
+            "calc(2rem + 3ch + 0.375rem)"
+        );
+    }
+
     #[test]
     fn test_is_valid_char() {
         assert!(is_valid_char('5', "", false, false, false));
@@ -2497,6 +4220,53 @@ mod tests {
         assert!(!is_valid_char('a', "123", false, false, false));
     }
 
+    #[test]
+    fn test_scientific_sig_figs_avogadro() {
+        let avogadro = 6.02214076e23;
+        assert_eq!(convert_to_scientific_sig(avogadro, 3), "6.02e23");
+        assert_eq!(convert_to_scientific_sig(avogadro, 6), "6.02214e23");
+        assert_eq!(convert_to_scientific_sig(-0.000123456, 2), "-1.2e-4");
+    }
+
+    #[test]
+    fn test_engineering_sig_figs_avogadro() {
+        let avogadro = 6.02214076e23;
+        assert_eq!(convert_to_engineering_sig(avogadro, 3), "602e21");
+        assert_eq!(convert_to_engineering_sig(avogadro, 6), "602.214e21");
+    }
+
+    #[test]
+    fn test_engineering_sig_figs_exponent_multiple_of_three() {
+        assert_eq!(convert_to_engineering_sig(1234567.0, 3), "1.23e6");
+        assert_eq!(convert_to_engineering_sig(12346.0, 4), "12.35e3");
+        assert_eq!(convert_to_engineering_sig(0.000123456, 3), "123e-6");
+        assert_eq!(convert_to_engineering_sig(-999.6, 3), "-1.00e3");
+        assert_eq!(convert_to_engineering_sig(42.0, 3), "42.0");
+        // Fewer sig figs than integer digits pads with zeros
+        assert_eq!(convert_to_engineering_sig(456000.0, 1), "500e3");
+    }
+
+    #[test]
+    fn test_format_display_value_sig_figs() {
+        let display = |format| {
+            format_display_value(
+                "602214076000000000000000",
+                format,
+                None,
+                ',',
+                NegativeStyle::Minus,
+                Some(4),
+            )
+        };
+        assert_eq!(display(NumberInputFormat::Scientific), "6.022e23");
+        assert_eq!(display(NumberInputFormat::Engineering), "602.2e21");
+        // Sig figs do not apply to other formats
+        assert_eq!(
+            display(NumberInputFormat::Thousand),
+            "602,214,076,000,000,000,000,000"
+        );
+    }
+
     #[test]
     fn test_format_number_engineering() {
         // Engineering notation (exponents divisible by 3)
@@ -2556,6 +4326,46 @@ mod tests {
         assert!(check_overflow_warning(&large_val, NumberInputPrecision::U64, 0.9).is_some());
     }
 
+    #[test]
+    fn test_public_format_number() {
+        assert_eq!(
+            format_number(
+                -1234.5,
+                NumberInputFormat::Thousand,
+                NumberInputLocale::Swiss,
+                Some(2)
+            ),
+            "-1'234.50"
+        );
+        assert_eq!(
+            format_number(
+                1234.5,
+                NumberInputFormat::Standard,
+                NumberInputLocale::US,
+                None
+            ),
+            "1234.5"
+        );
+        assert_eq!(
+            format_number(
+                6.02214076e23,
+                NumberInputFormat::Scientific,
+                NumberInputLocale::US,
+                Some(2)
+            ),
+            "6.02e23"
+        );
+        assert_eq!(
+            format_number(
+                f64::NAN,
+                NumberInputFormat::Thousand,
+                NumberInputLocale::US,
+                None
+            ),
+            "NaN"
+        );
+    }
+
     #[test]
     fn test_format_number_locale() {
         // US format
@@ -2931,5 +4741,25 @@ mod arbitrary_precision_tests {
             Some("100.00"),
         );
         assert_eq!(result, "100.00");
+
+        // At the bound, and a step that would overshoot it
+        let result = increment_value(
+            "100.00",
+            "0.01",
+            NumberInputPrecision::Arbitrary,
+            true,
+            None,
+            Some("100.00"),
+        );
+        assert_eq!(result, "100.00");
+        let result = increment_value(
+            "0.5",
+            "1",
+            NumberInputPrecision::Arbitrary,
+            false,
+            Some("0"),
+            None,
+        );
+        assert_eq!(result, "0");
     }
 }
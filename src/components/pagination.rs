@@ -13,6 +13,26 @@ pub enum PaginationSize {
     Xl,
 }
 
+/// Pages needed for `total_items` at `page_size` per page (0 for no items
+/// or a zero page size)
+pub fn page_count(total_items: usize, page_size: usize) -> usize {
+    if page_size == 0 {
+        0
+    } else {
+        total_items.div_ceil(page_size)
+    }
+}
+
+/// Page to go to for the jump input's text: clamped to `1..=total`, or
+/// `None` when the text isn't a whole number or there are no pages
+pub fn parse_jump_page(input: &str, total: usize) -> Option<usize> {
+    if total == 0 {
+        return None;
+    }
+    let page: u64 = input.trim().parse().ok()?;
+    Some(usize::try_from(page).unwrap_or(usize::MAX).clamp(1, total))
+}
+
 /// A pagination component for navigating through pages of content.
 ///
 /// # Example
@@ -30,10 +50,20 @@ pub enum PaginationSize {
 ///     />
 /// }
 /// ```
+///
+/// Instead of `total` pages, pass `total_items` and `page_size` to have
+/// the page count computed.
 #[component]
 pub fn Pagination(
     /// Total number of pages
-    total: usize,
+    #[prop(optional)]
+    total: Option<usize>,
+    /// Number of items being paged; with `page_size`, used when `total` is unset
+    #[prop(optional)]
+    total_items: Option<usize>,
+    /// Items per page, for `total_items`
+    #[prop(default = 10)]
+    page_size: usize,
     /// Current page (1-indexed)
     #[prop(into)]
     value: Signal<usize>,
@@ -52,6 +82,9 @@ pub fn Pagination(
     /// Whether to show previous/next buttons
     #[prop(default = true)]
     with_controls: bool,
+    /// Whether to show a page number input with a "Go" button
+    #[prop(default = false)]
+    with_jump: bool,
     /// Gap between buttons
     #[prop(default = "0.25rem".to_string(), into)]
     gap: String,
@@ -70,6 +103,9 @@ pub fn Pagination(
 ) -> impl IntoView {
     let theme = use_theme();
     let size = size.unwrap_or_default();
+    let total = total.unwrap_or_else(|| page_count(total_items.unwrap_or(0), page_size));
+    // Nothing to page through
+    let disabled = Signal::derive(move || disabled.get() || total == 0);
 
     // Calculate which page buttons to show
     // Returns Option<usize> where None represents ellipsis dots
@@ -227,6 +263,41 @@ pub fn Pagination(
         }
     };
 
+    let jump_text = RwSignal::new(String::new());
+    let jump_invalid = RwSignal::new(false);
+    let jump = move || match parse_jump_page(&jump_text.get_untracked(), total) {
+        Some(page) => {
+            jump_invalid.set(false);
+            jump_text.set(String::new());
+            handle_page_change(page);
+        }
+        None => jump_invalid.set(true),
+    };
+
+    let jump_input_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let border_color = if jump_invalid.get() {
+            scheme_colors
+                .get_color("red", 6)
+                .unwrap_or_else(|| "#fa5252".to_string())
+        } else {
+            scheme_colors.border.clone()
+        };
+        let mut builder = StyleBuilder::new();
+        builder
+            .add("width", "4rem")
+            .add("height", button_size)
+            .add("padding", "0 0.5rem")
+            .add("border", format!("1px solid {}", border_color))
+            .add("border-radius", &*theme_val.radius.sm)
+            .add("background-color", scheme_colors.background.clone())
+            .add("color", scheme_colors.text.clone())
+            .add("font-family", &*theme_val.typography.font_family)
+            .add("font-size", &*theme_val.typography.font_sizes.sm);
+        builder.build()
+    };
+
     let wrapper_styles = move || {
         let mut styles = format!("display: flex; align-items: center; gap: {};", gap);
         if let Some(ref s) = style {
@@ -340,6 +411,43 @@ pub fn Pagination(
                     </button>
                 }
             })}
+
+            // Jump to page
+            {with_jump.then(|| {
+                let button_styles = make_button_styles(false, true);
+                view! {
+                    <input
+                        type="number"
+                        inputmode="numeric"
+                        min="1"
+                        max=total
+                        placeholder="Page"
+                        aria-label="Jump to page"
+                        aria-invalid=move || jump_invalid.get().then_some("true")
+                        style=jump_input_styles
+                        disabled=move || disabled.get()
+                        prop:value=move || jump_text.get()
+                        on:input=move |ev| {
+                            jump_invalid.set(false);
+                            jump_text.set(event_target_value(&ev));
+                        }
+                        on:keydown=move |ev| {
+                            if ev.key() == "Enter" {
+                                ev.prevent_default();
+                                jump();
+                            }
+                        }
+                    />
+                    <button
+                        type="button"
+                        style=button_styles
+                        disabled=move || disabled.get()
+                        on:click=move |_| jump()
+                    >
+                        "Go"
+                    </button>
+                }
+            })}
         </nav>
     }
 }
@@ -348,6 +456,28 @@ pub fn Pagination(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_page_count() {
+        assert_eq!(page_count(100, 10), 10);
+        assert_eq!(page_count(101, 10), 11);
+        assert_eq!(page_count(1, 10), 1);
+        assert_eq!(page_count(0, 10), 0);
+        assert_eq!(page_count(50, 0), 0);
+    }
+
+    #[test]
+    fn test_jump_page_clamps() {
+        assert_eq!(parse_jump_page("3", 10), Some(3));
+        assert_eq!(parse_jump_page(" 10 ", 10), Some(10));
+        assert_eq!(parse_jump_page("42", 10), Some(10));
+        assert_eq!(parse_jump_page("0", 10), Some(1));
+        assert_eq!(parse_jump_page("abc", 10), None);
+        assert_eq!(parse_jump_page("-2", 10), None);
+        assert_eq!(parse_jump_page("", 10), None);
+        // No pages: nothing to jump to
+        assert_eq!(parse_jump_page("1", 0), None);
+    }
+
     #[test]
     fn test_pagination_size_default() {
         assert_eq!(PaginationSize::default(), PaginationSize::Md);
@@ -1,4 +1,4 @@
-use crate::theme::use_theme;
+use crate::theme::{use_reduced_motion, use_theme};
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
 
@@ -41,12 +41,17 @@ pub fn Paper(
     #[prop(optional, into)] radius: Option<String>,
     #[prop(optional)] with_border: bool,
     #[prop(optional, into)] shadow: Option<String>,
+    /// Shadow to transition to on hover, e.g. `"md"`
+    #[prop(optional, into)]
+    hover_shadow: Option<String>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
     let padding = padding.unwrap_or(PaperPadding::Md);
+    let hovered = RwSignal::new(false);
+    let reduced_motion = hover_shadow.is_some().then(use_reduced_motion);
 
     let paper_styles = move || {
         let theme_val = theme.get();
@@ -70,8 +75,16 @@ pub fn Paper(
             );
         }
 
-        if let Some(s) = shadow.as_ref() {
-            builder.add("box-shadow", s);
+        let shadow = match hover_shadow.as_ref() {
+            Some(h) if hovered.get() => Some(h),
+            _ => shadow.as_ref(),
+        };
+        if let Some(s) = shadow {
+            builder.add("box-shadow", theme_val.shadows.resolve(s));
+        }
+
+        if reduced_motion.is_some_and(|r| !r.get()) {
+            builder.add("transition", "box-shadow 0.15s ease");
         }
 
         if let Some(s) = style.as_ref() {
@@ -84,7 +97,12 @@ pub fn Paper(
     let class_str = format!("mingot-paper {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=paper_styles>
+        <div
+            class=class_str
+            style=paper_styles
+            on:mouseenter=move |_| hovered.set(true)
+            on:mouseleave=move |_| hovered.set(false)
+        >
             {children()}
         </div>
     }
@@ -1,3 +1,4 @@
+use crate::components::formula_input::{parse_expression, Expression, FormulaParseError};
 use crate::components::number_input::{NumberInputPrecision, ParseError};
 use crate::components::parameter_slider::{ParameterSliderScale, ParameterSliderSize};
 use crate::theme::use_theme;
@@ -33,6 +34,8 @@ pub struct ParameterDef {
     pub read_only: bool,
     /// Display precision (decimal places)
     pub display_precision: usize,
+    /// Formula over other parameter ids that computes this parameter
+    pub formula: Option<String>,
 }
 
 impl ParameterDef {
@@ -51,6 +54,7 @@ impl ParameterDef {
             group: None,
             read_only: false,
             display_precision: 2,
+            formula: None,
         }
     }
 
@@ -109,12 +113,120 @@ impl ParameterDef {
         self
     }
 
+    /// Compute this parameter from a formula over other parameter ids,
+    /// e.g. `"c / frequency"`. Computed parameters are read-only.
+    pub fn formula(mut self, formula: impl Into<String>) -> Self {
+        self.formula = Some(formula.into());
+        self.read_only = true;
+        self
+    }
+
+    /// Whether this parameter's value comes from a formula
+    pub fn is_computed(&self) -> bool {
+        self.formula.is_some()
+    }
+
     /// Get the effective current value
     pub fn current_value(&self) -> &str {
         self.value.as_ref().unwrap_or(&self.default)
     }
 }
 
+/// Error computing a formula-driven parameter
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParameterFormulaError {
+    /// The formula of parameter `id` failed to parse
+    Parse {
+        id: String,
+        error: FormulaParseError,
+    },
+    /// Computed parameters depend on each other in a cycle
+    Cycle(Vec<String>),
+    /// The formula of parameter `id` could not be evaluated
+    Evaluate { id: String, message: String },
+}
+
+impl std::fmt::Display for ParameterFormulaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse { id, error } => write!(f, "Formula for '{}': {}", id, error),
+            Self::Cycle(ids) => write!(f, "Cyclic formula dependency: {}", ids.join(" → ")),
+            Self::Evaluate { id, message } => write!(f, "Formula for '{}': {}", id, message),
+        }
+    }
+}
+
+/// Evaluate every computed parameter against `values`, returning `values`
+/// with the computed entries filled in. Formulas are evaluated in
+/// dependency order, so computed parameters may reference each other.
+pub fn evaluate_parameter_formulas(
+    parameters: &[ParameterDef],
+    values: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, ParameterFormulaError> {
+    let mut formulas: HashMap<&str, Expression> = HashMap::new();
+    for param in parameters {
+        if let Some(formula) = param.formula.as_deref() {
+            let expr = parse_expression(formula).map_err(|error| ParameterFormulaError::Parse {
+                id: param.id.clone(),
+                error,
+            })?;
+            formulas.insert(&param.id, expr);
+        }
+    }
+
+    // Depth-first topological sort over computed parameters
+    fn visit<'a>(
+        id: &'a str,
+        formulas: &'a HashMap<&'a str, Expression>,
+        path: &mut Vec<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> Result<(), ParameterFormulaError> {
+        if order.contains(&id) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|p| *p == id) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+            cycle.push(id.to_string());
+            return Err(ParameterFormulaError::Cycle(cycle));
+        }
+        path.push(id);
+        let mut deps: Vec<String> = formulas[id].variables().into_iter().collect();
+        deps.sort();
+        for dep in deps {
+            if let Some((dep_id, _)) = formulas.get_key_value(dep.as_str()) {
+                visit(dep_id, formulas, path, order)?;
+            }
+        }
+        path.pop();
+        order.push(id);
+        Ok(())
+    }
+
+    let mut order = Vec::new();
+    for param in parameters.iter().filter(|p| p.is_computed()) {
+        visit(&param.id, &formulas, &mut Vec::new(), &mut order)?;
+    }
+
+    let mut numeric: HashMap<String, f64> = values
+        .iter()
+        .filter_map(|(id, v)| v.trim().parse::<f64>().ok().map(|n| (id.clone(), n)))
+        .collect();
+    let mut resolved = values.clone();
+    for id in order {
+        let result =
+            formulas[id]
+                .evaluate(&numeric)
+                .map_err(|message| ParameterFormulaError::Evaluate {
+                    id: id.to_string(),
+                    message,
+                })?;
+        numeric.insert(id.to_string(), result);
+        resolved.insert(id.to_string(), result.to_string());
+    }
+
+    Ok(resolved)
+}
+
 /// Preset configuration for saving/loading parameter states
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParameterPreset {
@@ -171,6 +283,7 @@ pub enum ParameterGridLayout {
 /// - Preset save/load functionality
 /// - Reset to defaults
 /// - Linked parameter updates
+/// - Computed parameters driven by a formula over other parameters
 ///
 /// # Example
 /// ```rust,ignore
@@ -191,6 +304,9 @@ pub enum ParameterGridLayout {
 ///         .range("0", "6.283")
 ///         .step("0.01")
 ///         .default("0"),
+///     ParameterDef::new("period", "Period")
+///         .range("0.01", "10")
+///         .formula("1 / frequency"),
 /// ];
 ///
 /// view! {
@@ -240,6 +356,10 @@ pub fn ParameterGrid(
     /// Callback for validation results
     #[prop(optional)]
     on_validate: Option<Callback<(String, Result<String, ParseError>)>>,
+    /// Callback when a computed parameter's formula fails to parse,
+    /// evaluate, or forms a dependency cycle
+    #[prop(optional)]
+    on_formula_error: Option<Callback<ParameterFormulaError>>,
     /// Callback when preset is selected
     #[prop(optional)]
     on_preset_select: Option<Callback<String>>,
@@ -280,6 +400,18 @@ pub fn ParameterGrid(
         values.set(new_values);
     });
 
+    // Values with computed parameters filled in from their formulas
+    let resolved = Memo::new(move |_| {
+        parameters.with(|params| values.with(|v| evaluate_parameter_formulas(params, v)))
+    });
+    let current_values = move || resolved.get().unwrap_or_else(|_| values.get());
+
+    Effect::new(move |_| {
+        if let (Some(callback), Err(error)) = (on_formula_error, resolved.get()) {
+            callback.run(error);
+        }
+    });
+
     // Handle individual parameter change
     let handle_param_change = move |id: String, value: String| {
         values.update(|v| {
@@ -291,7 +423,7 @@ pub fn ParameterGrid(
         }
 
         if let Some(callback) = on_change {
-            callback.run(values.get());
+            callback.run(current_values());
         }
     };
 
@@ -302,10 +434,10 @@ pub fn ParameterGrid(
         for param in params {
             new_values.insert(param.id.clone(), param.default.clone());
         }
-        values.set(new_values.clone());
+        values.set(new_values);
 
         if let Some(callback) = on_change {
-            callback.run(new_values);
+            callback.run(current_values());
         }
 
         if let Some(callback) = on_reset {
@@ -326,7 +458,7 @@ pub fn ParameterGrid(
         }
 
         if let Some(callback) = on_change {
-            callback.run(values.get());
+            callback.run(current_values());
         }
     };
 
@@ -481,6 +613,18 @@ pub fn ParameterGrid(
         )
     };
 
+    let formula_error_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+
+        format!(
+            "font-size: 0.75rem; color: {};",
+            scheme_colors
+                .get_color("red", 6)
+                .unwrap_or_else(|| "#fa5252".to_string())
+        )
+    };
+
     let chevron_styles = move |is_collapsed: bool| {
         format!(
             "transform: rotate({}deg); transition: transform 0.2s ease;",
@@ -545,6 +689,14 @@ pub fn ParameterGrid(
                 }
             })}
 
+            {move || resolved.get().err().map(|error| {
+                view! {
+                    <div class="mingot-parameter-grid-error" style=formula_error_styles role="alert">
+                        {error.to_string()}
+                    </div>
+                }
+            })}
+
             // Parameter groups
             <div>
                 {move || {
@@ -580,7 +732,7 @@ pub fn ParameterGrid(
                                                 let param_id_for_validate = param.id.clone();
 
                                                 let param_value = Memo::new(move |_| {
-                                                    values.get().get(&param_id).cloned().unwrap_or_default()
+                                                    current_values().get(&param_id).cloned().unwrap_or_default()
                                                 });
 
                                                 let on_param_change = {
@@ -682,6 +834,80 @@ mod tests {
         assert!(param.read_only);
     }
 
+    #[test]
+    fn test_parameter_def_formula_is_read_only() {
+        let param = ParameterDef::new("wavelength", "Wavelength").formula("c / frequency");
+        assert!(param.is_computed());
+        assert!(param.read_only);
+        assert_eq!(param.formula.as_deref(), Some("c / frequency"));
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_parameter_formulas() {
+        let params = vec![
+            ParameterDef::new("c", "Speed"),
+            ParameterDef::new("frequency", "Frequency"),
+            ParameterDef::new("wavelength", "Wavelength").formula("c / frequency"),
+        ];
+        let resolved =
+            evaluate_parameter_formulas(&params, &values(&[("c", "300"), ("frequency", "4")]))
+                .unwrap();
+        assert_eq!(resolved.get("wavelength"), Some(&"75".to_string()));
+        assert_eq!(resolved.get("c"), Some(&"300".to_string()));
+    }
+
+    #[test]
+    fn test_evaluate_parameter_formulas_chained() {
+        // Declared before its dependency; evaluation follows dependency order
+        let params = vec![
+            ParameterDef::new("double_area", "2A").formula("2 * area"),
+            ParameterDef::new("area", "Area").formula("r ^ 2"),
+            ParameterDef::new("r", "Radius"),
+        ];
+        let resolved = evaluate_parameter_formulas(&params, &values(&[("r", "3")])).unwrap();
+        assert_eq!(resolved.get("area"), Some(&"9".to_string()));
+        assert_eq!(resolved.get("double_area"), Some(&"18".to_string()));
+    }
+
+    #[test]
+    fn test_evaluate_parameter_formulas_cycle() {
+        let params = vec![
+            ParameterDef::new("a", "A").formula("b + 1"),
+            ParameterDef::new("b", "B").formula("a * 2"),
+        ];
+        let result = evaluate_parameter_formulas(&params, &HashMap::new());
+        assert_eq!(
+            result,
+            Err(ParameterFormulaError::Cycle(vec![
+                "a".to_string(),
+                "b".to_string(),
+                "a".to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_evaluate_parameter_formulas_errors() {
+        let params = vec![ParameterDef::new("x", "X").formula("2 +")];
+        assert!(matches!(
+            evaluate_parameter_formulas(&params, &HashMap::new()),
+            Err(ParameterFormulaError::Parse { ref id, .. }) if id == "x"
+        ));
+
+        let params = vec![ParameterDef::new("x", "X").formula("missing * 2")];
+        assert!(matches!(
+            evaluate_parameter_formulas(&params, &HashMap::new()),
+            Err(ParameterFormulaError::Evaluate { ref id, .. }) if id == "x"
+        ));
+    }
+
     #[test]
     fn test_parameter_preset_new() {
         let preset = ParameterPreset::new("Default");
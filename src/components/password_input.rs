@@ -1,5 +1,5 @@
 use crate::components::input::{InputSize, InputVariant};
-use crate::theme::use_theme;
+use crate::theme::{use_mingot_config, use_theme};
 use crate::utils::StyleBuilder;
 use leptos::ev;
 use leptos::prelude::*;
@@ -69,8 +69,11 @@ pub fn PasswordInput(
     toggle_visibility: Option<bool>,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(InputVariant::Default);
-    let size = size.unwrap_or(InputSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.input_variant)
+        .unwrap_or(InputVariant::Default);
+    let size = size.or(config.input_size).unwrap_or(InputSize::Md);
     let toggle_visibility = toggle_visibility.unwrap_or(true);
 
     // State for password visibility
@@ -17,6 +17,66 @@ pub enum PinInputType {
     Text,
 }
 
+impl PinInputType {
+    /// Whether a cell of this type takes `c`
+    pub fn accepts(self, c: char) -> bool {
+        match self {
+            PinInputType::Number => c.is_ascii_digit(),
+            PinInputType::Alphanumeric => c.is_ascii_alphanumeric(),
+            PinInputType::Text => !c.is_control(),
+        }
+    }
+}
+
+/// Cells after pasting `text` into cell `start`, and the cell to focus.
+///
+/// Characters the type doesn't accept are dropped; the rest fill cells
+/// from `start` on, or from the first cell when they make up a whole
+/// code. Focus goes to the next empty cell after the pasted ones (the
+/// last cell when there is none).
+pub fn distribute_paste(
+    cells: &[String],
+    start: usize,
+    text: &str,
+    input_type: PinInputType,
+) -> (Vec<String>, usize) {
+    let mut cells = cells.to_vec();
+    let length = cells.len();
+    if length == 0 {
+        return (cells, 0);
+    }
+    let chars: Vec<char> = text.chars().filter(|&c| input_type.accepts(c)).collect();
+    let start = if chars.len() >= length {
+        0
+    } else {
+        start.min(length - 1)
+    };
+    let filled = chars.len().min(length - start);
+    for (cell, c) in cells[start..].iter_mut().zip(chars) {
+        *cell = c.to_string();
+    }
+    let after = start + filled;
+    let focus = (after..length)
+        .find(|&i| cells[i].is_empty())
+        .unwrap_or(length - 1);
+    (cells, focus)
+}
+
+/// What Backspace in cell `index` does: `(cell to clear, cell to focus)`.
+/// A filled cell is cleared in place; an empty one moves back and clears
+/// the previous cell. `None` in an empty first cell.
+pub fn backspace_target(cells: &[String], index: usize) -> Option<(usize, usize)> {
+    match cells.get(index) {
+        Some(cell) if !cell.is_empty() => Some((index, index)),
+        Some(_) if index > 0 => Some((index - 1, index - 1)),
+        _ => None,
+    }
+}
+
+fn cells_complete(cells: &[String]) -> bool {
+    !cells.is_empty() && cells.iter().all(|c| !c.is_empty())
+}
+
 /// A PIN input component for entering verification codes, OTPs, etc.
 ///
 /// # Example
@@ -68,7 +128,7 @@ pub fn PinInput(
     /// Callback when a single character changes
     #[prop(optional)]
     on_change: Option<Callback<String>>,
-    /// Callback when all fields are filled
+    /// Callback when the last empty field is filled
     #[prop(optional)]
     on_complete: Option<Callback<String>>,
     /// Additional CSS class
@@ -196,36 +256,35 @@ pub fn PinInput(
         builder.build()
     };
 
-    let is_valid_char = move |c: char| -> bool {
-        match input_type {
-            PinInputType::Number => c.is_ascii_digit(),
-            PinInputType::Alphanumeric => c.is_ascii_alphanumeric(),
-            PinInputType::Text => true,
+    let set_cells = move |cells: Vec<String>| {
+        let was_complete = input_values.with_untracked(|v| cells_complete(v));
+        if input_values.with_untracked(|v| *v == cells) {
+            return;
         }
-    };
-
-    let update_value = move |index: usize, new_char: String| {
-        input_values.update(|values| {
-            if index < values.len() {
-                values[index] = new_char;
-            }
-        });
-
-        // Build combined value
-        let combined: String = input_values.get().iter().map(|s| s.as_str()).collect();
+        let now_complete = cells_complete(&cells);
+        let combined: String = cells.concat();
+        input_values.set(cells);
 
         if let Some(callback) = on_change {
             callback.run(combined.clone());
         }
 
-        // Check if complete
-        if combined.len() == length && input_values.get().iter().all(|s| !s.is_empty()) {
+        // Only when the code becomes complete, not on every later edit
+        if now_complete && !was_complete {
             if let Some(callback) = on_complete {
                 callback.run(combined);
             }
         }
     };
 
+    let update_value = move |index: usize, new_char: String| {
+        let mut cells = input_values.get_untracked();
+        if index < cells.len() {
+            cells[index] = new_char;
+        }
+        set_cells(cells);
+    };
+
     let focus_input = move |index: usize| {
         if index < input_refs_clone.len() {
             if let Some(input) = input_refs_clone[index].get() {
@@ -264,6 +323,7 @@ pub fn PinInput(
                     let input_styles = make_input_styles.clone();
                     let focus_input_for_input = focus_input.clone();
                     let focus_input_for_keydown = focus_input.clone();
+                    let focus_input_for_paste = focus_input.clone();
 
                     let handle_input = move |ev: ev::Event| {
                         if disabled.get() {
@@ -277,33 +337,27 @@ pub fn PinInput(
 
                         let input_value = input.value();
 
-                        // Handle paste of multiple characters
-                        if input_value.len() > 1 {
-                            let chars: Vec<char> = input_value
-                                .chars()
-                                .filter(|&c| is_valid_char(c))
-                                .take(length - index)
-                                .collect();
-
-                            for (i, c) in chars.iter().enumerate() {
-                                update_value(index + i, c.to_string());
-                            }
-
-                            let next_index = (index + chars.len()).min(length - 1);
-                            focus_input_for_input(next_index);
+                        // Several characters at once (e.g. one-time-code autofill)
+                        if input_value.chars().count() > 1 {
+                            let (cells, focus) = input_values.with_untracked(|v| {
+                                distribute_paste(v, index, &input_value, input_type)
+                            });
+                            input.set_value(&cells[index]);
+                            set_cells(cells);
+                            focus_input_for_input(focus);
                             return;
                         }
 
                         // Single character input
                         if let Some(c) = input_value.chars().last() {
-                            if is_valid_char(c) {
+                            if input_type.accepts(c) {
                                 update_value(index, c.to_string());
                                 if index < length - 1 {
                                     focus_input_for_input(index + 1);
                                 }
                             } else {
-                                // Clear invalid input
-                                input.set_value(&input_values.get()[index]);
+                                // Reject the character, keeping what the cell had
+                                input.set_value(&input_values.get_untracked()[index]);
                             }
                         } else {
                             // Input was cleared
@@ -320,30 +374,42 @@ pub fn PinInput(
 
                         match key.as_str() {
                             "Backspace" => {
-                                let current_value = &input_values.get()[index];
-                                if current_value.is_empty() && index > 0 {
-                                    // Move to previous input and clear it
-                                    update_value(index - 1, String::new());
-                                    focus_input_for_keydown(index - 1);
-                                } else {
-                                    // Clear current input
-                                    update_value(index, String::new());
+                                ev.prevent_default();
+                                let target = input_values.with_untracked(|v| backspace_target(v, index));
+                                if let Some((clear, focus)) = target {
+                                    update_value(clear, String::new());
+                                    if focus != index {
+                                        focus_input_for_keydown(focus);
+                                    }
                                 }
                             }
-                            "ArrowLeft" => {
-                                if index > 0 {
-                                    focus_input_for_keydown(index - 1);
-                                }
+                            "ArrowLeft" if index > 0 => {
+                                focus_input_for_keydown(index - 1);
                             }
-                            "ArrowRight" => {
-                                if index < length - 1 {
-                                    focus_input_for_keydown(index + 1);
-                                }
+                            "ArrowRight" if index < length - 1 => {
+                                focus_input_for_keydown(index + 1);
                             }
                             _ => {}
                         }
                     };
 
+                    // maxlength would cut a pasted code to one character,
+                    // so spread it over the cells here instead
+                    let handle_paste = move |ev: ev::ClipboardEvent| {
+                        ev.prevent_default();
+                        if disabled.get() {
+                            return;
+                        }
+                        let Some(text) = ev.clipboard_data().and_then(|d| d.get_data("text").ok())
+                        else {
+                            return;
+                        };
+                        let (cells, focus) = input_values
+                            .with_untracked(|v| distribute_paste(v, index, &text, input_type));
+                        set_cells(cells);
+                        focus_input_for_paste(focus);
+                    };
+
                     let handle_focus = move |ev: ev::FocusEvent| {
                         let target = ev.target();
                         if let Some(input) = target.and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) {
@@ -375,6 +441,7 @@ pub fn PinInput(
                             prop:value=display_value
                             on:input=handle_input
                             on:keydown=handle_keydown
+                            on:paste=handle_paste
                             on:focus=handle_focus
                         />
                     }
@@ -392,6 +459,84 @@ pub fn PinInput(
 mod tests {
     use super::*;
 
+    fn cells(s: &[&str]) -> Vec<String> {
+        s.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_paste_fills_all_cells() {
+        let empty = cells(&["", "", "", "", "", ""]);
+        let (filled, focus) = distribute_paste(&empty, 0, "123456", PinInputType::Number);
+        assert_eq!(filled, cells(&["1", "2", "3", "4", "5", "6"]));
+        assert_eq!(focus, 5);
+
+        // A whole code pasted into a later cell still starts at the first
+        let (filled, _) = distribute_paste(&empty, 3, "12-34 56", PinInputType::Number);
+        assert_eq!(filled, cells(&["1", "2", "3", "4", "5", "6"]));
+
+        // Extra characters are ignored
+        let (filled, _) = distribute_paste(&empty, 0, "12345678", PinInputType::Number);
+        assert_eq!(filled.concat(), "123456");
+    }
+
+    #[test]
+    fn test_paste_partial_from_cell() {
+        let start = cells(&["9", "", "", "", "", ""]);
+        let (filled, focus) = distribute_paste(&start, 1, "12", PinInputType::Number);
+        assert_eq!(filled, cells(&["9", "1", "2", "", "", ""]));
+        assert_eq!(focus, 3);
+
+        // Focus skips ahead to the next empty cell
+        let start = cells(&["", "", "", "7", "", ""]);
+        let (_, focus) = distribute_paste(&start, 0, "123", PinInputType::Number);
+        assert_eq!(focus, 4);
+    }
+
+    #[test]
+    fn test_paste_filters_by_type() {
+        let empty = cells(&["", "", "", ""]);
+        let (filled, focus) = distribute_paste(&empty, 0, "a1b2", PinInputType::Number);
+        assert_eq!(filled, cells(&["1", "2", "", ""]));
+        assert_eq!(focus, 2);
+
+        let (filled, _) = distribute_paste(&empty, 0, "a-1_B", PinInputType::Alphanumeric);
+        assert_eq!(filled, cells(&["a", "1", "B", ""]));
+
+        // Nothing usable: unchanged
+        let (filled, focus) = distribute_paste(&empty, 1, "abc", PinInputType::Number);
+        assert_eq!(filled, empty);
+        assert_eq!(focus, 1);
+    }
+
+    #[test]
+    fn test_backspace_rules() {
+        let state = cells(&["1", "2", "", ""]);
+        // Filled cell: clear it and stay
+        assert_eq!(backspace_target(&state, 1), Some((1, 1)));
+        // Empty cell: step back and clear the previous one
+        assert_eq!(backspace_target(&state, 2), Some((1, 1)));
+        // Empty first cell: nothing to do
+        assert_eq!(backspace_target(&cells(&["", ""]), 0), None);
+        assert_eq!(backspace_target(&state, 0), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_input_type_accepts() {
+        assert!(PinInputType::Number.accepts('7'));
+        assert!(!PinInputType::Number.accepts('a'));
+        assert!(PinInputType::Alphanumeric.accepts('Z'));
+        assert!(!PinInputType::Alphanumeric.accepts('-'));
+        assert!(PinInputType::Text.accepts('-'));
+        assert!(!PinInputType::Text.accepts('\n'));
+    }
+
+    #[test]
+    fn test_cells_complete() {
+        assert!(cells_complete(&cells(&["1", "2"])));
+        assert!(!cells_complete(&cells(&["1", ""])));
+        assert!(!cells_complete(&[]));
+    }
+
     #[test]
     fn test_pin_input_type_default() {
         assert_eq!(PinInputType::default(), PinInputType::Number);
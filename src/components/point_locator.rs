@@ -1,7 +1,7 @@
 //! Point locator component for visual point positioning.
 //!
-//! Mathematica-style drag-and-drop point positioning with grid snapping
-//! and precision coordinate display.
+//! Mathematica-style drag-and-drop point positioning with grid or polar
+//! snapping and precision coordinate display.
 
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
@@ -42,6 +42,26 @@ impl Point2D {
         }
     }
 
+    /// Snap to the nearest ray (multiple of the angle step) and, optionally,
+    /// the nearest ring (multiple of the radius step) around the origin
+    pub fn snap_to_polar(&self, snap: &PolarSnap) -> Self {
+        let mut radius = self.magnitude();
+        if let Some(step) = snap.radius_step.filter(|s| *s > 0.0) {
+            radius = (radius / step).round() * step;
+        }
+
+        let mut angle = self.y.atan2(self.x).to_degrees();
+        if snap.angle_step > 0.0 {
+            angle = (angle / snap.angle_step).round() * snap.angle_step;
+        }
+
+        let radians = angle.to_radians();
+        Self {
+            x: radius * radians.cos(),
+            y: radius * radians.sin(),
+        }
+    }
+
     /// Clamp to bounds
     pub fn clamp(&self, min_x: f64, max_x: f64, min_y: f64, max_y: f64) -> Self {
         Self {
@@ -51,6 +71,86 @@ impl Point2D {
     }
 }
 
+/// Polar snapping configuration for the point locator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolarSnap {
+    /// Angle step in degrees
+    pub angle_step: f64,
+    /// Radius step in data units (None snaps the angle only)
+    pub radius_step: Option<f64>,
+}
+
+impl Default for PolarSnap {
+    fn default() -> Self {
+        Self {
+            angle_step: 15.0,
+            radius_step: None,
+        }
+    }
+}
+
+impl PolarSnap {
+    /// Create a polar snap that only snaps the angle
+    pub fn new(angle_step: f64) -> Self {
+        Self {
+            angle_step,
+            radius_step: None,
+        }
+    }
+
+    /// Also snap the radius to multiples of `step`
+    pub fn with_radius_step(mut self, step: f64) -> Self {
+        self.radius_step = Some(step);
+        self
+    }
+}
+
+/// Most rings, and most rays, the polar grid draws
+const MAX_POLAR_GRID_LINES: usize = 360;
+
+/// Evenly spaced values `step, 2*step, ...` within `0..=limit` (or
+/// `0..limit` starting at 0 when `from_zero`). The step is widened to a
+/// whole multiple of itself when it would produce more than
+/// [`MAX_POLAR_GRID_LINES`] values; a step that isn't positive and finite
+/// uses `fallback`.
+fn grid_steps(limit: f64, step: f64, fallback: f64, from_zero: bool) -> Vec<f64> {
+    if !limit.is_finite() || limit <= 0.0 {
+        return Vec::new();
+    }
+    let mut step = if step.is_finite() && step > 0.0 {
+        step
+    } else {
+        fallback
+    };
+    let count = |step: f64| {
+        if from_zero {
+            (limit / step).ceil()
+        } else {
+            (limit / step).floor()
+        }
+    };
+    let max = MAX_POLAR_GRID_LINES as f64;
+    if count(step) > max {
+        step *= (count(step) / max).ceil();
+    }
+    let count = count(step).min(max) as usize;
+    let first = if from_zero { 0 } else { 1 };
+    (first..first + count)
+        .map(|i| i as f64 * step)
+        .filter(|&v| if from_zero { v < limit } else { v <= limit })
+        .collect()
+}
+
+/// Radii of the polar grid rings out to `max_radius`
+fn polar_grid_radii(max_radius: f64, step: f64) -> Vec<f64> {
+    grid_steps(max_radius, step, 1.0, false)
+}
+
+/// Angles in degrees of the polar grid rays around the full circle
+fn polar_grid_angles(step: f64) -> Vec<f64> {
+    grid_steps(360.0, step, 15.0, true)
+}
+
 /// Bounds for the point locator
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Bounds {
@@ -139,6 +239,14 @@ pub fn PointLocator(
     #[prop(optional, into)]
     snap_to_grid: Option<f64>,
 
+    /// Polar snapping to rays and rings around the origin (takes precedence over grid snapping)
+    #[prop(optional, into)]
+    snap_polar: Option<PolarSnap>,
+
+    /// Show a polar grid (rays and rings) instead of the rectangular grid
+    #[prop(optional)]
+    show_polar_grid: bool,
+
     /// Canvas width in pixels
     #[prop(optional, default = 300)]
     width: u32,
@@ -214,18 +322,24 @@ pub fn PointLocator(
         (canvas_x, canvas_y)
     };
 
+    // Apply polar or grid snapping, whichever is configured
+    let snap_point = move |point: Point2D| -> Point2D {
+        if let Some(polar) = snap_polar {
+            point.snap_to_polar(&polar)
+        } else if let Some(grid) = snap_to_grid {
+            point.snap_to_grid(grid)
+        } else {
+            point
+        }
+    };
+
     // Handle mouse/touch events
     let update_point = move |canvas_x: f64, canvas_y: f64| {
         if disabled.get() {
             return;
         }
 
-        let mut point = canvas_to_data(canvas_x, canvas_y);
-
-        // Apply grid snapping if enabled
-        if let Some(grid) = snap_to_grid {
-            point = point.snap_to_grid(grid);
-        }
+        let mut point = snap_point(canvas_to_data(canvas_x, canvas_y));
 
         // Clamp to bounds
         point = bounds.clamp(&point);
@@ -389,7 +503,7 @@ pub fn PointLocator(
                 >
                     // Grid lines
                     {move || {
-                        if !show_grid {
+                        if !show_grid || show_polar_grid {
                             return view! { <g></g> }.into_any();
                         }
 
@@ -438,6 +552,83 @@ pub fn PointLocator(
                         }.into_any()
                     }}
 
+                    // Polar grid (rings and rays)
+                    {move || {
+                        if !show_polar_grid {
+                            return view! { <g></g> }.into_any();
+                        }
+
+                        let theme_val = theme.get();
+                        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+                        let border_color = scheme_colors.border.clone();
+                        let polar = snap_polar.unwrap_or_default();
+                        let ring_step = polar.radius_step.or(snap_to_grid).unwrap_or(1.0);
+
+                        let (origin_x, origin_y) = data_to_canvas(&Point2D::new(0.0, 0.0));
+                        let scale_x = width as f64 / bounds.width();
+                        let scale_y = height as f64 / bounds.height();
+
+                        // Furthest corner from the origin bounds the rings and rays
+                        let max_radius = [
+                            Point2D::new(bounds.min_x, bounds.min_y),
+                            Point2D::new(bounds.min_x, bounds.max_y),
+                            Point2D::new(bounds.max_x, bounds.min_y),
+                            Point2D::new(bounds.max_x, bounds.max_y),
+                        ]
+                        .iter()
+                        .map(|p| p.magnitude())
+                        .fold(0.0, f64::max);
+
+                        let rings: Vec<(f64, f64)> = polar_grid_radii(max_radius, ring_step)
+                            .into_iter()
+                            .map(|r| (r * scale_x, r * scale_y))
+                            .collect();
+
+                        let rays: Vec<(f64, f64, bool)> = polar_grid_angles(polar.angle_step)
+                            .into_iter()
+                            .map(|angle| {
+                                let end = Point2D::new(
+                                    max_radius * angle.to_radians().cos(),
+                                    max_radius * angle.to_radians().sin(),
+                                );
+                                let (ex, ey) = data_to_canvas(&end);
+                                (ex, ey, angle % 90.0 == 0.0)
+                            })
+                            .collect();
+
+                        view! {
+                            <g class="polar-grid">
+                                {rings.into_iter().map(|(rx, ry)| view! {
+                                    <ellipse
+                                        cx=origin_x
+                                        cy=origin_y
+                                        rx=rx
+                                        ry=ry
+                                        fill="none"
+                                        stroke=border_color.clone()
+                                        stroke-width="0.5"
+                                        opacity="0.3"
+                                    />
+                                }).collect_view()}
+                                {rays.into_iter().map(|(ex, ey, is_axis)| {
+                                    let stroke_width = if is_axis { "1.5" } else { "0.5" };
+                                    let opacity = if is_axis { "0.6" } else { "0.3" };
+                                    view! {
+                                        <line
+                                            x1=origin_x
+                                            y1=origin_y
+                                            x2=ex
+                                            y2=ey
+                                            stroke=border_color.clone()
+                                            stroke-width=stroke_width
+                                            opacity=opacity
+                                        />
+                                    }
+                                }).collect_view()}
+                            </g>
+                        }.into_any()
+                    }}
+
                     // Axis labels
                     {move || {
                         if !show_labels {
@@ -567,11 +758,7 @@ pub fn PointLocator(
                 }}
                 {move || {
                     mouse_pos.get().map(|mp| {
-                        let snapped = if let Some(grid) = snap_to_grid {
-                            mp.snap_to_grid(grid)
-                        } else {
-                            mp
-                        };
+                        let snapped = snap_point(mp);
                         format!(
                             " → ({}, {})",
                             format_number(snapped.x, precision),
@@ -624,6 +811,36 @@ mod tests {
         assert_eq!(snapped_half.y, 4.5);
     }
 
+    #[test]
+    fn test_point_snap_to_polar_angle() {
+        // A drag near 44° with a 15° snap lands on 45°
+        let radians = 44.0_f64.to_radians();
+        let p = Point2D::new(5.0 * radians.cos(), 5.0 * radians.sin());
+        let snapped = p.snap_to_polar(&PolarSnap::new(15.0));
+
+        let angle = snapped.y.atan2(snapped.x).to_degrees();
+        assert!((angle - 45.0).abs() < 1e-9);
+        assert!((snapped.magnitude() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_point_snap_to_polar_radius() {
+        let radians = 100.0_f64.to_radians();
+        let p = Point2D::new(2.7 * radians.cos(), 2.7 * radians.sin());
+        let snapped = p.snap_to_polar(&PolarSnap::new(30.0).with_radius_step(0.5));
+
+        let angle = snapped.y.atan2(snapped.x).to_degrees();
+        assert!((angle - 90.0).abs() < 1e-9);
+        assert!((snapped.magnitude() - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_polar_snap_default() {
+        let snap = PolarSnap::default();
+        assert_eq!(snap.angle_step, 15.0);
+        assert_eq!(snap.radius_step, None);
+    }
+
     #[test]
     fn test_point_clamp() {
         let p = Point2D::new(15.0, -15.0);
@@ -666,4 +883,38 @@ mod tests {
         assert_eq!(clamped.x, 10.0);
         assert_eq!(clamped.y, -10.0);
     }
+
+    #[test]
+    fn test_polar_grid_radii() {
+        assert_eq!(polar_grid_radii(3.5, 1.0), vec![1.0, 2.0, 3.0]);
+        assert_eq!(polar_grid_radii(2.0, 1.0), vec![1.0, 2.0]);
+        assert!(polar_grid_radii(0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_polar_grid_angles() {
+        assert_eq!(polar_grid_angles(90.0), vec![0.0, 90.0, 180.0, 270.0]);
+        assert_eq!(polar_grid_angles(15.0).len(), 24);
+    }
+
+    #[test]
+    fn test_polar_grid_rejects_bad_steps() {
+        // Zero, negative and non-finite steps fall back instead of looping forever
+        for step in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(polar_grid_radii(3.0, step), vec![1.0, 2.0, 3.0]);
+            assert_eq!(polar_grid_angles(step).len(), 24);
+        }
+    }
+
+    #[test]
+    fn test_polar_grid_caps_line_count() {
+        let radii = polar_grid_radii(100.0, 1e-9);
+        assert!(!radii.is_empty() && radii.len() <= MAX_POLAR_GRID_LINES);
+        // The rings still reach the edge
+        assert!(*radii.last().unwrap() > 99.0);
+
+        let angles = polar_grid_angles(1e-9);
+        assert!(!angles.is_empty() && angles.len() <= MAX_POLAR_GRID_LINES);
+        assert!(*angles.last().unwrap() > 350.0);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::theme::use_theme;
+use crate::utils::{compute_placement, placement_candidates, FloatingPosition, Placement, Rect};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -9,10 +10,69 @@ pub enum PopoverPosition {
     Right,
 }
 
+impl From<PopoverPosition> for Placement {
+    fn from(position: PopoverPosition) -> Self {
+        match position {
+            PopoverPosition::Top => Placement::Top,
+            PopoverPosition::Bottom => Placement::Bottom,
+            PopoverPosition::Left => Placement::Left,
+            PopoverPosition::Right => Placement::Right,
+        }
+    }
+}
+
+impl From<Placement> for PopoverPosition {
+    fn from(placement: Placement) -> Self {
+        match placement {
+            Placement::Top => PopoverPosition::Top,
+            Placement::Bottom => PopoverPosition::Bottom,
+            Placement::Left => PopoverPosition::Left,
+            Placement::Right => PopoverPosition::Right,
+        }
+    }
+}
+
+/// Distance between the target and the dropdown, matching the 8px
+/// offset in the dropdown's transform
+const POPOVER_GAP: f64 = 8.0;
+
+/// Flip settings shared from `Popover` with its dropdown
+#[derive(Clone)]
+struct PopoverAutoPlacement {
+    flip: bool,
+    fallbacks: Vec<PopoverPosition>,
+}
+
+/// Side and shift for a dropdown of `width` x `height` next to `target`.
+/// `position` is tried first; with `flip` the `fallbacks` follow, or the
+/// opposite side when there are none.
+pub fn popover_placement(
+    target: Rect,
+    width: f64,
+    height: f64,
+    viewport: (f64, f64),
+    position: PopoverPosition,
+    flip: bool,
+    fallbacks: &[PopoverPosition],
+) -> FloatingPosition {
+    let fallbacks: Vec<Placement> = fallbacks.iter().map(|&p| p.into()).collect();
+    let candidates = placement_candidates(position.into(), flip, &fallbacks);
+    compute_placement(target, width, height, viewport, &candidates, POPOVER_GAP)
+}
+
+/// Dropdown anchored to a target. By default the dropdown flips to the
+/// opposite side and shifts along its edge to stay inside the viewport,
+/// re-measuring on scroll and resize while open.
 #[component]
 pub fn Popover(
     #[prop(optional)] opened: Option<RwSignal<bool>>,
     #[prop(optional)] position: Option<PopoverPosition>,
+    /// Move to another side when `position` would overflow (default `true`)
+    #[prop(optional)]
+    flip: Option<bool>,
+    /// Sides to try, in order, when flipping; defaults to the opposite side
+    #[prop(optional)]
+    fallback_positions: Option<Vec<PopoverPosition>>,
     #[prop(optional)] with_arrow: bool,
     #[prop(optional, into)] width: Option<String>,
     #[prop(optional, into)] class: Option<String>,
@@ -26,6 +86,10 @@ pub fn Popover(
     provide_context::<Signal<PopoverPosition>>(Signal::derive(move || position));
     provide_context::<Signal<bool>>(Signal::derive(move || with_arrow));
     provide_context::<Signal<Option<String>>>(Signal::derive(move || width.clone()));
+    provide_context(PopoverAutoPlacement {
+        flip: flip.unwrap_or(true),
+        fallbacks: fallback_positions.unwrap_or_default(),
+    });
 
     let wrapper_styles = "position: relative; display: inline-block;".to_string();
     let class_str = format!("mingot-popover {}", class.unwrap_or_default());
@@ -93,40 +157,109 @@ pub fn PopoverDropdown(
     let with_arrow = use_context::<Signal<bool>>().unwrap_or_else(|| Signal::derive(move || false));
     let width =
         use_context::<Signal<Option<String>>>().unwrap_or_else(|| Signal::derive(move || None));
+    let auto_placement = StoredValue::new(use_context::<PopoverAutoPlacement>().unwrap_or(
+        PopoverAutoPlacement {
+            flip: true,
+            fallbacks: Vec::new(),
+        },
+    ));
+
+    let dropdown_ref = NodeRef::<leptos::html::Div>::new();
+    // Measured placement while open; `None` uses the requested position
+    let measured = RwSignal::new(None::<FloatingPosition>);
+
+    let reposition = move || {
+        if !is_opened.get_untracked() {
+            return;
+        }
+        let Some(dropdown) = dropdown_ref.get_untracked() else {
+            return;
+        };
+        let Some(target) = dropdown.parent_element() else {
+            return;
+        };
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let viewport = (
+            window
+                .inner_width()
+                .ok()
+                .and_then(|v| v.as_f64())
+                .unwrap_or(f64::MAX),
+            window
+                .inner_height()
+                .ok()
+                .and_then(|v| v.as_f64())
+                .unwrap_or(f64::MAX),
+        );
+        let size = dropdown.get_bounding_client_rect();
+        let placed = auto_placement.with_value(|auto| {
+            popover_placement(
+                Rect::from_dom(&target.get_bounding_client_rect()),
+                size.width(),
+                size.height(),
+                viewport,
+                position.get_untracked(),
+                auto.flip,
+                &auto.fallbacks,
+            )
+        });
+        measured.set(Some(placed));
+    };
+
+    Effect::new(move |_| {
+        if is_opened.get() {
+            position.track();
+            reposition();
+        } else {
+            measured.set(None);
+        }
+    });
+
+    track_viewport_changes(reposition);
+
+    let current_position = move || {
+        measured
+            .get()
+            .map(|m| m.placement.into())
+            .unwrap_or_else(|| position.get())
+    };
 
     let dropdown_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
         let is_open = is_opened.get();
+        let shift = measured.get().map(|m| m.shift).unwrap_or(0.0);
 
-        let (top, left, bottom, right, transform) = match position.get() {
+        let (top, left, bottom, right, transform) = match current_position() {
             PopoverPosition::Top => (
                 "auto",
                 "50%",
                 "100%",
                 "auto",
-                "translateX(-50%) translateY(-8px)",
+                format!("translateX(calc(-50% + {}px)) translateY(-8px)", shift),
             ),
             PopoverPosition::Bottom => (
                 "100%",
                 "50%",
                 "auto",
                 "auto",
-                "translateX(-50%) translateY(8px)",
+                format!("translateX(calc(-50% + {}px)) translateY(8px)", shift),
             ),
             PopoverPosition::Left => (
                 "50%",
                 "auto",
                 "auto",
                 "100%",
-                "translateX(-8px) translateY(-50%)",
+                format!("translateX(-8px) translateY(calc(-50% + {}px))", shift),
             ),
             PopoverPosition::Right => (
                 "50%",
                 "100%",
                 "auto",
                 "auto",
-                "translateX(8px) translateY(-50%)",
+                format!("translateX(8px) translateY(calc(-50% + {}px))", shift),
             ),
         };
 
@@ -167,7 +300,7 @@ pub fn PopoverDropdown(
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
 
-        let (border_style, top, left, bottom, right) = match position.get() {
+        let (border_style, top, left, bottom, right) = match current_position() {
             PopoverPosition::Top => (
                 format!(
                     "5px solid {}; 5px solid transparent",
@@ -228,6 +361,7 @@ pub fn PopoverDropdown(
 
     view! {
         <div
+            node_ref=dropdown_ref
             class=class_str
             style=move || {
                 if let Some(s) = style.as_ref() {
@@ -252,3 +386,121 @@ pub fn PopoverDropdown(
         </div>
     }
 }
+
+/// Call `on_change` whenever the window is resized or anything scrolls.
+///
+/// The scroll listener is capture-phase so scrolling inside any container
+/// is seen. Listeners are removed when the owner is cleaned up.
+fn track_viewport_changes(on_change: impl Fn() + Clone + 'static) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        let on_scroll = {
+            let on_change = on_change.clone();
+            Closure::<dyn Fn(web_sys::Event)>::new(move |_: web_sys::Event| on_change())
+        };
+        let on_resize =
+            Closure::<dyn Fn(web_sys::Event)>::new(move |_: web_sys::Event| on_change());
+
+        let _ = window.add_event_listener_with_callback_and_bool(
+            "scroll",
+            on_scroll.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ =
+            window.add_event_listener_with_callback("resize", on_resize.as_ref().unchecked_ref());
+
+        let handle = StoredValue::new_local((window, on_scroll, on_resize));
+        on_cleanup(move || {
+            handle.try_with_value(|(window, on_scroll, on_resize)| {
+                let _ = window.remove_event_listener_with_callback_and_bool(
+                    "scroll",
+                    on_scroll.as_ref().unchecked_ref(),
+                    true,
+                );
+                let _ = window.remove_event_listener_with_callback(
+                    "resize",
+                    on_resize.as_ref().unchecked_ref(),
+                );
+            });
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = on_change;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (1024.0, 768.0);
+
+    #[test]
+    fn test_popover_placement_keeps_position_with_room() {
+        let target = Rect::new(400.0, 300.0, 80.0, 32.0);
+        let placed = popover_placement(
+            target,
+            260.0,
+            120.0,
+            VIEWPORT,
+            PopoverPosition::Bottom,
+            true,
+            &[],
+        );
+        assert_eq!(placed.placement, Placement::Bottom);
+        assert_eq!(placed.shift, 0.0);
+    }
+
+    #[test]
+    fn test_popover_placement_flips_and_shifts() {
+        // Bottom-left corner: no room below, dropdown wider than the
+        // space left of the target's centre
+        let target = Rect::new(0.0, 720.0, 80.0, 32.0);
+        let placed = popover_placement(
+            target,
+            260.0,
+            120.0,
+            VIEWPORT,
+            PopoverPosition::Bottom,
+            true,
+            &[],
+        );
+        assert_eq!(
+            PopoverPosition::from(placed.placement),
+            PopoverPosition::Top
+        );
+        assert_eq!(placed.shift, 98.0);
+    }
+
+    #[test]
+    fn test_popover_placement_respects_flip_and_fallbacks() {
+        let target = Rect::new(900.0, 720.0, 80.0, 32.0);
+        let fixed = popover_placement(
+            target,
+            260.0,
+            120.0,
+            VIEWPORT,
+            PopoverPosition::Right,
+            false,
+            &[],
+        );
+        assert_eq!(fixed.placement, Placement::Right);
+
+        let placed = popover_placement(
+            target,
+            260.0,
+            120.0,
+            VIEWPORT,
+            PopoverPosition::Right,
+            true,
+            &[PopoverPosition::Bottom, PopoverPosition::Top],
+        );
+        assert_eq!(placed.placement, Placement::Top);
+    }
+}
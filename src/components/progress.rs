@@ -1,4 +1,4 @@
-use crate::theme::use_theme;
+use crate::theme::{contrast_ratio, use_theme};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -22,52 +22,127 @@ impl ProgressSize {
     }
 }
 
+/// Where `Progress` draws its label and value text
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ProgressLabelPosition {
+    /// Centered over the bar; the bar grows tall enough to fit the text
+    #[default]
+    Inside,
+    /// In a row above the bar, label left and value right
+    Above,
+}
+
+/// Rounded percentage text, e.g. `"42%"`
+fn format_percent(value: f32) -> String {
+    format!("{:.0}%", value.clamp(0.0, 100.0))
+}
+
+/// Text drawn inside the bar: the label, the value, or both
+fn inside_text(label: Option<&str>, show_value: bool, value: f32) -> Option<String> {
+    match (label, show_value) {
+        (Some(l), true) => Some(format!("{} {}", l, format_percent(value))),
+        (Some(l), false) => Some(l.to_string()),
+        (None, true) => Some(format_percent(value)),
+        (None, false) => None,
+    }
+}
+
+/// Whichever of `light` and `dark` reads better on `background`
+fn readable_on<'a>(background: &str, light: &'a str, dark: &'a str) -> &'a str {
+    if contrast_ratio(background, light) >= contrast_ratio(background, dark) {
+        light
+    } else {
+        dark
+    }
+}
+
+/// ARIA state for the progressbar role: a determinate bar reports its
+/// value, an indeterminate one drops `aria-valuenow` and is marked busy
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressAria {
+    pub value_now: Option<String>,
+    pub busy: bool,
+}
+
+impl ProgressAria {
+    pub fn new(value: f32, indeterminate: bool) -> Self {
+        if indeterminate {
+            Self {
+                value_now: None,
+                busy: true,
+            }
+        } else {
+            Self {
+                value_now: Some(format!("{}", value.clamp(0.0, 100.0))),
+                busy: false,
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Progress(
-    #[prop(into)] value: Signal<f32>,
+    /// Percentage, 0-100; ignored while `indeterminate`
+    #[prop(optional, into)]
+    value: Signal<f32>,
+    /// Unknown duration: a sweeping bar instead of a fill. Can be toggled
+    /// at runtime.
+    #[prop(optional, into)]
+    indeterminate: Signal<bool>,
     #[prop(optional)] size: Option<ProgressSize>,
     #[prop(optional, into)] color: Option<String>,
     #[prop(optional)] striped: bool,
     #[prop(optional)] animate: bool,
     #[prop(optional, into)] label: Option<String>,
+    /// Show the percentage, alongside `label` if both are set
+    #[prop(optional)]
+    show_value: bool,
+    #[prop(optional)] label_position: ProgressLabelPosition,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
     let size = size.unwrap_or(ProgressSize::Md);
+    let has_inside_text =
+        label_position == ProgressLabelPosition::Inside && (label.is_some() || show_value);
+    let label = StoredValue::new(label);
+    let color = StoredValue::new(color.unwrap_or_else(|| "blue".to_string()));
+
+    let bar_color = move |theme_val: &crate::theme::Theme| {
+        let scheme_colors = crate::theme::get_scheme_colors(theme_val);
+        scheme_colors
+            .get_color(&color.get_value(), 6)
+            .unwrap_or_else(|| "#228be6".to_string())
+    };
+    let track_color = move |theme_val: &crate::theme::Theme| {
+        let scheme_colors = crate::theme::get_scheme_colors(theme_val);
+        scheme_colors
+            .get_color("gray", 1)
+            .unwrap_or_else(|| "#f1f3f5".to_string())
+    };
 
     let container_styles = move || {
         let theme_val = theme.get();
-        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
         format!(
             "position: relative; \
              width: 100%; \
              height: {}; \
+             min-height: {}; \
              background-color: {}; \
              border-radius: {}; \
              overflow: hidden;",
             size.to_height(),
-            scheme_colors
-                .get_color("gray", 1)
-                .unwrap_or_else(|| "#f1f3f5".to_string()),
+            if has_inside_text { "1.25rem" } else { "0" },
+            track_color(&theme_val),
             theme_val.radius.sm
         )
     };
 
     let bar_styles = move || {
         let theme_val = theme.get();
-        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let indeterminate = indeterminate.get();
         let progress = value.get().clamp(0.0, 100.0);
-
-        let bg_color = if let Some(ref c) = color {
-            scheme_colors
-                .get_color(c, 6)
-                .unwrap_or_else(|| "#228be6".to_string())
-        } else {
-            scheme_colors
-                .get_color("blue", 6)
-                .unwrap_or_else(|| "#228be6".to_string())
-        };
+        let bg_color = bar_color(&theme_val);
 
         let background = if striped {
             format!(
@@ -84,11 +159,18 @@ pub fn Progress(
             bg_color
         };
 
-        let animation = if animate {
-            "mingot-progress-animation 1s linear infinite"
-        } else {
-            "none"
+        let stripes =
+            (striped && animate).then_some("mingot-progress-animation 1s linear infinite");
+        let sweep =
+            indeterminate.then_some("mingot-progress-indeterminate 1.5s ease-in-out infinite");
+        let animation = match (sweep, stripes) {
+            (Some(sweep), Some(stripes)) => format!("{}, {}", sweep, stripes),
+            (Some(a), None) | (None, Some(a)) => a.to_string(),
+            (None, None) => "none".to_string(),
         };
+        // The sweep is a 40% segment; the width transition eases between
+        // it and the fill when the mode changes
+        let width = if indeterminate { 40.0 } else { progress };
 
         format!(
             "height: 100%; \
@@ -99,24 +181,67 @@ pub fn Progress(
              display: flex; \
              align-items: center; \
              justify-content: center;",
-            progress, background, animation
+            width, background, animation
         )
     };
 
+    // Centered over the whole track, so the text's background is the bar
+    // once progress passes the midpoint and the track before that
     let label_styles = move || {
         let theme_val = theme.get();
+        let background = if !indeterminate.get() && value.get() >= 50.0 {
+            bar_color(&theme_val)
+        } else {
+            track_color(&theme_val)
+        };
+        let text_color = readable_on(&background, "#ffffff", "#212529");
+        // A halo keeps the text legible over stripes
+        let halo = if text_color == "#ffffff" {
+            "rgba(0, 0, 0, 0.35)"
+        } else {
+            "rgba(255, 255, 255, 0.6)"
+        };
         format!(
-            "font-size: {}; \
+            "position: absolute; \
+             inset: 0; \
+             display: flex; \
+             align-items: center; \
+             justify-content: center; \
+             font-size: {}; \
              font-weight: {}; \
-             color: #ffffff; \
-             padding: 0 {};",
+             color: {}; \
+             text-shadow: 0 0 2px {}; \
+             padding: 0 {}; \
+             white-space: nowrap; \
+             pointer-events: none;",
             &*theme_val.typography.font_sizes.xs,
             theme_val.typography.font_weights.bold,
+            text_color,
+            halo,
             theme_val.spacing.xs
         )
     };
 
+    let above_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "display: flex; \
+             justify-content: space-between; \
+             gap: {}; \
+             margin-bottom: 0.25rem; \
+             font-size: {}; \
+             font-weight: {}; \
+             color: {};",
+            &*theme_val.spacing.sm,
+            &*theme_val.typography.font_sizes.sm,
+            theme_val.typography.font_weights.medium,
+            scheme_colors.text
+        )
+    };
+
     let class_str = format!("mingot-progress {}", class.unwrap_or_default());
+    let aria = move || ProgressAria::new(value.get(), indeterminate.get());
 
     view! {
         <>
@@ -124,10 +249,31 @@ pub fn Progress(
                 "@keyframes mingot-progress-animation {
                     0% { background-position: 0 0; }
                     100% { background-position: 40px 0; }
+                }
+                @keyframes mingot-progress-indeterminate {
+                    0% { transform: translateX(-100%); }
+                    100% { transform: translateX(250%); }
                 }"
             </style>
+            {(label_position == ProgressLabelPosition::Above && (label.with_value(Option::is_some) || show_value))
+                .then(|| view! {
+                    <div class="mingot-progress-label" style=above_styles>
+                        <span>{label.get_value().unwrap_or_default()}</span>
+                        {show_value.then(|| view! {
+                            <span>
+                                {move || (!indeterminate.get()).then(|| format_percent(value.get()))}
+                            </span>
+                        })}
+                    </div>
+                })}
             <div
                 class=class_str
+                role="progressbar"
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow=move || aria().value_now
+                aria-busy=move || aria().busy.then_some("true")
+                aria-label=label.get_value()
                 style=move || {
                     if let Some(s) = style.as_ref() {
                         format!("{}; {}", container_styles(), s)
@@ -137,12 +283,71 @@ pub fn Progress(
                 }
             >
 
-                <div class="mingot-progress-bar" style=bar_styles>
-                    {label.as_ref().map(|l| {
-                        view! { <span style=label_styles>{l.clone()}</span> }
-                    })}
-                </div>
+                <div class="mingot-progress-bar" style=bar_styles></div>
+                {has_inside_text.then(|| view! {
+                    <span class="mingot-progress-value" style=label_styles>
+                        {move || {
+                            label.with_value(|l| {
+                                inside_text(
+                                    l.as_deref(),
+                                    show_value && !indeterminate.get(),
+                                    value.get(),
+                                )
+                            })
+                        }}
+                    </span>
+                })}
             </div>
         </>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inside_text() {
+        assert_eq!(inside_text(None, true, 42.4), Some("42%".to_string()));
+        assert_eq!(
+            inside_text(Some("Uploading"), true, 99.6),
+            Some("Uploading 100%".to_string())
+        );
+        assert_eq!(
+            inside_text(Some("Done"), false, 100.0),
+            Some("Done".to_string())
+        );
+        assert_eq!(inside_text(None, false, 50.0), None);
+        assert_eq!(format_percent(120.0), "100%");
+    }
+
+    #[test]
+    fn test_aria_by_mode() {
+        assert_eq!(
+            ProgressAria::new(42.0, false),
+            ProgressAria {
+                value_now: Some("42".to_string()),
+                busy: false,
+            }
+        );
+        assert_eq!(
+            ProgressAria::new(130.0, false).value_now,
+            Some("100".to_string())
+        );
+        // Indeterminate ignores the value
+        assert_eq!(
+            ProgressAria::new(42.0, true),
+            ProgressAria {
+                value_now: None,
+                busy: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_readable_on_picks_higher_contrast() {
+        assert_eq!(readable_on("#228be6", "#ffffff", "#212529"), "#212529");
+        assert_eq!(readable_on("#1c7ed6", "#ffffff", "#212529"), "#ffffff");
+        assert_eq!(readable_on("#f1f3f5", "#ffffff", "#212529"), "#212529");
+    }
+}
@@ -198,7 +198,7 @@ pub fn Radio(
                         type="radio"
                         class=class_str
                         style=radio_styles
-                        checked=move || is_checked.get()
+                        prop:checked=move || is_checked.get()
                         disabled=disabled
                         name=name.unwrap_or_default()
                         value=value
@@ -1,3 +1,4 @@
+use crate::components::slider::{snap_to_step, value_to_percent, SliderMark};
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::ev;
@@ -14,6 +15,41 @@ pub enum RangeSliderSize {
     Xl,
 }
 
+/// Index of the thumb (0 low, 1 high) nearest to `target`. When the
+/// thumbs overlap, the one that can move towards `target` wins.
+pub fn closest_thumb(target: f64, (low, high): (f64, f64)) -> usize {
+    let low_distance = (target - low).abs();
+    let high_distance = (target - high).abs();
+    if low_distance < high_distance || (low_distance == high_distance && target <= low) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Range after moving `thumb` (0 low, 1 high) to `target`.
+///
+/// The moved thumb stops `min_range` short of the other one instead of
+/// passing it, so the result is always ordered and within `min..=max`.
+pub fn move_range_thumb(
+    (low, high): (f64, f64),
+    thumb: usize,
+    target: f64,
+    min: f64,
+    max: f64,
+    min_range: f64,
+) -> (f64, f64) {
+    let (low, high) = (low.min(high), low.max(high));
+    let min_range = min_range.clamp(0.0, max - min);
+    if thumb == 0 {
+        let low = target.clamp(min, (high - min_range).max(min));
+        (low, high.max(low + min_range).min(max))
+    } else {
+        let high = target.clamp((low + min_range).min(max), max);
+        (low.min(high - min_range).max(min), high)
+    }
+}
+
 /// A range slider component for selecting a range of values.
 ///
 /// # Example
@@ -60,6 +96,9 @@ pub fn RangeSlider(
     /// Whether to show the current values
     #[prop(default = false)]
     show_value: bool,
+    /// Marks to display on the track
+    #[prop(optional)]
+    marks: Option<Vec<SliderMark>>,
     /// Number of decimal places to display
     #[prop(default = 0)]
     precision: usize,
@@ -86,36 +125,19 @@ pub fn RangeSlider(
     let dragging_thumb = RwSignal::new(Option::<usize>::None);
 
     // Calculate percentages from values
-    let left_percentage = move || {
-        let (low, _) = value.get();
-        let range = max - min;
-        if range == 0.0 {
-            0.0
-        } else {
-            ((low - min) / range) * 100.0
-        }
-    };
+    let left_percentage = move || value_to_percent(value.get().0, min, max);
 
     let right_percentage = move || {
-        let (_, high) = value.get();
-        let range = max - min;
-        if range == 0.0 {
+        if max == min {
             100.0
         } else {
-            ((high - min) / range) * 100.0
+            value_to_percent(value.get().1, min, max)
         }
     };
 
     // Convert percentage to value, respecting step
-    let percentage_to_value = move |pct: f64| {
-        let raw_value = min + (pct / 100.0) * (max - min);
-        if step > 0.0 {
-            let steps = ((raw_value - min) / step).round();
-            (min + steps * step).clamp(min, max)
-        } else {
-            raw_value.clamp(min, max)
-        }
-    };
+    let percentage_to_value =
+        move |pct: f64| snap_to_step(min + (pct / 100.0) * (max - min), min, max, step);
 
     // Track dimensions
     let (track_height, thumb_size) = match size {
@@ -261,6 +283,13 @@ pub fn RangeSlider(
         )
     };
 
+    let mark_styles = move |mark_value: f64| {
+        format!(
+            "position: absolute; left: {}%; transform: translateX(-50%); top: 100%; margin-top: 0.5rem; font-size: 0.75rem;",
+            value_to_percent(mark_value, min, max)
+        )
+    };
+
     // Handle mouse/touch interaction
     let track_ref = NodeRef::<leptos::html::Div>::new();
 
@@ -286,34 +315,21 @@ pub fn RangeSlider(
         let pct = (relative_x / track_width * 100.0).clamp(0.0, 100.0);
         let new_value = percentage_to_value(pct);
 
-        let (current_low, current_high) = value.get();
+        let current = value.get();
 
-        // Determine which thumb to move based on dragging state or proximity
-        let thumb_idx = if let Some(idx) = dragging_thumb.get() {
+        // Move the dragged thumb, or on a track press the closest one
+        // (which then keeps being dragged)
+        let thumb_idx = dragging_thumb.get().unwrap_or_else(|| {
+            let idx = closest_thumb(new_value, current);
+            dragging_thumb.set(Some(idx));
             idx
-        } else {
-            // Choose thumb based on which is closer
-            let low_distance = (new_value - current_low).abs();
-            let high_distance = (new_value - current_high).abs();
-            if low_distance <= high_distance {
-                0
-            } else {
-                1
-            }
-        };
-
-        let (new_low, new_high) = if thumb_idx == 0 {
-            // Moving left thumb
-            let max_low = current_high - min_range;
-            (new_value.min(max_low), current_high)
-        } else {
-            // Moving right thumb
-            let min_high = current_low + min_range;
-            (current_low, new_value.max(min_high))
-        };
+        });
 
-        if let Some(callback) = on_change {
-            callback.run((new_low, new_high));
+        let new_range = move_range_thumb(current, thumb_idx, new_value, min, max, min_range);
+        if new_range != current {
+            if let Some(callback) = on_change {
+                callback.run(new_range);
+            }
         }
     };
 
@@ -412,6 +428,16 @@ pub fn RangeSlider(
                         on:mousedown=move |ev| handle_mouse_down(ev, 1)
                     ></div>
                 </div>
+
+                {marks.map(|m| view! {
+                    <div class="mingot-range-slider-marks">
+                        {m.into_iter().map(|mark| {
+                            view! {
+                                <div style=mark_styles(mark.value)>{mark.label}</div>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </div>
+                })}
             </div>
         </div>
     }
@@ -421,6 +447,67 @@ pub fn RangeSlider(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_dragging_low_past_high_stops_at_min_range() {
+        // Low thumb dragged far beyond the high one
+        assert_eq!(
+            move_range_thumb((20.0, 60.0), 0, 90.0, 0.0, 100.0, 10.0),
+            (50.0, 60.0)
+        );
+        // Exactly at the boundary is allowed
+        assert_eq!(
+            move_range_thumb((20.0, 60.0), 0, 50.0, 0.0, 100.0, 10.0),
+            (50.0, 60.0)
+        );
+        // Without a minimum range the thumbs may meet but not swap
+        assert_eq!(
+            move_range_thumb((20.0, 60.0), 0, 90.0, 0.0, 100.0, 0.0),
+            (60.0, 60.0)
+        );
+    }
+
+    #[test]
+    fn test_dragging_high_past_low_stops_at_min_range() {
+        assert_eq!(
+            move_range_thumb((40.0, 80.0), 1, 5.0, 0.0, 100.0, 10.0),
+            (40.0, 50.0)
+        );
+        assert_eq!(
+            move_range_thumb((40.0, 80.0), 1, 95.0, 0.0, 100.0, 10.0),
+            (40.0, 95.0)
+        );
+    }
+
+    #[test]
+    fn test_move_range_stays_ordered_and_in_bounds() {
+        // An inverted input is reordered
+        assert_eq!(
+            move_range_thumb((70.0, 30.0), 0, 10.0, 0.0, 100.0, 0.0),
+            (10.0, 70.0)
+        );
+        // The high thumb at the top pushes nothing past max
+        assert_eq!(
+            move_range_thumb((95.0, 100.0), 0, 100.0, 0.0, 100.0, 10.0),
+            (90.0, 100.0)
+        );
+        for target in [-20.0, 0.0, 33.0, 100.0, 150.0] {
+            for thumb in [0, 1] {
+                let (low, high) = move_range_thumb((40.0, 60.0), thumb, target, 0.0, 100.0, 5.0);
+                assert!(low <= high - 5.0, "{target} {thumb}");
+                assert!(low >= 0.0 && high <= 100.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_closest_thumb() {
+        assert_eq!(closest_thumb(10.0, (20.0, 60.0)), 0);
+        assert_eq!(closest_thumb(55.0, (20.0, 60.0)), 1);
+        // Overlapping thumbs: pick the one that can move that way
+        assert_eq!(closest_thumb(30.0, (50.0, 50.0)), 0);
+        assert_eq!(closest_thumb(70.0, (50.0, 50.0)), 1);
+    }
+
     #[test]
     fn test_range_slider_size_default() {
         assert_eq!(RangeSliderSize::default(), RangeSliderSize::Md);
@@ -1,4 +1,4 @@
-use crate::theme::use_theme;
+use crate::theme::{use_reduced_motion, use_theme};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -22,7 +22,7 @@ impl RingProgressSize {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RingProgressSection {
     pub value: f32,
     pub color: String,
@@ -44,6 +44,81 @@ impl RingProgressSection {
     }
 }
 
+/// How section arcs animate when their values change
+#[derive(Clone, Debug, PartialEq)]
+pub struct RingProgressTransition {
+    pub duration_ms: u32,
+    /// CSS timing function, e.g. `"ease-out"`
+    pub easing: String,
+}
+
+impl RingProgressTransition {
+    pub fn new(duration_ms: u32) -> Self {
+        Self {
+            duration_ms,
+            easing: "ease".to_string(),
+        }
+    }
+
+    pub fn easing(mut self, easing: impl Into<String>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// CSS `transition` for an arc. Length and offset share the timing so
+    /// neighbouring arcs stay joined while they resize.
+    fn css(&self) -> String {
+        format!(
+            "stroke-dasharray {ms}ms {e}, stroke-dashoffset {ms}ms {e}",
+            ms = self.duration_ms,
+            e = self.easing
+        )
+    }
+}
+
+/// Stroke of one section on the ring
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct RingArc {
+    /// Drawn length along the circumference
+    length: f32,
+    /// Negative distance from the ring's start, as `stroke-dashoffset`
+    offset: f32,
+}
+
+impl RingArc {
+    fn dasharray(&self, circumference: f32) -> String {
+        format!("{} {}", self.length, circumference)
+    }
+}
+
+/// Arcs for `sections` laid end to end, each starting where the previous
+/// one ends
+fn ring_arcs(sections: &[RingProgressSection], circumference: f32) -> Vec<RingArc> {
+    let mut start = 0.0;
+    sections
+        .iter()
+        .map(|section| {
+            let length = circumference * section.value / 100.0;
+            let arc = RingArc {
+                length,
+                offset: -start,
+            };
+            start += length;
+            arc
+        })
+        .collect()
+}
+
+/// Sum of all section values, used for the auto-generated center label
+fn sections_total(sections: &[RingProgressSection]) -> f64 {
+    sections.iter().map(|s| s.value as f64).sum()
+}
+
+/// Format a percentage value with a fixed number of decimal places
+fn format_percentage(value: f64, precision: usize) -> String {
+    format!("{:.*}%", precision, value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,14 +177,77 @@ mod tests {
         assert!(RingProgressSize::Md.to_px() < RingProgressSize::Lg.to_px());
         assert!(RingProgressSize::Lg.to_px() < RingProgressSize::Xl.to_px());
     }
+
+    #[test]
+    fn test_sections_total() {
+        let sections = [
+            RingProgressSection::new(30.0, "blue"),
+            RingProgressSection::new(25.0, "green"),
+            RingProgressSection::new(20.0, "orange"),
+        ];
+        assert_eq!(sections_total(&sections), 75.0);
+        assert_eq!(sections_total(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_ring_arcs_fill_circumference() {
+        let circumference = 100.0;
+        let sections = [
+            RingProgressSection::new(40.0, "blue"),
+            RingProgressSection::new(60.0, "green"),
+        ];
+        let arcs = ring_arcs(&sections, circumference);
+        assert_eq!(
+            arcs,
+            vec![
+                RingArc {
+                    length: 40.0,
+                    offset: 0.0,
+                },
+                RingArc {
+                    length: 60.0,
+                    offset: -40.0,
+                },
+            ]
+        );
+        // The second arc starts exactly where the first ends, and together
+        // they close the ring
+        assert_eq!(-arcs[1].offset, arcs[0].length);
+        assert_eq!(arcs[0].length + arcs[1].length, circumference);
+        assert_eq!(arcs[1].dasharray(circumference), "60 100");
+    }
+
+    #[test]
+    fn test_transition_css() {
+        assert_eq!(
+            RingProgressTransition::new(400).easing("ease-out").css(),
+            "stroke-dasharray 400ms ease-out, stroke-dashoffset 400ms ease-out"
+        );
+    }
+
+    #[test]
+    fn test_format_percentage() {
+        assert_eq!(format_percentage(40.0, 0), "40%");
+        assert_eq!(format_percentage(33.333, 1), "33.3%");
+        assert_eq!(format_percentage(66.666, 2), "66.67%");
+    }
 }
 
 #[component]
 pub fn RingProgress(
-    #[prop(optional)] sections: Vec<RingProgressSection>,
+    #[prop(optional, into)] sections: Signal<Vec<RingProgressSection>>,
     #[prop(optional)] size: Option<RingProgressSize>,
     #[prop(optional)] thickness: Option<u32>,
     #[prop(optional)] label: Option<Children>,
+    #[prop(optional)] show_percentage: bool,
+    #[prop(optional)] precision: Option<usize>,
+    #[prop(optional)] value_label_format: Option<Callback<f64, String>>,
+    /// Animate arcs between old and new values
+    #[prop(optional)]
+    transition: Option<RingProgressTransition>,
+    /// Skip the `transition`; follows the OS reduced-motion setting when unset
+    #[prop(optional)]
+    reduce_motion: Option<bool>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
@@ -117,6 +255,13 @@ pub fn RingProgress(
     let size = size.unwrap_or(RingProgressSize::Md);
     let size_px = size.to_px();
     let thickness = thickness.unwrap_or(8);
+    let precision = precision.unwrap_or(0);
+    let total = move || sections.with(|s| sections_total(s));
+    let reduce_motion = match reduce_motion {
+        Some(reduce) => Signal::derive(move || reduce),
+        None => use_reduced_motion(),
+    };
+    let transition = StoredValue::new(transition);
 
     let container_styles = move || {
         format!(
@@ -156,22 +301,11 @@ pub fn RingProgress(
     let radius = (size_px / 2) - (thickness / 2);
     let circumference = 2.0 * std::f32::consts::PI * radius as f32;
 
-    // Build sections with cumulative offsets
-    let mut current_offset = 0.0;
-    let ring_sections: Vec<_> = sections
-        .iter()
-        .map(|section| {
-            let stroke_dasharray = format!(
-                "{} {}",
-                (circumference * section.value / 100.0),
-                circumference
-            );
-            let stroke_dashoffset = -(current_offset * circumference / 100.0);
-            current_offset += section.value;
-
-            (section.color.clone(), stroke_dasharray, stroke_dashoffset)
-        })
-        .collect();
+    let arcs = Memo::new(move |_| sections.with(|s| ring_arcs(s, circumference)));
+    let arc_styles = move || match transition.get_value() {
+        Some(t) if !reduce_motion.get() => format!("transition: {};", t.css()),
+        _ => String::new(),
+    };
 
     let class_str = format!("mingot-ring-progress {}", class.unwrap_or_default());
 
@@ -202,29 +336,40 @@ pub fn RingProgress(
 
                     stroke-width=thickness
                 />
-                // Progress sections
-                {ring_sections
-                    .into_iter()
-                    .map(|(color, dasharray, offset)| {
-                        view! {
-                            <circle
-                                cx=size_px / 2
-                                cy=size_px / 2
-                                r=radius
-                                fill="none"
-                                stroke=color
-                                stroke-width=thickness
-                                stroke-dasharray=dasharray
-                                stroke-dashoffset=offset
-                                stroke-linecap="round"
-                            />
+                // Progress sections, keyed by position so an arc keeps its
+                // element (and can transition) when values change
+                <For each=move || 0..arcs.with(Vec::len) key=|i| *i let:i>
+                    <circle
+                        cx=size_px / 2
+                        cy=size_px / 2
+                        r=radius
+                        fill="none"
+                        stroke=move || sections.with(|s| s.get(i).map(|s| s.color.clone()))
+                        stroke-width=thickness
+                        stroke-dasharray=move || {
+                            arcs.with(|a| a.get(i).map(|a| a.dasharray(circumference)))
                         }
-                    })
-                    .collect::<Vec<_>>()}
+                        stroke-dashoffset=move || arcs.with(|a| a.get(i).map(|a| a.offset))
+                        stroke-linecap="round"
+                        style=arc_styles
+                    />
+                </For>
 
             </svg>
 
-            {label.map(|l| view! { <div style=label_styles>{l()}</div> })}
+            {match label {
+                // A custom label always takes precedence over the auto-formatted value
+                Some(l) => Some(view! { <div style=label_styles>{l()}</div> }.into_any()),
+                // The label element stays put; only its text follows the values
+                None if value_label_format.is_some() || show_percentage => {
+                    let text = move || match value_label_format {
+                        Some(format) => format.run(total()),
+                        None => format_percentage(total(), precision),
+                    };
+                    Some(view! { <div style=label_styles>{text}</div> }.into_any())
+                }
+                None => None,
+            }}
         </div>
     }
 }
@@ -1,7 +1,8 @@
-use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::theme::{use_mingot_config, use_theme};
+use crate::utils::{StyleBuilder, VirtualWindow};
 use leptos::ev;
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct SelectOption {
@@ -25,6 +26,92 @@ impl SelectOption {
     }
 }
 
+/// Options listed under a non-selectable header
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOptionGroup {
+    pub label: String,
+    pub options: Vec<SelectOption>,
+}
+
+impl SelectOptionGroup {
+    pub fn new(label: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        Self {
+            label: label.into(),
+            options,
+        }
+    }
+}
+
+/// Options accepted by `Select`: a flat list or groups with headers.
+///
+/// Both convert from a `Vec`, so `options=vec![...]` works for either.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectData {
+    Flat(Vec<SelectOption>),
+    Grouped(Vec<SelectOptionGroup>),
+}
+
+impl SelectData {
+    /// Every option in display order
+    pub fn options(&self) -> Vec<SelectOption> {
+        match self {
+            Self::Flat(options) => options.clone(),
+            Self::Grouped(groups) => groups
+                .iter()
+                .flat_map(|group| group.options.iter().cloned())
+                .collect(),
+        }
+    }
+
+    /// Group label of each option, in the order of [`options`](Self::options)
+    fn group_labels(&self) -> Vec<Option<String>> {
+        match self {
+            Self::Flat(options) => vec![None; options.len()],
+            Self::Grouped(groups) => groups
+                .iter()
+                .flat_map(|group| group.options.iter().map(|_| Some(group.label.clone())))
+                .collect(),
+        }
+    }
+}
+
+impl From<Vec<SelectOption>> for SelectData {
+    fn from(options: Vec<SelectOption>) -> Self {
+        Self::Flat(options)
+    }
+}
+
+impl From<Vec<SelectOptionGroup>> for SelectData {
+    fn from(groups: Vec<SelectOptionGroup>) -> Self {
+        Self::Grouped(groups)
+    }
+}
+
+/// A row of the custom dropdown: a group header or an option index
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum SelectRow {
+    Header(String),
+    Option(usize),
+}
+
+/// Dropdown rows for the options at `indices`, with a header before each
+/// run of options from the same group. Groups with no listed options get
+/// no header.
+fn build_rows(group_labels: &[Option<String>], indices: &[usize]) -> Vec<SelectRow> {
+    let mut rows = Vec::with_capacity(indices.len());
+    let mut current: Option<&String> = None;
+    for &index in indices {
+        if let Some(label) = group_labels.get(index).and_then(Option::as_ref) {
+            if current != Some(label) {
+                rows.push(SelectRow::Header(label.clone()));
+                current = Some(label);
+            }
+        }
+        rows.push(SelectRow::Option(index));
+    }
+    rows
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SelectVariant {
     Default,
@@ -41,6 +128,114 @@ pub enum SelectSize {
     Xl,
 }
 
+/// Index of the nearest enabled option row after (or before) `from`,
+/// skipping group headers.
+///
+/// With no current index, returns the first (or last) enabled option.
+fn next_enabled_row(
+    rows: &[SelectRow],
+    options: &[SelectOption],
+    from: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    next_selectable(
+        rows.len(),
+        from,
+        forward,
+        |i| matches!(rows[i], SelectRow::Option(index) if !options[index].disabled),
+    )
+}
+
+fn next_selectable(
+    len: usize,
+    from: Option<usize>,
+    forward: bool,
+    selectable: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if forward {
+        let start = from.map_or(0, |i| i + 1);
+        (start..len).find(|&i| selectable(i))
+    } else {
+        let end = from.unwrap_or(len).min(len);
+        (0..end).rev().find(|&i| selectable(i))
+    }
+}
+
+/// Lowercase `text` and strip common Latin diacritics, so "É" matches "e"
+pub fn fold_search_text(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !('\u{0300}'..='\u{036F}').contains(c))
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+            'ď' | 'đ' => 'd',
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+            'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+            'ł' | 'ĺ' | 'ļ' | 'ľ' => 'l',
+            'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+            'ŕ' | 'ŗ' | 'ř' => 'r',
+            'ś' | 'ŝ' | 'ş' | 'š' => 's',
+            'ţ' | 'ť' => 't',
+            'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'ź' | 'ż' | 'ž' => 'z',
+            other => other,
+        })
+        .collect()
+}
+
+/// Default option matcher: case- and accent-insensitive substring match on
+/// the label. An empty query matches everything.
+pub fn default_option_filter(option: &SelectOption, query: &str) -> bool {
+    let query = fold_search_text(query.trim());
+    query.is_empty() || fold_search_text(&option.label).contains(&query)
+}
+
+/// Indices of the options matching `query`, in their original order
+fn filter_options(
+    options: &[SelectOption],
+    query: &str,
+    filter_fn: Option<Callback<(SelectOption, String), bool>>,
+) -> Vec<usize> {
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| match filter_fn {
+            Some(filter) => filter.run(((*option).clone(), query.to_string())),
+            None => default_option_filter(option, query),
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Scroll offset that brings option `index` fully into the viewport,
+/// moving as little as possible
+fn scroll_to_reveal(
+    index: usize,
+    option_height: f64,
+    viewport_height: f64,
+    scroll_top: f64,
+) -> f64 {
+    let top = index as f64 * option_height;
+    let bottom = top + option_height;
+    if top < scroll_top {
+        top
+    } else if bottom > scroll_top + viewport_height {
+        (bottom - viewport_height).max(0.0)
+    } else {
+        scroll_top
+    }
+}
+
+/// Dropdown select.
+///
+/// By default renders a native `<select>`. With `virtualized`, renders a
+/// custom listbox that only mounts the options in view, for option lists in
+/// the thousands. With `searchable`, the custom listbox gets a filter input
+/// at the top that narrows the options as the user types.
 #[component]
 pub fn Select(
     #[prop(optional)] variant: Option<SelectVariant>,
@@ -50,18 +245,231 @@ pub fn Select(
     #[prop(optional)] disabled: bool,
     #[prop(optional, into)] error: Option<String>,
     #[prop(optional)] required: bool,
-    #[prop(into)] options: Vec<SelectOption>,
+    /// Flat `Vec<SelectOption>` or grouped `Vec<SelectOptionGroup>`
+    #[prop(into)]
+    options: SelectData,
     #[prop(optional)] on_change: Option<Callback<String>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional, into)] description: Option<String>,
+    /// Render a custom dropdown that only mounts the visible options
+    #[prop(optional)]
+    virtualized: bool,
+    /// Height of each option in pixels when virtualized (default: 34)
+    #[prop(optional)]
+    option_height: Option<f64>,
+    /// Maximum dropdown height in pixels when virtualized (default: 250)
+    #[prop(optional)]
+    max_dropdown_height: Option<f64>,
+    /// Show a filter input at the top of the dropdown
+    #[prop(optional)]
+    searchable: bool,
+    /// Custom matcher for `searchable`, called with each option and the query.
+    /// Defaults to `default_option_filter`.
+    #[prop(optional)]
+    filter_fn: Option<Callback<(SelectOption, String), bool>>,
+    /// Row shown when the filter matches nothing (default: "No results")
+    #[prop(optional, into)]
+    nothing_found: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(SelectVariant::Default);
-    let size = size.unwrap_or(SelectSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.select_variant)
+        .unwrap_or(SelectVariant::Default);
+    let size = size.or(config.select_size).unwrap_or(SelectSize::Md);
+    let option_height = option_height.unwrap_or(34.0);
+    let max_dropdown_height = max_dropdown_height.unwrap_or(250.0);
 
     let select_value = value.unwrap_or_else(|| RwSignal::new(String::new()));
+    let group_labels = StoredValue::new(options.group_labels());
+    let groups = match &options {
+        SelectData::Grouped(groups) => Some(groups.clone()),
+        SelectData::Flat(_) => None,
+    };
+    let options = StoredValue::new(options.options());
+
+    let custom_dropdown = virtualized || searchable;
+    let nothing_found = nothing_found.unwrap_or_else(|| "No results".to_string());
+
+    // Dropdown state for the custom listbox. `highlighted` is a position in
+    // the dropdown rows; selection is tracked by value, so both survive
+    // options being unmounted while scrolled out of view.
+    let opened = RwSignal::new(false);
+    let highlighted = RwSignal::new(None::<usize>);
+    let scroll_top = RwSignal::new(0.0_f64);
+    let query = RwSignal::new(String::new());
+    let listbox_ref = NodeRef::<leptos::html::Div>::new();
+    let search_ref = NodeRef::<leptos::html::Input>::new();
+    let trigger_ref = NodeRef::<leptos::html::Button>::new();
+
+    // Indices of the options matching the filter
+    let filtered =
+        Memo::new(move |_| query.with(|q| options.with_value(|o| filter_options(o, q, filter_fn))));
+    // Filtered options interleaved with the headers of their groups
+    let rows = Memo::new(move |_| filtered.with(|f| group_labels.with_value(|g| build_rows(g, f))));
+    let row_option = move |position: usize| {
+        rows.with_untracked(|r| match r.get(position) {
+            Some(SelectRow::Option(index)) => options.with_value(|o| o.get(*index).cloned()),
+            _ => None,
+        })
+    };
+    let next_row = move |from: Option<usize>, forward: bool| {
+        rows.with_untracked(|r| options.with_value(|o| next_enabled_row(r, o, from, forward)))
+    };
+
+    let virtual_window = Memo::new(move |_| {
+        let total = rows.with(Vec::len);
+        if virtualized {
+            VirtualWindow::compute(
+                total,
+                option_height,
+                max_dropdown_height,
+                scroll_top.get(),
+                5,
+            )
+        } else {
+            VirtualWindow {
+                start: 0,
+                end: total,
+                offset_top: 0.0,
+                offset_bottom: 0.0,
+            }
+        }
+    });
+
+    // Move focus into the filter input once it is mounted
+    Effect::new(move |_| {
+        if let Some(input) = search_ref.get() {
+            let _ = input.focus();
+        }
+    });
+
+    // Restore the scroll offset whenever the listbox is (re)mounted
+    Effect::new(move |_| {
+        if let Some(element) = listbox_ref.get() {
+            element.set_scroll_top(scroll_top.get_untracked() as i32);
+        }
+    });
+
+    let reveal = move |index: usize| {
+        let target = scroll_to_reveal(
+            index,
+            option_height,
+            max_dropdown_height,
+            scroll_top.get_untracked(),
+        );
+        scroll_top.set(target);
+        if let Some(element) = listbox_ref.get_untracked() {
+            element.set_scroll_top(target as i32);
+        }
+    };
+
+    let open_dropdown = move || {
+        query.set(String::new());
+        let selected = select_value.get_untracked();
+        let index = rows
+            .with_untracked(|r| {
+                options.with_value(|o| {
+                    r.iter().position(|row| {
+                        matches!(row, SelectRow::Option(i) if o[*i].value == selected && !o[*i].disabled)
+                    })
+                })
+            })
+            .or_else(|| next_row(None, true));
+        highlighted.set(index);
+        if let Some(i) = index {
+            reveal(i);
+        }
+        opened.set(true);
+    };
+
+    let close_dropdown = move || {
+        opened.set(false);
+        if searchable {
+            if let Some(trigger) = trigger_ref.get_untracked() {
+                let _ = trigger.focus();
+            }
+        }
+    };
+
+    let commit = move |position: usize| {
+        let Some(opt) = row_option(position) else {
+            return;
+        };
+        if opt.disabled {
+            return;
+        }
+        select_value.set(opt.value.clone());
+        if let Some(callback) = on_change {
+            callback.run(opt.value);
+        }
+        close_dropdown();
+    };
+
+    // Shared by the trigger and the filter input; Space types into the filter
+    let handle_keydown = move |ev: ev::KeyboardEvent, from_search: bool| {
+        if disabled {
+            return;
+        }
+
+        let is_open = opened.get_untracked();
+        let key = ev.key();
+        match key.as_str() {
+            "ArrowDown" | "ArrowUp" => {
+                ev.prevent_default();
+                if !is_open {
+                    open_dropdown();
+                    return;
+                }
+                let forward = key == "ArrowDown";
+                let next = next_row(highlighted.get_untracked(), forward);
+                if let Some(i) = next {
+                    highlighted.set(Some(i));
+                    reveal(i);
+                }
+            }
+            "Home" | "End" if is_open => {
+                ev.prevent_default();
+                let next = next_row(None, key == "Home");
+                if let Some(i) = next {
+                    highlighted.set(Some(i));
+                    reveal(i);
+                }
+            }
+            "Enter" | " " if key == "Enter" || !from_search => {
+                ev.prevent_default();
+                if !is_open {
+                    open_dropdown();
+                } else if let Some(i) = highlighted.get_untracked() {
+                    commit(i);
+                }
+            }
+            "Escape" if is_open => {
+                ev.prevent_default();
+                close_dropdown();
+            }
+            "Tab" => opened.set(false),
+            _ => {}
+        }
+    };
+
+    // Narrow the list and highlight the first enabled match
+    let handle_search_input = move |ev: ev::Event| {
+        query.set(event_target_value(&ev));
+        let first = next_row(None, true);
+        highlighted.set(first);
+        scroll_top.set(0.0);
+        if let Some(element) = listbox_ref.get_untracked() {
+            element.set_scroll_top(0);
+        }
+    };
+
+    let handle_listbox_scroll = move |ev: ev::Event| {
+        let element = event_target::<web_sys::Element>(&ev);
+        scroll_top.set(element.scroll_top() as f64);
+    };
 
     let error_clone = error.clone();
     let select_styles = move || {
@@ -209,6 +617,141 @@ pub fn Select(
         )
     };
 
+    let base_styles = select_styles.clone();
+    let trigger_styles = move || {
+        format!(
+            "{}; display: flex; align-items: center; text-align: left; \
+             white-space: nowrap; overflow: hidden; text-overflow: ellipsis;",
+            base_styles()
+        )
+    };
+
+    let dropdown_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("position", "absolute")
+            .add("top", "100%")
+            .add("left", "0")
+            .add("right", "0")
+            .add("margin-top", "0.25rem")
+            .add("overflow", "hidden")
+            .add("background-color", scheme_colors.background.clone())
+            .add("border", format!("1px solid {}", scheme_colors.border))
+            .add("border-radius", &*theme_val.radius.sm)
+            .add("box-shadow", &*theme_val.shadows.md)
+            .add("z-index", "1000")
+            .build()
+    };
+
+    let listbox_styles = format!("max-height: {}px; overflow-y: auto;", max_dropdown_height);
+
+    let search_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        StyleBuilder::new()
+            .add("display", "block")
+            .add("width", "100%")
+            .add("box-sizing", "border-box")
+            .add("padding", "0.5rem 0.75rem")
+            .add("border", "none")
+            .add(
+                "border-bottom",
+                format!("1px solid {}", scheme_colors.border),
+            )
+            .add("outline", "none")
+            .add("font-family", &*theme_val.typography.font_family)
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("background-color", "transparent")
+            .add("color", scheme_colors.text.clone())
+            .build()
+    };
+
+    // Group headers share the option row height so virtualization stays exact
+    let group_label_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "display: flex; align-items: end; box-sizing: border-box; height: {}px; \
+             padding: 0 0.75rem 0.25rem; font-size: {}; font-weight: {}; color: {}; \
+             user-select: none; cursor: default;",
+            option_height,
+            &*theme_val.typography.font_sizes.xs,
+            theme_val.typography.font_weights.semibold,
+            scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string())
+        )
+    };
+
+    let empty_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "padding: 0.5rem 0.75rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.sm,
+            scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string())
+        )
+    };
+
+    let option_styles = move |is_selected: bool, is_highlighted: bool, is_disabled: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let primary = scheme_colors
+            .get_color(&theme_val.colors.primary_color, 6)
+            .unwrap_or_else(|| "#228be6".to_string());
+        let highlight_bg = scheme_colors
+            .get_color("gray", 1)
+            .unwrap_or_else(|| "#f1f3f5".to_string());
+
+        let mut builder = StyleBuilder::new();
+        builder
+            .add("display", "flex")
+            .add("align-items", "center")
+            .add("box-sizing", "border-box")
+            .add("height", format!("{}px", option_height))
+            .add("padding", "0 0.75rem")
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("white-space", "nowrap")
+            .add("overflow", "hidden")
+            .add("text-overflow", "ellipsis")
+            .add("user-select", "none")
+            .add_if(is_disabled, "opacity", "0.5")
+            .add(
+                "cursor",
+                if is_disabled {
+                    "not-allowed"
+                } else {
+                    "pointer"
+                },
+            );
+
+        if is_selected {
+            builder
+                .add("background-color", primary)
+                .add("color", scheme_colors.white.clone());
+        } else if is_highlighted {
+            builder
+                .add("background-color", highlight_bg)
+                .add("color", scheme_colors.text.clone());
+        } else {
+            builder.add("color", scheme_colors.text.clone());
+        }
+
+        builder.build()
+    };
+
+    let selected_label = move || {
+        let selected = select_value.get();
+        options.with_value(|o| {
+            o.iter()
+                .find(|opt| opt.value == selected)
+                .map(|opt| opt.label.clone())
+        })
+    };
+
     let class_str = format!("mingot-select {}", class.unwrap_or_default());
 
     view! {
@@ -220,24 +763,177 @@ pub fn Select(
                 </label>
             })}
 
-            <select
-                class=class_str
-                style=select_styles
-                disabled=disabled
-                required=required
-                prop:value=move || select_value.get()
-                on:change=handle_change
-            >
-                {placeholder.map(|p| view! {
-                    <option value="" disabled=true selected=true>{p}</option>
-                })}
-
-                {options.into_iter().map(|opt| view! {
-                    <option value=opt.value.clone() disabled=opt.disabled>
-                        {opt.label}
-                    </option>
-                }).collect::<Vec<_>>()}
-            </select>
+            {if custom_dropdown {
+                view! {
+                    <div class="mingot-select-virtual" style="position: relative; width: 100%;">
+                        <button
+                            type="button"
+                            class=class_str
+                            style=trigger_styles
+                            disabled=disabled
+                            node_ref=trigger_ref
+                            role="combobox"
+                            aria-haspopup="listbox"
+                            aria-expanded=move || opened.get().to_string()
+                            // While the filter input has focus, keep it there so
+                            // clicking the trigger simply closes the dropdown
+                            on:mousedown=move |ev: ev::MouseEvent| {
+                                if searchable && opened.get_untracked() {
+                                    ev.prevent_default();
+                                }
+                            }
+                            on:click=move |_| {
+                                if opened.get_untracked() {
+                                    close_dropdown();
+                                } else {
+                                    open_dropdown();
+                                }
+                            }
+                            on:keydown=move |ev| handle_keydown(ev, false)
+                            on:blur=move |_| {
+                                if !searchable {
+                                    opened.set(false);
+                                }
+                            }
+                        >
+                            {move || match selected_label() {
+                                Some(l) => view! { <span>{l}</span> }.into_any(),
+                                None => view! {
+                                    <span style="opacity: 0.6;">{placeholder.clone().unwrap_or_default()}</span>
+                                }.into_any(),
+                            }}
+                        </button>
+
+                        <Show when=move || opened.get()>
+                            <div
+                                class="mingot-select-dropdown"
+                                style=dropdown_styles
+                                // Keep focus on the trigger or filter while clicking or scrolling the list
+                                on:mousedown=|ev: ev::MouseEvent| {
+                                    let on_input = ev
+                                        .target()
+                                        .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+                                        .is_some();
+                                    if !on_input {
+                                        ev.prevent_default();
+                                    }
+                                }
+                            >
+                                {searchable.then(|| view! {
+                                    <input
+                                        type="text"
+                                        class="mingot-select-search"
+                                        style=search_styles
+                                        placeholder="Search..."
+                                        aria-label="Filter options"
+                                        autocomplete="off"
+                                        node_ref=search_ref
+                                        prop:value=move || query.get()
+                                        on:input=handle_search_input
+                                        on:keydown=move |ev| handle_keydown(ev, true)
+                                        on:blur=move |_| opened.set(false)
+                                    />
+                                })}
+                                <div
+                                    role="listbox"
+                                    node_ref=listbox_ref
+                                    style=listbox_styles.clone()
+                                    on:scroll=handle_listbox_scroll
+                                >
+                                    <div style=move || format!("height: {}px;", virtual_window.get().offset_top)></div>
+                                    <For
+                                        each=move || {
+                                            let window = virtual_window.get();
+                                            rows.with(|r| {
+                                                (window.start..window.end)
+                                                    .filter_map(|position| r.get(position).map(|row| (position, row.clone())))
+                                                    .collect::<Vec<_>>()
+                                            })
+                                        }
+                                        key=|entry| entry.clone()
+                                        children=move |(position, row)| {
+                                            let index = match row {
+                                                SelectRow::Header(label) => {
+                                                    return view! {
+                                                        <div class="mingot-select-group-label" role="presentation" style=group_label_styles>
+                                                            {label}
+                                                        </div>
+                                                    }
+                                                    .into_any();
+                                                }
+                                                SelectRow::Option(index) => index,
+                                            };
+                                            let opt = options.with_value(|o| o[index].clone());
+                                            let value = opt.value.clone();
+                                            let is_selected = Memo::new(move |_| select_value.get() == value);
+                                            let is_highlighted = move || highlighted.get() == Some(position);
+                                            view! {
+                                                <div
+                                                    class="mingot-select-option"
+                                                    role="option"
+                                                    aria-selected=move || is_selected.get().to_string()
+                                                    aria-disabled=opt.disabled.to_string()
+                                                    style=move || option_styles(is_selected.get(), is_highlighted(), opt.disabled)
+                                                    on:mouseenter=move |_| {
+                                                        if !opt.disabled {
+                                                            highlighted.set(Some(position));
+                                                        }
+                                                    }
+                                                    on:click=move |_| commit(position)
+                                                >
+                                                    {opt.label.clone()}
+                                                </div>
+                                            }
+                                            .into_any()
+                                        }
+                                    />
+                                    <div style=move || format!("height: {}px;", virtual_window.get().offset_bottom)></div>
+                                    {
+                                        let nothing_found = nothing_found.clone();
+                                        move || rows.with(Vec::is_empty).then(|| view! {
+                                            <div class="mingot-select-empty" role="presentation" style=empty_styles>
+                                                {nothing_found.clone()}
+                                            </div>
+                                        })
+                                    }
+                                </div>
+                            </div>
+                        </Show>
+                    </div>
+                }.into_any()
+            } else {
+                view! {
+                <select
+                    class=class_str
+                    style=select_styles
+                    disabled=disabled
+                    required=required
+                    prop:value=move || select_value.get()
+                    on:change=handle_change
+                >
+                    {placeholder.map(|p| view! {
+                        <option value="" disabled=true selected=true>{p}</option>
+                    })}
+
+                    {match groups {
+                        Some(groups) => groups.into_iter().map(|group| view! {
+                            <optgroup label=group.label>
+                                {group.options.into_iter().map(|opt| view! {
+                                    <option value=opt.value.clone() disabled=opt.disabled>
+                                        {opt.label}
+                                    </option>
+                                }).collect::<Vec<_>>()}
+                            </optgroup>
+                        }).collect::<Vec<_>>().into_any(),
+                        None => options.get_value().into_iter().map(|opt| view! {
+                            <option value=opt.value.clone() disabled=opt.disabled>
+                                {opt.label}
+                            </option>
+                        }).collect::<Vec<_>>().into_any(),
+                    }}
+                </select>
+                }.into_any()
+            }}
 
             {description.map(|d| view! {
                 <div style=description_styles>{d}</div>
@@ -249,3 +945,156 @@ pub fn Select(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Navigation over an ungrouped list, where rows and options coincide
+    fn next_enabled_index(
+        options: &[SelectOption],
+        from: Option<usize>,
+        forward: bool,
+    ) -> Option<usize> {
+        let rows: Vec<SelectRow> = (0..options.len()).map(SelectRow::Option).collect();
+        next_enabled_row(&rows, options, from, forward)
+    }
+
+    fn sample_options() -> Vec<SelectOption> {
+        vec![
+            SelectOption::new("a", "A").disabled(true),
+            SelectOption::new("b", "B"),
+            SelectOption::new("c", "C").disabled(true),
+            SelectOption::new("d", "D"),
+            SelectOption::new("e", "E").disabled(true),
+        ]
+    }
+
+    #[test]
+    fn test_next_enabled_index_skips_disabled() {
+        let options = sample_options();
+        assert_eq!(next_enabled_index(&options, Some(1), true), Some(3));
+        assert_eq!(next_enabled_index(&options, Some(3), false), Some(1));
+    }
+
+    #[test]
+    fn test_next_enabled_index_at_ends() {
+        let options = sample_options();
+        assert_eq!(next_enabled_index(&options, Some(3), true), None);
+        assert_eq!(next_enabled_index(&options, Some(1), false), None);
+    }
+
+    #[test]
+    fn test_next_enabled_index_without_highlight() {
+        let options = sample_options();
+        assert_eq!(next_enabled_index(&options, None, true), Some(1));
+        assert_eq!(next_enabled_index(&options, None, false), Some(3));
+        assert_eq!(next_enabled_index(&[], None, true), None);
+    }
+
+    #[test]
+    fn test_scroll_to_reveal() {
+        // Already visible: no movement
+        assert_eq!(scroll_to_reveal(2, 30.0, 120.0, 0.0), 0.0);
+        // Below the viewport: align to the bottom edge
+        assert_eq!(scroll_to_reveal(10, 30.0, 120.0, 0.0), 210.0);
+        // Above the viewport: align to the top edge
+        assert_eq!(scroll_to_reveal(3, 30.0, 120.0, 300.0), 90.0);
+    }
+
+    #[test]
+    fn test_default_filter_ignores_case_and_accents() {
+        let option = SelectOption::new("fr", "Équateur Français");
+        assert!(default_option_filter(&option, "equa"));
+        assert!(default_option_filter(&option, "FRANCAIS"));
+        assert!(default_option_filter(&option, "  çais "));
+        assert!(default_option_filter(&option, ""));
+        assert!(!default_option_filter(&option, "ecuador"));
+        // Decomposed input (e + combining acute) folds the same way
+        assert!(default_option_filter(&option, "e\u{0301}quateur"));
+        assert_eq!(fold_search_text("Ñandú"), "nandu");
+    }
+
+    #[test]
+    fn test_filter_options_and_empty_result() {
+        let options = vec![
+            SelectOption::new("react", "React"),
+            SelectOption::new("svelte", "Svelte"),
+            SelectOption::new("solid", "SolidJS"),
+        ];
+        assert_eq!(filter_options(&options, "s", None), vec![1, 2]);
+        assert_eq!(filter_options(&options, "", None), vec![0, 1, 2]);
+        assert!(filter_options(&options, "leptos", None).is_empty());
+        assert_eq!(next_enabled_index(&[], None, true), None);
+    }
+
+    fn grouped_data() -> SelectData {
+        vec![
+            SelectOptionGroup::new(
+                "Fruits",
+                vec![
+                    SelectOption::new("apple", "Apple"),
+                    SelectOption::new("banana", "Banana"),
+                ],
+            ),
+            SelectOptionGroup::new(
+                "Vegetables",
+                vec![
+                    SelectOption::new("carrot", "Carrot").disabled(true),
+                    SelectOption::new("leek", "Leek"),
+                ],
+            ),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn test_navigation_skips_group_headers() {
+        let data = grouped_data();
+        let options = data.options();
+        let all: Vec<usize> = (0..options.len()).collect();
+        let rows = build_rows(&data.group_labels(), &all);
+        assert_eq!(
+            rows,
+            vec![
+                SelectRow::Header("Fruits".into()),
+                SelectRow::Option(0),
+                SelectRow::Option(1),
+                SelectRow::Header("Vegetables".into()),
+                SelectRow::Option(2),
+                SelectRow::Option(3),
+            ]
+        );
+
+        // First selectable row is below the first header
+        assert_eq!(next_enabled_row(&rows, &options, None, true), Some(1));
+        // Banana -> (header, disabled Carrot) -> Leek
+        assert_eq!(next_enabled_row(&rows, &options, Some(2), true), Some(5));
+        assert_eq!(next_enabled_row(&rows, &options, Some(5), false), Some(2));
+        // Never lands on the leading header
+        assert_eq!(next_enabled_row(&rows, &options, Some(1), false), None);
+    }
+
+    #[test]
+    fn test_filtering_hides_empty_groups() {
+        let data = grouped_data();
+        let options = data.options();
+        let matches = filter_options(&options, "an", None);
+        assert_eq!(matches, vec![1]);
+        let rows = build_rows(&data.group_labels(), &matches);
+        assert_eq!(
+            rows,
+            vec![SelectRow::Header("Fruits".into()), SelectRow::Option(1)]
+        );
+
+        let rows = build_rows(&data.group_labels(), &filter_options(&options, "zzz", None));
+        assert!(rows.is_empty());
+    }
+
+    #[test]
+    fn test_flat_data_has_no_headers() {
+        let data: SelectData = sample_options().into();
+        let rows = build_rows(&data.group_labels(), &[1, 3]);
+        assert_eq!(rows, vec![SelectRow::Option(1), SelectRow::Option(3)]);
+    }
+}
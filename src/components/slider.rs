@@ -1,5 +1,5 @@
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{Rect, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
 
@@ -14,6 +14,15 @@ pub enum SliderSize {
     Xl,
 }
 
+/// Direction of the slider track
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    /// Bottom to top: up increases the value
+    Vertical,
+}
+
 /// Mark to display on the slider track
 #[derive(Clone, Debug, PartialEq)]
 pub struct SliderMark {
@@ -34,6 +43,69 @@ impl SliderMark {
     }
 }
 
+/// Position of `value` along `min..max` as a percentage
+pub fn value_to_percent(value: f64, min: f64, max: f64) -> f64 {
+    let range = max - min;
+    if range == 0.0 {
+        0.0
+    } else {
+        ((value - min) / range) * 100.0
+    }
+}
+
+/// `raw` rounded to the nearest `step` from `min` (any value when `step`
+/// is 0) and clamped to `min..=max`
+pub fn snap_to_step(raw: f64, min: f64, max: f64, step: f64) -> f64 {
+    if step > 0.0 {
+        let steps = ((raw - min) / step).round();
+        (min + steps * step).clamp(min, max)
+    } else {
+        raw.clamp(min, max)
+    }
+}
+
+/// Percentage along `track` for a pointer at `(x, y)`. Vertical tracks
+/// count from the bottom, so moving up increases the value.
+pub fn pointer_to_percent(x: f64, y: f64, track: Rect, orientation: SliderOrientation) -> f64 {
+    let (offset, length) = match orientation {
+        SliderOrientation::Horizontal => (x - track.left, track.width),
+        SliderOrientation::Vertical => (track.bottom() - y, track.height),
+    };
+    if length <= 0.0 {
+        return 0.0;
+    }
+    (offset / length * 100.0).clamp(0.0, 100.0)
+}
+
+/// Value after pressing `key` on a slider thumb, per the ARIA slider
+/// pattern: arrows move by `step`, Page Up/Down by `page_step`, Home/End
+/// jump to the bounds. `None` for keys the slider doesn't handle.
+pub fn slider_key_value(
+    key: &str,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    page_step: f64,
+) -> Option<f64> {
+    // A continuous slider still needs a keyboard increment
+    let step = if step > 0.0 {
+        step
+    } else {
+        (max - min) / 100.0
+    };
+    let target = match key {
+        "ArrowRight" | "ArrowUp" => value + step,
+        "ArrowLeft" | "ArrowDown" => value - step,
+        "PageUp" => value + page_step,
+        "PageDown" => value - page_step,
+        "Home" => min,
+        "End" => max,
+        _ => return None,
+    };
+    Some(snap_to_step(target, min, max, step))
+}
+
 /// A slider component for selecting numeric values within a range.
 ///
 /// # Example
@@ -54,6 +126,9 @@ impl SliderMark {
 ///     />
 /// }
 /// ```
+///
+/// The thumb is focusable: arrow keys move by `step`, Page Up/Down by
+/// `page_step` and Home/End jump to `min`/`max`.
 #[component]
 pub fn Slider(
     /// Current value of the slider
@@ -68,6 +143,13 @@ pub fn Slider(
     /// Step increment (0.0 for continuous)
     #[prop(default = 1.0)]
     step: f64,
+    /// Increment for Page Up/Down; defaults to 10 steps (a tenth of the
+    /// range when continuous)
+    #[prop(optional)]
+    page_step: Option<f64>,
+    /// Horizontal, or vertical with the maximum at the top
+    #[prop(optional)]
+    orientation: SliderOrientation,
     /// Size of the slider
     #[prop(optional)]
     size: Option<SliderSize>,
@@ -102,28 +184,18 @@ pub fn Slider(
     let theme = use_theme();
     let size = size.unwrap_or_default();
     let is_dragging = RwSignal::new(false);
+    let vertical = orientation == SliderOrientation::Vertical;
+    let page_step = page_step.unwrap_or(if step > 0.0 {
+        step * 10.0
+    } else {
+        (max - min) / 10.0
+    });
 
-    // Calculate percentage from value
-    let percentage = move || {
-        let val = value.get();
-        let range = max - min;
-        if range == 0.0 {
-            0.0
-        } else {
-            ((val - min) / range) * 100.0
-        }
-    };
+    let percentage = move || value_to_percent(value.get(), min, max);
 
     // Convert percentage to value, respecting step
-    let percentage_to_value = move |pct: f64| {
-        let raw_value = min + (pct / 100.0) * (max - min);
-        if step > 0.0 {
-            let steps = ((raw_value - min) / step).round();
-            (min + steps * step).clamp(min, max)
-        } else {
-            raw_value.clamp(min, max)
-        }
-    };
+    let percentage_to_value =
+        move |pct: f64| snap_to_step(min + (pct / 100.0) * (max - min), min, max, step);
 
     // Track dimensions for slider
     let (track_height, thumb_size) = match size {
@@ -142,8 +214,8 @@ pub fn Slider(
         let mut builder = StyleBuilder::new();
         builder
             .add("position", "relative")
-            .add("width", "100%")
-            .add("height", track_height)
+            .add("width", if vertical { track_height } else { "100%" })
+            .add("height", if vertical { "100%" } else { track_height })
             .add("border-radius", &*theme_val.radius.xl)
             .add(
                 "background-color",
@@ -172,9 +244,6 @@ pub fn Slider(
         builder
             .add("position", "absolute")
             .add("left", "0")
-            .add("top", "0")
-            .add("height", "100%")
-            .add("width", format!("{}%", percentage()))
             .add("border-radius", &*theme_val.radius.xl)
             .add(
                 "background-color",
@@ -184,13 +253,25 @@ pub fn Slider(
             )
             .add(
                 "transition",
-                if is_dragging.get() {
-                    "none"
-                } else {
-                    "width 0.1s ease"
+                match (is_dragging.get(), vertical) {
+                    (true, _) => "none",
+                    (false, false) => "width 0.1s ease",
+                    (false, true) => "height 0.1s ease",
                 },
             );
 
+        if vertical {
+            builder
+                .add("bottom", "0")
+                .add("width", "100%")
+                .add("height", format!("{}%", percentage()));
+        } else {
+            builder
+                .add("top", "0")
+                .add("height", "100%")
+                .add("width", format!("{}%", percentage()));
+        }
+
         builder.build()
     };
 
@@ -200,11 +281,19 @@ pub fn Slider(
         let is_disabled = disabled.get();
 
         let mut builder = StyleBuilder::new();
+        builder.add("position", "absolute");
+        if vertical {
+            builder
+                .add("left", "50%")
+                .add("bottom", format!("{}%", percentage()))
+                .add("transform", "translate(-50%, 50%)");
+        } else {
+            builder
+                .add("top", "50%")
+                .add("left", format!("{}%", percentage()))
+                .add("transform", "translate(-50%, -50%)");
+        }
         builder
-            .add("position", "absolute")
-            .add("top", "50%")
-            .add("left", format!("{}%", percentage()))
-            .add("transform", "translate(-50%, -50%)")
             .add("width", thumb_size)
             .add("height", thumb_size)
             .add("border-radius", "50%")
@@ -222,10 +311,10 @@ pub fn Slider(
             .add("cursor", if is_disabled { "not-allowed" } else { "grab" })
             .add(
                 "transition",
-                if is_dragging.get() {
-                    "none"
-                } else {
-                    "left 0.1s ease"
+                match (is_dragging.get(), vertical) {
+                    (true, _) => "none",
+                    (false, false) => "left 0.1s ease",
+                    (false, true) => "bottom 0.1s ease",
                 },
             )
             .add("z-index", "1");
@@ -249,29 +338,37 @@ pub fn Slider(
     };
 
     let mark_styles = move |mark_value: f64| {
-        let pct = ((mark_value - min) / (max - min)) * 100.0;
-        format!(
-            "position: absolute; left: {}%; transform: translateX(-50%); top: 100%; margin-top: 0.5rem; font-size: 0.75rem;",
-            pct
-        )
+        let pct = value_to_percent(mark_value, min, max);
+        if vertical {
+            format!(
+                "position: absolute; bottom: {}%; transform: translateY(50%); left: 100%; margin-left: 0.75rem; font-size: 0.75rem; white-space: nowrap;",
+                pct
+            )
+        } else {
+            format!(
+                "position: absolute; left: {}%; transform: translateX(-50%); top: 100%; margin-top: 0.5rem; font-size: 0.75rem;",
+                pct
+            )
+        }
     };
 
     // Handle mouse/touch interaction
-    let handle_interaction = move |client_x: i32, track_element: web_sys::HtmlElement| {
+    let handle_interaction = move |ev: &ev::MouseEvent, track_element: web_sys::HtmlElement| {
         if disabled.get() {
             return;
         }
 
-        let rect = track_element.get_bounding_client_rect();
-        let track_left = rect.left();
-        let track_width = rect.width();
-
-        if track_width == 0.0 {
+        let track = Rect::from_dom(&track_element.get_bounding_client_rect());
+        if track.width == 0.0 && track.height == 0.0 {
             return;
         }
 
-        let relative_x = (client_x as f64) - track_left;
-        let pct = (relative_x / track_width * 100.0).clamp(0.0, 100.0);
+        let pct = pointer_to_percent(
+            ev.client_x() as f64,
+            ev.client_y() as f64,
+            track,
+            orientation,
+        );
         let new_value = percentage_to_value(pct);
 
         if let Some(callback) = on_change {
@@ -291,7 +388,7 @@ pub fn Slider(
 
         if let Some(track) = track_ref.get() {
             let element: web_sys::HtmlElement = track.into();
-            handle_interaction(ev.client_x(), element);
+            handle_interaction(&ev, element);
         }
     };
 
@@ -302,7 +399,7 @@ pub fn Slider(
 
         if let Some(track) = track_ref.get() {
             let element: web_sys::HtmlElement = track.into();
-            handle_interaction(ev.client_x(), element);
+            handle_interaction(&ev, element);
         }
     };
 
@@ -315,6 +412,27 @@ pub fn Slider(
         }
     };
 
+    let handle_keydown = move |ev: ev::KeyboardEvent| {
+        if disabled.get() {
+            return;
+        }
+        let current = value.get_untracked();
+        let Some(new_value) = slider_key_value(&ev.key(), current, min, max, step, page_step)
+        else {
+            return;
+        };
+        ev.prevent_default();
+        if new_value == current {
+            return;
+        }
+        if let Some(callback) = on_change {
+            callback.run(new_value);
+        }
+        if let Some(callback) = on_change_end {
+            callback.run(new_value);
+        }
+    };
+
     // Format displayed value
     let display_value = move || {
         let val = value.get();
@@ -326,7 +444,13 @@ pub fn Slider(
     };
 
     let wrapper_styles = move || {
-        let mut styles = String::from("width: 100%;");
+        // Vertical sliders take their length from the wrapper; override
+        // it through `style`
+        let mut styles = String::from(if vertical {
+            "display: inline-flex; flex-direction: column; height: 12rem;"
+        } else {
+            "width: 100%;"
+        });
         if let Some(ref s) = style {
             styles.push_str(s);
         }
@@ -352,7 +476,11 @@ pub fn Slider(
                 </div>
             })}
 
-            <div style="position: relative; padding: 0.5rem 0;">
+            <div style=if vertical {
+                "position: relative; flex: 1; padding: 0 0.5rem; display: flex; justify-content: center;"
+            } else {
+                "position: relative; padding: 0.5rem 0;"
+            }>
                 <div
                     node_ref=track_ref
                     class="mingot-slider-track"
@@ -360,11 +488,27 @@ pub fn Slider(
                     on:mousedown=handle_mouse_down
                 >
                     <div class="mingot-slider-filled" style=filled_styles></div>
-                    <div class="mingot-slider-thumb" style=thumb_styles></div>
+                    <div
+                        class="mingot-slider-thumb"
+                        style=thumb_styles
+                        role="slider"
+                        tabindex=move || if disabled.get() { "-1" } else { "0" }
+                        aria-valuemin=min
+                        aria-valuemax=max
+                        aria-valuenow=move || value.get()
+                        aria-valuetext=display_value
+                        aria-orientation=if vertical { "vertical" } else { "horizontal" }
+                        aria-label=label.clone()
+                        aria-disabled=move || disabled.get().then_some("true")
+                        on:keydown=handle_keydown
+                    ></div>
                 </div>
 
                 {marks.map(|m| view! {
-                    <div class="mingot-slider-marks">
+                    <div
+                        class="mingot-slider-marks"
+                        style=vertical.then_some("position: absolute; inset: 0 auto 0 50%;")
+                    >
                         {m.into_iter().map(|mark| {
                             let mark_label = mark.label.clone();
                             view! {
@@ -398,6 +542,59 @@ mod tests {
         assert_eq!(mark.label, Some("75%".to_string()));
     }
 
+    #[test]
+    fn test_pointer_to_percent_horizontal() {
+        let track = Rect::new(100.0, 50.0, 200.0, 8.0);
+        let h = SliderOrientation::Horizontal;
+        assert_eq!(pointer_to_percent(100.0, 0.0, track, h), 0.0);
+        assert_eq!(pointer_to_percent(150.0, 0.0, track, h), 25.0);
+        assert_eq!(pointer_to_percent(300.0, 0.0, track, h), 100.0);
+        // Outside the track clamps
+        assert_eq!(pointer_to_percent(20.0, 0.0, track, h), 0.0);
+        assert_eq!(pointer_to_percent(900.0, 0.0, track, h), 100.0);
+    }
+
+    #[test]
+    fn test_pointer_to_percent_vertical() {
+        // Track from y=100 (top) to y=300 (bottom)
+        let track = Rect::new(0.0, 100.0, 8.0, 200.0);
+        let v = SliderOrientation::Vertical;
+        assert_eq!(pointer_to_percent(0.0, 300.0, track, v), 0.0);
+        assert_eq!(pointer_to_percent(0.0, 250.0, track, v), 25.0);
+        assert_eq!(pointer_to_percent(0.0, 100.0, track, v), 100.0);
+        assert_eq!(pointer_to_percent(0.0, 0.0, track, v), 100.0);
+    }
+
+    #[test]
+    fn test_keyboard_steps_and_bounds() {
+        let key = |k, v| slider_key_value(k, v, 0.0, 100.0, 5.0, 25.0);
+        assert_eq!(key("ArrowRight", 50.0), Some(55.0));
+        assert_eq!(key("ArrowUp", 50.0), Some(55.0));
+        assert_eq!(key("ArrowLeft", 50.0), Some(45.0));
+        assert_eq!(key("ArrowDown", 50.0), Some(45.0));
+        assert_eq!(key("PageUp", 50.0), Some(75.0));
+        assert_eq!(key("PageDown", 50.0), Some(25.0));
+        assert_eq!(key("Home", 50.0), Some(0.0));
+        assert_eq!(key("End", 50.0), Some(100.0));
+        assert_eq!(key("Enter", 50.0), None);
+
+        // Clamped at the bounds
+        assert_eq!(key("ArrowRight", 100.0), Some(100.0));
+        assert_eq!(key("PageUp", 90.0), Some(100.0));
+        assert_eq!(key("ArrowLeft", 0.0), Some(0.0));
+        assert_eq!(key("PageDown", 10.0), Some(0.0));
+    }
+
+    #[test]
+    fn test_snap_to_step() {
+        assert_eq!(snap_to_step(47.0, 0.0, 100.0, 5.0), 45.0);
+        assert_eq!(snap_to_step(48.0, 0.0, 100.0, 5.0), 50.0);
+        assert_eq!(snap_to_step(0.37, 0.0, 1.0, 0.0), 0.37);
+        assert_eq!(snap_to_step(120.0, 0.0, 100.0, 5.0), 100.0);
+        assert_eq!(value_to_percent(25.0, 0.0, 50.0), 50.0);
+        assert_eq!(value_to_percent(5.0, 5.0, 5.0), 0.0);
+    }
+
     #[test]
     fn test_slider_size_default() {
         assert_eq!(SliderSize::default(), SliderSize::Md);
@@ -1,5 +1,7 @@
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{
+    add_debug_outline, layout_debug_enabled, LayoutDebugKind, StyleBuilder, LAYOUT_DEBUG_CSS,
+};
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -47,6 +49,9 @@ pub fn Stack(
     #[prop(optional, into)] spacing: Option<String>,
     #[prop(optional)] align: Option<StackAlign>,
     #[prop(optional)] justify: Option<StackJustify>,
+    /// Outline this layout and label its spacing, for debugging
+    #[prop(optional)]
+    debug: bool,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
@@ -54,6 +59,8 @@ pub fn Stack(
     let theme = use_theme();
     let align = align.unwrap_or(StackAlign::Stretch);
     let justify = justify.unwrap_or(StackJustify::Start);
+    let debug = layout_debug_enabled(debug);
+    let debug_gap = spacing.clone();
 
     let stack_styles = move || {
         let theme_val = theme.get();
@@ -69,6 +76,10 @@ pub fn Stack(
         let gap = spacing.as_deref().unwrap_or(&*theme_val.spacing.md);
         builder.add("gap", gap);
 
+        if debug {
+            add_debug_outline(&mut builder, LayoutDebugKind::Stack, &theme_val);
+        }
+
         if let Some(s) = style.as_ref() {
             return format!("{}; {}", builder.build(), s);
         }
@@ -76,10 +87,20 @@ pub fn Stack(
         builder.build()
     };
 
+    let debug_label = move || {
+        debug.then(|| {
+            let gap = debug_gap
+                .clone()
+                .unwrap_or_else(|| theme.get().spacing.md.to_string());
+            LayoutDebugKind::Stack.label(&format!("gap {}", gap))
+        })
+    };
+
     let class_str = format!("mingot-stack {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=stack_styles>
+        <div class=class_str style=stack_styles data-mingot-debug=debug_label>
+            {debug.then(|| view! { <style>{LAYOUT_DEBUG_CSS}</style> })}
             {children()}
         </div>
     }
@@ -1,4 +1,5 @@
-use crate::theme::use_theme;
+use crate::components::{format_number, NumberInputFormat, NumberInputLocale};
+use crate::theme::{use_mingot_config, use_theme};
 use leptos::prelude::*;
 
 #[component]
@@ -8,10 +9,37 @@ pub fn Stats(
     #[prop(optional, into)] icon: Option<String>,
     #[prop(optional, into)] description: Option<String>,
     #[prop(optional)] diff: Option<f32>,
+    /// Format a numeric `value` like `NumberInput` does (default: Thousand
+    /// when `locale` is set). Non-numeric values are shown as-is.
+    #[prop(optional)]
+    format: Option<NumberInputFormat>,
+    /// Locale for numeric `value` (default: `MingotConfig` locale, then US)
+    #[prop(optional)]
+    locale: Option<NumberInputLocale>,
+    /// Decimal places for numeric `value`
+    #[prop(optional)]
+    precision: Option<u32>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
+    let config = use_mingot_config();
+
+    let value = if format.is_some() || locale.is_some() {
+        match value.trim().parse::<f64>() {
+            Ok(number) => format_number(
+                number,
+                format.unwrap_or(NumberInputFormat::Thousand),
+                locale
+                    .or(config.number_input_locale)
+                    .unwrap_or(NumberInputLocale::US),
+                precision,
+            ),
+            Err(_) => value,
+        }
+    } else {
+        value
+    };
 
     let stats_styles = move || {
         let theme_val = theme.get();
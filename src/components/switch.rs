@@ -1,3 +1,4 @@
+use crate::components::checkbox::form_entry;
 use crate::theme::use_theme;
 use leptos::prelude::*;
 
@@ -31,6 +32,12 @@ pub fn Switch(
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional, into)] description: Option<String>,
     #[prop(optional)] disabled: bool,
+    /// Form field name, so the switch is included in native form submission
+    #[prop(optional, into)]
+    name: Option<String>,
+    /// Submitted value when on; defaults to `"on"`
+    #[prop(optional, into)]
+    value: Option<String>,
     #[prop(optional)] on_change: Option<Callback<bool>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
@@ -165,6 +172,12 @@ pub fn Switch(
 
             on:click=handle_click
         >
+            // The switch is not a native control, so a hidden input carries
+            // its value into FormData while it is on
+            {move || {
+                form_entry(name.as_deref(), value.as_deref(), is_checked.get(), disabled)
+                    .map(|(name, value)| view! { <input type="hidden" name=name value=value /> })
+            }}
             <div class="mingot-switch-track" style=switch_track_styles>
                 <div class="mingot-switch-thumb" style=switch_thumb_styles></div>
             </div>
@@ -7,6 +7,7 @@ use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::ev;
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 
 /// Categories of mathematical symbols
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -665,6 +666,94 @@ pub fn get_symbols_by_categories(categories: &[SymbolCategory]) -> Vec<Symbol> {
         .collect()
 }
 
+/// Move `symbol` to the front of a recently-used list, dropping any earlier
+/// entry with the same character and keeping at most `limit` symbols
+pub fn push_recent_symbol(recent: &[Symbol], symbol: Symbol, limit: usize) -> Vec<Symbol> {
+    std::iter::once(symbol.clone())
+        .chain(recent.iter().filter(|s| s.char != symbol.char).cloned())
+        .take(limit)
+        .collect()
+}
+
+/// Grid index reached from `index` by a navigation key in a grid of `len`
+/// symbols laid out in `columns` columns, or `None` if the key does not move
+/// focus. Up/Down stop at the first and last rows. With `wrap`, Left/Right
+/// continue onto the previous/next row and around the ends of the grid;
+/// without it they stop at the row edges. Home/End go to the row edges.
+pub fn navigate_symbol_grid(
+    index: usize,
+    key: &str,
+    len: usize,
+    columns: usize,
+    wrap: bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    let columns = columns.max(1);
+    let index = index.min(len - 1);
+    let row_start = index - index % columns;
+    let row_end = (row_start + columns - 1).min(len - 1);
+    Some(match key {
+        "ArrowLeft" if index > row_start => index - 1,
+        "ArrowLeft" if wrap => (index + len - 1) % len,
+        "ArrowLeft" => index,
+        "ArrowRight" if index < row_end => index + 1,
+        "ArrowRight" if wrap => (index + 1) % len,
+        "ArrowRight" => index,
+        "ArrowUp" => index.checked_sub(columns).unwrap_or(index),
+        "ArrowDown" if index + columns < len => index + columns,
+        "ArrowDown" => index,
+        "Home" => row_start,
+        "End" => row_end,
+        _ => return None,
+    })
+}
+
+/// Index of the next symbol after `current` whose name starts with `letter`
+/// (case-insensitive), wrapping around so repeated presses cycle through matches
+pub fn find_symbol_by_initial(symbols: &[Symbol], letter: char, current: usize) -> Option<usize> {
+    let letter = letter.to_lowercase().to_string();
+    let len = symbols.len();
+    (1..=len)
+        .map(|offset| (current + offset) % len)
+        .find(|&i| symbols[i].name.to_lowercase().starts_with(&letter))
+}
+
+/// Look up recently-used symbols saved under `key` in `localStorage`
+#[cfg(target_arch = "wasm32")]
+fn load_recent_symbols(key: &str) -> Vec<Symbol> {
+    let Some(saved) = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+    else {
+        return Vec::new();
+    };
+    let symbols = get_all_symbols();
+    saved
+        .lines()
+        .filter_map(|c| symbols.iter().find(|s| s.char == c).cloned())
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_recent_symbols(_key: &str) -> Vec<Symbol> {
+    // No storage during SSR or native tests
+    Vec::new()
+}
+
+/// Save recently-used symbols under `key` in `localStorage`, one per line
+#[cfg(target_arch = "wasm32")]
+fn save_recent_symbols(key: &str, recent: &[Symbol]) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let chars: Vec<&str> = recent.iter().map(|s| s.char).collect();
+        let _ = storage.set_item(key, &chars.join("\n"));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_recent_symbols(_key: &str, _recent: &[Symbol]) {}
+
 /// Symbol palette component for selecting mathematical symbols
 #[component]
 pub fn SymbolPalette(
@@ -699,6 +788,18 @@ pub fn SymbolPalette(
     /// Label for the palette
     #[prop(optional, into)]
     label: Option<String>,
+
+    /// How many recently selected symbols to show above the grid (0 hides the row)
+    #[prop(optional, default = 16)]
+    recent_limit: usize,
+
+    /// `localStorage` key for keeping recent symbols across page loads
+    #[prop(optional, into)]
+    persist_key: Option<String>,
+
+    /// Whether Left/Right arrow keys wrap onto the previous/next row
+    #[prop(optional, default = true)]
+    wrap_navigation: bool,
 ) -> impl IntoView {
     let theme = use_theme();
 
@@ -710,6 +811,48 @@ pub fn SymbolPalette(
     // State
     let search_query = RwSignal::new(String::new());
     let active_category: RwSignal<Option<SymbolCategory>> = RwSignal::new(None);
+    let recent_symbols = RwSignal::new(
+        persist_key
+            .as_deref()
+            .map(load_recent_symbols)
+            .unwrap_or_default()
+            .into_iter()
+            .take(recent_limit)
+            .collect::<Vec<_>>(),
+    );
+    let persist_key = StoredValue::new(persist_key);
+
+    let select_symbol = move |symbol: Symbol| {
+        if recent_limit > 0 {
+            let recent = recent_symbols
+                .with_untracked(|r| push_recent_symbol(r, symbol.clone(), recent_limit));
+            persist_key.with_value(|key| {
+                if let Some(key) = key {
+                    save_recent_symbols(key, &recent);
+                }
+            });
+            recent_symbols.set(recent);
+        }
+        if let Some(cb) = on_select {
+            cb.run(symbol);
+        }
+    };
+    let select_symbol = Callback::new(select_symbol);
+
+    // Recent symbols from the palette's categories, hidden while searching
+    let categories_for_recent = available_categories.clone();
+    let visible_recent = Memo::new(move |_| {
+        if !search_query.with(|q| q.is_empty()) {
+            return Vec::new();
+        }
+        recent_symbols.with(|recent| {
+            recent
+                .iter()
+                .filter(|s| categories_for_recent.contains(&s.category))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
 
     // Get filtered symbols (using Memo so it can be used in multiple closures)
     let filtered_symbols = Memo::new(move |_| {
@@ -806,16 +949,37 @@ pub fn SymbolPalette(
             .build()
     };
 
-    let grid_styles = move || {
+    let recent_label_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
         StyleBuilder::new()
-            .add("display", "grid")
-            .add("grid-template-columns", format!("repeat({}, 1fr)", columns))
-            .add("gap", "2px")
-            .add("max-height", "300px")
-            .add("overflow-y", "auto")
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add(
+                "color",
+                scheme_colors
+                    .get_color("gray", 6)
+                    .unwrap_or_else(|| "#868e96".to_string()),
+            )
+            .add("margin-bottom", "2px")
             .build()
     };
 
+    // ArrowDown in the search box moves into the grid
+    let grid_ref = NodeRef::<leptos::html::Div>::new();
+    let handle_search_keydown = move |ev: ev::KeyboardEvent| {
+        if ev.key() != "ArrowDown" {
+            return;
+        }
+        let cell = grid_ref
+            .get_untracked()
+            .and_then(|grid| grid.query_selector("[tabindex=\"0\"]").ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        if let Some(cell) = cell {
+            ev.prevent_default();
+            let _ = cell.focus();
+        }
+    };
+
     view! {
         <div class="mingot-symbol-palette" style=container_styles>
             {label.map(|l| view! {
@@ -827,11 +991,13 @@ pub fn SymbolPalette(
                     <input
                         type="text"
                         placeholder="Search symbols..."
+                        aria-label="Search symbols"
                         style=search_styles
                         prop:value=move || search_query.get()
                         on:input=move |ev| {
                             search_query.set(event_target_value(&ev));
                         }
+                        on:keydown=handle_search_keydown
                     />
                 }
             })}
@@ -858,25 +1024,33 @@ pub fn SymbolPalette(
                 }
             })}
 
-            <div style=grid_styles>
-                {move || {
-                    filtered_symbols.get().into_iter().map(|symbol| {
-                        let sym = symbol.clone();
-                        let sym_for_click = symbol.clone();
-                        view! {
-                            <SymbolButton
-                                symbol=sym
-                                show_tooltip=show_tooltip
-                                show_latex=show_latex
-                                on_click=move |_| {
-                                    if let Some(cb) = on_select {
-                                        cb.run(sym_for_click.clone());
-                                    }
-                                }
-                            />
-                        }
-                    }).collect_view()
-                }}
+            {move || {
+                (!visible_recent.with(|r| r.is_empty())).then(|| view! {
+                    <div class="mingot-symbol-palette-recent">
+                        <div style=recent_label_styles>{"Recent"}</div>
+                        <SymbolGrid
+                            symbols=visible_recent
+                            columns=columns
+                            wrap=wrap_navigation
+                            show_tooltip=show_tooltip
+                            show_latex=show_latex
+                            label="Recent symbols"
+                            on_select=select_symbol
+                        />
+                    </div>
+                })
+            }}
+
+            <div node_ref=grid_ref>
+                <SymbolGrid
+                    symbols=filtered_symbols
+                    columns=columns
+                    wrap=wrap_navigation
+                    show_tooltip=show_tooltip
+                    show_latex=show_latex
+                    label="Symbols"
+                    on_select=select_symbol
+                />
             </div>
 
             <div style=move || {
@@ -894,6 +1068,127 @@ pub fn SymbolPalette(
     }
 }
 
+/// Internal grid of symbol buttons with a roving tabindex.
+///
+/// Only the active cell is tab-focusable. Arrow keys, Home and End move
+/// between cells (see [`navigate_symbol_grid`]), Enter or Space selects,
+/// Escape leaves the grid, and typing a letter jumps to the next symbol whose
+/// name starts with it.
+#[component]
+fn SymbolGrid(
+    symbols: Memo<Vec<Symbol>>,
+    columns: usize,
+    wrap: bool,
+    show_tooltip: bool,
+    show_latex: bool,
+    label: &'static str,
+    on_select: Callback<Symbol>,
+) -> impl IntoView {
+    let active_index = RwSignal::new(0usize);
+    let grid_ref = NodeRef::<leptos::html::Div>::new();
+
+    // Start from the first cell whenever the filtered symbols change
+    Effect::new(move || {
+        symbols.track();
+        active_index.set(0);
+    });
+
+    let focus_cell = move |index: usize| {
+        let cell = grid_ref
+            .get_untracked()
+            .and_then(|grid| {
+                grid.query_selector(&format!("[data-index=\"{}\"]", index))
+                    .ok()
+                    .flatten()
+            })
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        if let Some(cell) = cell {
+            let _ = cell.focus();
+        }
+    };
+
+    let handle_keydown = move |ev: ev::KeyboardEvent| {
+        let key = ev.key();
+        let current = active_index.get_untracked();
+        match key.as_str() {
+            "Enter" | " " => {
+                ev.prevent_default();
+                if let Some(symbol) = symbols.with_untracked(|s| s.get(current).cloned()) {
+                    on_select.run(symbol);
+                }
+            }
+            "Escape" => {
+                ev.prevent_default();
+                if let Some(cell) = ev
+                    .target()
+                    .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+                {
+                    let _ = cell.blur();
+                }
+            }
+            _ => {
+                let next = symbols.with_untracked(|s| {
+                    navigate_symbol_grid(current, &key, s.len(), columns, wrap).or_else(|| {
+                        let mut chars = key.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None)
+                                if c.is_alphabetic() && !ev.ctrl_key() && !ev.meta_key() =>
+                            {
+                                find_symbol_by_initial(s, c, current)
+                            }
+                            _ => None,
+                        }
+                    })
+                });
+                if let Some(next) = next {
+                    ev.prevent_default();
+                    active_index.set(next);
+                    focus_cell(next);
+                }
+            }
+        }
+    };
+
+    let grid_styles = move || {
+        StyleBuilder::new()
+            .add("display", "grid")
+            .add("grid-template-columns", format!("repeat({}, 1fr)", columns))
+            .add("gap", "2px")
+            .add("max-height", "300px")
+            .add("overflow-y", "auto")
+            .build()
+    };
+
+    view! {
+        <div
+            node_ref=grid_ref
+            role="grid"
+            aria-label=label
+            style=grid_styles
+            on:keydown=handle_keydown
+        >
+            {move || {
+                symbols.get().into_iter().enumerate().map(|(index, symbol)| {
+                    let sym_for_click = symbol.clone();
+                    view! {
+                        <SymbolButton
+                            symbol=symbol
+                            index=index
+                            active=move || active_index.get() == index
+                            show_tooltip=show_tooltip
+                            show_latex=show_latex
+                            on_click=move |_| {
+                                active_index.set(index);
+                                on_select.run(sym_for_click.clone());
+                            }
+                        />
+                    }
+                }).collect_view()
+            }}
+        </div>
+    }
+}
+
 /// Internal tab button component
 #[component]
 fn TabButton(
@@ -959,6 +1254,8 @@ fn TabButton(
 #[component]
 fn SymbolButton(
     symbol: Symbol,
+    index: usize,
+    active: impl Fn() -> bool + Copy + Send + Sync + 'static,
     show_tooltip: bool,
     show_latex: bool,
     on_click: impl Fn(ev::MouseEvent) + Send + Sync + 'static,
@@ -993,6 +1290,19 @@ fn SymbolButton(
             )
             .add("color", scheme_colors.text.clone())
             .add("position", "relative")
+            .add(
+                "box-shadow",
+                if active() {
+                    format!(
+                        "inset 0 0 0 1px {}",
+                        scheme_colors
+                            .get_color(&theme_val.colors.primary_color, 4)
+                            .unwrap_or_else(|| "#74c0fc".to_string())
+                    )
+                } else {
+                    "none".to_string()
+                },
+            )
             .build()
     };
 
@@ -1008,6 +1318,11 @@ fn SymbolButton(
     view! {
         <button
             type="button"
+            role="gridcell"
+            data-index=index
+            tabindex=move || if active() { "0" } else { "-1" }
+            aria-selected=move || active().to_string()
+            aria-label=symbol.name
             style=styles
             title=if show_tooltip { Some(tooltip_text) } else { None }
             on:click=on_click
@@ -1072,6 +1387,89 @@ mod tests {
         assert_eq!(infinity.latex, Some("\\infty"));
     }
 
+    #[test]
+    fn test_push_recent_symbol_lru_order() {
+        let symbols = get_all_symbols();
+        let find = |c: &str| symbols.iter().find(|s| s.char == c).unwrap().clone();
+
+        let mut recent = Vec::new();
+        for c in ["α", "β", "γ"] {
+            recent = push_recent_symbol(&recent, find(c), 3);
+        }
+        let chars = |r: &[Symbol]| r.iter().map(|s| s.char).collect::<Vec<_>>();
+        assert_eq!(chars(&recent), vec!["γ", "β", "α"]);
+
+        // Re-selecting moves to the front without duplicating
+        recent = push_recent_symbol(&recent, find("α"), 3);
+        assert_eq!(chars(&recent), vec!["α", "γ", "β"]);
+
+        // The least recently used symbol falls off past the limit
+        recent = push_recent_symbol(&recent, find("∑"), 3);
+        assert_eq!(chars(&recent), vec!["∑", "α", "γ"]);
+
+        assert!(push_recent_symbol(&recent, find("∞"), 0).is_empty());
+    }
+
+    #[test]
+    fn test_recent_symbols_without_storage() {
+        // Outside a browser nothing is persisted, and nothing panics
+        save_recent_symbols("mingot-recent", &get_all_symbols()[..2]);
+        assert!(load_recent_symbols("mingot-recent").is_empty());
+    }
+
+    #[test]
+    fn test_navigate_symbol_grid() {
+        // 10 symbols in 4 columns: rows [0..4), [4..8), [8..10)
+        let nav = |i, key, wrap| navigate_symbol_grid(i, key, 10, 4, wrap);
+        assert_eq!(nav(5, "ArrowRight", true), Some(6));
+        assert_eq!(nav(5, "ArrowLeft", true), Some(4));
+        assert_eq!(nav(5, "ArrowUp", true), Some(1));
+        assert_eq!(nav(5, "ArrowDown", true), Some(9));
+
+        // Up/Down stop at the edges, including a short last row
+        assert_eq!(nav(1, "ArrowUp", true), Some(1));
+        assert_eq!(nav(7, "ArrowDown", true), Some(7));
+
+        // Left/Right wrap across rows and around the grid
+        assert_eq!(nav(3, "ArrowRight", true), Some(4));
+        assert_eq!(nav(4, "ArrowLeft", true), Some(3));
+        assert_eq!(nav(9, "ArrowRight", true), Some(0));
+        assert_eq!(nav(0, "ArrowLeft", true), Some(9));
+
+        // Without wrapping they stop at the row edges
+        assert_eq!(nav(3, "ArrowRight", false), Some(3));
+        assert_eq!(nav(4, "ArrowLeft", false), Some(4));
+
+        assert_eq!(nav(6, "Home", true), Some(4));
+        assert_eq!(nav(6, "End", true), Some(7));
+        assert_eq!(nav(8, "End", true), Some(9));
+
+        assert_eq!(nav(5, "a", true), None);
+        assert_eq!(navigate_symbol_grid(0, "ArrowRight", 0, 4, true), None);
+    }
+
+    #[test]
+    fn test_find_symbol_by_initial() {
+        let greek = get_symbols_by_categories(&[SymbolCategory::Greek]);
+        let first_b = greek.iter().position(|s| s.name.starts_with('b')).unwrap();
+        assert_eq!(find_symbol_by_initial(&greek, 'B', 0), Some(first_b));
+
+        // Repeated presses cycle through every match and wrap around
+        let matches: Vec<usize> = (0..greek.len())
+            .filter(|&i| greek[i].name.to_lowercase().starts_with('p'))
+            .collect();
+        assert!(matches.len() > 1);
+        let mut current = find_symbol_by_initial(&greek, 'p', 0).unwrap();
+        assert_eq!(current, matches[0]);
+        current = find_symbol_by_initial(&greek, 'p', current).unwrap();
+        assert_eq!(current, matches[1]);
+        let last = *matches.last().unwrap();
+        assert_eq!(find_symbol_by_initial(&greek, 'p', last), Some(matches[0]));
+
+        assert_eq!(find_symbol_by_initial(&greek, 'q', 0), None);
+        assert_eq!(find_symbol_by_initial(&[], 'a', 0), None);
+    }
+
     #[test]
     fn test_symbol_names() {
         let symbols = get_all_symbols();
@@ -1,7 +1,10 @@
-use crate::components::{Button, ButtonVariant};
+use crate::components::{
+    format_number, Button, ButtonVariant, NumberInputFormat, NumberInputLocale,
+};
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{StyleBuilder, VirtualWindow};
 use leptos::prelude::*;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -12,11 +15,21 @@ pub enum SortDirection {
 }
 
 impl SortDirection {
+    /// Next state when a header is clicked: ascending, descending, then unsorted
     pub fn toggle(&self) -> Self {
         match self {
             SortDirection::None => SortDirection::Ascending,
             SortDirection::Ascending => SortDirection::Descending,
-            SortDirection::Descending => SortDirection::Ascending,
+            SortDirection::Descending => SortDirection::None,
+        }
+    }
+
+    /// Value for the header's `aria-sort` attribute
+    pub fn aria_sort(&self) -> &'static str {
+        match self {
+            SortDirection::Ascending => "ascending",
+            SortDirection::Descending => "descending",
+            SortDirection::None => "none",
         }
     }
 
@@ -29,6 +42,9 @@ impl SortDirection {
     }
 }
 
+/// Comparison used to sort a column's rows
+pub type SortFn<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
 /// Column definition for table
 pub struct TableColumn<T>
 where
@@ -38,6 +54,9 @@ where
     pub header: String,
     pub render: Arc<dyn Fn(&T) -> AnyView + Send + Sync>,
     pub sortable: bool,
+    /// Comparison for local sorting; a sortable column without one is
+    /// only sorted through `on_sort`, and shows no sort indicator otherwise
+    pub sort_fn: Option<SortFn<T>>,
     pub width: Option<String>,
 }
 
@@ -48,6 +67,7 @@ impl<T: Clone + 'static> Clone for TableColumn<T> {
             header: self.header.clone(),
             render: Arc::clone(&self.render),
             sortable: self.sortable,
+            sort_fn: self.sort_fn.clone(),
             width: self.width.clone(),
         }
     }
@@ -68,10 +88,35 @@ impl<T: Clone + 'static> TableColumn<T> {
             header: header.into(),
             render: Arc::new(render_fn),
             sortable: false,
+            sort_fn: None,
             width: None,
         }
     }
 
+    /// Right-aligned numeric column formatted like `NumberInput`, so
+    /// tables match the rest of a dashboard. When made sortable it sorts
+    /// by the numeric value rather than the formatted text.
+    pub fn numeric(
+        key: impl Into<String>,
+        header: impl Into<String>,
+        value: impl Fn(&T) -> f64 + Send + Sync + 'static,
+        format: NumberInputFormat,
+        locale: NumberInputLocale,
+        precision: Option<u32>,
+    ) -> Self {
+        let value = Arc::new(value);
+        let sort_value = Arc::clone(&value);
+        let column = Self::new(key, header, move |item: &T| {
+            let text = format_number(value(item), format, locale, precision);
+            view! {
+                <span style="display: block; text-align: right; font-variant-numeric: tabular-nums;">
+                    {text}
+                </span>
+            }
+        });
+        column.sort_by(move |a, b| sort_value(a).total_cmp(&sort_value(b)))
+    }
+
     pub fn sortable(mut self, sortable: bool) -> Self {
         self.sortable = sortable;
         self
@@ -81,6 +126,66 @@ impl<T: Clone + 'static> TableColumn<T> {
         self.width = Some(width.into());
         self
     }
+
+    /// Sort this column with a custom comparison
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + Send + Sync + 'static) -> Self {
+        self.sort_fn = Some(Arc::new(compare));
+        self
+    }
+
+    /// Sort this column by a key extracted from each row
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + Send + Sync + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Whether the header sorts when clicked: the column is `sortable` and
+    /// either has a comparator or the table sorts server-side through
+    /// `on_sort`. Only then does it show a sort indicator and `aria-sort`.
+    pub fn sorts(&self, server_side: bool) -> bool {
+        self.sortable && (server_side || self.sort_fn.is_some())
+    }
+
+    /// Comparison used for local sorting, if the column is sortable and has one
+    pub fn comparator(&self) -> Option<SortFn<T>> {
+        if self.sortable {
+            self.sort_fn.clone()
+        } else {
+            None
+        }
+    }
+}
+
+/// Display order of `rows` as indices into it. Sorting is stable, so equal
+/// rows keep their original order; without a comparator or direction the
+/// original order is returned.
+pub fn sort_order<T>(
+    rows: &[T],
+    comparator: Option<&SortFn<T>>,
+    direction: SortDirection,
+) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    if let Some(compare) = comparator {
+        match direction {
+            SortDirection::Ascending => order.sort_by(|&a, &b| compare(&rows[a], &rows[b])),
+            SortDirection::Descending => {
+                order.sort_by(|&a, &b| compare(&rows[a], &rows[b]).reverse())
+            }
+            SortDirection::None => {}
+        }
+    }
+    order
+}
+
+/// Comparator of the column keyed `column_key` among `columns`
+fn column_comparator<T: Clone + 'static>(
+    columns: &[TableColumn<T>],
+    column_key: Option<&str>,
+) -> Option<SortFn<T>> {
+    let key = column_key?;
+    columns
+        .iter()
+        .find(|column| column.key == key)
+        .and_then(TableColumn::comparator)
 }
 
 #[component]
@@ -91,15 +196,50 @@ pub fn Table<T>(
     #[prop(optional)] highlight_on_hover: bool,
     #[prop(optional)] with_border: bool,
     #[prop(optional)] with_column_borders: bool,
-    #[prop(optional)] sort_column: Option<RwSignal<Option<String>>>,
-    #[prop(optional)] sort_direction: Option<RwSignal<SortDirection>>,
-    #[prop(optional)] on_sort: Option<Callback<(String, SortDirection)>>,
+    /// Key of the sorted column
+    #[prop(optional)]
+    sort_column: Option<RwSignal<Option<String>>>,
+    /// Current sort direction
+    #[prop(optional)]
+    sort_direction: Option<RwSignal<SortDirection>>,
+    /// Called when a sortable header is clicked. When set, rows are shown in
+    /// the order given (for server-side sorting) instead of sorted locally.
+    #[prop(optional)]
+    on_sort: Option<Callback<(String, SortDirection)>>,
     #[prop(optional, into)] empty_message: Option<String>,
+    /// Render only the rows in (and near) the viewport of a fixed-height scroll container
+    #[prop(optional)]
+    virtualized: bool,
+    /// Fixed row height in pixels used by virtualization (default: 40)
+    #[prop(optional)]
+    row_height: Option<f64>,
+    /// Scroll container height in pixels when virtualized (default: 400)
+    #[prop(optional)]
+    viewport_height: Option<f64>,
+    /// Extra rows rendered above and below the viewport (default: 5)
+    #[prop(optional)]
+    overscan: Option<usize>,
 ) -> impl IntoView
 where
     T: Clone + Send + Sync + 'static,
 {
     let theme = use_theme();
+    let row_height = row_height.unwrap_or(40.0);
+    let viewport_height = viewport_height.unwrap_or(400.0);
+    let overscan = overscan.unwrap_or(5);
+
+    // Scroll offset of the virtualized container. It lives outside the row
+    // rendering so data updates keep the current scroll position.
+    let scroll_top = RwSignal::new(0.0_f64);
+    let virtual_window = Memo::new(move |_| {
+        VirtualWindow::compute(
+            data.with(|rows| rows.len()),
+            row_height,
+            viewport_height,
+            scroll_top.get(),
+            overscan,
+        )
+    });
 
     let current_sort_column = sort_column.unwrap_or_else(|| RwSignal::new(None));
     let current_sort_direction =
@@ -116,7 +256,8 @@ where
             SortDirection::Ascending
         };
 
-        current_sort_column.set(Some(column_key.clone()));
+        // The third click returns to the unsorted order
+        current_sort_column.set((new_direction != SortDirection::None).then(|| column_key.clone()));
         current_sort_direction.set(new_direction);
 
         if let Some(callback) = on_sort {
@@ -168,6 +309,14 @@ where
             .add("background-color", bg_color)
             .add("user-select", "none");
 
+        // Sticky header inside the virtualized scroll container
+        if virtualized {
+            builder
+                .add("position", "sticky")
+                .add("top", "0")
+                .add("z-index", "1");
+        }
+
         if with_column_borders {
             builder.add(
                 "border",
@@ -199,6 +348,15 @@ where
             format!("{} {}", &*theme_val.spacing.sm, &*theme_val.spacing.md),
         );
 
+        // Keep every row at `row_height` so scroll offsets map to row indices
+        if virtualized {
+            builder
+                .add("white-space", "nowrap")
+                .add("overflow", "hidden")
+                .add("text-overflow", "ellipsis")
+                .add("max-width", "0");
+        }
+
         if striped && row_index % 2 == 1 {
             let stripe_color = scheme_colors
                 .get_color("gray", 0)
@@ -245,8 +403,39 @@ where
         format!("th.sortable:hover {{ background-color: {}; }}", hover_color)
     };
 
+    let wrapper_styles = move || {
+        if virtualized {
+            format!(
+                "height: {}px; overflow-y: auto; position: relative;",
+                viewport_height
+            )
+        } else {
+            String::new()
+        }
+    };
+
+    let handle_scroll = move |ev: leptos::ev::Event| {
+        if virtualized {
+            let element = event_target::<web_sys::Element>(&ev);
+            scroll_top.set(element.scroll_top() as f64);
+        }
+    };
+
+    let columns_len = columns.len();
+
+    // Row indices in display order; unsorted when sorting happens server-side
+    let sort_columns = columns.clone();
+    let display_order = Memo::new(move |_| {
+        let comparator = if on_sort.is_some() {
+            None
+        } else {
+            current_sort_column.with(|key| column_comparator(&sort_columns, key.as_deref()))
+        };
+        data.with(|rows| sort_order(rows, comparator.as_ref(), current_sort_direction.get()))
+    });
+
     view! {
-        <div class="mingot-table-wrapper">
+        <div class="mingot-table-wrapper" style=wrapper_styles on:scroll=handle_scroll>
             <style>
                 {tr_hover_styles}
                 {th_hover_styles}
@@ -258,12 +447,22 @@ where
                             let key = col.key.clone();
                             let key_for_sort = key.clone();
                             let header = col.header.clone();
-                            let sortable = col.sortable;
+                            let sortable = col.sorts(on_sort.is_some());
                             let width = col.width.clone();
 
                             let is_current_sort = move || {
                                 current_sort_column.get().as_ref() == Some(&key_for_sort)
                             };
+                            let is_sorted_column = is_current_sort.clone();
+                            let aria_sort = move || {
+                                if !sortable {
+                                    None
+                                } else if is_sorted_column() {
+                                    Some(current_sort_direction.get().aria_sort())
+                                } else {
+                                    Some("none")
+                                }
+                            };
 
                             let sort_icon = move || {
                                 if !sortable {
@@ -280,6 +479,7 @@ where
                             view! {
                                 <th
                                     class=move || if sortable { "sortable" } else { "" }
+                                    aria-sort=aria_sort
                                     style=move || {
                                         let mut style = th_styles(sortable);
                                         if let Some(w) = &width {
@@ -299,21 +499,48 @@ where
                     </tr>
                 </thead>
                 <tbody>
+                    {move || {
+                        (virtualized && virtual_window.get().offset_top > 0.0).then(|| {
+                            let height = virtual_window.get().offset_top;
+                            view! {
+                                <tr class="mingot-table-spacer" aria-hidden="true">
+                                    <td colspan=columns_len style=format!("height: {}px; padding: 0; border: none;", height)></td>
+                                </tr>
+                            }
+                        })
+                    }}
                     <For
                         each=move || {
                             let rows = data.get();
-                            if rows.is_empty() {
-                                vec![]
+                            let order = display_order.get();
+                            let (start, end) = if virtualized {
+                                let window = virtual_window.get();
+                                (window.start, window.end)
                             } else {
-                                rows.into_iter().enumerate().collect::<Vec<_>>()
-                            }
+                                (0, order.len())
+                            };
+                            order
+                                .into_iter()
+                                .enumerate()
+                                .skip(start)
+                                .take(end.saturating_sub(start))
+                                .filter_map(|(position, index)| {
+                                    rows.get(index).map(|row| (position, index, row.clone()))
+                                })
+                                .collect::<Vec<_>>()
                         }
-                        key=|(i, _)| *i
+                        // Keyed by position and source row so re-sorting re-renders
+                        key=|(position, index, _)| (*position, *index)
                         children={
                             let columns_clone = columns.clone();
-                            move |(row_index, row)| {
+                            move |(row_index, _, row)| {
+                                let row_style = if virtualized {
+                                    format!("height: {}px;", row_height)
+                                } else {
+                                    String::new()
+                                };
                                 view! {
-                                    <tr>
+                                    <tr style=row_style>
                                         {columns_clone.iter().map(|col| {
                                             let cell_content = (col.render)(&row);
                                             view! {
@@ -327,8 +554,17 @@ where
                             }
                         }
                     />
+                    {move || {
+                        (virtualized && virtual_window.get().offset_bottom > 0.0).then(|| {
+                            let height = virtual_window.get().offset_bottom;
+                            view! {
+                                <tr class="mingot-table-spacer" aria-hidden="true">
+                                    <td colspan=columns_len style=format!("height: {}px; padding: 0; border: none;", height)></td>
+                                </tr>
+                            }
+                        })
+                    }}
                     {
-                        let columns_len = columns.len();
                         move || {
                             if data.get().is_empty() {
                                 let message = empty_message.clone().unwrap_or_else(|| "No data available".to_string());
@@ -535,8 +771,24 @@ where
         }
     });
 
+    // Sort the full data set before paging so pages follow the sort order
+    let sort_column = sort_column.unwrap_or_else(|| RwSignal::new(None));
+    let sort_direction = sort_direction.unwrap_or_else(|| RwSignal::new(SortDirection::None));
+    let sort_columns = columns.clone();
+    let sorted_data = Signal::derive(move || {
+        let rows = data.get();
+        if on_sort.is_some() {
+            return rows;
+        }
+        let comparator = sort_column.with(|key| column_comparator(&sort_columns, key.as_deref()));
+        sort_order(&rows, comparator.as_ref(), sort_direction.get())
+            .into_iter()
+            .map(|index| rows[index].clone())
+            .collect::<Vec<_>>()
+    });
+
     let paginated_data = Signal::derive(move || {
-        let all_data = data.get();
+        let all_data = sorted_data.get();
         let page = current_page.get();
         let size = page_size.get();
 
@@ -554,8 +806,8 @@ where
         }
     });
 
-    let table_view = match (sort_column, sort_direction, on_sort, empty_message) {
-        (Some(sc), Some(sd), Some(os), Some(em)) => view! {
+    let table_view = match (on_sort, empty_message) {
+        (Some(os), Some(em)) => view! {
             <Table
                 columns=columns.clone()
                 data=paginated_data
@@ -563,14 +815,14 @@ where
                 highlight_on_hover=highlight_on_hover
                 with_border=with_border
                 with_column_borders=with_column_borders
-                sort_column=sc
-                sort_direction=sd
+                sort_column=sort_column
+                sort_direction=sort_direction
                 on_sort=os
                 empty_message=em
             />
         }
         .into_any(),
-        (Some(sc), Some(sd), Some(os), None) => view! {
+        (Some(os), None) => view! {
             <Table
                 columns=columns.clone()
                 data=paginated_data
@@ -578,13 +830,13 @@ where
                 highlight_on_hover=highlight_on_hover
                 with_border=with_border
                 with_column_borders=with_column_borders
-                sort_column=sc
-                sort_direction=sd
+                sort_column=sort_column
+                sort_direction=sort_direction
                 on_sort=os
             />
         }
         .into_any(),
-        (_, _, _, Some(em)) => view! {
+        (None, Some(em)) => view! {
             <Table
                 columns=columns.clone()
                 data=paginated_data
@@ -592,11 +844,13 @@ where
                 highlight_on_hover=highlight_on_hover
                 with_border=with_border
                 with_column_borders=with_column_borders
+                sort_column=sort_column
+                sort_direction=sort_direction
                 empty_message=em
             />
         }
         .into_any(),
-        _ => view! {
+        (None, None) => view! {
             <Table
                 columns=columns.clone()
                 data=paginated_data
@@ -604,6 +858,8 @@ where
                 highlight_on_hover=highlight_on_hover
                 with_border=with_border
                 with_column_borders=with_column_borders
+                sort_column=sort_column
+                sort_direction=sort_direction
             />
         }
         .into_any(),
@@ -640,7 +896,7 @@ mod tests {
     fn test_sort_direction_toggle() {
         assert_eq!(SortDirection::None.toggle(), SortDirection::Ascending);
         assert_eq!(SortDirection::Ascending.toggle(), SortDirection::Descending);
-        assert_eq!(SortDirection::Descending.toggle(), SortDirection::Ascending);
+        assert_eq!(SortDirection::Descending.toggle(), SortDirection::None);
     }
 
     #[test]
@@ -657,8 +913,12 @@ mod tests {
         assert_eq!(dir, SortDirection::Ascending);
         dir = dir.toggle();
         assert_eq!(dir, SortDirection::Descending);
+        // Third click restores the unsorted order, then the cycle repeats
+        dir = dir.toggle();
+        assert_eq!(dir, SortDirection::None);
         dir = dir.toggle();
         assert_eq!(dir, SortDirection::Ascending);
+        assert_eq!(SortDirection::None.aria_sort(), "none");
     }
 
     #[derive(Clone)]
@@ -706,4 +966,104 @@ mod tests {
         assert_eq!(column1.sortable, column2.sortable);
         assert_eq!(column1.width, column2.width);
     }
+
+    fn sample_rows() -> Vec<TestData> {
+        vec![
+            TestData {
+                id: 10,
+                name: "b".into(),
+            },
+            TestData {
+                id: 2,
+                name: "c".into(),
+            },
+            TestData {
+                id: 33,
+                name: "a".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_comparator_selection() {
+        let numeric = TableColumn::numeric(
+            "id",
+            "ID",
+            |item: &TestData| item.id as f64,
+            NumberInputFormat::Standard,
+            NumberInputLocale::default(),
+            None,
+        )
+        .sortable(true);
+        let text_key = TableColumn::new("id_text", "ID", |item: &TestData| item.id)
+            .sortable(true)
+            .sort_by_key(|item: &TestData| item.id.to_string());
+        let unsortable = TableColumn::new("name", "Name", |item: &TestData| item.name.clone())
+            .sort_by_key(|item: &TestData| item.name.clone());
+        let no_accessor = TableColumn::new("raw", "Raw", |item: &TestData| item.id).sortable(true);
+
+        let rows = sample_rows();
+        let ids = |order: Vec<usize>| order.iter().map(|&i| rows[i].id).collect::<Vec<_>>();
+
+        // Numeric columns compare values, so 2 < 10 < 33
+        let cmp = numeric.comparator();
+        assert_eq!(
+            ids(sort_order(&rows, cmp.as_ref(), SortDirection::Ascending)),
+            vec![2, 10, 33]
+        );
+        // A string key sorts lexically: "10" < "2" < "33"
+        let cmp = text_key.comparator();
+        assert_eq!(
+            ids(sort_order(&rows, cmp.as_ref(), SortDirection::Ascending)),
+            vec![10, 2, 33]
+        );
+        assert!(unsortable.comparator().is_none());
+        assert!(no_accessor.comparator().is_none());
+
+        let columns = vec![numeric, unsortable];
+        assert!(column_comparator(&columns, Some("id")).is_some());
+        assert!(column_comparator(&columns, Some("name")).is_none());
+        assert!(column_comparator(&columns, None).is_none());
+    }
+
+    #[test]
+    fn test_sortable_without_comparator_sorts_only_server_side() {
+        let no_accessor = TableColumn::new("raw", "Raw", |item: &TestData| item.id).sortable(true);
+        assert!(!no_accessor.sorts(false));
+        assert!(no_accessor.sorts(true));
+
+        let keyed = no_accessor.sort_by_key(|item: &TestData| item.id);
+        assert!(keyed.sorts(false));
+
+        let unsortable = TableColumn::new("name", "Name", |item: &TestData| item.name.clone())
+            .sort_by_key(|item: &TestData| item.name.clone());
+        assert!(!unsortable.sorts(false));
+        assert!(!unsortable.sorts(true));
+    }
+
+    #[test]
+    fn test_sort_order_directions() {
+        let rows = sample_rows();
+        let column = TableColumn::new("name", "Name", |item: &TestData| item.name.clone())
+            .sortable(true)
+            .sort_by_key(|item: &TestData| item.name.clone());
+        let cmp = column.comparator();
+
+        assert_eq!(
+            sort_order(&rows, cmp.as_ref(), SortDirection::Ascending),
+            vec![2, 0, 1]
+        );
+        assert_eq!(
+            sort_order(&rows, cmp.as_ref(), SortDirection::Descending),
+            vec![1, 0, 2]
+        );
+        assert_eq!(
+            sort_order(&rows, cmp.as_ref(), SortDirection::None),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            sort_order(&rows, None, SortDirection::Ascending),
+            vec![0, 1, 2]
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
 use leptos::prelude::*;
+use std::sync::Arc;
+use wasm_bindgen::JsCast;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TabsVariant {
@@ -15,12 +17,113 @@ pub enum TabsOrientation {
     Vertical,
 }
 
+/// When a [`TabsPanel`] mounts its content. The default mounts every
+/// panel up front and keeps inactive ones hidden in the DOM.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PanelMountPolicy {
+    /// Defer mounting until the panel's tab is first activated
+    pub lazy: bool,
+    /// Keep an already-mounted panel in the DOM (hidden) while inactive;
+    /// when `false` it is unmounted and rebuilt on the next activation
+    pub keep_mounted: bool,
+}
+
+impl Default for PanelMountPolicy {
+    fn default() -> Self {
+        Self {
+            lazy: false,
+            keep_mounted: true,
+        }
+    }
+}
+
+impl PanelMountPolicy {
+    /// Whether the content is in the DOM given the panel is `active` now
+    /// and whether it has `activated` at some point
+    pub fn is_mounted(&self, active: bool, activated: bool) -> bool {
+        active || (self.keep_mounted && (activated || !self.lazy))
+    }
+}
+
+/// Reactive renderer for a panel's content: calls `factory` only while
+/// `policy` says the panel is mounted, so a lazy panel builds nothing (and
+/// creates none of its signals) until its first activation.
+fn panel_content<T>(
+    active: Signal<bool>,
+    policy: PanelMountPolicy,
+    factory: impl Fn() -> T + Send + Sync + 'static,
+) -> impl Fn() -> Option<T> + Send + Sync + 'static {
+    let activated =
+        Memo::new(move |prev: Option<&bool>| prev.copied().unwrap_or(false) || active.get());
+    let mounted = Memo::new(move |_| policy.is_mounted(active.get(), activated.get()));
+    move || mounted.get().then(&factory)
+}
+
+/// Index of the tab that should receive focus after `key` is pressed on
+/// tab `current`, following the ARIA tabs pattern. Arrow keys wrap around;
+/// Home and End jump to the first and last tab.
+pub fn tab_focus_target(
+    current: usize,
+    count: usize,
+    key: &str,
+    orientation: TabsOrientation,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let current = current.min(count - 1);
+    let (prev_key, next_key) = match orientation {
+        TabsOrientation::Horizontal => ("ArrowLeft", "ArrowRight"),
+        TabsOrientation::Vertical => ("ArrowUp", "ArrowDown"),
+    };
+    match key {
+        k if k == next_key => Some((current + 1) % count),
+        k if k == prev_key => Some((current + count - 1) % count),
+        "Home" => Some(0),
+        "End" => Some(count - 1),
+        _ => None,
+    }
+}
+
+/// Value that should be active after closing tab `closed`. Closing an
+/// inactive tab keeps `active`; closing the active tab moves to the tab
+/// after it, or the one before it when it was last. `None` when no tab
+/// is left.
+pub fn tab_after_close(values: &[String], closed: &str, active: &str) -> Option<String> {
+    if closed != active {
+        return Some(active.to_string());
+    }
+    let index = values.iter().position(|v| v == closed)?;
+    values
+        .get(index + 1)
+        .or_else(|| index.checked_sub(1).and_then(|i| values.get(i)))
+        .cloned()
+}
+
+/// `role="tab"` elements inside `list`, in document order
+fn tab_elements(list: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = list.query_selector_all("[role='tab']") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
 #[component]
 pub fn Tabs(
     #[prop(into)] active: RwSignal<String>,
     #[prop(optional)] variant: Option<TabsVariant>,
     #[prop(optional)] orientation: Option<TabsOrientation>,
     #[prop(optional)] grow: bool,
+    /// Mount each panel's content only when its tab is first activated
+    #[prop(optional)]
+    lazy: bool,
+    /// Keep activated panels in the DOM while inactive (default `true`);
+    /// `false` unmounts them
+    #[prop(optional)]
+    keep_mounted: Option<bool>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
@@ -28,6 +131,11 @@ pub fn Tabs(
     let variant = variant.unwrap_or(TabsVariant::Default);
     let orientation = orientation.unwrap_or(TabsOrientation::Horizontal);
 
+    provide_context(PanelMountPolicy {
+        lazy,
+        keep_mounted: keep_mounted.unwrap_or(true),
+    });
+
     // Provide context
     provide_context::<RwSignal<String>>(active);
     provide_context::<Signal<TabsVariant>>(Signal::derive(move || variant));
@@ -126,10 +234,44 @@ pub fn TabsList(
         builder.build()
     };
 
+    let list_ref = NodeRef::<leptos::html::Div>::new();
+
+    // Arrow keys move focus between tabs; Enter/Space activate the
+    // focused tab through the button's own click
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        let Some(list) = list_ref.get() else {
+            return;
+        };
+        let tabs = tab_elements(&list);
+        let focused = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.active_element());
+        let Some(current) = focused.and_then(|el| {
+            tabs.iter()
+                .position(|tab| AsRef::<web_sys::Element>::as_ref(tab) == &el)
+        }) else {
+            return;
+        };
+        if let Some(target) = tab_focus_target(current, tabs.len(), &ev.key(), orientation.get()) {
+            ev.prevent_default();
+            let _ = tabs[target].focus();
+        }
+    };
+
     let class_str = format!("mingot-tabs-list {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=list_styles>
+        <div
+            node_ref=list_ref
+            class=class_str
+            style=list_styles
+            role="tablist"
+            aria-orientation=move || match orientation.get() {
+                TabsOrientation::Horizontal => "horizontal",
+                TabsOrientation::Vertical => "vertical",
+            }
+            on:keydown=handle_keydown
+        >
             {children()}
         </div>
     }
@@ -139,12 +281,20 @@ pub fn TabsList(
 pub fn TabsTab(
     #[prop(into)] value: String,
     #[prop(optional, into)] icon: Option<String>,
+    /// Render a × button (and accept Delete) that closes this tab
+    #[prop(optional)]
+    closeable: bool,
+    /// Called with the tab value when it is closed. If it was the active
+    /// tab, a neighbour is activated first.
+    #[prop(optional)]
+    on_close: Option<Callback<String>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     children: Children,
 ) -> impl IntoView {
     let theme = use_theme();
     let active = use_context::<RwSignal<String>>().unwrap();
+    let tab_ref = NodeRef::<leptos::html::Button>::new();
     let variant = use_context::<Signal<TabsVariant>>()
         .unwrap_or(Signal::derive(move || TabsVariant::Default));
     let grow = use_context::<Signal<bool>>().unwrap_or(Signal::derive(move || false));
@@ -240,16 +390,74 @@ pub fn TabsTab(
         builder.build()
     };
 
+    let value = StoredValue::new(value);
+
     let handle_click = move |_| {
-        active.set(value.clone());
+        active.set(value.get_value());
     };
 
+    let close = move || {
+        let closed = value.get_value();
+        let values = tab_ref
+            .get()
+            .and_then(|tab| tab.parent_element())
+            .map(|list| {
+                tab_elements(&list)
+                    .iter()
+                    .filter_map(|el| el.get_attribute("data-value"))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if let Some(next) = tab_after_close(&values, &closed, &active.get_untracked()) {
+            if next != active.get_untracked() {
+                active.set(next);
+            }
+        }
+        if let Some(callback) = on_close {
+            callback.run(closed);
+        }
+    };
+
+    let handle_keydown = move |ev: web_sys::KeyboardEvent| {
+        if closeable && ev.key() == "Delete" {
+            ev.prevent_default();
+            close();
+        }
+    };
+
+    let is_selected = move || active.get() == value.get_value();
     let class_str = format!("mingot-tabs-tab {}", class.unwrap_or_default());
 
     view! {
-        <button class=class_str style=tab_styles on:click=handle_click>
+        <button
+            node_ref=tab_ref
+            class=class_str
+            style=tab_styles
+            role="tab"
+            data-value=value.get_value()
+            aria-selected=move || is_selected().to_string()
+            tabindex=move || if is_selected() { "0" } else { "-1" }
+            on:click=handle_click
+            on:keydown=handle_keydown
+        >
             {icon.map(|i| view! { <span>{i}</span> })}
             <span>{children()}</span>
+            {closeable
+                .then(|| {
+                    view! {
+                        <span
+                            class="mingot-tabs-tab-close"
+                            aria-label="Close tab"
+                            style="display: inline-flex; margin-left: 0.25rem; opacity: 0.6; line-height: 1;"
+                            on:click=move |ev| {
+                                ev.stop_propagation();
+                                close();
+                            }
+                        >
+                            "×"
+                        </span>
+                    }
+                })}
         </button>
     }
 }
@@ -258,15 +466,29 @@ pub fn TabsTab(
 pub fn TabsPanel(
     #[prop(into)] value: String,
     #[prop(optional, into)] padding: Option<String>,
+    /// Overrides the `lazy` setting of the surrounding `Tabs`
+    #[prop(optional)]
+    lazy: Option<bool>,
+    /// Overrides the `keep_mounted` setting of the surrounding `Tabs`
+    #[prop(optional)]
+    keep_mounted: Option<bool>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
-    children: Children,
+    children: ChildrenFn,
 ) -> impl IntoView {
     let theme = use_theme();
     let active = use_context::<RwSignal<String>>().unwrap();
+    let inherited = use_context::<PanelMountPolicy>().unwrap_or_default();
+    let policy = PanelMountPolicy {
+        lazy: lazy.unwrap_or(inherited.lazy),
+        keep_mounted: keep_mounted.unwrap_or(inherited.keep_mounted),
+    };
 
     let value_clone = value.clone();
     let is_active = move || active.get() == value_clone;
+    let content = panel_content(Signal::derive(is_active.clone()), policy, move || {
+        children()
+    });
 
     let panel_styles = move || {
         let theme_val = theme.get();
@@ -292,8 +514,227 @@ pub fn TabsPanel(
     let class_str = format!("mingot-tabs-panel {}", class.unwrap_or_default());
 
     view! {
-        <div class=class_str style=panel_styles>
-            {children()}
+        <div class=class_str style=panel_styles role="tabpanel">
+            {content}
         </div>
     }
 }
+
+/// One tab for [`DataTabs`]: its value, label and panel content
+#[derive(Clone)]
+pub struct TabItem {
+    pub value: String,
+    pub label: String,
+    pub icon: Option<String>,
+    pub content: Arc<dyn Fn() -> AnyView + Send + Sync>,
+}
+
+impl TabItem {
+    pub fn new<IV>(
+        value: impl Into<String>,
+        label: impl Into<String>,
+        content: impl Fn() -> IV + Send + Sync + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+            content: Arc::new(move || content().into_any()),
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+/// `Tabs` built from a list of [`TabItem`]s instead of children, for tabs
+/// that come from a data model. Use `Tabs` with `TabsTab`/`TabsPanel`
+/// children when a tab needs custom markup.
+///
+/// # Example
+/// ```rust,ignore
+/// let items = (1..=3)
+///     .map(|i| TabItem::new(format!("tab-{i}"), format!("Tab {i}"), move || format!("Panel {i}")))
+///     .collect::<Vec<_>>();
+///
+/// view! { <DataTabs items=items /> }
+/// ```
+#[component]
+pub fn DataTabs(
+    items: Vec<TabItem>,
+    /// Active tab value; defaults to the first item
+    #[prop(optional)]
+    active: Option<RwSignal<String>>,
+    #[prop(optional)] variant: Option<TabsVariant>,
+    #[prop(optional)] orientation: Option<TabsOrientation>,
+    #[prop(optional)] grow: bool,
+    /// Build each panel's content on first activation
+    #[prop(optional)]
+    lazy: bool,
+    /// Keep activated panels in the DOM while inactive (default `true`)
+    #[prop(optional)]
+    keep_mounted: Option<bool>,
+    /// Padding for every panel
+    #[prop(optional, into)]
+    panel_padding: Option<String>,
+    #[prop(optional, into)] class: Option<String>,
+    #[prop(optional, into)] style: Option<String>,
+) -> impl IntoView {
+    let active = active.unwrap_or_else(|| {
+        RwSignal::new(
+            items
+                .first()
+                .map(|item| item.value.clone())
+                .unwrap_or_default(),
+        )
+    });
+
+    let tabs = items
+        .iter()
+        .map(|item| {
+            let label = item.label.clone();
+            match item.icon.clone() {
+                Some(icon) => view! {
+                    <TabsTab value=item.value.clone() icon=icon>{label}</TabsTab>
+                }
+                .into_any(),
+                None => view! { <TabsTab value=item.value.clone()>{label}</TabsTab> }.into_any(),
+            }
+        })
+        .collect_view();
+
+    let panels = items
+        .into_iter()
+        .map(|item| {
+            let factory = StoredValue::new(item.content);
+            let content = move || factory.with_value(|f| f());
+            match panel_padding.clone() {
+                Some(padding) => view! {
+                    <TabsPanel value=item.value padding=padding>{content}</TabsPanel>
+                }
+                .into_any(),
+                None => view! { <TabsPanel value=item.value>{content}</TabsPanel> }.into_any(),
+            }
+        })
+        .collect_view();
+
+    view! {
+        <Tabs
+            active=active
+            variant=variant.unwrap_or(TabsVariant::Default)
+            orientation=orientation.unwrap_or(TabsOrientation::Horizontal)
+            grow=grow
+            lazy=lazy
+            keep_mounted=keep_mounted.unwrap_or(true)
+            class=class.unwrap_or_default()
+            style=style.unwrap_or_default()
+        >
+            <TabsList>{tabs}</TabsList>
+            {panels}
+        </Tabs>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_item_builder() {
+        let item = TabItem::new("settings", "Settings", || "Panel").icon("⚙");
+        assert_eq!(item.value, "settings");
+        assert_eq!(item.label, "Settings");
+        assert_eq!(item.icon.as_deref(), Some("⚙"));
+    }
+
+    #[test]
+    fn test_panel_mount_policy() {
+        let eager = PanelMountPolicy::default();
+        assert!(eager.is_mounted(false, false));
+        assert!(eager.is_mounted(true, true));
+
+        let lazy = PanelMountPolicy {
+            lazy: true,
+            keep_mounted: true,
+        };
+        assert!(!lazy.is_mounted(false, false));
+        assert!(lazy.is_mounted(true, true));
+        assert!(lazy.is_mounted(false, true));
+
+        let unmount = PanelMountPolicy {
+            lazy: true,
+            keep_mounted: false,
+        };
+        assert!(!unmount.is_mounted(false, true));
+        assert!(unmount.is_mounted(true, true));
+    }
+
+    #[test]
+    fn test_lazy_panel_factory_waits_for_activation() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let _owner = Owner::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let active = RwSignal::new(false);
+        let policy = PanelMountPolicy {
+            lazy: true,
+            keep_mounted: true,
+        };
+        let counter = calls.clone();
+        let render = panel_content(active.into(), policy, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(render().is_none());
+        assert!(render().is_none());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        active.set(true);
+        assert!(render().is_some());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Still mounted after deactivation
+        active.set(false);
+        assert!(render().is_some());
+    }
+
+    #[test]
+    fn test_tab_focus_target_horizontal_wraps() {
+        let h = TabsOrientation::Horizontal;
+        assert_eq!(tab_focus_target(0, 3, "ArrowRight", h), Some(1));
+        assert_eq!(tab_focus_target(2, 3, "ArrowRight", h), Some(0));
+        assert_eq!(tab_focus_target(0, 3, "ArrowLeft", h), Some(2));
+        assert_eq!(tab_focus_target(1, 3, "Home", h), Some(0));
+        assert_eq!(tab_focus_target(1, 3, "End", h), Some(2));
+        // Vertical keys do nothing in a horizontal list
+        assert_eq!(tab_focus_target(1, 3, "ArrowDown", h), None);
+        assert_eq!(tab_focus_target(0, 0, "ArrowRight", h), None);
+    }
+
+    #[test]
+    fn test_tab_focus_target_vertical() {
+        let v = TabsOrientation::Vertical;
+        assert_eq!(tab_focus_target(0, 4, "ArrowDown", v), Some(1));
+        assert_eq!(tab_focus_target(0, 4, "ArrowUp", v), Some(3));
+        assert_eq!(tab_focus_target(0, 4, "ArrowRight", v), None);
+    }
+
+    #[test]
+    fn test_tab_after_close() {
+        let values = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        // Closing an inactive tab keeps the current one
+        assert_eq!(tab_after_close(&values, "a", "b").as_deref(), Some("b"));
+        // Closing the active tab moves right, or left at the end
+        assert_eq!(tab_after_close(&values, "b", "b").as_deref(), Some("c"));
+        assert_eq!(tab_after_close(&values, "c", "c").as_deref(), Some("b"));
+        assert_eq!(tab_after_close(&values, "a", "a").as_deref(), Some("b"));
+        // Nothing left to activate
+        let single = vec!["only".to_string()];
+        assert_eq!(tab_after_close(&single, "only", "only"), None);
+    }
+}
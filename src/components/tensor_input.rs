@@ -1,12 +1,258 @@
 //! Tensor input component for multi-dimensional array entry.
 //!
 //! Supports arbitrary-rank tensors with slice navigation and
-//! shape manipulation.
+//! shape manipulation, and NumPy `.npy` import/export.
 
 use crate::components::input::InputSize;
 use crate::theme::use_theme;
 use crate::utils::StyleBuilder;
+use leptos::ev;
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// `.npy` files start with these bytes
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Why a `.npy` file could not be read
+#[derive(Clone, Debug, PartialEq)]
+pub enum NpyError {
+    /// The bytes don't start with the `.npy` magic string
+    NotNpy,
+    /// Format versions other than 1.0, 2.0 and 3.0
+    UnsupportedVersion { major: u8, minor: u8 },
+    /// The header dictionary is missing or malformed
+    InvalidHeader(String),
+    /// Anything but little-endian `f8`/`f4`, e.g. object (pickled) arrays
+    UnsupportedDtype(String),
+    /// Fortran-ordered (column-major) arrays
+    FortranOrder,
+    /// The data section doesn't match the header's shape and dtype
+    DataLength { expected: usize, actual: usize },
+    /// The header's shape describes more bytes than fit in memory
+    TooLarge,
+}
+
+impl std::fmt::Display for NpyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotNpy => write!(f, "Not a .npy file"),
+            Self::UnsupportedVersion { major, minor } => {
+                write!(f, "Unsupported .npy format version {}.{}", major, minor)
+            }
+            Self::InvalidHeader(reason) => write!(f, "Invalid .npy header: {}", reason),
+            Self::UnsupportedDtype(descr) if descr.contains('O') => write!(
+                f,
+                "Object arrays ({}) hold pickled Python objects and can't be loaded; save a float array instead",
+                descr
+            ),
+            Self::UnsupportedDtype(descr) => write!(
+                f,
+                "Unsupported dtype {}; only little-endian float64 (<f8) and float32 (<f4) are supported",
+                descr
+            ),
+            Self::FortranOrder => write!(
+                f,
+                "Fortran-ordered arrays are not supported; save with np.ascontiguousarray"
+            ),
+            Self::DataLength { expected, actual } => write!(
+                f,
+                "Expected {} bytes of data, found {}",
+                expected, actual
+            ),
+            Self::TooLarge => write!(f, "Array shape is too large to load"),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {}
+
+/// Element types that can be read from `.npy`
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NpyDtype {
+    F64,
+    F32,
+}
+
+impl NpyDtype {
+    fn parse(descr: &str) -> Result<Self, NpyError> {
+        match descr {
+            "<f8" => Ok(Self::F64),
+            "<f4" => Ok(Self::F32),
+            _ => Err(NpyError::UnsupportedDtype(descr.to_string())),
+        }
+    }
+
+    fn item_size(self) -> usize {
+        match self {
+            Self::F64 => 8,
+            Self::F32 => 4,
+        }
+    }
+}
+
+/// Text following `'key':` in a header dictionary
+fn npy_header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let missing = || NpyError::InvalidHeader(format!("missing '{}'", key));
+    let start = header
+        .find(&format!("'{}'", key))
+        .or_else(|| header.find(&format!("\"{}\"", key)))
+        .ok_or_else(missing)?;
+    header[start + key.len() + 2..]
+        .trim_start()
+        .strip_prefix(':')
+        .map(str::trim_start)
+        .ok_or_else(missing)
+}
+
+/// Shape, dtype and data offset from the start of a `.npy` file
+fn parse_npy_header(bytes: &[u8]) -> Result<(Vec<usize>, NpyDtype, usize), NpyError> {
+    if !bytes.starts_with(NPY_MAGIC) || bytes.len() < 10 {
+        return Err(NpyError::NotNpy);
+    }
+    let (major, minor) = (bytes[6], bytes[7]);
+    let (header_len, header_start) = match major {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (
+            u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            12,
+        ),
+        _ => return Err(NpyError::UnsupportedVersion { major, minor }),
+    };
+    let data_start = header_start + header_len;
+    let header = bytes
+        .get(header_start..data_start)
+        .and_then(|h| std::str::from_utf8(h).ok())
+        .ok_or_else(|| NpyError::InvalidHeader("truncated header".to_string()))?;
+
+    // 'descr' is a quoted type string; structured dtypes are a list instead
+    let descr = npy_header_value(header, "descr")?;
+    let quote = descr.chars().next().filter(|c| *c == '\'' || *c == '"');
+    let dtype = match quote {
+        Some(q) => {
+            let end = descr[1..]
+                .find(q)
+                .ok_or_else(|| NpyError::InvalidHeader("unterminated 'descr'".to_string()))?;
+            NpyDtype::parse(&descr[1..=end])?
+        }
+        None => {
+            let token = descr.split(['}', ',']).next().unwrap_or(descr);
+            return Err(NpyError::UnsupportedDtype(token.trim().to_string()));
+        }
+    };
+
+    let fortran = npy_header_value(header, "fortran_order")?;
+    if fortran.starts_with("True") {
+        return Err(NpyError::FortranOrder);
+    } else if !fortran.starts_with("False") {
+        return Err(NpyError::InvalidHeader("bad 'fortran_order'".to_string()));
+    }
+
+    let shape_text = npy_header_value(header, "shape")?;
+    let bad_shape = || NpyError::InvalidHeader("bad 'shape'".to_string());
+    let end = shape_text.find(')').ok_or_else(bad_shape)?;
+    let shape = shape_text.strip_prefix('(').ok_or_else(bad_shape)?[..end - 1]
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.trim_end_matches('L')
+                .parse::<usize>()
+                .map_err(|_| bad_shape())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((shape, dtype, data_start))
+}
+
+/// A version 1.0 `.npy` file: magic, header dictionary padded to a
+/// 64-byte boundary, then the raw data
+/// Move a slice position by `delta`, wrapping around a dimension of
+/// length `len`. An empty dimension has no positions, so it stays at 0.
+fn step_slice_index(current: usize, delta: i32, len: usize) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (current as i64 + delta as i64).rem_euclid(len as i64) as usize
+}
+
+fn write_npy(header: &str, data: &[u8]) -> Vec<u8> {
+    let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+    let padding = (64 - unpadded % 64) % 64;
+    let header_len = (header.len() + padding + 1) as u16;
+
+    let mut bytes = Vec::with_capacity(unpadded + padding + data.len());
+    bytes.extend_from_slice(NPY_MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&header_len.to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend(std::iter::repeat_n(b' ', padding));
+    bytes.push(b'\n');
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Why a requested shape can't be applied
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReshapeError {
+    /// No dimensions were given
+    Empty,
+    /// A dimension that isn't a positive integer or `-1`
+    InvalidDimension(String),
+    /// More than one `-1`
+    MultipleInferred,
+    /// The known dimensions don't divide the element count
+    CannotInfer { known: usize, size: usize },
+    /// The dimensions multiply to a different element count
+    SizeMismatch { product: usize, size: usize },
+    /// The dimensions multiply past the largest possible element count
+    TooLarge,
+}
+
+impl std::fmt::Display for ReshapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Enter at least one dimension"),
+            Self::InvalidDimension(dim) => write!(f, "\"{}\" is not a valid dimension", dim),
+            Self::MultipleInferred => write!(f, "Only one dimension can be -1"),
+            Self::CannotInfer { known, size } => {
+                write!(
+                    f,
+                    "{} elements can't be split into groups of {}",
+                    size, known
+                )
+            }
+            Self::SizeMismatch { product, size } => {
+                write!(f, "{} ≠ {} elements", product, size)
+            }
+            Self::TooLarge => write!(f, "Shape is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ReshapeError {}
+
+/// Parse a comma-separated shape such as `2, 2, 6` or `(4, -1)`.
+/// `-1` marks a dimension to infer with [`Tensor::resolve_shape`].
+pub fn parse_shape(text: &str) -> Result<Vec<isize>, ReshapeError> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix('(')
+        .and_then(|t| t.strip_suffix(')'))
+        .unwrap_or(text);
+    let dims = text
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| match dim.parse::<isize>() {
+            Ok(n) if n >= 1 || n == -1 => Ok(n),
+            _ => Err(ReshapeError::InvalidDimension(dim.to_string())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if dims.is_empty() {
+        return Err(ReshapeError::Empty);
+    }
+    Ok(dims)
+}
 
 /// Represents a multi-dimensional tensor
 #[derive(Clone, Debug, PartialEq)]
@@ -174,6 +420,53 @@ impl Tensor {
         true
     }
 
+    /// Resolve a shape for this tensor's element count, inferring a single
+    /// `-1` dimension like NumPy's `reshape`
+    pub fn resolve_shape(&self, dims: &[isize]) -> Result<Vec<usize>, ReshapeError> {
+        if dims.is_empty() {
+            return Err(ReshapeError::Empty);
+        }
+        if let Some(&bad) = dims.iter().find(|&&d| d == 0 || d < -1) {
+            return Err(ReshapeError::InvalidDimension(bad.to_string()));
+        }
+        if dims.iter().filter(|&&d| d == -1).count() > 1 {
+            return Err(ReshapeError::MultipleInferred);
+        }
+        let known = dims
+            .iter()
+            .filter(|&&d| d > 0)
+            .try_fold(1usize, |acc, &d| acc.checked_mul(d as usize))
+            .ok_or(ReshapeError::TooLarge)?;
+        let inferred = if dims.contains(&-1) {
+            if known == 0 || !self.size.is_multiple_of(known) {
+                return Err(ReshapeError::CannotInfer {
+                    known,
+                    size: self.size,
+                });
+            }
+            self.size / known
+        } else if known != self.size {
+            return Err(ReshapeError::SizeMismatch {
+                product: known,
+                size: self.size,
+            });
+        } else {
+            0
+        };
+        Ok(dims
+            .iter()
+            .map(|&d| if d == -1 { inferred } else { d as usize })
+            .collect())
+    }
+
+    /// Reshape to a shape like `"2, 2, 6"` or `"4, -1"`, keeping the flat
+    /// data. The tensor is unchanged on error.
+    pub fn try_reshape(&mut self, shape: &str) -> Result<(), ReshapeError> {
+        let new_shape = self.resolve_shape(&parse_shape(shape)?)?;
+        self.shape = new_shape;
+        Ok(())
+    }
+
     /// Transpose (swap last two dimensions)
     pub fn transpose(&self) -> Option<Tensor> {
         if self.rank() < 2 {
@@ -235,6 +528,59 @@ impl Tensor {
         self.data.iter().copied().reduce(f64::max)
     }
 
+    /// Read a NumPy `.npy` file. Supports little-endian `float64` and
+    /// `float32` arrays in C (row-major) order; `float32` values are widened.
+    pub fn from_npy_bytes(bytes: &[u8]) -> Result<Tensor, NpyError> {
+        let (shape, dtype, data_start) = parse_npy_header(bytes)?;
+        // The shape comes from the file, so guard against overflow
+        let size = shape
+            .iter()
+            .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+            .ok_or(NpyError::TooLarge)?;
+        let expected = size
+            .checked_mul(dtype.item_size())
+            .ok_or(NpyError::TooLarge)?;
+        let payload = &bytes[data_start.min(bytes.len())..];
+        if payload.len() != expected {
+            return Err(NpyError::DataLength {
+                expected,
+                actual: payload.len(),
+            });
+        }
+
+        let data = match dtype {
+            NpyDtype::F64 => payload
+                .chunks_exact(8)
+                .map(|b| f64::from_le_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+                .collect(),
+            NpyDtype::F32 => payload
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64)
+                .collect(),
+        };
+        Ok(Self { data, shape, size })
+    }
+
+    /// Write as a NumPy `.npy` file (`<f8`, C order), readable with `np.load`
+    pub fn to_npy_bytes(&self) -> Vec<u8> {
+        let shape = match self.shape.as_slice() {
+            [n] => format!("({},)", n),
+            dims => format!(
+                "({})",
+                dims.iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}",
+            shape
+        );
+        let data: Vec<u8> = self.data.iter().flat_map(|v| v.to_le_bytes()).collect();
+        write_npy(&header, &data)
+    }
+
     /// Format shape as string
     pub fn shape_string(&self) -> String {
         format!(
@@ -258,6 +604,47 @@ fn format_number(value: f64) -> String {
     }
 }
 
+/// Read a dropped or chosen file and pass its bytes to `on_load`
+fn read_file_bytes(file: &web_sys::File, on_load: impl FnOnce(Vec<u8>) + 'static) {
+    let Ok(reader) = web_sys::FileReader::new() else {
+        return;
+    };
+    let loaded = reader.clone();
+    let callback = wasm_bindgen::closure::Closure::once_into_js(move || {
+        if let Ok(result) = loaded.result() {
+            on_load(js_sys::Uint8Array::new(&result).to_vec());
+        }
+    });
+    reader.set_onload(Some(callback.unchecked_ref()));
+    let _ = reader.read_as_array_buffer(file);
+}
+
+/// Offer `bytes` as a file download
+fn download_bytes(bytes: &[u8], file_name: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return;
+    };
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/octet-stream");
+    let Ok(blob) = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+    if let Some(link) = document
+        .create_element("a")
+        .ok()
+        .and_then(|el| el.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+    {
+        link.set_href(&url);
+        link.set_download(file_name);
+        link.click();
+    }
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
+
 /// Tensor input component
 #[component]
 pub fn TensorInput(
@@ -300,6 +687,24 @@ pub fn TensorInput(
     /// Whether the input is disabled
     #[prop(optional)]
     disabled: Signal<bool>,
+
+    /// Show a drop zone that loads NumPy `.npy` files (reshaping the
+    /// tensor to the file's shape) and a button that downloads one
+    #[prop(optional, default = false)]
+    enable_npy: bool,
+
+    /// File name offered when downloading `.npy`
+    #[prop(optional, into, default = "tensor.npy".to_string())]
+    npy_file_name: String,
+
+    /// Called when a dropped file can't be loaded
+    #[prop(optional, into)]
+    on_npy_error: Option<Callback<NpyError>>,
+
+    /// Show a shape editor (e.g. `2, 2, 6` or `4, -1`) that reshapes the
+    /// tensor in place once the element count matches
+    #[prop(optional, default = false)]
+    allow_reshape: bool,
 ) -> impl IntoView {
     let theme = use_theme();
 
@@ -343,7 +748,8 @@ pub fn TensorInput(
 
         let full_indices: Vec<usize> = if tensor.rank() <= 2 {
             if tensor.rank() == 1 {
-                vec![col]
+                // 1D tensors are shown as a single column
+                vec![row]
             } else {
                 vec![row, col]
             }
@@ -362,16 +768,105 @@ pub fn TensorInput(
         }
     };
 
+    // Load a `.npy` file, replacing the tensor and its shape
+    let npy_error = RwSignal::new(None::<String>);
+    let drag_over = RwSignal::new(false);
+    let load_npy = move |file: web_sys::File| {
+        let name = file.name();
+        read_file_bytes(&file, move |bytes| match Tensor::from_npy_bytes(&bytes) {
+            Ok(tensor) => {
+                npy_error.set(None);
+                slice_indices.set(vec![0; tensor.rank().saturating_sub(2)]);
+                internal_tensor.set(tensor.clone());
+                if let Some(cb) = on_change {
+                    cb.run(tensor);
+                }
+            }
+            Err(e) => {
+                npy_error.set(Some(format!("{}: {}", name, e)));
+                if let Some(cb) = on_npy_error {
+                    cb.run(e);
+                }
+            }
+        });
+    };
+
+    let handle_drop = move |ev: ev::DragEvent| {
+        ev.prevent_default();
+        drag_over.set(false);
+        if disabled.get_untracked() {
+            return;
+        }
+        if let Some(file) = ev
+            .data_transfer()
+            .and_then(|dt| dt.files())
+            .and_then(|files| files.get(0))
+        {
+            load_npy(file);
+        }
+    };
+
+    let handle_file_change = move |ev: ev::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        if let Some(file) = input.files().and_then(|files| files.get(0)) {
+            load_npy(file);
+        }
+        // Allow choosing the same file again
+        input.set_value("");
+    };
+
+    let npy_file_name = StoredValue::new(npy_file_name);
+    let download_npy = move |_| {
+        let bytes = internal_tensor.with_untracked(Tensor::to_npy_bytes);
+        npy_file_name.with_value(|name| download_bytes(&bytes, name));
+    };
+
+    // Shape editor text; follows the tensor's shape after each change
+    let shape_text = RwSignal::new(String::new());
+    Effect::new(move |_| {
+        let shape = internal_tensor.with(|t| t.shape().to_vec());
+        shape_text.set(
+            shape
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    });
+    let reshape_check = move || {
+        shape_text.with(|text| {
+            internal_tensor.with(|t| parse_shape(text).and_then(|dims| t.resolve_shape(&dims)))
+        })
+    };
+
+    let apply_reshape = move || {
+        let Ok(new_shape) = reshape_check() else {
+            return;
+        };
+        let mut tensor = internal_tensor.get_untracked();
+        if tensor.shape() == new_shape.as_slice() || !tensor.reshape(new_shape) {
+            return;
+        }
+        // Slice positions from the old shape may be out of range
+        slice_indices.set(vec![0; tensor.rank().saturating_sub(2)]);
+        internal_tensor.set(tensor.clone());
+        if let Some(cb) = on_change {
+            cb.run(tensor);
+        }
+    };
+
     // Navigate slice
     let change_slice_index = move |dim: usize, delta: i32| {
         let tensor = internal_tensor.get();
         let mut indices = slice_indices.get();
 
         if dim < indices.len() {
-            let max_val = tensor.shape()[dim];
-            let current = indices[dim] as i32;
-            let new_val = (current + delta).rem_euclid(max_val as i32) as usize;
-            indices[dim] = new_val;
+            indices[dim] = step_slice_index(indices[dim], delta, tensor.shape()[dim]);
             slice_indices.set(indices);
         }
     };
@@ -508,6 +1003,29 @@ pub fn TensorInput(
             .build()
     };
 
+    let drop_zone_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let border_color = if drag_over.get() {
+            scheme_colors
+                .get_color("blue", 6)
+                .unwrap_or_else(|| "#228be6".to_string())
+        } else {
+            scheme_colors.border.clone()
+        };
+        StyleBuilder::new()
+            .add("display", "flex")
+            .add("align-items", "center")
+            .add("justify-content", "space-between")
+            .add("gap", "0.5rem")
+            .add("padding", &*theme_val.spacing.sm)
+            .add("border", format!("1px dashed {}", border_color))
+            .add("border-radius", &*theme_val.radius.sm)
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .add("color", scheme_colors.text.clone())
+            .build()
+    };
+
     let description_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -553,6 +1071,40 @@ pub fn TensorInput(
                 }}
             </div>
 
+            {allow_reshape.then(|| {
+                view! {
+                    <div class="mingot-tensor-reshape" style=slice_control_styles>
+                        <span>{"Reshape:"}</span>
+                        <input
+                            type="text"
+                            style=move || format!("{}; width: 8rem; text-align: left;", cell_styles())
+                            aria-label="New shape"
+                            aria-invalid=move || reshape_check().is_err().to_string()
+                            prop:value=move || shape_text.get()
+                            disabled=disabled
+                            on:input=move |ev| shape_text.set(event_target_value(&ev))
+                            on:keydown=move |ev: ev::KeyboardEvent| {
+                                if ev.key() == "Enter" {
+                                    ev.prevent_default();
+                                    apply_reshape();
+                                }
+                            }
+                        />
+                        <button
+                            type="button"
+                            style=nav_button_styles
+                            disabled=move || disabled.get() || reshape_check().is_err()
+                            on:click=move |_| apply_reshape()
+                        >
+                            {"Apply"}
+                        </button>
+                        {move || reshape_check().err().map(|e| view! {
+                            <span style=error_styles role="alert">{e.to_string()}</span>
+                        })}
+                    </div>
+                }
+            })}
+
             // Slice navigation (for rank > 2)
             {move || {
                 let tensor = internal_tensor.get();
@@ -650,6 +1202,40 @@ pub fn TensorInput(
                 }
             })}
 
+            {enable_npy.then(|| {
+                view! {
+                    <div
+                        class="mingot-tensor-npy"
+                        style=drop_zone_styles
+                        on:dragover=move |ev: ev::DragEvent| {
+                            ev.prevent_default();
+                            drag_over.set(true);
+                        }
+                        on:dragleave=move |_| drag_over.set(false)
+                        on:drop=handle_drop
+                    >
+                        <label style="cursor: pointer;">
+                            {"Drop a .npy file here or "}
+                            <u>{"choose one"}</u>
+                            <input
+                                type="file"
+                                accept=".npy"
+                                style="display: none;"
+                                disabled=disabled
+                                on:change=handle_file_change
+                            />
+                        </label>
+                        <button type="button" style=nav_button_styles on:click=download_npy>
+                            {"Download .npy"}
+                        </button>
+                    </div>
+                }
+            })}
+
+            {move || npy_error.get().map(|e| view! {
+                <div style=error_styles role="alert">{e}</div>
+            })}
+
             {description.map(|d| view! {
                 <div style=description_styles>{d}</div>
             })}
@@ -756,6 +1342,241 @@ mod tests {
         assert_eq!(data[0], 12.0);
     }
 
+    #[test]
+    fn test_npy_round_trip_f64() {
+        let data: Vec<f64> = (0..24).map(|i| i as f64 * 0.5 - 3.0).collect();
+        let tensor = Tensor::from_data(data, vec![2, 3, 4]).unwrap();
+        let bytes = tensor.to_npy_bytes();
+
+        // Header is padded so the data starts on a 64-byte boundary
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert!(header.contains("'shape': (2, 3, 4)"));
+        assert!(header.ends_with('\n'));
+
+        let restored = Tensor::from_npy_bytes(&bytes).unwrap();
+        assert_eq!(restored, tensor);
+        assert_eq!(restored.get(&[1, 2, 3]), Some(8.5));
+    }
+
+    #[test]
+    fn test_npy_one_dimensional_shape() {
+        let tensor = Tensor::from_data(vec![1.0, 2.0], vec![2]).unwrap();
+        let bytes = tensor.to_npy_bytes();
+        let header = String::from_utf8_lossy(&bytes[10..64]);
+        assert!(header.contains("'shape': (2,)"));
+        assert_eq!(Tensor::from_npy_bytes(&bytes).unwrap(), tensor);
+    }
+
+    #[test]
+    fn test_npy_reads_f32() {
+        let data: Vec<u8> = [1.5f32, -2.0, 0.25, 4.0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let bytes = write_npy(
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (2, 2), }",
+            &data,
+        );
+        let tensor = Tensor::from_npy_bytes(&bytes).unwrap();
+        assert_eq!(tensor.shape(), &[2, 2]);
+        assert_eq!(tensor.get(&[1, 0]), Some(0.25));
+    }
+
+    #[test]
+    fn test_npy_rejects_object_array() {
+        // np.save of dtype=object writes a pickle after this header
+        let bytes = write_npy(
+            "{'descr': '|O', 'fortran_order': False, 'shape': (2,), }",
+            b"\x80\x02cnumpy.core.multiarray\n_reconstruct\n",
+        );
+        let err = Tensor::from_npy_bytes(&bytes).unwrap_err();
+        assert_eq!(err, NpyError::UnsupportedDtype("|O".to_string()));
+        assert!(err.to_string().contains("pickled"));
+    }
+
+    #[test]
+    fn test_npy_rejects_unsupported_layouts() {
+        let big_endian = write_npy(
+            "{'descr': '>f8', 'fortran_order': False, 'shape': (1,), }",
+            &[0; 8],
+        );
+        assert_eq!(
+            Tensor::from_npy_bytes(&big_endian),
+            Err(NpyError::UnsupportedDtype(">f8".to_string()))
+        );
+
+        let structured = write_npy(
+            "{'descr': [('x', '<f8')], 'fortran_order': False, 'shape': (1,), }",
+            &[0; 8],
+        );
+        assert!(matches!(
+            Tensor::from_npy_bytes(&structured),
+            Err(NpyError::UnsupportedDtype(_))
+        ));
+
+        let fortran = write_npy(
+            "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 2), }",
+            &[0; 32],
+        );
+        assert_eq!(
+            Tensor::from_npy_bytes(&fortran),
+            Err(NpyError::FortranOrder)
+        );
+    }
+
+    #[test]
+    fn test_npy_rejects_malformed_files() {
+        assert_eq!(Tensor::from_npy_bytes(b"a,b\n1,2"), Err(NpyError::NotNpy));
+
+        let truncated = write_npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }",
+            &[0; 24],
+        );
+        assert_eq!(
+            Tensor::from_npy_bytes(&truncated),
+            Err(NpyError::DataLength {
+                expected: 32,
+                actual: 24
+            })
+        );
+
+        let mut future = Tensor::zeros(vec![1]).to_npy_bytes();
+        future[6] = 9;
+        assert_eq!(
+            Tensor::from_npy_bytes(&future),
+            Err(NpyError::UnsupportedVersion { major: 9, minor: 0 })
+        );
+    }
+
+    #[test]
+    fn test_npy_rejects_overflowing_shape() {
+        // The shape's product overflows usize
+        let huge = write_npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296, 16), }",
+            &[0; 8],
+        );
+        assert_eq!(Tensor::from_npy_bytes(&huge), Err(NpyError::TooLarge));
+
+        // The element count fits, but not once multiplied by the item size
+        let bytes = write_npy(
+            &format!(
+                "{{'descr': '<f8', 'fortran_order': False, 'shape': ({},), }}",
+                usize::MAX / 4
+            ),
+            &[0; 8],
+        );
+        assert_eq!(Tensor::from_npy_bytes(&bytes), Err(NpyError::TooLarge));
+    }
+
+    #[test]
+    fn test_npy_with_empty_dimension() {
+        let bytes = write_npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (0, 3, 2), }",
+            &[],
+        );
+        let tensor = Tensor::from_npy_bytes(&bytes).unwrap();
+        assert_eq!(tensor.shape(), &[0, 3, 2]);
+        assert_eq!(tensor.size(), 0);
+        // Stepping through the empty dimension doesn't divide by zero
+        assert_eq!(step_slice_index(0, 1, 0), 0);
+        assert_eq!(step_slice_index(0, -1, 0), 0);
+    }
+
+    #[test]
+    fn test_step_slice_index_wraps() {
+        assert_eq!(step_slice_index(0, -1, 3), 2);
+        assert_eq!(step_slice_index(2, 1, 3), 0);
+        assert_eq!(step_slice_index(1, 1, 3), 2);
+    }
+
+    #[test]
+    fn test_try_reshape_keeps_flat_data() {
+        let data: Vec<f64> = (0..24).map(|i| i as f64).collect();
+        let mut tensor = Tensor::from_data(data.clone(), vec![2, 12]).unwrap();
+        assert_eq!(tensor.try_reshape("2, 2, 6"), Ok(()));
+        assert_eq!(tensor.shape(), &[2, 2, 6]);
+        assert_eq!(tensor.data, data);
+        assert_eq!(tensor.get(&[1, 0, 3]), Some(15.0));
+    }
+
+    #[test]
+    fn test_try_reshape_infers_dimension() {
+        let mut tensor = Tensor::zeros(vec![2, 12]);
+        assert_eq!(tensor.try_reshape("(4, -1)"), Ok(()));
+        assert_eq!(tensor.shape(), &[4, 6]);
+        assert_eq!(tensor.try_reshape("-1"), Ok(()));
+        assert_eq!(tensor.shape(), &[24]);
+
+        assert_eq!(
+            tensor.try_reshape("-1, 5"),
+            Err(ReshapeError::CannotInfer { known: 5, size: 24 })
+        );
+        assert_eq!(
+            tensor.try_reshape("-1, 2, -1"),
+            Err(ReshapeError::MultipleInferred)
+        );
+        assert_eq!(tensor.shape(), &[24]);
+    }
+
+    #[test]
+    fn test_try_reshape_rejects_mismatched_product() {
+        let mut tensor = Tensor::zeros(vec![2, 12]);
+        let err = tensor.try_reshape("2, 3").unwrap_err();
+        assert_eq!(
+            err,
+            ReshapeError::SizeMismatch {
+                product: 6,
+                size: 24
+            }
+        );
+        assert_eq!(err.to_string(), "6 ≠ 24 elements");
+        assert_eq!(tensor.shape(), &[2, 12]);
+    }
+
+    #[test]
+    fn test_parse_shape() {
+        assert_eq!(parse_shape("2, 2, 6"), Ok(vec![2, 2, 6]));
+        assert_eq!(parse_shape(" (3, -1,) "), Ok(vec![3, -1]));
+        assert_eq!(parse_shape(""), Err(ReshapeError::Empty));
+        assert_eq!(
+            parse_shape("2, x"),
+            Err(ReshapeError::InvalidDimension("x".to_string()))
+        );
+        assert_eq!(
+            parse_shape("-2, 12"),
+            Err(ReshapeError::InvalidDimension("-2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_zero_dimension_is_rejected() {
+        assert_eq!(
+            parse_shape("0, 24"),
+            Err(ReshapeError::InvalidDimension("0".to_string()))
+        );
+        let mut tensor = Tensor::zeros(vec![2, 12]);
+        assert_eq!(
+            tensor.resolve_shape(&[0, -1]),
+            Err(ReshapeError::InvalidDimension("0".to_string()))
+        );
+        assert!(tensor.try_reshape("24, 0").is_err());
+        assert_eq!(tensor.shape(), &[2, 12]);
+    }
+
+    #[test]
+    fn test_overflowing_shape_is_rejected() {
+        let mut tensor = Tensor::zeros(vec![2, 12]);
+        let huge = format!("{}, {}", isize::MAX, isize::MAX);
+        assert_eq!(tensor.try_reshape(&huge), Err(ReshapeError::TooLarge));
+        assert_eq!(
+            tensor.try_reshape(&format!("{}, {}, -1", isize::MAX, isize::MAX)),
+            Err(ReshapeError::TooLarge)
+        );
+        assert_eq!(tensor.shape(), &[2, 12]);
+    }
+
     #[test]
     fn test_tensor_shape_string() {
         let t = Tensor::zeros(vec![2, 3, 4]);
@@ -1,5 +1,6 @@
-use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::components::field_wrapper::{FieldAria, FieldIds, FieldWrapper};
+use crate::theme::{use_mingot_config, use_theme};
+use crate::utils::{grapheme_count, limit_insertion, CharacterCountState, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
 
@@ -19,37 +20,195 @@ pub enum TextareaSize {
     Xl,
 }
 
+/// Which way the user can drag the textarea's resize handle
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TextareaResize {
+    None,
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl TextareaResize {
+    fn css(self) -> &'static str {
+        match self {
+            TextareaResize::None => "none",
+            TextareaResize::Vertical => "vertical",
+            TextareaResize::Horizontal => "horizontal",
+            TextareaResize::Both => "both",
+        }
+    }
+}
+
+/// Rows an auto-sized textarea shows for `content_rows` rows of text: at
+/// least `min_rows` and at most `max_rows`, past which it scrolls
+pub fn autosize_rows(content_rows: u32, min_rows: u32, max_rows: Option<u32>) -> u32 {
+    let max_rows = max_rows.unwrap_or(u32::MAX).max(min_rows);
+    content_rows.clamp(min_rows, max_rows)
+}
+
+/// Rows of text in `content_height` pixels (scrollHeight less the
+/// vertical padding), allowing a pixel of rounding
+pub fn content_rows(content_height: f64, line_height: f64) -> u32 {
+    if line_height <= 0.0 {
+        return 1;
+    }
+    ((content_height - 1.0) / line_height).ceil().max(1.0) as u32
+}
+
 #[component]
 pub fn Textarea(
+    /// Id of the control, e.g. for an external `<label for>`; also the
+    /// prefix of the description and error ids. Generated when not set.
+    #[prop(optional, into)]
+    id: Option<String>,
     #[prop(optional)] variant: Option<TextareaVariant>,
     #[prop(optional)] size: Option<TextareaSize>,
     #[prop(optional, into)] placeholder: Option<String>,
     #[prop(optional)] value: Option<RwSignal<String>>,
     #[prop(optional)] disabled: bool,
-    #[prop(optional, into)] error: Option<String>,
+    /// Error message; a non-empty one turns the border red and renders below
+    #[prop(optional, into)]
+    error: Option<Signal<String>>,
     #[prop(optional)] required: bool,
     #[prop(optional)] rows: Option<u32>,
-    #[prop(optional)] auto_size: bool,
-    #[prop(optional)] _min_rows: Option<u32>,
-    #[prop(optional)] _max_rows: Option<u32>,
+    /// Grow to fit the content, between `min_rows` and `max_rows`
+    #[prop(optional)]
+    autosize: bool,
+    /// Fewest rows shown with `autosize` (defaults to `rows`)
+    #[prop(optional)]
+    min_rows: Option<u32>,
+    /// Most rows shown with `autosize` before scrolling
+    #[prop(optional)]
+    max_rows: Option<u32>,
+    /// Resize handle; `Vertical` by default, `None` with `autosize`
+    #[prop(optional)]
+    resize: Option<TextareaResize>,
     #[prop(optional)] on_input: Option<Callback<String>>,
     #[prop(optional)] on_change: Option<Callback<String>>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
     #[prop(optional, into)] label: Option<String>,
     #[prop(optional, into)] description: Option<String>,
-    #[prop(optional, into)] maxlength: Option<String>,
     #[prop(optional, into)] minlength: Option<String>,
+    /// Deprecated: use `max_length`. Read as a `max_length` when that is
+    /// not set; will be removed in the next release.
+    #[prop(optional, into)]
+    maxlength: Option<String>,
+    /// Maximum length in grapheme clusters. Typed or pasted text that
+    /// doesn't fit is cut without touching the rest of the value.
+    #[prop(optional)]
+    max_length: Option<usize>,
+    /// Show the `current/max` counter with `max_length` (default true)
+    #[prop(optional)]
+    show_count: Option<bool>,
     #[prop(optional, into)] autocomplete: Option<String>,
 ) -> impl IntoView {
     let theme = use_theme();
-    let variant = variant.unwrap_or(TextareaVariant::Default);
-    let size = size.unwrap_or(TextareaSize::Md);
+    let config = use_mingot_config();
+    let variant = variant
+        .or(config.textarea_variant)
+        .unwrap_or(TextareaVariant::Default);
+    let size = size.or(config.textarea_size).unwrap_or(TextareaSize::Md);
     let rows = rows.unwrap_or(3);
+    let min_rows = min_rows.unwrap_or(rows);
+    let resize = resize.unwrap_or(if autosize {
+        TextareaResize::None
+    } else {
+        TextareaResize::Vertical
+    });
+    let show_count = show_count.unwrap_or(true);
+    let max_length = max_length.or_else(|| maxlength.and_then(|m| m.trim().parse().ok()));
 
     let textarea_value = value.unwrap_or_else(|| RwSignal::new(String::new()));
 
-    let error_clone = error.clone();
+    // Character counter state, counted in grapheme clusters
+    let char_count = Memo::new(move |_| grapheme_count(&textarea_value.get()));
+    let over_limit = move || {
+        max_length.is_some_and(|max| {
+            CharacterCountState::from_count(char_count.get(), max) == CharacterCountState::Exceeded
+        })
+    };
+
+    // The caller's error, else the length limit's
+    let error_message = Signal::derive(move || {
+        let message = error.map(|e| e.get()).unwrap_or_default();
+        if !message.is_empty() {
+            return message;
+        }
+        max_length
+            .filter(|_| over_limit())
+            .map(|max| format!("Must be at most {} characters", max))
+            .unwrap_or_default()
+    });
+    let ids = id.as_deref().map_or_else(FieldIds::generate, FieldIds::new);
+    let has_description = description.is_some();
+    let aria = {
+        let ids = ids.clone();
+        move || error_message.with(|e| FieldAria::new(&ids, has_description, Some(e)))
+    };
+    let aria_invalid = aria.clone();
+
+    let textarea_ref = NodeRef::<leptos::html::Textarea>::new();
+    // Height and overflow from the last autosize pass, kept in the style
+    // so restyling doesn't drop them
+    let auto_height = RwSignal::new(None::<(f64, bool)>);
+    // Once the user drags the handle, the height is theirs
+    let user_resized = StoredValue::new(false);
+
+    if autosize {
+        // Runs after render on every value change, typed or programmatic
+        Effect::new(move |_| {
+            textarea_value.track();
+            theme.track();
+            let Some(el) = textarea_ref.get() else {
+                return;
+            };
+            if user_resized.get_value() {
+                return;
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                let rendered = el.get_bounding_client_rect().height();
+                if let Some((height, _)) = auto_height.get_untracked() {
+                    if (rendered - height).abs() > 1.0 {
+                        user_resized.set_value(true);
+                        return;
+                    }
+                }
+                let Some(computed) = window().get_computed_style(&el).ok().flatten() else {
+                    return;
+                };
+                let px = |name: &str| {
+                    computed
+                        .get_property_value(name)
+                        .ok()
+                        .and_then(|v| v.trim_end_matches("px").parse::<f64>().ok())
+                        .unwrap_or(0.0)
+                };
+                let line_height = match px("line-height") {
+                    lh if lh > 0.0 => lh,
+                    // "normal"
+                    _ => px("font-size") * 1.2,
+                };
+                let padding = px("padding-top") + px("padding-bottom");
+                let border = px("border-top-width") + px("border-bottom-width");
+
+                let style = el.style();
+                let _ = style.set_property("height", "auto");
+                let needed = content_rows(el.scroll_height() as f64 - padding, line_height);
+                let shown = autosize_rows(needed, min_rows, max_rows);
+                let height = shown as f64 * line_height + padding + border;
+                let _ = style.set_property("height", &format!("{}px", height));
+                auto_height.set(Some((height, needed > shown)));
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let _ = (el, min_rows, max_rows, auto_height);
+        });
+    }
+
     let textarea_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
@@ -63,7 +222,7 @@ pub fn Textarea(
             .add("transition", "all 0.15s ease")
             .add("outline", "none")
             .add("box-sizing", "border-box")
-            .add("resize", "vertical");
+            .add("resize", resize.css());
 
         // Size-based styles
         match size {
@@ -102,7 +261,7 @@ pub fn Textarea(
         // Variant-based styles
         match variant {
             TextareaVariant::Default => {
-                let border_color = if error_clone.is_some() {
+                let border_color = if error_message.with(|e| !e.is_empty()) {
                     scheme_colors
                         .get_color("red", 6)
                         .unwrap_or_else(|| "#fa5252".to_string())
@@ -142,8 +301,10 @@ pub fn Textarea(
         }
 
         // Auto-size
-        if auto_size {
-            builder.add("resize", "none");
+        if let Some((height, overflow)) = auto_height.get() {
+            builder
+                .add("height", format!("{}px", height))
+                .add("overflow-y", if overflow { "auto" } else { "hidden" });
         }
 
         if let Some(s) = style.as_ref() {
@@ -154,7 +315,19 @@ pub fn Textarea(
     };
 
     let handle_input = move |ev: ev::Event| {
-        let value = event_target_value(&ev);
+        let mut value = event_target_value(&ev);
+        // Cut by graphemes like the counter; the DOM maxlength counts
+        // UTF-16 units instead
+        let limited = max_length.and_then(|max| {
+            textarea_value.with_untracked(|last| limit_insertion(last, &value, max))
+        });
+        if let Some((allowed, caret)) = limited {
+            value = allowed;
+            if let Some(textarea) = textarea_ref.get() {
+                textarea.set_value(&value);
+                let _ = textarea.set_selection_range(caret, caret);
+            }
+        }
         textarea_value.set(value.clone());
         if let Some(callback) = on_input {
             callback.run(value);
@@ -168,74 +341,102 @@ pub fn Textarea(
         }
     };
 
-    let label_styles = move || {
-        let theme_val = theme.get();
-        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        format!(
-            "display: block; margin-bottom: 0.25rem; font-size: {}; font-weight: {}; color: {};",
-            &*theme_val.typography.font_sizes.sm,
-            theme_val.typography.font_weights.medium,
-            scheme_colors.text
-        )
-    };
-
-    let description_styles = move || {
+    let counter_styles = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        format!(
-            "margin-top: 0.25rem; font-size: {}; color: {};",
-            &*theme_val.typography.font_sizes.xs,
-            scheme_colors
+        let state = max_length
+            .map(|max| CharacterCountState::from_count(char_count.get(), max))
+            .unwrap_or(CharacterCountState::Normal);
+        let color = match state {
+            CharacterCountState::Normal => scheme_colors
                 .get_color("gray", 6)
-                .unwrap_or_else(|| "#868e96".to_string())
-        )
-    };
-
-    let error_styles = move || {
-        let theme_val = theme.get();
-        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
-        format!(
-            "margin-top: 0.25rem; font-size: {}; color: {};",
-            &*theme_val.typography.font_sizes.xs,
-            scheme_colors
+                .unwrap_or_else(|| "#868e96".to_string()),
+            CharacterCountState::Warning | CharacterCountState::Exceeded => scheme_colors
                 .get_color("red", 6)
-                .unwrap_or_else(|| "#fa5252".to_string())
+                .unwrap_or_else(|| "#fa5252".to_string()),
+        };
+        format!(
+            "margin-top: 0.25rem; font-size: {}; color: {}; text-align: end;",
+            &*theme_val.typography.font_sizes.xs, color
         )
     };
 
     let class_str = format!("mingot-textarea {}", class.unwrap_or_default());
 
     view! {
-        <div class="mingot-textarea-wrapper" style="width: 100%;">
-            {label.map(|l| view! {
-                <label style=label_styles>
-                    {l}
-                    {if required { " *" } else { "" }}
-                </label>
-            })}
-
+        <FieldWrapper
+            ids=ids.clone()
+            class="mingot-textarea-wrapper"
+            label=label
+            description=description
+            error=error_message
+            required=required
+            footer=Box::new(move || {
+                max_length
+                    .filter(|_| show_count)
+                    .map(|max| view! {
+                        <div class="mingot-textarea-counter" style=counter_styles>
+                            {move || format!("{}/{}", char_count.get(), max)}
+                        </div>
+                    })
+                    .into_any()
+            })
+        >
             <textarea
+                id=ids.control
+                aria-describedby=move || aria().described_by
+                aria-invalid=move || aria_invalid().invalid_attr()
                 class=class_str
                 style=textarea_styles
                 placeholder=placeholder.unwrap_or_default()
                 disabled=disabled
                 required=required
-                rows=rows
+                rows=if autosize { min_rows } else { rows }
+                node_ref=textarea_ref
                 prop:value=move || textarea_value.get()
                 on:input=handle_input
                 on:change=handle_change
-                maxlength=maxlength
                 minlength=minlength
                 autocomplete=autocomplete
             />
+        </FieldWrapper>
+    }
+}
 
-            {description.map(|d| view! {
-                <div style=description_styles>{d}</div>
-            })}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_autosize_rows_clamps_to_bounds() {
+        // One line of text still shows the minimum
+        assert_eq!(autosize_rows(1, 3, Some(6)), 3);
+        assert_eq!(autosize_rows(4, 3, Some(6)), 4);
+        // Past the maximum it stops growing and scrolls
+        assert_eq!(autosize_rows(12, 3, Some(6)), 6);
+        // No maximum: grows without bound
+        assert_eq!(autosize_rows(40, 2, None), 40);
+    }
+
+    #[test]
+    fn test_autosize_rows_max_below_min() {
+        assert_eq!(autosize_rows(10, 4, Some(2)), 4);
+        assert_eq!(autosize_rows(1, 4, Some(2)), 4);
+    }
+
+    #[test]
+    fn test_content_rows_from_height() {
+        assert_eq!(content_rows(5.0 * 20.0, 20.0), 5);
+        // Rounded-up scrollHeight doesn't add a row
+        assert_eq!(content_rows(5.0 * 21.7 + 0.5, 21.7), 5);
+        assert_eq!(content_rows(5.0 * 20.0 + 8.0, 20.0), 6);
+        assert_eq!(content_rows(0.0, 20.0), 1);
+        assert_eq!(content_rows(100.0, 0.0), 1);
+    }
 
-            {error.map(|e| view! {
-                <div style=error_styles>{e}</div>
-            })}
-        </div>
+    #[test]
+    fn test_resize_css() {
+        assert_eq!(TextareaResize::None.css(), "none");
+        assert_eq!(TextareaResize::Both.css(), "both");
     }
 }
@@ -0,0 +1,385 @@
+//! TimePicker - Time-of-day entry built from `NumberInput` fields
+
+use crate::components::number_input::{NumberInput, NumberInputPrecision};
+use crate::theme::use_theme;
+use crate::utils::FieldState;
+use leptos::prelude::*;
+
+/// A 24-hour time of day
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeOfDay {
+    /// Hour, 0-23
+    pub hour: u32,
+    /// Minute, 0-59
+    pub minute: u32,
+    /// Second, 0-59
+    pub second: u32,
+}
+
+/// Error parsing a time from text
+#[derive(Clone, Debug, PartialEq)]
+pub enum TimeParseError {
+    /// Input is not in `HH:MM` or `HH:MM:SS` form
+    InvalidFormat(String),
+    /// Hour, minute or second is out of range
+    OutOfRange(String),
+}
+
+impl std::fmt::Display for TimeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat(s) => write!(f, "Expected a time like 14:30, got '{}'", s),
+            Self::OutOfRange(s) => write!(f, "'{}' is not a valid time", s),
+        }
+    }
+}
+
+impl TimeOfDay {
+    /// Create a time, returning `None` if any field is out of range
+    pub fn new(hour: u32, minute: u32, second: u32) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Parse `HH:MM` or `HH:MM:SS`
+    pub fn parse(input: &str) -> Result<Self, TimeParseError> {
+        let trimmed = input.trim();
+        let fields: Vec<&str> = trimmed.split(':').collect();
+        if !(2..=3).contains(&fields.len()) {
+            return Err(TimeParseError::InvalidFormat(trimmed.to_string()));
+        }
+
+        let mut values = [0u32; 3];
+        for (value, field) in values.iter_mut().zip(&fields) {
+            *value = field
+                .parse()
+                .map_err(|_| TimeParseError::InvalidFormat(trimmed.to_string()))?;
+        }
+
+        Self::new(values[0], values[1], values[2])
+            .ok_or_else(|| TimeParseError::OutOfRange(trimmed.to_string()))
+    }
+
+    /// Seconds since midnight
+    pub fn to_seconds(&self) -> u32 {
+        self.hour * 3600 + self.minute * 60 + self.second
+    }
+
+    /// Format as `HH:MM`, or `HH:MM:SS` when `with_seconds` is set
+    pub fn format(&self, with_seconds: bool) -> String {
+        if with_seconds {
+            format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+        } else {
+            format!("{:02}:{:02}", self.hour, self.minute)
+        }
+    }
+
+    /// Convert to a `chrono::NaiveTime`
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_time(&self) -> Option<chrono::NaiveTime> {
+        chrono::NaiveTime::from_hms_opt(self.hour, self.minute, self.second)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveTime> for TimeOfDay {
+    fn from(time: chrono::NaiveTime) -> Self {
+        use chrono::Timelike;
+        Self {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+}
+
+impl std::fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(self.second != 0))
+    }
+}
+
+/// Time-of-day entry with separate hour, minute and (optionally) second
+/// fields. Each field is a `NumberInput`, so arrow keys and the mouse
+/// wheel step the value.
+///
+/// # Example
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use mingot::prelude::*;
+///
+/// let time = RwSignal::new(TimeOfDay::new(9, 30, 0));
+///
+/// view! {
+///     <TimePicker value=time label="Start time" minute_step=15 />
+/// }
+/// ```
+#[component]
+pub fn TimePicker(
+    /// Selected time (controlled)
+    #[prop(optional)]
+    value: Option<RwSignal<Option<TimeOfDay>>>,
+
+    /// Callback when any field changes to a valid time
+    #[prop(optional)]
+    on_change: Option<Callback<TimeOfDay>>,
+
+    /// Value restored by reset; also the starting value when `value` is not set
+    #[prop(optional)]
+    default_value: Option<TimeOfDay>,
+
+    /// Receives the field's dirty-tracking and reset handle on mount
+    #[prop(optional)]
+    on_register: Option<Callback<FieldState<Option<TimeOfDay>>>>,
+
+    /// Show a seconds field
+    #[prop(optional)]
+    with_seconds: bool,
+
+    /// Step for the minute field's arrow keys
+    #[prop(default = 1)]
+    minute_step: u32,
+
+    /// Whether input is disabled
+    #[prop(optional, into)]
+    disabled: Signal<bool>,
+
+    /// Error message
+    #[prop(optional, into)]
+    error: Option<String>,
+
+    /// Whether field is required
+    #[prop(optional)]
+    required: bool,
+
+    /// Label text
+    #[prop(optional, into)]
+    label: Option<String>,
+
+    /// Description text
+    #[prop(optional, into)]
+    description: Option<String>,
+
+    /// Additional CSS class
+    #[prop(optional, into)]
+    class: Option<String>,
+
+    /// Additional inline styles
+    #[prop(optional, into)]
+    style: Option<String>,
+) -> impl IntoView {
+    let theme = use_theme();
+    let selected = value.unwrap_or_else(|| RwSignal::new(default_value));
+    if let Some(callback) = on_register {
+        let default = default_value.or_else(|| selected.get_untracked());
+        let field_state = FieldState::new(selected, default).with_reset(move |default| {
+            selected.set(default);
+            if let (Some(time), Some(callback)) = (default, on_change) {
+                callback.run(time);
+            }
+        });
+        callback.run(field_state);
+    }
+
+    let field = |pick: fn(&TimeOfDay) -> u32| {
+        RwSignal::new(
+            selected
+                .get_untracked()
+                .map(|t| format!("{:02}", pick(&t)))
+                .unwrap_or_default(),
+        )
+    };
+    let hour = field(|t| t.hour);
+    let minute = field(|t| t.minute);
+    let second = field(|t| t.second);
+
+    // Follow external changes to the value, including a reset to empty
+    Effect::new(move || {
+        let current = selected.get();
+        let pad = |pick: fn(&TimeOfDay) -> u32| {
+            current
+                .map(|t| format!("{:02}", pick(&t)))
+                .unwrap_or_default()
+        };
+        hour.set(pad(|t| t.hour));
+        minute.set(pad(|t| t.minute));
+        second.set(pad(|t| t.second));
+    });
+
+    let commit = move || {
+        let parse = |s: RwSignal<String>| s.get_untracked().trim().parse::<u32>().ok();
+        let secs = if with_seconds { parse(second) } else { Some(0) };
+        let time = match (parse(hour), parse(minute), secs) {
+            (Some(h), Some(m), Some(s)) => TimeOfDay::new(h, m, s),
+            _ => None,
+        };
+
+        if let Some(time) = time {
+            if selected.get_untracked() != Some(time) {
+                selected.set(Some(time));
+                if let Some(callback) = on_change {
+                    callback.run(time);
+                }
+            }
+        }
+    };
+    let on_field_change = Callback::new(move |_: String| commit());
+
+    let label_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "display: block; margin-bottom: 0.25rem; font-size: {}; font-weight: {}; color: {};",
+            &*theme_val.typography.font_sizes.sm,
+            theme_val.typography.font_weights.medium,
+            scheme_colors.text
+        )
+    };
+
+    let separator_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "font-weight: {}; color: {};",
+            theme_val.typography.font_weights.bold, scheme_colors.text
+        )
+    };
+
+    let description_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("gray", 6)
+                .unwrap_or_else(|| "#868e96".to_string())
+        )
+    };
+
+    let error_styles = move || {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        format!(
+            "margin-top: 0.25rem; font-size: {}; color: {};",
+            &*theme_val.typography.font_sizes.xs,
+            scheme_colors
+                .get_color("red", 6)
+                .unwrap_or_else(|| "#fa5252".to_string())
+        )
+    };
+
+    let class_str = format!("mingot-time-picker {}", class.unwrap_or_default());
+    let field_style = "width: 4.5rem;";
+
+    view! {
+        <div class=class_str style=style.unwrap_or_default()>
+            {label.map(|l| view! {
+                <label style=label_styles>
+                    {l}
+                    {required.then(|| view! { <span style="color: #fa5252; margin-left: 0.25rem;">"*"</span> })}
+                </label>
+            })}
+
+            <div style="display: flex; align-items: center; gap: 0.25rem;" role="group">
+                <NumberInput
+                    value=hour
+                    on_change=on_field_change
+                    precision=NumberInputPrecision::U64
+                    min="0".to_string()
+                    max="23".to_string()
+                    placeholder="HH"
+                    disabled=disabled
+                    style=field_style
+                />
+                <span style=separator_styles>":"</span>
+                <NumberInput
+                    value=minute
+                    on_change=on_field_change
+                    precision=NumberInputPrecision::U64
+                    min="0".to_string()
+                    max="59".to_string()
+                    step=minute_step.to_string()
+                    placeholder="MM"
+                    disabled=disabled
+                    style=field_style
+                />
+                {with_seconds.then(|| view! {
+                    <span style=separator_styles>":"</span>
+                    <NumberInput
+                        value=second
+                        on_change=on_field_change
+                        precision=NumberInputPrecision::U64
+                        min="0".to_string()
+                        max="59".to_string()
+                        placeholder="SS"
+                        disabled=disabled
+                        style=field_style
+                    />
+                })}
+            </div>
+
+            {description.map(|d| view! {
+                <div style=description_styles>{d}</div>
+            })}
+
+            {error.map(|e| view! {
+                <div style=error_styles role="alert">{e}</div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_of_day_validation() {
+        assert!(TimeOfDay::new(23, 59, 59).is_some());
+        assert!(TimeOfDay::new(24, 0, 0).is_none());
+        assert!(TimeOfDay::new(12, 60, 0).is_none());
+    }
+
+    #[test]
+    fn test_time_of_day_parse() {
+        assert_eq!(
+            TimeOfDay::parse("14:30"),
+            Ok(TimeOfDay::new(14, 30, 0).unwrap())
+        );
+        assert_eq!(
+            TimeOfDay::parse(" 07:05:09 "),
+            Ok(TimeOfDay::new(7, 5, 9).unwrap())
+        );
+        assert!(matches!(
+            TimeOfDay::parse("1430"),
+            Err(TimeParseError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            TimeOfDay::parse("25:00"),
+            Err(TimeParseError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_time_of_day_format() {
+        let t = TimeOfDay::new(9, 5, 0).unwrap();
+        assert_eq!(t.format(false), "09:05");
+        assert_eq!(t.format(true), "09:05:00");
+        assert_eq!(t.to_string(), "09:05");
+        assert_eq!(TimeOfDay::new(9, 5, 7).unwrap().to_string(), "09:05:07");
+        assert_eq!(t.to_seconds(), 9 * 3600 + 5 * 60);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_round_trip() {
+        let naive = chrono::NaiveTime::from_hms_opt(14, 30, 15).unwrap();
+        let t = TimeOfDay::from(naive);
+        assert_eq!(t, TimeOfDay::new(14, 30, 15).unwrap());
+        assert_eq!(t.to_naive_time(), Some(naive));
+    }
+}
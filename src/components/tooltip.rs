@@ -1,4 +1,5 @@
 use crate::theme::use_theme;
+use crate::utils::TicketGate;
 use leptos::prelude::*;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -9,11 +10,47 @@ pub enum TooltipPosition {
     Right,
 }
 
+/// CSS for the triangle that points from the tooltip at its target
+fn arrow_styles(position: TooltipPosition, color: &str) -> String {
+    let (placement, border_side) = match position {
+        TooltipPosition::Top => ("top: 100%; left: 50%; transform: translateX(-50%);", "top"),
+        TooltipPosition::Bottom => (
+            "bottom: 100%; left: 50%; transform: translateX(-50%);",
+            "bottom",
+        ),
+        TooltipPosition::Left => ("left: 100%; top: 50%; transform: translateY(-50%);", "left"),
+        TooltipPosition::Right => (
+            "right: 100%; top: 50%; transform: translateY(-50%);",
+            "right",
+        ),
+    };
+    format!(
+        "position: absolute; \
+         {} \
+         width: 0; \
+         height: 0; \
+         border: 5px solid transparent; \
+         border-{}-color: {};",
+        placement, border_side, color
+    )
+}
+
+/// Label shown next to its children on hover or keyboard focus.
+/// `open_delay` and `close_delay` debounce the change so quick pointer
+/// movement does not make it flicker.
 #[component]
 pub fn Tooltip(
     #[prop(into)] label: String,
     #[prop(optional)] position: Option<TooltipPosition>,
-    #[prop(optional)] with_arrow: bool,
+    /// Draw a small triangle pointing at the target
+    #[prop(optional)]
+    with_arrow: bool,
+    /// Milliseconds to wait before showing
+    #[prop(optional)]
+    open_delay: u64,
+    /// Milliseconds to wait before hiding
+    #[prop(optional)]
+    close_delay: u64,
     #[prop(optional, into)] color: Option<String>,
     #[prop(optional, into)] class: Option<String>,
     #[prop(optional, into)] style: Option<String>,
@@ -22,8 +59,39 @@ pub fn Tooltip(
     let theme = use_theme();
     let position = position.unwrap_or(TooltipPosition::Top);
     let is_visible = RwSignal::new(false);
+    // Every pointer or focus change takes a ticket; a delayed show/hide
+    // applies only while its ticket is the latest, so leaving before the
+    // open delay cancels the show
+    let timer = StoredValue::new(TicketGate::default());
     let color_clone = color.clone();
 
+    let request_visible = move |visible: bool| {
+        let Some(ticket) = timer.try_update_value(|t| t.issue()) else {
+            return;
+        };
+        let delay = if visible { open_delay } else { close_delay };
+        if delay == 0 {
+            is_visible.set(visible);
+            return;
+        }
+        set_timeout(
+            move || {
+                if timer
+                    .try_with_value(|t| t.is_current(ticket))
+                    .unwrap_or(false)
+                {
+                    is_visible.set(visible);
+                }
+            },
+            std::time::Duration::from_millis(delay),
+        );
+    };
+
+    let hide_now = move || {
+        timer.update_value(|t| t.invalidate());
+        is_visible.set(false);
+    };
+
     let wrapper_styles = "position: relative; display: inline-block;".to_string();
 
     let tooltip_styles = move || {
@@ -114,7 +182,7 @@ pub fn Tooltip(
         )
     };
 
-    let arrow_styles = move || {
+    let arrow_style = move || {
         let theme_val = theme.get();
         let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
         let bg_color = if let Some(ref c) = color_clone {
@@ -124,50 +192,7 @@ pub fn Tooltip(
         } else {
             "#000000".to_string()
         };
-
-        let (border_style, top, left, bottom, right) = match position {
-            TooltipPosition::Top => (
-                format!("5px solid {}; 5px solid transparent", bg_color),
-                "auto",
-                "50%",
-                "-5px",
-                "auto",
-            ),
-            TooltipPosition::Bottom => (
-                format!("5px solid transparent; 5px solid {}", bg_color),
-                "-5px",
-                "50%",
-                "auto",
-                "auto",
-            ),
-            TooltipPosition::Left => (
-                format!("5px solid transparent; 5px solid {}", bg_color),
-                "50%",
-                "auto",
-                "auto",
-                "-5px",
-            ),
-            TooltipPosition::Right => (
-                format!("5px solid {}; 5px solid transparent", bg_color),
-                "50%",
-                "-5px",
-                "auto",
-                "auto",
-            ),
-        };
-
-        format!(
-            "position: absolute; \
-             top: {}; \
-             left: {}; \
-             bottom: {}; \
-             right: {}; \
-             width: 0; \
-             height: 0; \
-             border: {}; \
-             transform: translate(-50%, -50%);",
-            top, left, bottom, right, border_style
-        )
+        arrow_styles(position, &bg_color)
     };
 
     let class_str = format!("mingot-tooltip-wrapper {}", class.unwrap_or_default());
@@ -183,14 +208,21 @@ pub fn Tooltip(
                 }
             }
 
-            on:mouseenter=move |_| is_visible.set(true)
-            on:mouseleave=move |_| is_visible.set(false)
+            on:mouseenter=move |_| request_visible(true)
+            on:mouseleave=move |_| request_visible(false)
+            on:focusin=move |_| request_visible(true)
+            on:focusout=move |_| request_visible(false)
+            on:keydown=move |ev: web_sys::KeyboardEvent| {
+                if ev.key() == "Escape" {
+                    hide_now();
+                }
+            }
         >
             {children()}
-            <div class="mingot-tooltip" style=tooltip_styles>
+            <div class="mingot-tooltip" role="tooltip" style=tooltip_styles>
                 {label.clone()}
                 {if with_arrow {
-                    view! { <div class="mingot-tooltip-arrow" style=arrow_styles></div> }
+                    view! { <div class="mingot-tooltip-arrow" style=arrow_style></div> }
                         .into_any()
                 } else {
                     ().into_any()
@@ -200,3 +232,19 @@ pub fn Tooltip(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrow_points_at_target() {
+        let top = arrow_styles(TooltipPosition::Top, "#000");
+        assert!(top.contains("top: 100%"));
+        assert!(top.contains("border-top-color: #000"));
+
+        let right = arrow_styles(TooltipPosition::Right, "#000");
+        assert!(right.contains("right: 100%"));
+        assert!(right.contains("border-right-color: #000"));
+    }
+}
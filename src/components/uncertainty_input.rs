@@ -6,7 +6,9 @@
 
 use leptos::prelude::*;
 use leptos::tachys::html::event as ev;
+use std::collections::HashMap;
 
+use crate::components::formula_input::parse_expression;
 use crate::theme::use_theme;
 use crate::utils::style_builder::StyleBuilder;
 
@@ -189,6 +191,222 @@ impl UncertainValue {
     pub fn from_percentage(value: f64, percentage: f64) -> Self {
         Self::from_relative(value, percentage / 100.0)
     }
+
+    /// Standard uncertainty used for propagation (mean of upper and lower)
+    pub fn standard_uncertainty(&self) -> f64 {
+        (self.upper_uncertainty + self.lower_uncertainty) / 2.0
+    }
+
+    /// Propagate this value through `f` by Monte-Carlo sampling.
+    ///
+    /// Uses Gaussian sampling with the default seed; see
+    /// [`propagate_monte_carlo`] for multiple inputs and other options.
+    pub fn monte_carlo(&self, samples: usize, f: impl Fn(f64) -> f64) -> UncertainValue {
+        let options = MonteCarloOptions {
+            samples,
+            ..MonteCarloOptions::default()
+        };
+        propagate_monte_carlo(&[*self], options, |x| f(x[0]))
+    }
+
+    /// Propagate this value through `f` with first-order (linear) propagation
+    pub fn propagate(&self, f: impl Fn(f64) -> f64) -> UncertainValue {
+        propagate_analytic(&[*self], |x| f(x[0]))
+    }
+}
+
+/// Method used to propagate uncertainty through a formula
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PropagationMethod {
+    /// First-order propagation, combining partial derivatives in quadrature
+    #[default]
+    Analytic,
+    /// Random sampling of the inputs; reported alongside the analytic result
+    MonteCarlo,
+}
+
+/// Distribution assumed for each input when sampling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SamplingDistribution {
+    /// Normal distribution; asymmetric values use a split normal
+    #[default]
+    Gaussian,
+    /// Rectangular distribution with the same standard deviation as the input
+    Uniform,
+}
+
+/// Options for Monte-Carlo propagation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonteCarloOptions {
+    /// Number of samples to draw
+    pub samples: usize,
+    /// Distribution assumed for every input
+    pub distribution: SamplingDistribution,
+    /// RNG seed, fixed so results are reproducible
+    pub seed: u64,
+}
+
+impl Default for MonteCarloOptions {
+    fn default() -> Self {
+        Self {
+            samples: 10_000,
+            distribution: SamplingDistribution::Gaussian,
+            seed: 0x5EED,
+        }
+    }
+}
+
+/// Result of propagating uncertain inputs through a formula
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropagationResult {
+    /// First-order analytic estimate
+    pub analytic: UncertainValue,
+    /// Monte-Carlo estimate, when sampling was requested
+    pub monte_carlo: Option<UncertainValue>,
+}
+
+/// Small SplitMix64 generator; deterministic for a given seed
+struct SeededRng(u64);
+
+impl SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform sample in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal sample (Box-Muller)
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+
+    fn sample(&mut self, input: &UncertainValue, distribution: SamplingDistribution) -> f64 {
+        // Both distributions use a unit-variance draw scaled per side
+        let z = match distribution {
+            SamplingDistribution::Gaussian => self.next_gaussian(),
+            SamplingDistribution::Uniform => (2.0 * self.next_f64() - 1.0) * 3.0_f64.sqrt(),
+        };
+        if z >= 0.0 {
+            input.value + z * input.upper_uncertainty
+        } else {
+            input.value + z * input.lower_uncertainty
+        }
+    }
+}
+
+/// Linear interpolation percentile of sorted data, `p` in [0, 1]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+/// Propagate uncertain inputs through `f` using first-order linear propagation.
+///
+/// Partial derivatives are estimated by central differences and combined in
+/// quadrature; inputs are assumed uncorrelated. The result is symmetric.
+pub fn propagate_analytic(inputs: &[UncertainValue], f: impl Fn(&[f64]) -> f64) -> UncertainValue {
+    let center: Vec<f64> = inputs.iter().map(|v| v.value).collect();
+    let value = f(&center);
+
+    let mut variance = 0.0;
+    let mut point = center.clone();
+    for (i, input) in inputs.iter().enumerate() {
+        let sigma = input.standard_uncertainty();
+        if sigma == 0.0 {
+            continue;
+        }
+        let h = sigma * 1e-4;
+        point[i] = center[i] + h;
+        let forward = f(&point);
+        point[i] = center[i] - h;
+        let backward = f(&point);
+        point[i] = center[i];
+
+        let derivative = (forward - backward) / (2.0 * h);
+        variance += (derivative * sigma).powi(2);
+    }
+
+    UncertainValue::symmetric(value, variance.sqrt())
+}
+
+/// Propagate uncertain inputs through `f` by Monte-Carlo sampling.
+///
+/// The reported value is the sample mean; the upper and lower uncertainties
+/// span the central 68.27% interval, so skewed outputs come back asymmetric.
+/// Samples where `f` is not finite are discarded.
+pub fn propagate_monte_carlo(
+    inputs: &[UncertainValue],
+    options: MonteCarloOptions,
+    f: impl Fn(&[f64]) -> f64,
+) -> UncertainValue {
+    let mut rng = SeededRng(options.seed);
+    let mut point = vec![0.0; inputs.len()];
+    let mut outputs = Vec::with_capacity(options.samples);
+
+    for _ in 0..options.samples {
+        for (slot, input) in point.iter_mut().zip(inputs) {
+            *slot = rng.sample(input, options.distribution);
+        }
+        let y = f(&point);
+        if y.is_finite() {
+            outputs.push(y);
+        }
+    }
+
+    if outputs.is_empty() {
+        return UncertainValue::symmetric(f64::NAN, f64::NAN);
+    }
+
+    let mean = outputs.iter().sum::<f64>() / outputs.len() as f64;
+    outputs.sort_by(|a, b| a.total_cmp(b));
+    let lower = percentile(&outputs, 0.158_655);
+    let upper = percentile(&outputs, 0.841_345);
+
+    UncertainValue::asymmetric(mean, (upper - mean).max(0.0), (mean - lower).max(0.0))
+}
+
+/// Propagate named uncertain variables through a formula expression.
+///
+/// Monte-Carlo sampling is only run when `method` is [`PropagationMethod::MonteCarlo`].
+pub fn propagate_formula(
+    formula: &str,
+    variables: &[(String, UncertainValue)],
+    method: PropagationMethod,
+    options: MonteCarloOptions,
+) -> Result<PropagationResult, String> {
+    let expression = parse_expression(formula).map_err(|e| e.to_string())?;
+    let names: Vec<&String> = variables.iter().map(|(name, _)| name).collect();
+    let inputs: Vec<UncertainValue> = variables.iter().map(|(_, v)| *v).collect();
+
+    let evaluate = |point: &[f64]| -> Result<f64, String> {
+        let values: HashMap<String, f64> = names
+            .iter()
+            .zip(point)
+            .map(|(name, x)| ((*name).clone(), *x))
+            .collect();
+        expression.evaluate(&values)
+    };
+
+    // Surface undefined variables and similar errors before sampling
+    evaluate(&inputs.iter().map(|v| v.value).collect::<Vec<_>>())?;
+    let f = |point: &[f64]| evaluate(point).unwrap_or(f64::NAN);
+
+    Ok(PropagationResult {
+        analytic: propagate_analytic(&inputs, f),
+        monte_carlo: (method == PropagationMethod::MonteCarlo)
+            .then(|| propagate_monte_carlo(&inputs, options, f)),
+    })
 }
 
 impl std::fmt::Display for UncertainValue {
@@ -343,6 +561,24 @@ pub fn UncertaintyInput(
     /// Show additional info (bounds, relative uncertainty)
     #[prop(default = true)]
     show_info: bool,
+    /// Formula to propagate this value through, e.g. `"x^2 / y"`
+    #[prop(optional, into)]
+    formula: Option<String>,
+    /// Name of this input's value inside the formula
+    #[prop(default = "x".into(), into)]
+    variable_name: String,
+    /// Other uncertain variables referenced by the formula
+    #[prop(optional, into)]
+    variables: Option<Signal<Vec<(String, UncertainValue)>>>,
+    /// Initial propagation method (toggleable in the UI)
+    #[prop(default = PropagationMethod::Analytic)]
+    propagation_method: PropagationMethod,
+    /// Monte-Carlo sampling options (sample count, distribution, seed)
+    #[prop(default = MonteCarloOptions::default())]
+    monte_carlo_options: MonteCarloOptions,
+    /// Callback with the propagated result whenever it changes
+    #[prop(optional, into)]
+    on_propagate: Option<Callback<PropagationResult>>,
     /// Additional CSS class
     #[prop(optional, into)]
     class: Option<String>,
@@ -417,6 +653,31 @@ pub fn UncertaintyInput(
         }
     };
 
+    // Propagation through the optional formula
+    let current_method = RwSignal::new(propagation_method);
+    let has_formula = formula.is_some();
+    let propagation = Memo::new(move |_| {
+        let formula = formula.as_ref()?;
+        let mut inputs = vec![(variable_name.clone(), uncertain_value.get())];
+        if let Some(vars) = variables {
+            inputs.extend(vars.get());
+        }
+        Some(propagate_formula(
+            formula,
+            &inputs,
+            current_method.get(),
+            monte_carlo_options,
+        ))
+    });
+
+    if let Some(cb) = on_propagate {
+        Effect::new(move |_| {
+            if let Some(Ok(result)) = propagation.get() {
+                cb.run(result);
+            }
+        });
+    }
+
     // Clone error for use in closures
     let error_for_display = error;
 
@@ -791,6 +1052,59 @@ pub fn UncertaintyInput(
                     </div>
                 }
             })}
+
+            {has_formula.then(|| {
+                let format_result = move |v: UncertainValue| {
+                    if v.is_symmetric() {
+                        v.to_symmetric_string(decimal_places)
+                    } else {
+                        v.to_asymmetric_string(decimal_places)
+                    }
+                };
+
+                view! {
+                    <div class="mingot-uncertainty-propagation">
+                        <div style=type_switch_styles>
+                            <button
+                                type="button"
+                                style=move || type_btn_styles(current_method.get() == PropagationMethod::Analytic)
+                                on:click=move |_| current_method.set(PropagationMethod::Analytic)
+                                disabled=disabled
+                            >
+                                "Analytic"
+                            </button>
+                            <button
+                                type="button"
+                                style=move || type_btn_styles(current_method.get() == PropagationMethod::MonteCarlo)
+                                on:click=move |_| current_method.set(PropagationMethod::MonteCarlo)
+                                disabled=disabled
+                            >
+                                "Monte Carlo"
+                            </button>
+                        </div>
+                        {move || match propagation.get() {
+                            Some(Ok(result)) => view! {
+                                <div style=info_styles()>
+                                    <span>{format!("Analytic: {}", format_result(result.analytic))}</span>
+                                    {result.monte_carlo.map(|mc| view! {
+                                        <span>
+                                            {format!(
+                                                "Monte Carlo (N = {}): {}",
+                                                monte_carlo_options.samples,
+                                                format_result(mc)
+                                            )}
+                                        </span>
+                                    })}
+                                </div>
+                            }.into_any(),
+                            Some(Err(e)) => view! {
+                                <div style=error_styles()>{e}</div>
+                            }.into_any(),
+                            None => ().into_any(),
+                        }}
+                    </div>
+                }
+            })}
         </div>
     }
 }
@@ -917,4 +1231,97 @@ mod tests {
         assert!(s.contains("×"));
         assert!(s.contains("10^3"));
     }
+
+    #[test]
+    fn test_propagate_analytic_linear() {
+        let a = UncertainValue::symmetric(10.0, 0.3);
+        let b = UncertainValue::symmetric(5.0, 0.4);
+        let r = propagate_analytic(&[a, b], |x| x[0] + x[1]);
+        assert!((r.value - 15.0).abs() < 1e-10);
+        assert!((r.upper_uncertainty - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_monte_carlo_matches_analytic_for_linear() {
+        let a = UncertainValue::symmetric(10.0, 0.3);
+        let b = UncertainValue::symmetric(5.0, 0.4);
+        let options = MonteCarloOptions {
+            samples: 20_000,
+            ..MonteCarloOptions::default()
+        };
+        let r = propagate_monte_carlo(&[a, b], options, |x| x[0] + x[1]);
+        assert!((r.value - 15.0).abs() < 0.02);
+        assert!((r.standard_uncertainty() - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_monte_carlo_is_reproducible() {
+        let x = UncertainValue::symmetric(2.0, 0.1);
+        let first = x.monte_carlo(1_000, |v| v.sin());
+        let second = x.monte_carlo(1_000, |v| v.sin());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_monte_carlo_captures_nonlinearity() {
+        // x^2 at x = 0 has zero derivative, so linear propagation sees no spread
+        let x = UncertainValue::symmetric(0.0, 1.0);
+        let analytic = x.propagate(|v| v * v);
+        let mc = x.monte_carlo(20_000, |v| v * v);
+        assert!(analytic.upper_uncertainty.abs() < 1e-6);
+        assert!((mc.value - 1.0).abs() < 0.05);
+        assert!(!mc.is_symmetric());
+    }
+
+    #[test]
+    fn test_monte_carlo_uniform_distribution() {
+        let x = UncertainValue::symmetric(1.0, 0.2);
+        let options = MonteCarloOptions {
+            samples: 20_000,
+            distribution: SamplingDistribution::Uniform,
+            seed: 7,
+        };
+        let r = propagate_monte_carlo(&[x], options, |v| v[0]);
+        assert!((r.value - 1.0).abs() < 0.01);
+        // Every sample stays within sqrt(3) standard deviations
+        let bound = 0.2 * 3.0_f64.sqrt();
+        assert!(r.upper_uncertainty < bound && r.lower_uncertainty < bound);
+    }
+
+    #[test]
+    fn test_propagate_formula() {
+        let vars = vec![
+            ("x".to_string(), UncertainValue::symmetric(3.0, 0.1)),
+            ("y".to_string(), UncertainValue::symmetric(2.0, 0.05)),
+        ];
+        let r = propagate_formula(
+            "x * y",
+            &vars,
+            PropagationMethod::MonteCarlo,
+            MonteCarloOptions::default(),
+        )
+        .unwrap();
+        assert!((r.analytic.value - 6.0).abs() < 1e-10);
+        // sqrt((2 * 0.1)^2 + (3 * 0.05)^2) = 0.25
+        assert!((r.analytic.upper_uncertainty - 0.25).abs() < 1e-6);
+        let mc = r.monte_carlo.unwrap();
+        assert!((mc.standard_uncertainty() - 0.25).abs() < 0.01);
+
+        let analytic_only = propagate_formula(
+            "x * y",
+            &vars,
+            PropagationMethod::Analytic,
+            MonteCarloOptions::default(),
+        )
+        .unwrap();
+        assert!(analytic_only.monte_carlo.is_none());
+    }
+
+    #[test]
+    fn test_propagate_formula_errors() {
+        let vars = vec![("x".to_string(), UncertainValue::symmetric(1.0, 0.1))];
+        let options = MonteCarloOptions::default();
+        assert!(propagate_formula("x +", &vars, PropagationMethod::Analytic, options).is_err());
+        assert!(propagate_formula("x + z", &vars, PropagationMethod::Analytic, options).is_err());
+    }
 }
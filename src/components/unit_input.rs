@@ -5,9 +5,11 @@
 
 use crate::components::input::{InputSize, InputVariant};
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{FieldState, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
+use std::ops::RangeInclusive;
+use wasm_bindgen::JsCast;
 
 /// Unit categories for grouping compatible units
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -26,6 +28,263 @@ pub enum UnitCategory {
     Angle,
     Data,
     Custom,
+    /// Physical units without a named category, e.g. acceleration (L·T^-2)
+    Derived(Dimension),
+}
+
+impl UnitCategory {
+    /// Named category for a dimension, or `Derived` when there is none
+    pub fn for_dimension(dimension: Dimension) -> Self {
+        match dimension {
+            Dimension::LENGTH => UnitCategory::Length,
+            Dimension::MASS => UnitCategory::Mass,
+            Dimension::TIME => UnitCategory::Time,
+            Dimension::TEMPERATURE => UnitCategory::Temperature,
+            Dimension::AREA => UnitCategory::Area,
+            Dimension::VOLUME => UnitCategory::Volume,
+            Dimension::SPEED => UnitCategory::Speed,
+            Dimension::FORCE => UnitCategory::Force,
+            Dimension::ENERGY => UnitCategory::Energy,
+            Dimension::POWER => UnitCategory::Power,
+            Dimension::PRESSURE => UnitCategory::Pressure,
+            other => UnitCategory::Derived(other),
+        }
+    }
+}
+
+/// Physical dimension as exponents of the base quantities,
+/// e.g. speed is length¹·time⁻¹
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Dimension {
+    pub length: i8,
+    pub mass: i8,
+    pub time: i8,
+    pub temperature: i8,
+}
+
+impl Dimension {
+    pub const DIMENSIONLESS: Dimension = Dimension::new(0, 0, 0, 0);
+    pub const LENGTH: Dimension = Dimension::new(1, 0, 0, 0);
+    pub const MASS: Dimension = Dimension::new(0, 1, 0, 0);
+    pub const TIME: Dimension = Dimension::new(0, 0, 1, 0);
+    pub const TEMPERATURE: Dimension = Dimension::new(0, 0, 0, 1);
+    pub const AREA: Dimension = Dimension::new(2, 0, 0, 0);
+    pub const VOLUME: Dimension = Dimension::new(3, 0, 0, 0);
+    pub const SPEED: Dimension = Dimension::new(1, 0, -1, 0);
+    pub const FORCE: Dimension = Dimension::new(1, 1, -2, 0);
+    pub const ENERGY: Dimension = Dimension::new(2, 1, -2, 0);
+    pub const POWER: Dimension = Dimension::new(2, 1, -3, 0);
+    pub const PRESSURE: Dimension = Dimension::new(-1, 1, -2, 0);
+
+    pub const fn new(length: i8, mass: i8, time: i8, temperature: i8) -> Self {
+        Self {
+            length,
+            mass,
+            time,
+            temperature,
+        }
+    }
+
+    /// This dimension raised to an integer power, e.g. LENGTH³ = VOLUME
+    pub const fn pow(self, exponent: i8) -> Self {
+        Self::new(
+            self.length * exponent,
+            self.mass * exponent,
+            self.time * exponent,
+            self.temperature * exponent,
+        )
+    }
+
+    /// Common name for well-known dimensions (e.g. "length", "force")
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match *self {
+            Self::DIMENSIONLESS => "dimensionless",
+            Self::LENGTH => "length",
+            Self::MASS => "mass",
+            Self::TIME => "time",
+            Self::TEMPERATURE => "temperature",
+            Self::AREA => "area",
+            Self::VOLUME => "volume",
+            Self::SPEED => "speed",
+            Self::FORCE => "force",
+            Self::ENERGY => "energy",
+            Self::POWER => "power",
+            Self::PRESSURE => "pressure",
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name() {
+            return write!(f, "{}", name);
+        }
+
+        let factors: Vec<String> = [
+            ("L", self.length),
+            ("M", self.mass),
+            ("T", self.time),
+            ("Θ", self.temperature),
+        ]
+        .iter()
+        .filter(|(_, exp)| *exp != 0)
+        .map(|(symbol, exp)| {
+            if *exp == 1 {
+                symbol.to_string()
+            } else {
+                format!("{}^{}", symbol, exp)
+            }
+        })
+        .collect();
+        write!(f, "{}", factors.join("·"))
+    }
+}
+
+impl std::ops::Mul for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: Dimension) -> Dimension {
+        Dimension::new(
+            self.length + rhs.length,
+            self.mass + rhs.mass,
+            self.time + rhs.time,
+            self.temperature + rhs.temperature,
+        )
+    }
+}
+
+impl std::ops::Div for Dimension {
+    type Output = Dimension;
+
+    fn div(self, rhs: Dimension) -> Dimension {
+        self * rhs.pow(-1)
+    }
+}
+
+/// Error when a unit does not have the dimension a field expects
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitDimensionError {
+    /// Symbol of the rejected unit
+    pub unit: String,
+    /// Dimension the field expects
+    pub expected: Dimension,
+    /// Dimension of the rejected unit, if it has a physical one
+    pub found: Option<Dimension>,
+}
+
+impl std::fmt::Display for UnitDimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.found {
+            Some(found) => write!(
+                f,
+                "Expected a {} unit, but {} is a {} unit",
+                self.expected, self.unit, found
+            ),
+            None => write!(
+                f,
+                "Expected a {} unit, but {} has no physical dimension",
+                self.expected, self.unit
+            ),
+        }
+    }
+}
+
+/// SI prefix of a metric unit, ordered from smallest to largest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SiPrefix {
+    Femto,
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Centi,
+    /// No prefix (m, g, s)
+    Base,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+}
+
+impl SiPrefix {
+    pub const ALL: [SiPrefix; 12] = [
+        SiPrefix::Femto,
+        SiPrefix::Pico,
+        SiPrefix::Nano,
+        SiPrefix::Micro,
+        SiPrefix::Milli,
+        SiPrefix::Centi,
+        SiPrefix::Base,
+        SiPrefix::Kilo,
+        SiPrefix::Mega,
+        SiPrefix::Giga,
+        SiPrefix::Tera,
+        SiPrefix::Peta,
+    ];
+
+    /// Power of ten this prefix stands for (kilo → 3)
+    pub fn exponent(&self) -> i32 {
+        match self {
+            SiPrefix::Femto => -15,
+            SiPrefix::Pico => -12,
+            SiPrefix::Nano => -9,
+            SiPrefix::Micro => -6,
+            SiPrefix::Milli => -3,
+            SiPrefix::Centi => -2,
+            SiPrefix::Base => 0,
+            SiPrefix::Kilo => 3,
+            SiPrefix::Mega => 6,
+            SiPrefix::Giga => 9,
+            SiPrefix::Tera => 12,
+            SiPrefix::Peta => 15,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            SiPrefix::Femto => "f",
+            SiPrefix::Pico => "p",
+            SiPrefix::Nano => "n",
+            SiPrefix::Micro => "μ",
+            SiPrefix::Milli => "m",
+            SiPrefix::Centi => "c",
+            SiPrefix::Base => "",
+            SiPrefix::Kilo => "k",
+            SiPrefix::Mega => "M",
+            SiPrefix::Giga => "G",
+            SiPrefix::Tera => "T",
+            SiPrefix::Peta => "P",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SiPrefix::Femto => "femto",
+            SiPrefix::Pico => "pico",
+            SiPrefix::Nano => "nano",
+            SiPrefix::Micro => "micro",
+            SiPrefix::Milli => "milli",
+            SiPrefix::Centi => "centi",
+            SiPrefix::Base => "",
+            SiPrefix::Kilo => "kilo",
+            SiPrefix::Mega => "mega",
+            SiPrefix::Giga => "giga",
+            SiPrefix::Tera => "tera",
+            SiPrefix::Peta => "peta",
+        }
+    }
+}
+
+/// `value` × 10^`exponent`, dividing for negative exponents so that exact
+/// powers of ten stay exact (0.001 × 10³ is 1, not 0.999…)
+fn scale_pow10(value: f64, exponent: i32) -> f64 {
+    if exponent >= 0 {
+        value * 10f64.powi(exponent)
+    } else {
+        value / 10f64.powi(-exponent)
+    }
 }
 
 /// Common units with conversion factors
@@ -41,6 +300,9 @@ pub struct Unit {
     pub to_base: f64,
     /// Offset for conversions (used for temperature)
     pub offset: f64,
+    /// SI prefix of a metric unit (`Base` for m, `Kilo` for km); `None`
+    /// for units that don't take prefixes (in, lb, °C)
+    pub si_prefix: Option<SiPrefix>,
 }
 
 impl Unit {
@@ -52,6 +314,7 @@ impl Unit {
             category,
             to_base,
             offset: 0.0,
+            si_prefix: None,
         }
     }
 
@@ -69,12 +332,83 @@ impl Unit {
             category,
             to_base,
             offset,
+            si_prefix: None,
+        }
+    }
+
+    /// Mark as a metric unit carrying `prefix`, so it can be rescaled with
+    /// [`Unit::with_prefix`] (`Unit::new("km", ..).metric(SiPrefix::Kilo)`)
+    pub fn metric(mut self, prefix: SiPrefix) -> Self {
+        self.si_prefix = Some(prefix);
+        self
+    }
+
+    /// Whether this unit takes SI prefixes
+    pub fn is_metric(&self) -> bool {
+        self.si_prefix.is_some()
+    }
+
+    /// The same metric unit with another prefix (km → mm), or `None` for
+    /// units that don't take prefixes
+    pub fn with_prefix(&self, prefix: SiPrefix) -> Option<Unit> {
+        let current = self.si_prefix?;
+        if current == prefix {
+            return Some(self.clone());
         }
+        let root_symbol = self.symbol.strip_prefix(current.symbol())?;
+        let root_name = self.name.strip_prefix(current.name())?;
+        Some(Unit {
+            symbol: format!("{}{}", prefix.symbol(), root_symbol),
+            name: format!("{}{}", prefix.name(), root_name),
+            category: self.category,
+            to_base: scale_pow10(self.to_base, prefix.exponent() - current.exponent()),
+            offset: self.offset,
+            si_prefix: Some(prefix),
+        })
     }
 
-    /// Check if this unit is compatible with another
+    /// Check if this unit is compatible with another: physical units when
+    /// their dimensions match (so km/h converts to a Speed unit), other
+    /// units when they share a category
     pub fn is_compatible(&self, other: &Unit) -> bool {
-        self.category == other.category
+        match (self.dimension(), other.dimension()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.category == other.category,
+        }
+    }
+
+    /// Physical dimension of this unit. Data and custom units have none.
+    pub fn dimension(&self) -> Option<Dimension> {
+        match self.category {
+            UnitCategory::Length => Some(Dimension::LENGTH),
+            UnitCategory::Mass => Some(Dimension::MASS),
+            UnitCategory::Time => Some(Dimension::TIME),
+            UnitCategory::Temperature => Some(Dimension::TEMPERATURE),
+            UnitCategory::Volume => Some(Dimension::VOLUME),
+            UnitCategory::Area => Some(Dimension::AREA),
+            UnitCategory::Speed => Some(Dimension::SPEED),
+            UnitCategory::Force => Some(Dimension::FORCE),
+            UnitCategory::Energy => Some(Dimension::ENERGY),
+            UnitCategory::Power => Some(Dimension::POWER),
+            UnitCategory::Pressure => Some(Dimension::PRESSURE),
+            UnitCategory::Angle => Some(Dimension::DIMENSIONLESS),
+            UnitCategory::Derived(dimension) => Some(dimension),
+            UnitCategory::Data | UnitCategory::Custom => None,
+        }
+    }
+
+    /// Check that this unit has the `expected` dimension
+    pub fn check_dimension(&self, expected: Dimension) -> Result<(), UnitDimensionError> {
+        let found = self.dimension();
+        if found == Some(expected) {
+            Ok(())
+        } else {
+            Err(UnitDimensionError {
+                unit: self.symbol.clone(),
+                expected,
+                found,
+            })
+        }
     }
 }
 
@@ -112,6 +446,53 @@ impl UnitValue {
         Some(UnitValue::new(converted_value, target_unit.clone()))
     }
 
+    /// Rescale a metric value to the SI prefix within `range` that puts it
+    /// between 1 and 1000 (1500 m → 1.5 km, 0.002 m → 2 mm). Zero moves to
+    /// the unprefixed unit. Only prefixes that are powers of 1000 are picked.
+    ///
+    /// The value in base units is kept exactly: if no mantissa in the new
+    /// unit converts back to the same base value, or the unit is not
+    /// metric, the value is returned unchanged.
+    pub fn with_auto_prefix(&self, range: &RangeInclusive<SiPrefix>) -> UnitValue {
+        let Some(current) = self.unit.si_prefix else {
+            return self.clone();
+        };
+        if !self.value.is_finite() {
+            return self.clone();
+        }
+
+        let candidates: Vec<SiPrefix> = SiPrefix::ALL
+            .into_iter()
+            .filter(|p| range.contains(p) && p.exponent() % 3 == 0)
+            .collect();
+        let target = if self.value == 0.0 {
+            candidates
+                .iter()
+                .copied()
+                .min_by_key(|p| p.exponent().abs())
+        } else {
+            let magnitude = self.value.abs();
+            candidates
+                .iter()
+                .rev()
+                .copied()
+                .find(|p| scale_pow10(magnitude, current.exponent() - p.exponent()) >= 1.0)
+                .or(candidates.first().copied())
+        };
+
+        let Some(target) = target.filter(|&p| p != current) else {
+            return self.clone();
+        };
+        let Some(unit) = self.unit.with_prefix(target) else {
+            return self.clone();
+        };
+        let mantissa = scale_pow10(self.value, current.exponent() - target.exponent());
+        match exact_mantissa(mantissa, self.to_base(), &unit) {
+            Some(value) => UnitValue::new(value, unit),
+            None => self.clone(),
+        }
+    }
+
     /// Format as string with unit
     pub fn to_string_with_unit(&self, precision: u32) -> String {
         if precision == 0 {
@@ -133,24 +514,250 @@ impl std::fmt::Display for UnitValue {
     }
 }
 
+/// `mantissa`, or one of its nearest floats, whose value in `unit` equals
+/// `base` exactly
+fn exact_mantissa(mantissa: f64, base: f64, unit: &Unit) -> Option<f64> {
+    let converts_back = |m: f64| UnitValue::new(m, unit.clone()).to_base() == base;
+    let (mut up, mut down) = (mantissa, mantissa);
+    if converts_back(mantissa) {
+        return Some(mantissa);
+    }
+    for _ in 0..4 {
+        up = up.next_up();
+        down = down.next_down();
+        if converts_back(up) {
+            return Some(up);
+        }
+        if converts_back(down) {
+            return Some(down);
+        }
+    }
+    None
+}
+
+/// A unit composed of other units raised to integer powers, e.g. km/h is
+/// km¹·h⁻¹ and N·m is N¹·m¹. Its dimension is the sum of its factors'
+/// dimensions, so km/h converts to m/s but m/s does not convert to N·m.
+///
+/// # Example
+/// ```rust,ignore
+/// let kmh = CompoundUnit::from(length::kilometer()).per(time::hour());
+/// let ms = CompoundUnit::from(length::meter()).per(time::second());
+/// assert_eq!(kmh.convert(36.0, &ms), Ok(10.0));
+///
+/// // Use it in a UnitInput like any other unit
+/// let units = vec![kmh.to_unit(), ms.to_unit()];
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CompoundUnit {
+    factors: Vec<(Unit, i8)>,
+}
+
+impl From<Unit> for CompoundUnit {
+    fn from(unit: Unit) -> Self {
+        Self::default().pow(unit, 1)
+    }
+}
+
+impl CompoundUnit {
+    /// Multiply by `unit` (N·m)
+    pub fn times(self, unit: Unit) -> Self {
+        self.pow(unit, 1)
+    }
+
+    /// Divide by `unit` (m/s)
+    pub fn per(self, unit: Unit) -> Self {
+        self.pow(unit, -1)
+    }
+
+    /// Multiply by `unit` raised to `exponent` (kg·m·s⁻² is
+    /// `from(kg).times(m).pow(s, -2)`). Repeated units combine.
+    pub fn pow(mut self, unit: Unit, exponent: i8) -> Self {
+        match self
+            .factors
+            .iter_mut()
+            .find(|(u, _)| u.symbol == unit.symbol)
+        {
+            Some((_, exp)) => *exp += exponent,
+            None => self.factors.push((unit, exponent)),
+        }
+        self.factors.retain(|(_, exp)| *exp != 0);
+        self
+    }
+
+    /// The units and their exponents
+    pub fn factors(&self) -> &[(Unit, i8)] {
+        &self.factors
+    }
+
+    /// Combined dimension vector. `None` if a factor has no physical
+    /// dimension, or an offset (°C) that cannot be multiplied.
+    pub fn dimension(&self) -> Option<Dimension> {
+        self.factors
+            .iter()
+            .try_fold(Dimension::DIMENSIONLESS, |acc, (unit, exp)| {
+                if unit.offset != 0.0 {
+                    return None;
+                }
+                Some(acc * unit.dimension()?.pow(*exp))
+            })
+    }
+
+    /// Factor to multiply by to get the base-unit value (km/h → 1000/3600)
+    pub fn to_base(&self) -> f64 {
+        self.factors
+            .iter()
+            .map(|(unit, exp)| unit.to_base.powi(i32::from(*exp)))
+            .product()
+    }
+
+    /// Symbol such as "km/h", "N·m", "m/s²" or "s⁻¹"
+    pub fn symbol(&self) -> String {
+        let power = |symbol: &str, exp: i8| {
+            if exp == 1 {
+                symbol.to_string()
+            } else {
+                format!("{}{}", symbol, superscript(exp))
+            }
+        };
+        let numerator: Vec<String> = self
+            .factors
+            .iter()
+            .filter(|(_, exp)| *exp > 0)
+            .map(|(unit, exp)| power(&unit.symbol, *exp))
+            .collect();
+        let denominator: Vec<String> = self
+            .factors
+            .iter()
+            .filter(|(_, exp)| *exp < 0)
+            .map(|(unit, exp)| power(&unit.symbol, -exp))
+            .collect();
+
+        match (numerator.is_empty(), denominator.len()) {
+            (_, 0) => numerator.join("·"),
+            // Nothing on top: write negative exponents instead of "1/s"
+            (true, _) => self
+                .factors
+                .iter()
+                .map(|(unit, exp)| power(&unit.symbol, *exp))
+                .collect::<Vec<_>>()
+                .join("·"),
+            (false, 1) => format!("{}/{}", numerator.join("·"), denominator[0]),
+            (false, _) => format!("{}/({})", numerator.join("·"), denominator.join("·")),
+        }
+    }
+
+    /// Name such as "kilometer per hour" or "meter per second squared"
+    pub fn name(&self) -> String {
+        let power = |name: &str, exp: i8| match exp {
+            1 => name.to_string(),
+            2 => format!("{} squared", name),
+            3 => format!("{} cubed", name),
+            _ => format!("{}^{}", name, exp),
+        };
+        let numerator: Vec<String> = self
+            .factors
+            .iter()
+            .filter(|(_, exp)| *exp > 0)
+            .map(|(unit, exp)| power(&unit.name, *exp))
+            .collect();
+        let denominator: Vec<String> = self
+            .factors
+            .iter()
+            .filter(|(_, exp)| *exp < 0)
+            .map(|(unit, exp)| power(&unit.name, -exp))
+            .collect();
+
+        if denominator.is_empty() {
+            numerator.join(" ")
+        } else {
+            format!("{} per {}", numerator.join(" "), denominator.join(" "))
+                .trim_start()
+                .to_string()
+        }
+    }
+
+    /// Whether values convert between the two units (same dimension vector)
+    pub fn is_convertible(&self, other: &CompoundUnit) -> bool {
+        self.conversion_factor(other).is_ok()
+    }
+
+    /// Factor that turns a value in this unit into one in `target`, or an
+    /// error if their dimension vectors differ
+    pub fn conversion_factor(&self, target: &CompoundUnit) -> Result<f64, UnitDimensionError> {
+        let found = self.dimension();
+        match target.dimension() {
+            Some(expected) if found == Some(expected) => Ok(self.to_base() / target.to_base()),
+            Some(expected) => Err(UnitDimensionError {
+                unit: self.symbol(),
+                expected,
+                found,
+            }),
+            None => Err(UnitDimensionError {
+                unit: target.symbol(),
+                expected: found.unwrap_or_default(),
+                found: None,
+            }),
+        }
+    }
+
+    /// Convert `value` from this unit into `target`
+    pub fn convert(&self, value: f64, target: &CompoundUnit) -> Result<f64, UnitDimensionError> {
+        self.conversion_factor(target).map(|factor| value * factor)
+    }
+
+    /// A [`Unit`] for use in UnitInput, with the composed symbol and name
+    pub fn to_unit(&self) -> Unit {
+        self.named(&self.symbol(), &self.name())
+    }
+
+    /// A [`Unit`] with its own symbol, e.g. "N" for kg·m/s²
+    pub fn named(&self, symbol: &str, name: &str) -> Unit {
+        let category = self
+            .dimension()
+            .map_or(UnitCategory::Custom, UnitCategory::for_dimension);
+        Unit::new(symbol, name, category, self.to_base())
+    }
+}
+
+/// Superscript digits for unit exponents ("²", "⁻¹")
+fn superscript(n: i8) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
 /// Common length units
 pub mod length {
     use super::*;
 
     pub fn meter() -> Unit {
-        Unit::new("m", "meter", UnitCategory::Length, 1.0)
+        Unit::new("m", "meter", UnitCategory::Length, 1.0).metric(SiPrefix::Base)
     }
 
     pub fn kilometer() -> Unit {
-        Unit::new("km", "kilometer", UnitCategory::Length, 1000.0)
+        Unit::new("km", "kilometer", UnitCategory::Length, 1000.0).metric(SiPrefix::Kilo)
     }
 
     pub fn centimeter() -> Unit {
-        Unit::new("cm", "centimeter", UnitCategory::Length, 0.01)
+        Unit::new("cm", "centimeter", UnitCategory::Length, 0.01).metric(SiPrefix::Centi)
     }
 
     pub fn millimeter() -> Unit {
-        Unit::new("mm", "millimeter", UnitCategory::Length, 0.001)
+        Unit::new("mm", "millimeter", UnitCategory::Length, 0.001).metric(SiPrefix::Milli)
     }
 
     pub fn inch() -> Unit {
@@ -188,15 +795,15 @@ pub mod mass {
     use super::*;
 
     pub fn kilogram() -> Unit {
-        Unit::new("kg", "kilogram", UnitCategory::Mass, 1.0)
+        Unit::new("kg", "kilogram", UnitCategory::Mass, 1.0).metric(SiPrefix::Kilo)
     }
 
     pub fn gram() -> Unit {
-        Unit::new("g", "gram", UnitCategory::Mass, 0.001)
+        Unit::new("g", "gram", UnitCategory::Mass, 0.001).metric(SiPrefix::Base)
     }
 
     pub fn milligram() -> Unit {
-        Unit::new("mg", "milligram", UnitCategory::Mass, 0.000001)
+        Unit::new("mg", "milligram", UnitCategory::Mass, 0.000001).metric(SiPrefix::Milli)
     }
 
     pub fn pound() -> Unit {
@@ -221,15 +828,15 @@ pub mod time {
     use super::*;
 
     pub fn second() -> Unit {
-        Unit::new("s", "second", UnitCategory::Time, 1.0)
+        Unit::new("s", "second", UnitCategory::Time, 1.0).metric(SiPrefix::Base)
     }
 
     pub fn millisecond() -> Unit {
-        Unit::new("ms", "millisecond", UnitCategory::Time, 0.001)
+        Unit::new("ms", "millisecond", UnitCategory::Time, 0.001).metric(SiPrefix::Milli)
     }
 
     pub fn microsecond() -> Unit {
-        Unit::new("μs", "microsecond", UnitCategory::Time, 0.000001)
+        Unit::new("μs", "microsecond", UnitCategory::Time, 0.000001).metric(SiPrefix::Micro)
     }
 
     pub fn minute() -> Unit {
@@ -333,6 +940,142 @@ pub mod data {
     }
 }
 
+/// Velocity units, composed from length and time
+pub mod velocity {
+    use super::*;
+
+    pub fn meter_per_second() -> Unit {
+        CompoundUnit::from(length::meter())
+            .per(time::second())
+            .to_unit()
+    }
+
+    pub fn kilometer_per_hour() -> Unit {
+        CompoundUnit::from(length::kilometer())
+            .per(time::hour())
+            .to_unit()
+    }
+
+    pub fn foot_per_second() -> Unit {
+        CompoundUnit::from(length::foot())
+            .per(time::second())
+            .to_unit()
+    }
+
+    pub fn mile_per_hour() -> Unit {
+        CompoundUnit::from(length::mile())
+            .per(time::hour())
+            .named("mph", "mile per hour")
+    }
+
+    pub fn all() -> Vec<Unit> {
+        vec![
+            meter_per_second(),
+            kilometer_per_hour(),
+            foot_per_second(),
+            mile_per_hour(),
+        ]
+    }
+}
+
+/// Force units (newton = kg·m/s²)
+pub mod force {
+    use super::*;
+
+    pub fn newton() -> Unit {
+        CompoundUnit::from(mass::kilogram())
+            .times(length::meter())
+            .pow(time::second(), -2)
+            .named("N", "newton")
+            .metric(SiPrefix::Base)
+    }
+
+    pub fn kilonewton() -> Unit {
+        Unit::new("kN", "kilonewton", UnitCategory::Force, 1000.0).metric(SiPrefix::Kilo)
+    }
+
+    pub fn dyne() -> Unit {
+        CompoundUnit::from(mass::gram())
+            .times(length::centimeter())
+            .pow(time::second(), -2)
+            .named("dyn", "dyne")
+    }
+
+    pub fn pound_force() -> Unit {
+        Unit::new(
+            "lbf",
+            "pound-force",
+            UnitCategory::Force,
+            4.448_221_615_260_5,
+        )
+    }
+
+    pub fn all() -> Vec<Unit> {
+        vec![newton(), kilonewton(), dyne(), pound_force()]
+    }
+}
+
+/// Energy units (joule = N·m)
+pub mod energy {
+    use super::*;
+
+    pub fn joule() -> Unit {
+        CompoundUnit::from(force::newton())
+            .times(length::meter())
+            .named("J", "joule")
+            .metric(SiPrefix::Base)
+    }
+
+    pub fn kilojoule() -> Unit {
+        Unit::new("kJ", "kilojoule", UnitCategory::Energy, 1000.0).metric(SiPrefix::Kilo)
+    }
+
+    pub fn newton_meter() -> Unit {
+        CompoundUnit::from(force::newton())
+            .times(length::meter())
+            .to_unit()
+    }
+
+    pub fn calorie() -> Unit {
+        Unit::new("cal", "calorie", UnitCategory::Energy, 4.184)
+    }
+
+    pub fn kilocalorie() -> Unit {
+        Unit::new("kcal", "kilocalorie", UnitCategory::Energy, 4184.0)
+    }
+
+    pub fn watt_hour() -> Unit {
+        Unit::new("Wh", "watt-hour", UnitCategory::Energy, 3600.0).metric(SiPrefix::Base)
+    }
+
+    pub fn kilowatt_hour() -> Unit {
+        Unit::new("kWh", "kilowatt-hour", UnitCategory::Energy, 3_600_000.0).metric(SiPrefix::Kilo)
+    }
+
+    pub fn electronvolt() -> Unit {
+        Unit::new(
+            "eV",
+            "electronvolt",
+            UnitCategory::Energy,
+            1.602_176_634e-19,
+        )
+        .metric(SiPrefix::Base)
+    }
+
+    pub fn all() -> Vec<Unit> {
+        vec![
+            joule(),
+            kilojoule(),
+            newton_meter(),
+            calorie(),
+            kilocalorie(),
+            watt_hour(),
+            kilowatt_hour(),
+            electronvolt(),
+        ]
+    }
+}
+
 /// Parse a value with unit from string
 fn parse_unit_value(input: &str, available_units: &[Unit]) -> Option<UnitValue> {
     let trimmed = input.trim();
@@ -380,6 +1123,14 @@ pub fn UnitInput(
     #[prop(optional)]
     on_change: Option<Callback<UnitValue>>,
 
+    /// Value restored by reset; also the starting value when `value` is not set
+    #[prop(optional)]
+    default_value: Option<UnitValue>,
+
+    /// Receives the field's dirty-tracking and reset handle on mount
+    #[prop(optional)]
+    on_register: Option<Callback<FieldState<UnitValue>>>,
+
     /// Available units for this input
     #[prop(into)]
     units: Vec<Unit>,
@@ -396,6 +1147,21 @@ pub fn UnitInput(
     #[prop(optional)]
     on_unit_change: Option<Callback<Unit>>,
 
+    /// Dimension every unit must have; values entered or selected in
+    /// another dimension are rejected with an error
+    #[prop(optional)]
+    expected_dimension: Option<Dimension>,
+
+    /// Rescale metric values on blur or value change to the SI prefix that
+    /// keeps the number between 1 and 1000 (1500 m shows as 1.5 km)
+    #[prop(optional)]
+    auto_prefix: bool,
+
+    /// Prefixes `auto_prefix` may pick, e.g. `SiPrefix::Milli..=SiPrefix::Kilo`;
+    /// femto to peta by default
+    #[prop(optional)]
+    prefix_range: Option<RangeInclusive<SiPrefix>>,
+
     /// Input variant styling
     #[prop(optional)]
     variant: Option<InputVariant>,
@@ -448,8 +1214,23 @@ pub fn UnitInput(
     let default_unit = units.first().cloned().unwrap_or_else(length::meter);
 
     // Internal unit value
-    let unit_value =
-        value.unwrap_or_else(|| RwSignal::new(UnitValue::new(0.0, default_unit.clone())));
+    let unit_value = value.unwrap_or_else(|| {
+        RwSignal::new(
+            default_value
+                .clone()
+                .unwrap_or_else(|| UnitValue::new(0.0, default_unit.clone())),
+        )
+    });
+    if let Some(callback) = on_register {
+        let default = default_value.unwrap_or_else(|| unit_value.get_untracked());
+        let field_state = FieldState::new(unit_value, default).with_reset(move |default| {
+            unit_value.set(default.clone());
+            if let Some(callback) = on_change {
+                callback.run(default);
+            }
+        });
+        callback.run(field_state);
+    }
 
     // Text representation for editing
     let display_text = RwSignal::new(String::new());
@@ -457,18 +1238,76 @@ pub fn UnitInput(
     // Track if user is actively editing
     let is_editing = RwSignal::new(false);
 
+    // Rejected unit of the wrong dimension, shown in place of `error`
+    let dimension_error = RwSignal::new(None::<UnitDimensionError>);
+    let check_dimension = move |unit: &Unit| match expected_dimension {
+        Some(expected) => {
+            let result = unit.check_dimension(expected);
+            dimension_error.set(result.clone().err());
+            result.is_ok()
+        }
+        None => true,
+    };
+
     // Clone error for use in multiple closures
     let error_for_style = error.clone();
     let error_for_display = error.clone();
 
+    // Rescale to a readable prefix when `auto_prefix` is on. A unit picked
+    // in the selector sticks until the value is edited again.
+    let prefix_range = prefix_range.unwrap_or(SiPrefix::Femto..=SiPrefix::Peta);
+    let rescale = move |uv: UnitValue| {
+        if auto_prefix {
+            uv.with_auto_prefix(&prefix_range)
+        } else {
+            uv
+        }
+    };
+    let unit_pinned = StoredValue::new(false);
+
     // Initialize display text from value
+    let rescale_on_change = rescale.clone();
     Effect::new(move || {
         if !is_editing.get() {
             let uv = unit_value.get();
+            if !unit_pinned.get_value() {
+                let scaled = rescale_on_change(uv.clone());
+                if scaled.unit != uv.unit {
+                    // Runs again with the rescaled value
+                    unit_value.set(scaled.clone());
+                    if let Some(callback) = on_unit_change {
+                        callback.run(scaled.unit);
+                    }
+                    return;
+                }
+            }
             display_text.set(format!("{:.prec$}", uv.value, prec = precision as usize));
         }
     });
 
+    // Store an edited value, rescaled, and notify
+    let commit = move |new_value: UnitValue| {
+        unit_pinned.set_value(false);
+        let new_value = rescale(new_value);
+        let unit_changed = unit_value.with_untracked(|uv| uv.unit != new_value.unit);
+        unit_value.set(new_value.clone());
+
+        if let Some(callback) = on_change {
+            callback.run(new_value.clone());
+        }
+        if unit_changed {
+            if let Some(callback) = on_unit_change {
+                callback.run(new_value.unit.clone());
+            }
+        }
+
+        display_text.set(format!(
+            "{:.prec$}",
+            new_value.value,
+            prec = precision as usize
+        ));
+    };
+
     // Handle input changes
     let handle_input = move |ev: ev::Event| {
         let input_value = event_target_value(&ev);
@@ -490,6 +1329,12 @@ pub fn UnitInput(
 
         // Try to parse with unit, or just as number with current unit
         if let Some(parsed) = parse_unit_value(&text, &units_for_blur) {
+            if !check_dimension(&parsed.unit) {
+                let uv = unit_value.get();
+                display_text.set(format!("{:.prec$}", uv.value, prec = precision as usize));
+                return;
+            }
+
             // If parsed unit is different but compatible, convert
             let final_value = if parsed.unit.symbol != current_unit.symbol {
                 if let Some(converted) = parsed.convert_to(&current_unit) {
@@ -501,27 +1346,10 @@ pub fn UnitInput(
                 UnitValue::new(parsed.value, current_unit.clone())
             };
 
-            unit_value.set(final_value.clone());
-
-            if let Some(callback) = on_change {
-                callback.run(final_value.clone());
-            }
-
-            display_text.set(format!(
-                "{:.prec$}",
-                final_value.value,
-                prec = precision as usize
-            ));
+            commit(final_value);
         } else if let Ok(num) = text.parse::<f64>() {
             // Just a number, keep current unit
-            let new_value = UnitValue::new(num, current_unit);
-            unit_value.set(new_value.clone());
-
-            if let Some(callback) = on_change {
-                callback.run(new_value);
-            }
-
-            display_text.set(format!("{:.prec$}", num, prec = precision as usize));
+            commit(UnitValue::new(num, current_unit));
         } else if !text.is_empty() {
             // Invalid input - revert
             let uv = unit_value.get();
@@ -529,10 +1357,14 @@ pub fn UnitInput(
         }
     };
 
-    // Handle unit change from selector
+    // Handle unit change from selector, returning whether it was accepted
     let handle_unit_change = move |new_unit: Unit| {
         let current = unit_value.get();
 
+        if !check_dimension(&new_unit) {
+            return false;
+        }
+
         if current.unit.symbol != new_unit.symbol {
             // Convert value to new unit if compatible
             let new_value = if let Some(converted) = current.convert_to(&new_unit) {
@@ -541,6 +1373,7 @@ pub fn UnitInput(
                 UnitValue::new(current.value, new_unit.clone())
             };
 
+            unit_pinned.set_value(true);
             unit_value.set(new_value.clone());
             display_text.set(format!(
                 "{:.prec$}",
@@ -556,6 +1389,8 @@ pub fn UnitInput(
                 callback.run(new_unit);
             }
         }
+
+        true
     };
 
     // Styles
@@ -575,7 +1410,7 @@ pub fn UnitInput(
             InputSize::Xl => ("3rem", &*theme_val.typography.font_sizes.lg, "0 1.25rem"),
         };
 
-        let border_color = if error_for_style.is_some() {
+        let border_color = if error_for_style.is_some() || dimension_error.with(Option::is_some) {
             scheme_colors
                 .get_color("red", 6)
                 .unwrap_or_else(|| "#fa5252".to_string())
@@ -702,18 +1537,28 @@ pub fn UnitInput(
                 {move || {
                     if show_unit_selector && units_for_select.len() > 1 {
                         let current_unit = unit_value.get().unit;
-                        let units_clone = units_for_select.clone();
+                        // Prefixed units picked by `auto_prefix` may not be in the list
+                        let mut options = units_for_select.clone();
+                        if !options.iter().any(|u| u.symbol == current_unit.symbol) {
+                            options.push(current_unit.clone());
+                        }
+                        let units_clone = options.clone();
                         view! {
                             <select
                                 style="border: none; background: transparent; cursor: pointer; font-size: inherit; color: inherit; padding: 0 0.25rem; min-width: 3rem;"
                                 on:change=move |ev| {
                                     let symbol = event_target_value(&ev);
                                     if let Some(unit) = units_clone.iter().find(|u| u.symbol == symbol) {
-                                        handle_unit_change(unit.clone());
+                                        if !handle_unit_change(unit.clone()) {
+                                            // Rejected: put the selector back on the current unit
+                                            let select: web_sys::HtmlSelectElement =
+                                                ev.target().unwrap().unchecked_into();
+                                            select.set_value(&unit_value.get_untracked().unit.symbol);
+                                        }
                                     }
                                 }
                             >
-                                {units_for_select.iter().map(|u| {
+                                {options.iter().map(|u| {
                                     let is_selected = u.symbol == current_unit.symbol;
                                     let symbol_value = u.symbol.clone();
                                     let symbol_display = u.symbol.clone();
@@ -739,9 +1584,13 @@ pub fn UnitInput(
                 <div style=description_styles>{d}</div>
             })}
 
-            {error_for_display.clone().map(|e| view! {
-                <div style=error_styles role="alert">{e}</div>
-            })}
+            {move || {
+                dimension_error
+                    .get()
+                    .map(|e| e.to_string())
+                    .or_else(|| error_for_display.clone())
+                    .map(|e| view! { <div style=error_styles role="alert">{e}</div> })
+            }}
         </div>
     }
 }
@@ -769,6 +1618,121 @@ mod tests {
         assert!(!m.is_compatible(&kg));
     }
 
+    #[test]
+    fn test_unit_dimension() {
+        assert_eq!(length::meter().dimension(), Some(Dimension::LENGTH));
+        assert_eq!(mass::kilogram().dimension(), Some(Dimension::MASS));
+        assert_eq!(data::byte().dimension(), None);
+        assert_eq!(Dimension::FORCE.to_string(), "force");
+        assert_eq!(Dimension::new(1, 0, -2, 0).to_string(), "L·T^-2");
+    }
+
+    #[test]
+    fn test_check_dimension_rejects_mass_in_length_field() {
+        assert!(length::kilometer()
+            .check_dimension(Dimension::LENGTH)
+            .is_ok());
+
+        let err = mass::kilogram()
+            .check_dimension(Dimension::LENGTH)
+            .unwrap_err();
+        assert_eq!(err.unit, "kg");
+        assert_eq!(err.found, Some(Dimension::MASS));
+        assert_eq!(
+            err.to_string(),
+            "Expected a length unit, but kg is a mass unit"
+        );
+
+        // A pasted "5 kg" parses, but fails the length check
+        let mut units = length::all();
+        units.extend(mass::all());
+        let parsed = parse_unit_value("5 kg", &units).unwrap();
+        assert!(parsed.unit.check_dimension(Dimension::LENGTH).is_err());
+    }
+
+    #[test]
+    fn test_check_dimension_without_physical_dimension() {
+        let err = data::byte().check_dimension(Dimension::LENGTH).unwrap_err();
+        assert_eq!(err.found, None);
+        assert_eq!(
+            err.to_string(),
+            "Expected a length unit, but B has no physical dimension"
+        );
+    }
+
+    #[test]
+    fn test_compound_unit_round_trip() {
+        let kmh = CompoundUnit::from(length::kilometer()).per(time::hour());
+        let ms = CompoundUnit::from(length::meter()).per(time::second());
+        assert_eq!(kmh.dimension(), Some(Dimension::SPEED));
+        assert_eq!(kmh.symbol(), "km/h");
+        assert_eq!(kmh.name(), "kilometer per hour");
+
+        let there = kmh.convert(36.0, &ms).unwrap();
+        assert!((there - 10.0).abs() < 1e-9);
+        let back = ms.convert(there, &kmh).unwrap();
+        assert!((back - 36.0).abs() < 1e-9);
+
+        // The same conversion through UnitValue and the velocity helpers
+        let value = UnitValue::new(36.0, velocity::kilometer_per_hour());
+        let converted = value.convert_to(&velocity::meter_per_second()).unwrap();
+        assert!((converted.value - 10.0).abs() < 1e-9);
+        assert_eq!(converted.unit.category, UnitCategory::Speed);
+    }
+
+    #[test]
+    fn test_compound_unit_rejects_incompatible() {
+        let ms = CompoundUnit::from(length::meter()).per(time::second());
+        let newton_meter = CompoundUnit::from(force::newton()).times(length::meter());
+        assert_eq!(newton_meter.dimension(), Some(Dimension::ENERGY));
+        assert!(!ms.is_convertible(&newton_meter));
+
+        let err = ms.convert(1.0, &newton_meter).unwrap_err();
+        assert_eq!(err.found, Some(Dimension::SPEED));
+        assert_eq!(
+            err.to_string(),
+            "Expected a energy unit, but m/s is a speed unit"
+        );
+
+        let value = UnitValue::new(1.0, velocity::meter_per_second());
+        assert!(value.convert_to(&energy::newton_meter()).is_none());
+        // Compounds with non-physical factors never convert
+        let rate = CompoundUnit::from(data::megabyte()).per(time::second());
+        assert_eq!(rate.dimension(), None);
+        assert!(!rate.is_convertible(&ms));
+    }
+
+    #[test]
+    fn test_compound_unit_symbols() {
+        let accel = CompoundUnit::from(length::meter()).pow(time::second(), -2);
+        assert_eq!(accel.symbol(), "m/s²");
+        assert_eq!(accel.name(), "meter per second squared");
+        assert_eq!(
+            accel.to_unit().category,
+            UnitCategory::Derived(Dimension::new(1, 0, -2, 0))
+        );
+        assert_eq!(energy::newton_meter().symbol, "N·m");
+        assert_eq!(CompoundUnit::default().per(time::second()).symbol(), "s⁻¹");
+        // Repeated factors combine and cancel
+        let m = CompoundUnit::from(length::meter()).per(length::meter());
+        assert!(m.factors().is_empty());
+        assert_eq!(m.dimension(), Some(Dimension::DIMENSIONLESS));
+    }
+
+    #[test]
+    fn test_derived_unit_factors() {
+        assert!((force::newton().to_base - 1.0).abs() < 1e-12);
+        assert!((force::dyne().to_base - 1e-5).abs() < 1e-15);
+        assert_eq!(energy::joule().category, UnitCategory::Energy);
+        let kwh = UnitValue::new(1.0, energy::kilowatt_hour());
+        let joules = kwh.convert_to(&energy::joule()).unwrap();
+        assert!((joules.value - 3_600_000.0).abs() < 1e-6);
+        // Compound and hand-written speed units interoperate
+        let mph = UnitValue::new(60.0, velocity::mile_per_hour());
+        let kmh = mph.convert_to(&velocity::kilometer_per_hour()).unwrap();
+        assert!((kmh.value - 96.56064).abs() < 1e-6);
+    }
+
     #[test]
     fn test_unit_value_to_base() {
         let uv = UnitValue::new(1.0, length::kilometer());
@@ -887,4 +1851,99 @@ mod tests {
         let hour_value = day_value.convert_to(&time::hour()).unwrap();
         assert!((hour_value.value - 24.0).abs() < 0.0001);
     }
+
+    const ALL_PREFIXES: RangeInclusive<SiPrefix> = SiPrefix::Femto..=SiPrefix::Peta;
+
+    fn auto(value: f64, unit: Unit) -> UnitValue {
+        UnitValue::new(value, unit).with_auto_prefix(&ALL_PREFIXES)
+    }
+
+    #[test]
+    fn test_with_prefix() {
+        let mm = length::kilometer().with_prefix(SiPrefix::Milli).unwrap();
+        assert_eq!(mm.symbol, "mm");
+        assert_eq!(mm.name, "millimeter");
+        assert_eq!(mm.to_base, 0.001);
+        assert_eq!(
+            mass::kilogram().with_prefix(SiPrefix::Base),
+            Some(mass::gram())
+        );
+        assert_eq!(length::inch().with_prefix(SiPrefix::Kilo), None);
+        assert!(!length::inch().is_metric());
+    }
+
+    #[test]
+    fn test_auto_prefix_keeps_mantissa_between_1_and_1000() {
+        let v = auto(1500.0, length::meter());
+        assert_eq!((v.value, v.unit.symbol.as_str()), (1.5, "km"));
+
+        let v = auto(0.002, length::meter());
+        assert_eq!((v.value, v.unit.symbol.as_str()), (2.0, "mm"));
+
+        let v = auto(-2.5e-7, time::second());
+        assert_eq!(v.unit.symbol, "ns");
+        assert!((v.value + 250.0).abs() < 1e-9);
+
+        // Already readable
+        assert_eq!(auto(999.5, length::meter()).unit.symbol, "m");
+
+        // Centi is never picked
+        let v = auto(50.0, length::centimeter());
+        assert_eq!((v.value, v.unit.symbol.as_str()), (500.0, "mm"));
+    }
+
+    #[test]
+    fn test_auto_prefix_at_exact_powers_of_ten() {
+        for (value, symbol) in [
+            (1.0, "m"),
+            (1000.0, "km"),
+            (1e6, "Mm"),
+            (0.001, "mm"),
+            (1e-6, "μm"),
+            (1e-9, "nm"),
+        ] {
+            let v = auto(value, length::meter());
+            assert_eq!((v.value, v.unit.symbol.as_str()), (1.0, symbol), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_auto_prefix_zero_stays_at_base_unit() {
+        let v = auto(0.0, length::kilometer());
+        assert_eq!((v.value, v.unit.symbol.as_str()), (0.0, "m"));
+
+        // Base out of range: the prefix nearest to it
+        let v = UnitValue::new(0.0, length::meter())
+            .with_auto_prefix(&(SiPrefix::Kilo..=SiPrefix::Giga));
+        assert_eq!(v.unit.symbol, "km");
+    }
+
+    #[test]
+    fn test_auto_prefix_preserves_base_value_exactly() {
+        for value in [1234.5678, 0.000_123_4, 3.3e-12, 7.77e13, 0.1, 299_792_458.0] {
+            let original = UnitValue::new(value, length::meter());
+            let scaled = original.with_auto_prefix(&ALL_PREFIXES);
+            assert_eq!(scaled.to_base(), original.to_base(), "{value}");
+        }
+
+        let original = UnitValue::new(42_000.0, mass::gram());
+        let scaled = original.with_auto_prefix(&ALL_PREFIXES);
+        assert_eq!(scaled.unit, mass::kilogram());
+        assert_eq!(scaled.to_base(), original.to_base());
+    }
+
+    #[test]
+    fn test_auto_prefix_respects_range_and_metric_flag() {
+        let range = SiPrefix::Milli..=SiPrefix::Kilo;
+        let v = UnitValue::new(5e6, length::meter()).with_auto_prefix(&range);
+        assert_eq!((v.value, v.unit.symbol.as_str()), (5000.0, "km"));
+
+        let v = UnitValue::new(2e-6, length::meter()).with_auto_prefix(&range);
+        assert_eq!(v.unit.symbol, "mm");
+
+        let inches = UnitValue::new(50_000.0, length::inch());
+        assert_eq!(inches.with_auto_prefix(&ALL_PREFIXES), inches);
+        let nan = UnitValue::new(f64::NAN, length::meter());
+        assert_eq!(nan.with_auto_prefix(&ALL_PREFIXES).unit.symbol, "m");
+    }
 }
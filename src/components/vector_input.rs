@@ -5,7 +5,7 @@
 
 use crate::components::input::InputSize;
 use crate::theme::use_theme;
-use crate::utils::StyleBuilder;
+use crate::utils::{EditHistory, HistoryShortcut, StyleBuilder};
 use leptos::ev;
 use leptos::prelude::*;
 use std::f64::consts::PI;
@@ -55,6 +55,37 @@ impl VectorNotation {
     }
 }
 
+/// Why a vector operation has no result
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VectorOpError {
+    /// The operands have different dimensions
+    DimensionMismatch { left: usize, right: usize },
+    /// The cross product is only defined for two 3D vectors
+    CrossProductNot3D { left: usize, right: usize },
+    /// The zero vector has no direction
+    ZeroVector,
+}
+
+impl std::fmt::Display for VectorOpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DimensionMismatch { left, right } => write!(
+                f,
+                "Vectors have different dimensions ({}D and {}D)",
+                left, right
+            ),
+            Self::CrossProductNot3D { left, right } => write!(
+                f,
+                "The cross product needs two 3D vectors, got {}D and {}D",
+                left, right
+            ),
+            Self::ZeroVector => write!(f, "The zero vector can't be normalized"),
+        }
+    }
+}
+
+impl std::error::Error for VectorOpError {}
+
 /// Represents a mathematical vector
 #[derive(Clone, Debug, PartialEq)]
 pub struct Vector {
@@ -133,13 +164,18 @@ impl Vector {
         self.components.iter().map(|x| x * x).sum()
     }
 
-    /// Normalize to unit vector
+    /// Normalize to unit vector; `None` for the zero vector
     pub fn normalize(&self) -> Option<Vector> {
+        self.try_normalize().ok()
+    }
+
+    /// Normalize to unit vector, reporting why the zero vector can't be
+    pub fn try_normalize(&self) -> Result<Vector, VectorOpError> {
         let mag = self.magnitude();
         if mag < 1e-10 {
-            return None;
+            return Err(VectorOpError::ZeroVector);
         }
-        Some(Vector::new(
+        Ok(Vector::new(
             self.components.iter().map(|x| x / mag).collect(),
         ))
     }
@@ -151,27 +187,42 @@ impl Vector {
 
     /// Calculate dot product with another vector
     pub fn dot(&self, other: &Vector) -> Option<f64> {
+        self.try_dot(other).ok()
+    }
+
+    /// Dot product, reporting mismatched dimensions
+    pub fn try_dot(&self, other: &Vector) -> Result<f64, VectorOpError> {
         if self.dimensions() != other.dimensions() {
-            return None;
+            return Err(VectorOpError::DimensionMismatch {
+                left: self.dimensions(),
+                right: other.dimensions(),
+            });
         }
-        Some(
-            self.components
-                .iter()
-                .zip(other.components.iter())
-                .map(|(a, b)| a * b)
-                .sum(),
-        )
+        Ok(self
+            .components
+            .iter()
+            .zip(other.components.iter())
+            .map(|(a, b)| a * b)
+            .sum())
     }
 
     /// Calculate cross product (3D only)
     pub fn cross(&self, other: &Vector) -> Option<Vector> {
+        self.try_cross(other).ok()
+    }
+
+    /// Cross product, reporting operands that aren't both 3D
+    pub fn try_cross(&self, other: &Vector) -> Result<Vector, VectorOpError> {
         if self.dimensions() != 3 || other.dimensions() != 3 {
-            return None;
+            return Err(VectorOpError::CrossProductNot3D {
+                left: self.dimensions(),
+                right: other.dimensions(),
+            });
         }
         let x = self.y() * other.z() - self.z() * other.y();
         let y = self.z() * other.x() - self.x() * other.z();
         let z = self.x() * other.y() - self.y() * other.x();
-        Some(Vector::new_3d(x, y, z))
+        Ok(Vector::new_3d(x, y, z))
     }
 
     /// Calculate angle between vectors (in radians)
@@ -286,6 +337,41 @@ impl Vector {
         }
     }
 
+    /// Format with named components (e.g., "Fx = 3, Fy = 4, Fz = -2").
+    ///
+    /// Returns `None` if the number of labels does not match the dimensions.
+    pub fn to_labeled_notation(&self, labels: &[String]) -> Option<String> {
+        validate_component_labels(labels, self.dimensions()).ok()?;
+        let parts: Vec<String> = labels
+            .iter()
+            .zip(&self.components)
+            .map(|(label, val)| format!("{} = {}", label, format_number(*val)))
+            .collect();
+        Some(parts.join(", "))
+    }
+
+    /// Format in a notation with fixed decimals, e.g. `⟨0.6000, 0.8000⟩`.
+    /// Column vectors are written as a transposed row.
+    pub fn format(&self, notation: VectorNotation, precision: usize) -> String {
+        // Round first so "-0.0000" and 0.99999 → "1" in unit notation can't occur
+        let scale = 10f64.powi(precision as i32);
+        let rounded: Vec<f64> = self
+            .components
+            .iter()
+            .map(|v| (v * scale).round() / scale + 0.0)
+            .collect();
+        if notation == VectorNotation::UnitVector {
+            return Vector::new(rounded).to_unit_notation();
+        }
+        let body = rounded
+            .iter()
+            .map(|v| format!("{:.prec$}", v, prec = precision))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let suffix = if notation.is_vertical() { "ᵀ" } else { "" };
+        format!("{}{}{}{}", notation.left(), body, notation.right(), suffix)
+    }
+
     /// Format as LaTeX
     pub fn to_latex(&self, column: bool) -> String {
         let vals: Vec<String> = self.components.iter().map(|v| format_number(*v)).collect();
@@ -300,6 +386,38 @@ impl Vector {
     }
 }
 
+/// Check that custom component labels cover every dimension exactly
+pub fn validate_component_labels(labels: &[String], dimensions: usize) -> Result<(), String> {
+    if labels.len() == dimensions {
+        Ok(())
+    } else {
+        Err(format!(
+            "Expected {} component labels, got {}",
+            dimensions,
+            labels.len()
+        ))
+    }
+}
+
+/// Default label for a component: x, y, z, w, then subscripted xₙ
+fn default_component_label(index: usize) -> String {
+    match index {
+        0 => "x".to_string(),
+        1 => "y".to_string(),
+        2 => "z".to_string(),
+        3 => "w".to_string(),
+        _ => {
+            let subscript: String = (index + 1)
+                .to_string()
+                .chars()
+                .filter_map(|c| c.to_digit(10))
+                .map(|d| char::from_u32(0x2080 + d).unwrap_or('?'))
+                .collect();
+            format!("x{}", subscript)
+        }
+    }
+}
+
 /// Format a number, removing unnecessary trailing zeros
 fn format_number(value: f64) -> String {
     if value.fract() == 0.0 {
@@ -333,6 +451,12 @@ pub fn VectorInput(
     #[prop(optional)]
     notation: VectorNotation,
 
+    /// Custom component labels (e.g. `["r", "θ", "z"]` or `["Fx", "Fy", "Fz"]`).
+    /// Must have one entry per dimension; otherwise x/y/z labels are used
+    /// and an error is shown.
+    #[prop(optional)]
+    component_labels: Option<Vec<String>>,
+
     /// Whether to show magnitude and direction
     #[prop(optional, default = true)]
     show_magnitude: bool,
@@ -360,15 +484,78 @@ pub fn VectorInput(
     /// Whether the input is disabled
     #[prop(optional)]
     disabled: Signal<bool>,
+
+    /// Enable undo/redo with Ctrl+Z/Ctrl+Y
+    #[prop(optional, default = true)]
+    enable_undo_redo: bool,
+
+    /// Maximum undo history size
+    #[prop(optional, default = 50)]
+    undo_history_size: usize,
+
+    /// Whether to show undo/redo buttons
+    #[prop(optional, default = false)]
+    show_history_controls: bool,
+
+    /// Show an operations panel with a normalize button. Pair two inputs
+    /// with [`VectorOps`] for dot and cross products.
+    #[prop(optional, default = false)]
+    show_operations: bool,
 ) -> impl IntoView {
     let theme = use_theme();
 
     // Internal state
     let internal_vector = value.unwrap_or_else(|| RwSignal::new(Vector::zeros(dimensions)));
 
+    // Undo/redo history; each resize and each component edit is one step
+    let history = RwSignal::new(EditHistory::new(if enable_undo_redo {
+        undo_history_size
+    } else {
+        0
+    }));
+    let component_edit_recorded = RwSignal::new(false);
+
+    let record_history = move || {
+        history.update(|h| h.record(internal_vector.get_untracked()));
+    };
+
+    let step_history = move |shortcut: HistoryShortcut| {
+        let current = internal_vector.get_untracked();
+        let restored = history
+            .try_update(|h| match shortcut {
+                HistoryShortcut::Undo => h.undo(current),
+                HistoryShortcut::Redo => h.redo(current),
+            })
+            .flatten();
+        if let Some(vec) = restored {
+            component_edit_recorded.set(false);
+            internal_vector.set(vec.clone());
+            if let Some(cb) = on_change {
+                cb.run(vec);
+            }
+        }
+    };
+
+    let handle_history_keydown = move |ev: ev::KeyboardEvent| {
+        if !enable_undo_redo || disabled.get_untracked() {
+            return;
+        }
+        if let Some(shortcut) =
+            HistoryShortcut::from_key(&ev.key(), ev.ctrl_key() || ev.meta_key(), ev.shift_key())
+        {
+            ev.prevent_default();
+            step_history(shortcut);
+        }
+    };
+
     // Update vector when component changes
     let update_component = move |index: usize, value: String| {
         if let Ok(num) = value.parse::<f64>() {
+            // Snapshot once per edit session, not per keystroke
+            if !component_edit_recorded.get_untracked() {
+                record_history();
+                component_edit_recorded.set(true);
+            }
             let mut vec = internal_vector.get();
             vec.set(index, num);
             internal_vector.set(vec.clone());
@@ -398,8 +585,21 @@ pub fn VectorInput(
         let _ = new_index;
     };
 
+    // Replace the vector with its unit vector
+    let normalize = move |_| {
+        if let Some(unit) = internal_vector.get_untracked().normalize() {
+            record_history();
+            component_edit_recorded.set(false);
+            internal_vector.set(unit.clone());
+            if let Some(cb) = on_change {
+                cb.run(unit);
+            }
+        }
+    };
+
     // Add dimension
     let add_dimension = move |_| {
+        record_history();
         let vec = internal_vector.get();
         let mut new_components = vec.components.clone();
         new_components.push(0.0);
@@ -410,21 +610,31 @@ pub fn VectorInput(
     let remove_dimension = move |_| {
         let vec = internal_vector.get();
         if vec.dimensions() > 1 {
+            record_history();
             let mut new_components = vec.components.clone();
             new_components.pop();
             internal_vector.set(Vector::new(new_components));
         }
     };
 
-    // Component labels
-    let get_label = move |index: usize| -> &'static str {
-        match index {
-            0 => "x",
-            1 => "y",
-            2 => "z",
-            3 => "w",
-            _ => "•",
-        }
+    // Component labels, valid only while they match the current dimensions
+    let component_labels = StoredValue::new(component_labels);
+    let labels_check = move || {
+        component_labels.with_value(|labels| {
+            labels.as_ref().map(|labels| {
+                validate_component_labels(labels, internal_vector.with(|v| v.dimensions()))
+            })
+        })
+    };
+    let custom_labels = move || {
+        matches!(labels_check(), Some(Ok(())))
+            .then(|| component_labels.get_value())
+            .flatten()
+    };
+    let get_label = move |index: usize| -> String {
+        custom_labels()
+            .and_then(|labels| labels.get(index).cloned())
+            .unwrap_or_else(|| default_component_label(index))
     };
 
     // Styles
@@ -632,7 +842,7 @@ pub fn VectorInput(
     };
 
     view! {
-        <div class="mingot-vector-input" style=container_styles>
+        <div class="mingot-vector-input" style=container_styles on:keydown=handle_history_keydown>
             {label.clone().map(|l| view! {
                 <label style=label_styles>{l}</label>
             })}
@@ -676,6 +886,7 @@ pub fn VectorInput(
                                             val_str.set(new_val.clone());
                                             update_component(i, new_val);
                                         }
+                                        on:focus=move |_| component_edit_recorded.set(false)
                                         on:keydown=move |ev| {
                                             handle_keydown(i, ev);
                                         }
@@ -708,6 +919,55 @@ pub fn VectorInput(
                 }
             })}
 
+            {(enable_undo_redo && show_history_controls).then(|| {
+                view! {
+                    <div style="display: flex; gap: 0.5rem;">
+                        <button
+                            type="button"
+                            style=resize_button_styles
+                            title="Undo (Ctrl+Z)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_undo())
+                            on:click=move |_| step_history(HistoryShortcut::Undo)
+                        >
+                            {"↶ Undo"}
+                        </button>
+                        <button
+                            type="button"
+                            style=resize_button_styles
+                            title="Redo (Ctrl+Y)"
+                            disabled=move || disabled.get() || !history.with(|h| h.can_redo())
+                            on:click=move |_| step_history(HistoryShortcut::Redo)
+                        >
+                            {"↷ Redo"}
+                        </button>
+                    </div>
+                }
+            })}
+
+            {show_operations.then(|| {
+                view! {
+                    <div class="mingot-vector-operations" style="display: flex; gap: 0.5rem; align-items: center;">
+                        <button
+                            type="button"
+                            style=resize_button_styles
+                            title="Scale to length 1"
+                            disabled=move || {
+                                disabled.get() || internal_vector.with(|v| v.normalize().is_none())
+                            }
+                            on:click=normalize
+                        >
+                            {"Normalize"}
+                        </button>
+                        <span style=info_styles>
+                            {move || match internal_vector.with(|v| v.try_normalize()) {
+                                Ok(unit) => format!("v̂ = {}", unit.format(notation, precision)),
+                                Err(e) => e.to_string(),
+                            }}
+                        </span>
+                    </div>
+                }
+            })}
+
             {show_magnitude.then(|| {
                 view! {
                     <div style=info_styles>
@@ -719,8 +979,12 @@ pub fn VectorInput(
                             let mag = vec.magnitude();
                             info.push(format!("|v| = {:.prec$}", mag, prec = precision));
 
-                            // Unit vector notation (for 2D/3D)
-                            if vec.dimensions() <= 3 {
+                            // Named components, or unit vector notation (for 2D/3D)
+                            if let Some(labeled) =
+                                custom_labels().and_then(|labels| vec.to_labeled_notation(&labels))
+                            {
+                                info.push(labeled);
+                            } else if vec.dimensions() <= 3 {
                                 info.push(vec.to_unit_notation());
                             }
 
@@ -747,6 +1011,180 @@ pub fn VectorInput(
             {error.map(|e| view! {
                 <div style=error_styles>{e}</div>
             })}
+
+            {move || match labels_check() {
+                Some(Err(e)) => Some(view! { <div style=error_styles>{e}</div> }),
+                _ => None,
+            }}
+        </div>
+    }
+}
+
+/// Product shown by [`VectorOps`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VectorProduct {
+    Dot,
+    Cross,
+}
+
+impl VectorProduct {
+    pub fn label(&self) -> &'static str {
+        match self {
+            VectorProduct::Dot => "a · b",
+            VectorProduct::Cross => "a × b",
+        }
+    }
+}
+
+/// Result line for a product of `a` and `b`, formatted in `notation`
+fn product_text(
+    product: VectorProduct,
+    a: &Vector,
+    b: &Vector,
+    notation: VectorNotation,
+    precision: usize,
+) -> Result<String, VectorOpError> {
+    let result = match product {
+        VectorProduct::Dot => format!("{:.prec$}", a.try_dot(b)? + 0.0, prec = precision),
+        VectorProduct::Cross => a.try_cross(b)?.format(notation, precision),
+    };
+    Ok(format!("{} = {}", product.label(), result))
+}
+
+/// Dot and cross products of two vectors, usually the values of two
+/// [`VectorInput`]s. The cross product button is only enabled while both
+/// vectors are 3D.
+///
+/// # Example
+/// ```rust,ignore
+/// let a = RwSignal::new(Vector::new_3d(1.0, 0.0, 0.0));
+/// let b = RwSignal::new(Vector::new_3d(0.0, 1.0, 0.0));
+///
+/// view! {
+///     <VectorInput value=a />
+///     <VectorInput value=b />
+///     <VectorOps a=a b=b />
+/// }
+/// ```
+#[component]
+pub fn VectorOps(
+    /// Left operand
+    #[prop(into)]
+    a: Signal<Vector>,
+
+    /// Right operand
+    #[prop(into)]
+    b: Signal<Vector>,
+
+    /// Notation for vector results
+    #[prop(optional)]
+    notation: VectorNotation,
+
+    /// Number of decimal places for display
+    #[prop(optional, default = 4)]
+    precision: usize,
+) -> impl IntoView {
+    let theme = use_theme();
+    let product = RwSignal::new(VectorProduct::Dot);
+
+    let cross_available =
+        move || a.with(|a| a.dimensions() == 3) && b.with(|b| b.dimensions() == 3);
+
+    let button_styles = move |active: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let accent = scheme_colors
+            .get_color("blue", 6)
+            .unwrap_or_else(|| "#228be6".to_string());
+        StyleBuilder::new()
+            .add("padding", "0.25rem 0.5rem")
+            .add(
+                "border",
+                format!(
+                    "1px solid {}",
+                    if active {
+                        accent.clone()
+                    } else {
+                        scheme_colors.border.clone()
+                    }
+                ),
+            )
+            .add("border-radius", &*theme_val.radius.sm)
+            .add(
+                "background",
+                if active {
+                    accent
+                } else {
+                    scheme_colors.background.clone()
+                },
+            )
+            .add(
+                "color",
+                if active {
+                    scheme_colors.white.clone()
+                } else {
+                    scheme_colors.text.clone()
+                },
+            )
+            .add("cursor", "pointer")
+            .add("font-size", &*theme_val.typography.font_sizes.xs)
+            .build()
+    };
+
+    let result_styles = move |is_error: bool| {
+        let theme_val = theme.get();
+        let scheme_colors = crate::theme::get_scheme_colors(&theme_val);
+        let (color, fallback) = if is_error {
+            ("red", "#fa5252")
+        } else {
+            ("gray", "#868e96")
+        };
+        StyleBuilder::new()
+            .add("font-size", &*theme_val.typography.font_sizes.sm)
+            .add("font-family", "monospace")
+            .add(
+                "color",
+                scheme_colors
+                    .get_color(color, 6)
+                    .unwrap_or_else(|| fallback.to_string()),
+            )
+            .build()
+    };
+
+    view! {
+        <div class="mingot-vector-ops" style="display: flex; flex-direction: column; gap: 0.5rem;">
+            <div style="display: flex; gap: 0.25rem;">
+                {[VectorProduct::Dot, VectorProduct::Cross]
+                    .into_iter()
+                    .map(|kind| view! {
+                        <button
+                            type="button"
+                            style=move || button_styles(product.get() == kind)
+                            aria-pressed=move || (product.get() == kind).to_string()
+                            disabled=move || kind == VectorProduct::Cross && !cross_available()
+                            title=move || {
+                                (kind == VectorProduct::Cross && !cross_available())
+                                    .then_some("Only defined for two 3D vectors")
+                            }
+                            on:click=move |_| product.set(kind)
+                        >
+                            {kind.label()}
+                        </button>
+                    })
+                    .collect_view()}
+            </div>
+            {move || {
+                let result = a.with(|a| {
+                    b.with(|b| product_text(product.get(), a, b, notation, precision))
+                });
+                match result {
+                    Ok(text) => view! { <span style=move || result_styles(false)>{text}</span> }.into_any(),
+                    Err(e) => view! {
+                        <span style=move || result_styles(true) role="alert">{e.to_string()}</span>
+                    }
+                    .into_any(),
+                }
+            }}
         </div>
     }
 }
@@ -785,6 +1223,71 @@ mod tests {
         assert!((n.y() - 0.8).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_vector_normalize_zero() {
+        let zero = Vector::zeros(3);
+        assert_eq!(zero.normalize(), None);
+        assert_eq!(zero.try_normalize(), Err(VectorOpError::ZeroVector));
+        // Below the magnitude tolerance counts as zero too
+        assert_eq!(
+            Vector::new_2d(1e-12, 0.0).try_normalize(),
+            Err(VectorOpError::ZeroVector)
+        );
+        assert!(Vector::zeros(0).normalize().is_none());
+    }
+
+    #[test]
+    fn test_vector_try_cross_requires_3d() {
+        let a = Vector::new_2d(1.0, 0.0);
+        let b = Vector::new_3d(0.0, 1.0, 0.0);
+        let err = a.try_cross(&b).unwrap_err();
+        assert_eq!(err, VectorOpError::CrossProductNot3D { left: 2, right: 3 });
+        assert_eq!(
+            err.to_string(),
+            "The cross product needs two 3D vectors, got 2D and 3D"
+        );
+        assert_eq!(
+            a.try_dot(&b),
+            Err(VectorOpError::DimensionMismatch { left: 2, right: 3 })
+        );
+    }
+
+    #[test]
+    fn test_vector_format() {
+        let v = Vector::new_2d(0.6, -0.8);
+        assert_eq!(v.format(VectorNotation::Row, 2), "[0.60, -0.80]");
+        assert_eq!(v.format(VectorNotation::Column, 1), "[0.6, -0.8]ᵀ");
+        assert_eq!(v.format(VectorNotation::AngleBrackets, 1), "⟨0.6, -0.8⟩");
+        assert_eq!(v.format(VectorNotation::Parentheses, 0), "(1, -1)");
+        assert_eq!(v.format(VectorNotation::UnitVector, 1), "0.6î - 0.8ĵ");
+        assert_eq!(
+            Vector::new_2d(-1e-9, 1.0).format(VectorNotation::Row, 2),
+            "[0.00, 1.00]"
+        );
+    }
+
+    #[test]
+    fn test_product_text() {
+        let a = Vector::new_3d(1.0, 0.0, 0.0);
+        let b = Vector::new_3d(0.0, 1.0, 0.0);
+        assert_eq!(
+            product_text(VectorProduct::Dot, &a, &b, VectorNotation::Row, 2),
+            Ok("a · b = 0.00".to_string())
+        );
+        assert_eq!(
+            product_text(VectorProduct::Cross, &a, &b, VectorNotation::Parentheses, 1),
+            Ok("a × b = (0.0, 0.0, 1.0)".to_string())
+        );
+        assert!(product_text(
+            VectorProduct::Cross,
+            &Vector::new_2d(1.0, 0.0),
+            &Vector::new_2d(0.0, 1.0),
+            VectorNotation::Row,
+            2
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_vector_dot_product() {
         let v1 = Vector::new_3d(1.0, 2.0, 3.0);
@@ -873,4 +1376,49 @@ mod tests {
         assert!(VectorNotation::Column.is_vertical());
         assert!(!VectorNotation::Row.is_vertical());
     }
+
+    #[test]
+    fn test_validate_component_labels() {
+        let labels: Vec<String> = ["Fx", "Fy", "Fz"].iter().map(|s| s.to_string()).collect();
+        assert!(validate_component_labels(&labels, 3).is_ok());
+        assert_eq!(
+            validate_component_labels(&labels, 2),
+            Err("Expected 2 component labels, got 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_vector_labeled_notation() {
+        let v = Vector::new_3d(3.0, 4.0, -2.5);
+        let labels: Vec<String> = ["r", "θ", "z"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            v.to_labeled_notation(&labels),
+            Some("r = 3, θ = 4, z = -2.5".to_string())
+        );
+        assert_eq!(v.to_labeled_notation(&labels[..2]), None);
+    }
+
+    #[test]
+    fn test_default_component_labels() {
+        assert_eq!(default_component_label(0), "x");
+        assert_eq!(default_component_label(3), "w");
+        assert_eq!(default_component_label(4), "x₅");
+        assert_eq!(default_component_label(11), "x₁₂");
+    }
+
+    #[test]
+    fn test_vector_history_undo_resize_and_edit() {
+        let mut history = EditHistory::new(50);
+        let original = Vector::new_3d(1.0, 2.0, 3.0);
+
+        // Edit a component, then shrink to 2D
+        history.record(original.clone());
+        let edited = Vector::new_3d(1.0, 5.0, 3.0);
+        history.record(edited.clone());
+        let resized = Vector::new_2d(1.0, 5.0);
+
+        let undone = history.undo(resized).unwrap();
+        assert_eq!(undone, edited);
+        assert_eq!(history.undo(undone), Some(original));
+    }
 }
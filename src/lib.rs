@@ -23,7 +23,7 @@
 //! <NumberInput
 //!     precision=NumberInputPrecision::U64
 //!     label="Large Integer"
-//!     on_valid_change=Callback::new(move |result| {
+//!     on_valid_change=Callback::new(move |value: String| {
 //!         // Supports values up to 18,446,744,073,709,551,615
 //!     })
 //! />
@@ -62,8 +62,10 @@ pub mod validation;
 // Re-export commonly used components
 pub use components::*;
 pub use theme::{
-    use_color_scheme, use_color_scheme_toggle, use_set_color_scheme, use_theme, ActiveColorScheme,
-    ColorSchemeMode, MingotProvider, Theme, ThemeBuilder, ThemeContext, ThemeOverride,
+    use_breakpoint, use_color_scheme, use_color_scheme_toggle, use_direction,
+    use_interaction_modality, use_media_query, use_mingot_config, use_set_color_scheme, use_theme,
+    ActiveColorScheme, Breakpoint, ColorSchemeMode, Direction, InteractionModality, MingotConfig,
+    MingotProvider, StyleOverrides, Theme, ThemeBuilder, ThemeContext, ThemeOverride,
 };
 pub use validation::{ValidationError, ValidationResult, Validator};
 
@@ -74,8 +76,11 @@ pub use leptos;
 pub mod prelude {
     pub use crate::components::*;
     pub use crate::theme::{
-        use_color_scheme, use_color_scheme_toggle, use_set_color_scheme, ActiveColorScheme,
-        ColorSchemeMode, MingotProvider, Theme, ThemeBuilder, ThemeOverride,
+        use_breakpoint, use_color_scheme, use_color_scheme_toggle, use_direction,
+        use_interaction_modality, use_media_query, use_mingot_config, use_set_color_scheme,
+        ActiveColorScheme, Breakpoint, ColorSchemeMode, Direction, InteractionModality,
+        MingotConfig, MingotProvider, StyleOverrides, Theme, ThemeBuilder, ThemeOverride,
     };
+    pub use crate::utils::FieldState;
     pub use crate::validation::{self, ValidationError, ValidationResult, Validator};
 }
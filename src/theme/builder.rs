@@ -1,6 +1,7 @@
 use super::{
-    BorderScale, Breakpoints, ColorPalette, ColorSchemeMode, ColorShades, FontSizes, FontWeights,
-    LayoutTokens, LineHeights, RadiusScale, ShadowScale, Spacing, Theme, Typography,
+    BorderScale, Breakpoints, ColorPalette, ColorScheme, ColorSchemeMode, ColorShades, Direction,
+    FocusRing, FontSizes, FontWeights, InvalidHexColor, LayoutTokens, LineHeights, RadiusScale,
+    ShadowScale, Spacing, Theme, Typography,
 };
 use std::borrow::Cow;
 
@@ -47,6 +48,12 @@ impl ThemeBuilder {
         self
     }
 
+    /// Set the text direction.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.theme.direction = direction;
+        self
+    }
+
     // --- Spacing ---
 
     /// Replace the entire spacing scale.
@@ -230,6 +237,25 @@ impl ThemeBuilder {
         self
     }
 
+    /// Add or replace a color in both schemes with ten shades generated
+    /// from `base` (see [`ColorScheme::generate_palette`]). Components
+    /// then take it by name, e.g. `color="brand"`.
+    ///
+    /// ```rust,ignore
+    /// let theme = ThemeBuilder::new()
+    ///     .generated_color("brand", "#5f3dc4")?
+    ///     .primary_color("brand")
+    ///     .build();
+    /// ```
+    pub fn generated_color(
+        self,
+        name: impl Into<String>,
+        base: &str,
+    ) -> Result<Self, InvalidHexColor> {
+        let shades = Vec::from(ColorScheme::generate_palette(base)?);
+        Ok(self.add_color(name, shades.clone(), shades))
+    }
+
     /// Override background colors for light and dark schemes.
     pub fn background(mut self, light: impl Into<String>, dark: impl Into<String>) -> Self {
         self.theme.colors.light.background = light.into();
@@ -309,6 +335,26 @@ impl ThemeBuilder {
         self
     }
 
+    // --- Focus ring ---
+
+    /// Replace the entire focus ring.
+    pub fn focus_ring(mut self, focus_ring: FocusRing) -> Self {
+        self.theme.focus_ring = focus_ring;
+        self
+    }
+
+    /// Set the focus ring color (defaults to the primary color).
+    pub fn focus_ring_color(mut self, val: impl Into<Cow<'static, str>>) -> Self {
+        self.theme.focus_ring.color = Some(val.into());
+        self
+    }
+
+    /// Set the focus ring outline width.
+    pub fn focus_ring_width(mut self, val: impl Into<Cow<'static, str>>) -> Self {
+        self.theme.focus_ring.width = val.into();
+        self
+    }
+
     // --- Breakpoints ---
 
     /// Replace the entire breakpoints scale.
@@ -353,6 +399,13 @@ mod tests {
         assert_eq!(theme.color_scheme, ColorSchemeMode::Dark);
     }
 
+    #[test]
+    fn test_builder_direction() {
+        assert_eq!(ThemeBuilder::new().build().direction, Direction::Ltr);
+        let theme = ThemeBuilder::new().direction(Direction::Rtl).build();
+        assert_eq!(theme.direction, Direction::Rtl);
+    }
+
     #[test]
     fn test_builder_spacing_individual() {
         let theme = ThemeBuilder::new()
@@ -389,6 +442,25 @@ mod tests {
         assert!(theme.colors.dark.colors.contains_key("custom"));
     }
 
+    #[test]
+    fn test_builder_generated_color() {
+        let theme = ThemeBuilder::new()
+            .generated_color("brand", "#5f3dc4")
+            .unwrap()
+            .primary_color("brand")
+            .build();
+        assert_eq!(
+            theme.colors.light.get_color("brand", 6),
+            Some("#5f3dc4".to_string())
+        );
+        assert_eq!(theme.colors.dark.colors["brand"].shades.len(), 10);
+        assert!(crate::theme::validate_theme(&theme).is_empty());
+
+        assert!(ThemeBuilder::new()
+            .generated_color("brand", "#nothex")
+            .is_err());
+    }
+
     #[test]
     fn test_builder_background_text() {
         let theme = ThemeBuilder::new()
@@ -521,4 +593,15 @@ mod tests {
         assert_eq!(&*theme.layout.container_lg, "1200px");
         assert_eq!(&*theme.layout.container_xl, "1400px");
     }
+
+    #[test]
+    fn test_builder_focus_ring() {
+        let theme = ThemeBuilder::new()
+            .focus_ring_color("#ff00ff")
+            .focus_ring_width("3px")
+            .build();
+        assert_eq!(theme.focus_ring.color.as_deref(), Some("#ff00ff"));
+        assert_eq!(&*theme.focus_ring.width, "3px");
+        assert_eq!(&*theme.focus_ring.offset, "2px"); // default unchanged
+    }
 }
@@ -15,8 +15,54 @@ impl ColorSchemeMode {
             ColorSchemeMode::Auto => detect_system_preference(),
         }
     }
+
+    /// Lowercase name used when saving the mode: `light`, `dark` or `auto`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorSchemeMode::Light => "light",
+            ColorSchemeMode::Dark => "dark",
+            ColorSchemeMode::Auto => "auto",
+        }
+    }
+
+    /// Inverse of [`as_str`](Self::as_str); `None` for anything else
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "light" => Some(ColorSchemeMode::Light),
+            "dark" => Some(ColorSchemeMode::Dark),
+            "auto" => Some(ColorSchemeMode::Auto),
+            _ => None,
+        }
+    }
 }
 
+/// Color scheme saved under `key` in `localStorage`. `None` when nothing
+/// (or something unrecognised) is saved, or outside a browser.
+#[cfg(target_arch = "wasm32")]
+pub fn load_color_scheme(key: &str) -> Option<ColorSchemeMode> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .and_then(|saved| ColorSchemeMode::from_name(&saved))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_color_scheme(_key: &str) -> Option<ColorSchemeMode> {
+    // No storage during SSR or native tests
+    None
+}
+
+/// Save `mode` under `key` in `localStorage`; does nothing outside a browser
+#[cfg(target_arch = "wasm32")]
+pub fn save_color_scheme(key: &str, mode: ColorSchemeMode) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(key, mode.as_str());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save_color_scheme(_key: &str, _mode: ColorSchemeMode) {}
+
 /// Detect the system color scheme preference via `matchMedia`.
 ///
 /// Returns `ActiveColorScheme::Dark` when the user's OS or browser prefers
@@ -88,6 +134,32 @@ mod tests {
         assert_eq!(mode.resolve(), ActiveColorScheme::Light);
     }
 
+    #[test]
+    fn test_mode_name_round_trip() {
+        for mode in [
+            ColorSchemeMode::Light,
+            ColorSchemeMode::Dark,
+            ColorSchemeMode::Auto,
+        ] {
+            assert_eq!(ColorSchemeMode::from_name(mode.as_str()), Some(mode));
+        }
+        assert_eq!(ColorSchemeMode::Dark.as_str(), "dark");
+    }
+
+    #[test]
+    fn test_unknown_mode_name() {
+        assert_eq!(ColorSchemeMode::from_name(""), None);
+        assert_eq!(ColorSchemeMode::from_name("Dark"), None);
+        assert_eq!(ColorSchemeMode::from_name("sepia"), None);
+    }
+
+    #[test]
+    fn test_storage_without_window() {
+        // Outside a browser saving is a no-op and nothing loads
+        save_color_scheme("mingot-color-scheme", ColorSchemeMode::Dark);
+        assert_eq!(load_color_scheme("mingot-color-scheme"), None);
+    }
+
     #[test]
     fn test_active_scheme_is_dark() {
         assert!(ActiveColorScheme::Dark.is_dark());
@@ -1,4 +1,6 @@
+use super::validation::{hex_to_rgb, srgb_to_linear};
 use std::collections::HashMap;
+use std::fmt;
 
 /// Contains both light and dark color schemes
 #[derive(Clone, Debug, PartialEq)]
@@ -564,10 +566,138 @@ impl ColorShades {
     }
 }
 
+impl From<[String; 10]> for ColorShades {
+    fn from(shades: [String; 10]) -> Self {
+        Self {
+            shades: shades.into(),
+        }
+    }
+}
+
 impl ColorScheme {
     pub fn get_color(&self, color: &str, shade: usize) -> Option<String> {
         self.colors.get(color).and_then(|c| c.get(shade).cloned())
     }
+
+    /// Ten shades, lightest first, generated from one hex color.
+    ///
+    /// The input becomes shade [`BASE_SHADE`], the one filled components
+    /// use. Lighter shades step up evenly in OKLab lightness to a near
+    /// white tint and lose chroma on the way; darker ones step down
+    /// keeping the hue. Accepts `#rrggbb` or `#rgb`.
+    pub fn generate_palette(base: &str) -> Result<[String; 10], InvalidHexColor> {
+        let (r, g, b) = hex_to_rgb(base).ok_or_else(|| InvalidHexColor(base.to_string()))?;
+        let base_lab = Oklab::from_rgb(r, g, b);
+        let (base_l, base_c, hue) = base_lab.to_lch();
+
+        // Lightest tint, kept above the base for very light inputs
+        let lightest = base_l.max(TINT_LIGHTNESS);
+        let darkest = base_l * DARKEST_RATIO;
+
+        Ok(std::array::from_fn(|index| {
+            if index == BASE_SHADE {
+                return format!("#{:02x}{:02x}{:02x}", r, g, b);
+            }
+            let (l, c) = if index < BASE_SHADE {
+                let t = index as f64 / BASE_SHADE as f64;
+                let chroma_scale = 0.2 + 0.8 * t;
+                (lightest + (base_l - lightest) * t, base_c * chroma_scale)
+            } else {
+                let t = (index - BASE_SHADE) as f64 / (9 - BASE_SHADE) as f64;
+                (base_l + (darkest - base_l) * t, base_c * (1.0 - 0.1 * t))
+            };
+            Oklab::from_lch(l, c, hue).to_hex()
+        }))
+    }
+}
+
+/// Shade of a generated palette that equals the input color
+pub const BASE_SHADE: usize = 6;
+/// OKLab lightness of a generated palette's lightest shade
+const TINT_LIGHTNESS: f64 = 0.97;
+/// Lightness of shade 9 relative to the base shade
+const DARKEST_RATIO: f64 = 0.72;
+
+/// A string that isn't a `#rrggbb` or `#rgb` color
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidHexColor(pub String);
+
+impl fmt::Display for InvalidHexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\" is not a hex color", self.0)
+    }
+}
+
+impl std::error::Error for InvalidHexColor {}
+
+/// Color in the OKLab space, where equal steps in `l` look like equal
+/// steps in lightness
+#[derive(Clone, Copy, Debug)]
+struct Oklab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+impl Oklab {
+    fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let [r, g, b] = [r, g, b].map(|v| srgb_to_linear(v as f64 / 255.0));
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Self {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Linear-light sRGB channels, possibly outside 0..=1
+    fn to_linear_rgb(self) -> [f64; 3] {
+        let l = (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m = (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s = (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    }
+
+    fn to_lch(self) -> (f64, f64, f64) {
+        (self.l, self.a.hypot(self.b), self.b.atan2(self.a))
+    }
+
+    fn from_lch(l: f64, c: f64, h: f64) -> Self {
+        Self {
+            l,
+            a: c * h.cos(),
+            b: c * h.sin(),
+        }
+    }
+
+    /// Nearest sRGB hex, reducing chroma until the color fits the gamut
+    fn to_hex(self) -> String {
+        let (l, mut c, h) = self.to_lch();
+        let mut rgb = self.to_linear_rgb();
+        for _ in 0..24 {
+            if rgb.iter().all(|v| (-1e-4..=1.0 + 1e-4).contains(v)) {
+                break;
+            }
+            c *= 0.9;
+            rgb = Oklab::from_lch(l, c, h).to_linear_rgb();
+        }
+        let [r, g, b] = rgb.map(|v| (linear_to_srgb(v.clamp(0.0, 1.0)) * 255.0).round() as u8);
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
 }
 
 impl ColorPalette {
@@ -726,4 +856,61 @@ mod tests {
         assert_ne!(light.text, dark.text);
         assert_ne!(light.border, dark.border);
     }
+
+    #[test]
+    fn test_generate_palette_known_base() {
+        let palette = ColorScheme::generate_palette("#228be6").unwrap();
+        assert_eq!(
+            palette,
+            [
+                "#eef6ff", "#cfe5fc", "#aed4fc", "#8cc2fb", "#6eb0f4", "#4d9eed", "#228be6",
+                "#0979cf", "#1068b1", "#105795",
+            ]
+            .map(String::from)
+        );
+    }
+
+    #[test]
+    fn test_generate_palette_keeps_base_at_base_shade() {
+        for base in ["#fa5252", "#fcc419", "#5f3dc4"] {
+            let palette = ColorScheme::generate_palette(base).unwrap();
+            assert_eq!(palette[BASE_SHADE], base);
+        }
+        // Short and uppercase input is normalized
+        let palette = ColorScheme::generate_palette("#F0A").unwrap();
+        assert_eq!(palette[BASE_SHADE], "#ff00aa");
+    }
+
+    #[test]
+    fn test_generate_palette_runs_light_to_dark() {
+        use crate::theme::relative_luminance;
+
+        for base in ["#228be6", "#fcc419", "#5f3dc4", "#000000"] {
+            let palette = ColorScheme::generate_palette(base).unwrap();
+            let luminance: Vec<f64> = palette
+                .iter()
+                .map(|hex| relative_luminance(hex).unwrap())
+                .collect();
+            assert!(
+                luminance.windows(2).all(|w| w[0] >= w[1]),
+                "{}: {:?}",
+                base,
+                palette
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_palette_invalid_hex() {
+        for bad in ["", "#12", "#12345", "blue", "#gggggg", "#1234567"] {
+            assert_eq!(
+                ColorScheme::generate_palette(bad),
+                Err(InvalidHexColor(bad.to_string()))
+            );
+        }
+        assert_eq!(
+            InvalidHexColor("blue".into()).to_string(),
+            "\"blue\" is not a hex color"
+        );
+    }
 }
@@ -0,0 +1,177 @@
+use super::StyleOverrides;
+// Through the crate-root re-exports, so the config names the public types
+// rather than reaching into component modules
+use crate::{
+    ButtonSize, ButtonVariant, InputSize, InputVariant, NumberInputLocale, SelectSize,
+    SelectVariant, TextareaSize, TextareaVariant,
+};
+use leptos::prelude::*;
+
+/// App-wide default props for core components, provided by `MingotProvider`.
+///
+/// Components consult the config only for props that were not set
+/// explicitly. Precedence is: explicit prop > config default > hardcoded
+/// default.
+///
+/// # Example
+/// ```rust,ignore
+/// let config = MingotConfig::default()
+///     .input_size(InputSize::Sm)
+///     .button_variant(ButtonVariant::Light)
+///     .number_input_locale(NumberInputLocale::EU);
+///
+/// view! {
+///     <MingotProvider config=config>
+///         // Every Input here is small unless it passes its own `size`
+///         <Input />
+///     </MingotProvider>
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MingotConfig {
+    /// Size for `Input`, `PasswordInput` and `NumberInput`
+    pub input_size: Option<InputSize>,
+    /// Variant for `Input`, `PasswordInput` and `NumberInput`
+    pub input_variant: Option<InputVariant>,
+    pub button_size: Option<ButtonSize>,
+    pub button_variant: Option<ButtonVariant>,
+    pub select_size: Option<SelectSize>,
+    pub select_variant: Option<SelectVariant>,
+    pub textarea_size: Option<TextareaSize>,
+    pub textarea_variant: Option<TextareaVariant>,
+    /// Formatting locale for `NumberInput`
+    pub number_input_locale: Option<NumberInputLocale>,
+    /// Outline and label every Grid, Stack, Group and Container
+    pub debug_layout: bool,
+    /// Extra CSS for named component parts, e.g. `button.root`
+    pub styles: StyleOverrides,
+}
+
+impl MingotConfig {
+    pub fn input_size(mut self, size: InputSize) -> Self {
+        self.input_size = Some(size);
+        self
+    }
+
+    pub fn input_variant(mut self, variant: InputVariant) -> Self {
+        self.input_variant = Some(variant);
+        self
+    }
+
+    pub fn button_size(mut self, size: ButtonSize) -> Self {
+        self.button_size = Some(size);
+        self
+    }
+
+    pub fn button_variant(mut self, variant: ButtonVariant) -> Self {
+        self.button_variant = Some(variant);
+        self
+    }
+
+    pub fn select_size(mut self, size: SelectSize) -> Self {
+        self.select_size = Some(size);
+        self
+    }
+
+    pub fn select_variant(mut self, variant: SelectVariant) -> Self {
+        self.select_variant = Some(variant);
+        self
+    }
+
+    pub fn textarea_size(mut self, size: TextareaSize) -> Self {
+        self.textarea_size = Some(size);
+        self
+    }
+
+    pub fn textarea_variant(mut self, variant: TextareaVariant) -> Self {
+        self.textarea_variant = Some(variant);
+        self
+    }
+
+    pub fn number_input_locale(mut self, locale: NumberInputLocale) -> Self {
+        self.number_input_locale = Some(locale);
+        self
+    }
+
+    pub fn debug_layout(mut self, enabled: bool) -> Self {
+        self.debug_layout = enabled;
+        self
+    }
+
+    pub fn styles(mut self, styles: StyleOverrides) -> Self {
+        self.styles = styles;
+        self
+    }
+}
+
+/// Get the component defaults provided by the nearest `MingotProvider`.
+///
+/// Returns an empty config (all hardcoded defaults) when none is provided.
+pub fn use_mingot_config() -> MingotConfig {
+    use_context::<MingotConfig>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_default_is_empty() {
+        let config = MingotConfig::default();
+        assert_eq!(config.input_size, None);
+        assert_eq!(config.button_variant, None);
+        assert_eq!(config.number_input_locale, None);
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let config = MingotConfig::default()
+            .input_size(InputSize::Sm)
+            .button_variant(ButtonVariant::Light)
+            .number_input_locale(NumberInputLocale::EU);
+        assert_eq!(config.input_size, Some(InputSize::Sm));
+        assert_eq!(config.button_variant, Some(ButtonVariant::Light));
+        assert_eq!(config.number_input_locale, Some(NumberInputLocale::EU));
+        assert_eq!(config.select_size, None);
+    }
+
+    #[test]
+    fn test_config_style_overrides() {
+        let config = MingotConfig::default()
+            .styles(StyleOverrides::new().set("button.root", "padding: 0 2rem"));
+        assert_eq!(config.styles.get("button.root"), Some("padding: 0 2rem"));
+        assert!(MingotConfig::default().styles.is_empty());
+    }
+
+    #[test]
+    fn test_use_mingot_config_reads_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(use_mingot_config(), MingotConfig::default());
+
+            provide_context(MingotConfig::default().select_size(SelectSize::Lg));
+            assert_eq!(use_mingot_config().select_size, Some(SelectSize::Lg));
+        });
+    }
+
+    #[test]
+    fn test_explicit_prop_takes_precedence() {
+        let config = MingotConfig::default().input_size(InputSize::Sm);
+        let explicit: Option<InputSize> = Some(InputSize::Lg);
+        let unset: Option<InputSize> = None;
+        assert_eq!(
+            explicit.or(config.input_size).unwrap_or(InputSize::Md),
+            InputSize::Lg
+        );
+        assert_eq!(
+            unset.or(config.input_size).unwrap_or(InputSize::Md),
+            InputSize::Sm
+        );
+        assert_eq!(
+            unset
+                .or(MingotConfig::default().input_size)
+                .unwrap_or(InputSize::Md),
+            InputSize::Md
+        );
+    }
+}
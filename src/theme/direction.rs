@@ -0,0 +1,156 @@
+use leptos::prelude::*;
+
+/// Text direction of the UI
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    /// Right-to-left, e.g. Arabic or Hebrew
+    Rtl,
+}
+
+impl Direction {
+    /// Value for the HTML `dir` attribute: `ltr` or `rtl`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+
+    /// Inverse of [`as_str`](Self::as_str); `None` for anything else
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ltr" => Some(Direction::Ltr),
+            "rtl" => Some(Direction::Rtl),
+            _ => None,
+        }
+    }
+
+    pub fn is_rtl(self) -> bool {
+        matches!(self, Direction::Rtl)
+    }
+
+    /// Screen edge that `side` falls on in this direction
+    pub fn physical(self, side: LogicalSide) -> PhysicalSide {
+        match (self, side) {
+            (Direction::Ltr, LogicalSide::Start) | (Direction::Rtl, LogicalSide::End) => {
+                PhysicalSide::Left
+            }
+            (Direction::Ltr, LogicalSide::End) | (Direction::Rtl, LogicalSide::Start) => {
+                PhysicalSide::Right
+            }
+        }
+    }
+
+    /// Reading-order side that the screen edge `side` is in this direction
+    pub fn logical(self, side: PhysicalSide) -> LogicalSide {
+        match (self, side) {
+            (Direction::Ltr, PhysicalSide::Left) | (Direction::Rtl, PhysicalSide::Right) => {
+                LogicalSide::Start
+            }
+            (Direction::Ltr, PhysicalSide::Right) | (Direction::Rtl, PhysicalSide::Left) => {
+                LogicalSide::End
+            }
+        }
+    }
+
+    /// Values for the start and end side, reordered as `(left, right)`
+    pub fn to_physical<T>(self, start: T, end: T) -> (T, T) {
+        match self {
+            Direction::Ltr => (start, end),
+            Direction::Rtl => (end, start),
+        }
+    }
+}
+
+/// Horizontal edge relative to the reading direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogicalSide {
+    Start,
+    End,
+}
+
+/// Horizontal edge on screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhysicalSide {
+    Left,
+    Right,
+}
+
+impl PhysicalSide {
+    /// CSS property name fragment, e.g. for `left:` or `padding-left`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PhysicalSide::Left => "left",
+            PhysicalSide::Right => "right",
+        }
+    }
+}
+
+/// The theme's text direction, following `ThemeOverride`s
+pub fn use_direction() -> Signal<Direction> {
+    let theme = super::use_theme();
+    Memo::new(move |_| theme.with(|t| t.direction)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_ltr() {
+        assert_eq!(Direction::default(), Direction::Ltr);
+        assert!(!Direction::Ltr.is_rtl());
+        assert!(Direction::Rtl.is_rtl());
+    }
+
+    #[test]
+    fn test_ltr_mapping() {
+        assert_eq!(
+            Direction::Ltr.physical(LogicalSide::Start),
+            PhysicalSide::Left
+        );
+        assert_eq!(
+            Direction::Ltr.physical(LogicalSide::End),
+            PhysicalSide::Right
+        );
+        assert_eq!(
+            Direction::Ltr.logical(PhysicalSide::Left),
+            LogicalSide::Start
+        );
+        assert_eq!(Direction::Ltr.to_physical("start", "end"), ("start", "end"));
+    }
+
+    #[test]
+    fn test_rtl_mapping() {
+        assert_eq!(
+            Direction::Rtl.physical(LogicalSide::Start),
+            PhysicalSide::Right
+        );
+        assert_eq!(
+            Direction::Rtl.physical(LogicalSide::End),
+            PhysicalSide::Left
+        );
+        assert_eq!(Direction::Rtl.logical(PhysicalSide::Left), LogicalSide::End);
+        assert_eq!(Direction::Rtl.to_physical("start", "end"), ("end", "start"));
+    }
+
+    #[test]
+    fn test_logical_and_physical_round_trip() {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            for side in [LogicalSide::Start, LogicalSide::End] {
+                assert_eq!(direction.logical(direction.physical(side)), side);
+            }
+        }
+    }
+
+    #[test]
+    fn test_direction_names() {
+        assert_eq!(Direction::Rtl.as_str(), "rtl");
+        assert_eq!(Direction::from_name("ltr"), Some(Direction::Ltr));
+        assert_eq!(Direction::from_name("rtl"), Some(Direction::Rtl));
+        assert_eq!(Direction::from_name("auto"), None);
+        assert_eq!(PhysicalSide::Right.as_str(), "right");
+    }
+}
@@ -0,0 +1,165 @@
+use leptos::prelude::*;
+
+/// How the user last interacted with the page.
+///
+/// Focus rings are shown only while this is `Keyboard`, so clicking a
+/// button doesn't leave an outline behind but tabbing to it does.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InteractionModality {
+    /// Assumed until the first pointer event so keyboard users never
+    /// start without a visible focus indicator
+    #[default]
+    Keyboard,
+    Pointer,
+}
+
+impl InteractionModality {
+    /// Value of the `data-mingot-modality` attribute on the provider root
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InteractionModality::Keyboard => "keyboard",
+            InteractionModality::Pointer => "pointer",
+        }
+    }
+
+    /// Whether a keydown should switch to keyboard modality.
+    ///
+    /// Bare modifiers and Ctrl/Cmd/Alt shortcuts (e.g. copy) don't move
+    /// focus, so they leave the current modality alone.
+    pub fn is_navigation_key(key: &str, ctrl: bool, meta: bool, alt: bool) -> bool {
+        if ctrl || meta || alt {
+            return false;
+        }
+        !matches!(
+            key,
+            "Shift" | "Control" | "Alt" | "Meta" | "CapsLock" | "Fn" | "OS"
+        )
+    }
+}
+
+/// Global stylesheet for focus rings, scoped to the provider root.
+///
+/// Uses `!important` because several inputs set `outline: none` inline
+/// to draw their own focused border.
+pub fn focus_ring_css() -> &'static str {
+    ".mingot-provider[data-mingot-modality=\"pointer\"] :focus { outline: none; }
+.mingot-provider[data-mingot-modality=\"keyboard\"] :focus-visible {
+    outline: var(--mingot-focus-ring-width, 2px) solid var(--mingot-focus-ring-color, #228be6) !important;
+    outline-offset: var(--mingot-focus-ring-offset, 2px) !important;
+}"
+}
+
+/// Current interaction modality from the nearest `MingotProvider`.
+///
+/// Defaults to `Keyboard` outside a provider.
+pub fn use_interaction_modality() -> Signal<InteractionModality> {
+    match use_context::<RwSignal<InteractionModality>>() {
+        Some(modality) => modality.into(),
+        None => Signal::stored(InteractionModality::Keyboard),
+    }
+}
+
+/// Track keyboard vs pointer interaction on the whole document.
+///
+/// Listeners are capture-phase so components that stop propagation
+/// still update the modality. Removed when the owner is cleaned up.
+pub(crate) fn track_interaction_modality(modality: RwSignal<InteractionModality>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+            return;
+        };
+
+        let on_key =
+            Closure::<dyn Fn(web_sys::KeyboardEvent)>::new(move |ev: web_sys::KeyboardEvent| {
+                if InteractionModality::is_navigation_key(
+                    &ev.key(),
+                    ev.ctrl_key(),
+                    ev.meta_key(),
+                    ev.alt_key(),
+                ) && modality.get_untracked() != InteractionModality::Keyboard
+                {
+                    modality.set(InteractionModality::Keyboard);
+                }
+            });
+        let on_pointer = Closure::<dyn Fn(web_sys::Event)>::new(move |_: web_sys::Event| {
+            if modality.get_untracked() != InteractionModality::Pointer {
+                modality.set(InteractionModality::Pointer);
+            }
+        });
+
+        let _ = document.add_event_listener_with_callback_and_bool(
+            "keydown",
+            on_key.as_ref().unchecked_ref(),
+            true,
+        );
+        let _ = document.add_event_listener_with_callback_and_bool(
+            "pointerdown",
+            on_pointer.as_ref().unchecked_ref(),
+            true,
+        );
+
+        let handle = StoredValue::new_local((document, on_key, on_pointer));
+        on_cleanup(move || {
+            handle.try_with_value(|(document, on_key, on_pointer)| {
+                let _ = document.remove_event_listener_with_callback_and_bool(
+                    "keydown",
+                    on_key.as_ref().unchecked_ref(),
+                    true,
+                );
+                let _ = document.remove_event_listener_with_callback_and_bool(
+                    "pointerdown",
+                    on_pointer.as_ref().unchecked_ref(),
+                    true,
+                );
+            });
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = modality;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_keys() {
+        assert!(InteractionModality::is_navigation_key(
+            "Tab", false, false, false
+        ));
+        assert!(InteractionModality::is_navigation_key(
+            "ArrowDown",
+            false,
+            false,
+            false
+        ));
+        assert!(!InteractionModality::is_navigation_key(
+            "Shift", false, false, false
+        ));
+        // Shortcuts like Ctrl+C don't move focus
+        assert!(!InteractionModality::is_navigation_key(
+            "c", true, false, false
+        ));
+    }
+
+    #[test]
+    fn test_use_interaction_modality_reads_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert_eq!(
+                use_interaction_modality().get_untracked(),
+                InteractionModality::Keyboard
+            );
+
+            provide_context(RwSignal::new(InteractionModality::Pointer));
+            assert_eq!(
+                use_interaction_modality().get_untracked(),
+                InteractionModality::Pointer
+            );
+        });
+    }
+}
@@ -0,0 +1,201 @@
+use super::Breakpoints;
+use leptos::prelude::*;
+
+/// Named theme breakpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Breakpoint {
+    /// The breakpoint's width in the given scale (e.g. `"48em"`)
+    pub fn value(self, breakpoints: &Breakpoints) -> &str {
+        match self {
+            Breakpoint::Xs => &breakpoints.xs,
+            Breakpoint::Sm => &breakpoints.sm,
+            Breakpoint::Md => &breakpoints.md,
+            Breakpoint::Lg => &breakpoints.lg,
+            Breakpoint::Xl => &breakpoints.xl,
+        }
+    }
+}
+
+/// Media query matching viewports at least `width` wide
+pub fn min_width_query(width: &str) -> String {
+    format!("(min-width: {})", width)
+}
+
+/// Media query matching viewports narrower than `width`.
+///
+/// Written as the complement of `(min-width: ...)` so that exactly one of
+/// "below" and "at or above" matches at the breakpoint itself.
+pub fn below_width_query(width: &str) -> String {
+    format!("not all and (min-width: {})", width)
+}
+
+/// Reactive result of `window.matchMedia(query)`.
+///
+/// Updates as the viewport changes, and re-subscribes when a reactive
+/// `query` changes; the listener is removed when the current owner is
+/// cleaned up. Always `false` outside the browser.
+pub fn use_media_query(query: impl Into<Signal<String>>) -> Signal<bool> {
+    let query = query.into();
+    let matches = RwSignal::new(false);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        type Listener = (web_sys::MediaQueryList, Closure<dyn Fn(web_sys::Event)>);
+
+        let subscribe = move |query: &str| -> Option<Listener> {
+            let mql = web_sys::window()?.match_media(query).ok().flatten()?;
+            matches.set(mql.matches());
+
+            let listener_mql = mql.clone();
+            let listener = Closure::<dyn Fn(web_sys::Event)>::new(move |_: web_sys::Event| {
+                matches.set(listener_mql.matches());
+            });
+            let _ =
+                mql.add_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+            Some((mql, listener))
+        };
+        let unsubscribe = |(mql, listener): &Listener| {
+            let _ = mql
+                .remove_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+        };
+
+        // Subscribe right away so the first read is already correct
+        let handle = StoredValue::new_local(subscribe(&query.get_untracked()));
+        Effect::new(move |prev: Option<()>| {
+            let query = query.get();
+            if prev.is_some() {
+                handle.update_value(|current| {
+                    if let Some(old) = current.take() {
+                        unsubscribe(&old);
+                    }
+                    *current = subscribe(&query);
+                });
+            }
+        });
+        on_cleanup(move || {
+            handle.try_with_value(|current| {
+                if let Some(listener) = current {
+                    unsubscribe(listener);
+                }
+            });
+        });
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = query;
+
+    matches.into()
+}
+
+/// Whether the user asked the OS to minimize motion
+pub fn use_reduced_motion() -> Signal<bool> {
+    use_media_query("(prefers-reduced-motion: reduce)")
+}
+
+/// Whether the viewport is at least as wide as the theme's `breakpoint`,
+/// mobile-first like CSS `min-width` queries. Follows the theme, so a
+/// `ThemeOverride` or theme switch with other breakpoints takes effect.
+///
+/// # Example
+/// ```rust,ignore
+/// let desktop = use_breakpoint(Breakpoint::Md);
+///
+/// view! {
+///     <Show when=move || desktop.get() fallback=|| view! { <Burger /> }>
+///         <Navbar />
+///     </Show>
+/// }
+/// ```
+pub fn use_breakpoint(breakpoint: Breakpoint) -> Signal<bool> {
+    use_media_query(breakpoint_query(breakpoint, min_width_query))
+}
+
+/// Whether the viewport is narrower than the theme's `breakpoint`; the
+/// complement of [`use_breakpoint`]
+pub fn use_below_breakpoint(breakpoint: Breakpoint) -> Signal<bool> {
+    use_media_query(breakpoint_query(breakpoint, below_width_query))
+}
+
+/// `query` for the current theme's width of `breakpoint`, only changing
+/// when that width does
+fn breakpoint_query(breakpoint: Breakpoint, query: fn(&str) -> String) -> Signal<String> {
+    let theme = super::use_theme();
+    Memo::new(move |_| theme.with(|t| query(breakpoint.value(&t.breakpoints)))).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakpoint_value() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(Breakpoint::Sm.value(&breakpoints), "48em");
+        assert_eq!(Breakpoint::Xl.value(&breakpoints), "88em");
+    }
+
+    #[test]
+    fn test_below_width_query() {
+        assert_eq!(below_width_query("48em"), "not all and (min-width: 48em)");
+    }
+
+    #[test]
+    fn test_breakpoint_queries() {
+        let breakpoints = Breakpoints::default();
+        let queries: Vec<String> = [
+            Breakpoint::Xs,
+            Breakpoint::Sm,
+            Breakpoint::Md,
+            Breakpoint::Lg,
+            Breakpoint::Xl,
+        ]
+        .into_iter()
+        .map(|bp| min_width_query(bp.value(&breakpoints)))
+        .collect();
+        assert_eq!(
+            queries,
+            vec![
+                "(min-width: 36em)",
+                "(min-width: 48em)",
+                "(min-width: 62em)",
+                "(min-width: 75em)",
+                "(min-width: 88em)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_query_tracks_theme() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let theme = RwSignal::new(crate::theme::Theme::default());
+            provide_context::<crate::theme::ThemeContext>(theme);
+            let query = breakpoint_query(Breakpoint::Md, min_width_query);
+            assert_eq!(query.get(), "(min-width: 62em)");
+
+            theme.update(|t| t.breakpoints.md = "900px".into());
+            assert_eq!(query.get(), "(min-width: 900px)");
+        });
+    }
+
+    #[test]
+    fn test_breakpoint_queries_follow_custom_theme() {
+        let breakpoints = Breakpoints {
+            md: "900px".into(),
+            ..Breakpoints::default()
+        };
+        let width = Breakpoint::Md.value(&breakpoints);
+        assert_eq!(min_width_query(width), "(min-width: 900px)");
+        assert_eq!(below_width_query(width), "not all and (min-width: 900px)");
+    }
+}
@@ -1,10 +1,15 @@
 mod builder;
 mod color_scheme;
 mod colors;
+mod config;
+mod direction;
+mod focus;
+mod media_query;
 mod override_provider;
 pub mod presets;
 mod provider;
 mod spacing;
+mod styles;
 #[cfg(feature = "theme-tokens")]
 pub mod tokens;
 mod typography;
@@ -13,9 +18,14 @@ pub mod validation;
 pub use builder::*;
 pub use color_scheme::*;
 pub use colors::*;
+pub use config::*;
+pub use direction::*;
+pub use focus::*;
+pub use media_query::*;
 pub use override_provider::*;
 pub use provider::*;
 pub use spacing::*;
+pub use styles::*;
 pub use typography::*;
 pub use validation::*;
 
@@ -32,7 +42,10 @@ pub struct Theme {
     pub breakpoints: Breakpoints,
     pub borders: BorderScale,
     pub layout: LayoutTokens,
+    pub focus_ring: FocusRing,
     pub color_scheme: ColorSchemeMode,
+    /// Text direction; components mirror start/end placements in RTL
+    pub direction: Direction,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -71,6 +84,25 @@ impl Default for BorderScale {
     }
 }
 
+/// Keyboard focus indicator, drawn as an outline
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusRing {
+    pub width: Cow<'static, str>,
+    pub offset: Cow<'static, str>,
+    /// Ring color; the primary color when `None`
+    pub color: Option<Cow<'static, str>>,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            width: Cow::Borrowed("2px"),
+            offset: Cow::Borrowed("2px"),
+            color: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct RadiusScale {
     pub xs: Cow<'static, str>,
@@ -101,6 +133,21 @@ pub struct ShadowScale {
     pub xl: Cow<'static, str>,
 }
 
+impl ShadowScale {
+    /// Resolve a shadow size name (`"xs"`..`"xl"`) to its token; any
+    /// other value is passed through as a raw `box-shadow`.
+    pub fn resolve<'a>(&'a self, value: &'a str) -> &'a str {
+        match value {
+            "xs" => &self.xs,
+            "sm" => &self.sm,
+            "md" => &self.md,
+            "lg" => &self.lg,
+            "xl" => &self.xl,
+            _ => value,
+        }
+    }
+}
+
 impl Default for ShadowScale {
     fn default() -> Self {
         Self {
@@ -148,6 +195,28 @@ pub fn get_scheme_colors(theme: &Theme) -> &ColorScheme {
 ///
 /// All variable names are namespaced with `--mingot-`.
 /// This is a pure function usable in tests without a DOM.
+///
+/// The names are part of the public API and stay stable across releases:
+///
+/// | Variables | Source |
+/// |-----------|--------|
+/// | `--mingot-background`, `-text`, `-border`, `-white`, `-black` | Active color scheme |
+/// | `--mingot-primary`, `-primary-light` | `primary_color`, shade 6 and 0 (1 in dark) |
+/// | `--mingot-success`, `-error`, `-warning` | Shade 6 of green, red, yellow |
+/// | `--mingot-surface-{0,1,2}`, `-hover-bg`, `-text-dimmed` | Gray shades |
+/// | `--mingot-color-{name}-{0..9}` | Every shade of every palette color |
+/// | `--mingot-spacing-{xs..xl}` | `Spacing` |
+/// | `--mingot-radius-{xs..xl}` | `RadiusScale` |
+/// | `--mingot-shadow-{xs..xl}` | `ShadowScale` |
+/// | `--mingot-border-width`, `-border-style` | `BorderScale` |
+/// | `--mingot-container-{xs..xl}` | `LayoutTokens` |
+/// | `--mingot-focus-ring-color`, `-width`, `-offset` | `FocusRing` |
+/// | `--mingot-font-family`, `-font-family-mono` | `Typography` |
+/// | `--mingot-font-size-{xs..xxl}` | `FontSizes` |
+/// | `--mingot-line-height-{xs..xl}` | `LineHeights` |
+/// | `--mingot-font-weight-{normal,medium,semibold,bold}` | `FontWeights` |
+///
+/// Palette colors are emitted in name order so the output is deterministic.
 pub fn theme_to_css_vars(theme: &Theme) -> Vec<(String, String)> {
     let mut vars = Vec::new();
     let scheme = get_scheme_colors(theme);
@@ -180,6 +249,15 @@ pub fn theme_to_css_vars(theme: &Theme) -> Vec<(String, String)> {
         vars.push(("--mingot-warning".into(), warning));
     }
 
+    // Full palette, one variable per shade
+    let mut names: Vec<&String> = scheme.colors.keys().collect();
+    names.sort();
+    for name in names {
+        for (index, shade) in scheme.colors[name].shades.iter().enumerate() {
+            vars.push((format!("--mingot-color-{}-{}", name, index), shade.clone()));
+        }
+    }
+
     // Surface colors (different shade indices for dark mode)
     let (s0, s1, s2, hover_idx) = if is_dark { (1, 2, 3, 3) } else { (0, 1, 2, 1) };
     if let Some(surface) = scheme.get_color("gray", s0) {
@@ -254,6 +332,23 @@ pub fn theme_to_css_vars(theme: &Theme) -> Vec<(String, String)> {
         theme.layout.container_xl.to_string(),
     ));
 
+    // Focus ring
+    let focus_color = match &theme.focus_ring.color {
+        Some(color) => Some(color.to_string()),
+        None => scheme.get_color(primary_key, if is_dark { 4 } else { 6 }),
+    };
+    if let Some(color) = focus_color {
+        vars.push(("--mingot-focus-ring-color".into(), color));
+    }
+    vars.push((
+        "--mingot-focus-ring-width".into(),
+        theme.focus_ring.width.to_string(),
+    ));
+    vars.push((
+        "--mingot-focus-ring-offset".into(),
+        theme.focus_ring.offset.to_string(),
+    ));
+
     // Typography
     vars.push((
         "--mingot-font-family".into(),
@@ -288,13 +383,57 @@ pub fn theme_to_css_vars(theme: &Theme) -> Vec<(String, String)> {
         theme.typography.font_sizes.xxl.to_string(),
     ));
 
+    let line_heights = &theme.typography.line_heights;
+    for (size, value) in [
+        ("xs", &line_heights.xs),
+        ("sm", &line_heights.sm),
+        ("md", &line_heights.md),
+        ("lg", &line_heights.lg),
+        ("xl", &line_heights.xl),
+    ] {
+        vars.push((format!("--mingot-line-height-{}", size), value.to_string()));
+    }
+
+    let weights = &theme.typography.font_weights;
+    for (name, value) in [
+        ("normal", weights.normal),
+        ("medium", weights.medium),
+        ("semibold", weights.semibold),
+        ("bold", weights.bold),
+    ] {
+        vars.push((format!("--mingot-font-weight-{}", name), value.to_string()));
+    }
+
     vars
 }
 
+impl Theme {
+    /// The variables from [`theme_to_css_vars`] as CSS declarations, one
+    /// per line, ready to wrap in a rule:
+    ///
+    /// ```rust,ignore
+    /// let css = format!(":root {{\n{}}}", theme.to_css_variables());
+    /// ```
+    pub fn to_css_variables(&self) -> String {
+        theme_to_css_vars(self)
+            .into_iter()
+            .map(|(name, value)| format!("  {}: {};\n", name, value))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod css_var_tests {
     use super::*;
 
+    #[test]
+    fn test_shadow_scale_resolve() {
+        let shadows = ShadowScale::default();
+        assert_eq!(shadows.resolve("md"), &*shadows.md);
+        assert_eq!(shadows.resolve("xl"), &*shadows.xl);
+        assert_eq!(shadows.resolve("0 0 4px red"), "0 0 4px red");
+    }
+
     #[test]
     fn test_theme_to_css_vars_default() {
         let theme = Theme::default();
@@ -422,4 +561,90 @@ mod css_var_tests {
         assert_eq!(var_map["--mingot-container-lg"], "1140px");
         assert_eq!(var_map["--mingot-container-xl"], "1320px");
     }
+
+    #[test]
+    fn test_theme_to_css_vars_has_palette_shades() {
+        let theme = Theme::default();
+        let var_map: std::collections::HashMap<_, _> =
+            theme_to_css_vars(&theme).into_iter().collect();
+
+        assert_eq!(var_map["--mingot-color-blue-6"], "#228be6");
+        assert_eq!(var_map["--mingot-color-blue-0"], "#e7f5ff");
+        for color in theme.colors.light.colors.keys() {
+            for shade in 0..10 {
+                assert!(
+                    var_map.contains_key(&format!("--mingot-color-{}-{}", color, shade) as &str),
+                    "Missing --mingot-color-{}-{}",
+                    color,
+                    shade
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_theme_to_css_vars_has_line_heights_and_weights() {
+        let theme = Theme::default();
+        let var_map: std::collections::HashMap<_, _> =
+            theme_to_css_vars(&theme).into_iter().collect();
+
+        assert_eq!(var_map["--mingot-line-height-md"], "1.5");
+        assert_eq!(var_map["--mingot-font-weight-bold"], "700");
+        assert_eq!(var_map["--mingot-font-weight-medium"], "500");
+    }
+
+    #[test]
+    fn test_theme_to_css_vars_is_deterministic() {
+        let theme = Theme::default();
+        assert_eq!(theme_to_css_vars(&theme), theme_to_css_vars(&theme.clone()));
+    }
+
+    #[test]
+    fn test_to_css_variables_block() {
+        let css = Theme::default().to_css_variables();
+
+        for expected in [
+            "  --mingot-color-blue-6: #228be6;\n",
+            "  --mingot-spacing-md: 1rem;\n",
+            "  --mingot-radius-lg: ",
+            "  --mingot-shadow-xl: ",
+            "  --mingot-font-family: ",
+            "  --mingot-primary: #228be6;\n",
+        ] {
+            assert!(
+                css.contains(expected),
+                "missing `{}` in:\n{}",
+                expected,
+                css
+            );
+        }
+        // One declaration per line
+        assert!(css
+            .lines()
+            .all(|l| l.starts_with("  --mingot-") && l.ends_with(';')));
+    }
+
+    #[test]
+    fn test_theme_to_css_vars_focus_ring() {
+        let theme = Theme::default();
+        let var_map: std::collections::HashMap<_, _> =
+            theme_to_css_vars(&theme).into_iter().collect();
+        assert_eq!(var_map["--mingot-focus-ring-width"], "2px");
+        assert_eq!(var_map["--mingot-focus-ring-offset"], "2px");
+        assert_eq!(
+            var_map["--mingot-focus-ring-color"],
+            var_map["--mingot-primary"]
+        );
+
+        let custom = Theme {
+            focus_ring: FocusRing {
+                color: Some("#ff00ff".into()),
+                ..FocusRing::default()
+            },
+            ..Theme::default()
+        };
+        let var_map: std::collections::HashMap<_, _> =
+            theme_to_css_vars(&custom).into_iter().collect();
+        assert_eq!(var_map["--mingot-focus-ring-color"], "#ff00ff");
+    }
 }
@@ -1,4 +1,4 @@
-use super::{ColorSchemeMode, RadiusScale, Spacing, ThemeContext, Typography};
+use super::{ColorSchemeMode, Direction, RadiusScale, Spacing, ThemeContext, Typography};
 use leptos::prelude::*;
 
 /// A scoped theme override component.
@@ -34,6 +34,10 @@ pub fn ThemeOverride(
     /// Override the typography for this subtree.
     #[prop(optional, into)]
     typography: Option<Typography>,
+    /// Override the text direction for this subtree. Also sets `dir` on a
+    /// wrapping element so the browser lays out text to match.
+    #[prop(optional, into)]
+    direction: Option<Direction>,
     children: Children,
 ) -> impl IntoView {
     let parent_theme =
@@ -56,6 +60,9 @@ pub fn ThemeOverride(
         if let Some(ref ty) = typography {
             t.typography = ty.clone();
         }
+        if let Some(d) = direction {
+            t.direction = d;
+        }
         t
     });
 
@@ -77,10 +84,16 @@ pub fn ThemeOverride(
         if let Some(ref ty) = typography {
             t.typography = ty.clone();
         }
+        if let Some(d) = direction {
+            t.direction = d;
+        }
         derived.set(t);
     });
 
     provide_context::<ThemeContext>(derived);
 
-    view! { <>{children()}</> }
+    match direction {
+        Some(d) => view! { <div dir=d.as_str()>{children()}</div> }.into_any(),
+        None => view! { <>{children()}</> }.into_any(),
+    }
 }
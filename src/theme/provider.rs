@@ -1,4 +1,8 @@
-use super::{ColorSchemeMode, Theme, ThemeContext};
+use super::{
+    load_color_scheme, save_color_scheme, ColorSchemeMode, InteractionModality, MingotConfig,
+    Theme, ThemeContext,
+};
+use crate::utils::{use_id_sequence, IdSequence};
 use leptos::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
@@ -13,16 +17,62 @@ pub fn MingotProvider(
     /// Defaults to `true`. Set to `false` if you manage CSS variables externally.
     #[prop(optional, default = true)]
     inject_css_vars: bool,
+    /// Default props for core components, consulted when a prop is not set.
+    /// See [`MingotConfig`] for precedence.
+    #[prop(optional)]
+    config: Option<MingotConfig>,
+    /// Show focus rings only after keyboard interaction, using the theme's
+    /// `focus_ring` token. Defaults to `true`.
+    #[prop(optional, default = true)]
+    focus_rings: bool,
+    /// `localStorage` key for remembering the color scheme across page
+    /// loads. A saved choice replaces the theme's `color_scheme` on mount,
+    /// and later changes (e.g. via [`use_set_color_scheme`]) are saved.
+    /// Without a saved value the theme's mode is used, so `Auto` follows
+    /// `prefers-color-scheme`.
+    #[prop(optional, into)]
+    persist_key: Option<String>,
     children: Children,
 ) -> impl IntoView {
-    let theme = theme.unwrap_or_default();
+    let mut theme = theme.unwrap_or_default();
+    if let Some(saved) = persist_key.as_deref().and_then(load_color_scheme) {
+        theme.color_scheme = saved;
+    }
     let theme_signal = RwSignal::new(theme);
 
+    // Save the scheme when it changes, but not the initial value, so an
+    // app's default isn't pinned for users who never picked one
+    if let Some(key) = persist_key {
+        let color_scheme = Memo::new(move |_| theme_signal.with(|t| t.color_scheme));
+        Effect::new(move |initialized: Option<()>| {
+            let mode = color_scheme.get();
+            if initialized.is_some() {
+                save_color_scheme(&key, mode);
+            }
+        });
+    }
+
     provide_context::<ThemeContext>(theme_signal);
+    provide_context::<MingotConfig>(config.unwrap_or_default());
+    // Generated element ids count in render order from here; nested
+    // providers keep counting in the outer sequence
+    if use_id_sequence().is_none() {
+        provide_context(IdSequence::new());
+    }
+
+    // Keyboard vs pointer modality drives focus-visible styling
+    let modality = RwSignal::new(InteractionModality::default());
+    provide_context(modality);
+    if focus_rings {
+        super::track_interaction_modality(modality);
+    }
 
-    // Inject CSS custom properties onto the document root element
+    // Inject CSS custom properties onto the document root element. Names
+    // set by the previous theme but absent from the new one (e.g. a removed
+    // palette color) are cleared so stale values don't linger.
     #[cfg(target_arch = "wasm32")]
     if inject_css_vars {
+        let injected = StoredValue::new(Vec::<String>::new());
         let _ = Effect::new(move || {
             let theme_val = theme_signal.get();
             let vars = theme_to_css_vars(&theme_val);
@@ -32,9 +82,17 @@ pub fn MingotProvider(
                     if let Some(root) = document.document_element() {
                         if let Some(el) = root.dyn_ref::<web_sys::HtmlElement>() {
                             let style = el.style();
+                            injected.with_value(|previous| {
+                                for name in previous {
+                                    if !vars.iter().any(|(n, _)| n == name) {
+                                        let _ = style.remove_property(name);
+                                    }
+                                }
+                            });
                             for (name, value) in &vars {
                                 let _ = style.set_property(name, value);
                             }
+                            injected.set_value(vars.into_iter().map(|(n, _)| n).collect());
                         }
                     }
                 }
@@ -85,7 +143,13 @@ pub fn MingotProvider(
     };
 
     view! {
-        <div class="mingot-provider" style=root_style>
+        <div
+            class="mingot-provider"
+            dir=move || theme_signal.with(|t| t.direction.as_str())
+            style=root_style
+            data-mingot-modality=move || focus_rings.then(|| modality.get().as_str())
+        >
+            {focus_rings.then(|| view! { <style>{super::focus_ring_css()}</style> })}
             {children()}
         </div>
     }
@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+/// Part names `Button` consults in [`StyleOverrides`]
+pub const BUTTON_PARTS: &[&str] = &["button.root"];
+
+/// Part names `Input` consults in [`StyleOverrides`]
+pub const INPUT_PARTS: &[&str] = &[
+    "input.wrapper",
+    "input.label",
+    "input.input",
+    "input.section",
+    "input.description",
+    "input.counter",
+    "input.error",
+];
+
+/// Part names `Card` and `CardSection` consult in [`StyleOverrides`]
+pub const CARD_PARTS: &[&str] = &["card.root", "card.section"];
+
+/// App-wide extra CSS for named component parts, set on `MingotConfig`.
+///
+/// Keys are `component.part`. A part's override is appended after the
+/// component's own styles, so it wins over the defaults, while a
+/// per-instance `style` prop still wins over the override.
+///
+/// | Component | Parts |
+/// |-----------|-------|
+/// | `Button` | `button.root` |
+/// | `Input` | `input.wrapper`, `input.label`, `input.input`, `input.section`, `input.description`, `input.counter`, `input.error` |
+/// | `Card` | `card.root`, `card.section` |
+///
+/// # Example
+/// ```rust,ignore
+/// let config = MingotConfig::default().styles(
+///     StyleOverrides::new()
+///         .set("button.root", "padding: 0 2rem; letter-spacing: 0.02em")
+///         .set("input.label", "text-transform: uppercase"),
+/// );
+///
+/// view! {
+///     <MingotProvider config=config>
+///         <Button>"Roomier"</Button>
+///     </MingotProvider>
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleOverrides {
+    parts: BTreeMap<String, String>,
+}
+
+impl StyleOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the extra CSS for a part, replacing any previous value
+    pub fn set(mut self, part: impl Into<String>, css: impl Into<String>) -> Self {
+        self.parts.insert(part.into(), css.into());
+        self
+    }
+
+    /// Extra CSS for a part, if any
+    pub fn get(&self, part: &str) -> Option<&str> {
+        self.parts.get(part).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Append a part's override to the component's base styles
+    pub fn apply(&self, part: &str, base: String) -> String {
+        match self.get(part) {
+            Some(css) if !css.trim().is_empty() => {
+                format!("{}; {}", base.trim_end().trim_end_matches(';'), css)
+            }
+            _ => base,
+        }
+    }
+
+    /// Keys that no component consults, usually typos like `buton.root`
+    pub fn unknown_parts(&self) -> Vec<&str> {
+        self.parts
+            .keys()
+            .map(String::as_str)
+            .filter(|key| {
+                ![BUTTON_PARTS, INPUT_PARTS, CARD_PARTS]
+                    .iter()
+                    .any(|parts| parts.contains(key))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_appends_after_base() {
+        let styles = StyleOverrides::new().set("button.root", "padding: 0 2rem");
+        assert_eq!(
+            styles.apply("button.root", "height: 2rem; padding: 0 1rem;".to_string()),
+            "height: 2rem; padding: 0 1rem; padding: 0 2rem"
+        );
+        assert_eq!(
+            styles.apply("card.root", "padding: 1rem".to_string()),
+            "padding: 1rem"
+        );
+    }
+
+    #[test]
+    fn test_set_replaces_previous_value() {
+        let styles = StyleOverrides::new()
+            .set("input.label", "color: red")
+            .set("input.label", "color: blue");
+        assert_eq!(styles.get("input.label"), Some("color: blue"));
+        assert!(!styles.is_empty());
+        assert!(StyleOverrides::new().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_parts() {
+        let styles = StyleOverrides::new()
+            .set("button.root", "padding: 0")
+            .set("buton.root", "padding: 0")
+            .set("card.section", "padding: 0");
+        assert_eq!(styles.unknown_parts(), vec!["buton.root"]);
+    }
+}
@@ -5,8 +5,9 @@
 //! W3C Design Tokens Community Group format.
 
 use super::{
-    BorderScale, Breakpoints, ColorPalette, ColorScheme, ColorSchemeMode, ColorShades, FontSizes,
-    FontWeights, LayoutTokens, LineHeights, RadiusScale, ShadowScale, Spacing, Theme, Typography,
+    BorderScale, Breakpoints, ColorPalette, ColorScheme, ColorSchemeMode, ColorShades, Direction,
+    FocusRing, FontSizes, FontWeights, LayoutTokens, LineHeights, RadiusScale, ShadowScale,
+    Spacing, Theme, Typography,
 };
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
@@ -23,6 +24,9 @@ pub struct DesignTokens {
     pub primary_color: String,
     /// Active color scheme mode.
     pub color_scheme: String,
+    /// Text direction, `ltr` or `rtl`.
+    #[serde(default)]
+    pub direction: String,
 
     // --- Colors ---
     pub light: SchemeTokens,
@@ -42,6 +46,9 @@ pub struct DesignTokens {
     // --- Layout ---
     #[serde(default)]
     pub layout: ContainerTokens,
+    // --- Focus ring ---
+    #[serde(default)]
+    pub focus_ring: FocusRingTokens,
 
     // --- Typography ---
     pub font_family: String,
@@ -121,6 +128,26 @@ impl Default for ContainerTokens {
     }
 }
 
+/// Focus ring tokens.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FocusRingTokens {
+    pub width: String,
+    pub offset: String,
+    /// Falls back to the primary color when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl Default for FocusRingTokens {
+    fn default() -> Self {
+        Self {
+            width: "2px".to_string(),
+            offset: "2px".to_string(),
+            color: None,
+        }
+    }
+}
+
 /// Font weight tokens.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FontWeightTokens {
@@ -135,11 +162,8 @@ impl DesignTokens {
     pub fn from_theme(theme: &Theme) -> Self {
         Self {
             primary_color: theme.colors.primary_color.clone(),
-            color_scheme: match theme.color_scheme {
-                ColorSchemeMode::Light => "light".to_string(),
-                ColorSchemeMode::Dark => "dark".to_string(),
-                ColorSchemeMode::Auto => "auto".to_string(),
-            },
+            color_scheme: theme.color_scheme.as_str().to_string(),
+            direction: theme.direction.as_str().to_string(),
             light: scheme_to_tokens(&theme.colors.light),
             dark: scheme_to_tokens(&theme.colors.dark),
             spacing: ScaleTokens {
@@ -181,6 +205,11 @@ impl DesignTokens {
                 lg: theme.layout.container_lg.to_string(),
                 xl: theme.layout.container_xl.to_string(),
             },
+            focus_ring: FocusRingTokens {
+                width: theme.focus_ring.width.to_string(),
+                offset: theme.focus_ring.offset.to_string(),
+                color: theme.focus_ring.color.as_ref().map(|c| c.to_string()),
+            },
             font_family: theme.typography.font_family.to_string(),
             font_family_monospace: theme.typography.font_family_monospace.to_string(),
             font_sizes: FontSizeTokens {
@@ -279,11 +308,13 @@ impl DesignTokens {
                 container_lg: Cow::Owned(self.layout.lg.clone()),
                 container_xl: Cow::Owned(self.layout.xl.clone()),
             },
-            color_scheme: match self.color_scheme.as_str() {
-                "dark" => ColorSchemeMode::Dark,
-                "auto" => ColorSchemeMode::Auto,
-                _ => ColorSchemeMode::Light,
+            focus_ring: FocusRing {
+                width: Cow::Owned(self.focus_ring.width.clone()),
+                offset: Cow::Owned(self.focus_ring.offset.clone()),
+                color: self.focus_ring.color.clone().map(Cow::Owned),
             },
+            color_scheme: ColorSchemeMode::from_name(&self.color_scheme).unwrap_or_default(),
+            direction: Direction::from_name(&self.direction).unwrap_or_default(),
         }
     }
 
@@ -387,6 +418,28 @@ mod tests {
         assert_eq!(DesignTokens::from_theme(&auto).color_scheme, "auto");
     }
 
+    #[test]
+    fn test_direction_roundtrip() {
+        let rtl = Theme {
+            direction: Direction::Rtl,
+            ..Theme::default()
+        };
+        let tokens = DesignTokens::from_theme(&rtl);
+        assert_eq!(tokens.direction, "rtl");
+        assert_eq!(tokens.to_theme().direction, Direction::Rtl);
+
+        // Token files written before `direction` existed load as LTR
+        let mut json: serde_json::Value = serde_json::from_str(
+            &DesignTokens::from_theme(&Theme::default())
+                .to_json()
+                .unwrap(),
+        )
+        .unwrap();
+        json.as_object_mut().unwrap().remove("direction");
+        let tokens: DesignTokens = serde_json::from_value(json).unwrap();
+        assert_eq!(tokens.to_theme().direction, Direction::Ltr);
+    }
+
     #[test]
     fn test_border_tokens_roundtrip() {
         use crate::theme::ThemeBuilder;
@@ -415,6 +468,19 @@ mod tests {
         assert_eq!(theme, recovered);
     }
 
+    #[test]
+    fn test_focus_ring_tokens_roundtrip() {
+        use crate::theme::ThemeBuilder;
+        let theme = ThemeBuilder::new()
+            .focus_ring_color("#ff00ff")
+            .focus_ring_width("3px")
+            .build();
+        let tokens = DesignTokens::from_theme(&theme);
+        assert_eq!(tokens.focus_ring.color.as_deref(), Some("#ff00ff"));
+        let recovered = tokens.to_theme();
+        assert_eq!(theme, recovered);
+    }
+
     #[test]
     fn test_preset_roundtrip() {
         use crate::theme::presets;
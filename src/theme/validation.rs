@@ -114,6 +114,9 @@ fn validate_css_value(value: &str, field: &str, warnings: &mut Vec<ThemeWarning>
 /// Parse a hex color string to (r, g, b) in 0..255 range.
 pub fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
     let hex = hex.trim_start_matches('#');
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
     match hex.len() {
         6 => {
             let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
@@ -132,7 +135,7 @@ pub fn hex_to_rgb(hex: &str) -> Option<(u8, u8, u8)> {
 }
 
 /// Convert a single sRGB channel (0..255) to linear light.
-fn srgb_to_linear(value: f64) -> f64 {
+pub(crate) fn srgb_to_linear(value: f64) -> f64 {
     if value <= 0.04045 {
         value / 12.92
     } else {
@@ -191,6 +194,8 @@ mod tests {
     fn test_hex_to_rgb_invalid() {
         assert_eq!(hex_to_rgb("invalid"), None);
         assert_eq!(hex_to_rgb("#gg0000"), None);
+        assert_eq!(hex_to_rgb("#+f+f+f"), None);
+        assert_eq!(hex_to_rgb("#é0000"), None);
     }
 
     #[test]
@@ -0,0 +1,182 @@
+use leptos::prelude::*;
+
+/// Dirty tracking and reset for a form field.
+///
+/// Inputs that accept `default_value` hand one of these to their
+/// `on_register` callback, so a form can show "unsaved changes" and
+/// offer "discard" without knowing the field's type. The handle is `Copy`
+/// and can be stored alongside other signals.
+///
+/// [`reset`](Self::reset) counts as a change: the built-in inputs restore
+/// the default the same way their own reset controls do, firing their
+/// `on_change` with it.
+///
+/// # Example
+/// ```rust,ignore
+/// let field = RwSignal::new(None::<FieldState<String>>);
+///
+/// view! {
+///     <NumberInput
+///         default_value="10"
+///         on_register=Callback::new(move |state| field.set(Some(state)))
+///     />
+///     <Button on_click=Callback::new(move |_| {
+///         if let Some(state) = field.get() { state.reset() }
+///     })>"Discard changes"</Button>
+/// }
+/// ```
+pub struct FieldState<T: Send + Sync + 'static> {
+    value: RwSignal<T>,
+    default: RwSignal<T>,
+    same: StoredValue<Comparator<T>>,
+    restore: StoredValue<Option<Restore<T>>>,
+}
+
+/// Decides whether a value counts as unchanged from the default
+type Comparator<T> = Box<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
+/// Puts the default back in place of a plain `set`
+type Restore<T> = Box<dyn Fn(T) + Send + Sync>;
+
+impl<T: Send + Sync + 'static> Clone for FieldState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for FieldState<T> {}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> FieldState<T> {
+    /// Track `value` against `default`
+    pub fn new(value: RwSignal<T>, default: T) -> Self {
+        Self::with_comparator(value, default, |a, b| a == b)
+    }
+
+    /// Track `value` against `default`, treating values for which `same`
+    /// returns true as unchanged, e.g. `"1,000"` and `"1000"`
+    pub fn with_comparator(
+        value: RwSignal<T>,
+        default: T,
+        same: impl Fn(&T, &T) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            value,
+            default: RwSignal::new(default),
+            same: StoredValue::new(Box::new(same)),
+            restore: StoredValue::new(None),
+        }
+    }
+
+    /// Restore the default with `restore` instead of setting the value
+    /// directly, e.g. so a reset also formats the value and notifies
+    /// `on_change` like any other change
+    pub fn with_reset(self, restore: impl Fn(T) + Send + Sync + 'static) -> Self {
+        self.restore.set_value(Some(Box::new(restore)));
+        self
+    }
+
+    /// Whether the value differs from the default
+    pub fn is_dirty(&self) -> Signal<bool> {
+        let (value, default, same) = (self.value, self.default, self.same);
+        Signal::derive(move || {
+            value.with(|v| default.with(|d| !same.with_value(|same| same(v, d))))
+        })
+    }
+
+    /// Restore the default value
+    pub fn reset(&self) {
+        let default = self.default.get_untracked();
+        self.restore.with_value(|restore| match restore {
+            Some(restore) => restore(default),
+            None => self.value.set(default),
+        });
+    }
+
+    /// Accept the current value as the new default, e.g. after saving
+    pub fn mark_clean(&self) {
+        self.default.set(self.value.get_untracked());
+    }
+
+    pub fn default_value(&self) -> T {
+        self.default.get_untracked()
+    }
+
+    pub fn value(&self) -> RwSignal<T> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dirty_on_edit_and_clean_on_reset() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new("10".to_string());
+            let state = FieldState::new(value, "10".to_string());
+            let dirty = state.is_dirty();
+            assert!(!dirty.get());
+
+            value.set("12".to_string());
+            assert!(dirty.get());
+
+            state.reset();
+            assert_eq!(value.get(), "10");
+            assert!(!dirty.get());
+        });
+    }
+
+    #[test]
+    fn test_reset_runs_custom_restore() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(3);
+            let notified = RwSignal::new(None);
+            let state = FieldState::new(value, 1).with_reset(move |default| {
+                value.set(default);
+                notified.set(Some(default));
+            });
+
+            state.reset();
+            assert_eq!(value.get(), 1);
+            assert_eq!(notified.get(), Some(1));
+        });
+    }
+
+    #[test]
+    fn test_comparator_ignores_formatting() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new("1000".to_string());
+            let state = FieldState::with_comparator(value, "1000".to_string(), |a, b| {
+                a.replace(',', "") == b.replace(',', "")
+            });
+            value.set("1,000".to_string());
+            assert!(!state.is_dirty().get());
+
+            value.set("1,001".to_string());
+            assert!(state.is_dirty().get());
+        });
+    }
+
+    #[test]
+    fn test_mark_clean_moves_default() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let value = RwSignal::new(1.5_f64);
+            let state = FieldState::new(value, 1.5);
+            value.set(2.0);
+            assert!(state.is_dirty().get());
+
+            state.mark_clean();
+            assert!(!state.is_dirty().get());
+            assert_eq!(state.default_value(), 2.0);
+
+            value.set(3.0);
+            state.reset();
+            assert_eq!(value.get(), 2.0);
+        });
+    }
+}
@@ -0,0 +1,299 @@
+//! Placement logic for floating elements (popovers, menus, tooltips).
+//!
+//! Everything works on plain rectangles in viewport coordinates so the
+//! decisions can be tested without a DOM; components measure with
+//! `getBoundingClientRect` and feed the results in.
+
+/// Axis-aligned rectangle in viewport (client) coordinates
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    pub fn new(left: f64, top: f64, width: f64, height: f64) -> Self {
+        Self {
+            left,
+            top,
+            width,
+            height,
+        }
+    }
+
+    pub fn right(&self) -> f64 {
+        self.left + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.top + self.height
+    }
+
+    pub fn from_dom(rect: &web_sys::DomRect) -> Self {
+        Self::new(rect.left(), rect.top(), rect.width(), rect.height())
+    }
+}
+
+/// Side of the target a floating element is drawn on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Placement {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Placement {
+    pub fn opposite(self) -> Self {
+        match self {
+            Placement::Top => Placement::Bottom,
+            Placement::Bottom => Placement::Top,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+
+    /// `true` for Top/Bottom, where the element is shifted horizontally
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Placement::Top | Placement::Bottom)
+    }
+}
+
+/// Result of [`compute_placement`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FloatingPosition {
+    pub placement: Placement,
+    /// Offset in pixels along the cross axis (x for Top/Bottom, y for
+    /// Left/Right) that keeps the centred element inside the viewport
+    pub shift: f64,
+}
+
+/// Pixels by which a `width` x `height` element placed on `placement`
+/// of `target`, `gap` pixels away, runs past the viewport on its main axis
+pub fn main_axis_overflow(
+    target: Rect,
+    width: f64,
+    height: f64,
+    placement: Placement,
+    viewport: (f64, f64),
+    gap: f64,
+) -> f64 {
+    let (viewport_width, viewport_height) = viewport;
+    let overflow = match placement {
+        Placement::Top => height + gap - target.top,
+        Placement::Bottom => target.bottom() + gap + height - viewport_height,
+        Placement::Left => width + gap - target.left,
+        Placement::Right => target.right() + gap + width - viewport_width,
+    };
+    overflow.max(0.0)
+}
+
+/// First of `candidates` on which the element fits without overflowing
+/// the viewport. When none fits, the one with the least overflow wins,
+/// earlier candidates breaking ties. Falls back to `Bottom` for an empty
+/// list.
+pub fn choose_placement(
+    target: Rect,
+    width: f64,
+    height: f64,
+    viewport: (f64, f64),
+    gap: f64,
+    candidates: &[Placement],
+) -> Placement {
+    let mut best: Option<(Placement, f64)> = None;
+    for &candidate in candidates {
+        let overflow = main_axis_overflow(target, width, height, candidate, viewport, gap);
+        if overflow == 0.0 {
+            return candidate;
+        }
+        if best.is_none_or(|(_, least)| overflow < least) {
+            best = Some((candidate, overflow));
+        }
+    }
+    best.map(|(placement, _)| placement)
+        .unwrap_or(Placement::Bottom)
+}
+
+/// Shift along the cross axis that moves an element centred on `target`
+/// back inside the viewport, keeping `padding` pixels from the edges.
+/// An element larger than the viewport is aligned to its start edge.
+pub fn cross_axis_shift(
+    target: Rect,
+    width: f64,
+    height: f64,
+    placement: Placement,
+    viewport: (f64, f64),
+    padding: f64,
+) -> f64 {
+    let (center, size, limit) = if placement.is_vertical() {
+        (target.left + target.width / 2.0, width, viewport.0)
+    } else {
+        (target.top + target.height / 2.0, height, viewport.1)
+    };
+    let start = center - size / 2.0;
+    let max_start = (limit - size - padding).max(padding);
+    start.clamp(padding, max_start) - start
+}
+
+/// Sides to try, in order: `preferred` first, then with `flip` the
+/// `fallbacks` (or just the opposite side when `fallbacks` is empty)
+pub fn placement_candidates(
+    preferred: Placement,
+    flip: bool,
+    fallbacks: &[Placement],
+) -> Vec<Placement> {
+    let mut candidates = vec![preferred];
+    if flip {
+        if fallbacks.is_empty() {
+            candidates.push(preferred.opposite());
+        } else {
+            candidates.extend(fallbacks.iter().filter(|&&p| p != preferred));
+        }
+    }
+    candidates
+}
+
+/// Pick a side from `candidates` for a `width` x `height` element and
+/// the cross-axis shift that keeps it on screen
+pub fn compute_placement(
+    target: Rect,
+    width: f64,
+    height: f64,
+    viewport: (f64, f64),
+    candidates: &[Placement],
+    gap: f64,
+) -> FloatingPosition {
+    let placement = choose_placement(target, width, height, viewport, gap, candidates);
+    let shift = cross_axis_shift(target, width, height, placement, viewport, gap);
+    FloatingPosition { placement, shift }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: (f64, f64) = (1024.0, 768.0);
+
+    #[test]
+    fn test_keeps_preferred_when_it_fits() {
+        let target = Rect::new(400.0, 300.0, 100.0, 40.0);
+        let position = compute_placement(
+            target,
+            200.0,
+            100.0,
+            VIEWPORT,
+            &placement_candidates(Placement::Bottom, true, &[]),
+            8.0,
+        );
+        assert_eq!(position.placement, Placement::Bottom);
+        assert_eq!(position.shift, 0.0);
+    }
+
+    #[test]
+    fn test_flips_to_opposite_near_bottom_edge() {
+        let target = Rect::new(400.0, 700.0, 100.0, 40.0);
+        let position = compute_placement(
+            target,
+            200.0,
+            100.0,
+            VIEWPORT,
+            &placement_candidates(Placement::Bottom, true, &[]),
+            8.0,
+        );
+        assert_eq!(position.placement, Placement::Top);
+
+        // Without flip the preferred side is kept even though it overflows
+        let fixed = compute_placement(
+            target,
+            200.0,
+            100.0,
+            VIEWPORT,
+            &placement_candidates(Placement::Bottom, false, &[]),
+            8.0,
+        );
+        assert_eq!(fixed.placement, Placement::Bottom);
+    }
+
+    #[test]
+    fn test_uses_fallbacks_in_order() {
+        // Tight at top and bottom, room only on the right
+        let target = Rect::new(100.0, 20.0, 100.0, 720.0);
+        let position = compute_placement(
+            target,
+            200.0,
+            100.0,
+            VIEWPORT,
+            &placement_candidates(
+                Placement::Bottom,
+                true,
+                &[Placement::Top, Placement::Left, Placement::Right],
+            ),
+            8.0,
+        );
+        assert_eq!(position.placement, Placement::Right);
+    }
+
+    #[test]
+    fn test_least_overflow_wins_when_nothing_fits() {
+        let target = Rect::new(400.0, 200.0, 100.0, 40.0);
+        // 600px tall: 200px of room above, 528px below
+        let placement = choose_placement(
+            target,
+            200.0,
+            600.0,
+            VIEWPORT,
+            0.0,
+            &[Placement::Top, Placement::Bottom],
+        );
+        assert_eq!(placement, Placement::Bottom);
+    }
+
+    #[test]
+    fn test_shifts_along_cross_axis_near_edges() {
+        // Target at the left edge: a centred 200px dropdown would start at -50
+        let target = Rect::new(0.0, 300.0, 100.0, 40.0);
+        let shift = cross_axis_shift(target, 200.0, 100.0, Placement::Bottom, VIEWPORT, 8.0);
+        assert_eq!(shift, 58.0);
+
+        // Near the right edge it moves left
+        let target = Rect::new(950.0, 300.0, 60.0, 40.0);
+        let shift = cross_axis_shift(target, 200.0, 100.0, Placement::Bottom, VIEWPORT, 8.0);
+        assert_eq!(shift, -64.0);
+
+        // Left/Right placements shift vertically
+        let target = Rect::new(400.0, 0.0, 100.0, 20.0);
+        let shift = cross_axis_shift(target, 200.0, 100.0, Placement::Right, VIEWPORT, 0.0);
+        assert_eq!(shift, 40.0);
+    }
+
+    #[test]
+    fn test_placement_candidates() {
+        assert_eq!(
+            placement_candidates(Placement::Left, true, &[]),
+            vec![Placement::Left, Placement::Right]
+        );
+        assert_eq!(
+            placement_candidates(Placement::Top, true, &[Placement::Top, Placement::Right]),
+            vec![Placement::Top, Placement::Right]
+        );
+        assert_eq!(
+            placement_candidates(Placement::Top, false, &[Placement::Right]),
+            vec![Placement::Top]
+        );
+    }
+
+    #[test]
+    fn test_main_axis_overflow() {
+        let target = Rect::new(900.0, 100.0, 100.0, 40.0);
+        assert_eq!(
+            main_axis_overflow(target, 200.0, 50.0, Placement::Right, VIEWPORT, 0.0),
+            176.0
+        );
+        assert_eq!(
+            main_axis_overflow(target, 200.0, 50.0, Placement::Left, VIEWPORT, 0.0),
+            0.0
+        );
+    }
+}
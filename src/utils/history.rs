@@ -0,0 +1,136 @@
+/// Bounded undo/redo history of whole values.
+///
+/// Callers `record` the value *before* each discrete edit (a resize, a
+/// cell edit session, a conversion), not every keystroke. `undo`/`redo`
+/// take the current value so it can be moved onto the opposite stack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EditHistory<T> {
+    undo_stack: Vec<T>,
+    redo_stack: Vec<T>,
+    capacity: usize,
+}
+
+impl<T: Clone + PartialEq> EditHistory<T> {
+    /// Create an empty history keeping at most `capacity` undo steps
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Record the value before an edit. Clears the redo stack.
+    pub fn record(&mut self, previous: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        // Don't record duplicates
+        if self.undo_stack.last() != Some(&previous) {
+            self.undo_stack.push(previous);
+            if self.undo_stack.len() > self.capacity {
+                self.undo_stack.remove(0);
+            }
+        }
+        self.redo_stack.clear();
+    }
+
+    /// Step back, returning the value to restore
+    pub fn undo(&mut self, current: T) -> Option<T> {
+        let previous = self.undo_stack.pop()?;
+        self.redo_stack.push(current);
+        Some(previous)
+    }
+
+    /// Step forward again after an undo, returning the value to restore
+    pub fn redo(&mut self, current: T) -> Option<T> {
+        let next = self.redo_stack.pop()?;
+        self.undo_stack.push(current);
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
+
+/// Which history step a keyboard shortcut asks for.
+///
+/// Ctrl/Cmd+Z undoes; Ctrl/Cmd+Y and Ctrl/Cmd+Shift+Z redo.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HistoryShortcut {
+    Undo,
+    Redo,
+}
+
+impl HistoryShortcut {
+    pub fn from_key(key: &str, ctrl_or_meta: bool, shift: bool) -> Option<Self> {
+        if !ctrl_or_meta {
+            return None;
+        }
+        match key {
+            "z" | "Z" if shift => Some(HistoryShortcut::Redo),
+            "z" | "Z" => Some(HistoryShortcut::Undo),
+            "y" | "Y" => Some(HistoryShortcut::Redo),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_redo_round_trip() {
+        let mut history = EditHistory::new(10);
+        history.record(1);
+        history.record(2);
+
+        assert_eq!(history.undo(3), Some(2));
+        assert_eq!(history.undo(2), Some(1));
+        assert_eq!(history.undo(1), None);
+
+        assert_eq!(history.redo(1), Some(2));
+        assert_eq!(history.redo(2), Some(3));
+        assert_eq!(history.redo(3), None);
+    }
+
+    #[test]
+    fn test_record_clears_redo_and_caps_depth() {
+        let mut history = EditHistory::new(2);
+        history.record(1);
+        history.record(2);
+        history.record(3);
+        assert_eq!(history.undo(4), Some(3));
+        assert!(history.can_redo());
+
+        history.record(3);
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(5), Some(3));
+        assert_eq!(history.undo(3), Some(2));
+        // Oldest entry was dropped by the cap
+        assert_eq!(history.undo(2), None);
+    }
+
+    #[test]
+    fn test_history_shortcut() {
+        assert_eq!(
+            HistoryShortcut::from_key("z", true, false),
+            Some(HistoryShortcut::Undo)
+        );
+        assert_eq!(
+            HistoryShortcut::from_key("Z", true, true),
+            Some(HistoryShortcut::Redo)
+        );
+        assert_eq!(
+            HistoryShortcut::from_key("y", true, false),
+            Some(HistoryShortcut::Redo)
+        );
+        assert_eq!(HistoryShortcut::from_key("z", false, false), None);
+    }
+}
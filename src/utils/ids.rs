@@ -0,0 +1,62 @@
+use leptos::prelude::*;
+
+/// Counter for element ids generated while rendering one tree.
+///
+/// `MingotProvider` provides one, so ids count up from zero in render
+/// order below it. A server render and the client hydrating its output
+/// create components in the same order and so get the same ids, which a
+/// process-wide counter shared by every request can't guarantee.
+#[derive(Clone, Copy, Debug)]
+pub struct IdSequence(StoredValue<usize>);
+
+impl IdSequence {
+    pub fn new() -> Self {
+        Self(StoredValue::new(0))
+    }
+
+    /// The next number in the sequence
+    pub fn next(&self) -> usize {
+        let id = self.0.get_value();
+        self.0.set_value(id + 1);
+        id
+    }
+}
+
+impl Default for IdSequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The `IdSequence` in context, if a provider set one up
+pub fn use_id_sequence() -> Option<IdSequence> {
+    use_context::<IdSequence>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_counts_up() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let sequence = IdSequence::new();
+            assert_eq!(sequence.next(), 0);
+            assert_eq!(sequence.next(), 1);
+            assert_eq!(sequence.next(), 2);
+        });
+    }
+
+    #[test]
+    fn test_sequence_from_context() {
+        let owner = Owner::new();
+        owner.with(|| {
+            assert!(use_id_sequence().is_none());
+            provide_context(IdSequence::new());
+            let sequence = use_id_sequence().unwrap();
+            assert_eq!(sequence.next(), 0);
+            assert_eq!(use_id_sequence().unwrap().next(), 1);
+        });
+    }
+}
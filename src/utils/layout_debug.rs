@@ -0,0 +1,113 @@
+use crate::theme::{get_scheme_colors, use_mingot_config, Theme};
+use crate::utils::StyleBuilder;
+
+/// Layout primitive drawn by the debug overlay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutDebugKind {
+    Grid,
+    Stack,
+    Group,
+    Container,
+}
+
+impl LayoutDebugKind {
+    fn name(self) -> &'static str {
+        match self {
+            LayoutDebugKind::Grid => "Grid",
+            LayoutDebugKind::Stack => "Stack",
+            LayoutDebugKind::Group => "Group",
+            LayoutDebugKind::Container => "Container",
+        }
+    }
+
+    /// Palette color for this kind's outline and label
+    fn color_name(self) -> &'static str {
+        match self {
+            LayoutDebugKind::Grid => "violet",
+            LayoutDebugKind::Stack => "blue",
+            LayoutDebugKind::Group => "teal",
+            LayoutDebugKind::Container => "orange",
+        }
+    }
+
+    /// Label shown above the element, e.g. `"Stack · gap 1rem"`
+    pub fn label(self, details: &str) -> String {
+        if details.is_empty() {
+            self.name().to_string()
+        } else {
+            format!("{} · {}", self.name(), details)
+        }
+    }
+}
+
+/// Whether a layout component should draw its debug overlay: either its
+/// own `debug` prop or `MingotConfig::debug_layout` is set.
+pub fn layout_debug_enabled(explicit: bool) -> bool {
+    explicit || use_mingot_config().debug_layout
+}
+
+/// Add the debug outline and a translucent tint that makes gaps and
+/// padding visible between children.
+pub fn add_debug_outline(builder: &mut StyleBuilder, kind: LayoutDebugKind, theme: &Theme) {
+    let color = get_scheme_colors(theme)
+        .get_color(kind.color_name(), 6)
+        .unwrap_or_else(|| "#228be6".to_string());
+    builder
+        .add("outline", format!("1px dashed {}", color))
+        .add("outline-offset", "-1px")
+        .add("box-shadow", format!("inset 0 0 0 100vmax {}14", color))
+        .add("position", "relative")
+        .add("--mingot-debug-color", color);
+}
+
+/// Stylesheet for the `data-mingot-debug` label
+pub const LAYOUT_DEBUG_CSS: &str = "[data-mingot-debug]::before {
+    content: attr(data-mingot-debug);
+    position: absolute;
+    top: 0;
+    left: 0;
+    z-index: 1;
+    padding: 0 0.25rem;
+    font: 0.625rem/1.4 monospace;
+    color: #fff;
+    background: var(--mingot-debug-color);
+    pointer-events: none;
+    white-space: nowrap;
+}";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_label() {
+        assert_eq!(LayoutDebugKind::Stack.label("gap 1rem"), "Stack · gap 1rem");
+        assert_eq!(LayoutDebugKind::Grid.label(""), "Grid");
+    }
+
+    #[test]
+    fn test_debug_outline_uses_theme_color() {
+        let theme = Theme::default();
+        let mut builder = StyleBuilder::new();
+        add_debug_outline(&mut builder, LayoutDebugKind::Stack, &theme);
+        let blue = get_scheme_colors(&theme).get_color("blue", 6).unwrap();
+        let styles = builder.build();
+        assert!(styles.contains(&format!("outline: 1px dashed {}", blue)));
+        assert!(styles.contains(&format!("--mingot-debug-color: {}", blue)));
+    }
+
+    #[test]
+    fn test_layout_debug_enabled_from_config() {
+        use crate::theme::MingotConfig;
+        use leptos::prelude::*;
+
+        let owner = Owner::new();
+        owner.with(|| {
+            assert!(!layout_debug_enabled(false));
+            assert!(layout_debug_enabled(true));
+
+            provide_context(MingotConfig::default().debug_layout(true));
+            assert!(layout_debug_enabled(false));
+        });
+    }
+}
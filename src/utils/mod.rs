@@ -1,3 +1,19 @@
+pub mod field_state;
+pub mod floating;
+pub mod history;
+pub mod ids;
+pub mod layout_debug;
 pub mod style_builder;
+pub mod text;
+pub mod ticket_gate;
+pub mod virtual_window;
 
+pub use field_state::*;
+pub use floating::*;
+pub use history::*;
+pub use ids::*;
+pub use layout_debug::*;
 pub use style_builder::*;
+pub use text::*;
+pub use ticket_gate::*;
+pub use virtual_window::*;
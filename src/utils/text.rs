@@ -0,0 +1,215 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Count user-perceived characters (extended grapheme clusters) in a string.
+///
+/// Unlike `str::len` or `chars().count()`, this treats multi-codepoint
+/// sequences such as emoji with skin-tone modifiers or ZWJ families as a
+/// single character.
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// The longest prefix of `text` holding at most `max` grapheme clusters.
+///
+/// Never splits a cluster, so an emoji sequence is either kept whole or
+/// dropped whole.
+pub fn truncate_graphemes(text: &str, max: usize) -> &str {
+    match text.grapheme_indices(true).nth(max) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// Apply a `max` grapheme limit to an edit that turned `previous` into
+/// `next`.
+///
+/// Only the inserted text is cut, to whatever still fits, so the text
+/// around the edit is never lost; an insertion with no room left is
+/// rejected outright. Returns `None` when `next` is accepted as is,
+/// otherwise the accepted value and the caret offset just after the kept
+/// insertion, in UTF-16 code units as DOM selection ranges expect.
+pub fn limit_insertion(previous: &str, next: &str, max: usize) -> Option<(String, u32)> {
+    let before: Vec<&str> = previous.graphemes(true).collect();
+    let after: Vec<&str> = next.graphemes(true).collect();
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let inserted = &after[prefix..after.len() - suffix];
+    let room = max.saturating_sub(prefix + suffix);
+    if inserted.len() <= room {
+        return None;
+    }
+
+    let head: String = after[..prefix]
+        .iter()
+        .chain(&inserted[..room])
+        .copied()
+        .collect();
+    let caret = head.encode_utf16().count() as u32;
+    let tail: String = after[after.len() - suffix..].concat();
+    Some((head + &tail, caret))
+}
+
+/// Format a count, capping it at `max` with a trailing `+` (e.g. `99+`)
+pub fn format_count(count: u64, max: u64) -> String {
+    if count > max {
+        format!("{}+", max)
+    } else {
+        count.to_string()
+    }
+}
+
+/// Visual state of a `current/max` character counter
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CharacterCountState {
+    /// Comfortably below the limit
+    Normal,
+    /// At 90% of the limit or more, but not over it
+    Warning,
+    /// Over the limit
+    Exceeded,
+}
+
+impl CharacterCountState {
+    pub fn from_count(count: usize, max: usize) -> Self {
+        if count > max {
+            CharacterCountState::Exceeded
+        } else if count * 10 >= max * 9 {
+            CharacterCountState::Warning
+        } else {
+            CharacterCountState::Normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_count_ascii() {
+        assert_eq!(grapheme_count(""), 0);
+        assert_eq!(grapheme_count("hello"), 5);
+    }
+
+    #[test]
+    fn test_grapheme_count_multi_codepoint_emoji() {
+        // Family emoji: four people joined by zero-width joiners
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        assert_eq!(family.chars().count(), 7);
+        assert_eq!(grapheme_count(family), 1);
+
+        // Thumbs up with skin-tone modifier
+        assert_eq!(grapheme_count("ok \u{1F44D}\u{1F3FD}"), 4);
+    }
+
+    #[test]
+    fn test_grapheme_count_combining_marks() {
+        // "e" followed by a combining acute accent
+        assert_eq!(grapheme_count("caf\u{0065}\u{0301}"), 4);
+    }
+
+    #[test]
+    fn test_truncate_graphemes() {
+        assert_eq!(truncate_graphemes("hello", 3), "hel");
+        assert_eq!(truncate_graphemes("hi", 5), "hi");
+        assert_eq!(truncate_graphemes("hi", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_graphemes_keeps_clusters_whole() {
+        // Eleven UTF-16 code units, but one grapheme
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        assert_eq!(family.encode_utf16().count(), 11);
+        assert_eq!(truncate_graphemes(family, 1), family);
+
+        let text = format!("ab{}c", family);
+        assert_eq!(truncate_graphemes(&text, 3), format!("ab{}", family));
+        assert_eq!(truncate_graphemes(&text, 2), "ab");
+        assert_eq!(truncate_graphemes("cafe\u{0301}!", 4), "cafe\u{0301}");
+    }
+
+    #[test]
+    fn test_limit_insertion_keeps_surrounding_text() {
+        // Typing in the middle of a full field is rejected, caret stays put
+        assert_eq!(
+            limit_insertion("hello", "helXlo", 5),
+            Some(("hello".to_string(), 3))
+        );
+        // A paste is cut to what fits, not the text after it
+        assert_eq!(
+            limit_insertion("ab|cd", "abXYZ|cd", 7),
+            Some(("abXY|cd".to_string(), 4))
+        );
+        // Replacing a selection frees its room first
+        assert_eq!(limit_insertion("abcde", "aXYe", 5), None);
+        assert_eq!(
+            limit_insertion("abcde", "aXYZWe", 5),
+            Some(("aXYZe".to_string(), 4))
+        );
+    }
+
+    #[test]
+    fn test_limit_insertion_accepts_edits_within_limit() {
+        assert_eq!(limit_insertion("hell", "hello", 5), None);
+        assert_eq!(limit_insertion("hello", "helo", 5), None);
+        // Deleting from a value that is already too long is always allowed
+        assert_eq!(limit_insertion("toolong", "tolong", 3), None);
+        assert_eq!(
+            limit_insertion("toolong", "toolongX", 3),
+            Some(("toolong".to_string(), 7))
+        );
+    }
+
+    #[test]
+    fn test_limit_insertion_counts_graphemes() {
+        // One grapheme but eleven UTF-16 code units
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        let next = format!("a{}{}b", family, family);
+        assert_eq!(
+            limit_insertion("ab", &next, 3),
+            Some((format!("a{}b", family), 12))
+        );
+    }
+
+    #[test]
+    fn test_format_count_below_max() {
+        assert_eq!(format_count(0, 99), "0");
+        assert_eq!(format_count(7, 99), "7");
+        assert_eq!(format_count(99, 99), "99");
+    }
+
+    #[test]
+    fn test_format_count_overflow() {
+        assert_eq!(format_count(100, 99), "99+");
+        assert_eq!(format_count(1_000_000, 999), "999+");
+    }
+
+    #[test]
+    fn test_character_count_state() {
+        assert_eq!(
+            CharacterCountState::from_count(5, 10),
+            CharacterCountState::Normal
+        );
+        assert_eq!(
+            CharacterCountState::from_count(9, 10),
+            CharacterCountState::Warning
+        );
+        assert_eq!(
+            CharacterCountState::from_count(10, 10),
+            CharacterCountState::Warning
+        );
+        assert_eq!(
+            CharacterCountState::from_count(11, 10),
+            CharacterCountState::Exceeded
+        );
+    }
+}
@@ -0,0 +1,57 @@
+/// Tickets for delayed work where only the latest request may take effect.
+///
+/// Each request (a pointer move, a changed value, a resumed countdown)
+/// takes a ticket before it schedules its timeout or future. When that
+/// fires it checks the ticket first: a newer request, or an explicit
+/// [`invalidate`](Self::invalidate), makes older tickets stale, so a late
+/// callback never overwrites newer state. Used by `Tooltip` delays,
+/// `Notification` auto-close and async validation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TicketGate {
+    generation: u64,
+}
+
+impl TicketGate {
+    /// Start a new request, superseding every earlier ticket
+    pub fn issue(&mut self) -> u64 {
+        self.generation += 1;
+        self.generation
+    }
+
+    /// Supersede every earlier ticket without starting a new request,
+    /// e.g. when the pending work is cancelled
+    pub fn invalidate(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Whether `ticket` is still the latest
+    pub fn is_current(&self, ticket: u64) -> bool {
+        self.generation == ticket
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newer_ticket_supersedes_older() {
+        let mut gate = TicketGate::default();
+        let first = gate.issue();
+        assert!(gate.is_current(first));
+
+        let second = gate.issue();
+        assert!(!gate.is_current(first));
+        assert!(gate.is_current(second));
+    }
+
+    #[test]
+    fn test_invalidate_cancels_pending_ticket() {
+        let mut gate = TicketGate::default();
+        let ticket = gate.issue();
+        gate.invalidate();
+        assert!(!gate.is_current(ticket));
+        let next = gate.issue();
+        assert!(gate.is_current(next));
+    }
+}
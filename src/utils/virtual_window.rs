@@ -0,0 +1,104 @@
+/// The slice of rows rendered by a virtualized list (Table rows, Select
+/// options), plus the spacer heights standing in for the rows above and
+/// below it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VirtualWindow {
+    /// First rendered row (inclusive)
+    pub start: usize,
+    /// Last rendered row (exclusive)
+    pub end: usize,
+    /// Height in pixels of the rows skipped above the window
+    pub offset_top: f64,
+    /// Height in pixels of the rows skipped below the window
+    pub offset_bottom: f64,
+}
+
+impl VirtualWindow {
+    /// Compute which rows are visible for the given scroll position, with
+    /// `overscan` extra rows rendered on each side to avoid flicker
+    pub fn compute(
+        total_rows: usize,
+        row_height: f64,
+        viewport_height: f64,
+        scroll_top: f64,
+        overscan: usize,
+    ) -> Self {
+        if total_rows == 0 || row_height <= 0.0 {
+            return Self {
+                start: 0,
+                end: total_rows,
+                offset_top: 0.0,
+                offset_bottom: 0.0,
+            };
+        }
+
+        let first_visible = ((scroll_top.max(0.0) / row_height).floor() as usize).min(total_rows);
+        let visible_count = (viewport_height.max(0.0) / row_height).ceil() as usize + 1;
+
+        let start = first_visible.saturating_sub(overscan).min(total_rows);
+        let end = (first_visible + visible_count + overscan).min(total_rows);
+
+        Self {
+            start,
+            end,
+            offset_top: start as f64 * row_height,
+            offset_bottom: (total_rows - end) as f64 * row_height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_window_top() {
+        let window = VirtualWindow::compute(10_000, 40.0, 400.0, 0.0, 5);
+        assert_eq!(window.start, 0);
+        // 10 visible rows + 1 partial + 5 overscan
+        assert_eq!(window.end, 16);
+        assert_eq!(window.offset_top, 0.0);
+        assert_eq!(window.offset_bottom, (10_000 - 16) as f64 * 40.0);
+    }
+
+    #[test]
+    fn test_virtual_window_scrolled() {
+        let window = VirtualWindow::compute(10_000, 40.0, 400.0, 4_000.0, 5);
+        assert_eq!(window.start, 95);
+        assert_eq!(window.end, 116);
+        assert_eq!(window.offset_top, 95.0 * 40.0);
+        // Spacers plus rendered rows always add up to the full height
+        let rendered = (window.end - window.start) as f64 * 40.0;
+        assert_eq!(
+            window.offset_top + rendered + window.offset_bottom,
+            10_000.0 * 40.0
+        );
+    }
+
+    #[test]
+    fn test_virtual_window_bottom_and_shrunk_data() {
+        // Scrolled past the end (e.g. data shrank after an update)
+        let window = VirtualWindow::compute(50, 40.0, 400.0, 100_000.0, 5);
+        assert_eq!(window.start, 45);
+        assert_eq!(window.end, 50);
+        assert_eq!(window.offset_bottom, 0.0);
+    }
+
+    #[test]
+    fn test_virtual_window_partial_rows_without_overscan() {
+        // Scrolled halfway into row 2 of a viewport shorter than three rows
+        let window = VirtualWindow::compute(100, 30.0, 75.0, 75.0, 0);
+        assert_eq!(window.start, 2);
+        // Rows 2 to 4 are visible, plus one row of slack for the partial edge
+        assert_eq!(window.end, 6);
+        assert_eq!(window.offset_top, 60.0);
+        assert_eq!(window.offset_bottom, 94.0 * 30.0);
+    }
+
+    #[test]
+    fn test_virtual_window_empty() {
+        let window = VirtualWindow::compute(0, 40.0, 400.0, 0.0, 5);
+        assert_eq!(window.start, 0);
+        assert_eq!(window.end, 0);
+    }
+}
@@ -0,0 +1,325 @@
+use super::{ValidationError, ValidationResult, Validator};
+use crate::utils::TicketGate;
+use leptos::prelude::*;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Future returned by [`AsyncValidator::validate_async`]
+pub type ValidationFuture = Pin<Box<dyn Future<Output = ValidationResult>>>;
+
+/// Validator whose answer arrives later, e.g. a server-side uniqueness
+/// check. The future owns what it needs, so it can outlive `value`.
+pub trait AsyncValidator<T> {
+    fn validate_async(&self, value: &T) -> ValidationFuture;
+}
+
+/// Runs a synchronous [`Validator`] where an [`AsyncValidator`] is
+/// expected; the future is ready immediately. See [`Validator::into_async`].
+pub struct SyncAdapter<T, V> {
+    pub(super) validator: V,
+    pub(super) _phantom: PhantomData<T>,
+}
+
+impl<T, V: Validator<T>> AsyncValidator<T> for SyncAdapter<T, V> {
+    fn validate_async(&self, value: &T) -> ValidationFuture {
+        Box::pin(std::future::ready(self.validator.validate(value)))
+    }
+}
+
+/// Async validator using a closure that returns a future
+pub struct AsyncCustomValidator<T, F> {
+    func: F,
+    _phantom: PhantomData<T>,
+}
+
+impl<T, F, Fut> AsyncValidator<T> for AsyncCustomValidator<T, F>
+where
+    F: Fn(&T) -> Fut,
+    Fut: Future<Output = ValidationResult> + 'static,
+{
+    fn validate_async(&self, value: &T) -> ValidationFuture {
+        Box::pin((self.func)(value))
+    }
+}
+
+pub fn async_custom<T, F, Fut>(func: F) -> AsyncCustomValidator<T, F>
+where
+    F: Fn(&T) -> Fut,
+    Fut: Future<Output = ValidationResult> + 'static,
+{
+    AsyncCustomValidator {
+        func,
+        _phantom: PhantomData,
+    }
+}
+
+/// Bookkeeping for async validation of a changing value.
+///
+/// Every change starts a new run and gets a ticket; only the latest
+/// ticket may start validating (after the debounce) or record a result,
+/// so a slow response for an old value never overwrites a newer one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AsyncValidationState {
+    tickets: TicketGate,
+    pending: bool,
+    result: Option<ValidationResult>,
+}
+
+impl AsyncValidationState {
+    /// The value changed: supersede any run in flight
+    pub fn start(&mut self) -> u64 {
+        self.pending = true;
+        self.tickets.issue()
+    }
+
+    /// Whether `ticket` is still the latest run
+    pub fn is_current(&self, ticket: u64) -> bool {
+        self.tickets.is_current(ticket)
+    }
+
+    /// Record the result of run `ticket`. Returns `false`, leaving the
+    /// state alone, when a newer run has started since.
+    pub fn finish(&mut self, ticket: u64, result: ValidationResult) -> bool {
+        if !self.is_current(ticket) {
+            return false;
+        }
+        self.pending = false;
+        self.result = Some(result);
+        true
+    }
+
+    /// A validation is waiting on its debounce or its future
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Result of the latest finished run
+    pub fn result(&self) -> Option<&ValidationResult> {
+        self.result.as_ref()
+    }
+}
+
+/// Handle returned by [`use_async_validation`]
+#[derive(Clone, Copy)]
+pub struct AsyncValidation {
+    state: RwSignal<AsyncValidationState>,
+}
+
+impl AsyncValidation {
+    /// Validation in progress, for a loading indicator
+    pub fn pending(&self) -> bool {
+        self.state.with(|s| s.is_pending())
+    }
+
+    /// Latest result; `None` until the first run finishes
+    pub fn result(&self) -> Option<ValidationResult> {
+        self.state.with(|s| s.result().cloned())
+    }
+
+    /// Latest error, if the value is invalid
+    pub fn error(&self) -> Option<ValidationError> {
+        self.result().and_then(Result::err)
+    }
+
+    /// Error message, empty while valid or pending, to pass as an input's
+    /// `error`
+    pub fn error_message(&self) -> Signal<String> {
+        let state = self.state;
+        Signal::derive(move || {
+            state.with(|s| match s.result() {
+                Some(Err(e)) if !s.is_pending() => e.message.clone(),
+                _ => String::new(),
+            })
+        })
+    }
+}
+
+/// Validate `value` with `validator` whenever it changes, waiting for
+/// `debounce` of quiet first. Changes during the wait or while a check
+/// is in flight make the older run's result be dropped.
+///
+/// # Example
+/// ```rust,ignore
+/// let username = RwSignal::new(String::new());
+/// let available = async_custom(|name: &String| {
+///     let name = name.clone();
+///     async move { check_username(&name).await }
+/// });
+/// let validation = use_async_validation(username.into(), available, Duration::from_millis(300));
+///
+/// view! {
+///     <Input value=username on_input=Callback::new(move |v| username.set(v)) error=validation.error_message() />
+///     <Show when=move || validation.pending()>
+///         <Loader size=LoaderSize::Xs />
+///     </Show>
+/// }
+/// ```
+pub fn use_async_validation<T, V>(
+    value: Signal<T>,
+    validator: V,
+    debounce: Duration,
+) -> AsyncValidation
+where
+    T: Clone + Send + Sync + 'static,
+    V: AsyncValidator<T> + 'static,
+{
+    let state = RwSignal::new(AsyncValidationState::default());
+    let validator = StoredValue::new_local(validator);
+
+    Effect::new(move |_| {
+        let current = value.get();
+        let ticket = state.try_update(|s| s.start()).unwrap_or_default();
+        set_timeout(
+            move || {
+                if !state.with_untracked(|s| s.is_current(ticket)) {
+                    return;
+                }
+                let Some(future) = validator.try_with_value(|v| v.validate_async(&current)) else {
+                    return;
+                };
+                leptos::task::spawn_local(async move {
+                    let result = future.await;
+                    state.try_update(|s| s.finish(ticket, result));
+                });
+            },
+            debounce,
+        );
+    });
+
+    AsyncValidation { state }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{min_length, ErrorCode};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::task::{Context, Poll, Waker};
+
+    /// Future that stays pending until the test resolves it
+    #[derive(Clone, Default)]
+    struct MockResponse(Rc<RefCell<Option<ValidationResult>>>);
+
+    impl MockResponse {
+        fn resolve(&self, result: ValidationResult) {
+            *self.0.borrow_mut() = Some(result);
+        }
+    }
+
+    impl Future for MockResponse {
+        type Output = ValidationResult;
+
+        fn poll(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<ValidationResult> {
+            match self.0.borrow_mut().take() {
+                Some(result) => Poll::Ready(result),
+                None => Poll::Pending,
+            }
+        }
+    }
+
+    fn poll_once(future: &mut ValidationFuture) -> Poll<ValidationResult> {
+        future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    /// Async validator handing out a fresh mock response per call
+    fn mock_validator(responses: Rc<RefCell<Vec<MockResponse>>>) -> impl AsyncValidator<String> {
+        async_custom(move |_: &String| {
+            let response = MockResponse::default();
+            responses.borrow_mut().push(response.clone());
+            response
+        })
+    }
+
+    #[test]
+    fn test_sync_adapter_is_ready_immediately() {
+        let validator = min_length(3).into_async();
+        let mut ok = validator.validate_async(&"abcd".to_string());
+        assert_eq!(poll_once(&mut ok), Poll::Ready(Ok(())));
+        let mut err = validator.validate_async(&"ab".to_string());
+        match poll_once(&mut err) {
+            Poll::Ready(Err(e)) => assert_eq!(e.code, ErrorCode::MinLength),
+            other => panic!("unexpected {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_mock_future_waits_for_resolution() {
+        let responses = Rc::new(RefCell::new(Vec::new()));
+        let validator = mock_validator(responses.clone());
+        let mut future = validator.validate_async(&"taken".to_string());
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+
+        responses.borrow()[0].resolve(Err(ValidationError::custom("Taken")));
+        assert!(matches!(poll_once(&mut future), Poll::Ready(Err(_))));
+    }
+
+    #[test]
+    fn test_late_response_is_dropped() {
+        let responses = Rc::new(RefCell::new(Vec::new()));
+        let validator = mock_validator(responses.clone());
+        let mut state = AsyncValidationState::default();
+
+        // "ali" goes out, then the user types on and "alice" goes out
+        let first = state.start();
+        let mut first_future = validator.validate_async(&"ali".to_string());
+        let second = state.start();
+        let mut second_future = validator.validate_async(&"alice".to_string());
+        assert!(state.is_pending());
+
+        // The newer check answers first
+        responses.borrow()[1].resolve(Ok(()));
+        let Poll::Ready(result) = poll_once(&mut second_future) else {
+            panic!("second check should be ready");
+        };
+        assert!(state.finish(second, result));
+        assert!(!state.is_pending());
+
+        // The stale one arrives late and must not overwrite it
+        responses.borrow()[0].resolve(Err(ValidationError::custom("Taken")));
+        let Poll::Ready(result) = poll_once(&mut first_future) else {
+            panic!("first check should be ready");
+        };
+        assert!(!state.finish(first, result));
+        assert_eq!(state.result(), Some(&Ok(())));
+    }
+
+    #[test]
+    fn test_superseded_run_stays_pending() {
+        let mut state = AsyncValidationState::default();
+        let first = state.start();
+        let _second = state.start();
+        // The first run finishing doesn't end the loading state
+        assert!(!state.finish(first, Ok(())));
+        assert!(state.is_pending());
+        assert_eq!(state.result(), None);
+    }
+
+    #[test]
+    fn test_debounce_skips_superseded_timers() {
+        let mut state = AsyncValidationState::default();
+        // Three keystrokes inside the debounce window
+        let tickets: Vec<u64> = (0..3).map(|_| state.start()).collect();
+        // When the timers fire, only the last one may start validating
+        let started: Vec<bool> = tickets.iter().map(|&t| state.is_current(t)).collect();
+        assert_eq!(started, vec![false, false, true]);
+    }
+
+    #[test]
+    fn test_finish_records_latest_result() {
+        let mut state = AsyncValidationState::default();
+        let ticket = state.start();
+        let error = ValidationError::custom("Taken");
+        assert!(state.finish(ticket, Err(error.clone())));
+        assert_eq!(state.result(), Some(&Err(error)));
+
+        // A new edit keeps the previous result visible while pending
+        state.start();
+        assert!(state.is_pending());
+        assert!(state.result().is_some());
+    }
+}
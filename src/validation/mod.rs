@@ -1,5 +1,7 @@
+pub mod async_validator;
 pub mod validators;
 
+pub use async_validator::*;
 pub use validators::*;
 
 use std::fmt;
@@ -27,6 +29,10 @@ impl ValidationError {
         Self::new("Invalid email address", ErrorCode::InvalidEmail)
     }
 
+    pub fn invalid_url() -> Self {
+        Self::new("Invalid URL", ErrorCode::InvalidUrl)
+    }
+
     pub fn min_length(min: usize) -> Self {
         Self::new(
             format!("Must be at least {} characters", min),
@@ -56,6 +62,20 @@ impl ValidationError {
         )
     }
 
+    pub fn file_too_large(name: &str, max_size: impl fmt::Display) -> Self {
+        Self::new(
+            format!("{}: larger than {}", name, max_size),
+            ErrorCode::FileTooLarge,
+        )
+    }
+
+    pub fn too_many_files(name: &str, max_files: usize) -> Self {
+        Self::new(
+            format!("{}: at most {} files can be selected", name, max_files),
+            ErrorCode::TooManyFiles,
+        )
+    }
+
     pub fn custom(message: impl Into<String>) -> Self {
         Self::new(message, ErrorCode::Custom)
     }
@@ -78,6 +98,8 @@ pub enum ErrorCode {
     MinValue,
     MaxValue,
     Pattern,
+    FileTooLarge,
+    TooManyFiles,
     Custom,
 }
 
@@ -100,6 +122,42 @@ pub trait Validator<T> {
         }
     }
 
+    /// Combine this validator with another using OR logic. `other` only
+    /// runs when this one fails.
+    fn or<V: Validator<T>>(self, other: V) -> OrValidator<T, Self, V>
+    where
+        Self: Sized,
+    {
+        OrValidator {
+            first: self,
+            second: other,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Pass exactly when this validator fails, with `message` otherwise
+    fn not(self, message: impl Into<String>) -> NotValidator<T, Self>
+    where
+        Self: Sized,
+    {
+        NotValidator {
+            validator: self,
+            message: message.into(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Use this validator where an [`AsyncValidator`] is expected
+    fn into_async(self) -> SyncAdapter<T, Self>
+    where
+        Self: Sized,
+    {
+        SyncAdapter {
+            validator: self,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
     /// Map validation errors to custom messages
     fn with_message(self, message: impl Into<String>) -> MessageValidator<T, Self>
     where
@@ -128,6 +186,50 @@ impl<T, V1: Validator<T>, V2: Validator<T>> Validator<T> for AndValidator<T, V1,
     }
 }
 
+/// Validator that combines two validators with OR logic
+pub struct OrValidator<T, V1, V2> {
+    first: V1,
+    second: V2,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T, V1: Validator<T>, V2: Validator<T>> Validator<T> for OrValidator<T, V1, V2> {
+    fn validate(&self, value: &T) -> ValidationResult {
+        let Err(first) = self.first.validate(value) else {
+            return Ok(());
+        };
+        let Err(second) = self.second.validate(value) else {
+            return Ok(());
+        };
+        // Keep the code when both failed the same way
+        let code = if first.code == second.code {
+            first.code
+        } else {
+            ErrorCode::Custom
+        };
+        Err(ValidationError::new(
+            format!("{} or {}", first.message, second.message),
+            code,
+        ))
+    }
+}
+
+/// Validator that inverts another's result
+pub struct NotValidator<T, V> {
+    validator: V,
+    message: String,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T, V: Validator<T>> Validator<T> for NotValidator<T, V> {
+    fn validate(&self, value: &T) -> ValidationResult {
+        match self.validator.validate(value) {
+            Ok(()) => Err(ValidationError::custom(&self.message)),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
 /// Validator that overrides the error message
 pub struct MessageValidator<T, V> {
     validator: V,
@@ -1,5 +1,9 @@
 use super::{ErrorCode, ValidationError, ValidationResult, Validator};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt::Display;
 use std::marker::PhantomData;
+use std::ops::Bound;
 
 /// Validator that checks if a string is not empty
 #[derive(Clone)]
@@ -85,41 +89,93 @@ pub fn email() -> EmailValidator {
     EmailValidator
 }
 
-/// Validator that checks URL format
+/// Validator that checks URL structure: an `http`/`https` scheme, `://`
+/// and a well-formed host with an optional port
 #[derive(Clone)]
 pub struct UrlValidator;
 
 impl Validator<String> for UrlValidator {
     fn validate(&self, value: &String) -> ValidationResult {
-        if value.trim().is_empty() {
+        let value = value.trim();
+        if value.is_empty() {
             return Ok(()); // Empty is valid, use required() to make it mandatory
         }
 
-        if value.starts_with("http://") || value.starts_with("https://") {
+        if is_valid_url(value) {
             Ok(())
         } else {
-            Err(ValidationError::new(
-                "Invalid URL format",
-                ErrorCode::InvalidUrl,
-            ))
+            Err(ValidationError::invalid_url())
         }
     }
 }
 
+fn is_valid_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    if !["http", "https"].contains(&scheme.to_ascii_lowercase().as_str()) {
+        return false;
+    }
+    if rest.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    // Drop any `user:password@`
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+
+    let port_ok = |port: Option<&str>| {
+        port.is_none_or(|p| !p.is_empty() && p.len() <= 5 && p.bytes().all(|b| b.is_ascii_digit()))
+    };
+
+    // IPv6 literal, e.g. `[::1]:8080`
+    if let Some(bracketed) = host_port.strip_prefix('[') {
+        let Some((address, after)) = bracketed.split_once(']') else {
+            return false;
+        };
+        let address_ok =
+            !address.is_empty() && address.chars().all(|c| c.is_ascii_hexdigit() || c == ':');
+        let port = match after {
+            "" => None,
+            _ => match after.strip_prefix(':') {
+                Some(port) => Some(port),
+                None => return false,
+            },
+        };
+        return address_ok && port_ok(port);
+    }
+
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (host_port, None),
+    };
+    let host_ok = !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        });
+    host_ok && port_ok(port)
+}
+
 pub fn url() -> UrlValidator {
     UrlValidator
 }
 
-/// Validator that checks if a value matches a pattern
+/// Validator that checks if a value contains `pattern` as literal text.
+///
+/// Despite the name this is a substring check, not a regular expression;
+/// use [`RegexValidator`] for patterns.
+#[deprecated(note = "substring match only; use `regex()` or `RegexValidator::new()`")]
 #[derive(Clone)]
 pub struct PatternValidator {
     pattern: String,
 }
 
+#[allow(deprecated)]
 impl Validator<String> for PatternValidator {
     fn validate(&self, value: &String) -> ValidationResult {
-        // For now, just check if value contains the pattern
-        // In a real implementation, you'd use the regex crate
         if value.contains(&self.pattern) {
             Ok(())
         } else {
@@ -128,12 +184,68 @@ impl Validator<String> for PatternValidator {
     }
 }
 
+/// Substring validator; see [`PatternValidator`]
+#[deprecated(note = "substring match only; use `regex()` or `RegexValidator::new()`")]
+#[allow(deprecated)]
 pub fn pattern(pattern: impl Into<String>) -> PatternValidator {
     PatternValidator {
         pattern: pattern.into(),
     }
 }
 
+/// Validator that checks a string against a regular expression,
+/// compiled once up front. Anchor the pattern (`^...$`) to match the
+/// whole value.
+#[derive(Clone)]
+pub struct RegexValidator {
+    regex: Regex,
+}
+
+impl RegexValidator {
+    /// Compile `pattern`, or explain why it isn't a valid regex
+    pub fn new(pattern: &str) -> Result<Self, ValidationError> {
+        Regex::new(pattern).map(Self::from).map_err(|e| {
+            ValidationError::new(
+                format!("Invalid pattern `{}`: {}", pattern, e),
+                ErrorCode::Pattern,
+            )
+        })
+    }
+}
+
+impl From<Regex> for RegexValidator {
+    fn from(regex: Regex) -> Self {
+        Self { regex }
+    }
+}
+
+impl Validator<String> for RegexValidator {
+    fn validate(&self, value: &String) -> ValidationResult {
+        if value.is_empty() {
+            return Ok(()); // Empty is valid, use required() to make it mandatory
+        }
+
+        if self.regex.is_match(value) {
+            Ok(())
+        } else {
+            Err(ValidationError::pattern(self.regex.as_str()))
+        }
+    }
+}
+
+/// Regex validator for a pattern known to be valid, such as a literal.
+///
+/// # Panics
+///
+/// If `pattern` doesn't compile; use [`RegexValidator::new`] for patterns
+/// from user input.
+pub fn regex(pattern: &str) -> RegexValidator {
+    match RegexValidator::new(pattern) {
+        Ok(validator) => validator,
+        Err(e) => panic!("{}", e.message),
+    }
+}
+
 /// Validator for numeric minimum value
 #[derive(Clone)]
 pub struct MinValueValidator<T> {
@@ -174,6 +286,88 @@ pub fn max_value<T: PartialOrd + std::fmt::Display + Clone>(max: T) -> MaxValueV
     MaxValueValidator { max }
 }
 
+/// Validator for a value between optional lower and upper bounds, each
+/// inclusive or exclusive. Values that don't compare (NaN) fail.
+///
+/// # Example
+/// ```rust,ignore
+/// let percent = RangeValidator::between(0.0, 100.0);
+/// let probability = RangeValidator::between(0.0, 1.0).exclusive_max();
+/// let positive = RangeValidator::min(0).exclusive_min();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RangeValidator<T> {
+    lower: Bound<T>,
+    upper: Bound<T>,
+}
+
+impl<T> RangeValidator<T> {
+    pub fn new(lower: Bound<T>, upper: Bound<T>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// At least `min`
+    pub fn min(min: T) -> Self {
+        Self::new(Bound::Included(min), Bound::Unbounded)
+    }
+
+    /// At most `max`
+    pub fn max(max: T) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(max))
+    }
+
+    /// From `min` to `max`, both included
+    pub fn between(min: T, max: T) -> Self {
+        Self::new(Bound::Included(min), Bound::Included(max))
+    }
+
+    /// Exclude the lower bound itself
+    pub fn exclusive_min(mut self) -> Self {
+        self.lower = exclude(self.lower);
+        self
+    }
+
+    /// Exclude the upper bound itself
+    pub fn exclusive_max(mut self) -> Self {
+        self.upper = exclude(self.upper);
+        self
+    }
+}
+
+fn exclude<T>(bound: Bound<T>) -> Bound<T> {
+    match bound {
+        Bound::Included(value) => Bound::Excluded(value),
+        other => other,
+    }
+}
+
+impl<T: PartialOrd + Display> Validator<T> for RangeValidator<T> {
+    fn validate(&self, value: &T) -> ValidationResult {
+        let order = |bound: &T| value.partial_cmp(bound);
+        match &self.lower {
+            Bound::Included(min) if matches!(order(min), Some(Ordering::Less) | None) => {
+                return Err(ValidationError::min_value(min));
+            }
+            Bound::Excluded(min) if !matches!(order(min), Some(Ordering::Greater)) => {
+                return Err(ValidationError::new(
+                    format!("Must be greater than {}", min),
+                    ErrorCode::MinValue,
+                ));
+            }
+            _ => {}
+        }
+        match &self.upper {
+            Bound::Included(max) if matches!(order(max), Some(Ordering::Greater) | None) => {
+                Err(ValidationError::max_value(max))
+            }
+            Bound::Excluded(max) if !matches!(order(max), Some(Ordering::Less)) => Err(
+                ValidationError::new(format!("Must be less than {}", max), ErrorCode::MaxValue),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Custom validator using a closure
 #[derive(Clone)]
 pub struct CustomValidator<T, F>
@@ -276,6 +470,75 @@ mod tests {
         assert!(validator.validate(&"example.com".to_string()).is_err());
     }
 
+    #[test]
+    fn test_url_validator_structure() {
+        let validator = url();
+        for valid in [
+            "https://example.com/path?q=1#top",
+            "http://localhost:8080",
+            "https://user:pw@sub.example.co.uk/",
+            "HTTPS://EXAMPLE.COM",
+            "http://[::1]:3000/",
+            "http://192.168.0.1",
+        ] {
+            assert!(validator.validate(&valid.to_string()).is_ok(), "{valid}");
+        }
+        for invalid in [
+            "example.com",
+            "www.example.com/path",
+            "://example.com",
+            "ftp://example.com",
+            "https://",
+            "https:///path",
+            "https://exa mple.com",
+            "https://-example.com",
+            "https://example..com",
+            "https://example.com:port",
+            "https://example.com:",
+            "http://[::1",
+        ] {
+            let error = validator.validate(&invalid.to_string()).unwrap_err();
+            assert_eq!(error.code, ErrorCode::InvalidUrl, "{invalid}");
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_pattern_is_a_literal_substring_match() {
+        let validator = pattern("a.c");
+        assert!(validator.validate(&"xa.cx".to_string()).is_ok());
+        // `.` is not a wildcard
+        assert!(validator.validate(&"abc".to_string()).is_err());
+        assert!(regex("a.c").validate(&"abc".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_regex_validator() {
+        let validator = regex(r"^[A-Z]{3}-\d{4}$");
+        assert!(validator.validate(&"ABC-1234".to_string()).is_ok());
+        assert!(validator.validate(&"".to_string()).is_ok());
+
+        let error = validator.validate(&"abc-1234".to_string()).unwrap_err();
+        assert_eq!(error.code, ErrorCode::Pattern);
+        assert_eq!(error.message, r"Must match pattern: ^[A-Z]{3}-\d{4}$");
+        assert!(validator.validate(&"ABC-12345".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_regex_validator_invalid_pattern() {
+        let Err(error) = RegexValidator::new("([a-z]") else {
+            panic!("unclosed group should not compile");
+        };
+        assert_eq!(error.code, ErrorCode::Pattern);
+        assert!(error.message.starts_with("Invalid pattern `([a-z]`"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid pattern")]
+    fn test_regex_constructor_panics_on_invalid_pattern() {
+        regex("*");
+    }
+
     #[test]
     fn test_min_value_validator() {
         let validator = min_value(5);
@@ -308,4 +571,155 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().message, "Username is required");
     }
+
+    #[test]
+    fn test_or_passes_if_either_passes() {
+        let phone = custom(|value: &String| {
+            if !value.is_empty()
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || " +".contains(c))
+            {
+                Ok(())
+            } else {
+                Err(ValidationError::custom("Invalid phone number"))
+            }
+        });
+        let validator = email().and(required()).or(phone);
+        assert!(validator.validate(&"a@example.com".to_string()).is_ok());
+        assert!(validator.validate(&"+44 20 7946 0000".to_string()).is_ok());
+        assert!(validator.validate(&"neither".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_or_short_circuits() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let calls = Rc::new(Cell::new(0));
+        let counted = {
+            let calls = calls.clone();
+            custom(move |_: &String| {
+                calls.set(calls.get() + 1);
+                Ok(())
+            })
+        };
+        let validator = required().or(counted);
+
+        assert!(validator.validate(&"set".to_string()).is_ok());
+        assert_eq!(calls.get(), 0);
+        assert!(validator.validate(&"".to_string()).is_ok());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_or_combines_messages() {
+        let validator = min_length(5).or(max_length(1));
+        let error = validator.validate(&"abc".to_string()).unwrap_err();
+        assert_eq!(
+            error.message,
+            "Must be at least 5 characters or Must be at most 1 characters"
+        );
+        assert_eq!(error.code, ErrorCode::Custom);
+
+        let error = min_length(5)
+            .or(min_length(4))
+            .validate(&"abc".to_string())
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::MinLength);
+    }
+
+    #[test]
+    fn test_not_inverts() {
+        let validator = equals("admin".to_string(), "").not("That name is reserved");
+        assert!(validator.validate(&"alice".to_string()).is_ok());
+        let error = validator.validate(&"admin".to_string()).unwrap_err();
+        assert_eq!(error.message, "That name is reserved");
+        assert_eq!(error.code, ErrorCode::Custom);
+    }
+
+    #[test]
+    fn test_double_not_matches_original() {
+        let double = min_length(3).not("too long").not("too short");
+        for value in ["", "ab", "abc", "abcdef"] {
+            let value = value.to_string();
+            assert_eq!(
+                double.validate(&value).is_ok(),
+                min_length(3).validate(&value).is_ok(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_inclusive_bounds() {
+        let validator = RangeValidator::between(1, 10);
+        assert!(validator.validate(&1).is_ok());
+        assert!(validator.validate(&10).is_ok());
+        assert!(validator.validate(&5).is_ok());
+
+        let error = validator.validate(&0).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MinValue);
+        assert_eq!(error.message, "Must be at least 1");
+        let error = validator.validate(&11).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MaxValue);
+        assert_eq!(error.message, "Must be at most 10");
+    }
+
+    #[test]
+    fn test_range_exclusive_bounds() {
+        let validator = RangeValidator::between(0.0, 1.0)
+            .exclusive_min()
+            .exclusive_max();
+        assert!(validator.validate(&0.5).is_ok());
+
+        let error = validator.validate(&0.0).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MinValue);
+        assert_eq!(error.message, "Must be greater than 0");
+        let error = validator.validate(&1.0).unwrap_err();
+        assert_eq!(error.code, ErrorCode::MaxValue);
+        assert_eq!(error.message, "Must be less than 1");
+    }
+
+    #[test]
+    fn test_range_mixed_bounds() {
+        // Half-open [0, 360)
+        let validator = RangeValidator::between(0, 360).exclusive_max();
+        assert!(validator.validate(&0).is_ok());
+        assert!(validator.validate(&359).is_ok());
+        assert!(validator.validate(&360).is_err());
+    }
+
+    #[test]
+    fn test_range_single_sided() {
+        let at_least = RangeValidator::min(18);
+        assert!(at_least.validate(&18).is_ok());
+        assert!(at_least.validate(&i32::MAX).is_ok());
+        assert!(at_least.validate(&17).is_err());
+
+        let positive = RangeValidator::min(0.0).exclusive_min();
+        assert!(positive.validate(&f64::MIN_POSITIVE).is_ok());
+        assert!(positive.validate(&0.0).is_err());
+
+        let at_most = RangeValidator::max(100u8);
+        assert!(at_most.validate(&0).is_ok());
+        assert!(at_most.validate(&101).is_err());
+    }
+
+    #[test]
+    fn test_range_rejects_nan() {
+        let validator = RangeValidator::between(0.0, 1.0);
+        assert_eq!(
+            validator.validate(&f64::NAN).unwrap_err().code,
+            ErrorCode::MinValue
+        );
+        assert!(RangeValidator::max(1.0).validate(&f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_range_other_ordered_types() {
+        let validator = RangeValidator::between("b".to_string(), "d".to_string());
+        assert!(validator.validate(&"c".to_string()).is_ok());
+        assert!(validator.validate(&"e".to_string()).is_err());
+    }
 }
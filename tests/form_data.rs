@@ -0,0 +1,47 @@
+//! Browser tests for native form submission.
+//!
+//! Run with `wasm-pack test --headless --firefox`.
+#![cfg(target_arch = "wasm32")]
+
+use leptos::prelude::*;
+use mingot::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Mount `view` inside a fresh `<form>` and read its `FormData`
+fn form_data<N: IntoView + 'static>(view: impl FnOnce() -> N + 'static) -> web_sys::FormData {
+    let document = document();
+    let form: web_sys::HtmlFormElement = document.create_element("form").unwrap().unchecked_into();
+    document.body().unwrap().append_child(&form).unwrap();
+
+    let _handle = leptos::mount::mount_to(form.clone().unchecked_into(), view);
+    web_sys::FormData::new_with_form(&form).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn checked_checkbox_is_submitted() {
+    let data = form_data(|| {
+        view! { <Checkbox name="agree" value="yes" checked=RwSignal::new(true) /> }
+    });
+    assert_eq!(data.get("agree").as_string().as_deref(), Some("yes"));
+}
+
+#[wasm_bindgen_test]
+fn unchecked_checkbox_is_not_submitted() {
+    let data = form_data(|| view! { <Checkbox name="agree" /> });
+    assert!(data.get("agree").is_null());
+}
+
+#[wasm_bindgen_test]
+fn switch_and_radio_are_submitted() {
+    let data = form_data(|| {
+        view! {
+            <Switch name="notifications" checked=RwSignal::new(true) />
+            <Radio name="plan" value="pro" checked=RwSignal::new(true) />
+        }
+    });
+    assert_eq!(data.get("notifications").as_string().as_deref(), Some("on"));
+    assert_eq!(data.get("plan").as_string().as_deref(), Some("pro"));
+}